@@ -0,0 +1,41 @@
+use common_types::{
+    bech32,
+    json::{self, JsonObject},
+};
+
+multiversx_sc::imports!();
+
+pub static SRC_CALLBACK_KEY: &[u8] = b"src_callback";
+pub static DEST_CALLBACK_KEY: &[u8] = b"dest_callback";
+
+/// The contract to notify about a packet, as named in its memo
+///
+/// e.g. `{"src_callback":{"address":"erd1...","gas_limit":"5000000"}}`
+pub struct CallbackData<M: ManagedTypeApi> {
+    pub address: ManagedAddress<M>,
+    pub gas_limit: u64,
+}
+
+impl<M: ManagedTypeApi> CallbackData<M> {
+    /// Returns `None` if the packet data has no memo, or its memo has no valid callback under `key`
+    ///
+    /// A missing, zero or too high gas limit is replaced by `max_gas`.
+    pub fn from_packet_data(data: &ManagedBuffer<M>, key: &[u8], max_gas: u64) -> Option<Self> {
+        let memo = JsonObject::parse(data)?.get_string(b"memo")?;
+        let callback = JsonObject::parse(&memo)?.get_object(key)?;
+        let address = bech32::decode_address(&callback.get_string(b"address")?)?;
+        let gas_limit = match callback.get_string(b"gas_limit") {
+            Some(gas_limit) => json::parse_u64(&gas_limit)?,
+            None => 0,
+        };
+
+        Some(CallbackData {
+            address,
+            gas_limit: if gas_limit == 0 || gas_limit > max_gas {
+                max_gas
+            } else {
+                gas_limit
+            },
+        })
+    }
+}
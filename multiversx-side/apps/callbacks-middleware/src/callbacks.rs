@@ -0,0 +1,155 @@
+use ::channel::channel_libs::packet_types::Packet;
+use common_types::{ChannelId, PortId, Sequence};
+
+use crate::callback_data::{CallbackData, DEST_CALLBACK_KEY, SRC_CALLBACK_KEY};
+
+multiversx_sc::imports!();
+
+/// `ibcReceivePacketCallback(packet: Packet, ack: bytes)`
+pub static RECV_PACKET_CALLBACK_ENDPOINT: &[u8] = b"ibcReceivePacketCallback";
+/// `ibcOnAcknowledgementPacketCallback(packet: Packet, ack: bytes, relayer: Address)`
+pub static ACK_PACKET_CALLBACK_ENDPOINT: &[u8] = b"ibcOnAcknowledgementPacketCallback";
+/// `ibcOnTimeoutPacketCallback(packet: Packet, relayer: Address)`
+pub static TIMEOUT_PACKET_CALLBACK_ENDPOINT: &[u8] = b"ibcOnTimeoutPacketCallback";
+
+/// Invokes the callbacks of the packets
+///
+/// Anyone can name any contract in the memo of their packet, so callback contracts must check the packet they are
+/// notified about, e.g. its sender, before acting on it.
+#[multiversx_sc::module]
+pub trait CallbacksModule: crate::events::EventsModule {
+    /// Sets the maximum gas of a callback, which is also the gas of the callbacks without a gas limit
+    #[only_owner]
+    #[endpoint(setMaxCallbackGas)]
+    fn set_max_callback_gas(&self, max_callback_gas: u64) {
+        require!(max_callback_gas > 0, "Max callback gas must be positive");
+
+        self.max_callback_gas().set(max_callback_gas);
+    }
+
+    /// Notifies the destination callback of a packet of the acknowledgement written for it
+    fn invoke_recv_packet_callback(&self, packet: &Packet<Self::Api>, ack: &ManagedBuffer) {
+        let callback = match self.get_recv_packet_callback(packet) {
+            Some(callback) => callback,
+            None => return,
+        };
+
+        let mut args = ManagedArgBuffer::new();
+        args.push_arg(packet);
+        args.push_arg(ack);
+        self.invoke_callback(
+            &packet.dest_port,
+            &packet.dest_channel,
+            packet.seq,
+            callback,
+            RECV_PACKET_CALLBACK_ENDPOINT,
+            args,
+        );
+    }
+
+    fn get_recv_packet_callback(
+        &self,
+        packet: &Packet<Self::Api>,
+    ) -> Option<CallbackData<Self::Api>> {
+        CallbackData::from_packet_data(
+            &packet.data,
+            DEST_CALLBACK_KEY,
+            self.max_callback_gas().get(),
+        )
+    }
+
+    fn invoke_ack_packet_callback(
+        &self,
+        packet: &Packet<Self::Api>,
+        ack: &ManagedBuffer,
+        relayer: &ManagedAddress,
+    ) {
+        let callback = match CallbackData::from_packet_data(
+            &packet.data,
+            SRC_CALLBACK_KEY,
+            self.max_callback_gas().get(),
+        ) {
+            Some(callback) => callback,
+            None => return,
+        };
+
+        let mut args = ManagedArgBuffer::new();
+        args.push_arg(packet);
+        args.push_arg(ack);
+        args.push_arg(relayer);
+        self.invoke_callback(
+            &packet.src_port,
+            &packet.src_channel,
+            packet.seq,
+            callback,
+            ACK_PACKET_CALLBACK_ENDPOINT,
+            args,
+        );
+    }
+
+    fn invoke_timeout_packet_callback(&self, packet: &Packet<Self::Api>, relayer: &ManagedAddress) {
+        let callback = match CallbackData::from_packet_data(
+            &packet.data,
+            SRC_CALLBACK_KEY,
+            self.max_callback_gas().get(),
+        ) {
+            Some(callback) => callback,
+            None => return,
+        };
+
+        let mut args = ManagedArgBuffer::new();
+        args.push_arg(packet);
+        args.push_arg(relayer);
+        self.invoke_callback(
+            &packet.src_port,
+            &packet.src_channel,
+            packet.seq,
+            callback,
+            TIMEOUT_PACKET_CALLBACK_ENDPOINT,
+            args,
+        );
+    }
+
+    /// Registers the callback as a promise without callback, which runs after the packet is processed
+    ///
+    /// The gas of the callback is reserved from the transaction of the relayer, which fails if it can't afford it.
+    fn invoke_callback(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+        seq: Sequence,
+        callback: CallbackData<Self::Api>,
+        endpoint: &[u8],
+        args: ManagedArgBuffer<Self::Api>,
+    ) {
+        let endpoint = ManagedBuffer::from(endpoint);
+        self.callback_event(
+            port_id,
+            channel_id,
+            seq,
+            &callback.address,
+            &endpoint,
+            callback.gas_limit,
+        );
+
+        self.tx()
+            .to(&callback.address)
+            .gas(callback.gas_limit)
+            .raw_call(endpoint)
+            .arguments_raw(args)
+            .register_promise();
+    }
+
+    #[view(getMaxCallbackGas)]
+    #[storage_mapper("maxCallbackGas")]
+    fn max_callback_gas(&self) -> SingleValueMapper<u64>;
+
+    /// The packets received with an asynchronous acknowledgement, whose destination callback waits for it
+    #[storage_mapper("asyncAckPackets")]
+    fn async_ack_packets(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+        seq: Sequence,
+    ) -> SingleValueMapper<Packet<Self::Api>>;
+}
@@ -0,0 +1,17 @@
+use common_types::{ChannelId, PortId, Sequence};
+
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait EventsModule {
+    #[event("callbackEvent")]
+    fn callback_event(
+        &self,
+        #[indexed] port_id: &PortId<Self::Api>,
+        #[indexed] channel_id: &ChannelId<Self::Api>,
+        #[indexed] seq: Sequence,
+        #[indexed] callback_address: &ManagedAddress,
+        #[indexed] endpoint: &ManagedBuffer,
+        gas_limit: u64,
+    );
+}
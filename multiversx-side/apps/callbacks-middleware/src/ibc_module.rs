@@ -0,0 +1,175 @@
+use ::channel::{
+    channel_libs::packet_types::Packet,
+    interfaces::ibc_module_interface::{
+        ibc_module_proxy::ProxyTrait as _, MsgOnChanCloseConfirm, MsgOnChanCloseInit,
+        MsgOnChanOpenAck, MsgOnChanOpenConfirm, MsgOnChanOpenInit, MsgOnChanOpenTry,
+        MsgOnChanUpgradeAck, MsgOnChanUpgradeInit, MsgOnChanUpgradeOpen, MsgOnChanUpgradeTry,
+    },
+};
+use common_types::{ChannelId, PortId, Sequence, Version};
+
+multiversx_sc::imports!();
+
+/// The callbacks of the IBC handler, forwarded to the module below
+///
+/// The middleware doesn't negotiate a version of its own, so handshakes and upgrades are passed down unchanged.
+#[multiversx_sc::module]
+pub trait IbcModule:
+    crate::callbacks::CallbacksModule
+    + crate::ics4_wrapper::Ics4WrapperModule
+    + ::channel::middleware::ibc_middleware::IbcMiddlewareModule
+    + crate::events::EventsModule
+{
+    #[endpoint(onChanOpenInit)]
+    fn on_chan_open_init(&self, args: MsgOnChanOpenInit<Self::Api>) -> Version<Self::Api> {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        self.ibc_module_proxy(lower_module)
+            .on_chan_open_init(args)
+            .execute_on_dest_context()
+    }
+
+    #[endpoint(onChanOpenTry)]
+    fn on_chan_open_try(&self, args: MsgOnChanOpenTry<Self::Api>) -> Version<Self::Api> {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        self.ibc_module_proxy(lower_module)
+            .on_chan_open_try(args)
+            .execute_on_dest_context()
+    }
+
+    #[endpoint(onChanOpenAck)]
+    fn on_chan_open_ack(&self, args: MsgOnChanOpenAck<Self::Api>) {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        self.ibc_module_proxy(lower_module)
+            .on_chan_open_ack(args)
+            .execute_on_dest_context::<()>();
+    }
+
+    #[endpoint(onChanOpenConfirm)]
+    fn on_chan_open_confirm(&self, args: MsgOnChanOpenConfirm<Self::Api>) {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        self.ibc_module_proxy(lower_module)
+            .on_chan_open_confirm(args)
+            .execute_on_dest_context::<()>();
+    }
+
+    #[endpoint(onChanCloseInit)]
+    fn on_chan_close_init(&self, args: MsgOnChanCloseInit<Self::Api>) {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        self.ibc_module_proxy(lower_module)
+            .on_chan_close_init(args)
+            .execute_on_dest_context::<()>();
+    }
+
+    #[endpoint(onChanCloseConfirm)]
+    fn on_chan_close_confirm(&self, args: MsgOnChanCloseConfirm<Self::Api>) {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        self.ibc_module_proxy(lower_module)
+            .on_chan_close_confirm(args)
+            .execute_on_dest_context::<()>();
+    }
+
+    #[endpoint(onChanUpgradeInit)]
+    fn on_chan_upgrade_init(&self, args: MsgOnChanUpgradeInit<Self::Api>) -> Version<Self::Api> {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        self.ibc_module_proxy(lower_module)
+            .on_chan_upgrade_init(args)
+            .execute_on_dest_context()
+    }
+
+    #[endpoint(onChanUpgradeTry)]
+    fn on_chan_upgrade_try(&self, args: MsgOnChanUpgradeTry<Self::Api>) -> Version<Self::Api> {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        self.ibc_module_proxy(lower_module)
+            .on_chan_upgrade_try(args)
+            .execute_on_dest_context()
+    }
+
+    #[endpoint(onChanUpgradeAck)]
+    fn on_chan_upgrade_ack(&self, args: MsgOnChanUpgradeAck<Self::Api>) {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        self.ibc_module_proxy(lower_module)
+            .on_chan_upgrade_ack(args)
+            .execute_on_dest_context::<()>();
+    }
+
+    #[endpoint(onChanUpgradeOpen)]
+    fn on_chan_upgrade_open(&self, args: MsgOnChanUpgradeOpen<Self::Api>) {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        self.ibc_module_proxy(lower_module)
+            .on_chan_upgrade_open(args)
+            .execute_on_dest_context::<()>();
+    }
+
+    #[view(canTransitionToFlushComplete)]
+    fn can_transition_to_flush_complete(
+        &self,
+        port_id: PortId<Self::Api>,
+        channel_id: ChannelId<Self::Api>,
+        upgrade_sequence: Sequence,
+    ) -> bool {
+        let (_, lower_module) = self.get_stack_neighbours(&port_id, &channel_id);
+        self.ibc_module_proxy(lower_module)
+            .can_transition_to_flush_complete(port_id, channel_id, upgrade_sequence)
+            .execute_on_dest_context()
+    }
+
+    /// The destination callback is notified once the acknowledgement is written, which may be later on
+    #[endpoint(onRecvPacket)]
+    fn on_recv_packet(&self, packet: Packet<Self::Api>, relayer: ManagedAddress) -> ManagedBuffer {
+        let lower_module =
+            self.require_upper_module_caller(&packet.dest_port, &packet.dest_channel);
+
+        let ack: ManagedBuffer = self
+            .ibc_module_proxy(lower_module)
+            .on_recv_packet(packet.clone(), relayer)
+            .execute_on_dest_context();
+
+        if ack.is_empty() {
+            if self.get_recv_packet_callback(&packet).is_some() {
+                self.async_ack_packets(&packet.dest_port, &packet.dest_channel, packet.seq)
+                    .set(packet);
+            }
+        } else {
+            self.invoke_recv_packet_callback(&packet, &ack);
+        }
+
+        ack
+    }
+
+    #[endpoint(onAcknowledgementPacket)]
+    fn on_ack_packet(
+        &self,
+        packet: Packet<Self::Api>,
+        ack: ManagedBuffer,
+        relayer: ManagedAddress,
+    ) {
+        let lower_module = self.require_upper_module_caller(&packet.src_port, &packet.src_channel);
+
+        self.ibc_module_proxy(lower_module)
+            .on_ack_packet(packet.clone(), ack.clone(), relayer.clone())
+            .execute_on_dest_context::<()>();
+
+        self.invoke_ack_packet_callback(&packet, &ack, &relayer);
+    }
+
+    #[endpoint(onTimeoutPacket)]
+    fn on_timeout_packet(&self, packet: Packet<Self::Api>, relayer: ManagedAddress) {
+        let lower_module = self.require_upper_module_caller(&packet.src_port, &packet.src_channel);
+
+        self.ibc_module_proxy(lower_module)
+            .on_timeout_packet(packet.clone(), relayer.clone())
+            .execute_on_dest_context::<()>();
+
+        self.invoke_timeout_packet_callback(&packet, &relayer);
+    }
+}
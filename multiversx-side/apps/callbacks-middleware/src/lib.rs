@@ -0,0 +1,31 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod callback_data;
+pub mod callbacks;
+pub mod events;
+pub mod ibc_module;
+pub mod ics4_wrapper;
+
+/// ADR-8 callbacks middleware, notifying the contracts named in the memo of a packet of its lifecycle
+///
+/// The middleware is added to the stack of the port of an app whose packet data carries a JSON `memo`, e.g. ICS-20
+/// or ICS-721. Callbacks are asynchronous calls without callback, so their failure can't revert the packet.
+#[multiversx_sc::contract]
+pub trait CallbacksMiddleware:
+    callbacks::CallbacksModule
+    + ibc_module::IbcModule
+    + ics4_wrapper::Ics4WrapperModule
+    + ::channel::middleware::ibc_middleware::IbcMiddlewareModule
+    + events::EventsModule
+{
+    #[init]
+    fn init(&self, ibc_handler: ManagedAddress, max_callback_gas: u64) {
+        self.ibc_handler().set(ibc_handler);
+        self.set_max_callback_gas(max_callback_gas);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+}
@@ -0,0 +1,77 @@
+use callbacks_middleware::callback_data::{CallbackData, DEST_CALLBACK_KEY, SRC_CALLBACK_KEY};
+use common_types::bech32;
+use multiversx_sc::types::{ManagedAddress, ManagedBuffer};
+use multiversx_sc_scenario::DebugApi;
+
+const MAX_GAS: u64 = 10_000_000;
+
+fn callback_address() -> (ManagedAddress<DebugApi>, String) {
+    let address = ManagedAddress::<DebugApi>::from([7u8; 32]);
+    let encoded = bech32::encode_address(&address).to_boxed_bytes().into_vec();
+
+    (address, String::from_utf8(encoded).unwrap())
+}
+
+/// Packet data carrying `memo`, escaped as a JSON string
+fn packet_data(memo: &str) -> ManagedBuffer<DebugApi> {
+    ManagedBuffer::from(format!(r#"{{"memo":"{}"}}"#, memo.replace('"', r#"\""#)).as_bytes())
+}
+
+#[test]
+fn parse_callback_test() {
+    DebugApi::dummy();
+    let (address, encoded) = callback_address();
+    let data = packet_data(&format!(
+        r#"{{"src_callback":{{"address":"{}","gas_limit":"5000000"}}}}"#,
+        encoded
+    ));
+
+    let callback = CallbackData::from_packet_data(&data, SRC_CALLBACK_KEY, MAX_GAS).unwrap();
+    assert_eq!(callback.address, address);
+    assert_eq!(callback.gas_limit, 5_000_000);
+
+    assert!(CallbackData::from_packet_data(&data, DEST_CALLBACK_KEY, MAX_GAS).is_none());
+}
+
+#[test]
+fn parse_callback_gas_limit_capped_test() {
+    DebugApi::dummy();
+    let (_, encoded) = callback_address();
+    for memo in [
+        format!(r#"{{"dest_callback":{{"address":"{}"}}}}"#, encoded),
+        format!(
+            r#"{{"dest_callback":{{"address":"{}","gas_limit":"0"}}}}"#,
+            encoded
+        ),
+        format!(
+            r#"{{"dest_callback":{{"address":"{}","gas_limit":"10000001"}}}}"#,
+            encoded
+        ),
+    ] {
+        let callback =
+            CallbackData::from_packet_data(&packet_data(&memo), DEST_CALLBACK_KEY, MAX_GAS)
+                .unwrap();
+        assert_eq!(callback.gas_limit, MAX_GAS);
+    }
+}
+
+#[test]
+fn parse_invalid_callback_test() {
+    DebugApi::dummy();
+    let (_, encoded) = callback_address();
+    let invalid_data = [
+        ManagedBuffer::from(b"data"),
+        ManagedBuffer::from(br#"{"amount":"100"}"#),
+        packet_data("not a json memo"),
+        packet_data(r#"{"src_callback":{"address":"cosmos1invalid"}}"#),
+        packet_data(&format!(
+            r#"{{"src_callback":{{"address":"{}","gas_limit":"-1"}}}}"#,
+            encoded
+        )),
+        packet_data(&format!(r#"{{"src_callback":"{}"}}"#, encoded)),
+    ];
+
+    for data in invalid_data {
+        assert!(CallbackData::from_packet_data(&data, SRC_CALLBACK_KEY, MAX_GAS).is_none());
+    }
+}
@@ -0,0 +1,389 @@
+#![allow(dead_code)]
+
+use std::{cell::RefCell, rc::Rc};
+
+use ::channel::channel_libs::packet_types::Packet;
+use callbacks_middleware::{ibc_module::IbcModule, CallbacksMiddleware};
+use client_common::ClientStatus;
+use common_types::{
+    channel_types::{channel, channel_counterparty, height, upgrade},
+    connection_types::{connection_end, counterparty, merkle_prefix, version},
+    ConnectionHops, FeatureVec, VersionVec,
+};
+use host::{
+    host_config::HostConfigModule,
+    module_manager::ModuleManagerModule,
+    storage::{ChannelInfo, ClientInfo, RecvStartSequence, StorageModule},
+};
+use ibc_handler::IbcHandler;
+use mock::{
+    client_logic::ClientLogicModule,
+    mock_types::{client_state, consensus_state},
+    Mock,
+};
+use mock_app::{MockApp, MOCK_APP_VERSION};
+use multiversx_sc::types::{Address, MultiValueEncoded};
+use multiversx_sc_scenario::{
+    imports::{BlockchainStateWrapper, ContractObjWrapper, TxResult},
+    managed_address, managed_buffer, rust_biguint, DebugApi,
+};
+
+pub static CLIENT_TYPE: &[u8] = b"mock-client";
+pub static CLIENT_ID: &[u8] = b"mock-client-0";
+pub static CONNECTION_ID: &[u8] = b"connection-0";
+pub static COUNTERPARTY_CLIENT_ID: &[u8] = b"counterparty-client-0";
+pub static COUNTERPARTY_CONNECTION_ID: &[u8] = b"connection-7";
+pub static PORT_ID: &[u8] = b"mockapp";
+pub static CHANNEL_ID: &[u8] = b"channel-0";
+pub static COUNTERPARTY_PORT_ID: &[u8] = b"counterparty-mockapp";
+pub static COUNTERPARTY_CHANNEL_ID: &[u8] = b"channel-5";
+pub static COMMITMENT_PREFIX: &[u8] = b"ibc";
+
+pub const PROOF_HEIGHT: height::Data = height::Data {
+    revision_number: 0,
+    revision_height: 10,
+};
+pub const CONSENSUS_TIMESTAMP: u64 = 1_000_000_000;
+pub const TIMEOUT_TIMESTAMP: u64 = 1_000_000_000_000;
+pub const MAX_CALLBACK_GAS: u64 = 10_000_000;
+
+pub struct CallbacksMiddlewareSetup<
+    IbcHandlerObjBuilder,
+    MockClientObjBuilder,
+    CallbacksMiddlewareObjBuilder,
+    MockAppObjBuilder,
+> where
+    IbcHandlerObjBuilder: 'static + Copy + Fn() -> ibc_handler::ContractObj<DebugApi>,
+    MockClientObjBuilder: 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    CallbacksMiddlewareObjBuilder:
+        'static + Copy + Fn() -> callbacks_middleware::ContractObj<DebugApi>,
+    MockAppObjBuilder: 'static + Copy + Fn() -> mock_app::ContractObj<DebugApi>,
+{
+    pub b_mock: Rc<RefCell<BlockchainStateWrapper>>,
+    pub owner: Address,
+    pub relayer: Address,
+    pub ibc_handler_wrapper:
+        ContractObjWrapper<ibc_handler::ContractObj<DebugApi>, IbcHandlerObjBuilder>,
+    pub mock_client_wrapper: ContractObjWrapper<mock::ContractObj<DebugApi>, MockClientObjBuilder>,
+    pub callbacks_middleware_wrapper: ContractObjWrapper<
+        callbacks_middleware::ContractObj<DebugApi>,
+        CallbacksMiddlewareObjBuilder,
+    >,
+    pub mock_app_wrapper: ContractObjWrapper<mock_app::ContractObj<DebugApi>, MockAppObjBuilder>,
+}
+
+impl<
+        IbcHandlerObjBuilder,
+        MockClientObjBuilder,
+        CallbacksMiddlewareObjBuilder,
+        MockAppObjBuilder,
+    >
+    CallbacksMiddlewareSetup<
+        IbcHandlerObjBuilder,
+        MockClientObjBuilder,
+        CallbacksMiddlewareObjBuilder,
+        MockAppObjBuilder,
+    >
+where
+    IbcHandlerObjBuilder: 'static + Copy + Fn() -> ibc_handler::ContractObj<DebugApi>,
+    MockClientObjBuilder: 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    CallbacksMiddlewareObjBuilder:
+        'static + Copy + Fn() -> callbacks_middleware::ContractObj<DebugApi>,
+    MockAppObjBuilder: 'static + Copy + Fn() -> mock_app::ContractObj<DebugApi>,
+{
+    pub fn new(
+        b_mock: Rc<RefCell<BlockchainStateWrapper>>,
+        owner: &Address,
+        ibc_handler_builder: IbcHandlerObjBuilder,
+        mock_client_builder: MockClientObjBuilder,
+        callbacks_middleware_builder: CallbacksMiddlewareObjBuilder,
+        mock_app_builder: MockAppObjBuilder,
+    ) -> Self {
+        let rust_zero = rust_biguint!(0u64);
+        let relayer = b_mock.borrow_mut().create_user_account(&rust_zero);
+
+        let ibc_handler_wrapper = b_mock.borrow_mut().create_sc_account(
+            &rust_zero,
+            Some(owner),
+            ibc_handler_builder,
+            "ibc handler",
+        );
+        let mock_client_wrapper = b_mock.borrow_mut().create_sc_account(
+            &rust_zero,
+            Some(owner),
+            mock_client_builder,
+            "mock client",
+        );
+        let callbacks_middleware_wrapper = b_mock.borrow_mut().create_sc_account(
+            &rust_zero,
+            Some(owner),
+            callbacks_middleware_builder,
+            "callbacks middleware",
+        );
+        let mock_app_wrapper = b_mock.borrow_mut().create_sc_account(
+            &rust_zero,
+            Some(owner),
+            mock_app_builder,
+            "mock app",
+        );
+
+        let ibc_handler_address = ibc_handler_wrapper.address_ref().clone();
+        let mock_client_address = mock_client_wrapper.address_ref().clone();
+        let callbacks_middleware_address = callbacks_middleware_wrapper.address_ref().clone();
+        let mock_app_address = mock_app_wrapper.address_ref().clone();
+
+        b_mock
+            .borrow_mut()
+            .execute_tx(owner, &mock_client_wrapper, &rust_zero, |sc| {
+                sc.init(managed_address!(&ibc_handler_address));
+
+                let client_id = managed_buffer!(CLIENT_ID);
+                sc.client_states(&client_id)
+                    .set(client_state::Data::new(PROOF_HEIGHT));
+                sc.consensus_states(&client_id, &PROOF_HEIGHT.to_biguint_concat())
+                    .set(consensus_state::Data::new(CONSENSUS_TIMESTAMP));
+                sc.statuses(&client_id).set(ClientStatus::Active);
+            })
+            .assert_ok();
+
+        b_mock
+            .borrow_mut()
+            .execute_tx(owner, &callbacks_middleware_wrapper, &rust_zero, |sc| {
+                sc.init(managed_address!(&ibc_handler_address), MAX_CALLBACK_GAS);
+            })
+            .assert_ok();
+
+        b_mock
+            .borrow_mut()
+            .execute_tx(owner, &mock_app_wrapper, &rust_zero, |sc| {
+                sc.init(managed_address!(&callbacks_middleware_address));
+            })
+            .assert_ok();
+
+        b_mock
+            .borrow_mut()
+            .execute_tx(owner, &ibc_handler_wrapper, &rust_zero, |sc| {
+                sc.init();
+
+                sc.set_expected_time_per_block(6);
+                sc.register_client(
+                    managed_buffer!(CLIENT_TYPE),
+                    managed_address!(&mock_client_address),
+                );
+                sc.bind_port(
+                    managed_buffer!(PORT_ID),
+                    managed_address!(&mock_app_address),
+                );
+                let mut middlewares = MultiValueEncoded::new();
+                middlewares.push(managed_address!(&callbacks_middleware_address));
+                sc.set_port_middlewares(managed_buffer!(PORT_ID), middlewares);
+
+                sc.client_info(&managed_buffer!(CLIENT_ID)).set(ClientInfo {
+                    client_type: managed_buffer!(CLIENT_TYPE),
+                    client_impl: managed_address!(&mock_client_address),
+                });
+                sc.connection_info(&managed_buffer!(CONNECTION_ID))
+                    .set(open_connection());
+
+                let port_id = managed_buffer!(PORT_ID);
+                let channel_id = managed_buffer!(CHANNEL_ID);
+                sc.claim_channel_stack(&port_id, &channel_id);
+                sc.channel_info(&port_id, &channel_id)
+                    .set(open_channel_info());
+            })
+            .assert_ok();
+
+        Self {
+            b_mock,
+            owner: owner.clone(),
+            relayer,
+            ibc_handler_wrapper,
+            mock_client_wrapper,
+            callbacks_middleware_wrapper,
+            mock_app_wrapper,
+        }
+    }
+
+    /// Delivers the acknowledgement of a packet sent on `CHANNEL_ID`, as the handler does for the relayer
+    pub fn ack_packet(&self, seq: u64, data: &[u8], ack: &[u8]) -> TxResult {
+        let ibc_handler_address = self.ibc_handler_wrapper.address_ref().clone();
+        let relayer = self.relayer.clone();
+        self.b_mock.borrow_mut().execute_tx(
+            &ibc_handler_address,
+            &self.callbacks_middleware_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.on_ack_packet(
+                    sent_packet(seq, data),
+                    managed_buffer!(ack),
+                    managed_address!(&relayer),
+                );
+            },
+        )
+    }
+
+    pub fn timeout_packet(&self, seq: u64, data: &[u8]) -> TxResult {
+        let ibc_handler_address = self.ibc_handler_wrapper.address_ref().clone();
+        let relayer = self.relayer.clone();
+        self.b_mock.borrow_mut().execute_tx(
+            &ibc_handler_address,
+            &self.callbacks_middleware_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.on_timeout_packet(sent_packet(seq, data), managed_address!(&relayer));
+            },
+        )
+    }
+
+    /// Delivers a packet of the counterparty to `CHANNEL_ID`, returning the acknowledgement
+    pub fn recv_packet(&self, seq: u64, data: &[u8]) -> Vec<u8> {
+        let mut ack = Vec::new();
+        let ibc_handler_address = self.ibc_handler_wrapper.address_ref().clone();
+        let relayer = self.relayer.clone();
+        self.b_mock
+            .borrow_mut()
+            .execute_tx(
+                &ibc_handler_address,
+                &self.callbacks_middleware_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    ack = sc
+                        .on_recv_packet(received_packet(seq, data), managed_address!(&relayer))
+                        .to_boxed_bytes()
+                        .into_vec();
+                },
+            )
+            .assert_ok();
+
+        ack
+    }
+
+    /// Checks the callbacks the mock app received for `CHANNEL_ID`, in order
+    /// Marks a received packet as awaiting its asynchronous acknowledgement, as the handler does when the stack
+    /// returns an empty one
+    pub fn set_pending_ack(&self, seq: u64) {
+        self.b_mock
+            .borrow_mut()
+            .execute_tx(
+                &self.owner,
+                &self.ibc_handler_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    sc.pending_acks(&managed_buffer!(PORT_ID), &managed_buffer!(CHANNEL_ID))
+                        .insert(seq);
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn check_app_callbacks(&self, expected: &[&[u8]]) {
+        self.b_mock
+            .borrow_mut()
+            .execute_query(&self.mock_app_wrapper, |sc| {
+                let callbacks =
+                    sc.callbacks(&managed_buffer!(PORT_ID), &managed_buffer!(CHANNEL_ID));
+                assert_eq!(callbacks.len(), expected.len());
+                for (index, callback) in expected.iter().enumerate() {
+                    assert_eq!(callbacks.get(index + 1), managed_buffer!(callback));
+                }
+            })
+            .assert_ok();
+    }
+}
+
+/// A memo naming a callback contract under `key`, i.e. `src_callback` or `dest_callback`
+pub fn callback_memo(key: &str, address: &str, gas_limit: Option<u64>) -> String {
+    match gas_limit {
+        Some(gas_limit) => format!(
+            r#"{{"{}":{{"address":"{}","gas_limit":"{}"}}}}"#,
+            key, address, gas_limit
+        ),
+        None => format!(r#"{{"{}":{{"address":"{}"}}}}"#, key, address),
+    }
+}
+
+/// ICS-20 packet data carrying `memo`, as ibc-go encodes it
+pub fn transfer_data(memo: &str) -> Vec<u8> {
+    format!(
+        r#"{{"amount":"100","denom":"uatom","memo":"{}","receiver":"cosmos1receiver","sender":"erd1sender"}}"#,
+        memo.replace('"', r#"\""#)
+    )
+    .into_bytes()
+}
+
+/// A packet the app sent on `CHANNEL_ID`
+pub fn sent_packet(seq: u64, data: &[u8]) -> Packet<DebugApi> {
+    Packet {
+        seq,
+        src_port: managed_buffer!(PORT_ID),
+        src_channel: managed_buffer!(CHANNEL_ID),
+        dest_port: managed_buffer!(COUNTERPARTY_PORT_ID),
+        dest_channel: managed_buffer!(COUNTERPARTY_CHANNEL_ID),
+        data: managed_buffer!(data),
+        timeout_height: height::Data::default(),
+        timeout_timestamp: TIMEOUT_TIMESTAMP,
+    }
+}
+
+/// A packet the counterparty sent to `CHANNEL_ID`
+pub fn received_packet(seq: u64, data: &[u8]) -> Packet<DebugApi> {
+    Packet {
+        seq,
+        src_port: managed_buffer!(COUNTERPARTY_PORT_ID),
+        src_channel: managed_buffer!(COUNTERPARTY_CHANNEL_ID),
+        dest_port: managed_buffer!(PORT_ID),
+        dest_channel: managed_buffer!(CHANNEL_ID),
+        data: managed_buffer!(data),
+        timeout_height: height::Data::default(),
+        timeout_timestamp: TIMEOUT_TIMESTAMP,
+    }
+}
+
+pub fn open_channel_info() -> ChannelInfo<DebugApi> {
+    ChannelInfo {
+        channel: channel::Data {
+            state: channel::State::Open,
+            ordering: channel::Order::Unordered,
+            counterparty: channel_counterparty::Data {
+                port_id: managed_buffer!(COUNTERPARTY_PORT_ID),
+                channel_id: managed_buffer!(COUNTERPARTY_CHANNEL_ID),
+            },
+            connection_hops: ConnectionHops::from_single_item(managed_buffer!(CONNECTION_ID)),
+            version: managed_buffer!(MOCK_APP_VERSION),
+            upgrade_sequence: 0,
+        },
+        next_seq_send: 1,
+        next_seq_recv: 1,
+        next_seq_ack: 1,
+        upgrade: upgrade::Data::default(),
+        latest_error_rec_seq: 0,
+        recv_start_seq: RecvStartSequence {
+            seq: 0,
+            prev_seq: 0,
+        },
+        ack_start_seq: 0,
+    }
+}
+
+pub fn open_connection() -> connection_end::Data<DebugApi> {
+    let mut features = FeatureVec::new();
+    features.push(managed_buffer!(channel::ORDERED));
+    features.push(managed_buffer!(channel::UNORDERED));
+
+    connection_end::Data {
+        client_id: managed_buffer!(CLIENT_ID),
+        versions: VersionVec::from_single_item(version::Data {
+            identifier: managed_buffer!(b"1"),
+            features,
+        }),
+        state: connection_end::State::Open,
+        counterparty: counterparty::Data {
+            client_id: managed_buffer!(COUNTERPARTY_CLIENT_ID),
+            connection_id: managed_buffer!(COUNTERPARTY_CONNECTION_ID),
+            prefix: merkle_prefix::Data {
+                key_prefix: managed_buffer!(COMMITMENT_PREFIX),
+            },
+        },
+        delay_period: 0,
+    }
+}
@@ -0,0 +1,198 @@
+use std::{cell::RefCell, rc::Rc};
+
+use callbacks_middleware::{
+    callbacks::CallbacksModule, ibc_module::IbcModule, ics4_wrapper::Ics4WrapperModule,
+};
+use callbacks_middleware_setup::*;
+use multiversx_sc_scenario::{
+    imports::BlockchainStateWrapper, managed_address, managed_buffer, rust_biguint, DebugApi,
+};
+use test_utils::bech32_address;
+
+pub mod callbacks_middleware_setup;
+
+static SUCCESSFUL_TRANSFER_ACK: &[u8] = br#"{"result":"AQ=="}"#;
+
+type Setup = CallbacksMiddlewareSetup<
+    fn() -> ibc_handler::ContractObj<DebugApi>,
+    fn() -> mock::ContractObj<DebugApi>,
+    fn() -> callbacks_middleware::ContractObj<DebugApi>,
+    fn() -> mock_app::ContractObj<DebugApi>,
+>;
+
+fn get_callbacks_middleware_setup() -> Setup {
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_biguint!(0));
+    CallbacksMiddlewareSetup::new(
+        Rc::new(RefCell::new(b_mock)),
+        &owner,
+        ibc_handler::contract_obj,
+        mock::contract_obj,
+        callbacks_middleware::contract_obj,
+        mock_app::contract_obj,
+    )
+}
+
+/// Packet data whose memo names the mock app as callback contract under `key`
+fn data_with_app_callback(setup: &Setup, key: &str) -> Vec<u8> {
+    let mock_app_address = setup.mock_app_wrapper.address_ref().clone();
+    let memo = callback_memo(
+        key,
+        &bech32_address(
+            &setup.b_mock,
+            &setup.callbacks_middleware_wrapper,
+            &mock_app_address,
+        ),
+        Some(1_000_000),
+    );
+
+    transfer_data(&memo)
+}
+
+#[test]
+fn ack_packet_invokes_src_callback_test() {
+    let setup = get_callbacks_middleware_setup();
+    let data = data_with_app_callback(&setup, "src_callback");
+
+    setup
+        .ack_packet(1, &data, SUCCESSFUL_TRANSFER_ACK)
+        .assert_ok();
+
+    setup.check_app_callbacks(&[
+        b"onAcknowledgementPacket",
+        b"ibcOnAcknowledgementPacketCallback",
+    ]);
+}
+
+#[test]
+fn timeout_packet_invokes_src_callback_test() {
+    let setup = get_callbacks_middleware_setup();
+    let data = data_with_app_callback(&setup, "src_callback");
+
+    setup.timeout_packet(1, &data).assert_ok();
+
+    setup.check_app_callbacks(&[b"onTimeoutPacket", b"ibcOnTimeoutPacketCallback"]);
+}
+
+#[test]
+fn recv_packet_invokes_dest_callback_test() {
+    let setup = get_callbacks_middleware_setup();
+    let data = data_with_app_callback(&setup, "dest_callback");
+
+    let ack = setup.recv_packet(1, &data);
+
+    assert_eq!(ack, mock_app::SUCCESSFUL_ACK);
+    setup.check_app_callbacks(&[b"onRecvPacket", b"ibcReceivePacketCallback"]);
+}
+
+#[test]
+fn callbacks_of_other_side_not_invoked_test() {
+    let setup = get_callbacks_middleware_setup();
+    let dest_callback_data = data_with_app_callback(&setup, "dest_callback");
+    let src_callback_data = data_with_app_callback(&setup, "src_callback");
+
+    setup
+        .ack_packet(1, &dest_callback_data, SUCCESSFUL_TRANSFER_ACK)
+        .assert_ok();
+    setup.recv_packet(1, &src_callback_data);
+
+    setup.check_app_callbacks(&[b"onAcknowledgementPacket", b"onRecvPacket"]);
+}
+
+#[test]
+fn packet_without_memo_test() {
+    let setup = get_callbacks_middleware_setup();
+
+    setup
+        .ack_packet(1, &transfer_data(""), SUCCESSFUL_TRANSFER_ACK)
+        .assert_ok();
+    setup.timeout_packet(2, b"data").assert_ok();
+
+    setup.check_app_callbacks(&[b"onAcknowledgementPacket", b"onTimeoutPacket"]);
+}
+
+#[test]
+fn failed_callback_does_not_revert_packet_test() {
+    let setup = get_callbacks_middleware_setup();
+    let mock_client_address = setup.mock_client_wrapper.address_ref().clone();
+    let memo = callback_memo(
+        "src_callback",
+        &bech32_address(
+            &setup.b_mock,
+            &setup.callbacks_middleware_wrapper,
+            &mock_client_address,
+        ),
+        None,
+    );
+
+    setup
+        .ack_packet(1, &transfer_data(&memo), SUCCESSFUL_TRANSFER_ACK)
+        .assert_ok();
+
+    setup.check_app_callbacks(&[b"onAcknowledgementPacket"]);
+}
+
+#[test]
+fn async_ack_invokes_dest_callback_test() {
+    let setup = get_callbacks_middleware_setup();
+    let data = data_with_app_callback(&setup, "dest_callback");
+    let ibc_handler_address = setup.ibc_handler_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.callbacks_middleware_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                // the packet as the middleware keeps it when the app acknowledges it asynchronously
+                sc.async_ack_packets(&managed_buffer!(PORT_ID), &managed_buffer!(CHANNEL_ID), 1)
+                    .set(received_packet(1, &data));
+            },
+        )
+        .assert_ok();
+    setup.set_pending_ack(1);
+
+    let mock_app_address = setup.mock_app_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &mock_app_address,
+            &setup.callbacks_middleware_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.write_ack(
+                    managed_buffer!(PORT_ID),
+                    managed_buffer!(CHANNEL_ID),
+                    1,
+                    managed_buffer!(mock_app::SUCCESSFUL_ACK),
+                );
+
+                assert!(sc
+                    .async_ack_packets(&managed_buffer!(PORT_ID), &managed_buffer!(CHANNEL_ID), 1)
+                    .is_empty());
+            },
+        )
+        .assert_ok();
+
+    setup.check_app_callbacks(&[b"ibcReceivePacketCallback"]);
+}
+
+#[test]
+fn try_callback_not_upper_module_test() {
+    let setup = get_callbacks_middleware_setup();
+    let relayer = setup.relayer.clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &relayer,
+            &setup.callbacks_middleware_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.on_timeout_packet(sent_packet(1, b"data"), managed_address!(&relayer));
+            },
+        )
+        .assert_user_error("Only the module above in the stack may call this endpoint");
+}
@@ -0,0 +1,44 @@
+use common_types::{ChannelId, PortId, Sequence};
+
+use crate::fees::PacketFee;
+
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait EventsModule {
+    #[event("payPacketFeeEvent")]
+    fn pay_packet_fee_event(
+        &self,
+        #[indexed] port_id: &PortId<Self::Api>,
+        #[indexed] channel_id: &ChannelId<Self::Api>,
+        #[indexed] seq: Sequence,
+        packet_fee: &PacketFee<Self::Api>,
+    );
+
+    #[event("registerPayeeEvent")]
+    fn register_payee_event(
+        &self,
+        #[indexed] port_id: &PortId<Self::Api>,
+        #[indexed] channel_id: &ChannelId<Self::Api>,
+        #[indexed] relayer: &ManagedAddress,
+        payee: &ManagedAddress,
+    );
+
+    #[event("registerCounterpartyPayeeEvent")]
+    fn register_counterparty_payee_event(
+        &self,
+        #[indexed] port_id: &PortId<Self::Api>,
+        #[indexed] channel_id: &ChannelId<Self::Api>,
+        #[indexed] relayer: &ManagedAddress,
+        counterparty_payee: &ManagedBuffer,
+    );
+
+    #[event("distributeFeesEvent")]
+    fn distribute_fees_event(
+        &self,
+        #[indexed] port_id: &PortId<Self::Api>,
+        #[indexed] channel_id: &ChannelId<Self::Api>,
+        #[indexed] seq: Sequence,
+        payee: &ManagedAddress,
+    );
+}
@@ -0,0 +1,327 @@
+use common_types::{bech32, ChannelId, PortId, Sequence};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+/// The fees paid for relaying a packet, all in the same token
+///
+/// `recv_fee` goes to the relayer delivering the packet, `ack_fee` to the one relaying its acknowledgement and
+/// `timeout_fee` to the one relaying its timeout. The fees of the step which didn't happen are refunded.
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct Fee<M: ManagedTypeApi> {
+    pub token: EgldOrEsdtTokenIdentifier<M>,
+    pub recv_fee: BigUint<M>,
+    pub ack_fee: BigUint<M>,
+    pub timeout_fee: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> Fee<M> {
+    pub fn total(&self) -> BigUint<M> {
+        &self.recv_fee + &self.ack_fee + &self.timeout_fee
+    }
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct PacketFee<M: ManagedTypeApi> {
+    pub fee: Fee<M>,
+    pub refund_address: ManagedAddress<M>,
+}
+
+/// Escrows the fees of the packets sent on fee enabled channels, and pays them out to the relayers
+#[multiversx_sc::module]
+pub trait FeesModule: crate::events::EventsModule {
+    /// Escrows fees for a packet which was sent, but not yet acknowledged or timed out
+    ///
+    /// The payment must be the sum of the three fees. Anyone may pay fees for any packet, several times, and gets
+    /// back the fees which aren't paid out.
+    #[payable("*")]
+    #[endpoint(payPacketFee)]
+    fn pay_packet_fee(
+        &self,
+        port_id: PortId<Self::Api>,
+        channel_id: ChannelId<Self::Api>,
+        seq: Sequence,
+        recv_fee: BigUint,
+        ack_fee: BigUint,
+        timeout_fee: BigUint,
+    ) {
+        require!(
+            self.fee_enabled(&port_id, &channel_id).get(),
+            "Fees are not enabled on this channel"
+        );
+        require!(
+            self.packets_in_flight(&port_id, &channel_id).contains(&seq),
+            "Packet not in flight"
+        );
+
+        let (token, amount) = self.call_value().egld_or_single_fungible_esdt();
+        let fee = Fee {
+            token,
+            recv_fee,
+            ack_fee,
+            timeout_fee,
+        };
+        require!(amount > 0, "No fees paid");
+        require!(amount == fee.total(), "Payment must be the sum of the fees");
+
+        let packet_fee = PacketFee {
+            fee,
+            refund_address: self.blockchain().get_caller(),
+        };
+        self.pay_packet_fee_event(&port_id, &channel_id, seq, &packet_fee);
+        self.packet_fees(&port_id, &channel_id, seq)
+            .push(&packet_fee);
+    }
+
+    /// Makes the acknowledgement and timeout fees of the packets relayed by the caller go to `payee`
+    #[endpoint(registerPayee)]
+    fn register_payee(
+        &self,
+        port_id: PortId<Self::Api>,
+        channel_id: ChannelId<Self::Api>,
+        payee: ManagedAddress,
+    ) {
+        require!(
+            self.fee_enabled(&port_id, &channel_id).get(),
+            "Fees are not enabled on this channel"
+        );
+
+        let relayer = self.blockchain().get_caller();
+        self.register_payee_event(&port_id, &channel_id, &relayer, &payee);
+        self.payees(&port_id, &channel_id, &relayer).set(payee);
+    }
+
+    /// Sets the address, on the counterparty chain, to which the receive fees of the packets the caller delivers
+    /// here are paid
+    #[endpoint(registerCounterpartyPayee)]
+    fn register_counterparty_payee(
+        &self,
+        port_id: PortId<Self::Api>,
+        channel_id: ChannelId<Self::Api>,
+        counterparty_payee: ManagedBuffer,
+    ) {
+        require!(
+            self.fee_enabled(&port_id, &channel_id).get(),
+            "Fees are not enabled on this channel"
+        );
+        require!(!counterparty_payee.is_empty(), "Empty counterparty payee");
+
+        let relayer = self.blockchain().get_caller();
+        self.register_counterparty_payee_event(
+            &port_id,
+            &channel_id,
+            &relayer,
+            &counterparty_payee,
+        );
+        self.counterparty_payees(&port_id, &channel_id, &relayer)
+            .set(counterparty_payee);
+    }
+
+    /// Pays the receive fees to the forward relayer, the acknowledgement fees to the relayer of the acknowledgement,
+    /// and refunds the timeout fees
+    ///
+    /// The receive fees are refunded if the forward relayer isn't a valid address.
+    fn distribute_fees_on_ack(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+        seq: Sequence,
+        forward_relayer_address: &ManagedBuffer,
+        relayer: &ManagedAddress,
+    ) {
+        let packet_fees = self.take_packet_fees(port_id, channel_id, seq);
+        if packet_fees.is_empty() {
+            return;
+        }
+
+        let opt_forward_relayer = bech32::decode_address(forward_relayer_address);
+        let payee = self.get_payee_or_relayer(port_id, channel_id, relayer);
+        for packet_fee in packet_fees.iter() {
+            let fee = &packet_fee.fee;
+            let recv_fee_receiver = match &opt_forward_relayer {
+                Some(forward_relayer) => forward_relayer,
+                None => &packet_fee.refund_address,
+            };
+            self.pay_fee(&fee.token, &fee.recv_fee, recv_fee_receiver);
+            self.pay_fee(&fee.token, &fee.ack_fee, &payee);
+            self.pay_fee(&fee.token, &fee.timeout_fee, &packet_fee.refund_address);
+        }
+
+        self.distribute_fees_event(port_id, channel_id, seq, &payee);
+    }
+
+    /// Pays the timeout fees to the relayer of the timeout, and refunds the receive and acknowledgement fees
+    fn distribute_fees_on_timeout(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+        seq: Sequence,
+        relayer: &ManagedAddress,
+    ) {
+        let packet_fees = self.take_packet_fees(port_id, channel_id, seq);
+        if packet_fees.is_empty() {
+            return;
+        }
+
+        let payee = self.get_payee_or_relayer(port_id, channel_id, relayer);
+        for packet_fee in packet_fees.iter() {
+            let fee = &packet_fee.fee;
+            let refund = &fee.recv_fee + &fee.ack_fee;
+            self.pay_fee(&fee.token, &refund, &packet_fee.refund_address);
+            self.pay_fee(&fee.token, &fee.timeout_fee, &payee);
+        }
+
+        self.distribute_fees_event(port_id, channel_id, seq, &payee);
+    }
+
+    /// Refunds all the fees escrowed for the packets of a channel, which is closing
+    fn refund_channel_fees(&self, port_id: &PortId<Self::Api>, channel_id: &ChannelId<Self::Api>) {
+        let mut in_flight_mapper = self.packets_in_flight(port_id, channel_id);
+        for seq in in_flight_mapper.iter() {
+            for packet_fee in self.take_packet_fees(port_id, channel_id, seq).iter() {
+                let fee = &packet_fee.fee;
+                self.pay_fee(&fee.token, &fee.total(), &packet_fee.refund_address);
+            }
+        }
+
+        in_flight_mapper.clear();
+    }
+
+    /// Removes the packet from the ones in flight, returning its fees
+    fn take_packet_fees(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+        seq: Sequence,
+    ) -> ManagedVec<PacketFee<Self::Api>> {
+        self.packets_in_flight(port_id, channel_id)
+            .swap_remove(&seq);
+
+        let mut fees_mapper = self.packet_fees(port_id, channel_id, seq);
+        let mut fees = ManagedVec::new();
+        for packet_fee in fees_mapper.iter() {
+            fees.push(packet_fee);
+        }
+        fees_mapper.clear();
+
+        fees
+    }
+
+    fn get_payee_or_relayer(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+        relayer: &ManagedAddress,
+    ) -> ManagedAddress {
+        let mapper = self.payees(port_id, channel_id, relayer);
+        if mapper.is_empty() {
+            return relayer.clone();
+        }
+
+        mapper.get()
+    }
+
+    fn pay_fee(
+        &self,
+        token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+        receiver: &ManagedAddress,
+    ) {
+        if *amount > 0u32 {
+            self.send().direct(receiver, token, 0, amount);
+        }
+    }
+
+    /// The fees escrowed for a packet, by the order in which they were paid
+    #[view(getPacketFees)]
+    fn get_packet_fees(
+        &self,
+        port_id: PortId<Self::Api>,
+        channel_id: ChannelId<Self::Api>,
+        seq: Sequence,
+    ) -> MultiValueEncoded<PacketFee<Self::Api>> {
+        self.packet_fees(&port_id, &channel_id, seq)
+            .iter()
+            .collect()
+    }
+
+    #[view(getPayee)]
+    fn get_payee(
+        &self,
+        port_id: PortId<Self::Api>,
+        channel_id: ChannelId<Self::Api>,
+        relayer: ManagedAddress,
+    ) -> OptionalValue<ManagedAddress> {
+        let mapper = self.payees(&port_id, &channel_id, &relayer);
+        if mapper.is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(mapper.get())
+    }
+
+    #[view(getCounterpartyPayee)]
+    fn get_counterparty_payee(
+        &self,
+        port_id: PortId<Self::Api>,
+        channel_id: ChannelId<Self::Api>,
+        relayer: ManagedAddress,
+    ) -> OptionalValue<ManagedBuffer> {
+        let mapper = self.counterparty_payees(&port_id, &channel_id, &relayer);
+        if mapper.is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(mapper.get())
+    }
+
+    /// Set for the channels whose both ends negotiated the fee version
+    #[view(isFeeEnabled)]
+    #[storage_mapper("feeEnabled")]
+    fn fee_enabled(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+    ) -> SingleValueMapper<bool>;
+
+    /// The packets sent on fee enabled channels which weren't yet acknowledged or timed out
+    #[storage_mapper("packetsInFlight")]
+    fn packets_in_flight(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+    ) -> UnorderedSetMapper<Sequence>;
+
+    #[storage_mapper("packetFees")]
+    fn packet_fees(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+        seq: Sequence,
+    ) -> VecMapper<PacketFee<Self::Api>>;
+
+    #[storage_mapper("payees")]
+    fn payees(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+        relayer: &ManagedAddress,
+    ) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("counterpartyPayees")]
+    fn counterparty_payees(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+        relayer: &ManagedAddress,
+    ) -> SingleValueMapper<ManagedBuffer>;
+
+    /// The relayers of the packets whose acknowledgement the app writes later
+    #[storage_mapper("asyncAckRelayers")]
+    fn async_ack_relayers(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+        seq: Sequence,
+    ) -> SingleValueMapper<ManagedAddress>;
+}
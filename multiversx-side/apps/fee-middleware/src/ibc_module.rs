@@ -0,0 +1,266 @@
+use ::channel::{
+    channel_libs::packet_types::Packet,
+    interfaces::ibc_module_interface::{
+        ibc_module_proxy::ProxyTrait as _, MsgOnChanCloseConfirm, MsgOnChanCloseInit,
+        MsgOnChanOpenAck, MsgOnChanOpenConfirm, MsgOnChanOpenInit, MsgOnChanOpenTry,
+        MsgOnChanUpgradeAck, MsgOnChanUpgradeInit, MsgOnChanUpgradeOpen, MsgOnChanUpgradeTry,
+    },
+};
+use common_types::{ChannelId, PortId, Sequence, Version};
+
+use crate::metadata::{IncentivizedAcknowledgement, FEE_VERSION_KEY, ICS29_VERSION};
+
+multiversx_sc::imports!();
+
+/// The callbacks of the IBC handler, forwarded to the module below
+///
+/// Versions which aren't fee metadata are passed down unchanged, so that fees stay optional on every channel.
+/// Otherwise the module below only sees the `app_version` of the metadata.
+#[multiversx_sc::module]
+pub trait IbcModule:
+    crate::fees::FeesModule
+    + crate::ics4_wrapper::Ics4WrapperModule
+    + ::channel::middleware::ibc_middleware::IbcMiddlewareModule
+    + crate::events::EventsModule
+{
+    /// An empty version proposes fees, along with the default version of the app
+    #[endpoint(onChanOpenInit)]
+    fn on_chan_open_init(&self, args: MsgOnChanOpenInit<Self::Api>) -> Version<Self::Api> {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        let port_id = args.port_id.clone();
+        let channel_id = args.channel_id.clone();
+        let (version, fee_enabled) =
+            self.wrapped_on_chan_open_init(lower_module, args, FEE_VERSION_KEY, ICS29_VERSION);
+        if fee_enabled {
+            self.fee_enabled(&port_id, &channel_id).set(true);
+        }
+
+        version
+    }
+
+    #[endpoint(onChanOpenTry)]
+    fn on_chan_open_try(&self, args: MsgOnChanOpenTry<Self::Api>) -> Version<Self::Api> {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        let port_id = args.port_id.clone();
+        let channel_id = args.channel_id.clone();
+        let (version, fee_enabled) =
+            self.wrapped_on_chan_open_try(lower_module, args, FEE_VERSION_KEY, ICS29_VERSION);
+        if fee_enabled {
+            self.fee_enabled(&port_id, &channel_id).set(true);
+        }
+
+        version
+    }
+
+    /// A channel proposing fees must get fee metadata back
+    #[endpoint(onChanOpenAck)]
+    fn on_chan_open_ack(&self, args: MsgOnChanOpenAck<Self::Api>) {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        let counterparty_version = self.unwrap_counterparty_version(
+            args.counterparty_version,
+            self.fee_enabled(&args.port_id, &args.channel_id).get(),
+            FEE_VERSION_KEY,
+            ICS29_VERSION,
+        );
+        self.ibc_module_proxy(lower_module)
+            .on_chan_open_ack(MsgOnChanOpenAck {
+                port_id: args.port_id,
+                channel_id: args.channel_id,
+                counterparty_version,
+            })
+            .execute_on_dest_context::<()>();
+    }
+
+    #[endpoint(onChanOpenConfirm)]
+    fn on_chan_open_confirm(&self, args: MsgOnChanOpenConfirm<Self::Api>) {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        self.ibc_module_proxy(lower_module)
+            .on_chan_open_confirm(args)
+            .execute_on_dest_context::<()>();
+    }
+
+    /// The fees of the packets in flight are refunded once the app accepts to close the channel
+    #[endpoint(onChanCloseInit)]
+    fn on_chan_close_init(&self, args: MsgOnChanCloseInit<Self::Api>) {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        let port_id = args.port_id.clone();
+        let channel_id = args.channel_id.clone();
+        self.ibc_module_proxy(lower_module)
+            .on_chan_close_init(args)
+            .execute_on_dest_context::<()>();
+
+        self.refund_channel_fees(&port_id, &channel_id);
+    }
+
+    #[endpoint(onChanCloseConfirm)]
+    fn on_chan_close_confirm(&self, args: MsgOnChanCloseConfirm<Self::Api>) {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        let port_id = args.port_id.clone();
+        let channel_id = args.channel_id.clone();
+        self.ibc_module_proxy(lower_module)
+            .on_chan_close_confirm(args)
+            .execute_on_dest_context::<()>();
+
+        self.refund_channel_fees(&port_id, &channel_id);
+    }
+
+    #[endpoint(onChanUpgradeInit)]
+    fn on_chan_upgrade_init(&self, args: MsgOnChanUpgradeInit<Self::Api>) -> Version<Self::Api> {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        self.wrapped_on_chan_upgrade_init(lower_module, args, FEE_VERSION_KEY, ICS29_VERSION)
+    }
+
+    #[endpoint(onChanUpgradeTry)]
+    fn on_chan_upgrade_try(&self, args: MsgOnChanUpgradeTry<Self::Api>) -> Version<Self::Api> {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        self.wrapped_on_chan_upgrade_try(lower_module, args, FEE_VERSION_KEY, ICS29_VERSION)
+    }
+
+    #[endpoint(onChanUpgradeAck)]
+    fn on_chan_upgrade_ack(&self, args: MsgOnChanUpgradeAck<Self::Api>) {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        let (counterparty_version, _) =
+            self.unwrap_upgrade_version(args.counterparty_version, FEE_VERSION_KEY, ICS29_VERSION);
+        self.ibc_module_proxy(lower_module)
+            .on_chan_upgrade_ack(MsgOnChanUpgradeAck {
+                port_id: args.port_id,
+                channel_id: args.channel_id,
+                upgrade_sequence: args.upgrade_sequence,
+                counterparty_version,
+            })
+            .execute_on_dest_context::<()>();
+    }
+
+    /// Fees are enabled or disabled depending on the version the channel was upgraded to
+    #[endpoint(onChanUpgradeOpen)]
+    fn on_chan_upgrade_open(&self, args: MsgOnChanUpgradeOpen<Self::Api>) {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        let (version, fee_enabled) =
+            self.unwrap_upgrade_version(args.version, FEE_VERSION_KEY, ICS29_VERSION);
+        let fee_enabled_mapper = self.fee_enabled(&args.port_id, &args.channel_id);
+        if fee_enabled {
+            fee_enabled_mapper.set(true);
+        } else {
+            fee_enabled_mapper.clear();
+        }
+
+        self.ibc_module_proxy(lower_module)
+            .on_chan_upgrade_open(MsgOnChanUpgradeOpen {
+                port_id: args.port_id,
+                channel_id: args.channel_id,
+                upgrade_sequence: args.upgrade_sequence,
+                order: args.order,
+                connection_hops: args.connection_hops,
+                version,
+            })
+            .execute_on_dest_context::<()>();
+    }
+
+    #[view(canTransitionToFlushComplete)]
+    fn can_transition_to_flush_complete(
+        &self,
+        port_id: PortId<Self::Api>,
+        channel_id: ChannelId<Self::Api>,
+        upgrade_sequence: Sequence,
+    ) -> bool {
+        let (_, lower_module) = self.get_stack_neighbours(&port_id, &channel_id);
+        self.ibc_module_proxy(lower_module)
+            .can_transition_to_flush_complete(port_id, channel_id, upgrade_sequence)
+            .execute_on_dest_context()
+    }
+
+    /// On fee enabled channels, the acknowledgement of the app is wrapped with the counterparty payee of the relayer
+    ///
+    /// The relayer is kept until the app writes its acknowledgement, if it does so asynchronously.
+    #[endpoint(onRecvPacket)]
+    fn on_recv_packet(&self, packet: Packet<Self::Api>, relayer: ManagedAddress) -> ManagedBuffer {
+        let lower_module =
+            self.require_upper_module_caller(&packet.dest_port, &packet.dest_channel);
+
+        let port_id = packet.dest_port.clone();
+        let channel_id = packet.dest_channel.clone();
+        let seq = packet.seq;
+        let app_ack: ManagedBuffer = self
+            .ibc_module_proxy(lower_module)
+            .on_recv_packet(packet, relayer.clone())
+            .execute_on_dest_context();
+        if !self.fee_enabled(&port_id, &channel_id).get() {
+            return app_ack;
+        }
+
+        if app_ack.is_empty() {
+            self.async_ack_relayers(&port_id, &channel_id, seq)
+                .set(relayer);
+            return app_ack;
+        }
+
+        self.wrap_ack(&port_id, &channel_id, &relayer, app_ack)
+    }
+
+    /// Pays out the fees of the packet, then passes the acknowledgement of the counterparty app to the app
+    #[endpoint(onAcknowledgementPacket)]
+    fn on_ack_packet(
+        &self,
+        packet: Packet<Self::Api>,
+        ack: ManagedBuffer,
+        relayer: ManagedAddress,
+    ) {
+        let lower_module = self.require_upper_module_caller(&packet.src_port, &packet.src_channel);
+
+        let app_ack = if self
+            .fee_enabled(&packet.src_port, &packet.src_channel)
+            .get()
+        {
+            let incentivized_ack = match IncentivizedAcknowledgement::from_json(&ack) {
+                Some(incentivized_ack) => incentivized_ack,
+                None => sc_panic!("Invalid incentivized acknowledgement"),
+            };
+            self.distribute_fees_on_ack(
+                &packet.src_port,
+                &packet.src_channel,
+                packet.seq,
+                &incentivized_ack.forward_relayer_address,
+                &relayer,
+            );
+
+            incentivized_ack.app_acknowledgement
+        } else {
+            ack
+        };
+
+        self.ibc_module_proxy(lower_module)
+            .on_ack_packet(packet, app_ack, relayer)
+            .execute_on_dest_context::<()>();
+    }
+
+    #[endpoint(onTimeoutPacket)]
+    fn on_timeout_packet(&self, packet: Packet<Self::Api>, relayer: ManagedAddress) {
+        let lower_module = self.require_upper_module_caller(&packet.src_port, &packet.src_channel);
+
+        if self
+            .fee_enabled(&packet.src_port, &packet.src_channel)
+            .get()
+        {
+            self.distribute_fees_on_timeout(
+                &packet.src_port,
+                &packet.src_channel,
+                packet.seq,
+                &relayer,
+            );
+        }
+
+        self.ibc_module_proxy(lower_module)
+            .on_timeout_packet(packet, relayer)
+            .execute_on_dest_context::<()>();
+    }
+}
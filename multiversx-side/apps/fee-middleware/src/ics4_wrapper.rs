@@ -0,0 +1,97 @@
+use ::channel::interfaces::ibc_handler_interface::ibc_handler_proxy::ProxyTrait as _;
+use common_types::{
+    channel_types::{acknowledgement, height},
+    ChannelId, PortId, Sequence, UnixTimestamp,
+};
+
+use crate::metadata::IncentivizedAcknowledgement;
+
+multiversx_sc::imports!();
+
+/// The endpoints of the IBC handler the module below calls, which the middleware forwards up the stack
+#[multiversx_sc::module]
+pub trait Ics4WrapperModule:
+    crate::fees::FeesModule
+    + ::channel::middleware::ibc_middleware::IbcMiddlewareModule
+    + crate::events::EventsModule
+{
+    #[endpoint(sendPacket)]
+    fn send_packet(
+        &self,
+        src_port: PortId<Self::Api>,
+        src_channel: ChannelId<Self::Api>,
+        timeout_height: height::Data,
+        timeout_timestamp: UnixTimestamp,
+        data: ManagedBuffer,
+    ) -> Sequence {
+        let upper_module = self.require_lower_module_caller(&src_port, &src_channel);
+        let seq: Sequence = self
+            .ibc_handler_proxy(upper_module)
+            .send_packet(
+                src_port.clone(),
+                src_channel.clone(),
+                timeout_height,
+                timeout_timestamp,
+                data,
+            )
+            .execute_on_dest_context();
+
+        if self.fee_enabled(&src_port, &src_channel).get() {
+            self.packets_in_flight(&src_port, &src_channel).insert(seq);
+        }
+
+        seq
+    }
+
+    /// Writes the asynchronous acknowledgement of a packet, wrapped on fee enabled channels with the counterparty payee
+    /// of the relayer which delivered it
+    #[endpoint(writeAcknowledgement)]
+    fn write_ack(
+        &self,
+        dest_port: PortId<Self::Api>,
+        dest_channel: ChannelId<Self::Api>,
+        seq: Sequence,
+        ack: ManagedBuffer,
+    ) {
+        let upper_module = self.require_lower_module_caller(&dest_port, &dest_channel);
+        let ack = if self.fee_enabled(&dest_port, &dest_channel).get() {
+            let relayer_mapper = self.async_ack_relayers(&dest_port, &dest_channel, seq);
+            require!(
+                !relayer_mapper.is_empty(),
+                "No relayer stored for this acknowledgement"
+            );
+
+            let relayer = relayer_mapper.take();
+            self.wrap_ack(&dest_port, &dest_channel, &relayer, ack)
+        } else {
+            ack
+        };
+
+        self.ibc_handler_proxy(upper_module)
+            .write_ack(dest_port, dest_channel, seq, ack)
+            .execute_on_dest_context::<()>();
+    }
+
+    fn wrap_ack(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+        relayer: &ManagedAddress,
+        app_ack: ManagedBuffer,
+    ) -> ManagedBuffer {
+        let counterparty_payee_mapper = self.counterparty_payees(port_id, channel_id, relayer);
+        let forward_relayer_address = if counterparty_payee_mapper.is_empty() {
+            ManagedBuffer::new()
+        } else {
+            counterparty_payee_mapper.get()
+        };
+        let underlying_app_success = acknowledgement::is_successful(&app_ack) != Some(false);
+
+        IncentivizedAcknowledgement {
+            app_acknowledgement: app_ack,
+            forward_relayer_address,
+            underlying_app_success,
+        }
+        .to_json()
+    }
+}
@@ -0,0 +1,30 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod events;
+pub mod fees;
+pub mod ibc_module;
+pub mod ics4_wrapper;
+pub mod metadata;
+
+/// ICS-29 fee middleware, wrapping an IBC app to let users pay relayers for delivering its packets
+///
+/// The middleware is added to the stack of the port of the app in the IBC handler. Fees are only escrowed and paid on
+/// channels whose both ends negotiated the `ics29-1` version wrapper.
+#[multiversx_sc::contract]
+pub trait FeeMiddleware:
+    fees::FeesModule
+    + ibc_module::IbcModule
+    + ics4_wrapper::Ics4WrapperModule
+    + ::channel::middleware::ibc_middleware::IbcMiddlewareModule
+    + events::EventsModule
+{
+    #[init]
+    fn init(&self, ibc_handler: ManagedAddress) {
+        self.ibc_handler().set(ibc_handler);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+}
@@ -0,0 +1,49 @@
+use common_types::{
+    base64,
+    json::{JsonObject, JsonObjectBuilder},
+};
+
+multiversx_sc::imports!();
+
+/// The key of the fee version in the channel version, e.g. `{"fee_version":"ics29-1","app_version":"ics20-1"}`
+pub static FEE_VERSION_KEY: &[u8] = b"fee_version";
+pub static ICS29_VERSION: &[u8] = b"ics29-1";
+
+/// The acknowledgement written on fee enabled channels, wrapping the acknowledgement of the underlying app
+///
+/// `forward_relayer_address` is the counterparty payee of the relayer which delivered the packet, to which the
+/// receive fee is paid on the sending chain.
+pub struct IncentivizedAcknowledgement<M: ManagedTypeApi> {
+    pub app_acknowledgement: ManagedBuffer<M>,
+    pub forward_relayer_address: ManagedBuffer<M>,
+    pub underlying_app_success: bool,
+}
+
+impl<M: ManagedTypeApi> IncentivizedAcknowledgement<M> {
+    pub fn to_json(&self) -> ManagedBuffer<M> {
+        let success: &[u8] = if self.underlying_app_success {
+            b"true"
+        } else {
+            b"false"
+        };
+
+        JsonObjectBuilder::new()
+            .string(
+                b"app_acknowledgement",
+                &base64::encode(&self.app_acknowledgement),
+            )
+            .string(b"forward_relayer_address", &self.forward_relayer_address)
+            .raw(b"underlying_app_success", &ManagedBuffer::from(success))
+            .build()
+    }
+
+    pub fn from_json(encoded: &ManagedBuffer<M>) -> Option<Self> {
+        let object = JsonObject::parse(encoded)?;
+
+        Some(IncentivizedAcknowledgement {
+            app_acknowledgement: base64::decode(&object.get_string(b"app_acknowledgement")?)?,
+            forward_relayer_address: object.get_string(b"forward_relayer_address")?,
+            underlying_app_success: object.get_bool(b"underlying_app_success")?,
+        })
+    }
+}
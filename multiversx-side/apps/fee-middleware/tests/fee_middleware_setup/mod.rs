@@ -0,0 +1,554 @@
+#![allow(dead_code)]
+
+use std::{cell::RefCell, rc::Rc};
+
+use ::channel::{
+    channel_libs::packet_types::Packet,
+    interfaces::ibc_module_interface::{MsgOnChanOpenInit, MsgOnChanOpenTry},
+};
+use client_common::ClientStatus;
+use common_types::{
+    channel_types::{channel, channel_counterparty, height, upgrade},
+    connection_types::{connection_end, counterparty, merkle_prefix, version},
+    ConnectionHops, FeatureVec, VersionVec,
+};
+use fee_middleware::{
+    fees::FeesModule, ibc_module::IbcModule, ics4_wrapper::Ics4WrapperModule, FeeMiddleware,
+};
+use host::{
+    host_config::HostConfigModule,
+    module_manager::ModuleManagerModule,
+    storage::{ChannelInfo, ClientInfo, RecvStartSequence, StorageModule},
+};
+use ibc_handler::IbcHandler;
+use mock::{
+    client_logic::ClientLogicModule,
+    mock_types::{client_state, consensus_state},
+    Mock,
+};
+use mock_app::MockApp;
+use multiversx_sc::types::{Address, MultiValueEncoded};
+use multiversx_sc_scenario::{
+    imports::{BlockchainStateWrapper, ContractObjWrapper},
+    managed_address, managed_biguint, managed_buffer, rust_biguint, DebugApi,
+};
+
+pub static CLIENT_TYPE: &[u8] = b"mock-client";
+pub static CLIENT_ID: &[u8] = b"mock-client-0";
+pub static CONNECTION_ID: &[u8] = b"connection-0";
+pub static COUNTERPARTY_CLIENT_ID: &[u8] = b"counterparty-client-0";
+pub static COUNTERPARTY_CONNECTION_ID: &[u8] = b"connection-7";
+pub static PORT_ID: &[u8] = b"mockapp";
+pub static CHANNEL_ID: &[u8] = b"channel-0";
+pub static COUNTERPARTY_PORT_ID: &[u8] = b"counterparty-mockapp";
+pub static COUNTERPARTY_CHANNEL_ID: &[u8] = b"channel-5";
+pub static COMMITMENT_PREFIX: &[u8] = b"ibc";
+pub static FEE_TOKEN: &[u8] = b"FEE-123456";
+pub static COUNTERPARTY_PAYEE: &[u8] = b"cosmos1payee";
+
+/// The fee metadata of a channel of the mock app
+pub static FEE_VERSION: &[u8] = br#"{"fee_version":"ics29-1","app_version":"mockapp-1"}"#;
+
+pub const PROOF_HEIGHT: height::Data = height::Data {
+    revision_number: 0,
+    revision_height: 10,
+};
+pub const CONSENSUS_TIMESTAMP: u64 = 1_000_000_000;
+pub const TIMEOUT_TIMESTAMP: u64 = 1_000_000_000_000;
+pub const USER_BALANCE: u64 = 1_000;
+
+pub struct FeeMiddlewareSetup<
+    IbcHandlerObjBuilder,
+    MockClientObjBuilder,
+    FeeMiddlewareObjBuilder,
+    MockAppObjBuilder,
+> where
+    IbcHandlerObjBuilder: 'static + Copy + Fn() -> ibc_handler::ContractObj<DebugApi>,
+    MockClientObjBuilder: 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    FeeMiddlewareObjBuilder: 'static + Copy + Fn() -> fee_middleware::ContractObj<DebugApi>,
+    MockAppObjBuilder: 'static + Copy + Fn() -> mock_app::ContractObj<DebugApi>,
+{
+    pub b_mock: Rc<RefCell<BlockchainStateWrapper>>,
+    pub owner: Address,
+    pub user: Address,
+    pub relayer: Address,
+    pub payee: Address,
+    pub ibc_handler_wrapper:
+        ContractObjWrapper<ibc_handler::ContractObj<DebugApi>, IbcHandlerObjBuilder>,
+    pub mock_client_wrapper: ContractObjWrapper<mock::ContractObj<DebugApi>, MockClientObjBuilder>,
+    pub fee_middleware_wrapper:
+        ContractObjWrapper<fee_middleware::ContractObj<DebugApi>, FeeMiddlewareObjBuilder>,
+    pub mock_app_wrapper: ContractObjWrapper<mock_app::ContractObj<DebugApi>, MockAppObjBuilder>,
+}
+
+impl<IbcHandlerObjBuilder, MockClientObjBuilder, FeeMiddlewareObjBuilder, MockAppObjBuilder>
+    FeeMiddlewareSetup<
+        IbcHandlerObjBuilder,
+        MockClientObjBuilder,
+        FeeMiddlewareObjBuilder,
+        MockAppObjBuilder,
+    >
+where
+    IbcHandlerObjBuilder: 'static + Copy + Fn() -> ibc_handler::ContractObj<DebugApi>,
+    MockClientObjBuilder: 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    FeeMiddlewareObjBuilder: 'static + Copy + Fn() -> fee_middleware::ContractObj<DebugApi>,
+    MockAppObjBuilder: 'static + Copy + Fn() -> mock_app::ContractObj<DebugApi>,
+{
+    pub fn new(
+        b_mock: Rc<RefCell<BlockchainStateWrapper>>,
+        owner: &Address,
+        ibc_handler_builder: IbcHandlerObjBuilder,
+        mock_client_builder: MockClientObjBuilder,
+        fee_middleware_builder: FeeMiddlewareObjBuilder,
+        mock_app_builder: MockAppObjBuilder,
+    ) -> Self {
+        let rust_zero = rust_biguint!(0u64);
+        let user = b_mock.borrow_mut().create_user_account(&rust_zero);
+        let relayer = b_mock.borrow_mut().create_user_account(&rust_zero);
+        let payee = b_mock.borrow_mut().create_user_account(&rust_zero);
+        b_mock
+            .borrow_mut()
+            .set_esdt_balance(&user, FEE_TOKEN, &rust_biguint!(USER_BALANCE));
+
+        let ibc_handler_wrapper = b_mock.borrow_mut().create_sc_account(
+            &rust_zero,
+            Some(owner),
+            ibc_handler_builder,
+            "ibc handler",
+        );
+        let mock_client_wrapper = b_mock.borrow_mut().create_sc_account(
+            &rust_zero,
+            Some(owner),
+            mock_client_builder,
+            "mock client",
+        );
+        let fee_middleware_wrapper = b_mock.borrow_mut().create_sc_account(
+            &rust_zero,
+            Some(owner),
+            fee_middleware_builder,
+            "fee middleware",
+        );
+        let mock_app_wrapper = b_mock.borrow_mut().create_sc_account(
+            &rust_zero,
+            Some(owner),
+            mock_app_builder,
+            "mock app",
+        );
+
+        let ibc_handler_address = ibc_handler_wrapper.address_ref().clone();
+        let mock_client_address = mock_client_wrapper.address_ref().clone();
+        let fee_middleware_address = fee_middleware_wrapper.address_ref().clone();
+        let mock_app_address = mock_app_wrapper.address_ref().clone();
+
+        b_mock
+            .borrow_mut()
+            .execute_tx(owner, &mock_client_wrapper, &rust_zero, |sc| {
+                sc.init(managed_address!(&ibc_handler_address));
+
+                let client_id = managed_buffer!(CLIENT_ID);
+                sc.client_states(&client_id)
+                    .set(client_state::Data::new(PROOF_HEIGHT));
+                sc.consensus_states(&client_id, &PROOF_HEIGHT.to_biguint_concat())
+                    .set(consensus_state::Data::new(CONSENSUS_TIMESTAMP));
+                sc.statuses(&client_id).set(ClientStatus::Active);
+            })
+            .assert_ok();
+
+        b_mock
+            .borrow_mut()
+            .execute_tx(owner, &fee_middleware_wrapper, &rust_zero, |sc| {
+                sc.init(managed_address!(&ibc_handler_address));
+            })
+            .assert_ok();
+
+        b_mock
+            .borrow_mut()
+            .execute_tx(owner, &mock_app_wrapper, &rust_zero, |sc| {
+                sc.init(managed_address!(&fee_middleware_address));
+            })
+            .assert_ok();
+
+        b_mock
+            .borrow_mut()
+            .execute_tx(owner, &ibc_handler_wrapper, &rust_zero, |sc| {
+                sc.init();
+
+                sc.set_expected_time_per_block(6);
+                sc.register_client(
+                    managed_buffer!(CLIENT_TYPE),
+                    managed_address!(&mock_client_address),
+                );
+                sc.bind_port(
+                    managed_buffer!(PORT_ID),
+                    managed_address!(&mock_app_address),
+                );
+                let mut middlewares = MultiValueEncoded::new();
+                middlewares.push(managed_address!(&fee_middleware_address));
+                sc.set_port_middlewares(managed_buffer!(PORT_ID), middlewares);
+
+                sc.client_info(&managed_buffer!(CLIENT_ID)).set(ClientInfo {
+                    client_type: managed_buffer!(CLIENT_TYPE),
+                    client_impl: managed_address!(&mock_client_address),
+                });
+                sc.connection_info(&managed_buffer!(CONNECTION_ID))
+                    .set(open_connection());
+            })
+            .assert_ok();
+
+        Self {
+            b_mock,
+            owner: owner.clone(),
+            user,
+            relayer,
+            payee,
+            ibc_handler_wrapper,
+            mock_client_wrapper,
+            fee_middleware_wrapper,
+            mock_app_wrapper,
+        }
+    }
+
+    /// Gives `CHANNEL_ID` the stack of the port, as the handler does when a handshake starts
+    pub fn claim_channel(&self) {
+        self.b_mock
+            .borrow_mut()
+            .execute_tx(
+                &self.owner,
+                &self.ibc_handler_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    sc.claim_channel_stack(&managed_buffer!(PORT_ID), &managed_buffer!(CHANNEL_ID));
+                },
+            )
+            .assert_ok();
+    }
+
+    /// Opens `CHANNEL_ID` as if the handshake negotiated `version`, which enables fees if it is fee metadata
+    pub fn open_channel(&self, version: &[u8]) -> Vec<u8> {
+        self.claim_channel();
+
+        let mut negotiated_version = Vec::new();
+        let ibc_handler_address = self.ibc_handler_wrapper.address_ref().clone();
+        self.b_mock
+            .borrow_mut()
+            .execute_tx(
+                &ibc_handler_address,
+                &self.fee_middleware_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    negotiated_version = sc
+                        .on_chan_open_try(open_try_args(version))
+                        .to_boxed_bytes()
+                        .into_vec();
+                },
+            )
+            .assert_ok();
+
+        let version = negotiated_version.clone();
+        self.b_mock
+            .borrow_mut()
+            .execute_tx(
+                &self.owner,
+                &self.ibc_handler_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    let port_id = managed_buffer!(PORT_ID);
+                    let channel_id = managed_buffer!(CHANNEL_ID);
+                    sc.channel_info(&port_id, &channel_id).set(ChannelInfo {
+                        channel: channel::Data {
+                            state: channel::State::Open,
+                            ordering: channel::Order::Unordered,
+                            counterparty: channel_counterparty::Data {
+                                port_id: managed_buffer!(COUNTERPARTY_PORT_ID),
+                                channel_id: managed_buffer!(COUNTERPARTY_CHANNEL_ID),
+                            },
+                            connection_hops: ConnectionHops::from_single_item(managed_buffer!(
+                                CONNECTION_ID
+                            )),
+                            version: managed_buffer!(&version),
+                            upgrade_sequence: 0,
+                        },
+                        next_seq_send: 1,
+                        next_seq_recv: 1,
+                        next_seq_ack: 1,
+                        upgrade: upgrade::Data::default(),
+                        latest_error_rec_seq: 0,
+                        recv_start_seq: RecvStartSequence {
+                            seq: 0,
+                            prev_seq: 0,
+                        },
+                        ack_start_seq: 0,
+                    });
+                },
+            )
+            .assert_ok();
+
+        negotiated_version
+    }
+
+    /// Sends a packet on `CHANNEL_ID` as the mock app, returning its sequence
+    pub fn send_packet(&self) -> u64 {
+        let mut seq = 0;
+        let mock_app_address = self.mock_app_wrapper.address_ref().clone();
+        self.b_mock
+            .borrow_mut()
+            .execute_tx(
+                &mock_app_address,
+                &self.fee_middleware_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    seq = sc.send_packet(
+                        managed_buffer!(PORT_ID),
+                        managed_buffer!(CHANNEL_ID),
+                        height::Data::default(),
+                        TIMEOUT_TIMESTAMP,
+                        managed_buffer!(b"data"),
+                    );
+                },
+            )
+            .assert_ok();
+
+        seq
+    }
+
+    /// Pays fees for a packet as the user
+    pub fn pay_packet_fee(&self, seq: u64, recv_fee: u64, ack_fee: u64, timeout_fee: u64) {
+        self.b_mock
+            .borrow_mut()
+            .execute_esdt_transfer(
+                &self.user,
+                &self.fee_middleware_wrapper,
+                FEE_TOKEN,
+                0,
+                &rust_biguint!(recv_fee + ack_fee + timeout_fee),
+                |sc| {
+                    sc.pay_packet_fee(
+                        managed_buffer!(PORT_ID),
+                        managed_buffer!(CHANNEL_ID),
+                        seq,
+                        managed_biguint!(recv_fee),
+                        managed_biguint!(ack_fee),
+                        managed_biguint!(timeout_fee),
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
+    /// Makes the acknowledgement and timeout fees relayed by the relayer go to the payee
+    pub fn register_payee(&self) {
+        let payee = self.payee.clone();
+        self.b_mock
+            .borrow_mut()
+            .execute_tx(
+                &self.relayer,
+                &self.fee_middleware_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    sc.register_payee(
+                        managed_buffer!(PORT_ID),
+                        managed_buffer!(CHANNEL_ID),
+                        managed_address!(&payee),
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn register_counterparty_payee(&self) {
+        self.b_mock
+            .borrow_mut()
+            .execute_tx(
+                &self.relayer,
+                &self.fee_middleware_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    sc.register_counterparty_payee(
+                        managed_buffer!(PORT_ID),
+                        managed_buffer!(CHANNEL_ID),
+                        managed_buffer!(COUNTERPARTY_PAYEE),
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
+    /// Delivers the acknowledgement of a packet sent on `CHANNEL_ID`, as the handler does for the relayer
+    pub fn ack_packet(&self, seq: u64, ack: &[u8]) {
+        let ibc_handler_address = self.ibc_handler_wrapper.address_ref().clone();
+        let relayer = self.relayer.clone();
+        self.b_mock
+            .borrow_mut()
+            .execute_tx(
+                &ibc_handler_address,
+                &self.fee_middleware_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    sc.on_ack_packet(
+                        sent_packet(seq),
+                        managed_buffer!(ack),
+                        managed_address!(&relayer),
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn timeout_packet(&self, seq: u64) {
+        let ibc_handler_address = self.ibc_handler_wrapper.address_ref().clone();
+        let relayer = self.relayer.clone();
+        self.b_mock
+            .borrow_mut()
+            .execute_tx(
+                &ibc_handler_address,
+                &self.fee_middleware_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    sc.on_timeout_packet(sent_packet(seq), managed_address!(&relayer));
+                },
+            )
+            .assert_ok();
+    }
+
+    /// Delivers a packet of the counterparty to `CHANNEL_ID`, returning the acknowledgement
+    pub fn recv_packet(&self, seq: u64, data: &[u8]) -> Vec<u8> {
+        let mut ack = Vec::new();
+        let ibc_handler_address = self.ibc_handler_wrapper.address_ref().clone();
+        let relayer = self.relayer.clone();
+        self.b_mock
+            .borrow_mut()
+            .execute_tx(
+                &ibc_handler_address,
+                &self.fee_middleware_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    ack = sc
+                        .on_recv_packet(received_packet(seq, data), managed_address!(&relayer))
+                        .to_boxed_bytes()
+                        .into_vec();
+                },
+            )
+            .assert_ok();
+
+        if ack.is_empty() {
+            self.set_pending_ack(seq);
+        }
+
+        ack
+    }
+
+    /// Marks a received packet as awaiting its asynchronous acknowledgement, as the handler does when the stack
+    /// returns an empty one
+    pub fn set_pending_ack(&self, seq: u64) {
+        self.b_mock
+            .borrow_mut()
+            .execute_tx(
+                &self.owner,
+                &self.ibc_handler_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    sc.pending_acks(&managed_buffer!(PORT_ID), &managed_buffer!(CHANNEL_ID))
+                        .insert(seq);
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn check_fee_balance(&self, address: &Address, expected: u64) {
+        self.b_mock
+            .borrow()
+            .check_esdt_balance(address, FEE_TOKEN, &rust_biguint!(expected));
+    }
+}
+
+/// `IncentivizedAcknowledgement`, as ibc-go encodes it
+pub fn incentivized_ack(
+    base64_app_ack: &str,
+    forward_relayer_address: &str,
+    underlying_app_success: bool,
+) -> Vec<u8> {
+    format!(
+        r#"{{"app_acknowledgement":"{}","forward_relayer_address":"{}","underlying_app_success":{}}}"#,
+        base64_app_ack, forward_relayer_address, underlying_app_success
+    )
+    .into_bytes()
+}
+
+pub fn open_init_args(version: &[u8]) -> MsgOnChanOpenInit<DebugApi> {
+    MsgOnChanOpenInit {
+        order: channel::Order::Unordered,
+        connection_hops: ConnectionHops::from_single_item(managed_buffer!(CONNECTION_ID)),
+        port_id: managed_buffer!(PORT_ID),
+        channel_id: managed_buffer!(CHANNEL_ID),
+        counterparty: channel_counterparty::Data {
+            port_id: managed_buffer!(COUNTERPARTY_PORT_ID),
+            channel_id: managed_buffer!(b""),
+        },
+        version: managed_buffer!(version),
+    }
+}
+
+pub fn open_try_args(counterparty_version: &[u8]) -> MsgOnChanOpenTry<DebugApi> {
+    MsgOnChanOpenTry {
+        order: channel::Order::Unordered,
+        connection_hops: ConnectionHops::from_single_item(managed_buffer!(CONNECTION_ID)),
+        port_id: managed_buffer!(PORT_ID),
+        channel_id: managed_buffer!(CHANNEL_ID),
+        counterparty: channel_counterparty::Data {
+            port_id: managed_buffer!(COUNTERPARTY_PORT_ID),
+            channel_id: managed_buffer!(COUNTERPARTY_CHANNEL_ID),
+        },
+        counterparty_version: managed_buffer!(counterparty_version),
+    }
+}
+
+/// A packet the mock app sent on `CHANNEL_ID`
+pub fn sent_packet(seq: u64) -> Packet<DebugApi> {
+    Packet {
+        seq,
+        src_port: managed_buffer!(PORT_ID),
+        src_channel: managed_buffer!(CHANNEL_ID),
+        dest_port: managed_buffer!(COUNTERPARTY_PORT_ID),
+        dest_channel: managed_buffer!(COUNTERPARTY_CHANNEL_ID),
+        data: managed_buffer!(b"data"),
+        timeout_height: height::Data::default(),
+        timeout_timestamp: TIMEOUT_TIMESTAMP,
+    }
+}
+
+/// A packet the counterparty sent to `CHANNEL_ID`
+pub fn received_packet(seq: u64, data: &[u8]) -> Packet<DebugApi> {
+    Packet {
+        seq,
+        src_port: managed_buffer!(COUNTERPARTY_PORT_ID),
+        src_channel: managed_buffer!(COUNTERPARTY_CHANNEL_ID),
+        dest_port: managed_buffer!(PORT_ID),
+        dest_channel: managed_buffer!(CHANNEL_ID),
+        data: managed_buffer!(data),
+        timeout_height: height::Data::default(),
+        timeout_timestamp: TIMEOUT_TIMESTAMP,
+    }
+}
+
+pub fn open_connection() -> connection_end::Data<DebugApi> {
+    let mut features = FeatureVec::new();
+    features.push(managed_buffer!(channel::ORDERED));
+    features.push(managed_buffer!(channel::UNORDERED));
+
+    connection_end::Data {
+        client_id: managed_buffer!(CLIENT_ID),
+        versions: VersionVec::from_single_item(version::Data {
+            identifier: managed_buffer!(b"1"),
+            features,
+        }),
+        state: connection_end::State::Open,
+        counterparty: counterparty::Data {
+            client_id: managed_buffer!(COUNTERPARTY_CLIENT_ID),
+            connection_id: managed_buffer!(COUNTERPARTY_CONNECTION_ID),
+            prefix: merkle_prefix::Data {
+                key_prefix: managed_buffer!(COMMITMENT_PREFIX),
+            },
+        },
+        delay_period: 0,
+    }
+}
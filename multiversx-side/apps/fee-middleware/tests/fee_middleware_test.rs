@@ -0,0 +1,544 @@
+use std::{cell::RefCell, rc::Rc};
+
+use ::channel::{
+    channel_libs::handshake_types::MsgChannelOpenInit,
+    handshake::chan_open::ChannelOpenModule,
+    interfaces::ibc_module_interface::{MsgOnChanCloseInit, MsgOnChanOpenAck},
+};
+use common_types::{
+    channel_types::{channel, channel_counterparty},
+    ConnectionHops,
+};
+use fee_middleware::{fees::FeesModule, ibc_module::IbcModule, ics4_wrapper::Ics4WrapperModule};
+use fee_middleware_setup::*;
+use host::module_manager::ModuleManagerModule;
+use mock_app::{MockApp, MOCK_APP_VERSION};
+use multiversx_sc_scenario::{
+    imports::BlockchainStateWrapper, managed_address, managed_biguint, managed_buffer,
+    rust_biguint, DebugApi,
+};
+use test_utils::bech32_address;
+
+pub mod fee_middleware_setup;
+
+/// base64 of the acknowledgement of the mock app
+static MOCK_APP_BASE64_ACK: &str = "bW9jayBhY2tub3dsZWRnZW1lbnQ=";
+
+type Setup = FeeMiddlewareSetup<
+    fn() -> ibc_handler::ContractObj<DebugApi>,
+    fn() -> mock::ContractObj<DebugApi>,
+    fn() -> fee_middleware::ContractObj<DebugApi>,
+    fn() -> mock_app::ContractObj<DebugApi>,
+>;
+
+fn get_fee_middleware_setup() -> Setup {
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_biguint!(0));
+    FeeMiddlewareSetup::new(
+        Rc::new(RefCell::new(b_mock)),
+        &owner,
+        ibc_handler::contract_obj,
+        mock::contract_obj,
+        fee_middleware::contract_obj,
+        mock_app::contract_obj,
+    )
+}
+
+fn check_fee_enabled(setup: &Setup, expected: bool) {
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.fee_middleware_wrapper, |sc| {
+            let enabled = sc
+                .fee_enabled(&managed_buffer!(PORT_ID), &managed_buffer!(CHANNEL_ID))
+                .get();
+            assert_eq!(enabled, expected);
+        })
+        .assert_ok();
+}
+
+/// Sends a packet on a fee enabled channel, for which the user pays 10, 20 and 30 tokens
+fn send_packet_with_fees(setup: &Setup) -> u64 {
+    setup.open_channel(FEE_VERSION);
+    let seq = setup.send_packet();
+    setup.pay_packet_fee(seq, 10, 20, 30);
+    setup.check_fee_balance(&setup.user, USER_BALANCE - 60);
+
+    seq
+}
+
+#[test]
+fn open_init_empty_version_proposes_fees_test() {
+    let setup = get_fee_middleware_setup();
+    setup.claim_channel();
+    let ibc_handler_address = setup.ibc_handler_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.fee_middleware_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let version = sc.on_chan_open_init(open_init_args(b""));
+                assert_eq!(version, managed_buffer!(FEE_VERSION));
+            },
+        )
+        .assert_ok();
+
+    check_fee_enabled(&setup, true);
+}
+
+#[test]
+fn channel_open_init_through_stack_test() {
+    let setup = get_fee_middleware_setup();
+    let user = setup.user.clone();
+    let fee_middleware_address = setup.fee_middleware_wrapper.address_ref().clone();
+    let mock_app_address = setup.mock_app_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(&user, &setup.ibc_handler_wrapper, &rust_biguint!(0), |sc| {
+            let (channel_id, version) = sc
+                .channel_open_init(MsgChannelOpenInit {
+                    port_id: managed_buffer!(PORT_ID),
+                    channel: channel::Data {
+                        state: channel::State::Init,
+                        ordering: channel::Order::Unordered,
+                        counterparty: channel_counterparty::Data {
+                            port_id: managed_buffer!(COUNTERPARTY_PORT_ID),
+                            channel_id: managed_buffer!(b""),
+                        },
+                        connection_hops: ConnectionHops::from_single_item(managed_buffer!(
+                            CONNECTION_ID
+                        )),
+                        version: managed_buffer!(b""),
+                        upgrade_sequence: 0,
+                    },
+                })
+                .into_tuple();
+            assert_eq!(channel_id, managed_buffer!(CHANNEL_ID));
+            assert_eq!(version, managed_buffer!(FEE_VERSION));
+
+            assert_eq!(
+                sc.lookup_module_by_channel(&managed_buffer!(PORT_ID), &channel_id),
+                managed_address!(&fee_middleware_address)
+            );
+            let stack = sc
+                .get_channel_stack(managed_buffer!(PORT_ID), channel_id)
+                .to_vec();
+            assert_eq!(stack.len(), 2);
+            assert_eq!(*stack.get(0), managed_address!(&fee_middleware_address));
+            assert_eq!(*stack.get(1), managed_address!(&mock_app_address));
+        })
+        .assert_ok();
+
+    check_fee_enabled(&setup, true);
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.mock_app_wrapper, |sc| {
+            let callbacks = sc.callbacks(&managed_buffer!(PORT_ID), &managed_buffer!(CHANNEL_ID));
+            assert_eq!(callbacks.get(1), managed_buffer!(b"onChanOpenInit"));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn try_callback_not_upper_module_test() {
+    let setup = get_fee_middleware_setup();
+    setup.open_channel(FEE_VERSION);
+
+    let user = setup.user.clone();
+    let relayer = setup.relayer.clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &user,
+            &setup.fee_middleware_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.on_recv_packet(received_packet(1, b"data"), managed_address!(&relayer));
+            },
+        )
+        .assert_user_error("Only the module above in the stack may call this endpoint");
+}
+
+#[test]
+fn open_init_app_version_passes_through_test() {
+    let setup = get_fee_middleware_setup();
+    setup.claim_channel();
+    let ibc_handler_address = setup.ibc_handler_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.fee_middleware_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let version = sc.on_chan_open_init(open_init_args(MOCK_APP_VERSION));
+                assert_eq!(version, managed_buffer!(MOCK_APP_VERSION));
+            },
+        )
+        .assert_ok();
+
+    check_fee_enabled(&setup, false);
+}
+
+#[test]
+fn open_try_fee_version_test() {
+    let setup = get_fee_middleware_setup();
+    let version = setup.open_channel(FEE_VERSION);
+    assert_eq!(version, FEE_VERSION.to_vec());
+
+    check_fee_enabled(&setup, true);
+}
+
+#[test]
+fn try_open_with_unsupported_fee_version_test() {
+    let setup = get_fee_middleware_setup();
+    setup.claim_channel();
+    let ibc_handler_address = setup.ibc_handler_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.fee_middleware_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.on_chan_open_try(open_try_args(
+                    br#"{"fee_version":"ics29-2","app_version":"mockapp-1"}"#,
+                ));
+            },
+        )
+        .assert_user_error("Unsupported middleware version");
+}
+
+#[test]
+fn try_open_ack_without_fee_metadata_test() {
+    let setup = get_fee_middleware_setup();
+    setup.claim_channel();
+    let ibc_handler_address = setup.ibc_handler_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.fee_middleware_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.on_chan_open_init(open_init_args(b""));
+                sc.on_chan_open_ack(MsgOnChanOpenAck {
+                    port_id: managed_buffer!(PORT_ID),
+                    channel_id: managed_buffer!(CHANNEL_ID),
+                    counterparty_version: managed_buffer!(MOCK_APP_VERSION),
+                });
+            },
+        )
+        .assert_user_error("Invalid counterparty middleware version");
+}
+
+#[test]
+fn recv_packet_wraps_ack_test() {
+    let setup = get_fee_middleware_setup();
+    setup.open_channel(FEE_VERSION);
+
+    let ack = setup.recv_packet(1, b"data");
+    assert_eq!(ack, incentivized_ack(MOCK_APP_BASE64_ACK, "", true));
+
+    setup.register_counterparty_payee();
+    let ack = setup.recv_packet(2, b"data");
+    assert_eq!(
+        ack,
+        incentivized_ack(
+            MOCK_APP_BASE64_ACK,
+            std::str::from_utf8(COUNTERPARTY_PAYEE).unwrap(),
+            true
+        )
+    );
+}
+
+#[test]
+fn recv_packet_without_fees_test() {
+    let setup = get_fee_middleware_setup();
+    setup.open_channel(MOCK_APP_VERSION);
+
+    let ack = setup.recv_packet(1, b"data");
+    assert_eq!(ack, mock_app::SUCCESSFUL_ACK.to_vec());
+}
+
+#[test]
+fn async_ack_test() {
+    let setup = get_fee_middleware_setup();
+    setup.open_channel(FEE_VERSION);
+
+    let ack = setup.recv_packet(1, mock_app::ASYNC_ACK_DATA);
+    assert!(ack.is_empty());
+
+    let mock_app_address = setup.mock_app_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &mock_app_address,
+            &setup.fee_middleware_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.write_ack(
+                    managed_buffer!(PORT_ID),
+                    managed_buffer!(CHANNEL_ID),
+                    1,
+                    managed_buffer!(mock_app::SUCCESSFUL_ACK),
+                );
+            },
+        )
+        .assert_ok();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &mock_app_address,
+            &setup.fee_middleware_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.write_ack(
+                    managed_buffer!(PORT_ID),
+                    managed_buffer!(CHANNEL_ID),
+                    1,
+                    managed_buffer!(mock_app::SUCCESSFUL_ACK),
+                );
+            },
+        )
+        .assert_user_error("No relayer stored for this acknowledgement");
+}
+
+#[test]
+fn try_write_ack_not_app_test() {
+    let setup = get_fee_middleware_setup();
+    setup.open_channel(FEE_VERSION);
+
+    let user = setup.user.clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &user,
+            &setup.fee_middleware_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.write_ack(
+                    managed_buffer!(PORT_ID),
+                    managed_buffer!(CHANNEL_ID),
+                    1,
+                    managed_buffer!(mock_app::SUCCESSFUL_ACK),
+                );
+            },
+        )
+        .assert_user_error("Only the module below in the stack may call this endpoint");
+}
+
+#[test]
+fn ack_distributes_fees_test() {
+    let setup = get_fee_middleware_setup();
+    let seq = send_packet_with_fees(&setup);
+    setup.register_payee();
+
+    let forward_relayer = setup
+        .b_mock
+        .borrow_mut()
+        .create_user_account(&rust_biguint!(0));
+    let forward_relayer_address = bech32_address(
+        &setup.b_mock,
+        &setup.fee_middleware_wrapper,
+        &forward_relayer,
+    );
+    setup.ack_packet(
+        seq,
+        &incentivized_ack(MOCK_APP_BASE64_ACK, &forward_relayer_address, true),
+    );
+
+    setup.check_fee_balance(&forward_relayer, 10);
+    setup.check_fee_balance(&setup.payee, 20);
+    setup.check_fee_balance(&setup.relayer, 0);
+    setup.check_fee_balance(&setup.user, USER_BALANCE - 30);
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.mock_app_wrapper, |sc| {
+            let callbacks = sc.callbacks(&managed_buffer!(PORT_ID), &managed_buffer!(CHANNEL_ID));
+            assert_eq!(
+                callbacks.get(callbacks.len()),
+                managed_buffer!(b"onAcknowledgementPacket")
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn ack_without_forward_relayer_refunds_recv_fee_test() {
+    let setup = get_fee_middleware_setup();
+    let seq = send_packet_with_fees(&setup);
+
+    setup.ack_packet(seq, &incentivized_ack(MOCK_APP_BASE64_ACK, "", true));
+
+    setup.check_fee_balance(&setup.relayer, 20);
+    setup.check_fee_balance(&setup.user, USER_BALANCE - 20);
+}
+
+#[test]
+fn try_ack_not_incentivized_test() {
+    let setup = get_fee_middleware_setup();
+    let seq = send_packet_with_fees(&setup);
+
+    let ibc_handler_address = setup.ibc_handler_wrapper.address_ref().clone();
+    let relayer = setup.relayer.clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.fee_middleware_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.on_ack_packet(
+                    sent_packet(seq),
+                    managed_buffer!(mock_app::SUCCESSFUL_ACK),
+                    managed_address!(&relayer),
+                );
+            },
+        )
+        .assert_user_error("Invalid incentivized acknowledgement");
+}
+
+#[test]
+fn timeout_distributes_fees_test() {
+    let setup = get_fee_middleware_setup();
+    let seq = send_packet_with_fees(&setup);
+
+    setup.timeout_packet(seq);
+
+    setup.check_fee_balance(&setup.relayer, 30);
+    setup.check_fee_balance(&setup.user, USER_BALANCE - 30);
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.fee_middleware_wrapper, |sc| {
+            let fees =
+                sc.get_packet_fees(managed_buffer!(PORT_ID), managed_buffer!(CHANNEL_ID), seq);
+            assert_eq!(fees.len(), 0);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn try_pay_packet_fee_invalid_test() {
+    let setup = get_fee_middleware_setup();
+    setup.open_channel(FEE_VERSION);
+    let seq = setup.send_packet();
+
+    let user = setup.user.clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_esdt_transfer(
+            &user,
+            &setup.fee_middleware_wrapper,
+            FEE_TOKEN,
+            0,
+            &rust_biguint!(50),
+            |sc| {
+                sc.pay_packet_fee(
+                    managed_buffer!(PORT_ID),
+                    managed_buffer!(CHANNEL_ID),
+                    seq,
+                    managed_biguint!(10),
+                    managed_biguint!(20),
+                    managed_biguint!(30),
+                );
+            },
+        )
+        .assert_user_error("Payment must be the sum of the fees");
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_esdt_transfer(
+            &user,
+            &setup.fee_middleware_wrapper,
+            FEE_TOKEN,
+            0,
+            &rust_biguint!(60),
+            |sc| {
+                sc.pay_packet_fee(
+                    managed_buffer!(PORT_ID),
+                    managed_buffer!(CHANNEL_ID),
+                    seq + 1,
+                    managed_biguint!(10),
+                    managed_biguint!(20),
+                    managed_biguint!(30),
+                );
+            },
+        )
+        .assert_user_error("Packet not in flight");
+}
+
+#[test]
+fn try_pay_packet_fee_fees_disabled_test() {
+    let setup = get_fee_middleware_setup();
+    setup.open_channel(MOCK_APP_VERSION);
+    let seq = setup.send_packet();
+
+    let user = setup.user.clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_esdt_transfer(
+            &user,
+            &setup.fee_middleware_wrapper,
+            FEE_TOKEN,
+            0,
+            &rust_biguint!(60),
+            |sc| {
+                sc.pay_packet_fee(
+                    managed_buffer!(PORT_ID),
+                    managed_buffer!(CHANNEL_ID),
+                    seq,
+                    managed_biguint!(10),
+                    managed_biguint!(20),
+                    managed_biguint!(30),
+                );
+            },
+        )
+        .assert_user_error("Fees are not enabled on this channel");
+}
+
+#[test]
+fn close_refunds_fees_test() {
+    let setup = get_fee_middleware_setup();
+    send_packet_with_fees(&setup);
+
+    let ibc_handler_address = setup.ibc_handler_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.fee_middleware_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.on_chan_close_init(MsgOnChanCloseInit {
+                    port_id: managed_buffer!(PORT_ID),
+                    channel_id: managed_buffer!(CHANNEL_ID),
+                });
+            },
+        )
+        .assert_ok();
+
+    setup.check_fee_balance(&setup.user, USER_BALANCE);
+}
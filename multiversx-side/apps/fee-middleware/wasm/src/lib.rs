@@ -0,0 +1,50 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           24
+// Async Callback (empty):               1
+// Total number of exported functions:  27
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    fee_middleware
+    (
+        init => init
+        upgrade => upgrade
+        payPacketFee => pay_packet_fee
+        registerPayee => register_payee
+        registerCounterpartyPayee => register_counterparty_payee
+        getPacketFees => get_packet_fees
+        getPayee => get_payee
+        getCounterpartyPayee => get_counterparty_payee
+        isFeeEnabled => fee_enabled
+        onChanOpenInit => on_chan_open_init
+        onChanOpenTry => on_chan_open_try
+        onChanOpenAck => on_chan_open_ack
+        onChanOpenConfirm => on_chan_open_confirm
+        onChanCloseInit => on_chan_close_init
+        onChanCloseConfirm => on_chan_close_confirm
+        onChanUpgradeInit => on_chan_upgrade_init
+        onChanUpgradeTry => on_chan_upgrade_try
+        onChanUpgradeAck => on_chan_upgrade_ack
+        onChanUpgradeOpen => on_chan_upgrade_open
+        canTransitionToFlushComplete => can_transition_to_flush_complete
+        onRecvPacket => on_recv_packet
+        onAcknowledgementPacket => on_ack_packet
+        onTimeoutPacket => on_timeout_packet
+        sendPacket => send_packet
+        writeAcknowledgement => write_ack
+        getIbcHandler => ibc_handler
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
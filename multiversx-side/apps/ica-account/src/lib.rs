@@ -0,0 +1,41 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+/// An interchain account, deployed by the interchain accounts host for every controller account of another chain
+///
+/// Holds the funds of its controller and makes the contract calls the host executes for it. Only the host, which
+/// owns the contract, may use it.
+#[multiversx_sc::contract]
+pub trait InterchainAccount {
+    #[init]
+    fn init(&self) {}
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// Calls `function` of `contract` from the account, paying `egld_value` from its balance
+    ///
+    /// Returns the results of the call.
+    #[only_owner]
+    #[endpoint]
+    fn execute(
+        &self,
+        contract: ManagedAddress,
+        function: ManagedBuffer,
+        egld_value: BigUint,
+        args: MultiValueEncoded<ManagedBuffer>,
+    ) -> MultiValueEncoded<ManagedBuffer> {
+        let results = self.send_raw().execute_on_dest_context_raw(
+            self.blockchain().get_gas_left(),
+            &contract,
+            &egld_value,
+            &function,
+            &ManagedArgBuffer::from(args.to_vec()),
+        );
+        // the results are returned once, instead of also being forwarded as the results of the nested call
+        self.send_raw().clean_return_data();
+
+        results.into()
+    }
+}
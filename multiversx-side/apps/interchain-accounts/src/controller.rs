@@ -0,0 +1,324 @@
+use ::channel::{
+    channel_libs::{handshake_types::MsgChannelOpenInit, packet_types::Packet},
+    interfaces::{
+        ibc_handler_interface::ibc_handler_proxy::ProxyTrait as _,
+        ibc_module_interface::{MsgOnChanOpenAck, MsgOnChanOpenInit},
+    },
+};
+use common_types::{
+    bech32,
+    channel_types::{
+        acknowledgement,
+        channel::{self, Order},
+        channel_counterparty, height,
+    },
+    connection_types::connection_end,
+    ChannelId, ConnectionId, PortId, Sequence, UnixTimestamp, Version,
+};
+
+use crate::{
+    host::HOST_PORT_ID,
+    metadata::Metadata,
+    packet_data::{self, AnyMessage, InterchainAccountPacketData},
+};
+
+multiversx_sc::imports!();
+
+pub static CONTROLLER_PORT_PREFIX: &[u8] = b"icacontroller-";
+
+const NANOS_PER_SECOND: UnixTimestamp = 1_000_000_000;
+
+static ACTIVE_CHANNEL_ERR_MSG: &[u8] = b"Interchain account channel already active";
+static INVALID_METADATA_ERR_MSG: &[u8] = b"Invalid metadata";
+
+/// The controller side of ICS-27: any account of this chain can own an interchain account on other chains
+///
+/// Every owner has its own port, "icacontroller-{bech32 owner}", which this contract binds on the first registration.
+/// The owner of the IBC handler must have let it bind the ports of the `icacontroller-` prefix with `bindPortPrefix`.
+#[multiversx_sc::module]
+pub trait ControllerModule:
+    crate::ibc_handler::IbcHandlerModule + crate::events::EventsModule
+{
+    /// Opens an ordered channel to the interchain accounts host at the other end of `connection_id`, which creates
+    /// the interchain account of the caller on the first registration and reuses it afterwards
+    ///
+    /// Returns the channel ID. The address of the account is known once the handshake completes.
+    #[endpoint(registerInterchainAccount)]
+    fn register_interchain_account(
+        &self,
+        connection_id: ConnectionId<Self::Api>,
+    ) -> ChannelId<Self::Api> {
+        let owner = self.blockchain().get_caller();
+        let port_id = self.get_controller_port(owner.clone());
+        require!(
+            self.active_channels(&port_id, &connection_id).is_empty(),
+            ACTIVE_CHANNEL_ERR_MSG
+        );
+
+        let ibc_handler = self.ibc_handler().get();
+        let owner_mapper = self.controller_port_owners(&port_id);
+        if owner_mapper.is_empty() {
+            self.ibc_handler_proxy(ibc_handler.clone())
+                .bind_prefixed_port(port_id.clone(), PortId::from(CONTROLLER_PORT_PREFIX))
+                .execute_on_dest_context::<()>();
+            owner_mapper.set(&owner);
+        }
+
+        let connection: connection_end::Data<Self::Api> = self
+            .ibc_handler_proxy(ibc_handler.clone())
+            .get_connection(connection_id.clone())
+            .execute_on_dest_context();
+        let address_mapper = self.interchain_account_addresses(&port_id, &connection_id);
+        let address = if address_mapper.is_empty() {
+            ManagedBuffer::new()
+        } else {
+            address_mapper.get()
+        };
+        let metadata = Metadata::new(
+            connection_id.clone(),
+            connection.counterparty.connection_id,
+            address,
+        );
+
+        let mut connection_hops = ManagedVec::new();
+        connection_hops.push(connection_id.clone());
+        let (channel_id, _) = self
+            .ibc_handler_proxy(ibc_handler)
+            .channel_open_init(MsgChannelOpenInit {
+                port_id,
+                channel: channel::Data {
+                    state: channel::State::Init,
+                    ordering: Order::Ordered,
+                    counterparty: channel_counterparty::Data {
+                        port_id: PortId::from(HOST_PORT_ID),
+                        channel_id: ChannelId::new(),
+                    },
+                    connection_hops,
+                    version: metadata.to_json(),
+                    upgrade_sequence: 0,
+                },
+            })
+            .execute_on_dest_context::<MultiValue2<ChannelId<Self::Api>, Version<Self::Api>>>()
+            .into_tuple();
+
+        self.register_interchain_account_event(&owner, &connection_id, &channel_id);
+
+        channel_id
+    }
+
+    /// Sends `messages`, given as type URL and protobuf encoded value pairs, as a `CosmosTx` executed by the
+    /// interchain account of the caller at the other end of `connection_id`
+    ///
+    /// The packet times out `relative_timeout` nanoseconds after the current block. Returns the sequence of the packet.
+    #[endpoint(sendTx)]
+    fn send_tx(
+        &self,
+        connection_id: ConnectionId<Self::Api>,
+        relative_timeout: UnixTimestamp,
+        memo: ManagedBuffer,
+        messages: MultiValueEncoded<MultiValue2<ManagedBuffer, ManagedBuffer>>,
+    ) -> Sequence {
+        require!(!messages.is_empty(), "No messages");
+
+        let owner = self.blockchain().get_caller();
+        let port_id = self.get_controller_port(owner.clone());
+        let channel_mapper = self.active_channels(&port_id, &connection_id);
+        require!(
+            !channel_mapper.is_empty(),
+            "No active interchain account channel"
+        );
+
+        let timeout_timestamp = self
+            .blockchain()
+            .get_block_timestamp()
+            .checked_mul(NANOS_PER_SECOND)
+            .and_then(|now| now.checked_add(relative_timeout));
+        let timeout_timestamp = match timeout_timestamp {
+            Some(timeout_timestamp) if relative_timeout > 0 => timeout_timestamp,
+            _ => sc_panic!("Invalid timeout"),
+        };
+
+        let mut any_messages = ManagedVec::new();
+        for message in messages {
+            let (type_url, value) = message.into_tuple();
+            any_messages.push(AnyMessage { type_url, value });
+        }
+        let packet_data = InterchainAccountPacketData {
+            data: packet_data::encode_cosmos_tx(&any_messages),
+            memo,
+        }
+        .to_json();
+
+        let channel_id = channel_mapper.get();
+        let ibc_handler = self.ibc_handler().get();
+        let seq: Sequence = self
+            .ibc_handler_proxy(ibc_handler)
+            .send_packet(
+                port_id,
+                channel_id.clone(),
+                height::Data::default(),
+                timeout_timestamp,
+                packet_data.clone(),
+            )
+            .execute_on_dest_context();
+
+        self.send_tx_event(&channel_id, seq, &owner, &packet_data);
+
+        seq
+    }
+
+    fn controller_on_chan_open_init(
+        &self,
+        args: &MsgOnChanOpenInit<Self::Api>,
+    ) -> Version<Self::Api> {
+        require!(
+            matches!(args.order, Order::Ordered),
+            "Interchain account channels must be ordered"
+        );
+        require!(
+            &args.counterparty.port_id == HOST_PORT_ID,
+            "Invalid host port"
+        );
+
+        let connection_id = args.connection_hops.get(0).clone_value();
+        let metadata = match Metadata::from_json(&args.version) {
+            Some(metadata) => metadata,
+            None => sc_panic!(INVALID_METADATA_ERR_MSG),
+        };
+        require!(
+            metadata.controller_connection_id == connection_id,
+            INVALID_METADATA_ERR_MSG
+        );
+        require!(
+            self.active_channels(&args.port_id, &connection_id)
+                .is_empty(),
+            ACTIVE_CHANNEL_ERR_MSG
+        );
+
+        self.controller_channel_connections(&args.channel_id)
+            .set(&connection_id);
+
+        args.version.clone()
+    }
+
+    /// Activates the channel and records the address of the interchain account, chosen by the host
+    fn controller_on_chan_open_ack(&self, args: &MsgOnChanOpenAck<Self::Api>) {
+        let connection_id = self.controller_channel_connections(&args.channel_id).get();
+        let metadata = match Metadata::from_json(&args.counterparty_version) {
+            Some(metadata) => metadata,
+            None => sc_panic!(INVALID_METADATA_ERR_MSG),
+        };
+        require!(
+            metadata.controller_connection_id == connection_id && !metadata.address.is_empty(),
+            INVALID_METADATA_ERR_MSG
+        );
+
+        let address_mapper = self.interchain_account_addresses(&args.port_id, &connection_id);
+        require!(
+            address_mapper.is_empty() || address_mapper.get() == metadata.address,
+            "Interchain account address mismatch"
+        );
+
+        address_mapper.set(&metadata.address);
+        self.active_channels(&args.port_id, &connection_id)
+            .set(&args.channel_id);
+    }
+
+    fn controller_on_chan_close_confirm(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+    ) {
+        self.deactivate_channel(port_id, channel_id);
+    }
+
+    fn controller_on_ack_packet(&self, packet: &Packet<Self::Api>, ack: &ManagedBuffer) {
+        let success = match acknowledgement::is_successful(ack) {
+            Some(success) => success,
+            None => sc_panic!("Invalid acknowledgement"),
+        };
+
+        self.tx_acknowledged_event(&packet.src_channel, packet.seq, success, ack);
+    }
+
+    /// A timeout closes the ordered channel, which has to be registered again
+    fn controller_on_timeout_packet(&self, packet: &Packet<Self::Api>) {
+        self.deactivate_channel(&packet.src_port, &packet.src_channel);
+
+        self.tx_timeout_event(&packet.src_channel, packet.seq);
+    }
+
+    fn deactivate_channel(&self, port_id: &PortId<Self::Api>, channel_id: &ChannelId<Self::Api>) {
+        let connection_id = self.controller_channel_connections(channel_id).get();
+        let mapper = self.active_channels(port_id, &connection_id);
+        if !mapper.is_empty() && &mapper.get() == channel_id {
+            mapper.clear();
+        }
+    }
+
+    /// "icacontroller-{bech32 owner}"
+    #[view(getControllerPort)]
+    fn get_controller_port(&self, owner: ManagedAddress) -> PortId<Self::Api> {
+        let mut port_id = PortId::from(CONTROLLER_PORT_PREFIX);
+        port_id.append(&bech32::encode_address(&owner));
+
+        port_id
+    }
+
+    #[view(getActiveChannel)]
+    fn get_active_channel(
+        &self,
+        owner: ManagedAddress,
+        connection_id: ConnectionId<Self::Api>,
+    ) -> OptionalValue<ChannelId<Self::Api>> {
+        let mapper = self.active_channels(&self.get_controller_port(owner), &connection_id);
+        if mapper.is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(mapper.get())
+    }
+
+    /// The address of the interchain account of `owner` on the chain at the other end of `connection_id`, as
+    /// encoded by that chain
+    #[view(getInterchainAccountAddress)]
+    fn get_interchain_account_address(
+        &self,
+        owner: ManagedAddress,
+        connection_id: ConnectionId<Self::Api>,
+    ) -> OptionalValue<ManagedBuffer> {
+        let mapper =
+            self.interchain_account_addresses(&self.get_controller_port(owner), &connection_id);
+        if mapper.is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(mapper.get())
+    }
+
+    #[storage_mapper("controllerPortOwners")]
+    fn controller_port_owners(
+        &self,
+        port_id: &PortId<Self::Api>,
+    ) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("controllerChannelConnections")]
+    fn controller_channel_connections(
+        &self,
+        channel_id: &ChannelId<Self::Api>,
+    ) -> SingleValueMapper<ConnectionId<Self::Api>>;
+
+    #[storage_mapper("activeChannels")]
+    fn active_channels(
+        &self,
+        port_id: &PortId<Self::Api>,
+        connection_id: &ConnectionId<Self::Api>,
+    ) -> SingleValueMapper<ChannelId<Self::Api>>;
+
+    #[storage_mapper("interchainAccountAddresses")]
+    fn interchain_account_addresses(
+        &self,
+        port_id: &PortId<Self::Api>,
+        connection_id: &ConnectionId<Self::Api>,
+    ) -> SingleValueMapper<ManagedBuffer>;
+}
@@ -0,0 +1,56 @@
+use common_types::{ChannelId, ConnectionId, PortId, Sequence};
+
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait EventsModule {
+    #[event("registerInterchainAccountEvent")]
+    fn register_interchain_account_event(
+        &self,
+        #[indexed] owner: &ManagedAddress,
+        #[indexed] connection_id: &ConnectionId<Self::Api>,
+        #[indexed] channel_id: &ChannelId<Self::Api>,
+    );
+
+    #[event("sendTxEvent")]
+    fn send_tx_event(
+        &self,
+        #[indexed] source_channel: &ChannelId<Self::Api>,
+        #[indexed] seq: Sequence,
+        #[indexed] owner: &ManagedAddress,
+        packet_data: &ManagedBuffer,
+    );
+
+    #[event("txAcknowledgedEvent")]
+    fn tx_acknowledged_event(
+        &self,
+        #[indexed] source_channel: &ChannelId<Self::Api>,
+        #[indexed] seq: Sequence,
+        #[indexed] success: bool,
+        ack: &ManagedBuffer,
+    );
+
+    #[event("txTimeoutEvent")]
+    fn tx_timeout_event(
+        &self,
+        #[indexed] source_channel: &ChannelId<Self::Api>,
+        #[indexed] seq: Sequence,
+    );
+
+    #[event("interchainAccountCreatedEvent")]
+    fn interchain_account_created_event(
+        &self,
+        #[indexed] connection_id: &ConnectionId<Self::Api>,
+        #[indexed] controller_port: &PortId<Self::Api>,
+        #[indexed] account: &ManagedAddress,
+    );
+
+    #[event("executeTxEvent")]
+    fn execute_tx_event(
+        &self,
+        #[indexed] dest_channel: &ChannelId<Self::Api>,
+        #[indexed] seq: Sequence,
+        #[indexed] success: bool,
+        ack: &ManagedBuffer,
+    );
+}
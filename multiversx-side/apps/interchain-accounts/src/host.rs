@@ -0,0 +1,284 @@
+use channel::{
+    channel_libs::packet_types::Packet, interfaces::ibc_module_interface::MsgOnChanOpenTry,
+};
+use common_types::{
+    base64, bech32,
+    channel_types::{acknowledgement, channel::Order},
+    ChannelId, ConnectionId, PortId, Version,
+};
+
+use crate::{
+    controller::CONTROLLER_PORT_PREFIX,
+    metadata::Metadata,
+    packet_data::{self, InterchainAccountPacketData, MsgExecuteContract},
+};
+
+multiversx_sc::imports!();
+
+pub static HOST_PORT_ID: &[u8] = b"icahost";
+
+static INVALID_METADATA_ERR_MSG: &[u8] = b"Invalid metadata";
+static INVALID_PACKET_DATA_ERR_MSG: &[u8] = b"Invalid packet data";
+static UNSUPPORTED_MESSAGE_ERR_MSG: &[u8] = b"Unsupported message";
+static CALL_NOT_ALLOWED_ERR_MSG: &[u8] = b"Call not allowed";
+static UNKNOWN_CONTRACT_ERR_MSG: &[u8] = b"Unknown contract";
+static INSUFFICIENT_FUNDS_ERR_MSG: &[u8] = b"Insufficient funds";
+
+pub mod interchain_account_proxy {
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::proxy]
+    pub trait InterchainAccountProxy {
+        #[endpoint]
+        fn execute(
+            &self,
+            contract: ManagedAddress,
+            function: ManagedBuffer,
+            egld_value: BigUint,
+            args: MultiValueEncoded<ManagedBuffer>,
+        ) -> MultiValueEncoded<ManagedBuffer>;
+    }
+}
+
+/// The host side of ICS-27, bound to the `icahost` port of the IBC handler
+///
+/// Every controller port of another chain gets its own interchain account contract, deployed from the account
+/// template on the first channel. Received transactions may only call the contract functions allowed by the owner.
+/// Transactions with messages that can't be decoded or aren't allowed are answered with an error acknowledgement.
+/// The failure of a synchronous call can't be caught, so it fails the whole packet, which then times out and closes the
+/// channel. The failures that can be detected beforehand, i.e. calls to accounts which aren't contracts or paying
+/// more than the balance of the account, are answered with an error acknowledgement instead.
+#[multiversx_sc::module]
+pub trait HostModule: crate::ibc_handler::IbcHandlerModule + crate::events::EventsModule {
+    #[only_owner]
+    #[endpoint(allowCalls)]
+    fn allow_calls(&self, contract: ManagedAddress, functions: MultiValueEncoded<ManagedBuffer>) {
+        let mut mapper = self.allowed_calls(&contract);
+        for function in functions {
+            let _ = mapper.insert(function);
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(disallowCalls)]
+    fn disallow_calls(
+        &self,
+        contract: ManagedAddress,
+        functions: MultiValueEncoded<ManagedBuffer>,
+    ) {
+        let mut mapper = self.allowed_calls(&contract);
+        for function in functions {
+            let _ = mapper.swap_remove(&function);
+        }
+    }
+
+    #[view(isCallAllowed)]
+    fn is_call_allowed(&self, contract: ManagedAddress, function: ManagedBuffer) -> bool {
+        self.allowed_calls(&contract).contains(&function)
+    }
+
+    /// The interchain account of `controller_port` of the chain at the other end of `connection_id`
+    #[view(getInterchainAccount)]
+    fn get_interchain_account(
+        &self,
+        connection_id: ConnectionId<Self::Api>,
+        controller_port: PortId<Self::Api>,
+    ) -> OptionalValue<ManagedAddress> {
+        let mapper = self.interchain_accounts(&connection_id, &controller_port);
+        if mapper.is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(mapper.get())
+    }
+
+    /// Deploys the interchain account of the controller port, unless it already has one, and returns the metadata
+    /// with its address
+    fn host_on_chan_open_try(&self, args: &MsgOnChanOpenTry<Self::Api>) -> Version<Self::Api> {
+        require!(
+            matches!(args.order, Order::Ordered),
+            "Interchain account channels must be ordered"
+        );
+
+        let controller_port = &args.counterparty.port_id;
+        let prefix_len = CONTROLLER_PORT_PREFIX.len();
+        require!(
+            controller_port.len() > prefix_len
+                && controller_port.copy_slice(0, prefix_len)
+                    == Some(ManagedBuffer::from(CONTROLLER_PORT_PREFIX)),
+            "Invalid controller port"
+        );
+
+        let connection_id = args.connection_hops.get(0).clone_value();
+        let metadata = match Metadata::from_json(&args.counterparty_version) {
+            Some(metadata) => metadata,
+            None => sc_panic!(INVALID_METADATA_ERR_MSG),
+        };
+        require!(
+            metadata.host_connection_id == connection_id,
+            INVALID_METADATA_ERR_MSG
+        );
+
+        let active_channel_mapper = self.active_host_channels(&connection_id, controller_port);
+        require!(
+            active_channel_mapper.is_empty(),
+            "Interchain account channel already active"
+        );
+
+        let account_mapper = self.interchain_accounts(&connection_id, controller_port);
+        let account = if account_mapper.is_empty() {
+            let account = self.deploy_interchain_account();
+            account_mapper.set(&account);
+            self.interchain_account_created_event(&connection_id, controller_port, &account);
+
+            account
+        } else {
+            account_mapper.get()
+        };
+        let address = bech32::encode_address(&account);
+        require!(
+            metadata.address.is_empty() || metadata.address == address,
+            "Interchain account address mismatch"
+        );
+
+        active_channel_mapper.set(&args.channel_id);
+        self.host_channels(&args.channel_id)
+            .set((connection_id, controller_port.clone()));
+
+        Metadata::new(
+            metadata.controller_connection_id,
+            metadata.host_connection_id,
+            address,
+        )
+        .to_json()
+    }
+
+    fn host_on_chan_close_confirm(&self, channel_id: &ChannelId<Self::Api>) {
+        let (connection_id, controller_port) = self.host_channels(channel_id).get();
+        self.active_host_channels(&connection_id, &controller_port)
+            .clear();
+    }
+
+    /// Executes the calls of the transaction from the interchain account of the channel, returning the acknowledgement
+    ///
+    /// The result of a successful transaction is a `TxMsgData`, with the results of every call.
+    fn execute_tx(&self, packet: &Packet<Self::Api>) -> ManagedBuffer {
+        let (connection_id, controller_port) = self.host_channels(&packet.dest_channel).get();
+        let account = self
+            .interchain_accounts(&connection_id, &controller_port)
+            .get();
+
+        let ack = match self.decode_calls(&account, &packet.data) {
+            Ok(calls) => {
+                let mut responses = ManagedVec::new();
+                for call in &calls {
+                    let results: MultiValueEncoded<ManagedBuffer> = self
+                        .interchain_account_proxy(account.clone())
+                        .execute(
+                            call.contract,
+                            call.function,
+                            call.egld_value,
+                            MultiValueEncoded::from(call.args),
+                        )
+                        .execute_on_dest_context();
+                    responses.push(packet_data::encode_execute_contract_response(
+                        &results.to_vec(),
+                    ));
+                }
+
+                acknowledgement::new_result(&base64::encode(&packet_data::encode_tx_msg_data(
+                    &responses,
+                )))
+            }
+            Err(err_msg) => acknowledgement::new_error(err_msg),
+        };
+
+        self.execute_tx_event(
+            &packet.dest_channel,
+            packet.seq,
+            acknowledgement::is_successful(&ack) == Some(true),
+            &ack,
+        );
+
+        ack
+    }
+
+    /// Checks every message of the transaction before any of them is executed, since a failing call can't be caught
+    fn decode_calls(
+        &self,
+        account: &ManagedAddress,
+        data: &ManagedBuffer,
+    ) -> Result<ManagedVec<MsgExecuteContract<Self::Api>>, &'static [u8]> {
+        let packet_data =
+            InterchainAccountPacketData::from_json(data).ok_or(INVALID_PACKET_DATA_ERR_MSG)?;
+
+        let mut calls = ManagedVec::new();
+        let mut total_egld_value = BigUint::zero();
+        for message in &packet_data::decode_cosmos_tx(&packet_data.data) {
+            let call = MsgExecuteContract::from_any(&message).ok_or(UNSUPPORTED_MESSAGE_ERR_MSG)?;
+            if !self.allowed_calls(&call.contract).contains(&call.function) {
+                return Err(CALL_NOT_ALLOWED_ERR_MSG);
+            }
+            if !self.blockchain().is_smart_contract(&call.contract) {
+                return Err(UNKNOWN_CONTRACT_ERR_MSG);
+            }
+
+            total_egld_value += &call.egld_value;
+            calls.push(call);
+        }
+        if calls.is_empty() {
+            return Err(INVALID_PACKET_DATA_ERR_MSG);
+        }
+        if total_egld_value > 0 && total_egld_value > self.blockchain().get_balance(account) {
+            return Err(INSUFFICIENT_FUNDS_ERR_MSG);
+        }
+
+        Ok(calls)
+    }
+
+    fn deploy_interchain_account(&self) -> ManagedAddress {
+        let (account, _) = self.send_raw().deploy_from_source_contract(
+            self.blockchain().get_gas_left(),
+            &BigUint::zero(),
+            &self.account_template().get(),
+            CodeMetadata::PAYABLE | CodeMetadata::PAYABLE_BY_SC,
+            &ManagedArgBuffer::new(),
+        );
+
+        account
+    }
+
+    #[proxy]
+    fn interchain_account_proxy(
+        &self,
+        sc_address: ManagedAddress,
+    ) -> interchain_account_proxy::InterchainAccountProxy<Self::Api>;
+
+    /// The contract whose code is deployed for every interchain account
+    #[view(getAccountTemplate)]
+    #[storage_mapper("accountTemplate")]
+    fn account_template(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("allowedCalls")]
+    fn allowed_calls(&self, contract: &ManagedAddress) -> UnorderedSetMapper<ManagedBuffer>;
+
+    #[storage_mapper("interchainAccounts")]
+    fn interchain_accounts(
+        &self,
+        connection_id: &ConnectionId<Self::Api>,
+        controller_port: &PortId<Self::Api>,
+    ) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("activeHostChannels")]
+    fn active_host_channels(
+        &self,
+        connection_id: &ConnectionId<Self::Api>,
+        controller_port: &PortId<Self::Api>,
+    ) -> SingleValueMapper<ChannelId<Self::Api>>;
+
+    #[storage_mapper("hostChannels")]
+    fn host_channels(
+        &self,
+        channel_id: &ChannelId<Self::Api>,
+    ) -> SingleValueMapper<(ConnectionId<Self::Api>, PortId<Self::Api>)>;
+}
@@ -0,0 +1,24 @@
+use channel::interfaces::ibc_handler_interface::ibc_handler_proxy;
+
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait IbcHandlerModule {
+    fn require_ibc_handler_caller(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == self.ibc_handler().get(),
+            "Only the IBC handler may call this endpoint"
+        );
+    }
+
+    #[proxy]
+    fn ibc_handler_proxy(
+        &self,
+        sc_address: ManagedAddress,
+    ) -> ibc_handler_proxy::IbcHandlerProxy<Self::Api>;
+
+    #[view(getIbcHandler)]
+    #[storage_mapper("ibcHandler")]
+    fn ibc_handler(&self) -> SingleValueMapper<ManagedAddress>;
+}
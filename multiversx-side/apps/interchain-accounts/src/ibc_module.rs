@@ -0,0 +1,161 @@
+use channel::{
+    channel_libs::packet_types::Packet,
+    interfaces::ibc_module_interface::{
+        MsgOnChanCloseConfirm, MsgOnChanCloseInit, MsgOnChanOpenAck, MsgOnChanOpenConfirm,
+        MsgOnChanOpenInit, MsgOnChanOpenTry, MsgOnChanUpgradeAck, MsgOnChanUpgradeInit,
+        MsgOnChanUpgradeOpen, MsgOnChanUpgradeTry,
+    },
+};
+use common_types::{ChannelId, PortId, Sequence, Version};
+
+use crate::host::HOST_PORT_ID;
+
+multiversx_sc::imports!();
+
+static OPENED_BY_CONTROLLER_ERR_MSG: &[u8] =
+    b"Interchain account channels must be opened by the controller";
+static UPGRADES_NOT_SUPPORTED_ERR_MSG: &[u8] =
+    b"Interchain account channel upgrades are not supported";
+static HOST_SENDS_NO_PACKETS_ERR_MSG: &[u8] = b"The host does not send packets";
+
+/// The callbacks of the IBC handler, which may only be called by it
+///
+/// The callbacks of the `icahost` port go to the host, those of the controller ports to the controller.
+#[multiversx_sc::module]
+pub trait IbcModule:
+    crate::controller::ControllerModule
+    + crate::host::HostModule
+    + crate::ibc_handler::IbcHandlerModule
+    + crate::events::EventsModule
+{
+    #[endpoint(onChanOpenInit)]
+    fn on_chan_open_init(&self, args: MsgOnChanOpenInit<Self::Api>) -> Version<Self::Api> {
+        self.require_ibc_handler_caller();
+        require!(
+            !self.is_host_port(&args.port_id),
+            OPENED_BY_CONTROLLER_ERR_MSG
+        );
+
+        self.controller_on_chan_open_init(&args)
+    }
+
+    #[endpoint(onChanOpenTry)]
+    fn on_chan_open_try(&self, args: MsgOnChanOpenTry<Self::Api>) -> Version<Self::Api> {
+        self.require_ibc_handler_caller();
+        require!(
+            self.is_host_port(&args.port_id),
+            OPENED_BY_CONTROLLER_ERR_MSG
+        );
+
+        self.host_on_chan_open_try(&args)
+    }
+
+    #[endpoint(onChanOpenAck)]
+    fn on_chan_open_ack(&self, args: MsgOnChanOpenAck<Self::Api>) {
+        self.require_ibc_handler_caller();
+        require!(
+            !self.is_host_port(&args.port_id),
+            OPENED_BY_CONTROLLER_ERR_MSG
+        );
+
+        self.controller_on_chan_open_ack(&args);
+    }
+
+    #[endpoint(onChanOpenConfirm)]
+    fn on_chan_open_confirm(&self, args: MsgOnChanOpenConfirm<Self::Api>) {
+        self.require_ibc_handler_caller();
+        require!(
+            self.is_host_port(&args.port_id),
+            OPENED_BY_CONTROLLER_ERR_MSG
+        );
+    }
+
+    /// Interchain account channels only close when a packet times out
+    #[endpoint(onChanCloseInit)]
+    fn on_chan_close_init(&self, _args: MsgOnChanCloseInit<Self::Api>) {
+        sc_panic!("Interchain account channels cannot be closed");
+    }
+
+    #[endpoint(onChanCloseConfirm)]
+    fn on_chan_close_confirm(&self, args: MsgOnChanCloseConfirm<Self::Api>) {
+        self.require_ibc_handler_caller();
+
+        if self.is_host_port(&args.port_id) {
+            self.host_on_chan_close_confirm(&args.channel_id);
+        } else {
+            self.controller_on_chan_close_confirm(&args.port_id, &args.channel_id);
+        }
+    }
+
+    #[endpoint(onChanUpgradeInit)]
+    fn on_chan_upgrade_init(&self, _args: MsgOnChanUpgradeInit<Self::Api>) -> Version<Self::Api> {
+        sc_panic!(UPGRADES_NOT_SUPPORTED_ERR_MSG);
+    }
+
+    #[endpoint(onChanUpgradeTry)]
+    fn on_chan_upgrade_try(&self, _args: MsgOnChanUpgradeTry<Self::Api>) -> Version<Self::Api> {
+        sc_panic!(UPGRADES_NOT_SUPPORTED_ERR_MSG);
+    }
+
+    #[endpoint(onChanUpgradeAck)]
+    fn on_chan_upgrade_ack(&self, _args: MsgOnChanUpgradeAck<Self::Api>) {
+        sc_panic!(UPGRADES_NOT_SUPPORTED_ERR_MSG);
+    }
+
+    #[endpoint(onChanUpgradeOpen)]
+    fn on_chan_upgrade_open(&self, _args: MsgOnChanUpgradeOpen<Self::Api>) {
+        sc_panic!(UPGRADES_NOT_SUPPORTED_ERR_MSG);
+    }
+
+    #[view(canTransitionToFlushComplete)]
+    fn can_transition_to_flush_complete(
+        &self,
+        _port_id: PortId<Self::Api>,
+        _channel_id: ChannelId<Self::Api>,
+        _upgrade_sequence: Sequence,
+    ) -> bool {
+        true
+    }
+
+    #[endpoint(onRecvPacket)]
+    fn on_recv_packet(&self, packet: Packet<Self::Api>, _relayer: ManagedAddress) -> ManagedBuffer {
+        self.require_ibc_handler_caller();
+        require!(
+            self.is_host_port(&packet.dest_port),
+            "The controller does not receive packets"
+        );
+
+        self.execute_tx(&packet)
+    }
+
+    #[endpoint(onAcknowledgementPacket)]
+    fn on_ack_packet(
+        &self,
+        packet: Packet<Self::Api>,
+        ack: ManagedBuffer,
+        _relayer: ManagedAddress,
+    ) {
+        self.require_ibc_handler_caller();
+        require!(
+            !self.is_host_port(&packet.src_port),
+            HOST_SENDS_NO_PACKETS_ERR_MSG
+        );
+
+        self.controller_on_ack_packet(&packet, &ack);
+    }
+
+    #[endpoint(onTimeoutPacket)]
+    fn on_timeout_packet(&self, packet: Packet<Self::Api>, _relayer: ManagedAddress) {
+        self.require_ibc_handler_caller();
+        require!(
+            !self.is_host_port(&packet.src_port),
+            HOST_SENDS_NO_PACKETS_ERR_MSG
+        );
+
+        self.controller_on_timeout_packet(&packet);
+    }
+
+    fn is_host_port(&self, port_id: &PortId<Self::Api>) -> bool {
+        port_id == HOST_PORT_ID
+    }
+}
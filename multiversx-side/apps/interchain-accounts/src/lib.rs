@@ -0,0 +1,34 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod controller;
+pub mod events;
+pub mod host;
+pub mod ibc_handler;
+pub mod ibc_module;
+pub mod metadata;
+pub mod packet_data;
+
+/// ICS-27 interchain accounts application, acting both as controller, on the `icacontroller-` ports, and as host,
+/// on the `icahost` port of the IBC handler.
+///
+/// Owners on this chain control accounts on other chains by sending them `CosmosTx`s. Controllers of other chains get
+/// an `interchain-account` contract here, which makes the contract calls allowed by the owner.
+#[multiversx_sc::contract]
+pub trait InterchainAccounts:
+    controller::ControllerModule
+    + host::HostModule
+    + ibc_module::IbcModule
+    + ibc_handler::IbcHandlerModule
+    + events::EventsModule
+{
+    #[init]
+    fn init(&self, ibc_handler: ManagedAddress, account_template: ManagedAddress) {
+        self.ibc_handler().set(ibc_handler);
+        self.account_template().set(account_template);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+}
@@ -0,0 +1,79 @@
+use common_types::{
+    json::{JsonObject, JsonObjectBuilder},
+    ConnectionId,
+};
+
+multiversx_sc::imports!();
+
+pub static ICS27_VERSION: &[u8] = b"ics27-1";
+pub static PROTO3_ENCODING: &[u8] = b"proto3";
+pub static SDK_MULTI_MSG_TX_TYPE: &[u8] = b"sdk_multi_msg";
+
+/// ICS-27 channel version metadata, JSON encoded in ibc-go's field order
+///
+/// The controller proposes it without `address`, which the host fills in with the interchain account address.
+pub struct Metadata<M: ManagedTypeApi> {
+    pub version: ManagedBuffer<M>,
+    pub controller_connection_id: ConnectionId<M>,
+    pub host_connection_id: ConnectionId<M>,
+    pub address: ManagedBuffer<M>,
+    pub encoding: ManagedBuffer<M>,
+    pub tx_type: ManagedBuffer<M>,
+}
+
+impl<M: ManagedTypeApi> Metadata<M> {
+    /// The only metadata supported, with protobuf encoded `CosmosTx`s
+    pub fn new(
+        controller_connection_id: ConnectionId<M>,
+        host_connection_id: ConnectionId<M>,
+        address: ManagedBuffer<M>,
+    ) -> Self {
+        Metadata {
+            version: ManagedBuffer::from(ICS27_VERSION),
+            controller_connection_id,
+            host_connection_id,
+            address,
+            encoding: ManagedBuffer::from(PROTO3_ENCODING),
+            tx_type: ManagedBuffer::from(SDK_MULTI_MSG_TX_TYPE),
+        }
+    }
+
+    pub fn to_json(&self) -> ManagedBuffer<M> {
+        JsonObjectBuilder::new()
+            .string(b"version", &self.version)
+            .string(b"controller_connection_id", &self.controller_connection_id)
+            .string(b"host_connection_id", &self.host_connection_id)
+            .string(b"address", &self.address)
+            .string(b"encoding", &self.encoding)
+            .string(b"tx_type", &self.tx_type)
+            .build()
+    }
+
+    /// Returns `None` if the metadata can't be decoded or isn't supported
+    pub fn from_json(encoded: &ManagedBuffer<M>) -> Option<Self> {
+        let object = JsonObject::parse(encoded)?;
+        let address = if object.contains(b"address") {
+            object.get_string(b"address")?
+        } else {
+            ManagedBuffer::new()
+        };
+        let metadata = Metadata {
+            version: object.get_string(b"version")?,
+            controller_connection_id: object.get_string(b"controller_connection_id")?,
+            host_connection_id: object.get_string(b"host_connection_id")?,
+            address,
+            encoding: object.get_string(b"encoding")?,
+            tx_type: object.get_string(b"tx_type")?,
+        };
+        if &metadata.version != ICS27_VERSION
+            || &metadata.encoding != PROTO3_ENCODING
+            || &metadata.tx_type != SDK_MULTI_MSG_TX_TYPE
+            || metadata.controller_connection_id.is_empty()
+            || metadata.host_connection_id.is_empty()
+        {
+            return None;
+        }
+
+        Some(metadata)
+    }
+}
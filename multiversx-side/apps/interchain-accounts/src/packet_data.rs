@@ -0,0 +1,215 @@
+use common_types::{
+    base64, bech32,
+    json::{JsonObject, JsonObjectBuilder},
+    proto::{self, FieldReader},
+};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub static EXECUTE_TX_TYPE: &[u8] = b"TYPE_EXECUTE_TX";
+pub static MSG_EXECUTE_CONTRACT_TYPE_URL: &[u8] =
+    b"/multiversx.interchainaccounts.v1.MsgExecuteContract";
+pub static MSG_EXECUTE_CONTRACT_RESPONSE_TYPE_URL: &[u8] =
+    b"/multiversx.interchainaccounts.v1.MsgExecuteContractResponse";
+
+/// ICS-27 `InterchainAccountPacketData` of type `TYPE_EXECUTE_TX`, JSON encoded in ibc-go's field order
+///
+/// `data` holds the protobuf encoded `CosmosTx`, which is base64 encoded in JSON.
+pub struct InterchainAccountPacketData<M: ManagedTypeApi> {
+    pub data: ManagedBuffer<M>,
+    pub memo: ManagedBuffer<M>,
+}
+
+impl<M: ManagedTypeApi> InterchainAccountPacketData<M> {
+    pub fn to_json(&self) -> ManagedBuffer<M> {
+        JsonObjectBuilder::new()
+            .string(b"type", &ManagedBuffer::from(EXECUTE_TX_TYPE))
+            .string(b"data", &base64::encode(&self.data))
+            .string(b"memo", &self.memo)
+            .build()
+    }
+
+    /// Returns `None` if the packet data isn't a valid `TYPE_EXECUTE_TX` packet
+    pub fn from_json(encoded: &ManagedBuffer<M>) -> Option<Self> {
+        let object = JsonObject::parse(encoded)?;
+        if &object.get_string(b"type")? != EXECUTE_TX_TYPE {
+            return None;
+        }
+
+        let memo = if object.contains(b"memo") {
+            object.get_string(b"memo")?
+        } else {
+            ManagedBuffer::new()
+        };
+        let data = base64::decode(&object.get_string(b"data")?)?;
+        if data.is_empty() {
+            return None;
+        }
+
+        Some(InterchainAccountPacketData { data, memo })
+    }
+}
+
+/// google.protobuf.Any
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct AnyMessage<M: ManagedTypeApi> {
+    pub type_url: ManagedBuffer<M>,
+    pub value: ManagedBuffer<M>,
+}
+
+impl<M: ManagedTypeApi> AnyMessage<M> {
+    pub fn to_proto(&self) -> ManagedBuffer<M> {
+        let mut encoded = ManagedBuffer::new();
+        proto::append_bytes_field(&mut encoded, 1, &self.type_url);
+        proto::append_bytes_field(&mut encoded, 2, &self.value);
+
+        encoded
+    }
+
+    pub fn from_proto(encoded: &ManagedBuffer<M>) -> Self {
+        let mut message = AnyMessage {
+            type_url: ManagedBuffer::new(),
+            value: ManagedBuffer::new(),
+        };
+        for field in FieldReader::new(encoded) {
+            match field.number {
+                1 => message.type_url = field.value.into_bytes(),
+                2 => message.value = field.value.into_bytes(),
+                _ => {}
+            }
+        }
+
+        message
+    }
+}
+
+/// ibc.applications.interchain_accounts.v1.CosmosTx
+pub fn encode_cosmos_tx<M: ManagedTypeApi>(
+    messages: &ManagedVec<M, AnyMessage<M>>,
+) -> ManagedBuffer<M> {
+    let mut encoded = ManagedBuffer::new();
+    for message in messages {
+        proto::append_message_field(&mut encoded, 1, &message.to_proto());
+    }
+
+    encoded
+}
+
+pub fn decode_cosmos_tx<M: ManagedTypeApi>(
+    encoded: &ManagedBuffer<M>,
+) -> ManagedVec<M, AnyMessage<M>> {
+    let mut messages = ManagedVec::new();
+    for field in FieldReader::new(encoded) {
+        if field.number == 1 {
+            messages.push(AnyMessage::from_proto(&field.value.into_bytes()));
+        }
+    }
+
+    messages
+}
+
+/// A call of a contract by an interchain account, the only message the host executes
+///
+/// The contract is bech32 encoded and the EGLD value, paid by the account, is a decimal string.
+#[derive(ManagedVecItem)]
+pub struct MsgExecuteContract<M: ManagedTypeApi> {
+    pub contract: ManagedAddress<M>,
+    pub function: ManagedBuffer<M>,
+    pub egld_value: BigUint<M>,
+    pub args: ManagedVec<M, ManagedBuffer<M>>,
+}
+
+impl<M: ManagedTypeApi> MsgExecuteContract<M> {
+    pub fn to_any(&self) -> AnyMessage<M> {
+        let mut value = ManagedBuffer::new();
+        proto::append_bytes_field(&mut value, 1, &bech32::encode_address(&self.contract));
+        proto::append_bytes_field(&mut value, 2, &self.function);
+        for arg in &self.args {
+            proto::append_message_field(&mut value, 3, &arg);
+        }
+        if self.egld_value > 0 {
+            proto::append_bytes_field(&mut value, 4, &self.egld_value.to_display());
+        }
+
+        AnyMessage {
+            type_url: ManagedBuffer::from(MSG_EXECUTE_CONTRACT_TYPE_URL),
+            value,
+        }
+    }
+
+    /// Returns `None` if the message isn't a valid contract call
+    pub fn from_any(message: &AnyMessage<M>) -> Option<Self> {
+        if &message.type_url != MSG_EXECUTE_CONTRACT_TYPE_URL {
+            return None;
+        }
+
+        let mut contract = ManagedBuffer::new();
+        let mut function = ManagedBuffer::new();
+        let mut egld_value = ManagedBuffer::new();
+        let mut args = ManagedVec::new();
+        for field in FieldReader::new(&message.value) {
+            match field.number {
+                1 => contract = field.value.into_bytes(),
+                2 => function = field.value.into_bytes(),
+                3 => args.push(field.value.into_bytes()),
+                4 => egld_value = field.value.into_bytes(),
+                _ => {}
+            }
+        }
+        if function.is_empty() {
+            return None;
+        }
+
+        Some(MsgExecuteContract {
+            contract: bech32::decode_address(&contract)?,
+            function,
+            egld_value: parse_egld_value(&egld_value)?,
+            args,
+        })
+    }
+}
+
+/// The `MsgExecuteContractResponse` of a call, holding its results
+pub fn encode_execute_contract_response<M: ManagedTypeApi>(
+    results: &ManagedVec<M, ManagedBuffer<M>>,
+) -> AnyMessage<M> {
+    let mut value = ManagedBuffer::new();
+    for result in results {
+        proto::append_message_field(&mut value, 1, &result);
+    }
+
+    AnyMessage {
+        type_url: ManagedBuffer::from(MSG_EXECUTE_CONTRACT_RESPONSE_TYPE_URL),
+        value,
+    }
+}
+
+/// cosmos.base.abci.v1beta1.TxMsgData, the result of a successful `CosmosTx`
+pub fn encode_tx_msg_data<M: ManagedTypeApi>(
+    responses: &ManagedVec<M, AnyMessage<M>>,
+) -> ManagedBuffer<M> {
+    let mut encoded = ManagedBuffer::new();
+    for response in responses {
+        proto::append_message_field(&mut encoded, 2, &response.to_proto());
+    }
+
+    encoded
+}
+
+/// Parses a decimal value without leading zeros, an empty string standing for zero
+fn parse_egld_value<M: ManagedTypeApi>(digits: &ManagedBuffer<M>) -> Option<BigUint<M>> {
+    let mut value = BigUint::zero();
+    let mut digit = [0u8; 1];
+    for offset in 0..digits.len() {
+        digits.load_slice(offset, &mut digit).ok()?;
+        if !digit[0].is_ascii_digit() || (offset == 0 && digit[0] == b'0') {
+            return None;
+        }
+
+        value *= 10u32;
+        value += (digit[0] - b'0') as u32;
+    }
+
+    Some(value)
+}
@@ -0,0 +1,389 @@
+#![allow(dead_code)]
+
+use std::{cell::RefCell, rc::Rc};
+
+use ::channel::{
+    channel_libs::packet_types::Packet,
+    interfaces::ibc_module_interface::{MsgOnChanOpenAck, MsgOnChanOpenTry},
+};
+use client_common::ClientStatus;
+use common_types::{
+    channel_types::{channel, channel_counterparty, height},
+    connection_types::{connection_end, counterparty, merkle_prefix, version},
+    ConnectionHops, FeatureVec, VersionVec,
+};
+use host::{
+    host_config::HostConfigModule,
+    storage::{ClientInfo, StorageModule},
+};
+use ibc_handler::IbcHandler;
+use interchain_accounts::{
+    controller::{ControllerModule, CONTROLLER_PORT_PREFIX},
+    host::HOST_PORT_ID,
+    ibc_module::IbcModule,
+    InterchainAccounts,
+};
+use mock::{
+    client_logic::ClientLogicModule,
+    mock_types::{client_state, consensus_state},
+    Mock,
+};
+use multiversx_sc::types::Address;
+use multiversx_sc_scenario::{
+    imports::{BlockchainStateWrapper, ContractObjWrapper},
+    managed_address, managed_buffer, rust_biguint, DebugApi,
+};
+use test_utils::bech32_address;
+
+pub static CLIENT_TYPE: &[u8] = b"mock-client";
+pub static CLIENT_ID: &[u8] = b"mock-client-0";
+pub static CONNECTION_ID: &[u8] = b"connection-0";
+pub static COUNTERPARTY_CLIENT_ID: &[u8] = b"counterparty-client-0";
+pub static COUNTERPARTY_CONNECTION_ID: &[u8] = b"connection-7";
+/// The channel of the host port, opened by a controller of the counterparty chain
+pub static HOST_CHANNEL_ID: &[u8] = b"channel-3";
+pub static COUNTERPARTY_CHANNEL_ID: &[u8] = b"channel-5";
+pub static COUNTERPARTY_CONTROLLER_PORT: &[u8] = b"icacontroller-cosmos1owner";
+pub static COUNTERPARTY_ACCOUNT_ADDRESS: &[u8] = b"cosmos1interchainaccount";
+pub static COMMITMENT_PREFIX: &[u8] = b"ibc";
+
+pub const PROOF_HEIGHT: height::Data = height::Data {
+    revision_number: 0,
+    revision_height: 10,
+};
+pub const CONSENSUS_TIMESTAMP: u64 = 1_000_000_000;
+
+pub struct InterchainAccountsSetup<
+    IbcHandlerObjBuilder,
+    MockClientObjBuilder,
+    InterchainAccountsObjBuilder,
+    InterchainAccountObjBuilder,
+> where
+    IbcHandlerObjBuilder: 'static + Copy + Fn() -> ibc_handler::ContractObj<DebugApi>,
+    MockClientObjBuilder: 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    InterchainAccountsObjBuilder:
+        'static + Copy + Fn() -> interchain_accounts::ContractObj<DebugApi>,
+    InterchainAccountObjBuilder: 'static + Copy + Fn() -> ica_account::ContractObj<DebugApi>,
+{
+    pub b_mock: Rc<RefCell<BlockchainStateWrapper>>,
+    pub owner: Address,
+    pub user: Address,
+    pub ibc_handler_wrapper:
+        ContractObjWrapper<ibc_handler::ContractObj<DebugApi>, IbcHandlerObjBuilder>,
+    pub mock_client_wrapper: ContractObjWrapper<mock::ContractObj<DebugApi>, MockClientObjBuilder>,
+    pub interchain_accounts_wrapper: ContractObjWrapper<
+        interchain_accounts::ContractObj<DebugApi>,
+        InterchainAccountsObjBuilder,
+    >,
+    pub account_template_wrapper:
+        ContractObjWrapper<ica_account::ContractObj<DebugApi>, InterchainAccountObjBuilder>,
+}
+
+impl<
+        IbcHandlerObjBuilder,
+        MockClientObjBuilder,
+        InterchainAccountsObjBuilder,
+        InterchainAccountObjBuilder,
+    >
+    InterchainAccountsSetup<
+        IbcHandlerObjBuilder,
+        MockClientObjBuilder,
+        InterchainAccountsObjBuilder,
+        InterchainAccountObjBuilder,
+    >
+where
+    IbcHandlerObjBuilder: 'static + Copy + Fn() -> ibc_handler::ContractObj<DebugApi>,
+    MockClientObjBuilder: 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    InterchainAccountsObjBuilder:
+        'static + Copy + Fn() -> interchain_accounts::ContractObj<DebugApi>,
+    InterchainAccountObjBuilder: 'static + Copy + Fn() -> ica_account::ContractObj<DebugApi>,
+{
+    pub fn new(
+        b_mock: Rc<RefCell<BlockchainStateWrapper>>,
+        owner: &Address,
+        ibc_handler_builder: IbcHandlerObjBuilder,
+        mock_client_builder: MockClientObjBuilder,
+        interchain_accounts_builder: InterchainAccountsObjBuilder,
+        interchain_account_builder: InterchainAccountObjBuilder,
+    ) -> Self {
+        let rust_zero = rust_biguint!(0u64);
+        let user = b_mock.borrow_mut().create_user_account(&rust_zero);
+
+        let ibc_handler_wrapper = b_mock.borrow_mut().create_sc_account(
+            &rust_zero,
+            Some(owner),
+            ibc_handler_builder,
+            "ibc handler",
+        );
+        let mock_client_wrapper = b_mock.borrow_mut().create_sc_account(
+            &rust_zero,
+            Some(owner),
+            mock_client_builder,
+            "mock client",
+        );
+        let interchain_accounts_wrapper = b_mock.borrow_mut().create_sc_account(
+            &rust_zero,
+            Some(owner),
+            interchain_accounts_builder,
+            "interchain accounts",
+        );
+        let account_template_wrapper = b_mock.borrow_mut().create_sc_account(
+            &rust_zero,
+            Some(owner),
+            interchain_account_builder,
+            "interchain account",
+        );
+
+        let ibc_handler_address = ibc_handler_wrapper.address_ref().clone();
+        let mock_client_address = mock_client_wrapper.address_ref().clone();
+        let interchain_accounts_address = interchain_accounts_wrapper.address_ref().clone();
+        let account_template_address = account_template_wrapper.address_ref().clone();
+
+        b_mock
+            .borrow_mut()
+            .execute_tx(owner, &mock_client_wrapper, &rust_zero, |sc| {
+                sc.init(managed_address!(&ibc_handler_address));
+
+                let client_id = managed_buffer!(CLIENT_ID);
+                sc.client_states(&client_id)
+                    .set(client_state::Data::new(PROOF_HEIGHT));
+                sc.consensus_states(&client_id, &PROOF_HEIGHT.to_biguint_concat())
+                    .set(consensus_state::Data::new(CONSENSUS_TIMESTAMP));
+                sc.statuses(&client_id).set(ClientStatus::Active);
+            })
+            .assert_ok();
+
+        b_mock
+            .borrow_mut()
+            .execute_tx(owner, &interchain_accounts_wrapper, &rust_zero, |sc| {
+                sc.init(
+                    managed_address!(&ibc_handler_address),
+                    managed_address!(&account_template_address),
+                );
+            })
+            .assert_ok();
+
+        b_mock
+            .borrow_mut()
+            .execute_tx(owner, &ibc_handler_wrapper, &rust_zero, |sc| {
+                sc.init();
+
+                sc.set_expected_time_per_block(6);
+                sc.register_client(
+                    managed_buffer!(CLIENT_TYPE),
+                    managed_address!(&mock_client_address),
+                );
+                sc.bind_port(
+                    managed_buffer!(HOST_PORT_ID),
+                    managed_address!(&interchain_accounts_address),
+                );
+                sc.bind_port_prefix(
+                    managed_buffer!(CONTROLLER_PORT_PREFIX),
+                    managed_address!(&interchain_accounts_address),
+                );
+
+                sc.client_info(&managed_buffer!(CLIENT_ID)).set(ClientInfo {
+                    client_type: managed_buffer!(CLIENT_TYPE),
+                    client_impl: managed_address!(&mock_client_address),
+                });
+                sc.connection_info(&managed_buffer!(CONNECTION_ID))
+                    .set(open_connection());
+            })
+            .assert_ok();
+
+        Self {
+            b_mock,
+            owner: owner.clone(),
+            user,
+            ibc_handler_wrapper,
+            mock_client_wrapper,
+            interchain_accounts_wrapper,
+            account_template_wrapper,
+        }
+    }
+
+    /// Registers the interchain account of the user, returning the channel ID
+    pub fn register_interchain_account(&self) -> Vec<u8> {
+        let mut channel_id = Vec::new();
+        self.b_mock
+            .borrow_mut()
+            .execute_tx(
+                &self.user,
+                &self.interchain_accounts_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    channel_id = sc
+                        .register_interchain_account(managed_buffer!(CONNECTION_ID))
+                        .to_boxed_bytes()
+                        .into_vec();
+                },
+            )
+            .assert_ok();
+
+        channel_id
+    }
+
+    /// Completes the handshake of a channel registered by the user, as if the host answered with `metadata`, and
+    /// opens the channel in the handler
+    pub fn open_controller_channel(&self, channel_id: &[u8], metadata: &[u8]) {
+        let controller_port = self.controller_port(&self.user);
+        let ibc_handler_address = self.ibc_handler_wrapper.address_ref().clone();
+        self.b_mock
+            .borrow_mut()
+            .execute_tx(
+                &ibc_handler_address,
+                &self.interchain_accounts_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    sc.on_chan_open_ack(MsgOnChanOpenAck {
+                        port_id: managed_buffer!(controller_port.as_bytes()),
+                        channel_id: managed_buffer!(channel_id),
+                        counterparty_version: managed_buffer!(metadata),
+                    });
+                },
+            )
+            .assert_ok();
+
+        self.b_mock
+            .borrow_mut()
+            .execute_tx(
+                &self.owner,
+                &self.ibc_handler_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    sc.channel_info(
+                        &managed_buffer!(controller_port.as_bytes()),
+                        &managed_buffer!(channel_id),
+                    )
+                    .update(|info| {
+                        info.channel.state = channel::State::Open;
+                        info.channel.counterparty.channel_id =
+                            managed_buffer!(COUNTERPARTY_CHANNEL_ID);
+                    });
+                },
+            )
+            .assert_ok();
+    }
+
+    /// Opens `HOST_CHANNEL_ID` on the host port, for `COUNTERPARTY_CONTROLLER_PORT`
+    pub fn open_host_channel(&self, channel_id: &[u8], counterparty_version: &[u8]) -> Vec<u8> {
+        let mut version = Vec::new();
+        let ibc_handler_address = self.ibc_handler_wrapper.address_ref().clone();
+        self.b_mock
+            .borrow_mut()
+            .execute_tx(
+                &ibc_handler_address,
+                &self.interchain_accounts_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    version = sc
+                        .on_chan_open_try(host_open_try_args(channel_id, counterparty_version))
+                        .to_boxed_bytes()
+                        .into_vec();
+                },
+            )
+            .assert_ok();
+
+        version
+    }
+
+    /// "icacontroller-{bech32 owner}"
+    pub fn controller_port(&self, owner: &Address) -> String {
+        format!(
+            "{}{}",
+            String::from_utf8(CONTROLLER_PORT_PREFIX.to_vec()).unwrap(),
+            bech32_address(&self.b_mock, &self.interchain_accounts_wrapper, owner)
+        )
+    }
+}
+
+/// The version metadata, as ibc-go encodes it
+pub fn metadata(
+    controller_connection_id: &[u8],
+    host_connection_id: &[u8],
+    address: &[u8],
+) -> Vec<u8> {
+    format!(
+        r#"{{"version":"ics27-1","controller_connection_id":"{}","host_connection_id":"{}","address":"{}","encoding":"proto3","tx_type":"sdk_multi_msg"}}"#,
+        String::from_utf8_lossy(controller_connection_id),
+        String::from_utf8_lossy(host_connection_id),
+        String::from_utf8_lossy(address),
+    )
+    .into_bytes()
+}
+
+/// The metadata a controller of the counterparty chain proposes to the host
+pub fn counterparty_controller_metadata() -> Vec<u8> {
+    metadata(COUNTERPARTY_CONNECTION_ID, CONNECTION_ID, b"")
+}
+
+pub fn host_open_try_args(
+    channel_id: &[u8],
+    counterparty_version: &[u8],
+) -> MsgOnChanOpenTry<DebugApi> {
+    MsgOnChanOpenTry {
+        order: channel::Order::Ordered,
+        connection_hops: ConnectionHops::from_single_item(managed_buffer!(CONNECTION_ID)),
+        port_id: managed_buffer!(HOST_PORT_ID),
+        channel_id: managed_buffer!(channel_id),
+        counterparty: channel_counterparty::Data {
+            port_id: managed_buffer!(COUNTERPARTY_CONTROLLER_PORT),
+            channel_id: managed_buffer!(COUNTERPARTY_CHANNEL_ID),
+        },
+        counterparty_version: managed_buffer!(counterparty_version),
+    }
+}
+
+/// A packet sent by the counterparty controller to `HOST_CHANNEL_ID`
+pub fn host_packet(seq: u64, data: &[u8]) -> Packet<DebugApi> {
+    Packet {
+        seq,
+        src_port: managed_buffer!(COUNTERPARTY_CONTROLLER_PORT),
+        src_channel: managed_buffer!(COUNTERPARTY_CHANNEL_ID),
+        dest_port: managed_buffer!(HOST_PORT_ID),
+        dest_channel: managed_buffer!(HOST_CHANNEL_ID),
+        data: managed_buffer!(data),
+        timeout_height: height::Data::default(),
+        timeout_timestamp: 1_000_000_000_000,
+    }
+}
+
+/// `InterchainAccountPacketData`, as ibc-go encodes it
+pub fn packet_data(base64_tx: &str) -> Vec<u8> {
+    format!(
+        r#"{{"type":"TYPE_EXECUTE_TX","data":"{}","memo":""}}"#,
+        base64_tx
+    )
+    .into_bytes()
+}
+
+/// A protobuf field with a length delimited value, shorter than 128 bytes
+pub fn proto_field(number: u8, value: &[u8]) -> Vec<u8> {
+    let mut field = vec![(number << 3) | 2, value.len() as u8];
+    field.extend_from_slice(value);
+
+    field
+}
+
+pub fn open_connection() -> connection_end::Data<DebugApi> {
+    let mut features = FeatureVec::new();
+    features.push(managed_buffer!(channel::ORDERED));
+    features.push(managed_buffer!(channel::UNORDERED));
+
+    connection_end::Data {
+        client_id: managed_buffer!(CLIENT_ID),
+        versions: VersionVec::from_single_item(version::Data {
+            identifier: managed_buffer!(b"1"),
+            features,
+        }),
+        state: connection_end::State::Open,
+        counterparty: counterparty::Data {
+            client_id: managed_buffer!(COUNTERPARTY_CLIENT_ID),
+            connection_id: managed_buffer!(COUNTERPARTY_CONNECTION_ID),
+            prefix: merkle_prefix::Data {
+                key_prefix: managed_buffer!(COMMITMENT_PREFIX),
+            },
+        },
+        delay_period: 0,
+    }
+}
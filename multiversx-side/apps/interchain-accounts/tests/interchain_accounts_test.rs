@@ -0,0 +1,712 @@
+use std::{cell::RefCell, rc::Rc};
+
+use ::channel::{
+    channel_libs::packet_types::Packet,
+    interfaces::ibc_module_interface::{
+        MsgOnChanCloseConfirm, MsgOnChanOpenAck, MsgOnChanOpenInit,
+    },
+};
+use common_types::{
+    base64,
+    channel_types::{channel, channel_counterparty, height},
+    json::JsonObject,
+    ConnectionHops,
+};
+use host::storage::StorageModule;
+use interchain_accounts::{
+    controller::ControllerModule,
+    host::{HostModule, HOST_PORT_ID},
+    ibc_module::IbcModule,
+    packet_data::{self, AnyMessage, InterchainAccountPacketData, MsgExecuteContract},
+};
+use interchain_accounts_setup::*;
+use multiversx_sc::types::{Address, ManagedVec, MultiValueEncoded};
+use multiversx_sc_scenario::{
+    imports::{BlockchainStateWrapper, OptionalValue},
+    managed_address, managed_buffer, rust_biguint, DebugApi,
+};
+use test_utils::bech32_address;
+
+pub mod interchain_accounts_setup;
+
+/// The CosmosTx holding a single `Any` with type URL "/a" and value "b", base64 encoded
+static SINGLE_MESSAGE_TX: &str = "CgcKAi9hEgFi";
+static MOCK_CLIENT_FUNCTION: &[u8] = b"getTimestampAtHeight";
+
+type Setup = InterchainAccountsSetup<
+    fn() -> ibc_handler::ContractObj<DebugApi>,
+    fn() -> mock::ContractObj<DebugApi>,
+    fn() -> interchain_accounts::ContractObj<DebugApi>,
+    fn() -> ica_account::ContractObj<DebugApi>,
+>;
+
+fn get_interchain_accounts_setup() -> Setup {
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_biguint!(0));
+    InterchainAccountsSetup::new(
+        Rc::new(RefCell::new(b_mock)),
+        &owner,
+        ibc_handler::contract_obj,
+        mock::contract_obj,
+        interchain_accounts::contract_obj,
+        ica_account::contract_obj,
+    )
+}
+
+/// The arguments of `getTimestampAtHeight` for `PROOF_HEIGHT`
+fn mock_client_call_args() -> Vec<Vec<u8>> {
+    let mut encoded_height = PROOF_HEIGHT.revision_number.to_be_bytes().to_vec();
+    encoded_height.extend_from_slice(&PROOF_HEIGHT.revision_height.to_be_bytes());
+
+    vec![CLIENT_ID.to_vec(), encoded_height]
+}
+
+/// `InterchainAccountPacketData` executing `function` of the mock client
+fn execute_packet_data(setup: &Setup, function: &[u8]) -> Vec<u8> {
+    let mock_client_address = setup.mock_client_wrapper.address_ref().clone();
+    execute_packet_data_to(setup, &mock_client_address, function)
+}
+
+/// A transaction calling `function` of `contract` with the arguments of the mock client call
+fn execute_packet_data_to(setup: &Setup, contract: &Address, function: &[u8]) -> Vec<u8> {
+    let mut data = Vec::new();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.interchain_accounts_wrapper, |_| {
+            let mut args = ManagedVec::new();
+            for arg in mock_client_call_args() {
+                args.push(managed_buffer!(&arg));
+            }
+            let call = MsgExecuteContract::<DebugApi> {
+                contract: managed_address!(contract),
+                function: managed_buffer!(function),
+                egld_value: 0u32.into(),
+                args,
+            };
+            let packet_data = InterchainAccountPacketData {
+                data: packet_data::encode_cosmos_tx(&ManagedVec::from_single_item(call.to_any())),
+                memo: managed_buffer!(b""),
+            };
+            data = packet_data.to_json().to_boxed_bytes().into_vec();
+        })
+        .assert_ok();
+
+    data
+}
+
+fn receive_packet(setup: &Setup, data: &[u8]) -> Vec<u8> {
+    let ibc_handler_address = setup.ibc_handler_wrapper.address_ref().clone();
+    let mut ack = Vec::new();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.interchain_accounts_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let result =
+                    sc.on_recv_packet(host_packet(1, data), managed_address!(&ibc_handler_address));
+                ack = result.to_boxed_bytes().into_vec();
+            },
+        )
+        .assert_ok();
+
+    ack
+}
+
+fn error_ack(error: &str) -> Vec<u8> {
+    format!(r#"{{"error":"{}"}}"#, error).into_bytes()
+}
+
+/// A packet sent by the user's controller port on `channel_id`
+fn controller_packet(controller_port: &str, channel_id: &[u8]) -> Packet<DebugApi> {
+    Packet {
+        seq: 1,
+        src_port: managed_buffer!(controller_port.as_bytes()),
+        src_channel: managed_buffer!(channel_id),
+        dest_port: managed_buffer!(HOST_PORT_ID),
+        dest_channel: managed_buffer!(COUNTERPARTY_CHANNEL_ID),
+        data: managed_buffer!(&packet_data(SINGLE_MESSAGE_TX)),
+        timeout_height: height::Data::default(),
+        timeout_timestamp: 1_000_000_000_000,
+    }
+}
+
+#[test]
+fn packet_data_encoding_test() {
+    DebugApi::dummy();
+
+    let message = AnyMessage::<DebugApi> {
+        type_url: managed_buffer!(b"/a"),
+        value: managed_buffer!(b"b"),
+    };
+    let data = packet_data::encode_cosmos_tx(&ManagedVec::from_single_item(message));
+    let encoded = InterchainAccountPacketData {
+        data,
+        memo: managed_buffer!(b""),
+    }
+    .to_json();
+    assert_eq!(encoded, managed_buffer!(&packet_data(SINGLE_MESSAGE_TX)));
+
+    let decoded = InterchainAccountPacketData::from_json(&encoded).unwrap();
+    let messages = packet_data::decode_cosmos_tx(&decoded.data);
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages.get(0).type_url, managed_buffer!(b"/a"));
+    assert_eq!(messages.get(0).value, managed_buffer!(b"b"));
+
+    assert!(
+        InterchainAccountPacketData::<DebugApi>::from_json(&managed_buffer!(
+            br#"{"type":"TYPE_UNSPECIFIED","data":"CgcKAi9hEgFi","memo":""}"#
+        ))
+        .is_none()
+    );
+}
+
+#[test]
+fn register_interchain_account_test() {
+    let setup = get_interchain_accounts_setup();
+    let channel_id = setup.register_interchain_account();
+    assert_eq!(channel_id, b"channel-0".to_vec());
+
+    let controller_port = setup.controller_port(&setup.user);
+    let interchain_accounts_address = setup.interchain_accounts_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.ibc_handler_wrapper, |sc| {
+            let port_id = managed_buffer!(controller_port.as_bytes());
+            assert_eq!(
+                sc.port_capabilities(&port_id).get(),
+                managed_address!(&interchain_accounts_address)
+            );
+
+            let channel_info = sc
+                .channel_info(&port_id, &managed_buffer!(&channel_id))
+                .get();
+            assert!(matches!(channel_info.channel.state, channel::State::Init));
+            assert!(matches!(
+                channel_info.channel.ordering,
+                channel::Order::Ordered
+            ));
+            assert_eq!(
+                channel_info.channel.counterparty.port_id,
+                managed_buffer!(HOST_PORT_ID)
+            );
+            assert_eq!(
+                channel_info.channel.version,
+                managed_buffer!(&metadata(CONNECTION_ID, COUNTERPARTY_CONNECTION_ID, b""))
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn open_controller_channel_test() {
+    let setup = get_interchain_accounts_setup();
+    let channel_id = setup.register_interchain_account();
+    setup.open_controller_channel(
+        &channel_id,
+        &metadata(
+            CONNECTION_ID,
+            COUNTERPARTY_CONNECTION_ID,
+            COUNTERPARTY_ACCOUNT_ADDRESS,
+        ),
+    );
+
+    let user = setup.user.clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.interchain_accounts_wrapper, |sc| {
+            assert_eq!(
+                sc.get_active_channel(managed_address!(&user), managed_buffer!(CONNECTION_ID))
+                    .into_option(),
+                Some(managed_buffer!(&channel_id))
+            );
+            assert_eq!(
+                sc.get_interchain_account_address(
+                    managed_address!(&user),
+                    managed_buffer!(CONNECTION_ID)
+                )
+                .into_option(),
+                Some(managed_buffer!(COUNTERPARTY_ACCOUNT_ADDRESS))
+            );
+        })
+        .assert_ok();
+
+    // a second channel can't be registered while the first one is active
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &setup.user,
+            &setup.interchain_accounts_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.register_interchain_account(managed_buffer!(CONNECTION_ID));
+            },
+        )
+        .assert_user_error("Interchain account channel already active");
+}
+
+#[test]
+fn open_controller_channel_without_address_test() {
+    let setup = get_interchain_accounts_setup();
+    let channel_id = setup.register_interchain_account();
+    let controller_port = setup.controller_port(&setup.user);
+    let ibc_handler_address = setup.ibc_handler_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.interchain_accounts_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.on_chan_open_ack(MsgOnChanOpenAck {
+                    port_id: managed_buffer!(controller_port.as_bytes()),
+                    channel_id: managed_buffer!(&channel_id),
+                    counterparty_version: managed_buffer!(&metadata(
+                        CONNECTION_ID,
+                        COUNTERPARTY_CONNECTION_ID,
+                        b""
+                    )),
+                });
+            },
+        )
+        .assert_user_error("Invalid metadata");
+}
+
+#[test]
+fn controller_open_init_test() {
+    let setup = get_interchain_accounts_setup();
+    let ibc_handler_address = setup.ibc_handler_wrapper.address_ref().clone();
+    let controller_port = setup.controller_port(&setup.user);
+    let open_init_args = |order: channel::Order, counterparty_port: &[u8]| MsgOnChanOpenInit {
+        order,
+        connection_hops: ConnectionHops::from_single_item(managed_buffer!(CONNECTION_ID)),
+        port_id: managed_buffer!(controller_port.as_bytes()),
+        channel_id: managed_buffer!(b"channel-9"),
+        counterparty: channel_counterparty::Data {
+            port_id: managed_buffer!(counterparty_port),
+            channel_id: managed_buffer!(b""),
+        },
+        version: managed_buffer!(&metadata(CONNECTION_ID, COUNTERPARTY_CONNECTION_ID, b"")),
+    };
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.interchain_accounts_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ =
+                    sc.on_chan_open_init(open_init_args(channel::Order::Unordered, HOST_PORT_ID));
+            },
+        )
+        .assert_user_error("Interchain account channels must be ordered");
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.interchain_accounts_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.on_chan_open_init(open_init_args(channel::Order::Ordered, b"transfer"));
+            },
+        )
+        .assert_user_error("Invalid host port");
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &setup.user,
+            &setup.interchain_accounts_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.on_chan_open_init(open_init_args(channel::Order::Ordered, HOST_PORT_ID));
+            },
+        )
+        .assert_user_error("Only the IBC handler may call this endpoint");
+}
+
+#[test]
+fn send_tx_test() {
+    let setup = get_interchain_accounts_setup();
+    // after the latest consensus timestamp of the counterparty, so that the packet doesn't time out right away
+    setup.b_mock.borrow_mut().set_block_timestamp(10);
+    let channel_id = setup.register_interchain_account();
+    setup.open_controller_channel(
+        &channel_id,
+        &metadata(
+            CONNECTION_ID,
+            COUNTERPARTY_CONNECTION_ID,
+            COUNTERPARTY_ACCOUNT_ADDRESS,
+        ),
+    );
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &setup.user,
+            &setup.interchain_accounts_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut messages = MultiValueEncoded::new();
+                messages.push((managed_buffer!(b"/a"), managed_buffer!(b"b")).into());
+                let seq = sc.send_tx(
+                    managed_buffer!(CONNECTION_ID),
+                    1_000,
+                    managed_buffer!(b""),
+                    messages,
+                );
+                assert_eq!(seq, 1);
+            },
+        )
+        .assert_ok();
+
+    let controller_port = setup.controller_port(&setup.user);
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.ibc_handler_wrapper, |sc| {
+            let channel_info = sc
+                .channel_info(
+                    &managed_buffer!(controller_port.as_bytes()),
+                    &managed_buffer!(&channel_id),
+                )
+                .get();
+            assert_eq!(channel_info.next_seq_send, 2);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn send_tx_without_channel_test() {
+    let setup = get_interchain_accounts_setup();
+    let _ = setup.register_interchain_account();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &setup.user,
+            &setup.interchain_accounts_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut messages = MultiValueEncoded::new();
+                messages.push((managed_buffer!(b"/a"), managed_buffer!(b"b")).into());
+                let _ = sc.send_tx(
+                    managed_buffer!(CONNECTION_ID),
+                    1_000,
+                    managed_buffer!(b""),
+                    messages,
+                );
+            },
+        )
+        .assert_user_error("No active interchain account channel");
+}
+
+#[test]
+fn timeout_deactivates_channel_test() {
+    let setup = get_interchain_accounts_setup();
+    let channel_id = setup.register_interchain_account();
+    setup.open_controller_channel(
+        &channel_id,
+        &metadata(
+            CONNECTION_ID,
+            COUNTERPARTY_CONNECTION_ID,
+            COUNTERPARTY_ACCOUNT_ADDRESS,
+        ),
+    );
+
+    let controller_port = setup.controller_port(&setup.user);
+    let ibc_handler_address = setup.ibc_handler_wrapper.address_ref().clone();
+    let user = setup.user.clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.interchain_accounts_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.on_timeout_packet(
+                    controller_packet(&controller_port, &channel_id),
+                    managed_address!(&ibc_handler_address),
+                );
+
+                assert!(sc
+                    .get_active_channel(managed_address!(&user), managed_buffer!(CONNECTION_ID))
+                    .into_option()
+                    .is_none());
+            },
+        )
+        .assert_ok();
+
+    // the next registration must get the same account
+    let channel_id = setup.register_interchain_account();
+    assert_eq!(channel_id, b"channel-1".to_vec());
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.interchain_accounts_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.on_chan_open_ack(MsgOnChanOpenAck {
+                    port_id: managed_buffer!(controller_port.as_bytes()),
+                    channel_id: managed_buffer!(&channel_id),
+                    counterparty_version: managed_buffer!(&metadata(
+                        CONNECTION_ID,
+                        COUNTERPARTY_CONNECTION_ID,
+                        b"cosmos1other"
+                    )),
+                });
+            },
+        )
+        .assert_user_error("Interchain account address mismatch");
+
+    setup.open_controller_channel(
+        &channel_id,
+        &metadata(
+            CONNECTION_ID,
+            COUNTERPARTY_CONNECTION_ID,
+            COUNTERPARTY_ACCOUNT_ADDRESS,
+        ),
+    );
+}
+
+#[test]
+fn host_open_try_test() {
+    let setup = get_interchain_accounts_setup();
+    let version = setup.open_host_channel(HOST_CHANNEL_ID, &counterparty_controller_metadata());
+
+    let mut account = None;
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.interchain_accounts_wrapper, |sc| {
+            match sc.get_interchain_account(
+                managed_buffer!(CONNECTION_ID),
+                managed_buffer!(COUNTERPARTY_CONTROLLER_PORT),
+            ) {
+                OptionalValue::Some(address) => account = Some(address.to_address()),
+                OptionalValue::None => panic!("interchain account not deployed"),
+            }
+        })
+        .assert_ok();
+    let account = account.unwrap();
+
+    assert_eq!(
+        version,
+        metadata(
+            COUNTERPARTY_CONNECTION_ID,
+            CONNECTION_ID,
+            bech32_address(&setup.b_mock, &setup.interchain_accounts_wrapper, &account).as_bytes()
+        )
+    );
+}
+
+#[test]
+fn host_reopen_channel_test() {
+    let setup = get_interchain_accounts_setup();
+    let version = setup.open_host_channel(HOST_CHANNEL_ID, &counterparty_controller_metadata());
+
+    let ibc_handler_address = setup.ibc_handler_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.interchain_accounts_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.on_chan_open_try(host_open_try_args(
+                    b"channel-4",
+                    &counterparty_controller_metadata(),
+                ));
+            },
+        )
+        .assert_user_error("Interchain account channel already active");
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.interchain_accounts_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.on_chan_close_confirm(MsgOnChanCloseConfirm {
+                    port_id: managed_buffer!(HOST_PORT_ID),
+                    channel_id: managed_buffer!(HOST_CHANNEL_ID),
+                });
+            },
+        )
+        .assert_ok();
+
+    // the new channel gets the same account
+    let reopened_version =
+        setup.open_host_channel(b"channel-4", &counterparty_controller_metadata());
+    assert_eq!(reopened_version, version);
+}
+
+#[test]
+fn host_open_try_invalid_channel_test() {
+    let setup = get_interchain_accounts_setup();
+    let ibc_handler_address = setup.ibc_handler_wrapper.address_ref().clone();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.interchain_accounts_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut args =
+                    host_open_try_args(HOST_CHANNEL_ID, &counterparty_controller_metadata());
+                args.order = channel::Order::Unordered;
+                let _ = sc.on_chan_open_try(args);
+            },
+        )
+        .assert_user_error("Interchain account channels must be ordered");
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.interchain_accounts_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut args =
+                    host_open_try_args(HOST_CHANNEL_ID, &counterparty_controller_metadata());
+                args.counterparty.port_id = managed_buffer!(b"transfer");
+                let _ = sc.on_chan_open_try(args);
+            },
+        )
+        .assert_user_error("Invalid controller port");
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.interchain_accounts_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.on_chan_open_try(host_open_try_args(
+                    HOST_CHANNEL_ID,
+                    &metadata(COUNTERPARTY_CONNECTION_ID, b"connection-1", b""),
+                ));
+            },
+        )
+        .assert_user_error("Invalid metadata");
+}
+
+#[test]
+fn host_execute_allowed_call_test() {
+    let setup = get_interchain_accounts_setup();
+    let _ = setup.open_host_channel(HOST_CHANNEL_ID, &counterparty_controller_metadata());
+
+    let mock_client_address = setup.mock_client_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &setup.owner,
+            &setup.interchain_accounts_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.allow_calls(
+                    managed_address!(&mock_client_address),
+                    MultiValueEncoded::from(ManagedVec::from_single_item(managed_buffer!(
+                        MOCK_CLIENT_FUNCTION
+                    ))),
+                );
+            },
+        )
+        .assert_ok();
+
+    let ack = receive_packet(&setup, &execute_packet_data(&setup, MOCK_CLIENT_FUNCTION));
+
+    // TxMsgData with the MsgExecuteContractResponse holding the timestamp returned by the mock client
+    let response = proto_field(1, &CONSENSUS_TIMESTAMP.to_be_bytes()[4..]);
+    let mut any = proto_field(1, packet_data::MSG_EXECUTE_CONTRACT_RESPONSE_TYPE_URL);
+    any.extend(proto_field(2, &response));
+    let expected_result = proto_field(2, &any);
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.interchain_accounts_wrapper, |_| {
+            let object = JsonObject::<DebugApi>::parse(&managed_buffer!(&ack)).unwrap();
+            let result = base64::decode(&object.get_string(b"result").unwrap()).unwrap();
+            assert_eq!(result, managed_buffer!(&expected_result));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn host_execute_disallowed_call_test() {
+    let setup = get_interchain_accounts_setup();
+    let _ = setup.open_host_channel(HOST_CHANNEL_ID, &counterparty_controller_metadata());
+
+    let ack = receive_packet(&setup, &execute_packet_data(&setup, MOCK_CLIENT_FUNCTION));
+    assert_eq!(ack, error_ack("Call not allowed"));
+}
+
+#[test]
+fn host_execute_unknown_contract_test() {
+    let setup = get_interchain_accounts_setup();
+    let _ = setup.open_host_channel(HOST_CHANNEL_ID, &counterparty_controller_metadata());
+
+    // the call would fail, reverting the whole packet, so it is answered with an error acknowledgement instead
+    let user = setup.user.clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &setup.owner,
+            &setup.interchain_accounts_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.allow_calls(
+                    managed_address!(&user),
+                    MultiValueEncoded::from(ManagedVec::from_single_item(managed_buffer!(
+                        MOCK_CLIENT_FUNCTION
+                    ))),
+                );
+            },
+        )
+        .assert_ok();
+
+    let ack = receive_packet(
+        &setup,
+        &execute_packet_data_to(&setup, &user, MOCK_CLIENT_FUNCTION),
+    );
+    assert_eq!(ack, error_ack("Unknown contract"));
+}
+
+#[test]
+fn host_execute_unsupported_message_test() {
+    let setup = get_interchain_accounts_setup();
+    let _ = setup.open_host_channel(HOST_CHANNEL_ID, &counterparty_controller_metadata());
+
+    let ack = receive_packet(&setup, &packet_data(SINGLE_MESSAGE_TX));
+    assert_eq!(ack, error_ack("Unsupported message"));
+
+    let ack = receive_packet(&setup, br#"{"type":"TYPE_EXECUTE_TX"}"#);
+    assert_eq!(ack, error_ack("Invalid packet data"));
+}
@@ -0,0 +1,51 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           25
+// Async Callback (empty):               1
+// Total number of exported functions:  28
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    interchain_accounts
+    (
+        init => init
+        upgrade => upgrade
+        registerInterchainAccount => register_interchain_account
+        sendTx => send_tx
+        getControllerPort => get_controller_port
+        getActiveChannel => get_active_channel
+        getInterchainAccountAddress => get_interchain_account_address
+        allowCalls => allow_calls
+        disallowCalls => disallow_calls
+        isCallAllowed => is_call_allowed
+        getInterchainAccount => get_interchain_account
+        getAccountTemplate => account_template
+        onChanOpenInit => on_chan_open_init
+        onChanOpenTry => on_chan_open_try
+        onChanOpenAck => on_chan_open_ack
+        onChanOpenConfirm => on_chan_open_confirm
+        onChanCloseInit => on_chan_close_init
+        onChanCloseConfirm => on_chan_close_confirm
+        onChanUpgradeInit => on_chan_upgrade_init
+        onChanUpgradeTry => on_chan_upgrade_try
+        onChanUpgradeAck => on_chan_upgrade_ack
+        onChanUpgradeOpen => on_chan_upgrade_open
+        canTransitionToFlushComplete => can_transition_to_flush_complete
+        onRecvPacket => on_recv_packet
+        onAcknowledgementPacket => on_ack_packet
+        onTimeoutPacket => on_timeout_packet
+        getIbcHandler => ibc_handler
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
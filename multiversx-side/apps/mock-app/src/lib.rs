@@ -0,0 +1,246 @@
+#![no_std]
+
+use channel::{
+    channel_libs::packet_types::Packet,
+    interfaces::ibc_module_interface::{
+        MsgOnChanCloseConfirm, MsgOnChanCloseInit, MsgOnChanOpenAck, MsgOnChanOpenConfirm,
+        MsgOnChanOpenInit, MsgOnChanOpenTry, MsgOnChanUpgradeAck, MsgOnChanUpgradeInit,
+        MsgOnChanUpgradeOpen, MsgOnChanUpgradeTry,
+    },
+};
+use common_types::{ChannelId, PortId, Sequence, Version};
+
+multiversx_sc::imports!();
+
+pub static MOCK_APP_VERSION: &[u8] = b"mockapp-1";
+pub static SUCCESSFUL_ACK: &[u8] = b"mock acknowledgement";
+
+/// Packets carrying this payload are acknowledged asynchronously
+pub static ASYNC_ACK_DATA: &[u8] = b"async";
+
+/// Minimal IBC application used for testing the core handshake and packet flows.
+///
+/// Accepts any channel using `MOCK_APP_VERSION` and records every callback it receives.
+#[multiversx_sc::contract]
+pub trait MockApp {
+    #[init]
+    fn init(&self, ibc_handler: ManagedAddress) {
+        self.ibc_handler().set(ibc_handler);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    #[endpoint(onChanOpenInit)]
+    fn on_chan_open_init(&self, args: MsgOnChanOpenInit<Self::Api>) -> Version<Self::Api> {
+        self.require_ibc_handler_caller();
+        require!(
+            args.version.is_empty() || &args.version == MOCK_APP_VERSION,
+            "Unexpected version"
+        );
+
+        self.callbacks(&args.port_id, &args.channel_id)
+            .push(&ManagedBuffer::from(b"onChanOpenInit"));
+
+        Version::from(MOCK_APP_VERSION)
+    }
+
+    #[endpoint(onChanOpenTry)]
+    fn on_chan_open_try(&self, args: MsgOnChanOpenTry<Self::Api>) -> Version<Self::Api> {
+        self.require_ibc_handler_caller();
+        require!(
+            &args.counterparty_version == MOCK_APP_VERSION,
+            "Unexpected counterparty version"
+        );
+
+        self.callbacks(&args.port_id, &args.channel_id)
+            .push(&ManagedBuffer::from(b"onChanOpenTry"));
+
+        Version::from(MOCK_APP_VERSION)
+    }
+
+    #[endpoint(onChanOpenAck)]
+    fn on_chan_open_ack(&self, args: MsgOnChanOpenAck<Self::Api>) {
+        self.require_ibc_handler_caller();
+        require!(
+            &args.counterparty_version == MOCK_APP_VERSION,
+            "Unexpected counterparty version"
+        );
+
+        self.callbacks(&args.port_id, &args.channel_id)
+            .push(&ManagedBuffer::from(b"onChanOpenAck"));
+    }
+
+    #[endpoint(onChanOpenConfirm)]
+    fn on_chan_open_confirm(&self, args: MsgOnChanOpenConfirm<Self::Api>) {
+        self.require_ibc_handler_caller();
+
+        self.callbacks(&args.port_id, &args.channel_id)
+            .push(&ManagedBuffer::from(b"onChanOpenConfirm"));
+    }
+
+    #[endpoint(onChanCloseInit)]
+    fn on_chan_close_init(&self, args: MsgOnChanCloseInit<Self::Api>) {
+        self.require_ibc_handler_caller();
+
+        self.callbacks(&args.port_id, &args.channel_id)
+            .push(&ManagedBuffer::from(b"onChanCloseInit"));
+    }
+
+    #[endpoint(onChanCloseConfirm)]
+    fn on_chan_close_confirm(&self, args: MsgOnChanCloseConfirm<Self::Api>) {
+        self.require_ibc_handler_caller();
+
+        self.callbacks(&args.port_id, &args.channel_id)
+            .push(&ManagedBuffer::from(b"onChanCloseConfirm"));
+    }
+
+    #[endpoint(onChanUpgradeInit)]
+    fn on_chan_upgrade_init(&self, args: MsgOnChanUpgradeInit<Self::Api>) -> Version<Self::Api> {
+        self.require_ibc_handler_caller();
+        require!(!args.proposed_version.is_empty(), "Unexpected version");
+
+        self.callbacks(&args.port_id, &args.channel_id)
+            .push(&ManagedBuffer::from(b"onChanUpgradeInit"));
+
+        args.proposed_version
+    }
+
+    #[endpoint(onChanUpgradeTry)]
+    fn on_chan_upgrade_try(&self, args: MsgOnChanUpgradeTry<Self::Api>) -> Version<Self::Api> {
+        self.require_ibc_handler_caller();
+        require!(
+            !args.counterparty_version.is_empty(),
+            "Unexpected counterparty version"
+        );
+
+        self.callbacks(&args.port_id, &args.channel_id)
+            .push(&ManagedBuffer::from(b"onChanUpgradeTry"));
+
+        args.counterparty_version
+    }
+
+    #[endpoint(onChanUpgradeAck)]
+    fn on_chan_upgrade_ack(&self, args: MsgOnChanUpgradeAck<Self::Api>) {
+        self.require_ibc_handler_caller();
+
+        self.callbacks(&args.port_id, &args.channel_id)
+            .push(&ManagedBuffer::from(b"onChanUpgradeAck"));
+    }
+
+    #[endpoint(onChanUpgradeOpen)]
+    fn on_chan_upgrade_open(&self, args: MsgOnChanUpgradeOpen<Self::Api>) {
+        self.require_ibc_handler_caller();
+
+        self.callbacks(&args.port_id, &args.channel_id)
+            .push(&ManagedBuffer::from(b"onChanUpgradeOpen"));
+    }
+
+    #[view(canTransitionToFlushComplete)]
+    fn can_transition_to_flush_complete(
+        &self,
+        port_id: PortId<Self::Api>,
+        channel_id: ChannelId<Self::Api>,
+        _upgrade_sequence: Sequence,
+    ) -> bool {
+        !self.flush_blocked(&port_id, &channel_id).get()
+    }
+
+    /// Simulates packets still being in flight, preventing the channel from completing an upgrade flush
+    #[endpoint(setFlushBlocked)]
+    fn set_flush_blocked(
+        &self,
+        port_id: PortId<Self::Api>,
+        channel_id: ChannelId<Self::Api>,
+        blocked: bool,
+    ) {
+        self.flush_blocked(&port_id, &channel_id).set(blocked);
+    }
+
+    #[endpoint(onRecvPacket)]
+    fn on_recv_packet(&self, packet: Packet<Self::Api>, _relayer: ManagedAddress) -> ManagedBuffer {
+        self.require_ibc_handler_caller();
+
+        self.callbacks(&packet.dest_port, &packet.dest_channel)
+            .push(&ManagedBuffer::from(b"onRecvPacket"));
+
+        if &packet.data == ASYNC_ACK_DATA {
+            return ManagedBuffer::new();
+        }
+
+        ManagedBuffer::from(SUCCESSFUL_ACK)
+    }
+
+    #[endpoint(onAcknowledgementPacket)]
+    fn on_ack_packet(
+        &self,
+        packet: Packet<Self::Api>,
+        _ack: ManagedBuffer,
+        _relayer: ManagedAddress,
+    ) {
+        self.require_ibc_handler_caller();
+
+        self.callbacks(&packet.src_port, &packet.src_channel)
+            .push(&ManagedBuffer::from(b"onAcknowledgementPacket"));
+    }
+
+    #[endpoint(onTimeoutPacket)]
+    fn on_timeout_packet(&self, packet: Packet<Self::Api>, _relayer: ManagedAddress) {
+        self.require_ibc_handler_caller();
+
+        self.callbacks(&packet.src_port, &packet.src_channel)
+            .push(&ManagedBuffer::from(b"onTimeoutPacket"));
+    }
+
+    /// Records the ADR-8 callbacks of the packets naming the app in their memo
+    #[endpoint(ibcReceivePacketCallback)]
+    fn ibc_receive_packet_callback(&self, packet: Packet<Self::Api>, _ack: ManagedBuffer) {
+        self.callbacks(&packet.dest_port, &packet.dest_channel)
+            .push(&ManagedBuffer::from(b"ibcReceivePacketCallback"));
+    }
+
+    #[endpoint(ibcOnAcknowledgementPacketCallback)]
+    fn ibc_on_ack_packet_callback(
+        &self,
+        packet: Packet<Self::Api>,
+        _ack: ManagedBuffer,
+        _relayer: ManagedAddress,
+    ) {
+        self.callbacks(&packet.src_port, &packet.src_channel)
+            .push(&ManagedBuffer::from(b"ibcOnAcknowledgementPacketCallback"));
+    }
+
+    #[endpoint(ibcOnTimeoutPacketCallback)]
+    fn ibc_on_timeout_packet_callback(&self, packet: Packet<Self::Api>, _relayer: ManagedAddress) {
+        self.callbacks(&packet.src_port, &packet.src_channel)
+            .push(&ManagedBuffer::from(b"ibcOnTimeoutPacketCallback"));
+    }
+
+    fn require_ibc_handler_caller(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == self.ibc_handler().get(),
+            "Only the IBC handler may call this endpoint"
+        );
+    }
+
+    #[view(getIbcHandler)]
+    #[storage_mapper("ibcHandler")]
+    fn ibc_handler(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getCallbacks)]
+    #[storage_mapper("callbacks")]
+    fn callbacks(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+    ) -> VecMapper<ManagedBuffer>;
+
+    #[view(isFlushBlocked)]
+    #[storage_mapper("flushBlocked")]
+    fn flush_blocked(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+    ) -> SingleValueMapper<bool>;
+}
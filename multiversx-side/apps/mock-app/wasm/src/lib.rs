@@ -0,0 +1,47 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           21
+// Async Callback (empty):               1
+// Total number of exported functions:  24
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    mock_app
+    (
+        init => init
+        upgrade => upgrade
+        onChanOpenInit => on_chan_open_init
+        onChanOpenTry => on_chan_open_try
+        onChanOpenAck => on_chan_open_ack
+        onChanOpenConfirm => on_chan_open_confirm
+        onChanCloseInit => on_chan_close_init
+        onChanCloseConfirm => on_chan_close_confirm
+        onChanUpgradeInit => on_chan_upgrade_init
+        onChanUpgradeTry => on_chan_upgrade_try
+        onChanUpgradeAck => on_chan_upgrade_ack
+        onChanUpgradeOpen => on_chan_upgrade_open
+        canTransitionToFlushComplete => can_transition_to_flush_complete
+        setFlushBlocked => set_flush_blocked
+        onRecvPacket => on_recv_packet
+        onAcknowledgementPacket => on_ack_packet
+        onTimeoutPacket => on_timeout_packet
+        ibcReceivePacketCallback => ibc_receive_packet_callback
+        ibcOnAcknowledgementPacketCallback => ibc_on_ack_packet_callback
+        ibcOnTimeoutPacketCallback => ibc_on_timeout_packet_callback
+        getIbcHandler => ibc_handler
+        getCallbacks => callbacks
+        isFlushBlocked => flush_blocked
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
@@ -0,0 +1,240 @@
+use common_types::{trace, Hash};
+
+multiversx_sc::imports!();
+
+static IBC_CLASS_ID_PREFIX: &[u8] = b"ibc/";
+
+/// Tracks the classes of the NFTs received from other chains, and the voucher collections minted for them
+///
+/// A class trace is the path a class took to reach this chain, e.g. "nft-transfer/channel-0/kitties". Native
+/// collections use their token identifier as class ID, and the nonces of their tokens as token IDs.
+#[multiversx_sc::module]
+pub trait ClassModule {
+    /// Issues the voucher collection of `class_trace`, which is needed before the first token of the class can be
+    /// received
+    ///
+    /// Anyone can issue a voucher collection, by paying the issue cost. The contract gets all the roles of the
+    /// collection.
+    #[payable("EGLD")]
+    #[endpoint(issueVoucherCollection)]
+    fn issue_voucher_collection(
+        &self,
+        class_trace: ManagedBuffer,
+        collection_name: ManagedBuffer,
+        collection_ticker: ManagedBuffer,
+    ) {
+        require!(
+            trace::split_first_hop(&class_trace).is_some(),
+            "Invalid class trace"
+        );
+
+        let class_hash = self.class_hash(&class_trace);
+        require!(
+            self.voucher_collections(&class_hash).is_empty(),
+            "Voucher collection already issued"
+        );
+        require!(
+            !self.pending_voucher_issues().contains(&class_hash),
+            "Voucher collection issue in progress"
+        );
+
+        self.pending_voucher_issues().insert(class_hash.clone());
+        self.class_traces(&class_hash).set(&class_trace);
+
+        let issue_cost = self.call_value().egld_value().clone_value();
+        let caller = self.blockchain().get_caller();
+        self.send()
+            .esdt_system_sc_proxy()
+            .issue_and_set_all_roles(
+                issue_cost,
+                collection_name,
+                collection_ticker,
+                EsdtTokenType::NonFungible,
+                0,
+            )
+            .callback(
+                self.callbacks()
+                    .collection_issue_callback(class_hash, caller),
+            )
+            .async_call_and_exit();
+    }
+
+    #[callback]
+    fn collection_issue_callback(
+        &self,
+        class_hash: Hash<Self::Api>,
+        caller: ManagedAddress,
+        #[call_result] result: ManagedAsyncCallResult<TokenIdentifier>,
+    ) {
+        self.pending_voucher_issues().swap_remove(&class_hash);
+
+        match result {
+            ManagedAsyncCallResult::Ok(collection) => {
+                self.voucher_collections(&class_hash).set(&collection);
+                self.voucher_class_hashes(&collection).set(&class_hash);
+            }
+            ManagedAsyncCallResult::Err(_) => {
+                let returned_cost = self.call_value().egld_value().clone_value();
+                if returned_cost > 0 {
+                    self.send().direct_egld(&caller, &returned_cost);
+                }
+            }
+        }
+    }
+
+    /// "ibc/{hex(sha256(class_trace))}", the class ID ibc-go chains show for the same trace
+    #[view(getIbcClassId)]
+    fn get_ibc_class_id(&self, class_trace: ManagedBuffer) -> ManagedBuffer {
+        trace::hex_with_prefix(IBC_CLASS_ID_PREFIX, &self.class_hash(&class_trace))
+    }
+
+    /// Returns the voucher collection of `class_trace`, if it was issued
+    #[view(getVoucherCollection)]
+    fn get_voucher_collection(&self, class_trace: ManagedBuffer) -> OptionalValue<TokenIdentifier> {
+        let mapper = self.voucher_collections(&self.class_hash(&class_trace));
+        if mapper.is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(mapper.get())
+    }
+
+    /// The class ID of `collection` in packets: the trace for voucher collections, the token identifier otherwise
+    #[view(getClassId)]
+    fn get_class_id(&self, collection: &TokenIdentifier) -> ManagedBuffer {
+        let hash_mapper = self.voucher_class_hashes(collection);
+        if hash_mapper.is_empty() {
+            return collection.as_managed_buffer().clone();
+        }
+
+        self.class_traces(&hash_mapper.get()).get()
+    }
+
+    /// The token ID of an NFT in packets: the original ID for vouchers, the nonce otherwise
+    #[view(getTokenId)]
+    fn get_token_id(&self, collection: &TokenIdentifier, nonce: u64) -> ManagedBuffer {
+        let mapper = self.voucher_token_ids(collection, nonce);
+        if mapper.is_empty() {
+            return BigUint::from(nonce).to_display();
+        }
+
+        mapper.get()
+    }
+
+    /// The local collection of a class ID, as returned by `get_class_id`
+    fn get_local_collection(&self, class_id: &ManagedBuffer) -> Option<TokenIdentifier> {
+        if trace::split_first_hop(class_id).is_some() {
+            let mapper = self.voucher_collections(&self.class_hash(class_id));
+            if mapper.is_empty() {
+                return None;
+            }
+
+            return Some(mapper.get());
+        }
+
+        let collection = TokenIdentifier::from(class_id.clone());
+        if !collection.is_valid_esdt_identifier() {
+            return None;
+        }
+
+        Some(collection)
+    }
+
+    /// The local nonce of a token ID, as returned by `get_token_id`
+    fn get_local_nonce(&self, class_id: &ManagedBuffer, token_id: &ManagedBuffer) -> Option<u64> {
+        if trace::split_first_hop(class_id).is_some() {
+            let mapper = self.voucher_nonces(&self.class_hash(class_id), token_id);
+            if mapper.is_empty() {
+                return None;
+            }
+
+            return Some(mapper.get());
+        }
+
+        match common_types::json::parse_u64(token_id)? {
+            0 => None,
+            nonce => Some(nonce),
+        }
+    }
+
+    /// Mints the voucher of a token, preserving its URI and data
+    fn mint_voucher(
+        &self,
+        class_hash: &Hash<Self::Api>,
+        collection: &TokenIdentifier,
+        token_id: &ManagedBuffer,
+        token_uri: &ManagedBuffer,
+        token_data: &ManagedBuffer,
+    ) -> u64 {
+        let mut uris = ManagedVec::new();
+        if !token_uri.is_empty() {
+            uris.push(token_uri.clone());
+        }
+
+        let nonce = self.send().esdt_nft_create(
+            collection,
+            &BigUint::from(1u32),
+            token_id,
+            &BigUint::zero(),
+            &ManagedBuffer::new(),
+            token_data,
+            &uris,
+        );
+        self.voucher_nonces(class_hash, token_id).set(nonce);
+        self.voucher_token_ids(collection, nonce).set(token_id);
+
+        nonce
+    }
+
+    fn burn_voucher(&self, collection: &TokenIdentifier, nonce: u64) {
+        let class_hash = self.voucher_class_hashes(collection).get();
+        let token_id = self.voucher_token_ids(collection, nonce).take();
+        self.voucher_nonces(&class_hash, &token_id).clear();
+
+        self.send()
+            .esdt_local_burn(collection, nonce, &BigUint::from(1u32));
+    }
+
+    fn class_hash(&self, class_trace: &ManagedBuffer) -> Hash<Self::Api> {
+        self.crypto().sha256(class_trace)
+    }
+
+    #[storage_mapper("classTraces")]
+    fn class_traces(&self, class_hash: &Hash<Self::Api>) -> SingleValueMapper<ManagedBuffer>;
+
+    /// The URI and data of the classes received from other chains, as sent by their origin
+    #[storage_mapper("classMetadata")]
+    fn class_metadata(
+        &self,
+        class_hash: &Hash<Self::Api>,
+    ) -> SingleValueMapper<(ManagedBuffer, ManagedBuffer)>;
+
+    #[storage_mapper("voucherCollections")]
+    fn voucher_collections(
+        &self,
+        class_hash: &Hash<Self::Api>,
+    ) -> SingleValueMapper<TokenIdentifier>;
+
+    #[storage_mapper("voucherClassHashes")]
+    fn voucher_class_hashes(
+        &self,
+        collection: &TokenIdentifier,
+    ) -> SingleValueMapper<Hash<Self::Api>>;
+
+    #[storage_mapper("voucherNonces")]
+    fn voucher_nonces(
+        &self,
+        class_hash: &Hash<Self::Api>,
+        token_id: &ManagedBuffer,
+    ) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("voucherTokenIds")]
+    fn voucher_token_ids(
+        &self,
+        collection: &TokenIdentifier,
+        nonce: u64,
+    ) -> SingleValueMapper<ManagedBuffer>;
+
+    #[storage_mapper("pendingVoucherIssues")]
+    fn pending_voucher_issues(&self) -> UnorderedSetMapper<Hash<Self::Api>>;
+}
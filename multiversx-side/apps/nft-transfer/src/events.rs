@@ -0,0 +1,32 @@
+use common_types::{ChannelId, Sequence};
+
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait EventsModule {
+    #[event("nftTransferEvent")]
+    fn nft_transfer_event(
+        &self,
+        #[indexed] source_channel: &ChannelId<Self::Api>,
+        #[indexed] seq: Sequence,
+        #[indexed] sender: &ManagedAddress,
+        packet_data: &ManagedBuffer,
+    );
+
+    #[event("receiveNftTransferEvent")]
+    fn receive_nft_transfer_event(
+        &self,
+        #[indexed] dest_channel: &ChannelId<Self::Api>,
+        #[indexed] seq: Sequence,
+        #[indexed] success: bool,
+        ack: &ManagedBuffer,
+    );
+
+    #[event("refundEvent")]
+    fn refund_event(
+        &self,
+        #[indexed] source_channel: &ChannelId<Self::Api>,
+        #[indexed] seq: Sequence,
+        #[indexed] sender: &ManagedAddress,
+    );
+}
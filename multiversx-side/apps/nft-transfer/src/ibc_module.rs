@@ -0,0 +1,158 @@
+use channel::{
+    channel_libs::packet_types::Packet,
+    interfaces::ibc_module_interface::{
+        MsgOnChanCloseConfirm, MsgOnChanCloseInit, MsgOnChanOpenAck, MsgOnChanOpenConfirm,
+        MsgOnChanOpenInit, MsgOnChanOpenTry, MsgOnChanUpgradeAck, MsgOnChanUpgradeInit,
+        MsgOnChanUpgradeOpen, MsgOnChanUpgradeTry,
+    },
+};
+use common_types::{
+    channel_types::{acknowledgement, channel::Order},
+    ChannelId, PortId, Sequence, Version,
+};
+
+multiversx_sc::imports!();
+
+pub static ICS721_VERSION: &[u8] = b"ics721-1";
+
+static UNORDERED_CHANNEL_ERR_MSG: &[u8] = b"NFT transfer channels must be unordered";
+static INVALID_VERSION_ERR_MSG: &[u8] = b"Invalid version";
+
+/// The callbacks of the IBC handler, which may only be called by it
+#[multiversx_sc::module]
+pub trait IbcModule:
+    crate::relay::RelayModule + crate::class::ClassModule + crate::events::EventsModule
+{
+    #[endpoint(onChanOpenInit)]
+    fn on_chan_open_init(&self, args: MsgOnChanOpenInit<Self::Api>) -> Version<Self::Api> {
+        self.require_ibc_handler_caller();
+        self.require_unordered(args.order);
+        require!(
+            args.version.is_empty() || &args.version == ICS721_VERSION,
+            INVALID_VERSION_ERR_MSG
+        );
+
+        Version::from(ICS721_VERSION)
+    }
+
+    #[endpoint(onChanOpenTry)]
+    fn on_chan_open_try(&self, args: MsgOnChanOpenTry<Self::Api>) -> Version<Self::Api> {
+        self.require_ibc_handler_caller();
+        self.require_unordered(args.order);
+        require!(
+            &args.counterparty_version == ICS721_VERSION,
+            INVALID_VERSION_ERR_MSG
+        );
+
+        Version::from(ICS721_VERSION)
+    }
+
+    #[endpoint(onChanOpenAck)]
+    fn on_chan_open_ack(&self, args: MsgOnChanOpenAck<Self::Api>) {
+        self.require_ibc_handler_caller();
+        require!(
+            &args.counterparty_version == ICS721_VERSION,
+            INVALID_VERSION_ERR_MSG
+        );
+    }
+
+    #[endpoint(onChanOpenConfirm)]
+    fn on_chan_open_confirm(&self, _args: MsgOnChanOpenConfirm<Self::Api>) {
+        self.require_ibc_handler_caller();
+    }
+
+    /// Closing a transfer channel would lock the tokens escrowed for it
+    #[endpoint(onChanCloseInit)]
+    fn on_chan_close_init(&self, _args: MsgOnChanCloseInit<Self::Api>) {
+        sc_panic!("NFT transfer channels cannot be closed");
+    }
+
+    #[endpoint(onChanCloseConfirm)]
+    fn on_chan_close_confirm(&self, _args: MsgOnChanCloseConfirm<Self::Api>) {
+        self.require_ibc_handler_caller();
+    }
+
+    #[endpoint(onChanUpgradeInit)]
+    fn on_chan_upgrade_init(&self, args: MsgOnChanUpgradeInit<Self::Api>) -> Version<Self::Api> {
+        self.require_ibc_handler_caller();
+        self.require_unordered(args.proposed_order);
+        require!(
+            args.proposed_version.is_empty() || &args.proposed_version == ICS721_VERSION,
+            INVALID_VERSION_ERR_MSG
+        );
+
+        Version::from(ICS721_VERSION)
+    }
+
+    #[endpoint(onChanUpgradeTry)]
+    fn on_chan_upgrade_try(&self, args: MsgOnChanUpgradeTry<Self::Api>) -> Version<Self::Api> {
+        self.require_ibc_handler_caller();
+        self.require_unordered(args.proposed_order);
+        require!(
+            &args.counterparty_version == ICS721_VERSION,
+            INVALID_VERSION_ERR_MSG
+        );
+
+        Version::from(ICS721_VERSION)
+    }
+
+    #[endpoint(onChanUpgradeAck)]
+    fn on_chan_upgrade_ack(&self, args: MsgOnChanUpgradeAck<Self::Api>) {
+        self.require_ibc_handler_caller();
+        require!(
+            &args.counterparty_version == ICS721_VERSION,
+            INVALID_VERSION_ERR_MSG
+        );
+    }
+
+    #[endpoint(onChanUpgradeOpen)]
+    fn on_chan_upgrade_open(&self, _args: MsgOnChanUpgradeOpen<Self::Api>) {
+        self.require_ibc_handler_caller();
+    }
+
+    /// Transfers settle when the packet is handled, so there is never anything left to flush
+    #[view(canTransitionToFlushComplete)]
+    fn can_transition_to_flush_complete(
+        &self,
+        _port_id: PortId<Self::Api>,
+        _channel_id: ChannelId<Self::Api>,
+        _upgrade_sequence: Sequence,
+    ) -> bool {
+        true
+    }
+
+    #[endpoint(onRecvPacket)]
+    fn on_recv_packet(&self, packet: Packet<Self::Api>, _relayer: ManagedAddress) -> ManagedBuffer {
+        self.require_ibc_handler_caller();
+
+        self.receive_nft_transfer(&packet)
+    }
+
+    /// Refunds the sender if the transfer failed on the receiving chain
+    #[endpoint(onAcknowledgementPacket)]
+    fn on_ack_packet(
+        &self,
+        packet: Packet<Self::Api>,
+        ack: ManagedBuffer,
+        _relayer: ManagedAddress,
+    ) {
+        self.require_ibc_handler_caller();
+
+        match acknowledgement::is_successful(&ack) {
+            Some(true) => {}
+            Some(false) => self.refund_nft_transfer(&packet),
+            None => sc_panic!("Invalid acknowledgement"),
+        }
+    }
+
+    #[endpoint(onTimeoutPacket)]
+    fn on_timeout_packet(&self, packet: Packet<Self::Api>, _relayer: ManagedAddress) {
+        self.require_ibc_handler_caller();
+
+        self.refund_nft_transfer(&packet);
+    }
+
+    fn require_unordered(&self, order: Order) {
+        require!(matches!(order, Order::Unordered), UNORDERED_CHANNEL_ERR_MSG);
+    }
+}
@@ -0,0 +1,26 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod class;
+pub mod events;
+pub mod ibc_module;
+pub mod packet_data;
+pub mod relay;
+
+/// ICS-721 non-fungible token transfer application, bound to the `nft-transfer` port of the IBC handler.
+///
+/// Native NFTs and SFTs are escrowed by collection and nonce when sent, and released when they come back. Tokens
+/// received from other chains are minted as voucher NFTs, keeping their URI and data, and are burnt when sent back.
+#[multiversx_sc::contract]
+pub trait NftTransfer:
+    class::ClassModule + relay::RelayModule + ibc_module::IbcModule + events::EventsModule
+{
+    #[init]
+    fn init(&self, ibc_handler: ManagedAddress) {
+        self.ibc_handler().set(ibc_handler);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+}
@@ -0,0 +1,122 @@
+use common_types::{
+    base64,
+    json::{JsonObject, JsonObjectBuilder},
+};
+
+multiversx_sc::imports!();
+
+/// ICS-721 `NonFungibleTokenPacketData`, JSON encoded with sorted keys
+///
+/// `class_data` and `token_data` hold the decoded bytes, which are base64 encoded in JSON. `token_uris` and `token_data`
+/// are either empty or have an entry for every token.
+pub struct NonFungibleTokenPacketData<M: ManagedTypeApi> {
+    pub class_id: ManagedBuffer<M>,
+    pub class_uri: ManagedBuffer<M>,
+    pub class_data: ManagedBuffer<M>,
+    pub token_ids: ManagedVec<M, ManagedBuffer<M>>,
+    pub token_uris: ManagedVec<M, ManagedBuffer<M>>,
+    pub token_data: ManagedVec<M, ManagedBuffer<M>>,
+    pub sender: ManagedBuffer<M>,
+    pub receiver: ManagedBuffer<M>,
+    pub memo: ManagedBuffer<M>,
+}
+
+impl<M: ManagedTypeApi> NonFungibleTokenPacketData<M> {
+    pub fn to_json(&self) -> ManagedBuffer<M> {
+        let mut builder = JsonObjectBuilder::new()
+            .optional_string(b"classData", &base64::encode(&self.class_data))
+            .string(b"classId", &self.class_id)
+            .optional_string(b"classUri", &self.class_uri)
+            .optional_string(b"memo", &self.memo)
+            .string(b"receiver", &self.receiver)
+            .string(b"sender", &self.sender);
+        if !self.token_data.is_empty() {
+            let mut encoded_data = ManagedVec::new();
+            for data in &self.token_data {
+                encoded_data.push(base64::encode(&data));
+            }
+            builder = builder.string_array(b"tokenData", &encoded_data);
+        }
+        builder = builder.string_array(b"tokenIds", &self.token_ids);
+        if !self.token_uris.is_empty() {
+            builder = builder.string_array(b"tokenUris", &self.token_uris);
+        }
+
+        builder.build()
+    }
+
+    /// Returns `None` if the data isn't valid, in which case the packet must be answered with an error acknowledgement
+    pub fn from_json(encoded: &ManagedBuffer<M>) -> Option<Self> {
+        let object = JsonObject::parse(encoded)?;
+        let mut token_data = ManagedVec::new();
+        for data in &optional_string_array(&object, b"tokenData")? {
+            token_data.push(base64::decode(&data)?);
+        }
+        let data = NonFungibleTokenPacketData {
+            class_id: object.get_string(b"classId")?,
+            class_uri: optional_string(&object, b"classUri")?,
+            class_data: base64::decode(&optional_string(&object, b"classData")?)?,
+            token_ids: object.get_string_array(b"tokenIds")?,
+            token_uris: optional_string_array(&object, b"tokenUris")?,
+            token_data,
+            sender: object.get_string(b"sender")?,
+            receiver: object.get_string(b"receiver")?,
+            memo: optional_string(&object, b"memo")?,
+        };
+        if !data.is_valid() {
+            return None;
+        }
+
+        Some(data)
+    }
+
+    fn is_valid(&self) -> bool {
+        let tokens_len = self.token_ids.len();
+        if self.class_id.is_empty()
+            || self.sender.is_empty()
+            || self.receiver.is_empty()
+            || tokens_len == 0
+            || (!self.token_uris.is_empty() && self.token_uris.len() != tokens_len)
+            || (!self.token_data.is_empty() && self.token_data.len() != tokens_len)
+        {
+            return false;
+        }
+
+        for (i, token_id) in self.token_ids.iter().enumerate() {
+            if token_id.is_empty() {
+                return false;
+            }
+
+            for other_token_id in self.token_ids.iter().skip(i + 1) {
+                if *token_id == *other_token_id {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+}
+
+/// Returns an empty string if the field is missing, `None` if it isn't a string
+fn optional_string<M: ManagedTypeApi>(
+    object: &JsonObject<M>,
+    key: &[u8],
+) -> Option<ManagedBuffer<M>> {
+    if !object.contains(key) {
+        return Some(ManagedBuffer::new());
+    }
+
+    object.get_string(key)
+}
+
+fn optional_string_array<M: ManagedTypeApi>(
+    object: &JsonObject<M>,
+    key: &[u8],
+) -> Option<ManagedVec<M, ManagedBuffer<M>>> {
+    if !object.contains(key) {
+        return Some(ManagedVec::new());
+    }
+
+    object.get_string_array(key)
+}
@@ -0,0 +1,333 @@
+use channel::{
+    channel_libs::packet_types::Packet, interfaces::ibc_handler_interface::ibc_handler_proxy,
+};
+use common_types::{
+    bech32, channel_types::acknowledgement, channel_types::height, trace, ChannelId, PortId,
+    Sequence, UnixTimestamp,
+};
+
+use crate::packet_data::NonFungibleTokenPacketData;
+
+multiversx_sc::imports!();
+
+pub static NFT_TRANSFER_PORT_ID: &[u8] = b"nft-transfer";
+
+static INVALID_PACKET_DATA_ERR_MSG: &[u8] = b"Invalid packet data";
+static INVALID_RECEIVER_ERR_MSG: &[u8] = b"Invalid receiver";
+static UNKNOWN_CLASS_ERR_MSG: &[u8] = b"Unknown class";
+static UNKNOWN_TOKEN_ERR_MSG: &[u8] = b"Unknown token";
+static TOKEN_NOT_ESCROWED_ERR_MSG: &[u8] = b"Token not escrowed";
+static TOKEN_ALREADY_RECEIVED_ERR_MSG: &[u8] = b"Token already received";
+static VOUCHER_NOT_ISSUED_ERR_MSG: &[u8] = b"Voucher collection not issued";
+
+/// Moves the NFTs of the transfers: escrow and burn when sending, unescrow and mint when receiving or refunding
+///
+/// SFTs are moved one unit at a time, so that every token ID in a packet stands for a single token.
+#[multiversx_sc::module]
+pub trait RelayModule: crate::class::ClassModule + crate::events::EventsModule {
+    /// Sends the paid NFTs, which must all be of the same collection, to `receiver` on the chain at the other end of
+    /// `source_channel`
+    ///
+    /// Native tokens are escrowed, while vouchers going back to their origin are burnt.
+    ///
+    /// Returns the sequence of the packet.
+    #[payable("*")]
+    #[endpoint]
+    fn transfer(
+        &self,
+        source_channel: ChannelId<Self::Api>,
+        receiver: ManagedBuffer,
+        timeout_height: height::Data,
+        timeout_timestamp: UnixTimestamp,
+        opt_memo: OptionalValue<ManagedBuffer>,
+    ) -> Sequence {
+        let payments = self.call_value().all_esdt_transfers().clone_value();
+        require!(!payments.is_empty(), "No tokens to transfer");
+        require!(!receiver.is_empty(), "Empty receiver");
+
+        let source_port = PortId::from(NFT_TRANSFER_PORT_ID);
+        let collection = payments.get(0).token_identifier;
+        let class_id = self.get_class_id(&collection);
+        let is_returning_voucher =
+            self.is_returning_voucher(&class_id, &source_port, &source_channel);
+        let (class_uri, class_data) = self.get_class_metadata(&class_id);
+
+        let sc_address = self.blockchain().get_sc_address();
+        let mut token_ids = ManagedVec::new();
+        let mut token_uris = ManagedVec::new();
+        let mut token_data = ManagedVec::new();
+        let mut has_uris = false;
+        let mut has_data = false;
+        for payment in &payments {
+            require!(
+                payment.token_identifier == collection,
+                "All tokens must be of the same collection"
+            );
+            require!(
+                payment.token_nonce > 0,
+                "Only NFTs and SFTs can be transferred"
+            );
+            require!(
+                payment.amount == 1u32,
+                "Only one unit of each token can be transferred"
+            );
+
+            let token_id = self.get_token_id(&collection, payment.token_nonce);
+            require!(!token_ids.contains(&token_id), "Duplicate token");
+
+            let esdt_data = self.blockchain().get_esdt_token_data(
+                &sc_address,
+                &collection,
+                payment.token_nonce,
+            );
+            let uri = get_or_empty(&esdt_data.uris, 0);
+            has_uris |= !uri.is_empty();
+            has_data |= !esdt_data.attributes.is_empty();
+
+            token_ids.push(token_id);
+            token_uris.push(uri);
+            token_data.push(esdt_data.attributes);
+
+            if is_returning_voucher {
+                self.burn_voucher(&collection, payment.token_nonce);
+            } else {
+                self.channel_escrow(&source_channel, &collection, payment.token_nonce)
+                    .update(|escrowed| *escrowed += 1u32);
+            }
+        }
+
+        let caller = self.blockchain().get_caller();
+        let packet_data = NonFungibleTokenPacketData {
+            class_id,
+            class_uri,
+            class_data,
+            token_ids,
+            token_uris: if has_uris {
+                token_uris
+            } else {
+                ManagedVec::new()
+            },
+            token_data: if has_data {
+                token_data
+            } else {
+                ManagedVec::new()
+            },
+            sender: bech32::encode_address(&caller),
+            receiver,
+            memo: opt_memo.into_option().unwrap_or_default(),
+        }
+        .to_json();
+
+        let ibc_handler = self.ibc_handler().get();
+        let seq: Sequence = self
+            .ibc_handler_proxy(ibc_handler)
+            .send_packet(
+                source_port,
+                source_channel.clone(),
+                timeout_height,
+                timeout_timestamp,
+                packet_data.clone(),
+            )
+            .execute_on_dest_context();
+
+        self.nft_transfer_event(&source_channel, seq, &caller, &packet_data);
+
+        seq
+    }
+
+    /// Credits the receiver of the packet, returning the acknowledgement
+    ///
+    /// Invalid packets are answered with an error acknowledgement instead of failing, so that the sender gets refunded.
+    fn receive_nft_transfer(&self, packet: &Packet<Self::Api>) -> ManagedBuffer {
+        let ack = match self.try_receive_nft_transfer(packet) {
+            Ok(()) => acknowledgement::new_successful(),
+            Err(err_msg) => acknowledgement::new_error(err_msg),
+        };
+
+        self.receive_nft_transfer_event(
+            &packet.dest_channel,
+            packet.seq,
+            acknowledgement::is_successful(&ack) == Some(true),
+            &ack,
+        );
+
+        ack
+    }
+
+    /// Checks every token before moving any of them, so that a failed packet changes nothing
+    fn try_receive_nft_transfer(&self, packet: &Packet<Self::Api>) -> Result<(), &'static [u8]> {
+        let data = NonFungibleTokenPacketData::from_json(&packet.data)
+            .ok_or(INVALID_PACKET_DATA_ERR_MSG)?;
+        let receiver = bech32::decode_address(&data.receiver).ok_or(INVALID_RECEIVER_ERR_MSG)?;
+
+        // the tokens come back to this chain, so they were escrowed when they were sent
+        if let Some(local_class_id) =
+            trace::strip_hop_prefix(&data.class_id, &packet.src_port, &packet.src_channel)
+        {
+            let collection = self
+                .get_local_collection(&local_class_id)
+                .ok_or(UNKNOWN_CLASS_ERR_MSG)?;
+            let mut nonces = ManagedVec::<Self::Api, u64>::new();
+            for token_id in &data.token_ids {
+                let nonce = self
+                    .get_local_nonce(&local_class_id, &token_id)
+                    .ok_or(UNKNOWN_TOKEN_ERR_MSG)?;
+                if self
+                    .channel_escrow(&packet.dest_channel, &collection, nonce)
+                    .is_empty()
+                {
+                    return Err(TOKEN_NOT_ESCROWED_ERR_MSG);
+                }
+
+                nonces.push(nonce);
+            }
+
+            for nonce in &nonces {
+                self.channel_escrow(&packet.dest_channel, &collection, nonce)
+                    .update(|escrowed| *escrowed -= 1u32);
+                self.send()
+                    .direct_esdt(&receiver, &collection, nonce, &BigUint::from(1u32));
+            }
+
+            return Ok(());
+        }
+
+        let mut class_trace = trace::hop_prefix(&packet.dest_port, &packet.dest_channel);
+        class_trace.append(&data.class_id);
+        let class_hash = self.class_hash(&class_trace);
+        let metadata_mapper = self.class_metadata(&class_hash);
+        if metadata_mapper.is_empty() {
+            metadata_mapper.set((data.class_uri.clone(), data.class_data.clone()));
+        }
+
+        let collection_mapper = self.voucher_collections(&class_hash);
+        if collection_mapper.is_empty() {
+            self.class_traces(&class_hash).set(&class_trace);
+            return Err(VOUCHER_NOT_ISSUED_ERR_MSG);
+        }
+
+        for token_id in &data.token_ids {
+            if !self.voucher_nonces(&class_hash, &token_id).is_empty() {
+                return Err(TOKEN_ALREADY_RECEIVED_ERR_MSG);
+            }
+        }
+
+        let collection = collection_mapper.get();
+        for (i, token_id) in data.token_ids.iter().enumerate() {
+            let nonce = self.mint_voucher(
+                &class_hash,
+                &collection,
+                &token_id,
+                &get_or_empty(&data.token_uris, i),
+                &get_or_empty(&data.token_data, i),
+            );
+            self.send()
+                .direct_esdt(&receiver, &collection, nonce, &BigUint::from(1u32));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the tokens of a packet which failed to the sender, as they were before `transfer`
+    fn refund_nft_transfer(&self, packet: &Packet<Self::Api>) {
+        let data = match NonFungibleTokenPacketData::from_json(&packet.data) {
+            Some(data) => data,
+            None => sc_panic!(INVALID_PACKET_DATA_ERR_MSG),
+        };
+        let sender = match bech32::decode_address(&data.sender) {
+            Some(sender) => sender,
+            None => sc_panic!("Invalid sender"),
+        };
+        let collection = match self.get_local_collection(&data.class_id) {
+            Some(collection) => collection,
+            None => sc_panic!(UNKNOWN_CLASS_ERR_MSG),
+        };
+
+        let is_returning_voucher =
+            self.is_returning_voucher(&data.class_id, &packet.src_port, &packet.src_channel);
+        let class_hash = self.class_hash(&data.class_id);
+        for (i, token_id) in data.token_ids.iter().enumerate() {
+            let nonce = if is_returning_voucher {
+                self.mint_voucher(
+                    &class_hash,
+                    &collection,
+                    &token_id,
+                    &get_or_empty(&data.token_uris, i),
+                    &get_or_empty(&data.token_data, i),
+                )
+            } else {
+                let nonce = match self.get_local_nonce(&data.class_id, &token_id) {
+                    Some(nonce) => nonce,
+                    None => sc_panic!(UNKNOWN_TOKEN_ERR_MSG),
+                };
+                self.channel_escrow(&packet.src_channel, &collection, nonce)
+                    .update(|escrowed| *escrowed -= 1u32);
+
+                nonce
+            };
+
+            self.send()
+                .direct_esdt(&sender, &collection, nonce, &BigUint::from(1u32));
+        }
+
+        self.refund_event(&packet.src_channel, packet.seq, &sender);
+    }
+
+    /// Vouchers sent back over the channel they were received on are burnt instead of escrowed
+    fn is_returning_voucher(
+        &self,
+        class_id: &ManagedBuffer,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+    ) -> bool {
+        trace::strip_hop_prefix(class_id, port_id, channel_id).is_some()
+    }
+
+    /// The URI and data of a class, which are only known for classes received from other chains
+    fn get_class_metadata(&self, class_id: &ManagedBuffer) -> (ManagedBuffer, ManagedBuffer) {
+        let mapper = self.class_metadata(&self.class_hash(class_id));
+        if mapper.is_empty() {
+            return (ManagedBuffer::new(), ManagedBuffer::new());
+        }
+
+        mapper.get()
+    }
+
+    fn require_ibc_handler_caller(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == self.ibc_handler().get(),
+            "Only the IBC handler may call this endpoint"
+        );
+    }
+
+    #[proxy]
+    fn ibc_handler_proxy(
+        &self,
+        sc_address: ManagedAddress,
+    ) -> ibc_handler_proxy::IbcHandlerProxy<Self::Api>;
+
+    #[view(getIbcHandler)]
+    #[storage_mapper("ibcHandler")]
+    fn ibc_handler(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// The amount of each NFT or SFT escrowed for the transfers sent on a channel
+    #[view(getChannelEscrow)]
+    #[storage_mapper("channelEscrow")]
+    fn channel_escrow(
+        &self,
+        channel_id: &ChannelId<Self::Api>,
+        collection: &TokenIdentifier,
+        nonce: u64,
+    ) -> SingleValueMapper<BigUint>;
+}
+
+fn get_or_empty<M: ManagedTypeApi>(
+    values: &ManagedVec<M, ManagedBuffer<M>>,
+    index: usize,
+) -> ManagedBuffer<M> {
+    match values.try_get(index) {
+        Some(value) => value.clone_value(),
+        None => ManagedBuffer::new(),
+    }
+}
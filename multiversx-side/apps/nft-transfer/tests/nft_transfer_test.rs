@@ -0,0 +1,678 @@
+use std::{cell::RefCell, rc::Rc};
+
+use ::channel::{
+    interfaces::ibc_module_interface::{MsgOnChanCloseInit, MsgOnChanOpenTry},
+    packet_handling::encoding::EncodingModule,
+};
+use common_types::{
+    channel_types::{acknowledgement, channel, channel_counterparty},
+    ConnectionHops,
+};
+use host::{commitment::CommitmentModule, storage::StorageModule};
+use multiversx_sc_scenario::{
+    imports::{BlockchainStateWrapper, OptionalValue, TxTokenTransfer},
+    managed_address, managed_buffer, managed_token_id, rust_biguint, DebugApi,
+};
+use nft_transfer::{
+    class::ClassModule, ibc_module::IbcModule, relay::RelayModule, relay::NFT_TRANSFER_PORT_ID,
+};
+use nft_transfer_setup::*;
+use test_utils::bech32_address;
+
+pub mod nft_transfer_setup;
+
+fn get_nft_transfer_setup() -> NftTransferSetup<
+    impl 'static + Copy + Fn() -> ibc_handler::ContractObj<DebugApi>,
+    impl 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    impl 'static + Copy + Fn() -> nft_transfer::ContractObj<DebugApi>,
+> {
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_biguint!(0));
+    NftTransferSetup::new(
+        Rc::new(RefCell::new(b_mock)),
+        &owner,
+        ibc_handler::contract_obj,
+        mock::contract_obj,
+        nft_transfer::contract_obj,
+    )
+}
+
+fn nfts(collection: &[u8], nonces: &[u64]) -> Vec<TxTokenTransfer> {
+    nonces
+        .iter()
+        .map(|nonce| TxTokenTransfer {
+            token_identifier: collection.to_vec(),
+            nonce: *nonce,
+            value: rust_biguint!(1),
+        })
+        .collect()
+}
+
+fn transfer_nfts<IbcHandlerObjBuilder, MockClientObjBuilder, NftTransferObjBuilder>(
+    setup: &NftTransferSetup<IbcHandlerObjBuilder, MockClientObjBuilder, NftTransferObjBuilder>,
+    payments: &[TxTokenTransfer],
+    expected_seq: u64,
+) where
+    IbcHandlerObjBuilder: 'static + Copy + Fn() -> ibc_handler::ContractObj<DebugApi>,
+    MockClientObjBuilder: 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    NftTransferObjBuilder: 'static + Copy + Fn() -> nft_transfer::ContractObj<DebugApi>,
+{
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_esdt_multi_transfer(&setup.user, &setup.nft_transfer_wrapper, payments, |sc| {
+            let seq = sc.transfer(
+                managed_buffer!(CHANNEL_ID),
+                managed_buffer!(COUNTERPARTY_RECEIVER),
+                TIMEOUT_HEIGHT,
+                0,
+                OptionalValue::None,
+            );
+            assert_eq!(seq, expected_seq);
+        })
+        .assert_ok();
+}
+
+/// Delivers a packet from the counterparty, returning the acknowledgement of the NFT transfer app
+fn receive_packet<IbcHandlerObjBuilder, MockClientObjBuilder, NftTransferObjBuilder>(
+    setup: &NftTransferSetup<IbcHandlerObjBuilder, MockClientObjBuilder, NftTransferObjBuilder>,
+    data: &[u8],
+) -> Vec<u8>
+where
+    IbcHandlerObjBuilder: 'static + Copy + Fn() -> ibc_handler::ContractObj<DebugApi>,
+    MockClientObjBuilder: 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    NftTransferObjBuilder: 'static + Copy + Fn() -> nft_transfer::ContractObj<DebugApi>,
+{
+    let ibc_handler_address = setup.ibc_handler_wrapper.address_ref().clone();
+    let mut ack = Vec::new();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.nft_transfer_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let result = sc.on_recv_packet(
+                    incoming_packet(1, data),
+                    managed_address!(&ibc_handler_address),
+                );
+                ack = result.to_boxed_bytes().into_vec();
+            },
+        )
+        .assert_ok();
+
+    ack
+}
+
+fn successful_ack() -> Vec<u8> {
+    br#"{"result":"AQ=="}"#.to_vec()
+}
+
+fn error_ack(error: &str) -> Vec<u8> {
+    format!(r#"{{"error":"{}"}}"#, error).into_bytes()
+}
+
+fn receiver() -> &'static str {
+    std::str::from_utf8(COUNTERPARTY_RECEIVER).unwrap()
+}
+
+#[test]
+fn transfer_native_nfts_test() {
+    let setup = get_nft_transfer_setup();
+    let user_bech32 = bech32_address(&setup.b_mock, &setup.nft_transfer_wrapper, &setup.user);
+    let nft_transfer_address = setup.nft_transfer_wrapper.address_ref().clone();
+
+    transfer_nfts(&setup, &nfts(NATIVE_COLLECTION, &[1, 2]), 1);
+
+    let b_mock = setup.b_mock.borrow();
+    for nonce in [1, 2] {
+        b_mock.check_nft_balance(
+            &setup.user,
+            NATIVE_COLLECTION,
+            nonce,
+            &rust_biguint!(0),
+            Option::<&Vec<u8>>::None,
+        );
+        b_mock.check_nft_balance(
+            &nft_transfer_address,
+            NATIVE_COLLECTION,
+            nonce,
+            &rust_biguint!(1),
+            Some(&native_attributes(nonce)),
+        );
+    }
+    drop(b_mock);
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.nft_transfer_wrapper, |sc| {
+            for nonce in [1, 2] {
+                let escrowed = sc
+                    .channel_escrow(
+                        &managed_buffer!(CHANNEL_ID),
+                        &managed_token_id!(NATIVE_COLLECTION),
+                        nonce,
+                    )
+                    .get();
+                assert_eq!(escrowed, 1u64);
+            }
+        })
+        .assert_ok();
+
+    // the token URIs and the base64 encoded attributes are part of the packet data
+    let data = format!(
+        r#"{{"classId":"KITTY-123456","receiver":"{}","sender":"{}","tokenData":["a2l0dHkgIzE=","a2l0dHkgIzI="],"tokenIds":["1","2"],"tokenUris":["{}","{}"]}}"#,
+        receiver(),
+        user_bech32,
+        native_uri(1),
+        native_uri(2)
+    )
+    .into_bytes();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.ibc_handler_wrapper, |sc| {
+            let commitment_key = sc.get_packet_commitment_key(
+                &managed_buffer!(NFT_TRANSFER_PORT_ID),
+                &managed_buffer!(CHANNEL_ID),
+                1,
+            );
+            assert_eq!(
+                sc.commitments(&commitment_key).get(),
+                sc.encode_and_hash_twice(TIMEOUT_HEIGHT, 0, &managed_buffer!(&data))
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn transfer_sft_test() {
+    let setup = get_nft_transfer_setup();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_esdt_transfer(
+            &setup.user,
+            &setup.nft_transfer_wrapper,
+            NATIVE_SFT_COLLECTION,
+            1,
+            &rust_biguint!(2),
+            |sc| {
+                sc.transfer(
+                    managed_buffer!(CHANNEL_ID),
+                    managed_buffer!(COUNTERPARTY_RECEIVER),
+                    TIMEOUT_HEIGHT,
+                    0,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error("Only one unit of each token can be transferred");
+
+    transfer_nfts(&setup, &nfts(NATIVE_SFT_COLLECTION, &[1]), 1);
+    transfer_nfts(&setup, &nfts(NATIVE_SFT_COLLECTION, &[1]), 2);
+
+    setup.b_mock.borrow().check_nft_balance(
+        &setup.user,
+        NATIVE_SFT_COLLECTION,
+        1,
+        &rust_biguint!(SFT_BALANCE - 2),
+        Option::<&Vec<u8>>::None,
+    );
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.nft_transfer_wrapper, |sc| {
+            let escrowed = sc
+                .channel_escrow(
+                    &managed_buffer!(CHANNEL_ID),
+                    &managed_token_id!(NATIVE_SFT_COLLECTION),
+                    1,
+                )
+                .get();
+            assert_eq!(escrowed, 2u64);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn transfer_mixed_collections_test() {
+    let setup = get_nft_transfer_setup();
+    let mut payments = nfts(NATIVE_COLLECTION, &[1]);
+    payments.extend(nfts(NATIVE_SFT_COLLECTION, &[1]));
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_esdt_multi_transfer(&setup.user, &setup.nft_transfer_wrapper, &payments, |sc| {
+            sc.transfer(
+                managed_buffer!(CHANNEL_ID),
+                managed_buffer!(COUNTERPARTY_RECEIVER),
+                TIMEOUT_HEIGHT,
+                0,
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("All tokens must be of the same collection");
+}
+
+#[test]
+fn receive_returning_native_nfts_test() {
+    let setup = get_nft_transfer_setup();
+    let user_bech32 = bech32_address(&setup.b_mock, &setup.nft_transfer_wrapper, &setup.user);
+    transfer_nfts(&setup, &nfts(NATIVE_COLLECTION, &[1, 2]), 1);
+
+    let ack = receive_packet(
+        &setup,
+        &packet_data(
+            "nft-transfer/channel-5/KITTY-123456",
+            &["1"],
+            "cosmos1sender",
+            &user_bech32,
+        ),
+    );
+    assert_eq!(ack, successful_ack());
+    setup.b_mock.borrow().check_nft_balance(
+        &setup.user,
+        NATIVE_COLLECTION,
+        1,
+        &rust_biguint!(1),
+        Some(&native_attributes(1)),
+    );
+
+    // nothing is released if any of the tokens wasn't escrowed on the channel
+    let ack = receive_packet(
+        &setup,
+        &packet_data(
+            "nft-transfer/channel-5/KITTY-123456",
+            &["2", "1"],
+            "cosmos1sender",
+            &user_bech32,
+        ),
+    );
+    assert_eq!(ack, error_ack("Token not escrowed"));
+    setup.b_mock.borrow().check_nft_balance(
+        &setup.user,
+        NATIVE_COLLECTION,
+        2,
+        &rust_biguint!(0),
+        Option::<&Vec<u8>>::None,
+    );
+
+    let ack = receive_packet(
+        &setup,
+        &packet_data(
+            "nft-transfer/channel-5/KITTY-123456",
+            &["02"],
+            "cosmos1sender",
+            &user_bech32,
+        ),
+    );
+    assert_eq!(ack, error_ack("Unknown token"));
+}
+
+#[test]
+fn receive_voucher_test() {
+    let setup = get_nft_transfer_setup();
+    let user_bech32 = bech32_address(&setup.b_mock, &setup.nft_transfer_wrapper, &setup.user);
+    setup.set_voucher_collection();
+
+    let data = format!(
+        r#"{{"classData":"cmVk","classId":"kitties","classUri":"https://kitties.example","receiver":"{}","sender":"cosmos1sender","tokenData":["Ymx1ZQ==",""],"tokenIds":["a","b"],"tokenUris":["https://kitties.example/a",""]}}"#,
+        user_bech32
+    );
+    let ack = receive_packet(&setup, data.as_bytes());
+    assert_eq!(ack, successful_ack());
+
+    let b_mock = setup.b_mock.borrow();
+    b_mock.check_nft_balance(
+        &setup.user,
+        VOUCHER_COLLECTION,
+        1,
+        &rust_biguint!(1),
+        Some(&b"blue".to_vec()),
+    );
+    b_mock.check_nft_balance(
+        &setup.user,
+        VOUCHER_COLLECTION,
+        2,
+        &rust_biguint!(1),
+        Some(&Vec::<u8>::new()),
+    );
+    drop(b_mock);
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.nft_transfer_wrapper, |sc| {
+            let collection = managed_token_id!(VOUCHER_COLLECTION);
+            assert_eq!(sc.get_token_id(&collection, 1), managed_buffer!(b"a"));
+            assert_eq!(sc.get_token_id(&collection, 2), managed_buffer!(b"b"));
+            assert_eq!(
+                sc.get_class_id(&collection),
+                managed_buffer!(VOUCHER_CLASS_TRACE)
+            );
+            assert_eq!(
+                sc.get_ibc_class_id(managed_buffer!(VOUCHER_CLASS_TRACE)),
+                managed_buffer!(
+                    b"ibc/2DE91F403AFBDC007F909CF5EBC47B6F04675B2B03A139B024C273B3DC4C1F3D"
+                )
+            );
+            assert_eq!(
+                sc.class_metadata(&sc.class_hash(&managed_buffer!(VOUCHER_CLASS_TRACE)))
+                    .get(),
+                (
+                    managed_buffer!(b"https://kitties.example"),
+                    managed_buffer!(b"red")
+                )
+            );
+        })
+        .assert_ok();
+
+    let ack = receive_packet(
+        &setup,
+        &packet_data("kitties", &["c", "a"], "cosmos1sender", &user_bech32),
+    );
+    assert_eq!(ack, error_ack("Token already received"));
+}
+
+#[test]
+fn receive_unissued_voucher_test() {
+    let setup = get_nft_transfer_setup();
+    let user_bech32 = bech32_address(&setup.b_mock, &setup.nft_transfer_wrapper, &setup.user);
+
+    let ack = receive_packet(
+        &setup,
+        &packet_data("punks", &["1"], "cosmos1sender", &user_bech32),
+    );
+    assert_eq!(ack, error_ack("Voucher collection not issued"));
+
+    // the trace is recorded, so that the voucher collection can be issued
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.nft_transfer_wrapper, |sc| {
+            let class_trace = managed_buffer!(b"nft-transfer/channel-0/punks");
+            assert_eq!(
+                sc.class_traces(&sc.class_hash(&class_trace)).get(),
+                class_trace
+            );
+            assert!(sc
+                .get_voucher_collection(class_trace)
+                .into_option()
+                .is_none());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn receive_invalid_packet_test() {
+    let setup = get_nft_transfer_setup();
+    let user_bech32 = bech32_address(&setup.b_mock, &setup.nft_transfer_wrapper, &setup.user);
+
+    let duplicate_ids = packet_data("kitties", &["a", "a"], "cosmos1sender", &user_bech32);
+    let no_ids = packet_data("kitties", &[], "cosmos1sender", &user_bech32);
+    let missing_uris = format!(
+        r#"{{"classId":"kitties","receiver":"{}","sender":"cosmos1sender","tokenIds":["a","b"],"tokenUris":["uri"]}}"#,
+        user_bech32
+    );
+    let invalid_data = format!(
+        r#"{{"classId":"kitties","receiver":"{}","sender":"cosmos1sender","tokenData":["Ymx1ZQ"],"tokenIds":["a"]}}"#,
+        user_bech32
+    );
+    for data in [
+        duplicate_ids,
+        no_ids,
+        missing_uris.into_bytes(),
+        invalid_data.into_bytes(),
+    ] {
+        assert_eq!(
+            receive_packet(&setup, &data),
+            error_ack("Invalid packet data")
+        );
+    }
+
+    let ack = receive_packet(
+        &setup,
+        &packet_data("kitties", &["a"], "cosmos1sender", "erd1abc"),
+    );
+    assert_eq!(ack, error_ack("Invalid receiver"));
+}
+
+#[test]
+fn voucher_round_trip_test() {
+    let setup = get_nft_transfer_setup();
+    let user_bech32 = bech32_address(&setup.b_mock, &setup.nft_transfer_wrapper, &setup.user);
+    let ibc_handler_address = setup.ibc_handler_wrapper.address_ref().clone();
+    setup.set_voucher_collection();
+
+    let data = format!(
+        r#"{{"classId":"kitties","receiver":"{}","sender":"cosmos1sender","tokenData":["Ymx1ZQ=="],"tokenIds":["a"],"tokenUris":["https://kitties.example/a"]}}"#,
+        user_bech32
+    );
+    assert_eq!(receive_packet(&setup, data.as_bytes()), successful_ack());
+
+    // the voucher is burnt, and goes back with its original ID, URI and data
+    transfer_nfts(&setup, &nfts(VOUCHER_COLLECTION, &[1]), 1);
+    setup.b_mock.borrow().check_nft_balance(
+        &setup.user,
+        VOUCHER_COLLECTION,
+        1,
+        &rust_biguint!(0),
+        Option::<&Vec<u8>>::None,
+    );
+
+    let sent_data = format!(
+        r#"{{"classId":"nft-transfer/channel-0/kitties","receiver":"{}","sender":"{}","tokenData":["Ymx1ZQ=="],"tokenIds":["a"],"tokenUris":["https://kitties.example/a"]}}"#,
+        receiver(),
+        user_bech32
+    )
+    .into_bytes();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.ibc_handler_wrapper, |sc| {
+            let commitment_key = sc.get_packet_commitment_key(
+                &managed_buffer!(NFT_TRANSFER_PORT_ID),
+                &managed_buffer!(CHANNEL_ID),
+                1,
+            );
+            assert_eq!(
+                sc.commitments(&commitment_key).get(),
+                sc.encode_and_hash_twice(TIMEOUT_HEIGHT, 0, &managed_buffer!(&sent_data))
+            );
+        })
+        .assert_ok();
+
+    // on timeout, the voucher is minted again
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.nft_transfer_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.on_timeout_packet(
+                    outgoing_packet(1, &sent_data),
+                    managed_address!(&ibc_handler_address),
+                );
+
+                let collection = managed_token_id!(VOUCHER_COLLECTION);
+                assert_eq!(sc.get_token_id(&collection, 2), managed_buffer!(b"a"));
+                assert!(sc.voucher_token_ids(&collection, 1).is_empty());
+            },
+        )
+        .assert_ok();
+
+    setup.b_mock.borrow().check_nft_balance(
+        &setup.user,
+        VOUCHER_COLLECTION,
+        2,
+        &rust_biguint!(1),
+        Some(&b"blue".to_vec()),
+    );
+}
+
+#[test]
+fn ack_refund_test() {
+    let setup = get_nft_transfer_setup();
+    let user_bech32 = bech32_address(&setup.b_mock, &setup.nft_transfer_wrapper, &setup.user);
+    let ibc_handler_address = setup.ibc_handler_wrapper.address_ref().clone();
+    transfer_nfts(&setup, &nfts(NATIVE_COLLECTION, &[2]), 1);
+
+    let data = packet_data("KITTY-123456", &["2"], &user_bech32, receiver());
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.nft_transfer_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.on_ack_packet(
+                    outgoing_packet(1, &data),
+                    managed_buffer!(b"{}"),
+                    managed_address!(&ibc_handler_address),
+                );
+            },
+        )
+        .assert_user_error("Invalid acknowledgement");
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.nft_transfer_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.on_ack_packet(
+                    outgoing_packet(1, &data),
+                    acknowledgement::new_error(b"failed"),
+                    managed_address!(&ibc_handler_address),
+                );
+
+                assert!(sc
+                    .channel_escrow(
+                        &managed_buffer!(CHANNEL_ID),
+                        &managed_token_id!(NATIVE_COLLECTION),
+                        2
+                    )
+                    .is_empty());
+            },
+        )
+        .assert_ok();
+
+    setup.b_mock.borrow().check_nft_balance(
+        &setup.user,
+        NATIVE_COLLECTION,
+        2,
+        &rust_biguint!(1),
+        Some(&native_attributes(2)),
+    );
+}
+
+#[test]
+fn channel_handshake_test() {
+    let setup = get_nft_transfer_setup();
+    let ibc_handler_address = setup.ibc_handler_wrapper.address_ref().clone();
+
+    let open_try =
+        |order: channel::Order, counterparty_version: &[u8]| MsgOnChanOpenTry::<DebugApi> {
+            order,
+            connection_hops: ConnectionHops::from_single_item(managed_buffer!(CONNECTION_ID)),
+            port_id: managed_buffer!(NFT_TRANSFER_PORT_ID),
+            channel_id: managed_buffer!(b"channel-1"),
+            counterparty: channel_counterparty::Data {
+                port_id: managed_buffer!(NFT_TRANSFER_PORT_ID),
+                channel_id: managed_buffer!(b"channel-9"),
+            },
+            counterparty_version: managed_buffer!(counterparty_version),
+        };
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.nft_transfer_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let version = sc.on_chan_open_try(open_try(channel::Order::Unordered, b"ics721-1"));
+                assert_eq!(version, managed_buffer!(b"ics721-1"));
+            },
+        )
+        .assert_ok();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.nft_transfer_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.on_chan_open_try(open_try(channel::Order::Ordered, b"ics721-1"));
+            },
+        )
+        .assert_user_error("NFT transfer channels must be unordered");
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.nft_transfer_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.on_chan_open_try(open_try(channel::Order::Unordered, b"ics20-1"));
+            },
+        )
+        .assert_user_error("Invalid version");
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.nft_transfer_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.on_chan_close_init(MsgOnChanCloseInit {
+                    port_id: managed_buffer!(NFT_TRANSFER_PORT_ID),
+                    channel_id: managed_buffer!(CHANNEL_ID),
+                });
+            },
+        )
+        .assert_user_error("NFT transfer channels cannot be closed");
+}
+
+#[test]
+fn only_ibc_handler_callbacks_test() {
+    let setup = get_nft_transfer_setup();
+    let user_bech32 = bech32_address(&setup.b_mock, &setup.nft_transfer_wrapper, &setup.user);
+    let user = setup.user.clone();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &user,
+            &setup.nft_transfer_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.on_timeout_packet(
+                    outgoing_packet(
+                        1,
+                        &packet_data("KITTY-123456", &["1"], &user_bech32, "cosmos1receiver"),
+                    ),
+                    managed_address!(&user),
+                );
+            },
+        )
+        .assert_user_error("Only the IBC handler may call this endpoint");
+}
@@ -0,0 +1,48 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           22
+// Async Callback:                       1
+// Total number of exported functions:  25
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    nft_transfer
+    (
+        init => init
+        upgrade => upgrade
+        issueVoucherCollection => issue_voucher_collection
+        getIbcClassId => get_ibc_class_id
+        getVoucherCollection => get_voucher_collection
+        getClassId => get_class_id
+        getTokenId => get_token_id
+        transfer => transfer
+        getIbcHandler => ibc_handler
+        getChannelEscrow => channel_escrow
+        onChanOpenInit => on_chan_open_init
+        onChanOpenTry => on_chan_open_try
+        onChanOpenAck => on_chan_open_ack
+        onChanOpenConfirm => on_chan_open_confirm
+        onChanCloseInit => on_chan_close_init
+        onChanCloseConfirm => on_chan_close_confirm
+        onChanUpgradeInit => on_chan_upgrade_init
+        onChanUpgradeTry => on_chan_upgrade_try
+        onChanUpgradeAck => on_chan_upgrade_ack
+        onChanUpgradeOpen => on_chan_upgrade_open
+        canTransitionToFlushComplete => can_transition_to_flush_complete
+        onRecvPacket => on_recv_packet
+        onAcknowledgementPacket => on_ack_packet
+        onTimeoutPacket => on_timeout_packet
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback! { nft_transfer }
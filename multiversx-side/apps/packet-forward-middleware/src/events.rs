@@ -0,0 +1,23 @@
+use common_types::{ChannelId, Sequence};
+
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait EventsModule {
+    #[event("forwardTransferEvent")]
+    fn forward_transfer_event(
+        &self,
+        #[indexed] channel_id: &ChannelId<Self::Api>,
+        #[indexed] seq: Sequence,
+        #[indexed] forward_channel_id: &ChannelId<Self::Api>,
+        #[indexed] forward_seq: Sequence,
+    );
+
+    #[event("forwardTransferFailedEvent")]
+    fn forward_transfer_failed_event(
+        &self,
+        #[indexed] channel_id: &ChannelId<Self::Api>,
+        #[indexed] seq: Sequence,
+        ack: &ManagedBuffer,
+    );
+}
@@ -0,0 +1,103 @@
+use common_types::{
+    json::{self, JsonObject},
+    ChannelId, PortId, UnixTimestamp,
+};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub static FORWARD_KEY: &[u8] = b"forward";
+
+/// The timeout of forwarded transfers whose memo has none, in nanoseconds
+pub const DEFAULT_FORWARD_TIMEOUT: UnixTimestamp = 600_000_000_000;
+
+static INVALID_FORWARD_METADATA_ERR_MSG: &[u8] = b"Invalid forward metadata";
+
+/// The next hop of a transfer, as named in its memo
+///
+/// e.g. `{"forward":{"receiver":"cosmos1...","port":"transfer","channel":"channel-1","timeout":"600000000000",
+/// "retries":2,"next":{"forward":{...}}}}`
+///
+/// `timeout` is relative to the time the transfer is forwarded, and `next` is the memo of the forwarded transfer, which
+/// may forward it again.
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct ForwardMetadata<M: ManagedTypeApi> {
+    pub receiver: ManagedBuffer<M>,
+    pub port: PortId<M>,
+    pub channel: ChannelId<M>,
+    pub timeout: UnixTimestamp,
+    pub retries: u64,
+    pub next: ManagedBuffer<M>,
+}
+
+impl<M: ManagedTypeApi> ForwardMetadata<M> {
+    /// Returns `Ok(None)` if the memo doesn't forward the transfer, and an error if it does with invalid metadata
+    ///
+    /// The transfer app only sends on its own port, so `port` may be omitted and must otherwise be `port_id`, the port
+    /// the transfer was received on.
+    pub fn from_memo(
+        memo: &ManagedBuffer<M>,
+        port_id: &PortId<M>,
+    ) -> Result<Option<Self>, &'static [u8]> {
+        let object = match JsonObject::parse(memo) {
+            Some(object) if object.contains(FORWARD_KEY) => object,
+            _ => return Ok(None),
+        };
+
+        object
+            .get_object(FORWARD_KEY)
+            .and_then(|forward| Self::from_json(&forward, port_id))
+            .map(Some)
+            .ok_or(INVALID_FORWARD_METADATA_ERR_MSG)
+    }
+
+    fn from_json(forward: &JsonObject<M>, port_id: &PortId<M>) -> Option<Self> {
+        let receiver = forward.get_string(b"receiver")?;
+        let channel = forward.get_string(b"channel")?;
+        if receiver.is_empty() || channel.is_empty() {
+            return None;
+        }
+
+        let port = match forward.get_raw(b"port") {
+            Some(_) => forward.get_string(b"port")?,
+            None => port_id.clone(),
+        };
+        if &port != port_id {
+            return None;
+        }
+
+        let timeout = match forward.get_raw(b"timeout") {
+            Some(_) => forward.get_u64(b"timeout")?,
+            None => DEFAULT_FORWARD_TIMEOUT,
+        };
+        if timeout == 0 {
+            return None;
+        }
+
+        let retries = match forward.get_raw(b"retries") {
+            Some(_) => forward.get_u64(b"retries")?,
+            None => 0,
+        };
+
+        // the next memo is either a JSON object or a string holding one
+        let next = match forward.get_raw(b"next") {
+            Some(raw) => match json::decode_string(&raw) {
+                Some(next) => next,
+                None => {
+                    JsonObject::parse(&raw)?;
+                    raw
+                }
+            },
+            None => ManagedBuffer::new(),
+        };
+
+        Some(ForwardMetadata {
+            receiver,
+            port,
+            channel,
+            timeout,
+            retries,
+            next,
+        })
+    }
+}
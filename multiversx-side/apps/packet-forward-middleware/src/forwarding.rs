@@ -0,0 +1,212 @@
+use ::channel::{
+    channel_libs::packet_types::Packet,
+    interfaces::ibc_handler_interface::ibc_handler_proxy::ProxyTrait as _,
+};
+use common_types::{
+    channel_types::{acknowledgement, height},
+    trace, ChannelId, PortId, Sequence, UnixTimestamp,
+};
+use transfer::{
+    denom::ProxyTrait as _,
+    relay::{ProxyTrait as _, TRANSFER_PORT_ID},
+};
+
+use crate::forward_metadata::ForwardMetadata;
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+const NANOS_PER_SECOND: UnixTimestamp = 1_000_000_000;
+
+static FORWARD_TIMED_OUT_ERR_MSG: &[u8] = b"Forwarded transfer timed out";
+static INVALID_TIMEOUT_ERR_MSG: &[u8] = b"Invalid timeout";
+static CHANNEL_NOT_OPEN_ERR_MSG: &[u8] = b"Forward channel not open";
+
+/// A received transfer which was forwarded, and waits for the acknowledgement of its next hop
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct InFlightPacket<M: ManagedTypeApi> {
+    /// The packet received from the previous hop, whose acknowledgement is written once the forward settles
+    pub packet: Packet<M>,
+    pub forward: ForwardMetadata<M>,
+    pub token: EgldOrEsdtTokenIdentifier<M>,
+    pub amount: BigUint<M>,
+    pub retries_left: u64,
+}
+
+/// Forwards the tokens of received transfers to their next hop, and settles the received packets with the outcome
+///
+/// The module below the middleware must be the transfer app, which credits the middleware with the tokens of the
+/// transfers it forwards.
+#[multiversx_sc::module]
+pub trait ForwardingModule:
+    ::channel::middleware::ibc_middleware::IbcMiddlewareModule + crate::events::EventsModule
+{
+    /// Sends the tokens of the packet on to the next hop through the transfer app, returning the sequence of the
+    /// forwarded packet
+    ///
+    /// The failure of the transfer call can't be caught, so what would make it fail is checked beforehand.
+    fn try_forward_transfer(
+        &self,
+        transfer_app: &ManagedAddress,
+        in_flight: InFlightPacket<Self::Api>,
+    ) -> Result<Sequence, &'static [u8]> {
+        let timeout_timestamp = self
+            .blockchain()
+            .get_block_timestamp()
+            .checked_mul(NANOS_PER_SECOND)
+            .and_then(|now| now.checked_add(in_flight.forward.timeout))
+            .ok_or(INVALID_TIMEOUT_ERR_MSG)?;
+
+        let forward = &in_flight.forward;
+        let ibc_handler = self.ibc_handler().get();
+        let is_channel_open: bool = self
+            .ibc_handler_proxy(ibc_handler)
+            .is_channel_open(PortId::from(TRANSFER_PORT_ID), forward.channel.clone())
+            .execute_on_dest_context();
+        if !is_channel_open {
+            return Err(CHANNEL_NOT_OPEN_ERR_MSG);
+        }
+
+        let seq: Sequence = self
+            .transfer_proxy(transfer_app.clone())
+            .transfer(
+                forward.channel.clone(),
+                forward.receiver.clone(),
+                height::Data::default(),
+                timeout_timestamp,
+                OptionalValue::Some(forward.next.clone()),
+            )
+            .with_egld_or_single_esdt_transfer((
+                in_flight.token.clone(),
+                0,
+                in_flight.amount.clone(),
+            ))
+            .execute_on_dest_context();
+
+        self.forward_transfer_event(
+            &in_flight.packet.dest_channel,
+            in_flight.packet.seq,
+            &forward.channel,
+            seq,
+        );
+        self.in_flight_packets(&forward.channel, seq).set(in_flight);
+
+        Ok(seq)
+    }
+
+    /// Passes the acknowledgement of the next hop back to the previous one, taking back the tokens if it failed
+    fn settle_forward_ack(
+        &self,
+        transfer_app: &ManagedAddress,
+        in_flight: InFlightPacket<Self::Api>,
+        ack: ManagedBuffer,
+    ) {
+        if acknowledgement::is_successful(&ack) == Some(true) {
+            self.write_received_packet_ack(&in_flight.packet, ack);
+        } else {
+            self.revert_forward(transfer_app, in_flight, ack);
+        }
+    }
+
+    /// Forwards the transfer again while it has retries left, and gives up on it otherwise
+    fn settle_forward_timeout(
+        &self,
+        transfer_app: &ManagedAddress,
+        mut in_flight: InFlightPacket<Self::Api>,
+    ) {
+        if in_flight.retries_left == 0 {
+            self.revert_forward(
+                transfer_app,
+                in_flight,
+                acknowledgement::new_error(FORWARD_TIMED_OUT_ERR_MSG),
+            );
+            return;
+        }
+
+        in_flight.retries_left -= 1;
+        if let Err(err_msg) = self.try_forward_transfer(transfer_app, in_flight.clone()) {
+            self.revert_forward(transfer_app, in_flight, acknowledgement::new_error(err_msg));
+        }
+    }
+
+    /// The transfer app refunded the forwarded tokens to the middleware, which gives them back to the transfer app to
+    /// undo their receipt, so that the previous hop refunds its sender on the error acknowledgement
+    fn revert_forward(
+        &self,
+        transfer_app: &ManagedAddress,
+        in_flight: InFlightPacket<Self::Api>,
+        ack: ManagedBuffer,
+    ) {
+        self.revert_receipt(transfer_app, &in_flight);
+        self.forward_transfer_failed_event(
+            &in_flight.packet.dest_channel,
+            in_flight.packet.seq,
+            &ack,
+        );
+        self.write_received_packet_ack(&in_flight.packet, ack);
+    }
+
+    /// Gives the received tokens back to the transfer app, which undoes their receipt
+    fn revert_receipt(&self, transfer_app: &ManagedAddress, in_flight: &InFlightPacket<Self::Api>) {
+        self.transfer_proxy(transfer_app.clone())
+            .revert_receive_transfer(in_flight.packet.clone())
+            .with_egld_or_single_esdt_transfer((
+                in_flight.token.clone(),
+                0,
+                in_flight.amount.clone(),
+            ))
+            .execute_on_dest_context::<()>();
+    }
+
+    /// Writes the asynchronous acknowledgement of a received packet, through the modules above
+    fn write_received_packet_ack(&self, packet: &Packet<Self::Api>, ack: ManagedBuffer) {
+        let (upper_module, _) = self.get_stack_neighbours(&packet.dest_port, &packet.dest_channel);
+
+        self.ibc_handler_proxy(upper_module)
+            .write_ack(
+                packet.dest_port.clone(),
+                packet.dest_channel.clone(),
+                packet.seq,
+                ack,
+            )
+            .execute_on_dest_context::<()>();
+    }
+
+    /// The token the transfer app credits for a received transfer of `denom`, if it knows the denom
+    fn get_received_token(
+        &self,
+        transfer_app: &ManagedAddress,
+        packet: &Packet<Self::Api>,
+        denom: &ManagedBuffer,
+    ) -> Option<EgldOrEsdtTokenIdentifier> {
+        let local_denom =
+            match trace::strip_hop_prefix(denom, &packet.src_port, &packet.src_channel) {
+                Some(native_denom) => native_denom,
+                None => {
+                    let mut denom_trace =
+                        trace::hop_prefix(&packet.dest_port, &packet.dest_channel);
+                    denom_trace.append(denom);
+                    denom_trace
+                }
+            };
+
+        let token: OptionalValue<EgldOrEsdtTokenIdentifier> = self
+            .transfer_proxy(transfer_app.clone())
+            .get_local_token_view(local_denom)
+            .execute_on_dest_context();
+
+        token.into_option()
+    }
+
+    #[proxy]
+    fn transfer_proxy(&self, sc_address: ManagedAddress) -> transfer::Proxy<Self::Api>;
+
+    /// The forwarded transfers, by the channel and sequence of the packet sent to the next hop
+    #[view(getInFlightPacket)]
+    #[storage_mapper("inFlightPackets")]
+    fn in_flight_packets(
+        &self,
+        channel_id: &ChannelId<Self::Api>,
+        seq: Sequence,
+    ) -> SingleValueMapper<InFlightPacket<Self::Api>>;
+}
@@ -0,0 +1,254 @@
+use ::channel::{
+    channel_libs::packet_types::Packet,
+    interfaces::ibc_module_interface::{
+        ibc_module_proxy::ProxyTrait as _, MsgOnChanCloseConfirm, MsgOnChanCloseInit,
+        MsgOnChanOpenAck, MsgOnChanOpenConfirm, MsgOnChanOpenInit, MsgOnChanOpenTry,
+        MsgOnChanUpgradeAck, MsgOnChanUpgradeInit, MsgOnChanUpgradeOpen, MsgOnChanUpgradeTry,
+    },
+};
+use common_types::{bech32, channel_types::acknowledgement, ChannelId, PortId, Sequence, Version};
+use transfer::packet_data::FungibleTokenPacketData;
+
+use crate::{forward_metadata::ForwardMetadata, forwarding::InFlightPacket};
+
+multiversx_sc::imports!();
+
+static UNKNOWN_DENOM_ERR_MSG: &[u8] = b"Unknown denom";
+
+/// The callbacks of the IBC handler, forwarded to the module below
+///
+/// The middleware doesn't negotiate a version of its own, so handshakes and upgrades are passed down unchanged.
+#[multiversx_sc::module]
+pub trait IbcModule:
+    crate::forwarding::ForwardingModule
+    + ::channel::middleware::ibc_middleware::IbcMiddlewareModule
+    + crate::events::EventsModule
+{
+    #[endpoint(onChanOpenInit)]
+    fn on_chan_open_init(&self, args: MsgOnChanOpenInit<Self::Api>) -> Version<Self::Api> {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        self.ibc_module_proxy(lower_module)
+            .on_chan_open_init(args)
+            .execute_on_dest_context()
+    }
+
+    #[endpoint(onChanOpenTry)]
+    fn on_chan_open_try(&self, args: MsgOnChanOpenTry<Self::Api>) -> Version<Self::Api> {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        self.ibc_module_proxy(lower_module)
+            .on_chan_open_try(args)
+            .execute_on_dest_context()
+    }
+
+    #[endpoint(onChanOpenAck)]
+    fn on_chan_open_ack(&self, args: MsgOnChanOpenAck<Self::Api>) {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        self.ibc_module_proxy(lower_module)
+            .on_chan_open_ack(args)
+            .execute_on_dest_context::<()>();
+    }
+
+    #[endpoint(onChanOpenConfirm)]
+    fn on_chan_open_confirm(&self, args: MsgOnChanOpenConfirm<Self::Api>) {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        self.ibc_module_proxy(lower_module)
+            .on_chan_open_confirm(args)
+            .execute_on_dest_context::<()>();
+    }
+
+    #[endpoint(onChanCloseInit)]
+    fn on_chan_close_init(&self, args: MsgOnChanCloseInit<Self::Api>) {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        self.ibc_module_proxy(lower_module)
+            .on_chan_close_init(args)
+            .execute_on_dest_context::<()>();
+    }
+
+    #[endpoint(onChanCloseConfirm)]
+    fn on_chan_close_confirm(&self, args: MsgOnChanCloseConfirm<Self::Api>) {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        self.ibc_module_proxy(lower_module)
+            .on_chan_close_confirm(args)
+            .execute_on_dest_context::<()>();
+    }
+
+    #[endpoint(onChanUpgradeInit)]
+    fn on_chan_upgrade_init(&self, args: MsgOnChanUpgradeInit<Self::Api>) -> Version<Self::Api> {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        self.ibc_module_proxy(lower_module)
+            .on_chan_upgrade_init(args)
+            .execute_on_dest_context()
+    }
+
+    #[endpoint(onChanUpgradeTry)]
+    fn on_chan_upgrade_try(&self, args: MsgOnChanUpgradeTry<Self::Api>) -> Version<Self::Api> {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        self.ibc_module_proxy(lower_module)
+            .on_chan_upgrade_try(args)
+            .execute_on_dest_context()
+    }
+
+    #[endpoint(onChanUpgradeAck)]
+    fn on_chan_upgrade_ack(&self, args: MsgOnChanUpgradeAck<Self::Api>) {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        self.ibc_module_proxy(lower_module)
+            .on_chan_upgrade_ack(args)
+            .execute_on_dest_context::<()>();
+    }
+
+    #[endpoint(onChanUpgradeOpen)]
+    fn on_chan_upgrade_open(&self, args: MsgOnChanUpgradeOpen<Self::Api>) {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        self.ibc_module_proxy(lower_module)
+            .on_chan_upgrade_open(args)
+            .execute_on_dest_context::<()>();
+    }
+
+    #[view(canTransitionToFlushComplete)]
+    fn can_transition_to_flush_complete(
+        &self,
+        port_id: PortId<Self::Api>,
+        channel_id: ChannelId<Self::Api>,
+        upgrade_sequence: Sequence,
+    ) -> bool {
+        let (_, lower_module) = self.get_stack_neighbours(&port_id, &channel_id);
+        self.ibc_module_proxy(lower_module)
+            .can_transition_to_flush_complete(port_id, channel_id, upgrade_sequence)
+            .execute_on_dest_context()
+    }
+
+    /// Transfers to forward are received by the middleware, which sends their tokens on to the next hop
+    ///
+    /// Their acknowledgement is asynchronous, written once the next hop settles. Transfers which fail to be received
+    /// are answered right away by the transfer app, and transfers with invalid forward metadata or which can't be
+    /// forwarded by the middleware, after taking back their receipt.
+    #[endpoint(onRecvPacket)]
+    fn on_recv_packet(&self, packet: Packet<Self::Api>, relayer: ManagedAddress) -> ManagedBuffer {
+        let lower_module =
+            self.require_upper_module_caller(&packet.dest_port, &packet.dest_channel);
+
+        let forward = match FungibleTokenPacketData::from_json(&packet.data) {
+            Some(packet_data) => {
+                match ForwardMetadata::from_memo(&packet_data.memo, &packet.dest_port) {
+                    Ok(Some(forward)) => Some((packet_data, forward)),
+                    Ok(None) => None,
+                    Err(err_msg) => return acknowledgement::new_error(err_msg),
+                }
+            }
+            None => None,
+        };
+        let (packet_data, forward) = match forward {
+            Some(forward) => forward,
+            None => {
+                return self
+                    .ibc_module_proxy(lower_module)
+                    .on_recv_packet(packet, relayer)
+                    .execute_on_dest_context()
+            }
+        };
+
+        // the token is looked up first, as the tokens of an unknown denom could not be handed back
+        let token = match self.get_received_token(&lower_module, &packet, &packet_data.denom) {
+            Some(token) => token,
+            None => return acknowledgement::new_error(UNKNOWN_DENOM_ERR_MSG),
+        };
+        let amount = packet_data.amount.clone();
+        let receiving_packet = Packet {
+            data: FungibleTokenPacketData {
+                receiver: bech32::encode_address(&self.blockchain().get_sc_address()),
+                ..packet_data
+            }
+            .to_json(),
+            ..packet.clone()
+        };
+        let ack: ManagedBuffer = self
+            .ibc_module_proxy(lower_module.clone())
+            .on_recv_packet(receiving_packet, relayer)
+            .execute_on_dest_context();
+        if acknowledgement::is_successful(&ack) != Some(true) {
+            return ack;
+        }
+
+        let retries_left = forward.retries;
+        let in_flight = InFlightPacket {
+            packet,
+            forward,
+            token,
+            amount,
+            retries_left,
+        };
+        if let Err(err_msg) = self.try_forward_transfer(&lower_module, in_flight.clone()) {
+            self.revert_receipt(&lower_module, &in_flight);
+
+            let ack = acknowledgement::new_error(err_msg);
+            self.forward_transfer_failed_event(
+                &in_flight.packet.dest_channel,
+                in_flight.packet.seq,
+                &ack,
+            );
+
+            return ack;
+        }
+
+        ManagedBuffer::new()
+    }
+
+    /// Settles the received packet of a forwarded transfer with the acknowledgement of the next hop
+    ///
+    /// The transfer app refunds the middleware first if the forwarded transfer failed.
+    #[endpoint(onAcknowledgementPacket)]
+    fn on_ack_packet(
+        &self,
+        packet: Packet<Self::Api>,
+        ack: ManagedBuffer,
+        relayer: ManagedAddress,
+    ) {
+        let lower_module = self.require_upper_module_caller(&packet.src_port, &packet.src_channel);
+        let in_flight = self.take_in_flight_packet(&packet.src_channel, packet.seq);
+
+        self.ibc_module_proxy(lower_module.clone())
+            .on_ack_packet(packet, ack.clone(), relayer)
+            .execute_on_dest_context::<()>();
+
+        if let Some(in_flight) = in_flight {
+            self.settle_forward_ack(&lower_module, in_flight, ack);
+        }
+    }
+
+    #[endpoint(onTimeoutPacket)]
+    fn on_timeout_packet(&self, packet: Packet<Self::Api>, relayer: ManagedAddress) {
+        let lower_module = self.require_upper_module_caller(&packet.src_port, &packet.src_channel);
+        let in_flight = self.take_in_flight_packet(&packet.src_channel, packet.seq);
+
+        self.ibc_module_proxy(lower_module.clone())
+            .on_timeout_packet(packet, relayer)
+            .execute_on_dest_context::<()>();
+
+        if let Some(in_flight) = in_flight {
+            self.settle_forward_timeout(&lower_module, in_flight);
+        }
+    }
+
+    fn take_in_flight_packet(
+        &self,
+        channel_id: &ChannelId<Self::Api>,
+        seq: Sequence,
+    ) -> Option<InFlightPacket<Self::Api>> {
+        let mapper = self.in_flight_packets(channel_id, seq);
+        if mapper.is_empty() {
+            return None;
+        }
+
+        Some(mapper.take())
+    }
+}
@@ -0,0 +1,47 @@
+use ::channel::interfaces::ibc_handler_interface::ibc_handler_proxy::ProxyTrait as _;
+use common_types::{channel_types::height, ChannelId, PortId, Sequence, UnixTimestamp};
+
+multiversx_sc::imports!();
+
+/// The endpoints of the IBC handler the module below calls, which the middleware forwards up the stack
+#[multiversx_sc::module]
+pub trait Ics4WrapperModule:
+    ::channel::middleware::ibc_middleware::IbcMiddlewareModule + crate::events::EventsModule
+{
+    #[endpoint(sendPacket)]
+    fn send_packet(
+        &self,
+        src_port: PortId<Self::Api>,
+        src_channel: ChannelId<Self::Api>,
+        timeout_height: height::Data,
+        timeout_timestamp: UnixTimestamp,
+        data: ManagedBuffer,
+    ) -> Sequence {
+        let upper_module = self.require_lower_module_caller(&src_port, &src_channel);
+
+        self.ibc_handler_proxy(upper_module)
+            .send_packet(
+                src_port,
+                src_channel,
+                timeout_height,
+                timeout_timestamp,
+                data,
+            )
+            .execute_on_dest_context()
+    }
+
+    #[endpoint(writeAcknowledgement)]
+    fn write_ack(
+        &self,
+        dest_port: PortId<Self::Api>,
+        dest_channel: ChannelId<Self::Api>,
+        seq: Sequence,
+        ack: ManagedBuffer,
+    ) {
+        let upper_module = self.require_lower_module_caller(&dest_port, &dest_channel);
+
+        self.ibc_handler_proxy(upper_module)
+            .write_ack(dest_port, dest_channel, seq, ack)
+            .execute_on_dest_context::<()>();
+    }
+}
@@ -0,0 +1,33 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod events;
+pub mod forward_metadata;
+pub mod forwarding;
+pub mod ibc_module;
+pub mod ics4_wrapper;
+
+/// Middleware forwarding ICS-20 transfers to another chain, for transfers routed through this chain
+///
+/// The middleware is added to the stack of the port of the transfer app in the IBC handler. Transfers whose memo has
+/// a `forward` object are received by the middleware, which sends the tokens on to the next hop and holds the
+/// acknowledgement of the received packet until the next hop acknowledges the forwarded one or it times out.
+///
+/// The contract must be deployed as payable, as the transfer app credits it with the forwarded tokens.
+#[multiversx_sc::contract]
+pub trait PacketForwardMiddleware:
+    forwarding::ForwardingModule
+    + ibc_module::IbcModule
+    + ics4_wrapper::Ics4WrapperModule
+    + ::channel::middleware::ibc_middleware::IbcMiddlewareModule
+    + events::EventsModule
+{
+    #[init]
+    fn init(&self, ibc_handler: ManagedAddress) {
+        self.ibc_handler().set(ibc_handler);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+}
@@ -0,0 +1,76 @@
+use multiversx_sc::types::ManagedBuffer;
+use multiversx_sc_scenario::DebugApi;
+use packet_forward_middleware::forward_metadata::{ForwardMetadata, DEFAULT_FORWARD_TIMEOUT};
+
+fn parse(memo: &str) -> Result<Option<ForwardMetadata<DebugApi>>, &'static [u8]> {
+    ForwardMetadata::from_memo(
+        &ManagedBuffer::from(memo.as_bytes()),
+        &ManagedBuffer::from(b"transfer"),
+    )
+}
+
+#[test]
+fn parse_forward_metadata_test() {
+    DebugApi::dummy();
+
+    let forward = parse(r#"{"forward":{"receiver":"osmo1receiver","channel":"channel-1"}}"#)
+        .unwrap()
+        .unwrap();
+    assert_eq!(forward.receiver, ManagedBuffer::from(b"osmo1receiver"));
+    assert_eq!(forward.port, ManagedBuffer::from(b"transfer"));
+    assert_eq!(forward.channel, ManagedBuffer::from(b"channel-1"));
+    assert_eq!(forward.timeout, DEFAULT_FORWARD_TIMEOUT);
+    assert_eq!(forward.retries, 0);
+    assert!(forward.next.is_empty());
+
+    let forward = parse(
+        r#"{"forward":{"receiver":"osmo1receiver","port":"transfer","channel":"channel-1","timeout":"60000000000","retries":2}}"#,
+    )
+    .unwrap()
+    .unwrap();
+    assert_eq!(forward.timeout, 60_000_000_000);
+    assert_eq!(forward.retries, 2);
+}
+
+#[test]
+fn parse_next_memo_test() {
+    DebugApi::dummy();
+    let next = r#"{"forward":{"receiver":"cosmos1receiver","channel":"channel-7"}}"#;
+
+    for memo in [
+        format!(
+            r#"{{"forward":{{"receiver":"osmo1receiver","channel":"channel-1","next":{}}}}}"#,
+            next
+        ),
+        format!(
+            r#"{{"forward":{{"receiver":"osmo1receiver","channel":"channel-1","next":"{}"}}}}"#,
+            next.replace('"', r#"\""#)
+        ),
+    ] {
+        let forward = parse(&memo).unwrap().unwrap();
+        assert_eq!(forward.next, ManagedBuffer::from(next.as_bytes()));
+    }
+}
+
+#[test]
+fn parse_memo_without_forward_test() {
+    DebugApi::dummy();
+
+    for memo in ["", "a note", r#"{"src_callback":{"address":"erd1abc"}}"#] {
+        assert!(parse(memo).unwrap().is_none());
+    }
+}
+
+#[test]
+fn parse_invalid_forward_metadata_test() {
+    DebugApi::dummy();
+
+    for memo in [
+        r#"{"forward":{}}"#,
+        r#"{"forward":{"receiver":"","channel":"channel-1"}}"#,
+        r#"{"forward":{"receiver":"osmo1receiver","channel":"channel-1","retries":-1}}"#,
+        r#"{"forward":{"receiver":"osmo1receiver","channel":"channel-1","next":42}}"#,
+    ] {
+        assert_eq!(parse(memo).err(), Some(&b"Invalid forward metadata"[..]));
+    }
+}
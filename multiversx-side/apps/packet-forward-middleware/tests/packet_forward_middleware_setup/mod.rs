@@ -0,0 +1,547 @@
+#![allow(dead_code)]
+
+use std::{cell::RefCell, rc::Rc};
+
+use ::channel::{channel_libs::packet_types::Packet, packet_handling::encoding::EncodingModule};
+use client_common::ClientStatus;
+use common_types::{
+    channel_types::{channel, channel_counterparty, height, upgrade},
+    connection_types::{connection_end, counterparty, merkle_prefix, version},
+    ConnectionHops, FeatureVec, VersionVec,
+};
+use host::{
+    commitment::CommitmentModule,
+    host_config::HostConfigModule,
+    module_manager::ModuleManagerModule,
+    storage::{ChannelInfo, ClientInfo, RecvStartSequence, StorageModule},
+};
+use ibc_handler::IbcHandler;
+use mock::{
+    client_logic::ClientLogicModule,
+    mock_types::{client_state, consensus_state},
+    Mock,
+};
+use multiversx_sc::{
+    contract_base::ContractBase,
+    types::{Address, EgldOrEsdtTokenIdentifier, EsdtLocalRole, MultiValueEncoded},
+};
+use multiversx_sc_scenario::{
+    imports::{BlockchainStateWrapper, ContractObjWrapper, TxResult},
+    managed_address, managed_biguint, managed_buffer, managed_token_id, rust_biguint, DebugApi,
+};
+use packet_forward_middleware::{
+    forward_metadata::DEFAULT_FORWARD_TIMEOUT, ibc_module::IbcModule, PacketForwardMiddleware,
+};
+use test_utils::bech32_address;
+use transfer::{
+    denom::DenomModule,
+    ibc_module::ICS20_VERSION,
+    relay::{RelayModule, TRANSFER_PORT_ID},
+    Transfer,
+};
+
+pub static CLIENT_TYPE: &[u8] = b"mock-client";
+pub static CLIENT_ID: &[u8] = b"mock-client-0";
+pub static CONNECTION_ID: &[u8] = b"connection-0";
+pub static COUNTERPARTY_CLIENT_ID: &[u8] = b"counterparty-client-0";
+pub static COUNTERPARTY_CONNECTION_ID: &[u8] = b"connection-7";
+/// The channel to the previous hop, on which the transfers to forward are received
+pub static CHANNEL_ID: &[u8] = b"channel-0";
+pub static COUNTERPARTY_CHANNEL_ID: &[u8] = b"channel-5";
+/// The channel to the next hop, on which the transfers are forwarded
+pub static FORWARD_CHANNEL_ID: &[u8] = b"channel-1";
+pub static FORWARD_COUNTERPARTY_CHANNEL_ID: &[u8] = b"channel-9";
+pub static COMMITMENT_PREFIX: &[u8] = b"ibc";
+
+pub static NATIVE_TOKEN_ID: &[u8] = b"TOKEN-123456";
+/// `NATIVE_TOKEN_ID`, as the previous hop sends it back
+pub static RETURNING_NATIVE_DENOM: &[u8] = b"transfer/channel-5/TOKEN-123456";
+pub static VOUCHER_TOKEN_ID: &[u8] = b"VOUCHER-abcdef";
+/// A token native to the previous hop
+pub static COUNTERPARTY_DENOM: &[u8] = b"uatom";
+/// The trace of `COUNTERPARTY_DENOM` received on `CHANNEL_ID`
+pub static VOUCHER_DENOM_TRACE: &[u8] = b"transfer/channel-0/uatom";
+pub static SENDER: &[u8] = b"cosmos1sender";
+pub static FORWARD_RECEIVER: &[u8] = b"osmo1receiver";
+
+pub const PROOF_HEIGHT: height::Data = height::Data {
+    revision_number: 0,
+    revision_height: 10,
+};
+pub const CONSENSUS_TIMESTAMP: u64 = 1_000_000_000;
+pub const TIMEOUT_TIMESTAMP: u64 = 1_000_000_000_000;
+pub const START_TIMESTAMP: u64 = 1_000;
+/// The timeout of the transfers forwarded at `START_TIMESTAMP` with the default timeout
+pub const FORWARD_TIMEOUT_TIMESTAMP: u64 =
+    START_TIMESTAMP * 1_000_000_000 + DEFAULT_FORWARD_TIMEOUT;
+
+pub struct PacketForwardMiddlewareSetup<
+    IbcHandlerObjBuilder,
+    MockClientObjBuilder,
+    PacketForwardMiddlewareObjBuilder,
+    TransferObjBuilder,
+> where
+    IbcHandlerObjBuilder: 'static + Copy + Fn() -> ibc_handler::ContractObj<DebugApi>,
+    MockClientObjBuilder: 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    PacketForwardMiddlewareObjBuilder:
+        'static + Copy + Fn() -> packet_forward_middleware::ContractObj<DebugApi>,
+    TransferObjBuilder: 'static + Copy + Fn() -> transfer::ContractObj<DebugApi>,
+{
+    pub b_mock: Rc<RefCell<BlockchainStateWrapper>>,
+    pub owner: Address,
+    pub relayer: Address,
+    pub ibc_handler_wrapper:
+        ContractObjWrapper<ibc_handler::ContractObj<DebugApi>, IbcHandlerObjBuilder>,
+    pub mock_client_wrapper: ContractObjWrapper<mock::ContractObj<DebugApi>, MockClientObjBuilder>,
+    pub packet_forward_middleware_wrapper: ContractObjWrapper<
+        packet_forward_middleware::ContractObj<DebugApi>,
+        PacketForwardMiddlewareObjBuilder,
+    >,
+    pub transfer_wrapper: ContractObjWrapper<transfer::ContractObj<DebugApi>, TransferObjBuilder>,
+}
+
+impl<
+        IbcHandlerObjBuilder,
+        MockClientObjBuilder,
+        PacketForwardMiddlewareObjBuilder,
+        TransferObjBuilder,
+    >
+    PacketForwardMiddlewareSetup<
+        IbcHandlerObjBuilder,
+        MockClientObjBuilder,
+        PacketForwardMiddlewareObjBuilder,
+        TransferObjBuilder,
+    >
+where
+    IbcHandlerObjBuilder: 'static + Copy + Fn() -> ibc_handler::ContractObj<DebugApi>,
+    MockClientObjBuilder: 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    PacketForwardMiddlewareObjBuilder:
+        'static + Copy + Fn() -> packet_forward_middleware::ContractObj<DebugApi>,
+    TransferObjBuilder: 'static + Copy + Fn() -> transfer::ContractObj<DebugApi>,
+{
+    pub fn new(
+        b_mock: Rc<RefCell<BlockchainStateWrapper>>,
+        owner: &Address,
+        ibc_handler_builder: IbcHandlerObjBuilder,
+        mock_client_builder: MockClientObjBuilder,
+        packet_forward_middleware_builder: PacketForwardMiddlewareObjBuilder,
+        transfer_builder: TransferObjBuilder,
+    ) -> Self {
+        let rust_zero = rust_biguint!(0u64);
+        let relayer = b_mock.borrow_mut().create_user_account(&rust_zero);
+        b_mock.borrow_mut().set_block_timestamp(START_TIMESTAMP);
+
+        let ibc_handler_wrapper = b_mock.borrow_mut().create_sc_account(
+            &rust_zero,
+            Some(owner),
+            ibc_handler_builder,
+            "ibc handler",
+        );
+        let mock_client_wrapper = b_mock.borrow_mut().create_sc_account(
+            &rust_zero,
+            Some(owner),
+            mock_client_builder,
+            "mock client",
+        );
+        let packet_forward_middleware_wrapper = b_mock.borrow_mut().create_sc_account(
+            &rust_zero,
+            Some(owner),
+            packet_forward_middleware_builder,
+            "packet forward middleware",
+        );
+        let transfer_wrapper = b_mock.borrow_mut().create_sc_account(
+            &rust_zero,
+            Some(owner),
+            transfer_builder,
+            "transfer",
+        );
+
+        let ibc_handler_address = ibc_handler_wrapper.address_ref().clone();
+        let mock_client_address = mock_client_wrapper.address_ref().clone();
+        let packet_forward_middleware_address =
+            packet_forward_middleware_wrapper.address_ref().clone();
+        let transfer_address = transfer_wrapper.address_ref().clone();
+
+        b_mock
+            .borrow_mut()
+            .execute_tx(owner, &mock_client_wrapper, &rust_zero, |sc| {
+                sc.init(managed_address!(&ibc_handler_address));
+
+                let client_id = managed_buffer!(CLIENT_ID);
+                sc.client_states(&client_id)
+                    .set(client_state::Data::new(PROOF_HEIGHT));
+                sc.consensus_states(&client_id, &PROOF_HEIGHT.to_biguint_concat())
+                    .set(consensus_state::Data::new(CONSENSUS_TIMESTAMP));
+                sc.statuses(&client_id).set(ClientStatus::Active);
+            })
+            .assert_ok();
+
+        b_mock
+            .borrow_mut()
+            .execute_tx(
+                owner,
+                &packet_forward_middleware_wrapper,
+                &rust_zero,
+                |sc| {
+                    sc.init(managed_address!(&ibc_handler_address));
+                },
+            )
+            .assert_ok();
+
+        b_mock
+            .borrow_mut()
+            .execute_tx(owner, &transfer_wrapper, &rust_zero, |sc| {
+                sc.init(managed_address!(&packet_forward_middleware_address));
+            })
+            .assert_ok();
+
+        b_mock
+            .borrow_mut()
+            .execute_tx(owner, &ibc_handler_wrapper, &rust_zero, |sc| {
+                sc.init();
+
+                sc.set_expected_time_per_block(6);
+                sc.register_client(
+                    managed_buffer!(CLIENT_TYPE),
+                    managed_address!(&mock_client_address),
+                );
+                sc.bind_port(
+                    managed_buffer!(TRANSFER_PORT_ID),
+                    managed_address!(&transfer_address),
+                );
+                let mut middlewares = MultiValueEncoded::new();
+                middlewares.push(managed_address!(&packet_forward_middleware_address));
+                sc.set_port_middlewares(managed_buffer!(TRANSFER_PORT_ID), middlewares);
+
+                sc.client_info(&managed_buffer!(CLIENT_ID)).set(ClientInfo {
+                    client_type: managed_buffer!(CLIENT_TYPE),
+                    client_impl: managed_address!(&mock_client_address),
+                });
+                sc.connection_info(&managed_buffer!(CONNECTION_ID))
+                    .set(open_connection());
+
+                let port_id = managed_buffer!(TRANSFER_PORT_ID);
+                for (channel_id, counterparty_channel_id) in [
+                    (CHANNEL_ID, COUNTERPARTY_CHANNEL_ID),
+                    (FORWARD_CHANNEL_ID, FORWARD_COUNTERPARTY_CHANNEL_ID),
+                ] {
+                    let channel_id = managed_buffer!(channel_id);
+                    sc.claim_channel_stack(&port_id, &channel_id);
+                    sc.channel_info(&port_id, &channel_id)
+                        .set(open_channel_info(counterparty_channel_id));
+                }
+            })
+            .assert_ok();
+
+        Self {
+            b_mock,
+            owner: owner.clone(),
+            relayer,
+            ibc_handler_wrapper,
+            mock_client_wrapper,
+            packet_forward_middleware_wrapper,
+            transfer_wrapper,
+        }
+    }
+
+    /// Registers `VOUCHER_TOKEN_ID` as the voucher of `VOUCHER_DENOM_TRACE`, as if it was issued
+    pub fn set_voucher(&self) {
+        let transfer_address = self.transfer_wrapper.address_ref().clone();
+        self.b_mock.borrow_mut().set_esdt_local_roles(
+            &transfer_address,
+            VOUCHER_TOKEN_ID,
+            &[EsdtLocalRole::Mint, EsdtLocalRole::Burn],
+        );
+
+        self.b_mock
+            .borrow_mut()
+            .execute_tx(
+                &self.owner,
+                &self.transfer_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    let denom_trace = managed_buffer!(VOUCHER_DENOM_TRACE);
+                    let denom_hash = sc.denom_hash(&denom_trace);
+                    sc.denom_traces(&denom_hash).set(&denom_trace);
+                    sc.voucher_tokens(&denom_hash)
+                        .set(managed_token_id!(VOUCHER_TOKEN_ID));
+                    sc.voucher_denom_hashes(&managed_token_id!(VOUCHER_TOKEN_ID))
+                        .set(&denom_hash);
+                },
+            )
+            .assert_ok();
+    }
+
+    /// Escrows `NATIVE_TOKEN_ID` on `CHANNEL_ID`, as if it was sent to the previous hop
+    pub fn set_native_escrow(&self, amount: u64) {
+        let transfer_address = self.transfer_wrapper.address_ref().clone();
+        self.b_mock.borrow_mut().set_esdt_balance(
+            &transfer_address,
+            NATIVE_TOKEN_ID,
+            &rust_biguint!(amount),
+        );
+
+        self.b_mock
+            .borrow_mut()
+            .execute_tx(
+                &self.owner,
+                &self.transfer_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    sc.channel_escrow(&managed_buffer!(CHANNEL_ID), &native_token())
+                        .set(managed_biguint!(amount));
+                },
+            )
+            .assert_ok();
+    }
+
+    /// Delivers a transfer of the previous hop to `CHANNEL_ID`, returning the acknowledgement
+    pub fn recv_transfer(&self, seq: u64, data: &[u8]) -> Vec<u8> {
+        let mut ack = Vec::new();
+        let ibc_handler_address = self.ibc_handler_wrapper.address_ref().clone();
+        let relayer = self.relayer.clone();
+        self.b_mock
+            .borrow_mut()
+            .execute_tx(
+                &ibc_handler_address,
+                &self.packet_forward_middleware_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    ack = sc
+                        .on_recv_packet(received_packet(seq, data), managed_address!(&relayer))
+                        .to_boxed_bytes()
+                        .into_vec();
+                },
+            )
+            .assert_ok();
+
+        if ack.is_empty() {
+            self.set_pending_ack(seq);
+        }
+
+        ack
+    }
+
+    /// Marks a received packet as awaiting its asynchronous acknowledgement, as the handler does when the stack
+    /// returns an empty one
+    pub fn set_pending_ack(&self, seq: u64) {
+        self.b_mock
+            .borrow_mut()
+            .execute_tx(
+                &self.owner,
+                &self.ibc_handler_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    sc.pending_acks(
+                        &managed_buffer!(TRANSFER_PORT_ID),
+                        &managed_buffer!(CHANNEL_ID),
+                    )
+                    .insert(seq);
+                },
+            )
+            .assert_ok();
+    }
+
+    /// Delivers the acknowledgement of a transfer forwarded on `FORWARD_CHANNEL_ID`, as the handler does for the
+    /// relayer
+    pub fn ack_forwarded_transfer(&self, seq: u64, data: &[u8], ack: &[u8]) -> TxResult {
+        let ibc_handler_address = self.ibc_handler_wrapper.address_ref().clone();
+        let relayer = self.relayer.clone();
+        self.b_mock.borrow_mut().execute_tx(
+            &ibc_handler_address,
+            &self.packet_forward_middleware_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.on_ack_packet(
+                    forwarded_packet(seq, data, FORWARD_TIMEOUT_TIMESTAMP),
+                    managed_buffer!(ack),
+                    managed_address!(&relayer),
+                );
+            },
+        )
+    }
+
+    pub fn timeout_forwarded_transfer(&self, seq: u64, data: &[u8]) -> TxResult {
+        let ibc_handler_address = self.ibc_handler_wrapper.address_ref().clone();
+        let relayer = self.relayer.clone();
+        self.b_mock.borrow_mut().execute_tx(
+            &ibc_handler_address,
+            &self.packet_forward_middleware_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.on_timeout_packet(
+                    forwarded_packet(seq, data, FORWARD_TIMEOUT_TIMESTAMP),
+                    managed_address!(&relayer),
+                );
+            },
+        )
+    }
+
+    /// Checks the acknowledgement written by the handler for a transfer received on `CHANNEL_ID`
+    pub fn check_written_ack(&self, seq: u64, expected: Option<&[u8]>) {
+        self.b_mock
+            .borrow_mut()
+            .execute_query(&self.ibc_handler_wrapper, |sc| {
+                let ack_key = sc.get_packet_acknowledgement_commitment_key(
+                    &managed_buffer!(TRANSFER_PORT_ID),
+                    &managed_buffer!(CHANNEL_ID),
+                    seq,
+                );
+                let comm_mapper = sc.commitments(&ack_key);
+                match expected {
+                    Some(ack) => {
+                        let ack_hash = sc.encode_ack(&managed_buffer!(ack));
+                        assert_eq!(
+                            comm_mapper.get(),
+                            sc.crypto().keccak256(ack_hash.as_managed_buffer())
+                        );
+                    }
+                    None => assert!(comm_mapper.is_empty()),
+                }
+            })
+            .assert_ok();
+    }
+
+    pub fn check_channel_escrow(&self, channel_id: &[u8], token: &[u8], expected: u64) {
+        self.b_mock
+            .borrow_mut()
+            .execute_query(&self.transfer_wrapper, |sc| {
+                let token = EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(token));
+                assert_eq!(
+                    sc.channel_escrow(&managed_buffer!(channel_id), &token)
+                        .get(),
+                    managed_biguint!(expected)
+                );
+            })
+            .assert_ok();
+    }
+
+    /// The packet data of a transfer the middleware forwarded
+    pub fn forwarded_data(&self, denom: &[u8], amount: u64) -> Vec<u8> {
+        let middleware_address = self.packet_forward_middleware_wrapper.address_ref().clone();
+
+        transfer_data(
+            denom,
+            amount,
+            bech32_address(&self.b_mock, &self.transfer_wrapper, &middleware_address).as_bytes(),
+            FORWARD_RECEIVER,
+            "",
+        )
+    }
+}
+
+fn native_token() -> EgldOrEsdtTokenIdentifier<DebugApi> {
+    EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(NATIVE_TOKEN_ID))
+}
+
+/// A memo forwarding the transfer to `FORWARD_RECEIVER` over `FORWARD_CHANNEL_ID`
+pub fn forward_memo(retries: u64) -> String {
+    format!(
+        r#"{{"forward":{{"receiver":"{}","channel":"{}","retries":{}}}}}"#,
+        std::str::from_utf8(FORWARD_RECEIVER).unwrap(),
+        std::str::from_utf8(FORWARD_CHANNEL_ID).unwrap(),
+        retries
+    )
+}
+
+/// `FungibleTokenPacketData` with sorted keys, as ibc-go encodes it
+pub fn transfer_data(
+    denom: &[u8],
+    amount: u64,
+    sender: &[u8],
+    receiver: &[u8],
+    memo: &str,
+) -> Vec<u8> {
+    let memo = if memo.is_empty() {
+        String::new()
+    } else {
+        format!(r#""memo":"{}","#, memo.replace('"', r#"\""#))
+    };
+
+    format!(
+        r#"{{"amount":"{}","denom":"{}",{}"receiver":"{}","sender":"{}"}}"#,
+        amount,
+        std::str::from_utf8(denom).unwrap(),
+        memo,
+        std::str::from_utf8(receiver).unwrap(),
+        std::str::from_utf8(sender).unwrap()
+    )
+    .into_bytes()
+}
+
+/// A packet the previous hop sent to `CHANNEL_ID`
+pub fn received_packet(seq: u64, data: &[u8]) -> Packet<DebugApi> {
+    Packet {
+        seq,
+        src_port: managed_buffer!(TRANSFER_PORT_ID),
+        src_channel: managed_buffer!(COUNTERPARTY_CHANNEL_ID),
+        dest_port: managed_buffer!(TRANSFER_PORT_ID),
+        dest_channel: managed_buffer!(CHANNEL_ID),
+        data: managed_buffer!(data),
+        timeout_height: height::Data::default(),
+        timeout_timestamp: TIMEOUT_TIMESTAMP,
+    }
+}
+
+/// A packet the middleware forwarded on `FORWARD_CHANNEL_ID`
+pub fn forwarded_packet(seq: u64, data: &[u8], timeout_timestamp: u64) -> Packet<DebugApi> {
+    Packet {
+        seq,
+        src_port: managed_buffer!(TRANSFER_PORT_ID),
+        src_channel: managed_buffer!(FORWARD_CHANNEL_ID),
+        dest_port: managed_buffer!(TRANSFER_PORT_ID),
+        dest_channel: managed_buffer!(FORWARD_COUNTERPARTY_CHANNEL_ID),
+        data: managed_buffer!(data),
+        timeout_height: height::Data::default(),
+        timeout_timestamp,
+    }
+}
+
+pub fn open_channel_info(counterparty_channel_id: &[u8]) -> ChannelInfo<DebugApi> {
+    ChannelInfo {
+        channel: channel::Data {
+            state: channel::State::Open,
+            ordering: channel::Order::Unordered,
+            counterparty: channel_counterparty::Data {
+                port_id: managed_buffer!(TRANSFER_PORT_ID),
+                channel_id: managed_buffer!(counterparty_channel_id),
+            },
+            connection_hops: ConnectionHops::from_single_item(managed_buffer!(CONNECTION_ID)),
+            version: managed_buffer!(ICS20_VERSION),
+            upgrade_sequence: 0,
+        },
+        next_seq_send: 1,
+        next_seq_recv: 1,
+        next_seq_ack: 1,
+        upgrade: upgrade::Data::default(),
+        latest_error_rec_seq: 0,
+        recv_start_seq: RecvStartSequence {
+            seq: 0,
+            prev_seq: 0,
+        },
+        ack_start_seq: 0,
+    }
+}
+
+pub fn open_connection() -> connection_end::Data<DebugApi> {
+    let mut features = FeatureVec::new();
+    features.push(managed_buffer!(channel::ORDERED));
+    features.push(managed_buffer!(channel::UNORDERED));
+
+    connection_end::Data {
+        client_id: managed_buffer!(CLIENT_ID),
+        versions: VersionVec::from_single_item(version::Data {
+            identifier: managed_buffer!(b"1"),
+            features,
+        }),
+        state: connection_end::State::Open,
+        counterparty: counterparty::Data {
+            client_id: managed_buffer!(COUNTERPARTY_CLIENT_ID),
+            connection_id: managed_buffer!(COUNTERPARTY_CONNECTION_ID),
+            prefix: merkle_prefix::Data {
+                key_prefix: managed_buffer!(COMMITMENT_PREFIX),
+            },
+        },
+        delay_period: 0,
+    }
+}
@@ -0,0 +1,261 @@
+use std::{cell::RefCell, rc::Rc};
+
+use multiversx_sc_scenario::{
+    imports::BlockchainStateWrapper, managed_buffer, rust_biguint, DebugApi,
+};
+use packet_forward_middleware::forwarding::ForwardingModule;
+use packet_forward_middleware_setup::*;
+use test_utils::bech32_address;
+
+pub mod packet_forward_middleware_setup;
+
+static SUCCESSFUL_TRANSFER_ACK: &[u8] = br#"{"result":"AQ=="}"#;
+static FAILED_TRANSFER_ACK: &[u8] = br#"{"error":"Invalid receiver"}"#;
+static FORWARD_TIMED_OUT_ACK: &[u8] = br#"{"error":"Forwarded transfer timed out"}"#;
+static INVALID_FORWARD_METADATA_ACK: &[u8] = br#"{"error":"Invalid forward metadata"}"#;
+
+type Setup = PacketForwardMiddlewareSetup<
+    fn() -> ibc_handler::ContractObj<DebugApi>,
+    fn() -> mock::ContractObj<DebugApi>,
+    fn() -> packet_forward_middleware::ContractObj<DebugApi>,
+    fn() -> transfer::ContractObj<DebugApi>,
+>;
+
+fn get_packet_forward_middleware_setup() -> Setup {
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_biguint!(0));
+    PacketForwardMiddlewareSetup::new(
+        Rc::new(RefCell::new(b_mock)),
+        &owner,
+        ibc_handler::contract_obj,
+        mock::contract_obj,
+        packet_forward_middleware::contract_obj,
+        transfer::contract_obj,
+    )
+}
+
+/// A transfer of `COUNTERPARTY_DENOM` to forward, with the placeholder receiver of the intermediate chain
+fn voucher_transfer_data(amount: u64, retries: u64) -> Vec<u8> {
+    transfer_data(
+        COUNTERPARTY_DENOM,
+        amount,
+        SENDER,
+        b"pfm",
+        &forward_memo(retries),
+    )
+}
+
+fn check_in_flight_packet(setup: &Setup, seq: u64, expected_retries_left: Option<u64>) {
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.packet_forward_middleware_wrapper, |sc| {
+            let mapper = sc.in_flight_packets(&managed_buffer!(FORWARD_CHANNEL_ID), seq);
+            match expected_retries_left {
+                Some(retries_left) => {
+                    let in_flight = mapper.get();
+                    assert_eq!(in_flight.packet.dest_channel, managed_buffer!(CHANNEL_ID));
+                    assert_eq!(in_flight.retries_left, retries_left);
+                }
+                None => assert!(mapper.is_empty()),
+            }
+        })
+        .assert_ok();
+}
+
+#[test]
+fn forward_voucher_test() {
+    let setup = get_packet_forward_middleware_setup();
+    setup.set_voucher();
+
+    let ack = setup.recv_transfer(1, &voucher_transfer_data(100, 0));
+
+    // the acknowledgement waits for the next hop
+    assert!(ack.is_empty());
+    setup.check_written_ack(1, None);
+    check_in_flight_packet(&setup, 1, Some(0));
+    setup.check_channel_escrow(FORWARD_CHANNEL_ID, VOUCHER_TOKEN_ID, 100);
+    setup.b_mock.borrow().check_esdt_balance(
+        setup.packet_forward_middleware_wrapper.address_ref(),
+        VOUCHER_TOKEN_ID,
+        &rust_biguint!(0),
+    );
+}
+
+#[test]
+fn forwarded_transfer_ack_test() {
+    let setup = get_packet_forward_middleware_setup();
+    setup.set_voucher();
+    setup.recv_transfer(1, &voucher_transfer_data(100, 0));
+
+    let forwarded_data = setup.forwarded_data(VOUCHER_DENOM_TRACE, 100);
+    setup
+        .ack_forwarded_transfer(1, &forwarded_data, SUCCESSFUL_TRANSFER_ACK)
+        .assert_ok();
+
+    setup.check_written_ack(1, Some(SUCCESSFUL_TRANSFER_ACK));
+    check_in_flight_packet(&setup, 1, None);
+    setup.check_channel_escrow(FORWARD_CHANNEL_ID, VOUCHER_TOKEN_ID, 100);
+}
+
+#[test]
+fn forwarded_transfer_error_ack_test() {
+    let setup = get_packet_forward_middleware_setup();
+    setup.set_voucher();
+    setup.recv_transfer(1, &voucher_transfer_data(100, 0));
+
+    let forwarded_data = setup.forwarded_data(VOUCHER_DENOM_TRACE, 100);
+    setup
+        .ack_forwarded_transfer(1, &forwarded_data, FAILED_TRANSFER_ACK)
+        .assert_ok();
+
+    // the vouchers minted for the received transfer are burnt, so the previous hop can refund its sender
+    setup.check_written_ack(1, Some(FAILED_TRANSFER_ACK));
+    check_in_flight_packet(&setup, 1, None);
+    setup.check_channel_escrow(FORWARD_CHANNEL_ID, VOUCHER_TOKEN_ID, 0);
+    for address in [
+        setup.transfer_wrapper.address_ref(),
+        setup.packet_forward_middleware_wrapper.address_ref(),
+    ] {
+        setup
+            .b_mock
+            .borrow()
+            .check_esdt_balance(address, VOUCHER_TOKEN_ID, &rust_biguint!(0));
+    }
+}
+
+#[test]
+fn forwarded_transfer_timeout_retry_test() {
+    let setup = get_packet_forward_middleware_setup();
+    setup.set_voucher();
+    setup.recv_transfer(1, &voucher_transfer_data(100, 1));
+
+    let forwarded_data = setup.forwarded_data(VOUCHER_DENOM_TRACE, 100);
+    setup
+        .timeout_forwarded_transfer(1, &forwarded_data)
+        .assert_ok();
+
+    setup.check_written_ack(1, None);
+    check_in_flight_packet(&setup, 1, None);
+    check_in_flight_packet(&setup, 2, Some(0));
+    setup.check_channel_escrow(FORWARD_CHANNEL_ID, VOUCHER_TOKEN_ID, 100);
+
+    setup
+        .timeout_forwarded_transfer(2, &forwarded_data)
+        .assert_ok();
+
+    setup.check_written_ack(1, Some(FORWARD_TIMED_OUT_ACK));
+    check_in_flight_packet(&setup, 2, None);
+    setup.check_channel_escrow(FORWARD_CHANNEL_ID, VOUCHER_TOKEN_ID, 0);
+}
+
+#[test]
+fn forward_returning_native_token_test() {
+    let setup = get_packet_forward_middleware_setup();
+    setup.set_native_escrow(100);
+
+    let data = transfer_data(RETURNING_NATIVE_DENOM, 60, SENDER, b"pfm", &forward_memo(0));
+    assert!(setup.recv_transfer(1, &data).is_empty());
+    setup.check_channel_escrow(CHANNEL_ID, NATIVE_TOKEN_ID, 40);
+    setup.check_channel_escrow(FORWARD_CHANNEL_ID, NATIVE_TOKEN_ID, 60);
+
+    let forwarded_data = setup.forwarded_data(NATIVE_TOKEN_ID, 60);
+    setup
+        .ack_forwarded_transfer(1, &forwarded_data, FAILED_TRANSFER_ACK)
+        .assert_ok();
+
+    // the tokens are escrowed for the previous hop again
+    setup.check_written_ack(1, Some(FAILED_TRANSFER_ACK));
+    setup.check_channel_escrow(CHANNEL_ID, NATIVE_TOKEN_ID, 100);
+    setup.check_channel_escrow(FORWARD_CHANNEL_ID, NATIVE_TOKEN_ID, 0);
+    setup.b_mock.borrow().check_esdt_balance(
+        setup.transfer_wrapper.address_ref(),
+        NATIVE_TOKEN_ID,
+        &rust_biguint!(100),
+    );
+}
+
+#[test]
+fn recv_invalid_forward_metadata_test() {
+    let setup = get_packet_forward_middleware_setup();
+    setup.set_voucher();
+
+    for memo in [
+        r#"{"forward":{"receiver":"osmo1receiver"}}"#,
+        r#"{"forward":{"receiver":"osmo1receiver","port":"nft-transfer","channel":"channel-1"}}"#,
+        r#"{"forward":{"receiver":"osmo1receiver","channel":"channel-1","timeout":0}}"#,
+        r#"{"forward":"channel-1"}"#,
+    ] {
+        let data = transfer_data(COUNTERPARTY_DENOM, 100, SENDER, b"pfm", memo);
+        assert_eq!(setup.recv_transfer(1, &data), INVALID_FORWARD_METADATA_ACK);
+    }
+
+    setup.check_channel_escrow(FORWARD_CHANNEL_ID, VOUCHER_TOKEN_ID, 0);
+}
+
+#[test]
+fn recv_failed_transfer_not_forwarded_test() {
+    let setup = get_packet_forward_middleware_setup();
+
+    // the voucher of the denom isn't issued, so the middleware can't tell which token it would forward
+    let ack = setup.recv_transfer(1, &voucher_transfer_data(100, 0));
+
+    assert_eq!(ack, br#"{"error":"Unknown denom"}"#);
+    check_in_flight_packet(&setup, 1, None);
+}
+
+#[test]
+fn recv_transfer_forward_setup_failed_test() {
+    let setup = get_packet_forward_middleware_setup();
+    setup.set_voucher();
+
+    for (memo, expected_ack) in [
+        (
+            r#"{"forward":{"receiver":"osmo1receiver","channel":"channel-7"}}"#,
+            br#"{"error":"Forward channel not open"}"#.as_slice(),
+        ),
+        (
+            r#"{"forward":{"receiver":"osmo1receiver","channel":"channel-1","timeout":"18446744073709551615"}}"#,
+            br#"{"error":"Invalid timeout"}"#.as_slice(),
+        ),
+    ] {
+        let data = transfer_data(COUNTERPARTY_DENOM, 100, SENDER, b"pfm", memo);
+        assert_eq!(setup.recv_transfer(1, &data), expected_ack);
+    }
+
+    // the vouchers minted for the received transfers are burnt again
+    check_in_flight_packet(&setup, 1, None);
+    setup.check_channel_escrow(FORWARD_CHANNEL_ID, VOUCHER_TOKEN_ID, 0);
+    for address in [
+        setup.transfer_wrapper.address_ref(),
+        setup.packet_forward_middleware_wrapper.address_ref(),
+    ] {
+        setup
+            .b_mock
+            .borrow()
+            .check_esdt_balance(address, VOUCHER_TOKEN_ID, &rust_biguint!(0));
+    }
+}
+
+#[test]
+fn recv_transfer_without_forward_test() {
+    let setup = get_packet_forward_middleware_setup();
+    setup.set_voucher();
+    let receiver = setup.relayer.clone();
+    let receiver_bech32 = bech32_address(&setup.b_mock, &setup.transfer_wrapper, &receiver);
+
+    let data = transfer_data(
+        COUNTERPARTY_DENOM,
+        100,
+        SENDER,
+        receiver_bech32.as_bytes(),
+        r#"{"note":"hello"}"#,
+    );
+    let ack = setup.recv_transfer(1, &data);
+
+    assert_eq!(ack, SUCCESSFUL_TRANSFER_ACK);
+    setup
+        .b_mock
+        .borrow()
+        .check_esdt_balance(&receiver, VOUCHER_TOKEN_ID, &rust_biguint!(100));
+}
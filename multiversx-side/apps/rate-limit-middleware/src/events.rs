@@ -0,0 +1,32 @@
+use common_types::{ChannelId, Sequence};
+
+use crate::rate_limits::RateLimit;
+
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait EventsModule {
+    #[event("setRateLimitEvent")]
+    fn set_rate_limit_event(
+        &self,
+        #[indexed] channel_id: &ChannelId<Self::Api>,
+        #[indexed] denom: &ManagedBuffer,
+        rate_limit: &RateLimit<Self::Api>,
+    );
+
+    #[event("removeRateLimitEvent")]
+    fn remove_rate_limit_event(
+        &self,
+        #[indexed] channel_id: &ChannelId<Self::Api>,
+        #[indexed] denom: &ManagedBuffer,
+    );
+
+    #[event("inflowRateLimitExceededEvent")]
+    fn inflow_rate_limit_exceeded_event(
+        &self,
+        #[indexed] channel_id: &ChannelId<Self::Api>,
+        #[indexed] denom: &ManagedBuffer,
+        #[indexed] seq: Sequence,
+        amount: &BigUint,
+    );
+}
@@ -0,0 +1,217 @@
+use ::channel::{
+    channel_libs::packet_types::Packet,
+    interfaces::ibc_module_interface::{
+        ibc_module_proxy::ProxyTrait as _, MsgOnChanCloseConfirm, MsgOnChanCloseInit,
+        MsgOnChanOpenAck, MsgOnChanOpenConfirm, MsgOnChanOpenInit, MsgOnChanOpenTry,
+        MsgOnChanUpgradeAck, MsgOnChanUpgradeInit, MsgOnChanUpgradeOpen, MsgOnChanUpgradeTry,
+    },
+};
+use common_types::{channel_types::acknowledgement, trace, ChannelId, PortId, Sequence, Version};
+use transfer::packet_data::FungibleTokenPacketData;
+
+multiversx_sc::imports!();
+
+static INFLOW_RATE_LIMIT_EXCEEDED_ERR_MSG: &[u8] = b"Inflow rate limit exceeded";
+
+/// The callbacks of the IBC handler, forwarded to the module below
+///
+/// The middleware doesn't negotiate a version of its own, so handshakes and upgrades are passed down unchanged.
+#[multiversx_sc::module]
+pub trait IbcModule:
+    crate::rate_limits::RateLimitsModule
+    + crate::ics4_wrapper::Ics4WrapperModule
+    + ::channel::middleware::ibc_middleware::IbcMiddlewareModule
+    + crate::events::EventsModule
+{
+    #[endpoint(onChanOpenInit)]
+    fn on_chan_open_init(&self, args: MsgOnChanOpenInit<Self::Api>) -> Version<Self::Api> {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        self.ibc_module_proxy(lower_module)
+            .on_chan_open_init(args)
+            .execute_on_dest_context()
+    }
+
+    #[endpoint(onChanOpenTry)]
+    fn on_chan_open_try(&self, args: MsgOnChanOpenTry<Self::Api>) -> Version<Self::Api> {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        self.ibc_module_proxy(lower_module)
+            .on_chan_open_try(args)
+            .execute_on_dest_context()
+    }
+
+    #[endpoint(onChanOpenAck)]
+    fn on_chan_open_ack(&self, args: MsgOnChanOpenAck<Self::Api>) {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        self.ibc_module_proxy(lower_module)
+            .on_chan_open_ack(args)
+            .execute_on_dest_context::<()>();
+    }
+
+    #[endpoint(onChanOpenConfirm)]
+    fn on_chan_open_confirm(&self, args: MsgOnChanOpenConfirm<Self::Api>) {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        self.ibc_module_proxy(lower_module)
+            .on_chan_open_confirm(args)
+            .execute_on_dest_context::<()>();
+    }
+
+    #[endpoint(onChanCloseInit)]
+    fn on_chan_close_init(&self, args: MsgOnChanCloseInit<Self::Api>) {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        self.ibc_module_proxy(lower_module)
+            .on_chan_close_init(args)
+            .execute_on_dest_context::<()>();
+    }
+
+    #[endpoint(onChanCloseConfirm)]
+    fn on_chan_close_confirm(&self, args: MsgOnChanCloseConfirm<Self::Api>) {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        self.ibc_module_proxy(lower_module)
+            .on_chan_close_confirm(args)
+            .execute_on_dest_context::<()>();
+    }
+
+    #[endpoint(onChanUpgradeInit)]
+    fn on_chan_upgrade_init(&self, args: MsgOnChanUpgradeInit<Self::Api>) -> Version<Self::Api> {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        self.ibc_module_proxy(lower_module)
+            .on_chan_upgrade_init(args)
+            .execute_on_dest_context()
+    }
+
+    #[endpoint(onChanUpgradeTry)]
+    fn on_chan_upgrade_try(&self, args: MsgOnChanUpgradeTry<Self::Api>) -> Version<Self::Api> {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        self.ibc_module_proxy(lower_module)
+            .on_chan_upgrade_try(args)
+            .execute_on_dest_context()
+    }
+
+    #[endpoint(onChanUpgradeAck)]
+    fn on_chan_upgrade_ack(&self, args: MsgOnChanUpgradeAck<Self::Api>) {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        self.ibc_module_proxy(lower_module)
+            .on_chan_upgrade_ack(args)
+            .execute_on_dest_context::<()>();
+    }
+
+    #[endpoint(onChanUpgradeOpen)]
+    fn on_chan_upgrade_open(&self, args: MsgOnChanUpgradeOpen<Self::Api>) {
+        let lower_module = self.require_upper_module_caller(&args.port_id, &args.channel_id);
+
+        self.ibc_module_proxy(lower_module)
+            .on_chan_upgrade_open(args)
+            .execute_on_dest_context::<()>();
+    }
+
+    #[view(canTransitionToFlushComplete)]
+    fn can_transition_to_flush_complete(
+        &self,
+        port_id: PortId<Self::Api>,
+        channel_id: ChannelId<Self::Api>,
+        upgrade_sequence: Sequence,
+    ) -> bool {
+        let (_, lower_module) = self.get_stack_neighbours(&port_id, &channel_id);
+        self.ibc_module_proxy(lower_module)
+            .can_transition_to_flush_complete(port_id, channel_id, upgrade_sequence)
+            .execute_on_dest_context()
+    }
+
+    /// Transfers exceeding the inflow quota of their token are answered with an error acknowledgement, which refunds
+    /// the sender, without reaching the app
+    ///
+    /// The inflow is recorded unless the app answers with an error acknowledgement. Asynchronous acknowledgements are
+    /// not known yet, so they always count.
+    #[endpoint(onRecvPacket)]
+    fn on_recv_packet(&self, packet: Packet<Self::Api>, relayer: ManagedAddress) -> ManagedBuffer {
+        let lower_module =
+            self.require_upper_module_caller(&packet.dest_port, &packet.dest_channel);
+
+        let inflow = FungibleTokenPacketData::from_json(&packet.data).map(|packet_data| {
+            (
+                self.local_denom(&packet, &packet_data.denom),
+                packet_data.amount,
+            )
+        });
+        if let Some((denom, amount)) = &inflow {
+            if !self.is_inflow_allowed(&packet.dest_channel, denom, amount) {
+                self.inflow_rate_limit_exceeded_event(
+                    &packet.dest_channel,
+                    denom,
+                    packet.seq,
+                    amount,
+                );
+                return acknowledgement::new_error(INFLOW_RATE_LIMIT_EXCEEDED_ERR_MSG);
+            }
+        }
+
+        let channel_id = packet.dest_channel.clone();
+        let ack: ManagedBuffer = self
+            .ibc_module_proxy(lower_module)
+            .on_recv_packet(packet, relayer)
+            .execute_on_dest_context();
+
+        if let Some((denom, amount)) = inflow {
+            if acknowledgement::is_successful(&ack) != Some(false) {
+                self.record_inflow(&channel_id, &denom, &amount);
+            }
+        }
+
+        ack
+    }
+
+    /// Gives back the outflow of a transfer which failed on the receiving chain
+    #[endpoint(onAcknowledgementPacket)]
+    fn on_ack_packet(
+        &self,
+        packet: Packet<Self::Api>,
+        ack: ManagedBuffer,
+        relayer: ManagedAddress,
+    ) {
+        let lower_module = self.require_upper_module_caller(&packet.src_port, &packet.src_channel);
+
+        if acknowledgement::is_successful(&ack) == Some(false) {
+            self.undo_outflow(&packet.src_channel, packet.seq);
+        } else {
+            self.pending_sends(&packet.src_channel, packet.seq).clear();
+        }
+
+        self.ibc_module_proxy(lower_module)
+            .on_ack_packet(packet, ack, relayer)
+            .execute_on_dest_context::<()>();
+    }
+
+    #[endpoint(onTimeoutPacket)]
+    fn on_timeout_packet(&self, packet: Packet<Self::Api>, relayer: ManagedAddress) {
+        let lower_module = self.require_upper_module_caller(&packet.src_port, &packet.src_channel);
+
+        self.undo_outflow(&packet.src_channel, packet.seq);
+
+        self.ibc_module_proxy(lower_module)
+            .on_timeout_packet(packet, relayer)
+            .execute_on_dest_context::<()>();
+    }
+
+    /// The denom of incoming tokens on this chain, as the transfer app computes it
+    fn local_denom(&self, packet: &Packet<Self::Api>, denom: &ManagedBuffer) -> ManagedBuffer {
+        if let Some(native_denom) =
+            trace::strip_hop_prefix(denom, &packet.src_port, &packet.src_channel)
+        {
+            return native_denom;
+        }
+
+        let mut denom_trace = trace::hop_prefix(&packet.dest_port, &packet.dest_channel);
+        denom_trace.append(denom);
+
+        denom_trace
+    }
+}
@@ -0,0 +1,29 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod events;
+pub mod ibc_module;
+pub mod ics4_wrapper;
+pub mod rate_limits;
+
+/// Middleware restricting the amounts of ICS-20 tokens which flow through a channel over a window of time
+///
+/// The middleware is added to the stack of the port of the transfer app in the IBC handler. Tokens without a rate
+/// limit on the channel are not restricted.
+#[multiversx_sc::contract]
+pub trait RateLimitMiddleware:
+    rate_limits::RateLimitsModule
+    + ibc_module::IbcModule
+    + ics4_wrapper::Ics4WrapperModule
+    + ::channel::middleware::ibc_middleware::IbcMiddlewareModule
+    + events::EventsModule
+{
+    #[init]
+    fn init(&self, ibc_handler: ManagedAddress) {
+        self.ibc_handler().set(ibc_handler);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+}
@@ -0,0 +1,295 @@
+use common_types::{ChannelId, Sequence};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+/// Percentages are in basis points
+pub const MAX_PERCENTAGE: u64 = 10_000;
+
+/// The maximum amount which may flow in one direction during a window
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub enum Quota<M: ManagedTypeApi> {
+    Unlimited,
+    Amount(BigUint<M>),
+    SupplyPercentage(u64),
+}
+
+impl<M: ManagedTypeApi> Quota<M> {
+    /// Returns `None` if the quota is unlimited
+    pub fn capacity(&self, supply: &BigUint<M>) -> Option<BigUint<M>> {
+        match self {
+            Quota::Unlimited => None,
+            Quota::Amount(amount) => Some(amount.clone()),
+            Quota::SupplyPercentage(percentage) => {
+                Some(supply * &BigUint::from(*percentage) / MAX_PERCENTAGE)
+            }
+        }
+    }
+
+    pub fn is_supply_percentage(&self) -> bool {
+        matches!(self, Quota::SupplyPercentage(_))
+    }
+}
+
+/// The quotas of a token on a channel, over windows of `window_duration` seconds
+///
+/// Contracts can't read the supply of a token, so the owner keeps `supply` up to date for the percentage quotas. The
+/// quotas follow the supply only as closely as it is updated: if it is stale they are too loose after the supply
+/// shrinks, and too tight after it grows.
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct RateLimit<M: ManagedTypeApi> {
+    pub window_duration: u64,
+    pub max_outflow: Quota<M>,
+    pub max_inflow: Quota<M>,
+    pub supply: BigUint<M>,
+}
+
+/// The amounts which flowed through a channel since the start of the current window
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct Flow<M: ManagedTypeApi> {
+    pub window_start: u64,
+    pub outflow: BigUint<M>,
+    pub inflow: BigUint<M>,
+}
+
+/// An outflow which is given back if the packet fails, as long as its window is still the current one
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct PendingSend<M: ManagedTypeApi> {
+    pub denom: ManagedBuffer<M>,
+    pub amount: BigUint<M>,
+    pub window_start: u64,
+}
+
+/// Owner configured quotas per channel and denom
+///
+/// Outgoing tokens are limited by their denom in the packet data, incoming ones by their denom on this chain, i.e.
+/// the trace of the vouchers they are minted as, or the native denom of the tokens coming back.
+#[multiversx_sc::module]
+pub trait RateLimitsModule: crate::events::EventsModule {
+    /// Sets the rate limit of a token on a channel, starting a new window
+    #[only_owner]
+    #[endpoint(setRateLimit)]
+    fn set_rate_limit(
+        &self,
+        channel_id: ChannelId<Self::Api>,
+        denom: ManagedBuffer,
+        window_duration: u64,
+        max_outflow: Quota<Self::Api>,
+        max_inflow: Quota<Self::Api>,
+        supply: BigUint,
+    ) {
+        require!(window_duration > 0, "Window duration must be positive");
+        for quota in [&max_outflow, &max_inflow] {
+            if let Quota::SupplyPercentage(percentage) = quota {
+                require!(*percentage <= MAX_PERCENTAGE, "Invalid percentage");
+            }
+        }
+
+        let rate_limit = RateLimit {
+            window_duration,
+            max_outflow,
+            max_inflow,
+            supply,
+        };
+        self.require_supply_set(&rate_limit);
+
+        self.set_rate_limit_event(&channel_id, &denom, &rate_limit);
+        self.rate_limits(&channel_id, &denom).set(rate_limit);
+        self.flows(&channel_id, &denom).clear();
+    }
+
+    /// Updates the supply the percentage quotas are computed from, without starting a new window
+    #[only_owner]
+    #[endpoint(updateSupply)]
+    fn update_supply(
+        &self,
+        channel_id: ChannelId<Self::Api>,
+        denom: ManagedBuffer,
+        supply: BigUint,
+    ) {
+        let rate_limit_mapper = self.rate_limits(&channel_id, &denom);
+        require!(!rate_limit_mapper.is_empty(), "Rate limit not found");
+
+        let mut rate_limit = rate_limit_mapper.get();
+        rate_limit.supply = supply;
+        self.require_supply_set(&rate_limit);
+
+        self.set_rate_limit_event(&channel_id, &denom, &rate_limit);
+        rate_limit_mapper.set(rate_limit);
+    }
+
+    #[only_owner]
+    #[endpoint(removeRateLimit)]
+    fn remove_rate_limit(&self, channel_id: ChannelId<Self::Api>, denom: ManagedBuffer) {
+        let rate_limit_mapper = self.rate_limits(&channel_id, &denom);
+        require!(!rate_limit_mapper.is_empty(), "Rate limit not found");
+
+        rate_limit_mapper.clear();
+        self.flows(&channel_id, &denom).clear();
+        self.remove_rate_limit_event(&channel_id, &denom);
+    }
+
+    /// Starts a new window, e.g. to lift a breach once it was investigated
+    #[only_owner]
+    #[endpoint(resetFlow)]
+    fn reset_flow(&self, channel_id: ChannelId<Self::Api>, denom: ManagedBuffer) {
+        require!(
+            !self.rate_limits(&channel_id, &denom).is_empty(),
+            "Rate limit not found"
+        );
+
+        self.flows(&channel_id, &denom).clear();
+    }
+
+    /// The usage of the quotas in the current window, which is empty if the last one has elapsed
+    #[view(getFlow)]
+    fn get_flow(&self, channel_id: ChannelId<Self::Api>, denom: ManagedBuffer) -> Flow<Self::Api> {
+        let rate_limit_mapper = self.rate_limits(&channel_id, &denom);
+        require!(!rate_limit_mapper.is_empty(), "Rate limit not found");
+
+        self.current_flow(&channel_id, &denom, &rate_limit_mapper.get())
+    }
+
+    /// A zero supply would block every transfer of a token with a percentage quota
+    fn require_supply_set(&self, rate_limit: &RateLimit<Self::Api>) {
+        let has_supply_percentage = rate_limit.max_outflow.is_supply_percentage()
+            || rate_limit.max_inflow.is_supply_percentage();
+        require!(
+            !has_supply_percentage || rate_limit.supply > 0u64,
+            "Supply must be positive"
+        );
+    }
+
+    /// Adds an outgoing amount to the flow, returning the start of its window if the token is rate limited
+    fn record_outflow(
+        &self,
+        channel_id: &ChannelId<Self::Api>,
+        denom: &ManagedBuffer,
+        amount: &BigUint,
+    ) -> Option<u64> {
+        let rate_limit_mapper = self.rate_limits(channel_id, denom);
+        if rate_limit_mapper.is_empty() {
+            return None;
+        }
+
+        let rate_limit = rate_limit_mapper.get();
+        let mut flow = self.current_flow(channel_id, denom, &rate_limit);
+        flow.outflow += amount;
+        if let Some(capacity) = rate_limit.max_outflow.capacity(&rate_limit.supply) {
+            require!(flow.outflow <= capacity, "Outflow rate limit exceeded");
+        }
+
+        let window_start = flow.window_start;
+        self.flows(channel_id, denom).set(flow);
+
+        Some(window_start)
+    }
+
+    /// Returns false if an incoming amount would exceed the inflow quota of its token
+    fn is_inflow_allowed(
+        &self,
+        channel_id: &ChannelId<Self::Api>,
+        denom: &ManagedBuffer,
+        amount: &BigUint,
+    ) -> bool {
+        let rate_limit_mapper = self.rate_limits(channel_id, denom);
+        if rate_limit_mapper.is_empty() {
+            return true;
+        }
+
+        let rate_limit = rate_limit_mapper.get();
+        match rate_limit.max_inflow.capacity(&rate_limit.supply) {
+            Some(capacity) => {
+                let flow = self.current_flow(channel_id, denom, &rate_limit);
+                flow.inflow + amount <= capacity
+            }
+            None => true,
+        }
+    }
+
+    fn record_inflow(
+        &self,
+        channel_id: &ChannelId<Self::Api>,
+        denom: &ManagedBuffer,
+        amount: &BigUint,
+    ) {
+        let rate_limit_mapper = self.rate_limits(channel_id, denom);
+        if rate_limit_mapper.is_empty() {
+            return;
+        }
+
+        let mut flow = self.current_flow(channel_id, denom, &rate_limit_mapper.get());
+        flow.inflow += amount;
+        self.flows(channel_id, denom).set(flow);
+    }
+
+    /// Gives back the outflow of a packet which failed
+    fn undo_outflow(&self, channel_id: &ChannelId<Self::Api>, seq: Sequence) {
+        let pending_send_mapper = self.pending_sends(channel_id, seq);
+        if pending_send_mapper.is_empty() {
+            return;
+        }
+
+        let pending_send = pending_send_mapper.take();
+        let rate_limit_mapper = self.rate_limits(channel_id, &pending_send.denom);
+        if rate_limit_mapper.is_empty() {
+            return;
+        }
+
+        let mut flow = self.current_flow(channel_id, &pending_send.denom, &rate_limit_mapper.get());
+        if flow.window_start != pending_send.window_start {
+            return;
+        }
+
+        if flow.outflow > pending_send.amount {
+            flow.outflow -= &pending_send.amount;
+        } else {
+            flow.outflow = BigUint::zero();
+        }
+        self.flows(channel_id, &pending_send.denom).set(flow);
+    }
+
+    fn current_flow(
+        &self,
+        channel_id: &ChannelId<Self::Api>,
+        denom: &ManagedBuffer,
+        rate_limit: &RateLimit<Self::Api>,
+    ) -> Flow<Self::Api> {
+        let now = self.blockchain().get_block_timestamp();
+        let flow_mapper = self.flows(channel_id, denom);
+        if !flow_mapper.is_empty() {
+            let flow = flow_mapper.get();
+            if now < flow.window_start + rate_limit.window_duration {
+                return flow;
+            }
+        }
+
+        Flow {
+            window_start: now,
+            outflow: BigUint::zero(),
+            inflow: BigUint::zero(),
+        }
+    }
+
+    #[view(getRateLimit)]
+    #[storage_mapper("rateLimits")]
+    fn rate_limits(
+        &self,
+        channel_id: &ChannelId<Self::Api>,
+        denom: &ManagedBuffer,
+    ) -> SingleValueMapper<RateLimit<Self::Api>>;
+
+    #[storage_mapper("flows")]
+    fn flows(
+        &self,
+        channel_id: &ChannelId<Self::Api>,
+        denom: &ManagedBuffer,
+    ) -> SingleValueMapper<Flow<Self::Api>>;
+
+    #[storage_mapper("pendingSends")]
+    fn pending_sends(
+        &self,
+        channel_id: &ChannelId<Self::Api>,
+        seq: Sequence,
+    ) -> SingleValueMapper<PendingSend<Self::Api>>;
+}
@@ -0,0 +1,451 @@
+#![allow(dead_code)]
+
+use std::{cell::RefCell, rc::Rc};
+
+use ::channel::channel_libs::packet_types::Packet;
+use client_common::ClientStatus;
+use common_types::{
+    channel_types::{channel, channel_counterparty, height, upgrade},
+    connection_types::{connection_end, counterparty, merkle_prefix, version},
+    ConnectionHops, FeatureVec, VersionVec,
+};
+use host::{
+    host_config::HostConfigModule,
+    module_manager::ModuleManagerModule,
+    storage::{ChannelInfo, ClientInfo, RecvStartSequence, StorageModule},
+};
+use ibc_handler::IbcHandler;
+use mock::{
+    client_logic::ClientLogicModule,
+    mock_types::{client_state, consensus_state},
+    Mock,
+};
+use mock_app::{MockApp, MOCK_APP_VERSION};
+use multiversx_sc::types::{Address, MultiValueEncoded};
+use multiversx_sc_scenario::{
+    imports::{BlockchainStateWrapper, ContractObjWrapper, TxResult},
+    managed_address, managed_biguint, managed_buffer, rust_biguint, DebugApi,
+};
+use rate_limit_middleware::{
+    ibc_module::IbcModule,
+    ics4_wrapper::Ics4WrapperModule,
+    rate_limits::{Quota, RateLimitsModule},
+    RateLimitMiddleware,
+};
+
+pub static CLIENT_TYPE: &[u8] = b"mock-client";
+pub static CLIENT_ID: &[u8] = b"mock-client-0";
+pub static CONNECTION_ID: &[u8] = b"connection-0";
+pub static COUNTERPARTY_CLIENT_ID: &[u8] = b"counterparty-client-0";
+pub static COUNTERPARTY_CONNECTION_ID: &[u8] = b"connection-7";
+pub static PORT_ID: &[u8] = b"mockapp";
+pub static CHANNEL_ID: &[u8] = b"channel-0";
+pub static COUNTERPARTY_PORT_ID: &[u8] = b"counterparty-mockapp";
+pub static COUNTERPARTY_CHANNEL_ID: &[u8] = b"channel-5";
+pub static COMMITMENT_PREFIX: &[u8] = b"ibc";
+
+/// A token native to this chain
+pub static NATIVE_DENOM: &[u8] = b"WEGLD-123456";
+/// `NATIVE_DENOM`, as the counterparty sends it back
+pub static RETURNING_NATIVE_DENOM: &[u8] = b"counterparty-mockapp/channel-5/WEGLD-123456";
+/// A token native to the counterparty chain
+pub static COUNTERPARTY_DENOM: &[u8] = b"uatom";
+/// The denom of the vouchers of `COUNTERPARTY_DENOM` on this chain
+pub static VOUCHER_DENOM: &[u8] = b"mockapp/channel-0/uatom";
+
+pub const PROOF_HEIGHT: height::Data = height::Data {
+    revision_number: 0,
+    revision_height: 10,
+};
+pub const CONSENSUS_TIMESTAMP: u64 = 1_000_000_000;
+pub const TIMEOUT_TIMESTAMP: u64 = 1_000_000_000_000;
+pub const START_TIMESTAMP: u64 = 1_000;
+pub const WINDOW_DURATION: u64 = 3_600;
+
+/// `Quota`, which can only be built inside transactions
+#[derive(Clone, Copy)]
+pub enum TestQuota {
+    Unlimited,
+    Amount(u64),
+    SupplyPercentage(u64),
+}
+
+impl TestQuota {
+    pub fn to_quota(self) -> Quota<DebugApi> {
+        match self {
+            TestQuota::Unlimited => Quota::Unlimited,
+            TestQuota::Amount(amount) => Quota::Amount(managed_biguint!(amount)),
+            TestQuota::SupplyPercentage(percentage) => Quota::SupplyPercentage(percentage),
+        }
+    }
+}
+
+pub struct RateLimitMiddlewareSetup<
+    IbcHandlerObjBuilder,
+    MockClientObjBuilder,
+    RateLimitMiddlewareObjBuilder,
+    MockAppObjBuilder,
+> where
+    IbcHandlerObjBuilder: 'static + Copy + Fn() -> ibc_handler::ContractObj<DebugApi>,
+    MockClientObjBuilder: 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    RateLimitMiddlewareObjBuilder:
+        'static + Copy + Fn() -> rate_limit_middleware::ContractObj<DebugApi>,
+    MockAppObjBuilder: 'static + Copy + Fn() -> mock_app::ContractObj<DebugApi>,
+{
+    pub b_mock: Rc<RefCell<BlockchainStateWrapper>>,
+    pub owner: Address,
+    pub relayer: Address,
+    pub ibc_handler_wrapper:
+        ContractObjWrapper<ibc_handler::ContractObj<DebugApi>, IbcHandlerObjBuilder>,
+    pub mock_client_wrapper: ContractObjWrapper<mock::ContractObj<DebugApi>, MockClientObjBuilder>,
+    pub rate_limit_middleware_wrapper: ContractObjWrapper<
+        rate_limit_middleware::ContractObj<DebugApi>,
+        RateLimitMiddlewareObjBuilder,
+    >,
+    pub mock_app_wrapper: ContractObjWrapper<mock_app::ContractObj<DebugApi>, MockAppObjBuilder>,
+}
+
+impl<
+        IbcHandlerObjBuilder,
+        MockClientObjBuilder,
+        RateLimitMiddlewareObjBuilder,
+        MockAppObjBuilder,
+    >
+    RateLimitMiddlewareSetup<
+        IbcHandlerObjBuilder,
+        MockClientObjBuilder,
+        RateLimitMiddlewareObjBuilder,
+        MockAppObjBuilder,
+    >
+where
+    IbcHandlerObjBuilder: 'static + Copy + Fn() -> ibc_handler::ContractObj<DebugApi>,
+    MockClientObjBuilder: 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    RateLimitMiddlewareObjBuilder:
+        'static + Copy + Fn() -> rate_limit_middleware::ContractObj<DebugApi>,
+    MockAppObjBuilder: 'static + Copy + Fn() -> mock_app::ContractObj<DebugApi>,
+{
+    pub fn new(
+        b_mock: Rc<RefCell<BlockchainStateWrapper>>,
+        owner: &Address,
+        ibc_handler_builder: IbcHandlerObjBuilder,
+        mock_client_builder: MockClientObjBuilder,
+        rate_limit_middleware_builder: RateLimitMiddlewareObjBuilder,
+        mock_app_builder: MockAppObjBuilder,
+    ) -> Self {
+        let rust_zero = rust_biguint!(0u64);
+        let relayer = b_mock.borrow_mut().create_user_account(&rust_zero);
+        b_mock.borrow_mut().set_block_timestamp(START_TIMESTAMP);
+
+        let ibc_handler_wrapper = b_mock.borrow_mut().create_sc_account(
+            &rust_zero,
+            Some(owner),
+            ibc_handler_builder,
+            "ibc handler",
+        );
+        let mock_client_wrapper = b_mock.borrow_mut().create_sc_account(
+            &rust_zero,
+            Some(owner),
+            mock_client_builder,
+            "mock client",
+        );
+        let rate_limit_middleware_wrapper = b_mock.borrow_mut().create_sc_account(
+            &rust_zero,
+            Some(owner),
+            rate_limit_middleware_builder,
+            "rate limit middleware",
+        );
+        let mock_app_wrapper = b_mock.borrow_mut().create_sc_account(
+            &rust_zero,
+            Some(owner),
+            mock_app_builder,
+            "mock app",
+        );
+
+        let ibc_handler_address = ibc_handler_wrapper.address_ref().clone();
+        let mock_client_address = mock_client_wrapper.address_ref().clone();
+        let rate_limit_middleware_address = rate_limit_middleware_wrapper.address_ref().clone();
+        let mock_app_address = mock_app_wrapper.address_ref().clone();
+
+        b_mock
+            .borrow_mut()
+            .execute_tx(owner, &mock_client_wrapper, &rust_zero, |sc| {
+                sc.init(managed_address!(&ibc_handler_address));
+
+                let client_id = managed_buffer!(CLIENT_ID);
+                sc.client_states(&client_id)
+                    .set(client_state::Data::new(PROOF_HEIGHT));
+                sc.consensus_states(&client_id, &PROOF_HEIGHT.to_biguint_concat())
+                    .set(consensus_state::Data::new(CONSENSUS_TIMESTAMP));
+                sc.statuses(&client_id).set(ClientStatus::Active);
+            })
+            .assert_ok();
+
+        b_mock
+            .borrow_mut()
+            .execute_tx(owner, &rate_limit_middleware_wrapper, &rust_zero, |sc| {
+                sc.init(managed_address!(&ibc_handler_address));
+            })
+            .assert_ok();
+
+        b_mock
+            .borrow_mut()
+            .execute_tx(owner, &mock_app_wrapper, &rust_zero, |sc| {
+                sc.init(managed_address!(&rate_limit_middleware_address));
+            })
+            .assert_ok();
+
+        b_mock
+            .borrow_mut()
+            .execute_tx(owner, &ibc_handler_wrapper, &rust_zero, |sc| {
+                sc.init();
+
+                sc.set_expected_time_per_block(6);
+                sc.register_client(
+                    managed_buffer!(CLIENT_TYPE),
+                    managed_address!(&mock_client_address),
+                );
+                sc.bind_port(
+                    managed_buffer!(PORT_ID),
+                    managed_address!(&mock_app_address),
+                );
+                let mut middlewares = MultiValueEncoded::new();
+                middlewares.push(managed_address!(&rate_limit_middleware_address));
+                sc.set_port_middlewares(managed_buffer!(PORT_ID), middlewares);
+
+                sc.client_info(&managed_buffer!(CLIENT_ID)).set(ClientInfo {
+                    client_type: managed_buffer!(CLIENT_TYPE),
+                    client_impl: managed_address!(&mock_client_address),
+                });
+                sc.connection_info(&managed_buffer!(CONNECTION_ID))
+                    .set(open_connection());
+
+                let port_id = managed_buffer!(PORT_ID);
+                let channel_id = managed_buffer!(CHANNEL_ID);
+                sc.claim_channel_stack(&port_id, &channel_id);
+                sc.channel_info(&port_id, &channel_id)
+                    .set(open_channel_info());
+            })
+            .assert_ok();
+
+        Self {
+            b_mock,
+            owner: owner.clone(),
+            relayer,
+            ibc_handler_wrapper,
+            mock_client_wrapper,
+            rate_limit_middleware_wrapper,
+            mock_app_wrapper,
+        }
+    }
+
+    pub fn set_rate_limit(
+        &self,
+        denom: &[u8],
+        max_outflow: TestQuota,
+        max_inflow: TestQuota,
+        supply: u64,
+    ) {
+        self.b_mock
+            .borrow_mut()
+            .execute_tx(
+                &self.owner,
+                &self.rate_limit_middleware_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    sc.set_rate_limit(
+                        managed_buffer!(CHANNEL_ID),
+                        managed_buffer!(denom),
+                        WINDOW_DURATION,
+                        max_outflow.to_quota(),
+                        max_inflow.to_quota(),
+                        managed_biguint!(supply),
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
+    /// Sends a transfer on `CHANNEL_ID` as the app
+    pub fn send_transfer(&self, denom: &[u8], amount: u64) -> TxResult {
+        let mock_app_address = self.mock_app_wrapper.address_ref().clone();
+        self.b_mock.borrow_mut().execute_tx(
+            &mock_app_address,
+            &self.rate_limit_middleware_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.send_packet(
+                    managed_buffer!(PORT_ID),
+                    managed_buffer!(CHANNEL_ID),
+                    height::Data::default(),
+                    TIMEOUT_TIMESTAMP,
+                    managed_buffer!(&transfer_data(denom, amount)),
+                );
+            },
+        )
+    }
+
+    /// Delivers the acknowledgement of a transfer sent on `CHANNEL_ID`, as the handler does for the relayer
+    pub fn ack_transfer(&self, seq: u64, denom: &[u8], amount: u64, ack: &[u8]) {
+        let ibc_handler_address = self.ibc_handler_wrapper.address_ref().clone();
+        let relayer = self.relayer.clone();
+        self.b_mock
+            .borrow_mut()
+            .execute_tx(
+                &ibc_handler_address,
+                &self.rate_limit_middleware_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    sc.on_ack_packet(
+                        sent_packet(seq, &transfer_data(denom, amount)),
+                        managed_buffer!(ack),
+                        managed_address!(&relayer),
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn timeout_transfer(&self, seq: u64, denom: &[u8], amount: u64) {
+        let ibc_handler_address = self.ibc_handler_wrapper.address_ref().clone();
+        let relayer = self.relayer.clone();
+        self.b_mock
+            .borrow_mut()
+            .execute_tx(
+                &ibc_handler_address,
+                &self.rate_limit_middleware_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    sc.on_timeout_packet(
+                        sent_packet(seq, &transfer_data(denom, amount)),
+                        managed_address!(&relayer),
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
+    /// Delivers a transfer of the counterparty to `CHANNEL_ID`, returning the acknowledgement
+    pub fn recv_transfer(&self, seq: u64, denom: &[u8], amount: u64) -> Vec<u8> {
+        let mut ack = Vec::new();
+        let ibc_handler_address = self.ibc_handler_wrapper.address_ref().clone();
+        let relayer = self.relayer.clone();
+        self.b_mock
+            .borrow_mut()
+            .execute_tx(
+                &ibc_handler_address,
+                &self.rate_limit_middleware_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    ack = sc
+                        .on_recv_packet(
+                            received_packet(seq, &transfer_data(denom, amount)),
+                            managed_address!(&relayer),
+                        )
+                        .to_boxed_bytes()
+                        .into_vec();
+                },
+            )
+            .assert_ok();
+
+        ack
+    }
+
+    pub fn check_flow(&self, denom: &[u8], window_start: u64, outflow: u64, inflow: u64) {
+        self.b_mock
+            .borrow_mut()
+            .execute_query(&self.rate_limit_middleware_wrapper, |sc| {
+                let flow = sc.get_flow(managed_buffer!(CHANNEL_ID), managed_buffer!(denom));
+                assert_eq!(flow.window_start, window_start);
+                assert_eq!(flow.outflow, managed_biguint!(outflow));
+                assert_eq!(flow.inflow, managed_biguint!(inflow));
+            })
+            .assert_ok();
+    }
+}
+
+/// ICS-20 packet data, as ibc-go encodes it
+pub fn transfer_data(denom: &[u8], amount: u64) -> Vec<u8> {
+    format!(
+        r#"{{"amount":"{}","denom":"{}","receiver":"cosmos1receiver","sender":"erd1sender"}}"#,
+        amount,
+        std::str::from_utf8(denom).unwrap()
+    )
+    .into_bytes()
+}
+
+/// A packet the app sent on `CHANNEL_ID`
+pub fn sent_packet(seq: u64, data: &[u8]) -> Packet<DebugApi> {
+    Packet {
+        seq,
+        src_port: managed_buffer!(PORT_ID),
+        src_channel: managed_buffer!(CHANNEL_ID),
+        dest_port: managed_buffer!(COUNTERPARTY_PORT_ID),
+        dest_channel: managed_buffer!(COUNTERPARTY_CHANNEL_ID),
+        data: managed_buffer!(data),
+        timeout_height: height::Data::default(),
+        timeout_timestamp: TIMEOUT_TIMESTAMP,
+    }
+}
+
+/// A packet the counterparty sent to `CHANNEL_ID`
+pub fn received_packet(seq: u64, data: &[u8]) -> Packet<DebugApi> {
+    Packet {
+        seq,
+        src_port: managed_buffer!(COUNTERPARTY_PORT_ID),
+        src_channel: managed_buffer!(COUNTERPARTY_CHANNEL_ID),
+        dest_port: managed_buffer!(PORT_ID),
+        dest_channel: managed_buffer!(CHANNEL_ID),
+        data: managed_buffer!(data),
+        timeout_height: height::Data::default(),
+        timeout_timestamp: TIMEOUT_TIMESTAMP,
+    }
+}
+
+pub fn open_channel_info() -> ChannelInfo<DebugApi> {
+    ChannelInfo {
+        channel: channel::Data {
+            state: channel::State::Open,
+            ordering: channel::Order::Unordered,
+            counterparty: channel_counterparty::Data {
+                port_id: managed_buffer!(COUNTERPARTY_PORT_ID),
+                channel_id: managed_buffer!(COUNTERPARTY_CHANNEL_ID),
+            },
+            connection_hops: ConnectionHops::from_single_item(managed_buffer!(CONNECTION_ID)),
+            version: managed_buffer!(MOCK_APP_VERSION),
+            upgrade_sequence: 0,
+        },
+        next_seq_send: 1,
+        next_seq_recv: 1,
+        next_seq_ack: 1,
+        upgrade: upgrade::Data::default(),
+        latest_error_rec_seq: 0,
+        recv_start_seq: RecvStartSequence {
+            seq: 0,
+            prev_seq: 0,
+        },
+        ack_start_seq: 0,
+    }
+}
+
+pub fn open_connection() -> connection_end::Data<DebugApi> {
+    let mut features = FeatureVec::new();
+    features.push(managed_buffer!(channel::ORDERED));
+    features.push(managed_buffer!(channel::UNORDERED));
+
+    connection_end::Data {
+        client_id: managed_buffer!(CLIENT_ID),
+        versions: VersionVec::from_single_item(version::Data {
+            identifier: managed_buffer!(b"1"),
+            features,
+        }),
+        state: connection_end::State::Open,
+        counterparty: counterparty::Data {
+            client_id: managed_buffer!(COUNTERPARTY_CLIENT_ID),
+            connection_id: managed_buffer!(COUNTERPARTY_CONNECTION_ID),
+            prefix: merkle_prefix::Data {
+                key_prefix: managed_buffer!(COMMITMENT_PREFIX),
+            },
+        },
+        delay_period: 0,
+    }
+}
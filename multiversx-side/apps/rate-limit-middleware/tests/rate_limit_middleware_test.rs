@@ -0,0 +1,384 @@
+use std::{cell::RefCell, rc::Rc};
+
+use mock_app::{MockApp, SUCCESSFUL_ACK};
+use multiversx_sc_scenario::{
+    imports::BlockchainStateWrapper, managed_biguint, managed_buffer, rust_biguint, DebugApi,
+};
+use rate_limit_middleware::rate_limits::{Quota, RateLimitsModule};
+use rate_limit_middleware_setup::*;
+
+pub mod rate_limit_middleware_setup;
+
+static SUCCESSFUL_TRANSFER_ACK: &[u8] = br#"{"result":"AQ=="}"#;
+static FAILED_TRANSFER_ACK: &[u8] = br#"{"error":"Invalid receiver"}"#;
+static INFLOW_RATE_LIMIT_EXCEEDED_ACK: &[u8] = br#"{"error":"Inflow rate limit exceeded"}"#;
+
+type Setup = RateLimitMiddlewareSetup<
+    fn() -> ibc_handler::ContractObj<DebugApi>,
+    fn() -> mock::ContractObj<DebugApi>,
+    fn() -> rate_limit_middleware::ContractObj<DebugApi>,
+    fn() -> mock_app::ContractObj<DebugApi>,
+>;
+
+fn get_rate_limit_middleware_setup() -> Setup {
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_biguint!(0));
+    RateLimitMiddlewareSetup::new(
+        Rc::new(RefCell::new(b_mock)),
+        &owner,
+        ibc_handler::contract_obj,
+        mock::contract_obj,
+        rate_limit_middleware::contract_obj,
+        mock_app::contract_obj,
+    )
+}
+
+fn check_recv_callbacks(setup: &Setup, expected: usize) {
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.mock_app_wrapper, |sc| {
+            let recv_callbacks = sc
+                .callbacks(&managed_buffer!(PORT_ID), &managed_buffer!(CHANNEL_ID))
+                .iter()
+                .filter(|callback| callback == &managed_buffer!(b"onRecvPacket"))
+                .count();
+            assert_eq!(recv_callbacks, expected);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn set_rate_limit_test() {
+    let setup = get_rate_limit_middleware_setup();
+    setup.set_rate_limit(
+        NATIVE_DENOM,
+        TestQuota::Amount(100),
+        TestQuota::SupplyPercentage(500),
+        1_000,
+    );
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.rate_limit_middleware_wrapper, |sc| {
+            let rate_limit = sc
+                .rate_limits(&managed_buffer!(CHANNEL_ID), &managed_buffer!(NATIVE_DENOM))
+                .get();
+            assert_eq!(rate_limit.window_duration, WINDOW_DURATION);
+            assert_eq!(rate_limit.max_outflow, Quota::Amount(managed_biguint!(100)));
+            assert_eq!(rate_limit.max_inflow, Quota::SupplyPercentage(500));
+            assert_eq!(rate_limit.supply, managed_biguint!(1_000));
+        })
+        .assert_ok();
+    setup.check_flow(NATIVE_DENOM, START_TIMESTAMP, 0, 0);
+}
+
+#[test]
+fn try_set_rate_limit_invalid_percentage_test() {
+    let setup = get_rate_limit_middleware_setup();
+    let owner = setup.owner.clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &owner,
+            &setup.rate_limit_middleware_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_rate_limit(
+                    managed_buffer!(CHANNEL_ID),
+                    managed_buffer!(NATIVE_DENOM),
+                    WINDOW_DURATION,
+                    Quota::Unlimited,
+                    Quota::SupplyPercentage(10_001),
+                    managed_biguint!(1_000),
+                );
+            },
+        )
+        .assert_user_error("Invalid percentage");
+}
+
+#[test]
+fn try_set_rate_limit_zero_supply_test() {
+    let setup = get_rate_limit_middleware_setup();
+    let owner = setup.owner.clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &owner,
+            &setup.rate_limit_middleware_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_rate_limit(
+                    managed_buffer!(CHANNEL_ID),
+                    managed_buffer!(NATIVE_DENOM),
+                    WINDOW_DURATION,
+                    Quota::SupplyPercentage(500),
+                    Quota::Unlimited,
+                    managed_biguint!(0),
+                );
+            },
+        )
+        .assert_user_error("Supply must be positive");
+}
+
+#[test]
+fn update_supply_test() {
+    let setup = get_rate_limit_middleware_setup();
+    setup.set_rate_limit(
+        NATIVE_DENOM,
+        TestQuota::SupplyPercentage(1_000),
+        TestQuota::Unlimited,
+        1_000,
+    );
+
+    let owner = setup.owner.clone();
+    let update_supply = |supply: u64| {
+        setup.b_mock.borrow_mut().execute_tx(
+            &owner,
+            &setup.rate_limit_middleware_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.update_supply(
+                    managed_buffer!(CHANNEL_ID),
+                    managed_buffer!(NATIVE_DENOM),
+                    managed_biguint!(supply),
+                );
+            },
+        )
+    };
+    update_supply(0).assert_user_error("Supply must be positive");
+    update_supply(2_000).assert_ok();
+
+    setup.send_transfer(NATIVE_DENOM, 200).assert_ok();
+    setup.check_flow(NATIVE_DENOM, START_TIMESTAMP, 200, 0);
+}
+
+#[test]
+fn send_within_quota_test() {
+    let setup = get_rate_limit_middleware_setup();
+    setup.set_rate_limit(
+        NATIVE_DENOM,
+        TestQuota::Amount(100),
+        TestQuota::Unlimited,
+        0,
+    );
+
+    setup.send_transfer(NATIVE_DENOM, 60).assert_ok();
+    setup.send_transfer(NATIVE_DENOM, 40).assert_ok();
+
+    setup.check_flow(NATIVE_DENOM, START_TIMESTAMP, 100, 0);
+}
+
+#[test]
+fn try_send_exceeding_quota_test() {
+    let setup = get_rate_limit_middleware_setup();
+    setup.set_rate_limit(
+        NATIVE_DENOM,
+        TestQuota::Amount(100),
+        TestQuota::Unlimited,
+        0,
+    );
+
+    setup.send_transfer(NATIVE_DENOM, 60).assert_ok();
+    setup
+        .send_transfer(NATIVE_DENOM, 41)
+        .assert_user_error("Outflow rate limit exceeded");
+
+    setup.check_flow(NATIVE_DENOM, START_TIMESTAMP, 60, 0);
+}
+
+#[test]
+fn try_send_exceeding_supply_percentage_quota_test() {
+    let setup = get_rate_limit_middleware_setup();
+    setup.set_rate_limit(
+        NATIVE_DENOM,
+        TestQuota::SupplyPercentage(1_000),
+        TestQuota::Unlimited,
+        1_000,
+    );
+
+    setup
+        .send_transfer(NATIVE_DENOM, 101)
+        .assert_user_error("Outflow rate limit exceeded");
+    setup.send_transfer(NATIVE_DENOM, 100).assert_ok();
+}
+
+#[test]
+fn send_token_without_rate_limit_test() {
+    let setup = get_rate_limit_middleware_setup();
+    setup.set_rate_limit(NATIVE_DENOM, TestQuota::Amount(1), TestQuota::Unlimited, 0);
+
+    setup.send_transfer(VOUCHER_DENOM, 1_000).assert_ok();
+
+    setup.check_flow(NATIVE_DENOM, START_TIMESTAMP, 0, 0);
+}
+
+#[test]
+fn window_reset_test() {
+    let setup = get_rate_limit_middleware_setup();
+    setup.set_rate_limit(
+        NATIVE_DENOM,
+        TestQuota::Amount(100),
+        TestQuota::Unlimited,
+        0,
+    );
+    setup.send_transfer(NATIVE_DENOM, 100).assert_ok();
+
+    let next_window_start = START_TIMESTAMP + WINDOW_DURATION;
+    setup
+        .b_mock
+        .borrow_mut()
+        .set_block_timestamp(next_window_start - 1);
+    setup
+        .send_transfer(NATIVE_DENOM, 1)
+        .assert_user_error("Outflow rate limit exceeded");
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .set_block_timestamp(next_window_start);
+    setup.check_flow(NATIVE_DENOM, next_window_start, 0, 0);
+    setup.send_transfer(NATIVE_DENOM, 100).assert_ok();
+
+    setup.check_flow(NATIVE_DENOM, next_window_start, 100, 0);
+}
+
+#[test]
+fn reset_flow_test() {
+    let setup = get_rate_limit_middleware_setup();
+    setup.set_rate_limit(
+        NATIVE_DENOM,
+        TestQuota::Amount(100),
+        TestQuota::Unlimited,
+        0,
+    );
+    setup.send_transfer(NATIVE_DENOM, 100).assert_ok();
+
+    let owner = setup.owner.clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &owner,
+            &setup.rate_limit_middleware_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.reset_flow(managed_buffer!(CHANNEL_ID), managed_buffer!(NATIVE_DENOM));
+            },
+        )
+        .assert_ok();
+
+    setup.send_transfer(NATIVE_DENOM, 100).assert_ok();
+}
+
+#[test]
+fn failed_transfer_gives_back_outflow_test() {
+    let setup = get_rate_limit_middleware_setup();
+    setup.set_rate_limit(
+        NATIVE_DENOM,
+        TestQuota::Amount(100),
+        TestQuota::Unlimited,
+        0,
+    );
+    setup.send_transfer(NATIVE_DENOM, 60).assert_ok();
+    setup.send_transfer(NATIVE_DENOM, 30).assert_ok();
+
+    setup.ack_transfer(1, NATIVE_DENOM, 60, FAILED_TRANSFER_ACK);
+    setup.check_flow(NATIVE_DENOM, START_TIMESTAMP, 30, 0);
+
+    setup.ack_transfer(2, NATIVE_DENOM, 30, SUCCESSFUL_TRANSFER_ACK);
+    setup.check_flow(NATIVE_DENOM, START_TIMESTAMP, 30, 0);
+}
+
+#[test]
+fn timed_out_transfer_gives_back_outflow_test() {
+    let setup = get_rate_limit_middleware_setup();
+    setup.set_rate_limit(
+        NATIVE_DENOM,
+        TestQuota::Amount(100),
+        TestQuota::Unlimited,
+        0,
+    );
+    setup.send_transfer(NATIVE_DENOM, 60).assert_ok();
+
+    setup.timeout_transfer(1, NATIVE_DENOM, 60);
+
+    setup.check_flow(NATIVE_DENOM, START_TIMESTAMP, 0, 0);
+}
+
+#[test]
+fn timed_out_transfer_of_past_window_test() {
+    let setup = get_rate_limit_middleware_setup();
+    setup.set_rate_limit(
+        NATIVE_DENOM,
+        TestQuota::Amount(100),
+        TestQuota::Unlimited,
+        0,
+    );
+    setup.send_transfer(NATIVE_DENOM, 60).assert_ok();
+
+    let next_window_start = START_TIMESTAMP + WINDOW_DURATION;
+    setup
+        .b_mock
+        .borrow_mut()
+        .set_block_timestamp(next_window_start);
+    setup.send_transfer(NATIVE_DENOM, 50).assert_ok();
+    setup.timeout_transfer(1, NATIVE_DENOM, 60);
+
+    setup.check_flow(NATIVE_DENOM, next_window_start, 50, 0);
+}
+
+#[test]
+fn recv_within_quota_test() {
+    let setup = get_rate_limit_middleware_setup();
+    setup.set_rate_limit(
+        VOUCHER_DENOM,
+        TestQuota::Unlimited,
+        TestQuota::Amount(100),
+        0,
+    );
+
+    let ack = setup.recv_transfer(1, COUNTERPARTY_DENOM, 100);
+
+    assert_eq!(ack, SUCCESSFUL_ACK);
+    check_recv_callbacks(&setup, 1);
+    setup.check_flow(VOUCHER_DENOM, START_TIMESTAMP, 0, 100);
+}
+
+#[test]
+fn recv_exceeding_quota_test() {
+    let setup = get_rate_limit_middleware_setup();
+    setup.set_rate_limit(
+        VOUCHER_DENOM,
+        TestQuota::Unlimited,
+        TestQuota::Amount(100),
+        0,
+    );
+    setup.recv_transfer(1, COUNTERPARTY_DENOM, 60);
+
+    let ack = setup.recv_transfer(2, COUNTERPARTY_DENOM, 41);
+
+    assert_eq!(ack, INFLOW_RATE_LIMIT_EXCEEDED_ACK);
+    check_recv_callbacks(&setup, 1);
+    setup.check_flow(VOUCHER_DENOM, START_TIMESTAMP, 0, 60);
+}
+
+#[test]
+fn recv_returning_tokens_test() {
+    let setup = get_rate_limit_middleware_setup();
+    setup.set_rate_limit(
+        NATIVE_DENOM,
+        TestQuota::Unlimited,
+        TestQuota::Amount(100),
+        0,
+    );
+
+    setup.recv_transfer(1, RETURNING_NATIVE_DENOM, 60);
+    let ack = setup.recv_transfer(2, RETURNING_NATIVE_DENOM, 60);
+
+    assert_eq!(ack, INFLOW_RATE_LIMIT_EXCEEDED_ACK);
+    setup.check_flow(NATIVE_DENOM, START_TIMESTAMP, 0, 60);
+}
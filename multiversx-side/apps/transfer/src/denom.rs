@@ -0,0 +1,227 @@
+use common_types::{trace, Hash};
+
+multiversx_sc::imports!();
+
+pub static EGLD_DENOM: &[u8] = b"EGLD";
+static IBC_DENOM_PREFIX: &[u8] = b"ibc/";
+static DISPLAY_NAME_PREFIX: &[u8] = b"Ibc";
+static DEFAULT_TICKER: &[u8] = b"IBC";
+
+// ESDT name and ticker lengths
+const MAX_DISPLAY_NAME_LEN: usize = 20;
+const MIN_TICKER_LEN: usize = 3;
+const MAX_TICKER_LEN: usize = 10;
+
+/// Tracks the denoms of the tokens received from other chains, and the voucher ESDTs minted for them
+///
+/// A denom trace is the path a token took to reach this chain, e.g. "transfer/channel-0/uatom" for `uatom` received
+/// on the local `transfer/channel-0` end.
+#[multiversx_sc::module]
+pub trait DenomModule {
+    /// Issues the voucher ESDT of `denom_trace`, which its receivers are credited with
+    ///
+    /// Issuing is up to the owner, as it costs EGLD and fixes the metadata of the voucher. Tokens received before their
+    /// voucher is issued are held for their receivers, who claim them with `claimVouchers` once it is, and their traces
+    /// are recorded so that the owner knows which vouchers to issue.
+    /// The name and ticker of the voucher are derived from the base denom, with no decimals since amounts are in the
+    /// smallest unit of the token. The contract gets all the roles of the voucher.
+    #[only_owner]
+    #[payable("EGLD")]
+    #[endpoint(issueVoucher)]
+    fn issue_voucher(&self, denom_trace: ManagedBuffer) {
+        require!(
+            trace::split_first_hop(&denom_trace).is_some(),
+            "Invalid denom trace"
+        );
+
+        let denom_hash = self.denom_hash(&denom_trace);
+        require!(
+            self.voucher_tokens(&denom_hash).is_empty(),
+            "Voucher already issued"
+        );
+        require!(
+            !self.pending_voucher_issues().contains(&denom_hash),
+            "Voucher issue in progress"
+        );
+
+        self.pending_voucher_issues().insert(denom_hash.clone());
+        self.denom_traces(&denom_hash).set(&denom_trace);
+
+        let issue_cost = self.call_value().egld_value().clone_value();
+        let caller = self.blockchain().get_caller();
+        self.send()
+            .esdt_system_sc_proxy()
+            .issue_and_set_all_roles(
+                issue_cost,
+                self.voucher_display_name(&denom_trace),
+                self.voucher_ticker(&denom_trace),
+                EsdtTokenType::Fungible,
+                0,
+            )
+            .callback(self.callbacks().voucher_issue_callback(denom_hash, caller))
+            .async_call_and_exit();
+    }
+
+    #[callback]
+    fn voucher_issue_callback(
+        &self,
+        denom_hash: Hash<Self::Api>,
+        caller: ManagedAddress,
+        #[call_result] result: ManagedAsyncCallResult<TokenIdentifier>,
+    ) {
+        self.pending_voucher_issues().swap_remove(&denom_hash);
+
+        match result {
+            ManagedAsyncCallResult::Ok(token_id) => {
+                self.voucher_tokens(&denom_hash).set(&token_id);
+                self.voucher_denom_hashes(&token_id).set(&denom_hash);
+            }
+            ManagedAsyncCallResult::Err(_) => {
+                let returned_cost = self.call_value().egld_value().clone_value();
+                if returned_cost > 0 {
+                    self.send().direct_egld(&caller, &returned_cost);
+                }
+            }
+        }
+    }
+
+    /// "ibc/{hex(sha256(denom_trace))}", the denom ibc-go chains show for the same trace
+    #[view(getIbcDenom)]
+    fn get_ibc_denom(&self, denom_trace: ManagedBuffer) -> ManagedBuffer {
+        trace::hex_with_prefix(IBC_DENOM_PREFIX, &self.denom_hash(&denom_trace))
+    }
+
+    /// Returns the voucher of `denom_trace`, if it was issued
+    #[view(getVoucherToken)]
+    fn get_voucher_token(&self, denom_trace: ManagedBuffer) -> OptionalValue<TokenIdentifier> {
+        let mapper = self.voucher_tokens(&self.denom_hash(&denom_trace));
+        if mapper.is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(mapper.get())
+    }
+
+    /// The denom of `token` in packets: the trace for vouchers, the token identifier otherwise
+    #[view(getDenom)]
+    fn get_denom(&self, token: &EgldOrEsdtTokenIdentifier) -> ManagedBuffer {
+        if token.is_egld() {
+            return ManagedBuffer::new_from_bytes(EGLD_DENOM);
+        }
+
+        let token_id = token.clone().unwrap_esdt();
+        let hash_mapper = self.voucher_denom_hashes(&token_id);
+        if hash_mapper.is_empty() {
+            return token_id.into_managed_buffer();
+        }
+
+        self.denom_traces(&hash_mapper.get()).get()
+    }
+
+    /// The local token of a denom, as returned by `getDenom`, if it is known
+    #[view(getLocalToken)]
+    fn get_local_token_view(
+        &self,
+        denom: ManagedBuffer,
+    ) -> OptionalValue<EgldOrEsdtTokenIdentifier> {
+        self.get_local_token(&denom).into()
+    }
+
+    /// The local token of a denom, as returned by `get_denom`
+    fn get_local_token(&self, denom: &ManagedBuffer) -> Option<EgldOrEsdtTokenIdentifier> {
+        if denom == EGLD_DENOM {
+            return Some(EgldOrEsdtTokenIdentifier::egld());
+        }
+
+        if trace::split_first_hop(denom).is_some() {
+            let mapper = self.voucher_tokens(&self.denom_hash(denom));
+            if mapper.is_empty() {
+                return None;
+            }
+
+            return Some(EgldOrEsdtTokenIdentifier::esdt(mapper.get()));
+        }
+
+        let token_id = TokenIdentifier::from(denom.clone());
+        if !token_id.is_valid_esdt_identifier() {
+            return None;
+        }
+
+        Some(EgldOrEsdtTokenIdentifier::esdt(token_id))
+    }
+
+    /// "Ibc" followed by the letters and digits of the base denom, e.g. "Ibcuatom" for "transfer/channel-0/uatom"
+    fn voucher_display_name(&self, denom_trace: &ManagedBuffer) -> ManagedBuffer {
+        let mut name = [0u8; MAX_DISPLAY_NAME_LEN];
+        name[..DISPLAY_NAME_PREFIX.len()].copy_from_slice(DISPLAY_NAME_PREFIX);
+        let len = DISPLAY_NAME_PREFIX.len()
+            + base_denom_alphanumerics(denom_trace, &mut name[DISPLAY_NAME_PREFIX.len()..]);
+
+        ManagedBuffer::new_from_bytes(&name[..len])
+    }
+
+    /// The uppercase letters and digits of the base denom, e.g. "UATOM" for "transfer/channel-0/uatom", or "IBC" if
+    /// there are too few of them
+    fn voucher_ticker(&self, denom_trace: &ManagedBuffer) -> ManagedBuffer {
+        let mut ticker = [0u8; MAX_TICKER_LEN];
+        let len = base_denom_alphanumerics(denom_trace, &mut ticker);
+        if len < MIN_TICKER_LEN {
+            return ManagedBuffer::new_from_bytes(DEFAULT_TICKER);
+        }
+
+        ticker[..len].make_ascii_uppercase();
+        ManagedBuffer::new_from_bytes(&ticker[..len])
+    }
+
+    fn denom_hash(&self, denom_trace: &ManagedBuffer) -> Hash<Self::Api> {
+        self.crypto().sha256(denom_trace)
+    }
+
+    #[storage_mapper("denomTraces")]
+    fn denom_traces(&self, denom_hash: &Hash<Self::Api>) -> SingleValueMapper<ManagedBuffer>;
+
+    #[storage_mapper("voucherTokens")]
+    fn voucher_tokens(&self, denom_hash: &Hash<Self::Api>) -> SingleValueMapper<TokenIdentifier>;
+
+    #[storage_mapper("voucherDenomHashes")]
+    fn voucher_denom_hashes(
+        &self,
+        token_id: &TokenIdentifier,
+    ) -> SingleValueMapper<Hash<Self::Api>>;
+
+    #[storage_mapper("pendingVoucherIssues")]
+    fn pending_voucher_issues(&self) -> UnorderedSetMapper<Hash<Self::Api>>;
+}
+
+/// Copies the ASCII letters and digits of the last segment of the trace, i.e. of the base denom, until `output` is full
+fn base_denom_alphanumerics<M: ManagedTypeApi>(
+    denom_trace: &ManagedBuffer<M>,
+    output: &mut [u8],
+) -> usize {
+    let trace_len = denom_trace.len();
+    let mut start = trace_len;
+    let mut byte = [0u8; 1];
+    while start > 0 {
+        let _ = denom_trace.load_slice(start - 1, &mut byte);
+        if byte[0] == b'/' {
+            break;
+        }
+
+        start -= 1;
+    }
+
+    let mut len = 0;
+    for i in start..trace_len {
+        if len == output.len() {
+            break;
+        }
+
+        let _ = denom_trace.load_slice(i, &mut byte);
+        if byte[0].is_ascii_alphanumeric() {
+            output[len] = byte[0];
+            len += 1;
+        }
+    }
+
+    len
+}
@@ -0,0 +1,47 @@
+use common_types::{ChannelId, Sequence};
+
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait EventsModule {
+    #[event("transferEvent")]
+    fn transfer_event(
+        &self,
+        #[indexed] source_channel: &ChannelId<Self::Api>,
+        #[indexed] seq: Sequence,
+        #[indexed] sender: &ManagedAddress,
+        packet_data: &ManagedBuffer,
+    );
+
+    #[event("receiveTransferEvent")]
+    fn receive_transfer_event(
+        &self,
+        #[indexed] dest_channel: &ChannelId<Self::Api>,
+        #[indexed] seq: Sequence,
+        #[indexed] success: bool,
+        ack: &ManagedBuffer,
+    );
+
+    #[event("refundEvent")]
+    fn refund_event(
+        &self,
+        #[indexed] source_channel: &ChannelId<Self::Api>,
+        #[indexed] seq: Sequence,
+        #[indexed] sender: &ManagedAddress,
+    );
+
+    #[event("revertReceiveTransferEvent")]
+    fn revert_receive_transfer_event(
+        &self,
+        #[indexed] dest_channel: &ChannelId<Self::Api>,
+        #[indexed] seq: Sequence,
+    );
+
+    #[event("claimVouchersEvent")]
+    fn claim_vouchers_event(
+        &self,
+        #[indexed] receiver: &ManagedAddress,
+        #[indexed] voucher: &TokenIdentifier,
+        amount: &BigUint,
+    );
+}
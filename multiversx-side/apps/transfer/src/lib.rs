@@ -0,0 +1,32 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod denom;
+pub mod events;
+pub mod ibc_handler;
+pub mod ibc_module;
+pub mod packet_data;
+pub mod relay;
+
+/// ICS-20 fungible token transfer application, bound to the `transfer` port of the IBC handler.
+///
+/// EGLD and native ESDTs are escrowed when sent and released when they come back. Tokens received from other chains
+/// are minted as voucher ESDTs, which are burnt when sent back. Those received before the owner issues their voucher
+/// are held for their receivers, who claim them once it is issued.
+#[multiversx_sc::contract]
+pub trait Transfer:
+    denom::DenomModule
+    + relay::RelayModule
+    + ibc_module::IbcModule
+    + events::EventsModule
+    + ibc_handler::IbcHandlerModule
+{
+    #[init]
+    fn init(&self, ibc_handler: ManagedAddress) {
+        self.ibc_handler().set(ibc_handler);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+}
@@ -0,0 +1,76 @@
+use common_types::json::{JsonObject, JsonObjectBuilder};
+
+multiversx_sc::imports!();
+
+/// uint256 has at most 78 decimal digits
+const MAX_AMOUNT_DIGITS: usize = 78;
+
+/// ICS-20 `FungibleTokenPacketData`, JSON encoded with sorted keys as ibc-go does
+///
+/// `sender` and `receiver` are bech32 addresses on the respective chains.
+pub struct FungibleTokenPacketData<M: ManagedTypeApi> {
+    pub denom: ManagedBuffer<M>,
+    pub amount: BigUint<M>,
+    pub sender: ManagedBuffer<M>,
+    pub receiver: ManagedBuffer<M>,
+    pub memo: ManagedBuffer<M>,
+}
+
+impl<M: ManagedTypeApi> FungibleTokenPacketData<M> {
+    pub fn to_json(&self) -> ManagedBuffer<M> {
+        JsonObjectBuilder::new()
+            .string(b"amount", &self.amount.to_display())
+            .string(b"denom", &self.denom)
+            .optional_string(b"memo", &self.memo)
+            .string(b"receiver", &self.receiver)
+            .string(b"sender", &self.sender)
+            .build()
+    }
+
+    /// Returns `None` if the data isn't valid, in which case the packet must be answered with an error acknowledgement
+    pub fn from_json(encoded: &ManagedBuffer<M>) -> Option<Self> {
+        let object = JsonObject::parse(encoded)?;
+        let memo = match object.get_raw(b"memo") {
+            Some(_) => object.get_string(b"memo")?,
+            None => ManagedBuffer::new(),
+        };
+        let data = FungibleTokenPacketData {
+            denom: object.get_string(b"denom")?,
+            amount: parse_amount(&object.get_string(b"amount")?)?,
+            sender: object.get_string(b"sender")?,
+            receiver: object.get_string(b"receiver")?,
+            memo,
+        };
+        if data.denom.is_empty() || data.sender.is_empty() || data.receiver.is_empty() {
+            return None;
+        }
+
+        Some(data)
+    }
+}
+
+/// Parses a positive decimal amount
+pub fn parse_amount<M: ManagedTypeApi>(digits: &ManagedBuffer<M>) -> Option<BigUint<M>> {
+    let len = digits.len();
+    if len == 0 || len > MAX_AMOUNT_DIGITS {
+        return None;
+    }
+
+    let mut bytes = [0u8; MAX_AMOUNT_DIGITS];
+    digits.load_slice(0, &mut bytes[..len]).ok()?;
+
+    let mut amount = BigUint::zero();
+    for digit in &bytes[..len] {
+        if !digit.is_ascii_digit() {
+            return None;
+        }
+
+        amount *= 10u32;
+        amount += (digit - b'0') as u32;
+    }
+    if amount == 0 {
+        return None;
+    }
+
+    Some(amount)
+}
@@ -0,0 +1,272 @@
+use channel::{
+    channel_libs::packet_types::Packet,
+    interfaces::ibc_handler_interface::ibc_handler_proxy::ProxyTrait as _,
+};
+use common_types::{
+    bech32, channel_types::acknowledgement, channel_types::height, trace, ChannelId, Hash, PortId,
+    Sequence, UnixTimestamp,
+};
+
+use crate::packet_data::FungibleTokenPacketData;
+
+multiversx_sc::imports!();
+
+pub static TRANSFER_PORT_ID: &[u8] = b"transfer";
+
+static INVALID_PACKET_DATA_ERR_MSG: &[u8] = b"Invalid packet data";
+static INVALID_RECEIVER_ERR_MSG: &[u8] = b"Invalid receiver";
+static UNKNOWN_DENOM_ERR_MSG: &[u8] = b"Unknown denom";
+static INSUFFICIENT_ESCROW_ERR_MSG: &[u8] = b"Insufficient escrowed amount";
+static INVALID_PAYMENT_ERR_MSG: &[u8] = b"Invalid payment";
+
+/// Moves the tokens of the transfers: escrow and burn when sending, unescrow and mint when receiving or refunding
+#[multiversx_sc::module]
+pub trait RelayModule:
+    crate::denom::DenomModule + crate::events::EventsModule + crate::ibc_handler::IbcHandlerModule
+{
+    /// Sends the paid tokens to `receiver` on the chain at the other end of `source_channel`
+    ///
+    /// Native tokens are escrowed, while vouchers going back to their origin are burnt.
+    ///
+    /// Returns the sequence of the packet.
+    #[payable("*")]
+    #[endpoint]
+    fn transfer(
+        &self,
+        source_channel: ChannelId<Self::Api>,
+        receiver: ManagedBuffer,
+        timeout_height: height::Data,
+        timeout_timestamp: UnixTimestamp,
+        opt_memo: OptionalValue<ManagedBuffer>,
+    ) -> Sequence {
+        let (token, nonce, amount) = self.call_value().egld_or_single_esdt().into_tuple();
+        require!(nonce == 0, "Only fungible tokens can be transferred");
+        require!(amount > 0, "Zero amount");
+        require!(!receiver.is_empty(), "Empty receiver");
+
+        let source_port = PortId::from(TRANSFER_PORT_ID);
+        let denom = self.get_denom(&token);
+        if self.is_returning_voucher(&denom, &source_port, &source_channel) {
+            self.send()
+                .esdt_local_burn(&token.clone().unwrap_esdt(), 0, &amount);
+        } else {
+            self.channel_escrow(&source_channel, &token)
+                .update(|escrowed| *escrowed += &amount);
+        }
+
+        let caller = self.blockchain().get_caller();
+        let packet_data = FungibleTokenPacketData {
+            denom,
+            amount,
+            sender: bech32::encode_address(&caller),
+            receiver,
+            memo: opt_memo.into_option().unwrap_or_default(),
+        }
+        .to_json();
+
+        let ibc_handler = self.ibc_handler().get();
+        let seq: Sequence = self
+            .ibc_handler_proxy(ibc_handler)
+            .send_packet(
+                source_port,
+                source_channel.clone(),
+                timeout_height,
+                timeout_timestamp,
+                packet_data.clone(),
+            )
+            .execute_on_dest_context();
+
+        self.transfer_event(&source_channel, seq, &caller, &packet_data);
+
+        seq
+    }
+
+    /// Credits the receiver of the packet, returning the acknowledgement
+    ///
+    /// Invalid packets are answered with an error acknowledgement instead of failing, so that the sender gets refunded.
+    fn receive_transfer(&self, packet: &Packet<Self::Api>) -> ManagedBuffer {
+        let ack = match self.try_receive_transfer(packet) {
+            Ok(()) => acknowledgement::new_successful(),
+            Err(err_msg) => acknowledgement::new_error(err_msg),
+        };
+
+        self.receive_transfer_event(
+            &packet.dest_channel,
+            packet.seq,
+            acknowledgement::is_successful(&ack) == Some(true),
+            &ack,
+        );
+
+        ack
+    }
+
+    fn try_receive_transfer(&self, packet: &Packet<Self::Api>) -> Result<(), &'static [u8]> {
+        let data =
+            FungibleTokenPacketData::from_json(&packet.data).ok_or(INVALID_PACKET_DATA_ERR_MSG)?;
+        let receiver = bech32::decode_address(&data.receiver).ok_or(INVALID_RECEIVER_ERR_MSG)?;
+
+        // the token comes back to this chain, so it was escrowed when it was sent
+        if let Some(local_denom) =
+            trace::strip_hop_prefix(&data.denom, &packet.src_port, &packet.src_channel)
+        {
+            let token = self
+                .get_local_token(&local_denom)
+                .ok_or(UNKNOWN_DENOM_ERR_MSG)?;
+            let escrow_mapper = self.channel_escrow(&packet.dest_channel, &token);
+            let escrowed = escrow_mapper.get();
+            if escrowed < data.amount {
+                return Err(INSUFFICIENT_ESCROW_ERR_MSG);
+            }
+
+            escrow_mapper.set(escrowed - &data.amount);
+            self.send().direct(&receiver, &token, 0, &data.amount);
+
+            return Ok(());
+        }
+
+        let mut denom_trace = trace::hop_prefix(&packet.dest_port, &packet.dest_channel);
+        denom_trace.append(&data.denom);
+        let denom_hash = self.denom_hash(&denom_trace);
+        let voucher_mapper = self.voucher_tokens(&denom_hash);
+        if voucher_mapper.is_empty() {
+            // the transfer is kept, and the receiver credited once the owner issues the voucher
+            self.denom_traces(&denom_hash).set(&denom_trace);
+            self.unclaimed_vouchers(&denom_hash, &receiver)
+                .update(|unclaimed| *unclaimed += &data.amount);
+
+            return Ok(());
+        }
+
+        let voucher = voucher_mapper.get();
+        self.send().esdt_local_mint(&voucher, 0, &data.amount);
+        self.send()
+            .direct_esdt(&receiver, &voucher, 0, &data.amount);
+
+        Ok(())
+    }
+
+    /// Mints and sends to `receiver` the vouchers of `denom_trace` it received before the voucher was issued
+    ///
+    /// Transfers of a denom without a voucher are received all the same, and their amounts kept for their receivers
+    /// until the owner issues the voucher with `issueVoucher`. Anyone may then claim them, always for the receiver.
+    #[endpoint(claimVouchers)]
+    fn claim_vouchers(&self, denom_trace: ManagedBuffer, receiver: ManagedAddress) {
+        let denom_hash = self.denom_hash(&denom_trace);
+        let voucher_mapper = self.voucher_tokens(&denom_hash);
+        require!(!voucher_mapper.is_empty(), "Voucher not issued");
+
+        let amount = self.unclaimed_vouchers(&denom_hash, &receiver).take();
+        require!(amount > 0, "No vouchers to claim");
+
+        let voucher = voucher_mapper.get();
+        self.send().esdt_local_mint(&voucher, 0, &amount);
+        self.send().direct_esdt(&receiver, &voucher, 0, &amount);
+
+        self.claim_vouchers_event(&receiver, &voucher, &amount);
+    }
+
+    /// The amount of vouchers of `denom_trace` received by `receiver` before the voucher was issued, and not claimed
+    #[view(getUnclaimedVouchers)]
+    fn get_unclaimed_vouchers(
+        &self,
+        denom_trace: ManagedBuffer,
+        receiver: ManagedAddress,
+    ) -> BigUint {
+        self.unclaimed_vouchers(&self.denom_hash(&denom_trace), &receiver)
+            .get()
+    }
+
+    /// Returns the tokens of a packet which failed to the sender, as they were before `transfer`
+    fn refund_transfer(&self, packet: &Packet<Self::Api>) {
+        let data = match FungibleTokenPacketData::from_json(&packet.data) {
+            Some(data) => data,
+            None => sc_panic!(INVALID_PACKET_DATA_ERR_MSG),
+        };
+        let sender = match bech32::decode_address(&data.sender) {
+            Some(sender) => sender,
+            None => sc_panic!("Invalid sender"),
+        };
+        let token = match self.get_local_token(&data.denom) {
+            Some(token) => token,
+            None => sc_panic!(UNKNOWN_DENOM_ERR_MSG),
+        };
+
+        if self.is_returning_voucher(&data.denom, &packet.src_port, &packet.src_channel) {
+            self.send()
+                .esdt_local_mint(&token.clone().unwrap_esdt(), 0, &data.amount);
+        } else {
+            self.channel_escrow(&packet.src_channel, &token)
+                .update(|escrowed| *escrowed -= &data.amount);
+        }
+        self.send().direct(&sender, &token, 0, &data.amount);
+
+        self.refund_event(&packet.src_channel, packet.seq, &sender);
+    }
+
+    /// Takes back the tokens credited for a received packet, as if the packet was never received
+    ///
+    /// Called by the module above, e.g. a middleware which received the tokens of a packet and answers it with an
+    /// error acknowledgement after all. The payment must be the tokens of the packet.
+    #[payable("*")]
+    #[endpoint(revertReceiveTransfer)]
+    fn revert_receive_transfer(&self, packet: Packet<Self::Api>) {
+        self.require_ibc_handler_caller();
+
+        let data = match FungibleTokenPacketData::from_json(&packet.data) {
+            Some(data) => data,
+            None => sc_panic!(INVALID_PACKET_DATA_ERR_MSG),
+        };
+        let (token, nonce, amount) = self.call_value().egld_or_single_esdt().into_tuple();
+        require!(nonce == 0 && amount == data.amount, INVALID_PAYMENT_ERR_MSG);
+
+        if let Some(local_denom) =
+            trace::strip_hop_prefix(&data.denom, &packet.src_port, &packet.src_channel)
+        {
+            require!(
+                self.get_local_token(&local_denom) == Some(token.clone()),
+                INVALID_PAYMENT_ERR_MSG
+            );
+
+            self.channel_escrow(&packet.dest_channel, &token)
+                .update(|escrowed| *escrowed += &amount);
+        } else {
+            let mut denom_trace = trace::hop_prefix(&packet.dest_port, &packet.dest_channel);
+            denom_trace.append(&data.denom);
+            require!(
+                self.get_local_token(&denom_trace) == Some(token.clone()),
+                INVALID_PAYMENT_ERR_MSG
+            );
+
+            self.send()
+                .esdt_local_burn(&token.unwrap_esdt(), 0, &amount);
+        }
+
+        self.revert_receive_transfer_event(&packet.dest_channel, packet.seq);
+    }
+
+    /// Vouchers sent back over the channel they were received on are burnt instead of escrowed
+    fn is_returning_voucher(
+        &self,
+        denom: &ManagedBuffer,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+    ) -> bool {
+        trace::strip_hop_prefix(denom, port_id, channel_id).is_some()
+    }
+
+    #[storage_mapper("unclaimedVouchers")]
+    fn unclaimed_vouchers(
+        &self,
+        denom_hash: &Hash<Self::Api>,
+        receiver: &ManagedAddress,
+    ) -> SingleValueMapper<BigUint>;
+
+    /// The amount of native tokens and foreign vouchers escrowed for the transfers sent on a channel
+    #[view(getChannelEscrow)]
+    #[storage_mapper("channelEscrow")]
+    fn channel_escrow(
+        &self,
+        channel_id: &ChannelId<Self::Api>,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+}
@@ -0,0 +1,283 @@
+#![allow(dead_code)]
+
+use std::{cell::RefCell, rc::Rc};
+
+use ::channel::{
+    channel_libs::packet_types::Packet, handshake::chan_internal::ChannelInternalModule,
+};
+use client_common::ClientStatus;
+use common_types::{
+    channel_types::{channel, channel_counterparty, height},
+    connection_types::{connection_end, counterparty, merkle_prefix, version},
+    ConnectionHops, FeatureVec, VersionVec,
+};
+use host::{
+    host_config::HostConfigModule,
+    module_manager::ModuleManagerModule,
+    storage::{ClientInfo, StorageModule},
+};
+use ibc_handler::IbcHandler;
+use mock::{
+    client_logic::ClientLogicModule,
+    mock_types::{client_state, consensus_state},
+    Mock,
+};
+use multiversx_sc::types::{Address, EsdtLocalRole};
+use multiversx_sc_scenario::{
+    imports::{BlockchainStateWrapper, ContractObjWrapper},
+    managed_address, managed_buffer, managed_token_id, rust_biguint, DebugApi,
+};
+use transfer::{denom::DenomModule, ibc_module::ICS20_VERSION, relay::TRANSFER_PORT_ID, Transfer};
+
+pub static CLIENT_TYPE: &[u8] = b"mock-client";
+pub static CLIENT_ID: &[u8] = b"mock-client-0";
+pub static CONNECTION_ID: &[u8] = b"connection-0";
+pub static COUNTERPARTY_CLIENT_ID: &[u8] = b"counterparty-client-0";
+pub static COUNTERPARTY_CONNECTION_ID: &[u8] = b"connection-7";
+pub static CHANNEL_ID: &[u8] = b"channel-0";
+pub static COUNTERPARTY_CHANNEL_ID: &[u8] = b"channel-5";
+pub static COMMITMENT_PREFIX: &[u8] = b"ibc";
+
+pub static NATIVE_TOKEN_ID: &[u8] = b"TOKEN-123456";
+pub static VOUCHER_TOKEN_ID: &[u8] = b"VOUCHER-abcdef";
+/// The trace of `uatom` received on the local end of the channel
+pub static VOUCHER_DENOM_TRACE: &[u8] = b"transfer/channel-0/uatom";
+pub static COUNTERPARTY_RECEIVER: &[u8] = b"cosmos1receiver";
+
+pub const PROOF_HEIGHT: height::Data = height::Data {
+    revision_number: 0,
+    revision_height: 10,
+};
+pub const TIMEOUT_HEIGHT: height::Data = height::Data {
+    revision_number: 0,
+    revision_height: 1_000,
+};
+pub const CONSENSUS_TIMESTAMP: u64 = 1_000_000_000;
+pub const USER_BALANCE: u64 = 1_000;
+
+pub struct TransferSetup<IbcHandlerObjBuilder, MockClientObjBuilder, TransferObjBuilder>
+where
+    IbcHandlerObjBuilder: 'static + Copy + Fn() -> ibc_handler::ContractObj<DebugApi>,
+    MockClientObjBuilder: 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    TransferObjBuilder: 'static + Copy + Fn() -> transfer::ContractObj<DebugApi>,
+{
+    pub b_mock: Rc<RefCell<BlockchainStateWrapper>>,
+    pub owner: Address,
+    pub user: Address,
+    pub ibc_handler_wrapper:
+        ContractObjWrapper<ibc_handler::ContractObj<DebugApi>, IbcHandlerObjBuilder>,
+    pub mock_client_wrapper: ContractObjWrapper<mock::ContractObj<DebugApi>, MockClientObjBuilder>,
+    pub transfer_wrapper: ContractObjWrapper<transfer::ContractObj<DebugApi>, TransferObjBuilder>,
+}
+
+impl<IbcHandlerObjBuilder, MockClientObjBuilder, TransferObjBuilder>
+    TransferSetup<IbcHandlerObjBuilder, MockClientObjBuilder, TransferObjBuilder>
+where
+    IbcHandlerObjBuilder: 'static + Copy + Fn() -> ibc_handler::ContractObj<DebugApi>,
+    MockClientObjBuilder: 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    TransferObjBuilder: 'static + Copy + Fn() -> transfer::ContractObj<DebugApi>,
+{
+    pub fn new(
+        b_mock: Rc<RefCell<BlockchainStateWrapper>>,
+        owner: &Address,
+        ibc_handler_builder: IbcHandlerObjBuilder,
+        mock_client_builder: MockClientObjBuilder,
+        transfer_builder: TransferObjBuilder,
+    ) -> Self {
+        let rust_zero = rust_biguint!(0u64);
+        let user = b_mock
+            .borrow_mut()
+            .create_user_account(&rust_biguint!(USER_BALANCE));
+        b_mock
+            .borrow_mut()
+            .set_esdt_balance(&user, NATIVE_TOKEN_ID, &rust_biguint!(USER_BALANCE));
+
+        let ibc_handler_wrapper = b_mock.borrow_mut().create_sc_account(
+            &rust_zero,
+            Some(owner),
+            ibc_handler_builder,
+            "ibc handler",
+        );
+        let mock_client_wrapper = b_mock.borrow_mut().create_sc_account(
+            &rust_zero,
+            Some(owner),
+            mock_client_builder,
+            "mock client",
+        );
+        let transfer_wrapper = b_mock.borrow_mut().create_sc_account(
+            &rust_zero,
+            Some(owner),
+            transfer_builder,
+            "transfer",
+        );
+
+        let ibc_handler_address = ibc_handler_wrapper.address_ref().clone();
+        let mock_client_address = mock_client_wrapper.address_ref().clone();
+        let transfer_address = transfer_wrapper.address_ref().clone();
+
+        b_mock
+            .borrow_mut()
+            .execute_tx(owner, &mock_client_wrapper, &rust_zero, |sc| {
+                sc.init(managed_address!(&ibc_handler_address));
+
+                let client_id = managed_buffer!(CLIENT_ID);
+                sc.client_states(&client_id)
+                    .set(client_state::Data::new(PROOF_HEIGHT));
+                sc.consensus_states(&client_id, &PROOF_HEIGHT.to_biguint_concat())
+                    .set(consensus_state::Data::new(CONSENSUS_TIMESTAMP));
+                sc.statuses(&client_id).set(ClientStatus::Active);
+            })
+            .assert_ok();
+
+        b_mock
+            .borrow_mut()
+            .execute_tx(owner, &transfer_wrapper, &rust_zero, |sc| {
+                sc.init(managed_address!(&ibc_handler_address));
+            })
+            .assert_ok();
+
+        b_mock
+            .borrow_mut()
+            .execute_tx(owner, &ibc_handler_wrapper, &rust_zero, |sc| {
+                sc.init();
+
+                sc.set_expected_time_per_block(6);
+                sc.register_client(
+                    managed_buffer!(CLIENT_TYPE),
+                    managed_address!(&mock_client_address),
+                );
+                sc.bind_port(
+                    managed_buffer!(TRANSFER_PORT_ID),
+                    managed_address!(&transfer_address),
+                );
+
+                sc.client_info(&managed_buffer!(CLIENT_ID)).set(ClientInfo {
+                    client_type: managed_buffer!(CLIENT_TYPE),
+                    client_impl: managed_address!(&mock_client_address),
+                });
+                sc.connection_info(&managed_buffer!(CONNECTION_ID))
+                    .set(open_connection());
+
+                let port_id = managed_buffer!(TRANSFER_PORT_ID);
+                let channel_id = managed_buffer!(CHANNEL_ID);
+                sc.claim_channel_capability(
+                    &port_id,
+                    &channel_id,
+                    &managed_address!(&transfer_address),
+                );
+                sc.init_channel_info(&port_id, &channel_id, open_channel());
+            })
+            .assert_ok();
+
+        Self {
+            b_mock,
+            owner: owner.clone(),
+            user,
+            ibc_handler_wrapper,
+            mock_client_wrapper,
+            transfer_wrapper,
+        }
+    }
+
+    /// Registers `VOUCHER_TOKEN_ID` as the voucher of `VOUCHER_DENOM_TRACE`, as if it was issued
+    pub fn set_voucher(&self) {
+        let transfer_address = self.transfer_wrapper.address_ref().clone();
+        self.b_mock.borrow_mut().set_esdt_local_roles(
+            &transfer_address,
+            VOUCHER_TOKEN_ID,
+            &[EsdtLocalRole::Mint, EsdtLocalRole::Burn],
+        );
+
+        self.b_mock
+            .borrow_mut()
+            .execute_tx(
+                &self.owner,
+                &self.transfer_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    let denom_trace = managed_buffer!(VOUCHER_DENOM_TRACE);
+                    let denom_hash = sc.denom_hash(&denom_trace);
+                    sc.denom_traces(&denom_hash).set(&denom_trace);
+                    sc.voucher_tokens(&denom_hash)
+                        .set(managed_token_id!(VOUCHER_TOKEN_ID));
+                    sc.voucher_denom_hashes(&managed_token_id!(VOUCHER_TOKEN_ID))
+                        .set(&denom_hash);
+                },
+            )
+            .assert_ok();
+    }
+}
+
+/// `FungibleTokenPacketData` as ibc-go encodes it
+pub fn packet_data(denom: &str, amount: u64, sender: &str, receiver: &str) -> Vec<u8> {
+    format!(
+        r#"{{"amount":"{}","denom":"{}","receiver":"{}","sender":"{}"}}"#,
+        amount, denom, receiver, sender
+    )
+    .into_bytes()
+}
+
+/// A packet sent by the transfer app on `CHANNEL_ID`
+pub fn outgoing_packet(seq: u64, data: &[u8]) -> Packet<DebugApi> {
+    Packet {
+        seq,
+        src_port: managed_buffer!(TRANSFER_PORT_ID),
+        src_channel: managed_buffer!(CHANNEL_ID),
+        dest_port: managed_buffer!(TRANSFER_PORT_ID),
+        dest_channel: managed_buffer!(COUNTERPARTY_CHANNEL_ID),
+        data: managed_buffer!(data),
+        timeout_height: TIMEOUT_HEIGHT,
+        timeout_timestamp: 0,
+    }
+}
+
+/// A packet sent by the counterparty transfer app to `CHANNEL_ID`
+pub fn incoming_packet(seq: u64, data: &[u8]) -> Packet<DebugApi> {
+    Packet {
+        seq,
+        src_port: managed_buffer!(TRANSFER_PORT_ID),
+        src_channel: managed_buffer!(COUNTERPARTY_CHANNEL_ID),
+        dest_port: managed_buffer!(TRANSFER_PORT_ID),
+        dest_channel: managed_buffer!(CHANNEL_ID),
+        data: managed_buffer!(data),
+        timeout_height: TIMEOUT_HEIGHT,
+        timeout_timestamp: 0,
+    }
+}
+
+pub fn open_connection() -> connection_end::Data<DebugApi> {
+    let mut features = FeatureVec::new();
+    features.push(managed_buffer!(channel::ORDERED));
+    features.push(managed_buffer!(channel::UNORDERED));
+
+    connection_end::Data {
+        client_id: managed_buffer!(CLIENT_ID),
+        versions: VersionVec::from_single_item(version::Data {
+            identifier: managed_buffer!(b"1"),
+            features,
+        }),
+        state: connection_end::State::Open,
+        counterparty: counterparty::Data {
+            client_id: managed_buffer!(COUNTERPARTY_CLIENT_ID),
+            connection_id: managed_buffer!(COUNTERPARTY_CONNECTION_ID),
+            prefix: merkle_prefix::Data {
+                key_prefix: managed_buffer!(COMMITMENT_PREFIX),
+            },
+        },
+        delay_period: 0,
+    }
+}
+
+pub fn open_channel() -> channel::Data<DebugApi> {
+    channel::Data {
+        state: channel::State::Open,
+        ordering: channel::Order::Unordered,
+        counterparty: channel_counterparty::Data {
+            port_id: managed_buffer!(TRANSFER_PORT_ID),
+            channel_id: managed_buffer!(COUNTERPARTY_CHANNEL_ID),
+        },
+        connection_hops: ConnectionHops::from_single_item(managed_buffer!(CONNECTION_ID)),
+        version: managed_buffer!(ICS20_VERSION),
+        upgrade_sequence: 0,
+    }
+}
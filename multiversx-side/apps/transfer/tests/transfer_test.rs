@@ -0,0 +1,821 @@
+use std::{cell::RefCell, rc::Rc};
+
+use ::channel::{
+    interfaces::ibc_module_interface::{MsgOnChanCloseInit, MsgOnChanOpenInit, MsgOnChanOpenTry},
+    packet_handling::encoding::EncodingModule,
+};
+use common_types::{
+    channel_types::{acknowledgement, channel, channel_counterparty},
+    ConnectionHops,
+};
+use host::{commitment::CommitmentModule, storage::StorageModule};
+use multiversx_sc::types::{EgldOrEsdtTokenIdentifier, ManagedBuffer};
+use multiversx_sc_scenario::{
+    imports::{BlockchainStateWrapper, OptionalValue},
+    managed_address, managed_buffer, managed_token_id, rust_biguint, DebugApi,
+};
+use test_utils::bech32_address;
+use transfer::{
+    denom::DenomModule, ibc_module::IbcModule, ibc_module::ICS20_VERSION, relay::RelayModule,
+    relay::TRANSFER_PORT_ID,
+};
+use transfer_setup::*;
+
+pub mod transfer_setup;
+
+fn get_transfer_setup() -> TransferSetup<
+    impl 'static + Copy + Fn() -> ibc_handler::ContractObj<DebugApi>,
+    impl 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    impl 'static + Copy + Fn() -> transfer::ContractObj<DebugApi>,
+> {
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_biguint!(0));
+    TransferSetup::new(
+        Rc::new(RefCell::new(b_mock)),
+        &owner,
+        ibc_handler::contract_obj,
+        mock::contract_obj,
+        transfer::contract_obj,
+    )
+}
+
+fn transfer_tokens<IbcHandlerObjBuilder, MockClientObjBuilder, TransferObjBuilder>(
+    setup: &TransferSetup<IbcHandlerObjBuilder, MockClientObjBuilder, TransferObjBuilder>,
+    token_id: &[u8],
+    amount: u64,
+) where
+    IbcHandlerObjBuilder: 'static + Copy + Fn() -> ibc_handler::ContractObj<DebugApi>,
+    MockClientObjBuilder: 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    TransferObjBuilder: 'static + Copy + Fn() -> transfer::ContractObj<DebugApi>,
+{
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_esdt_transfer(
+            &setup.user,
+            &setup.transfer_wrapper,
+            token_id,
+            0,
+            &rust_biguint!(amount),
+            |sc| {
+                let seq = sc.transfer(
+                    managed_buffer!(CHANNEL_ID),
+                    managed_buffer!(COUNTERPARTY_RECEIVER),
+                    TIMEOUT_HEIGHT,
+                    0,
+                    OptionalValue::None,
+                );
+                assert_eq!(seq, 1);
+            },
+        )
+        .assert_ok();
+}
+
+/// Delivers a packet from the counterparty, returning the acknowledgement of the transfer app
+fn receive_packet<IbcHandlerObjBuilder, MockClientObjBuilder, TransferObjBuilder>(
+    setup: &TransferSetup<IbcHandlerObjBuilder, MockClientObjBuilder, TransferObjBuilder>,
+    data: &[u8],
+) -> Vec<u8>
+where
+    IbcHandlerObjBuilder: 'static + Copy + Fn() -> ibc_handler::ContractObj<DebugApi>,
+    MockClientObjBuilder: 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    TransferObjBuilder: 'static + Copy + Fn() -> transfer::ContractObj<DebugApi>,
+{
+    let ibc_handler_address = setup.ibc_handler_wrapper.address_ref().clone();
+    let mut ack = Vec::new();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.transfer_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let result = sc.on_recv_packet(
+                    incoming_packet(1, data),
+                    managed_address!(&ibc_handler_address),
+                );
+                ack = result.to_boxed_bytes().into_vec();
+            },
+        )
+        .assert_ok();
+
+    ack
+}
+
+fn successful_ack() -> Vec<u8> {
+    br#"{"result":"AQ=="}"#.to_vec()
+}
+
+fn error_ack(error: &str) -> Vec<u8> {
+    format!(r#"{{"error":"{}"}}"#, error).into_bytes()
+}
+
+#[test]
+fn transfer_native_token_test() {
+    let setup = get_transfer_setup();
+    let user_bech32 = bech32_address(&setup.b_mock, &setup.transfer_wrapper, &setup.user);
+    let transfer_address = setup.transfer_wrapper.address_ref().clone();
+
+    transfer_tokens(&setup, NATIVE_TOKEN_ID, 100);
+
+    let b_mock = setup.b_mock.borrow();
+    b_mock.check_esdt_balance(&setup.user, NATIVE_TOKEN_ID, &rust_biguint!(900));
+    b_mock.check_esdt_balance(&transfer_address, NATIVE_TOKEN_ID, &rust_biguint!(100));
+    drop(b_mock);
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.transfer_wrapper, |sc| {
+            let token = EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(NATIVE_TOKEN_ID));
+            assert_eq!(
+                sc.channel_escrow(&managed_buffer!(CHANNEL_ID), &token)
+                    .get(),
+                100u64
+            );
+        })
+        .assert_ok();
+
+    // the packet data is committed exactly as ibc-go encodes it
+    let data = packet_data(
+        "TOKEN-123456",
+        100,
+        &user_bech32,
+        std::str::from_utf8(COUNTERPARTY_RECEIVER).unwrap(),
+    );
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.ibc_handler_wrapper, |sc| {
+            let commitment_key = sc.get_packet_commitment_key(
+                &managed_buffer!(TRANSFER_PORT_ID),
+                &managed_buffer!(CHANNEL_ID),
+                1,
+            );
+            assert_eq!(
+                sc.commitments(&commitment_key).get(),
+                sc.encode_and_hash_twice(TIMEOUT_HEIGHT, 0, &managed_buffer!(&data))
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn transfer_egld_test() {
+    let setup = get_transfer_setup();
+    let transfer_address = setup.transfer_wrapper.address_ref().clone();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &setup.user,
+            &setup.transfer_wrapper,
+            &rust_biguint!(300),
+            |sc| {
+                sc.transfer(
+                    managed_buffer!(CHANNEL_ID),
+                    managed_buffer!(COUNTERPARTY_RECEIVER),
+                    TIMEOUT_HEIGHT,
+                    0,
+                    OptionalValue::Some(managed_buffer!(b"memo")),
+                );
+
+                let escrowed = sc
+                    .channel_escrow(
+                        &managed_buffer!(CHANNEL_ID),
+                        &EgldOrEsdtTokenIdentifier::egld(),
+                    )
+                    .get();
+                assert_eq!(escrowed, 300u64);
+            },
+        )
+        .assert_ok();
+
+    setup
+        .b_mock
+        .borrow()
+        .check_egld_balance(&transfer_address, &rust_biguint!(300));
+}
+
+#[test]
+fn transfer_voucher_burns_test() {
+    let setup = get_transfer_setup();
+    let transfer_address = setup.transfer_wrapper.address_ref().clone();
+    setup.set_voucher();
+    setup.b_mock.borrow_mut().set_esdt_balance(
+        &setup.user,
+        VOUCHER_TOKEN_ID,
+        &rust_biguint!(USER_BALANCE),
+    );
+
+    transfer_tokens(&setup, VOUCHER_TOKEN_ID, 40);
+
+    let b_mock = setup.b_mock.borrow();
+    b_mock.check_esdt_balance(&setup.user, VOUCHER_TOKEN_ID, &rust_biguint!(960));
+    b_mock.check_esdt_balance(&transfer_address, VOUCHER_TOKEN_ID, &rust_biguint!(0));
+    drop(b_mock);
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.transfer_wrapper, |sc| {
+            let token = EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(VOUCHER_TOKEN_ID));
+            assert!(sc
+                .channel_escrow(&managed_buffer!(CHANNEL_ID), &token)
+                .is_empty());
+            assert_eq!(sc.get_denom(&token), managed_buffer!(VOUCHER_DENOM_TRACE));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn transfer_to_unknown_channel_test() {
+    let setup = get_transfer_setup();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_esdt_transfer(
+            &setup.user,
+            &setup.transfer_wrapper,
+            NATIVE_TOKEN_ID,
+            0,
+            &rust_biguint!(100),
+            |sc| {
+                sc.transfer(
+                    managed_buffer!(b"channel-1"),
+                    managed_buffer!(COUNTERPARTY_RECEIVER),
+                    TIMEOUT_HEIGHT,
+                    0,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error("Channel not claimed");
+}
+
+#[test]
+fn receive_returning_native_token_test() {
+    let setup = get_transfer_setup();
+    let user_bech32 = bech32_address(&setup.b_mock, &setup.transfer_wrapper, &setup.user);
+    transfer_tokens(&setup, NATIVE_TOKEN_ID, 100);
+
+    let ack = receive_packet(
+        &setup,
+        &packet_data(
+            "transfer/channel-5/TOKEN-123456",
+            40,
+            "cosmos1sender",
+            &user_bech32,
+        ),
+    );
+    assert_eq!(ack, successful_ack());
+    setup
+        .b_mock
+        .borrow()
+        .check_esdt_balance(&setup.user, NATIVE_TOKEN_ID, &rust_biguint!(940));
+
+    // only what was escrowed on the channel can come back
+    let ack = receive_packet(
+        &setup,
+        &packet_data(
+            "transfer/channel-5/TOKEN-123456",
+            61,
+            "cosmos1sender",
+            &user_bech32,
+        ),
+    );
+    assert_eq!(ack, error_ack("Insufficient escrowed amount"));
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.transfer_wrapper, |sc| {
+            let token = EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(NATIVE_TOKEN_ID));
+            assert_eq!(
+                sc.channel_escrow(&managed_buffer!(CHANNEL_ID), &token)
+                    .get(),
+                60u64
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn receive_voucher_test() {
+    let setup = get_transfer_setup();
+    let user_bech32 = bech32_address(&setup.b_mock, &setup.transfer_wrapper, &setup.user);
+    setup.set_voucher();
+
+    let ack = receive_packet(
+        &setup,
+        &packet_data("uatom", 25, "cosmos1sender", &user_bech32),
+    );
+    assert_eq!(ack, successful_ack());
+    setup
+        .b_mock
+        .borrow()
+        .check_esdt_balance(&setup.user, VOUCHER_TOKEN_ID, &rust_biguint!(25));
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.transfer_wrapper, |sc| {
+            assert_eq!(
+                sc.get_voucher_token(managed_buffer!(VOUCHER_DENOM_TRACE))
+                    .into_option(),
+                Some(managed_token_id!(VOUCHER_TOKEN_ID))
+            );
+            assert_eq!(
+                sc.get_ibc_denom(managed_buffer!(b"transfer/channel-0/uatom")),
+                managed_buffer!(
+                    b"ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+                )
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn receive_unissued_voucher_test() {
+    let setup = get_transfer_setup();
+    let user_bech32 = bech32_address(&setup.b_mock, &setup.transfer_wrapper, &setup.user);
+    let user = setup.user.clone();
+    let owner = setup.owner.clone();
+
+    // the transfers are received, and held for the receiver until the voucher is issued
+    for amount in [25, 15] {
+        let ack = receive_packet(
+            &setup,
+            &packet_data("uatom", amount, "cosmos1sender", &user_bech32),
+        );
+        assert_eq!(ack, successful_ack());
+    }
+    setup
+        .b_mock
+        .borrow()
+        .check_esdt_balance(&user, VOUCHER_TOKEN_ID, &rust_biguint!(0));
+
+    // the trace is recorded, so that the owner knows which voucher to issue
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.transfer_wrapper, |sc| {
+            let denom_trace = managed_buffer!(VOUCHER_DENOM_TRACE);
+            assert_eq!(
+                sc.denom_traces(&sc.denom_hash(&denom_trace)).get(),
+                denom_trace
+            );
+            assert!(sc
+                .get_voucher_token(denom_trace.clone())
+                .into_option()
+                .is_none());
+            assert_eq!(
+                sc.get_unclaimed_vouchers(denom_trace, managed_address!(&user)),
+                40u64
+            );
+        })
+        .assert_ok();
+
+    let claim_vouchers = || {
+        setup.b_mock.borrow_mut().execute_tx(
+            &owner,
+            &setup.transfer_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.claim_vouchers(
+                    managed_buffer!(VOUCHER_DENOM_TRACE),
+                    managed_address!(&user),
+                );
+            },
+        )
+    };
+    claim_vouchers().assert_user_error("Voucher not issued");
+
+    // anyone may claim once the voucher is issued, always for the receiver
+    setup.set_voucher();
+    claim_vouchers().assert_ok();
+    setup
+        .b_mock
+        .borrow()
+        .check_esdt_balance(&user, VOUCHER_TOKEN_ID, &rust_biguint!(40));
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.transfer_wrapper, |sc| {
+            assert_eq!(
+                sc.get_unclaimed_vouchers(
+                    managed_buffer!(VOUCHER_DENOM_TRACE),
+                    managed_address!(&user)
+                ),
+                0u64
+            );
+        })
+        .assert_ok();
+
+    claim_vouchers().assert_user_error("No vouchers to claim");
+}
+
+#[test]
+fn receive_invalid_packet_test() {
+    let setup = get_transfer_setup();
+
+    let ack = receive_packet(&setup, b"{\"amount\":\"1\"}");
+    assert_eq!(ack, error_ack("Invalid packet data"));
+
+    let ack = receive_packet(&setup, &packet_data("uatom", 0, "cosmos1sender", "erd1abc"));
+    assert_eq!(ack, error_ack("Invalid packet data"));
+
+    let ack = receive_packet(&setup, &packet_data("uatom", 1, "cosmos1sender", "erd1abc"));
+    assert_eq!(ack, error_ack("Invalid receiver"));
+}
+
+#[test]
+fn revert_receive_transfer_test() {
+    let setup = get_transfer_setup();
+    let user_bech32 = bech32_address(&setup.b_mock, &setup.transfer_wrapper, &setup.user);
+    let ibc_handler_address = setup.ibc_handler_wrapper.address_ref().clone();
+    setup.set_voucher();
+    transfer_tokens(&setup, NATIVE_TOKEN_ID, 100);
+
+    // the module above got the tokens of the packets, and gives them back
+    let returning_data = packet_data(
+        "transfer/channel-5/TOKEN-123456",
+        40,
+        "cosmos1sender",
+        &user_bech32,
+    );
+    let voucher_data = packet_data("uatom", 25, "cosmos1sender", &user_bech32);
+    setup.b_mock.borrow_mut().set_esdt_balance(
+        &ibc_handler_address,
+        NATIVE_TOKEN_ID,
+        &rust_biguint!(40),
+    );
+    setup.b_mock.borrow_mut().set_esdt_balance(
+        &ibc_handler_address,
+        VOUCHER_TOKEN_ID,
+        &rust_biguint!(25),
+    );
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_esdt_transfer(
+            &ibc_handler_address,
+            &setup.transfer_wrapper,
+            VOUCHER_TOKEN_ID,
+            0,
+            &rust_biguint!(20),
+            |sc| {
+                sc.revert_receive_transfer(incoming_packet(2, &voucher_data));
+            },
+        )
+        .assert_user_error("Invalid payment");
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_esdt_transfer(
+            &ibc_handler_address,
+            &setup.transfer_wrapper,
+            NATIVE_TOKEN_ID,
+            0,
+            &rust_biguint!(40),
+            |sc| {
+                sc.revert_receive_transfer(incoming_packet(1, &returning_data));
+
+                let token = EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(NATIVE_TOKEN_ID));
+                assert_eq!(
+                    sc.channel_escrow(&managed_buffer!(CHANNEL_ID), &token)
+                        .get(),
+                    140u64
+                );
+            },
+        )
+        .assert_ok();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_esdt_transfer(
+            &ibc_handler_address,
+            &setup.transfer_wrapper,
+            VOUCHER_TOKEN_ID,
+            0,
+            &rust_biguint!(25),
+            |sc| {
+                sc.revert_receive_transfer(incoming_packet(2, &voucher_data));
+            },
+        )
+        .assert_ok();
+    setup.b_mock.borrow().check_esdt_balance(
+        setup.transfer_wrapper.address_ref(),
+        VOUCHER_TOKEN_ID,
+        &rust_biguint!(0),
+    );
+}
+
+#[test]
+fn ack_refund_test() {
+    let setup = get_transfer_setup();
+    let user_bech32 = bech32_address(&setup.b_mock, &setup.transfer_wrapper, &setup.user);
+    let ibc_handler_address = setup.ibc_handler_wrapper.address_ref().clone();
+    let data = packet_data(
+        "TOKEN-123456",
+        100,
+        &user_bech32,
+        std::str::from_utf8(COUNTERPARTY_RECEIVER).unwrap(),
+    );
+    transfer_tokens(&setup, NATIVE_TOKEN_ID, 100);
+
+    // a successful transfer keeps the tokens escrowed
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.transfer_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.on_ack_packet(
+                    outgoing_packet(1, &data),
+                    acknowledgement::new_successful(),
+                    managed_address!(&ibc_handler_address),
+                );
+            },
+        )
+        .assert_ok();
+    setup
+        .b_mock
+        .borrow()
+        .check_esdt_balance(&setup.user, NATIVE_TOKEN_ID, &rust_biguint!(900));
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.transfer_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.on_ack_packet(
+                    outgoing_packet(1, &data),
+                    managed_buffer!(b"not an acknowledgement"),
+                    managed_address!(&ibc_handler_address),
+                );
+            },
+        )
+        .assert_user_error("Invalid acknowledgement");
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.transfer_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.on_ack_packet(
+                    outgoing_packet(1, &data),
+                    acknowledgement::new_error(b"failed"),
+                    managed_address!(&ibc_handler_address),
+                );
+
+                let token = EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(NATIVE_TOKEN_ID));
+                assert_eq!(
+                    sc.channel_escrow(&managed_buffer!(CHANNEL_ID), &token)
+                        .get(),
+                    0u64
+                );
+            },
+        )
+        .assert_ok();
+    setup.b_mock.borrow().check_esdt_balance(
+        &setup.user,
+        NATIVE_TOKEN_ID,
+        &rust_biguint!(USER_BALANCE),
+    );
+}
+
+#[test]
+fn timeout_refunds_voucher_test() {
+    let setup = get_transfer_setup();
+    let user_bech32 = bech32_address(&setup.b_mock, &setup.transfer_wrapper, &setup.user);
+    let ibc_handler_address = setup.ibc_handler_wrapper.address_ref().clone();
+    setup.set_voucher();
+    setup
+        .b_mock
+        .borrow_mut()
+        .set_esdt_balance(&setup.user, VOUCHER_TOKEN_ID, &rust_biguint!(50));
+    transfer_tokens(&setup, VOUCHER_TOKEN_ID, 50);
+    setup
+        .b_mock
+        .borrow()
+        .check_esdt_balance(&setup.user, VOUCHER_TOKEN_ID, &rust_biguint!(0));
+
+    let data = packet_data(
+        "transfer/channel-0/uatom",
+        50,
+        &user_bech32,
+        std::str::from_utf8(COUNTERPARTY_RECEIVER).unwrap(),
+    );
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.transfer_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.on_timeout_packet(
+                    outgoing_packet(1, &data),
+                    managed_address!(&ibc_handler_address),
+                );
+            },
+        )
+        .assert_ok();
+
+    setup
+        .b_mock
+        .borrow()
+        .check_esdt_balance(&setup.user, VOUCHER_TOKEN_ID, &rust_biguint!(50));
+}
+
+#[test]
+fn channel_handshake_test() {
+    let setup = get_transfer_setup();
+    let ibc_handler_address = setup.ibc_handler_wrapper.address_ref().clone();
+
+    let open_init = |order: channel::Order, version: &[u8]| MsgOnChanOpenInit::<DebugApi> {
+        order,
+        connection_hops: ConnectionHops::from_single_item(managed_buffer!(CONNECTION_ID)),
+        port_id: managed_buffer!(TRANSFER_PORT_ID),
+        channel_id: managed_buffer!(b"channel-1"),
+        counterparty: channel_counterparty::Data {
+            port_id: managed_buffer!(TRANSFER_PORT_ID),
+            channel_id: ManagedBuffer::new(),
+        },
+        version: managed_buffer!(version),
+    };
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.transfer_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let version = sc.on_chan_open_init(open_init(channel::Order::Unordered, b""));
+                assert_eq!(version, managed_buffer!(ICS20_VERSION));
+            },
+        )
+        .assert_ok();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.transfer_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.on_chan_open_init(open_init(channel::Order::Ordered, ICS20_VERSION));
+            },
+        )
+        .assert_user_error("Transfer channels must be unordered");
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.transfer_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.on_chan_open_init(open_init(channel::Order::Unordered, b"ics20-2"));
+            },
+        )
+        .assert_user_error("Invalid version");
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.transfer_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.on_chan_open_try(MsgOnChanOpenTry {
+                    order: channel::Order::Unordered,
+                    connection_hops: ConnectionHops::from_single_item(managed_buffer!(
+                        CONNECTION_ID
+                    )),
+                    port_id: managed_buffer!(TRANSFER_PORT_ID),
+                    channel_id: managed_buffer!(b"channel-1"),
+                    counterparty: channel_counterparty::Data {
+                        port_id: managed_buffer!(TRANSFER_PORT_ID),
+                        channel_id: managed_buffer!(b"channel-9"),
+                    },
+                    counterparty_version: managed_buffer!(b"mockapp-1"),
+                });
+            },
+        )
+        .assert_user_error("Invalid version");
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &ibc_handler_address,
+            &setup.transfer_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.on_chan_close_init(MsgOnChanCloseInit {
+                    port_id: managed_buffer!(TRANSFER_PORT_ID),
+                    channel_id: managed_buffer!(CHANNEL_ID),
+                });
+            },
+        )
+        .assert_user_error("Transfer channels cannot be closed");
+}
+
+#[test]
+fn only_ibc_handler_callbacks_test() {
+    let setup = get_transfer_setup();
+    let user_bech32 = bech32_address(&setup.b_mock, &setup.transfer_wrapper, &setup.user);
+    let user = setup.user.clone();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(&user, &setup.transfer_wrapper, &rust_biguint!(0), |sc| {
+            sc.on_recv_packet(
+                incoming_packet(1, &packet_data("uatom", 25, "cosmos1sender", &user_bech32)),
+                managed_address!(&user),
+            );
+        })
+        .assert_user_error("Only the IBC handler may call this endpoint");
+}
+
+#[test]
+fn issue_voucher_invalid_trace_test() {
+    let setup = get_transfer_setup();
+    let owner = setup.owner.clone();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(&owner, &setup.transfer_wrapper, &rust_biguint!(0), |sc| {
+            sc.issue_voucher(managed_buffer!(b"uatom"));
+        })
+        .assert_user_error("Invalid denom trace");
+
+    setup.set_voucher();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(&owner, &setup.transfer_wrapper, &rust_biguint!(0), |sc| {
+            sc.issue_voucher(managed_buffer!(VOUCHER_DENOM_TRACE));
+        })
+        .assert_user_error("Voucher already issued");
+}
+
+#[test]
+fn voucher_metadata_test() {
+    let setup = get_transfer_setup();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.transfer_wrapper, |sc| {
+            let denom_trace = managed_buffer!(b"transfer/channel-0/uatom");
+            assert_eq!(
+                sc.voucher_display_name(&denom_trace),
+                managed_buffer!(b"Ibcuatom")
+            );
+            assert_eq!(sc.voucher_ticker(&denom_trace), managed_buffer!(b"UATOM"));
+
+            // only the letters and digits of the base denom are kept, within the ESDT limits
+            let denom_trace =
+                managed_buffer!(b"transfer/channel-1/transfer/channel-0/erc20-0x1234567890abcdef");
+            assert_eq!(
+                sc.voucher_display_name(&denom_trace),
+                managed_buffer!(b"Ibcerc200x1234567890")
+            );
+            assert_eq!(
+                sc.voucher_ticker(&denom_trace),
+                managed_buffer!(b"ERC200X123")
+            );
+
+            let denom_trace = managed_buffer!(b"transfer/channel-0/gamm/pool/1");
+            assert_eq!(
+                sc.voucher_display_name(&denom_trace),
+                managed_buffer!(b"Ibc1")
+            );
+            assert_eq!(sc.voucher_ticker(&denom_trace), managed_buffer!(b"IBC"));
+        })
+        .assert_ok();
+}
@@ -1,4 +1,7 @@
-use common_types::{channel_types::height, ChannelId, PortId, Sequence, UnixTimestamp};
+use common_types::{
+    channel_types::{channel, error_receipt, height, upgrade, upgrade_fields},
+    ChannelId, ConnectionId, PortId, Sequence, UnixTimestamp, Version,
+};
 
 use super::packet_types::Packet;
 
@@ -20,6 +23,154 @@ pub trait EventsModule {
     #[event("generatedChannelIdEvent")]
     fn generated_channel_id_event(&self, #[indexed] channel_id: &ChannelId<Self::Api>);
 
+    #[event("channelOpenInitEvent")]
+    fn channel_open_init_event(
+        &self,
+        #[indexed] port_id: &PortId<Self::Api>,
+        #[indexed] channel_id: &ChannelId<Self::Api>,
+        #[indexed] counterparty_port_id: &PortId<Self::Api>,
+        #[indexed] connection_id: &ConnectionId<Self::Api>,
+        version: &Version<Self::Api>,
+    );
+
+    #[event("channelOpenTryEvent")]
+    fn channel_open_try_event(
+        &self,
+        #[indexed] port_id: &PortId<Self::Api>,
+        #[indexed] channel_id: &ChannelId<Self::Api>,
+        #[indexed] counterparty_port_id: &PortId<Self::Api>,
+        #[indexed] counterparty_channel_id: &ChannelId<Self::Api>,
+        #[indexed] connection_id: &ConnectionId<Self::Api>,
+        version: &Version<Self::Api>,
+    );
+
+    #[event("channelOpenAckEvent")]
+    fn channel_open_ack_event(
+        &self,
+        #[indexed] port_id: &PortId<Self::Api>,
+        #[indexed] channel_id: &ChannelId<Self::Api>,
+        #[indexed] counterparty_port_id: &PortId<Self::Api>,
+        #[indexed] counterparty_channel_id: &ChannelId<Self::Api>,
+        #[indexed] connection_id: &ConnectionId<Self::Api>,
+    );
+
+    #[event("channelOpenConfirmEvent")]
+    fn channel_open_confirm_event(
+        &self,
+        #[indexed] port_id: &PortId<Self::Api>,
+        #[indexed] channel_id: &ChannelId<Self::Api>,
+        #[indexed] counterparty_port_id: &PortId<Self::Api>,
+        #[indexed] counterparty_channel_id: &ChannelId<Self::Api>,
+        #[indexed] connection_id: &ConnectionId<Self::Api>,
+    );
+
+    #[event("channelCloseInitEvent")]
+    fn channel_close_init_event(
+        &self,
+        #[indexed] port_id: &PortId<Self::Api>,
+        #[indexed] channel_id: &ChannelId<Self::Api>,
+        #[indexed] counterparty_port_id: &PortId<Self::Api>,
+        #[indexed] counterparty_channel_id: &ChannelId<Self::Api>,
+        #[indexed] connection_id: &ConnectionId<Self::Api>,
+    );
+
+    #[event("channelCloseConfirmEvent")]
+    fn channel_close_confirm_event(
+        &self,
+        #[indexed] port_id: &PortId<Self::Api>,
+        #[indexed] channel_id: &ChannelId<Self::Api>,
+        #[indexed] counterparty_port_id: &PortId<Self::Api>,
+        #[indexed] counterparty_channel_id: &ChannelId<Self::Api>,
+        #[indexed] connection_id: &ConnectionId<Self::Api>,
+    );
+
+    #[event("channelClosedEvent")]
+    fn channel_closed_event(
+        &self,
+        #[indexed] port_id: &PortId<Self::Api>,
+        #[indexed] channel_id: &ChannelId<Self::Api>,
+        #[indexed] counterparty_port_id: &PortId<Self::Api>,
+        #[indexed] counterparty_channel_id: &ChannelId<Self::Api>,
+        #[indexed] connection_id: &ConnectionId<Self::Api>,
+    );
+
+    #[event("channelUpgradeInitEvent")]
+    fn channel_upgrade_init_event(
+        &self,
+        #[indexed] port_id: &PortId<Self::Api>,
+        #[indexed] channel_id: &ChannelId<Self::Api>,
+        #[indexed] upgrade_sequence: Sequence,
+        upgrade_fields: &upgrade_fields::Data<Self::Api>,
+    );
+
+    #[event("channelUpgradeTryEvent")]
+    fn channel_upgrade_try_event(
+        &self,
+        #[indexed] port_id: &PortId<Self::Api>,
+        #[indexed] channel_id: &ChannelId<Self::Api>,
+        #[indexed] upgrade_sequence: Sequence,
+        upgrade: &upgrade::Data<Self::Api>,
+    );
+
+    #[event("channelUpgradeAckEvent")]
+    fn channel_upgrade_ack_event(
+        &self,
+        #[indexed] port_id: &PortId<Self::Api>,
+        #[indexed] channel_id: &ChannelId<Self::Api>,
+        #[indexed] upgrade_sequence: Sequence,
+        channel_state: channel::State,
+    );
+
+    #[event("channelUpgradeConfirmEvent")]
+    fn channel_upgrade_confirm_event(
+        &self,
+        #[indexed] port_id: &PortId<Self::Api>,
+        #[indexed] channel_id: &ChannelId<Self::Api>,
+        #[indexed] upgrade_sequence: Sequence,
+        channel_state: channel::State,
+    );
+
+    #[event("channelUpgradeOpenEvent")]
+    fn channel_upgrade_open_event(
+        &self,
+        #[indexed] port_id: &PortId<Self::Api>,
+        #[indexed] channel_id: &ChannelId<Self::Api>,
+        #[indexed] upgrade_sequence: Sequence,
+        channel: &channel::Data<Self::Api>,
+    );
+
+    #[event("channelUpgradeCancelEvent")]
+    fn channel_upgrade_cancel_event(
+        &self,
+        #[indexed] port_id: &PortId<Self::Api>,
+        #[indexed] channel_id: &ChannelId<Self::Api>,
+        #[indexed] upgrade_sequence: Sequence,
+    );
+
+    #[event("channelUpgradeTimeoutEvent")]
+    fn channel_upgrade_timeout_event(
+        &self,
+        #[indexed] port_id: &PortId<Self::Api>,
+        #[indexed] channel_id: &ChannelId<Self::Api>,
+        #[indexed] upgrade_sequence: Sequence,
+    );
+
+    #[event("channelUpgradeErrorEvent")]
+    fn channel_upgrade_error_event(
+        &self,
+        #[indexed] port_id: &PortId<Self::Api>,
+        #[indexed] channel_id: &ChannelId<Self::Api>,
+        error_receipt: &error_receipt::Data<Self::Api>,
+    );
+
+    #[event("pendingAckEvent")]
+    fn pending_ack_event(
+        &self,
+        #[indexed] dest_port_id: &PortId<Self::Api>,
+        #[indexed] dest_channel: &ChannelId<Self::Api>,
+        #[indexed] seq: Sequence,
+    );
+
     #[event("writeAckEvent")]
     fn write_ack_event(
         &self,
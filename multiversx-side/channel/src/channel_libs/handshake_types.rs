@@ -1,6 +1,6 @@
 use common_types::{
-    channel_types::{channel, height},
-    ChannelId, Hash, PortId, Version,
+    channel_types::{channel, error_receipt, height, upgrade, upgrade_fields},
+    ChannelId, ConnectionHops, PortId, Proof, Sequence, Version,
 };
 
 multiversx_sc::imports!();
@@ -17,7 +17,7 @@ pub struct MsgChannelOpenTry<M: ManagedTypeApi> {
     pub port_id: PortId<M>,
     pub channel: channel::Data<M>,
     pub counterparty_version: Version<M>,
-    pub proof_init: Hash<M>,
+    pub proof_init: Proof<M>,
     pub proof_height: height::Data,
 }
 
@@ -27,7 +27,7 @@ pub struct MsgChannelOpenAck<M: ManagedTypeApi> {
     pub channel_id: ChannelId<M>,
     pub counterparty_version: Version<M>,
     pub counterparty_channel_id: ChannelId<M>,
-    pub proof_try: Hash<M>,
+    pub proof_try: Proof<M>,
     pub proof_height: height::Data,
 }
 
@@ -35,7 +35,7 @@ pub struct MsgChannelOpenAck<M: ManagedTypeApi> {
 pub struct MsgChannelOpenConfirm<M: ManagedTypeApi> {
     pub port_id: PortId<M>,
     pub channel_id: ChannelId<M>,
-    pub proof_ack: Hash<M>,
+    pub proof_ack: Proof<M>,
     pub proof_height: height::Data,
 }
 
@@ -49,6 +49,75 @@ pub struct MsgChannelCloseInit<M: ManagedTypeApi> {
 pub struct MsgChannelCloseConfirm<M: ManagedTypeApi> {
     pub port_id: PortId<M>,
     pub channel_id: ChannelId<M>,
-    pub proof_init: Hash<M>,
+    pub proof_init: Proof<M>,
+    pub proof_height: height::Data,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct ChannelUpgradeProofs<M: ManagedTypeApi> {
+    pub proof_channel: Proof<M>,
+    pub proof_upgrade: Proof<M>,
+    pub proof_height: height::Data,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode)]
+pub struct MsgChannelUpgradeInit<M: ManagedTypeApi> {
+    pub port_id: PortId<M>,
+    pub channel_id: ChannelId<M>,
+    pub proposed_upgrade_fields: upgrade_fields::Data<M>,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode)]
+pub struct MsgChannelUpgradeTry<M: ManagedTypeApi> {
+    pub port_id: PortId<M>,
+    pub channel_id: ChannelId<M>,
+    pub counterparty_upgrade_fields: upgrade_fields::Data<M>,
+    pub counterparty_upgrade_sequence: Sequence,
+    pub proposed_connection_hops: ConnectionHops<M>,
+    pub proofs: ChannelUpgradeProofs<M>,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode)]
+pub struct MsgChannelUpgradeAck<M: ManagedTypeApi> {
+    pub port_id: PortId<M>,
+    pub channel_id: ChannelId<M>,
+    pub counterparty_upgrade: upgrade::Data<M>,
+    pub proofs: ChannelUpgradeProofs<M>,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode)]
+pub struct MsgChannelUpgradeConfirm<M: ManagedTypeApi> {
+    pub port_id: PortId<M>,
+    pub channel_id: ChannelId<M>,
+    pub counterparty_channel_state: channel::State,
+    pub counterparty_upgrade: upgrade::Data<M>,
+    pub proofs: ChannelUpgradeProofs<M>,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode)]
+pub struct MsgChannelUpgradeOpen<M: ManagedTypeApi> {
+    pub port_id: PortId<M>,
+    pub channel_id: ChannelId<M>,
+    pub counterparty_channel_state: channel::State,
+    pub counterparty_upgrade_sequence: Sequence,
+    pub proof_channel: Proof<M>,
+    pub proof_height: height::Data,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode)]
+pub struct MsgCancelChannelUpgrade<M: ManagedTypeApi> {
+    pub port_id: PortId<M>,
+    pub channel_id: ChannelId<M>,
+    pub error_receipt: error_receipt::Data<M>,
+    pub proof_upgrade_error: Proof<M>,
+    pub proof_height: height::Data,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode)]
+pub struct MsgTimeoutChannelUpgrade<M: ManagedTypeApi> {
+    pub port_id: PortId<M>,
+    pub channel_id: ChannelId<M>,
+    pub counterparty_channel: channel::Data<M>,
+    pub proof_channel: Proof<M>,
     pub proof_height: height::Data,
 }
@@ -6,13 +6,15 @@ multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
 #[multiversx_sc::module]
-pub trait IbcChannelLibModule: common_modules::utils::UtilsModule {
+pub trait IbcChannelLibModule:
+    common_modules::utils::UtilsModule + crate::packet_handling::encoding::EncodingModule
+{
     fn receipt_commitment_to_receipt(&self, commitment: &Hash<Self::Api>) -> PacketReceipt {
         if self.is_empty_hash(commitment) {
             return PacketReceipt::None;
         }
 
-        let encoded_success = self.encode_to_buffer(&PacketReceipt::Successful);
+        let encoded_success = self.encode_successful_receipt();
         let successful_hash = self.crypto().keccak256(&encoded_success);
         if commitment == &successful_hash {
             return PacketReceipt::Successful;
@@ -1,4 +1,4 @@
-use common_types::{channel_types::height, ChannelId, Hash, PortId, Sequence, UnixTimestamp};
+use common_types::{channel_types::height, ChannelId, PortId, Proof, Sequence, UnixTimestamp};
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
@@ -41,7 +41,7 @@ pub struct Packet<M: ManagedTypeApi> {
 #[derive(TypeAbi, TopEncode, TopDecode)]
 pub struct MsgPacketRecv<M: ManagedTypeApi> {
     pub packet: Packet<M>,
-    pub proof: Hash<M>,
+    pub proof: Proof<M>,
     pub proof_height: height::Data,
 }
 
@@ -49,14 +49,14 @@ pub struct MsgPacketRecv<M: ManagedTypeApi> {
 pub struct MsgPacketAcknowledgement<M: ManagedTypeApi> {
     pub packet: Packet<M>,
     pub ack: ManagedBuffer<M>, // TODO: Or is it Hash<M>?
-    pub proof: Hash<M>,
+    pub proof: Proof<M>,
     pub proof_height: height::Data,
 }
 
 #[derive(TypeAbi, TopEncode, TopDecode)]
 pub struct MsgTimeoutPacket<M: ManagedTypeApi> {
     pub packet: Packet<M>,
-    pub proof: Hash<M>,
+    pub proof: Proof<M>,
     pub proof_height: height::Data,
     pub next_seq_recv: Sequence,
 }
@@ -64,8 +64,8 @@ pub struct MsgTimeoutPacket<M: ManagedTypeApi> {
 #[derive(TypeAbi, TopEncode, TopDecode)]
 pub struct MsgTimeoutOnClose<M: ManagedTypeApi> {
     pub packet: Packet<M>,
-    pub proof_unreceived: Hash<M>,
-    pub proof_close: Hash<M>,
+    pub proof_unreceived: Proof<M>,
+    pub proof_close: Proof<M>,
     pub proof_height: height::Data,
     pub next_seq_recv: Sequence,
     pub counterparty_upgrade_seq: Sequence,
@@ -74,7 +74,7 @@ pub struct MsgTimeoutOnClose<M: ManagedTypeApi> {
 pub trait TimeoutArgs<M: ManagedTypeApi> {
     fn get_packet(&self) -> &Packet<M>;
 
-    fn get_proof(&self) -> &Hash<M>;
+    fn get_proof(&self) -> &Proof<M>;
 
     fn get_proof_height(&self) -> height::Data;
 
@@ -88,7 +88,7 @@ impl<M: ManagedTypeApi> TimeoutArgs<M> for MsgTimeoutPacket<M> {
     }
 
     #[inline(always)]
-    fn get_proof(&self) -> &Hash<M> {
+    fn get_proof(&self) -> &Proof<M> {
         &self.proof
     }
 
@@ -110,7 +110,7 @@ impl<M: ManagedTypeApi> TimeoutArgs<M> for MsgTimeoutOnClose<M> {
     }
 
     #[inline(always)]
-    fn get_proof(&self) -> &Hash<M> {
+    fn get_proof(&self) -> &Proof<M> {
         &self.proof_unreceived
     }
 
@@ -0,0 +1,134 @@
+use common_types::{
+    channel_types::{channel, channel_counterparty},
+    ConnectionHops,
+};
+
+use crate::{
+    channel_libs::handshake_types::{MsgChannelCloseConfirm, MsgChannelCloseInit},
+    interfaces::ibc_module_interface::{self, MsgOnChanCloseConfirm, MsgOnChanCloseInit},
+};
+
+use super::chan_internal::VerifyChannelStateArgs;
+
+multiversx_sc::imports!();
+
+static CHANNEL_ALREADY_CLOSED_ERR_MSG: &[u8] = b"Channel already closed";
+
+#[multiversx_sc::module]
+pub trait ChannelCloseModule:
+    super::chan_internal::ChannelInternalModule
+    + host::module_manager::ModuleManagerModule
+    + host::storage::StorageModule
+    + host::commitment::CommitmentModule
+    + host::host_views::HostViewsModule
+    + crate::channel_libs::events::EventsModule
+    + common_modules::utils::UtilsModule
+    + crate::packet_handling::encoding::EncodingModule
+{
+    /// Is called by the module owning the channel to close its end of the channel.
+    ///
+    /// Once closed, channels cannot be reopened.
+    #[endpoint(channelCloseInit)]
+    fn channel_close_init(&self, args: MsgChannelCloseInit<Self::Api>) {
+        let caller = self.blockchain().get_caller();
+        self.authenticate_channel_capability(&args.port_id, &args.channel_id, &caller);
+
+        let mut channel_info = self.try_get_channel_info(&args.port_id, &args.channel_id);
+        let channel = &mut channel_info.channel;
+        require!(
+            !matches!(channel.state, channel::State::Closed),
+            CHANNEL_ALREADY_CLOSED_ERR_MSG
+        );
+        let _ = self.get_open_connection_for_channel(&channel.connection_hops, channel.ordering);
+
+        channel.state = channel::State::Closed;
+        self.update_channel_commitment(&args.port_id, &args.channel_id, channel);
+
+        let counterparty = channel.counterparty.clone();
+        let connection_id = channel.connection_hops.get(0).clone_value();
+        self.channel_info(&args.port_id, &args.channel_id)
+            .set(&channel_info);
+
+        let _: () = self
+            .ibc_module_proxy_impl_chan_close(caller)
+            .on_chan_close_init(MsgOnChanCloseInit {
+                port_id: args.port_id.clone(),
+                channel_id: args.channel_id.clone(),
+            })
+            .execute_on_dest_context();
+
+        self.channel_close_init_event(
+            &args.port_id,
+            &args.channel_id,
+            &counterparty.port_id,
+            &counterparty.channel_id,
+            &connection_id,
+        );
+    }
+
+    /// Is called by the counterparty module to close their end of the channel, since the other end has been closed.
+    #[endpoint(channelCloseConfirm)]
+    fn channel_close_confirm(&self, args: MsgChannelCloseConfirm<Self::Api>) {
+        let mut channel_info = self.try_get_channel_info(&args.port_id, &args.channel_id);
+        let channel = &mut channel_info.channel;
+        require!(
+            !matches!(channel.state, channel::State::Closed),
+            CHANNEL_ALREADY_CLOSED_ERR_MSG
+        );
+
+        let connection_info =
+            self.get_open_connection_for_channel(&channel.connection_hops, channel.ordering);
+        let expected_channel = channel::Data {
+            state: channel::State::Closed,
+            ordering: channel.ordering,
+            counterparty: channel_counterparty::Data {
+                port_id: args.port_id.clone(),
+                channel_id: args.channel_id.clone(),
+            },
+            connection_hops: ConnectionHops::from_single_item(
+                connection_info.counterparty.connection_id.clone(),
+            ),
+            version: channel.version.clone(),
+            upgrade_sequence: channel.upgrade_sequence,
+        };
+        self.verify_channel_state(VerifyChannelStateArgs {
+            connection_info: &connection_info,
+            height: args.proof_height,
+            proof: args.proof_init,
+            port_id: &channel.counterparty.port_id,
+            channel_id: &channel.counterparty.channel_id,
+            channel: &expected_channel,
+        });
+
+        channel.state = channel::State::Closed;
+        self.update_channel_commitment(&args.port_id, &args.channel_id, channel);
+
+        let counterparty = channel.counterparty.clone();
+        let connection_id = channel.connection_hops.get(0).clone_value();
+        self.channel_info(&args.port_id, &args.channel_id)
+            .set(&channel_info);
+
+        let module = self.lookup_module_by_channel(&args.port_id, &args.channel_id);
+        let _: () = self
+            .ibc_module_proxy_impl_chan_close(module)
+            .on_chan_close_confirm(MsgOnChanCloseConfirm {
+                port_id: args.port_id.clone(),
+                channel_id: args.channel_id.clone(),
+            })
+            .execute_on_dest_context();
+
+        self.channel_close_confirm_event(
+            &args.port_id,
+            &args.channel_id,
+            &counterparty.port_id,
+            &counterparty.channel_id,
+            &connection_id,
+        );
+    }
+
+    #[proxy]
+    fn ibc_module_proxy_impl_chan_close(
+        &self,
+        sc_address: ManagedAddress,
+    ) -> ibc_module_interface::ibc_module_proxy::IbcModuleProxy<Self::Api>;
+}
@@ -0,0 +1,136 @@
+use client_common::VerifyMembershipArgs;
+use common_types::{
+    channel_types::{channel, height, upgrade},
+    connection_types::connection_end,
+    ChannelId, ConnectionHops, PortId, Proof,
+};
+use host::storage::{ChannelInfo, RecvStartSequence};
+
+use crate::interfaces::client_interface;
+
+multiversx_sc::imports!();
+
+pub struct VerifyChannelStateArgs<'a, M: ManagedTypeApi> {
+    pub connection_info: &'a connection_end::Data<M>,
+    pub height: height::Data,
+    pub proof: Proof<M>,
+    pub port_id: &'a PortId<M>,
+    pub channel_id: &'a ChannelId<M>,
+    pub channel: &'a channel::Data<M>,
+}
+
+#[multiversx_sc::module]
+pub trait ChannelInternalModule:
+    host::module_manager::ModuleManagerModule
+    + host::storage::StorageModule
+    + host::commitment::CommitmentModule
+    + host::host_views::HostViewsModule
+    + common_modules::utils::UtilsModule
+    + crate::packet_handling::encoding::EncodingModule
+{
+    fn generate_channel_id(&self) -> ChannelId<Self::Api> {
+        let next_channel_seq = self.get_next_channel_seq();
+
+        sc_format!("channel-{}", next_channel_seq)
+    }
+
+    /// Stores a freshly created channel end, with all sequences starting at 1
+    fn init_channel_info(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+        channel: channel::Data<Self::Api>,
+    ) {
+        let mapper = self.channel_info(port_id, channel_id);
+        require!(mapper.is_empty(), "Channel already exists");
+
+        let channel_info = ChannelInfo {
+            channel,
+            next_seq_send: 1,
+            next_seq_recv: 1,
+            next_seq_ack: 1,
+            upgrade: upgrade::Data::default(),
+            latest_error_rec_seq: 0,
+            recv_start_seq: RecvStartSequence {
+                seq: 0,
+                prev_seq: 0,
+            },
+            ack_start_seq: 0,
+        };
+
+        let commitment_key = self.get_next_seq_recv_commitment_key(port_id, channel_id);
+        let encoded_val = self.encode_sequence(channel_info.next_seq_recv);
+        let hashed_val = self.crypto().keccak256(&encoded_val);
+        self.commitments(&commitment_key).set(hashed_val);
+
+        self.update_channel_commitment(port_id, channel_id, &channel_info.channel);
+        mapper.set(channel_info);
+    }
+
+    fn update_channel_commitment(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+        channel: &channel::Data<Self::Api>,
+    ) {
+        let channel_key = self.get_channel_commitment_key(port_id, channel_id);
+        let encoded_channel = channel.to_proto();
+        let hashed_channel = self.crypto().keccak256(encoded_channel);
+
+        self.commitments(&channel_key).set(hashed_channel);
+    }
+
+    /// The channel must use exactly one connection hop, the connection must be open
+    /// and its negotiated version must support the requested ordering
+    fn get_open_connection_for_channel(
+        &self,
+        connection_hops: &ConnectionHops<Self::Api>,
+        ordering: channel::Order,
+    ) -> connection_end::Data<Self::Api> {
+        require!(connection_hops.len() == 1, "Invalid connection hops length");
+
+        let connection_info = self.try_get_connection_info(&connection_hops.get(0));
+        require!(
+            matches!(connection_info.state, connection_end::State::Open),
+            "Connection not open"
+        );
+        require!(
+            connection_info.versions.len() == 1,
+            "Invalid connection versions length"
+        );
+
+        let feature = ManagedBuffer::from(ordering.to_byte_slice::<Self::Api>());
+        let version = connection_info.versions.get(0);
+        require!(
+            version.features.contains(&feature),
+            "Unsupported channel ordering"
+        );
+
+        connection_info
+    }
+
+    fn verify_channel_state(&self, args: VerifyChannelStateArgs<Self::Api>) {
+        let client = self.check_and_get_client(&args.connection_info.client_id);
+        let membership_args = VerifyMembershipArgs {
+            client_id: args.connection_info.client_id.clone(),
+            height: args.height,
+            delay_time_period: 0,
+            delay_block_period: 0,
+            proof: args.proof.clone(),
+            prefix: args.connection_info.counterparty.prefix.key_prefix.clone(),
+            path: self.get_channel_path(args.port_id, args.channel_id),
+            value: args.channel.to_proto(),
+        };
+        let membership_result: bool = self
+            .generic_client_proxy_impl_handshake(client)
+            .verify_membership(membership_args)
+            .execute_on_dest_context();
+        require!(membership_result, "Failed to verify channel state");
+    }
+
+    #[proxy]
+    fn generic_client_proxy_impl_handshake(
+        &self,
+        sc_address: ManagedAddress,
+    ) -> client_interface::generic_client_proxy::GenericClientProxy<Self::Api>;
+}
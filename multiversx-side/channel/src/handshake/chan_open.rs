@@ -0,0 +1,292 @@
+use common_types::{
+    channel_types::{channel, channel_counterparty},
+    ChannelId, ConnectionHops, PortId, Version,
+};
+
+use crate::{
+    channel_libs::handshake_types::{
+        MsgChannelOpenAck, MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry,
+    },
+    interfaces::ibc_module_interface::{
+        self, MsgOnChanOpenAck, MsgOnChanOpenConfirm, MsgOnChanOpenInit, MsgOnChanOpenTry,
+    },
+};
+
+use super::chan_internal::VerifyChannelStateArgs;
+
+multiversx_sc::imports!();
+
+static INVALID_CHANNEL_STATE_ERR_MSG: &[u8] = b"Invalid channel state";
+
+#[multiversx_sc::module]
+pub trait ChannelOpenModule:
+    super::chan_internal::ChannelInternalModule
+    + host::module_manager::ModuleManagerModule
+    + host::storage::StorageModule
+    + host::commitment::CommitmentModule
+    + host::host_views::HostViewsModule
+    + crate::channel_libs::events::EventsModule
+    + common_modules::utils::UtilsModule
+    + crate::packet_handling::encoding::EncodingModule
+{
+    /// Is called by a module to initiate a channel opening handshake with a module on another chain.
+    ///
+    /// The generated channel identifier and the version chosen by the module are returned.
+    #[endpoint(channelOpenInit)]
+    fn channel_open_init(
+        &self,
+        args: MsgChannelOpenInit<Self::Api>,
+    ) -> MultiValue2<ChannelId<Self::Api>, Version<Self::Api>> {
+        let channel = args.channel;
+        let _ = self.get_open_connection_for_channel(&channel.connection_hops, channel.ordering);
+        require!(
+            matches!(channel.state, channel::State::Init),
+            INVALID_CHANNEL_STATE_ERR_MSG
+        );
+        require!(
+            channel.counterparty.channel_id.is_empty(),
+            "Counterparty channel ID must be empty"
+        );
+
+        let channel_id = self.generate_channel_id();
+        let module = self.claim_channel_stack(&args.port_id, &channel_id);
+        self.init_channel_info(&args.port_id, &channel_id, channel.clone());
+
+        let version: Version<Self::Api> = self
+            .ibc_module_proxy_impl_chan_open(module)
+            .on_chan_open_init(MsgOnChanOpenInit {
+                order: channel.ordering,
+                connection_hops: channel.connection_hops.clone(),
+                port_id: args.port_id.clone(),
+                channel_id: channel_id.clone(),
+                counterparty: channel.counterparty.clone(),
+                version: channel.version,
+            })
+            .execute_on_dest_context();
+        self.set_channel_version(&args.port_id, &channel_id, &version);
+
+        self.generated_channel_id_event(&channel_id);
+        self.channel_open_init_event(
+            &args.port_id,
+            &channel_id,
+            &channel.counterparty.port_id,
+            &channel.connection_hops.get(0),
+            &version,
+        );
+
+        (channel_id, version).into()
+    }
+
+    /// Is called by a module to accept the first step of a channel opening handshake initiated by a module on another chain.
+    ///
+    /// The generated channel identifier and the version chosen by the module are returned.
+    #[endpoint(channelOpenTry)]
+    fn channel_open_try(
+        &self,
+        args: MsgChannelOpenTry<Self::Api>,
+    ) -> MultiValue2<ChannelId<Self::Api>, Version<Self::Api>> {
+        let channel = args.channel;
+        let connection_info =
+            self.get_open_connection_for_channel(&channel.connection_hops, channel.ordering);
+        require!(
+            matches!(channel.state, channel::State::TryOpen),
+            INVALID_CHANNEL_STATE_ERR_MSG
+        );
+        require!(
+            !channel.counterparty.channel_id.is_empty(),
+            "Invalid counterparty channel ID"
+        );
+
+        let expected_channel = channel::Data {
+            state: channel::State::Init,
+            ordering: channel.ordering,
+            counterparty: channel_counterparty::Data {
+                port_id: args.port_id.clone(),
+                channel_id: ChannelId::new(),
+            },
+            connection_hops: ConnectionHops::from_single_item(
+                connection_info.counterparty.connection_id.clone(),
+            ),
+            version: args.counterparty_version.clone(),
+            upgrade_sequence: 0,
+        };
+        self.verify_channel_state(VerifyChannelStateArgs {
+            connection_info: &connection_info,
+            height: args.proof_height,
+            proof: args.proof_init,
+            port_id: &channel.counterparty.port_id,
+            channel_id: &channel.counterparty.channel_id,
+            channel: &expected_channel,
+        });
+
+        let channel_id = self.generate_channel_id();
+        let module = self.claim_channel_stack(&args.port_id, &channel_id);
+        self.init_channel_info(&args.port_id, &channel_id, channel.clone());
+
+        let version: Version<Self::Api> = self
+            .ibc_module_proxy_impl_chan_open(module)
+            .on_chan_open_try(MsgOnChanOpenTry {
+                order: channel.ordering,
+                connection_hops: channel.connection_hops.clone(),
+                port_id: args.port_id.clone(),
+                channel_id: channel_id.clone(),
+                counterparty: channel.counterparty.clone(),
+                counterparty_version: args.counterparty_version,
+            })
+            .execute_on_dest_context();
+        self.set_channel_version(&args.port_id, &channel_id, &version);
+
+        self.generated_channel_id_event(&channel_id);
+        self.channel_open_try_event(
+            &args.port_id,
+            &channel_id,
+            &channel.counterparty.port_id,
+            &channel.counterparty.channel_id,
+            &channel.connection_hops.get(0),
+            &version,
+        );
+
+        (channel_id, version).into()
+    }
+
+    /// Is called by the handshake-originating module to acknowledge the acceptance of the initial request by the counterparty module on the other chain.
+    #[endpoint(channelOpenAck)]
+    fn channel_open_ack(&self, args: MsgChannelOpenAck<Self::Api>) {
+        let mut channel_info = self.try_get_channel_info(&args.port_id, &args.channel_id);
+        let channel = &mut channel_info.channel;
+        require!(
+            matches!(channel.state, channel::State::Init),
+            INVALID_CHANNEL_STATE_ERR_MSG
+        );
+
+        let connection_info =
+            self.get_open_connection_for_channel(&channel.connection_hops, channel.ordering);
+        let expected_channel = channel::Data {
+            state: channel::State::TryOpen,
+            ordering: channel.ordering,
+            counterparty: channel_counterparty::Data {
+                port_id: args.port_id.clone(),
+                channel_id: args.channel_id.clone(),
+            },
+            connection_hops: ConnectionHops::from_single_item(
+                connection_info.counterparty.connection_id.clone(),
+            ),
+            version: args.counterparty_version.clone(),
+            upgrade_sequence: 0,
+        };
+        self.verify_channel_state(VerifyChannelStateArgs {
+            connection_info: &connection_info,
+            height: args.proof_height,
+            proof: args.proof_try,
+            port_id: &channel.counterparty.port_id,
+            channel_id: &args.counterparty_channel_id,
+            channel: &expected_channel,
+        });
+
+        channel.state = channel::State::Open;
+        channel.version = args.counterparty_version.clone();
+        channel.counterparty.channel_id = args.counterparty_channel_id;
+        self.update_channel_commitment(&args.port_id, &args.channel_id, channel);
+
+        let counterparty = channel.counterparty.clone();
+        let connection_id = channel.connection_hops.get(0).clone_value();
+        self.channel_info(&args.port_id, &args.channel_id)
+            .set(&channel_info);
+
+        let module = self.lookup_module_by_channel(&args.port_id, &args.channel_id);
+        let _: () = self
+            .ibc_module_proxy_impl_chan_open(module)
+            .on_chan_open_ack(MsgOnChanOpenAck {
+                port_id: args.port_id.clone(),
+                channel_id: args.channel_id.clone(),
+                counterparty_version: args.counterparty_version,
+            })
+            .execute_on_dest_context();
+
+        self.channel_open_ack_event(
+            &args.port_id,
+            &args.channel_id,
+            &counterparty.port_id,
+            &counterparty.channel_id,
+            &connection_id,
+        );
+    }
+
+    /// Is called by the handshake-accepting module to confirm the acknowledgement of the handshake-originating module on the other chain and finish the channel opening handshake.
+    #[endpoint(channelOpenConfirm)]
+    fn channel_open_confirm(&self, args: MsgChannelOpenConfirm<Self::Api>) {
+        let mut channel_info = self.try_get_channel_info(&args.port_id, &args.channel_id);
+        let channel = &mut channel_info.channel;
+        require!(
+            matches!(channel.state, channel::State::TryOpen),
+            INVALID_CHANNEL_STATE_ERR_MSG
+        );
+
+        let connection_info =
+            self.get_open_connection_for_channel(&channel.connection_hops, channel.ordering);
+        let expected_channel = channel::Data {
+            state: channel::State::Open,
+            ordering: channel.ordering,
+            counterparty: channel_counterparty::Data {
+                port_id: args.port_id.clone(),
+                channel_id: args.channel_id.clone(),
+            },
+            connection_hops: ConnectionHops::from_single_item(
+                connection_info.counterparty.connection_id.clone(),
+            ),
+            version: channel.version.clone(),
+            upgrade_sequence: 0,
+        };
+        self.verify_channel_state(VerifyChannelStateArgs {
+            connection_info: &connection_info,
+            height: args.proof_height,
+            proof: args.proof_ack,
+            port_id: &channel.counterparty.port_id,
+            channel_id: &channel.counterparty.channel_id,
+            channel: &expected_channel,
+        });
+
+        channel.state = channel::State::Open;
+        self.update_channel_commitment(&args.port_id, &args.channel_id, channel);
+
+        let counterparty = channel.counterparty.clone();
+        let connection_id = channel.connection_hops.get(0).clone_value();
+        self.channel_info(&args.port_id, &args.channel_id)
+            .set(&channel_info);
+
+        let module = self.lookup_module_by_channel(&args.port_id, &args.channel_id);
+        let _: () = self
+            .ibc_module_proxy_impl_chan_open(module)
+            .on_chan_open_confirm(MsgOnChanOpenConfirm {
+                port_id: args.port_id.clone(),
+                channel_id: args.channel_id.clone(),
+            })
+            .execute_on_dest_context();
+
+        self.channel_open_confirm_event(
+            &args.port_id,
+            &args.channel_id,
+            &counterparty.port_id,
+            &counterparty.channel_id,
+            &connection_id,
+        );
+    }
+
+    fn set_channel_version(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+        version: &Version<Self::Api>,
+    ) {
+        let mut channel_info = self.channel_info(port_id, channel_id).get();
+        channel_info.channel.version = version.clone();
+        self.update_channel_commitment(port_id, channel_id, &channel_info.channel);
+        self.channel_info(port_id, channel_id).set(channel_info);
+    }
+
+    #[proxy]
+    fn ibc_module_proxy_impl_chan_open(
+        &self,
+        sc_address: ManagedAddress,
+    ) -> ibc_module_interface::ibc_module_proxy::IbcModuleProxy<Self::Api>;
+}
@@ -0,0 +1,678 @@
+use client_common::VerifyMembershipArgs;
+use common_types::{
+    channel_types::{channel, upgrade, upgrade_fields},
+    ChannelId, ConnectionHops, PortId, Sequence, UnixTimestamp, Version,
+};
+use host::storage::ChannelInfo;
+
+use crate::{
+    channel_libs::handshake_types::{
+        ChannelUpgradeProofs, MsgCancelChannelUpgrade, MsgChannelUpgradeAck,
+        MsgChannelUpgradeConfirm, MsgChannelUpgradeInit, MsgChannelUpgradeOpen,
+        MsgChannelUpgradeTry, MsgTimeoutChannelUpgrade,
+    },
+    interfaces::{
+        client_interface,
+        ibc_module_interface::{
+            self, MsgOnChanUpgradeAck, MsgOnChanUpgradeInit, MsgOnChanUpgradeTry,
+        },
+    },
+};
+
+use super::{
+    chan_internal::VerifyChannelStateArgs,
+    upgrade_internal::{
+        VerifyUpgradeArgs, COUNTERPARTY_UPGRADE_TIMEOUT_ERR_MSG, INCOMPATIBLE_UPGRADE_ERR_MSG,
+        INVALID_UPGRADE_SEQUENCE_ERR_MSG, UPGRADE_NOT_FOUND_ERR_MSG,
+    },
+};
+
+multiversx_sc::imports!();
+
+static INVALID_CHANNEL_STATE_ERR_MSG: &[u8] = b"Invalid channel state";
+static INVALID_COUNTERPARTY_STATE_ERR_MSG: &[u8] = b"Invalid counterparty channel state";
+static UPGRADE_CANCELLED_ERR_MSG: &[u8] = b"Upgrade cancelled";
+static UPGRADE_TIMED_OUT_ERR_MSG: &[u8] = b"Upgrade timed out";
+
+#[multiversx_sc::module]
+pub trait ChannelUpgradeModule:
+    super::upgrade_internal::UpgradeInternalModule
+    + super::chan_internal::ChannelInternalModule
+    + host::module_manager::ModuleManagerModule
+    + host::storage::StorageModule
+    + host::commitment::CommitmentModule
+    + host::host_views::HostViewsModule
+    + crate::channel_libs::events::EventsModule
+    + common_modules::utils::UtilsModule
+    + crate::packet_handling::encoding::EncodingModule
+{
+    /// Is called by the module owning the channel to propose new parameters for an open channel.
+    ///
+    /// An upgrade already in progress is aborted and replaced by the new proposal.
+    ///
+    /// The new upgrade sequence is returned.
+    #[endpoint(channelUpgradeInit)]
+    fn channel_upgrade_init(&self, args: MsgChannelUpgradeInit<Self::Api>) -> Sequence {
+        let caller = self.blockchain().get_caller();
+        self.authenticate_channel_capability(&args.port_id, &args.channel_id, &caller);
+
+        let mut channel_info = self.try_get_channel_info(&args.port_id, &args.channel_id);
+        self.require_state_open(channel_info.channel.state);
+        self.require_valid_upgrade_fields(&channel_info.channel, &args.proposed_upgrade_fields);
+
+        if self.is_upgrade_in_progress(&channel_info) {
+            let sequence = channel_info.channel.upgrade_sequence;
+            self.abort_upgrade(
+                &args.port_id,
+                &args.channel_id,
+                &mut channel_info,
+                sequence,
+                UPGRADE_CANCELLED_ERR_MSG,
+            );
+        }
+
+        channel_info.channel.upgrade_sequence += 1;
+        let upgrade_sequence = channel_info.channel.upgrade_sequence;
+
+        let fields = args.proposed_upgrade_fields;
+        let version: Version<Self::Api> = self
+            .ibc_module_proxy_impl_chan_upgrade(caller)
+            .on_chan_upgrade_init(MsgOnChanUpgradeInit {
+                port_id: args.port_id.clone(),
+                channel_id: args.channel_id.clone(),
+                upgrade_sequence,
+                proposed_order: fields.ordering,
+                proposed_connection_hops: fields.connection_hops.clone(),
+                proposed_version: fields.version,
+            })
+            .execute_on_dest_context();
+
+        channel_info.upgrade = upgrade::Data {
+            fields: upgrade_fields::Data {
+                ordering: fields.ordering,
+                connection_hops: fields.connection_hops,
+                version,
+            },
+            ..Default::default()
+        };
+        self.update_channel_commitment(&args.port_id, &args.channel_id, &channel_info.channel);
+        self.update_upgrade_commitment(&args.port_id, &args.channel_id, &channel_info.upgrade);
+        self.channel_info(&args.port_id, &args.channel_id)
+            .set(&channel_info);
+
+        self.channel_upgrade_init_event(
+            &args.port_id,
+            &args.channel_id,
+            upgrade_sequence,
+            &channel_info.upgrade.fields,
+        );
+
+        upgrade_sequence
+    }
+
+    /// Is called by a relayer to accept an upgrade proposed by the counterparty and start flushing in-flight packets.
+    ///
+    /// Returns `false` if the upgrade could not be accepted, in which case an error receipt is written
+    /// so the counterparty can cancel its upgrade.
+    #[endpoint(channelUpgradeTry)]
+    fn channel_upgrade_try(&self, args: MsgChannelUpgradeTry<Self::Api>) -> bool {
+        let mut channel_info = self.try_get_channel_info(&args.port_id, &args.channel_id);
+        self.require_state_open(channel_info.channel.state);
+
+        let is_crossing_hello = self.is_upgrade_in_progress(&channel_info);
+        if is_crossing_hello {
+            require!(
+                self.are_same_connection_hops(
+                    &args.proposed_connection_hops,
+                    &channel_info.upgrade.fields.connection_hops
+                ),
+                "Proposed connection hops mismatch"
+            );
+        }
+
+        let channel = &channel_info.channel;
+        let connection_info =
+            self.get_open_connection_for_channel(&channel.connection_hops, channel.ordering);
+        let counterparty_channel = self.build_counterparty_channel(
+            &args.port_id,
+            &args.channel_id,
+            channel,
+            &connection_info,
+            channel::State::Open,
+            args.counterparty_upgrade_sequence,
+        );
+        self.verify_channel_state(VerifyChannelStateArgs {
+            connection_info: &connection_info,
+            height: args.proofs.proof_height,
+            proof: args.proofs.proof_channel,
+            port_id: &channel.counterparty.port_id,
+            channel_id: &channel.counterparty.channel_id,
+            channel: &counterparty_channel,
+        });
+        self.verify_channel_upgrade(VerifyUpgradeArgs {
+            connection_info: &connection_info,
+            height: args.proofs.proof_height,
+            proof: args.proofs.proof_upgrade,
+            port_id: &channel.counterparty.port_id,
+            channel_id: &channel.counterparty.channel_id,
+            upgrade: &upgrade::Data {
+                fields: args.counterparty_upgrade_fields.clone(),
+                ..Default::default()
+            },
+        });
+
+        let current_sequence = channel.upgrade_sequence;
+        let expected_sequence = if is_crossing_hello {
+            current_sequence
+        } else {
+            current_sequence + 1
+        };
+        if args.counterparty_upgrade_sequence < current_sequence {
+            // the counterparty is outdated, it has to sync with our own upgrade sequence
+            self.abort_upgrade(
+                &args.port_id,
+                &args.channel_id,
+                &mut channel_info,
+                expected_sequence,
+                INVALID_UPGRADE_SEQUENCE_ERR_MSG,
+            );
+
+            return false;
+        }
+        if args.counterparty_upgrade_sequence != expected_sequence {
+            self.abort_upgrade(
+                &args.port_id,
+                &args.channel_id,
+                &mut channel_info,
+                args.counterparty_upgrade_sequence,
+                INVALID_UPGRADE_SEQUENCE_ERR_MSG,
+            );
+
+            return false;
+        }
+
+        if !is_crossing_hello {
+            let fields = upgrade_fields::Data {
+                ordering: args.counterparty_upgrade_fields.ordering,
+                connection_hops: args.proposed_connection_hops,
+                version: args.counterparty_upgrade_fields.version.clone(),
+            };
+            self.require_valid_upgrade_fields(&channel_info.channel, &fields);
+
+            channel_info.upgrade.fields = fields;
+            channel_info.channel.upgrade_sequence = args.counterparty_upgrade_sequence;
+        }
+
+        if !self.is_upgrade_compatible(
+            &channel_info.upgrade.fields,
+            &args.counterparty_upgrade_fields,
+        ) {
+            let sequence = channel_info.channel.upgrade_sequence;
+            self.abort_upgrade(
+                &args.port_id,
+                &args.channel_id,
+                &mut channel_info,
+                sequence,
+                INCOMPATIBLE_UPGRADE_ERR_MSG,
+            );
+
+            return false;
+        }
+
+        self.start_flushing(&mut channel_info);
+
+        let module = self.lookup_module_by_channel(&args.port_id, &args.channel_id);
+        let upgrade_sequence = channel_info.channel.upgrade_sequence;
+        let fields = &channel_info.upgrade.fields;
+        let version: Version<Self::Api> = self
+            .ibc_module_proxy_impl_chan_upgrade(module)
+            .on_chan_upgrade_try(MsgOnChanUpgradeTry {
+                port_id: args.port_id.clone(),
+                channel_id: args.channel_id.clone(),
+                upgrade_sequence,
+                proposed_order: fields.ordering,
+                proposed_connection_hops: fields.connection_hops.clone(),
+                counterparty_version: args.counterparty_upgrade_fields.version,
+            })
+            .execute_on_dest_context();
+        channel_info.upgrade.fields.version = version;
+
+        self.update_channel_commitment(&args.port_id, &args.channel_id, &channel_info.channel);
+        self.update_upgrade_commitment(&args.port_id, &args.channel_id, &channel_info.upgrade);
+        self.channel_info(&args.port_id, &args.channel_id)
+            .set(&channel_info);
+
+        self.channel_upgrade_try_event(
+            &args.port_id,
+            &args.channel_id,
+            upgrade_sequence,
+            &channel_info.upgrade,
+        );
+
+        true
+    }
+
+    /// Is called by a relayer on the upgrade-initiating end, once the counterparty has accepted the upgrade.
+    ///
+    /// Returns `false` if the upgrade was aborted, in which case an error receipt is written.
+    #[endpoint(channelUpgradeAck)]
+    fn channel_upgrade_ack(&self, args: MsgChannelUpgradeAck<Self::Api>) -> bool {
+        let mut channel_info = self.try_get_channel_info(&args.port_id, &args.channel_id);
+        require!(
+            matches!(
+                channel_info.channel.state,
+                channel::State::Open | channel::State::Flushing
+            ),
+            INVALID_CHANNEL_STATE_ERR_MSG
+        );
+        require!(
+            self.is_upgrade_in_progress(&channel_info),
+            UPGRADE_NOT_FOUND_ERR_MSG
+        );
+
+        self.verify_counterparty_upgrade_state(
+            &args.port_id,
+            &args.channel_id,
+            &channel_info,
+            channel::State::Flushing,
+            &args.counterparty_upgrade,
+            &args.proofs,
+        );
+
+        let upgrade_sequence = channel_info.channel.upgrade_sequence;
+        if !self.is_upgrade_compatible(
+            &channel_info.upgrade.fields,
+            &args.counterparty_upgrade.fields,
+        ) {
+            self.abort_upgrade(
+                &args.port_id,
+                &args.channel_id,
+                &mut channel_info,
+                upgrade_sequence,
+                INCOMPATIBLE_UPGRADE_ERR_MSG,
+            );
+
+            return false;
+        }
+
+        if matches!(channel_info.channel.state, channel::State::Open) {
+            self.start_flushing(&mut channel_info);
+        }
+
+        if self.has_upgrade_timed_out(&args.counterparty_upgrade.timeout) {
+            self.abort_upgrade(
+                &args.port_id,
+                &args.channel_id,
+                &mut channel_info,
+                upgrade_sequence,
+                COUNTERPARTY_UPGRADE_TIMEOUT_ERR_MSG,
+            );
+
+            return false;
+        }
+
+        self.set_recv_start_seq(
+            &mut channel_info,
+            args.counterparty_upgrade.next_sequence_send,
+        );
+
+        let module = self.lookup_module_by_channel(&args.port_id, &args.channel_id);
+        let _: () = self
+            .ibc_module_proxy_impl_chan_upgrade(module)
+            .on_chan_upgrade_ack(MsgOnChanUpgradeAck {
+                port_id: args.port_id.clone(),
+                channel_id: args.channel_id.clone(),
+                upgrade_sequence,
+                counterparty_version: args.counterparty_upgrade.fields.version,
+            })
+            .execute_on_dest_context();
+
+        self.try_transition_to_flush_complete(&args.port_id, &args.channel_id, &mut channel_info);
+        self.update_channel_commitment(&args.port_id, &args.channel_id, &channel_info.channel);
+        self.update_upgrade_commitment(&args.port_id, &args.channel_id, &channel_info.upgrade);
+        self.channel_info(&args.port_id, &args.channel_id)
+            .set(&channel_info);
+
+        self.channel_upgrade_ack_event(
+            &args.port_id,
+            &args.channel_id,
+            upgrade_sequence,
+            channel_info.channel.state,
+        );
+
+        true
+    }
+
+    /// Is called by a relayer on the upgrade-accepting end, once the counterparty is flushing as well.
+    ///
+    /// May be called again while FLUSHING, once all in-flight packets have been flushed.
+    /// If both ends have completed flushing, the upgraded channel is opened.
+    ///
+    /// Returns `false` if the upgrade was aborted, in which case an error receipt is written.
+    #[endpoint(channelUpgradeConfirm)]
+    fn channel_upgrade_confirm(&self, args: MsgChannelUpgradeConfirm<Self::Api>) -> bool {
+        let mut channel_info = self.try_get_channel_info(&args.port_id, &args.channel_id);
+        require!(
+            matches!(channel_info.channel.state, channel::State::Flushing),
+            INVALID_CHANNEL_STATE_ERR_MSG
+        );
+        require!(
+            matches!(
+                args.counterparty_channel_state,
+                channel::State::Flushing | channel::State::FlushComplete
+            ),
+            INVALID_COUNTERPARTY_STATE_ERR_MSG
+        );
+
+        self.verify_counterparty_upgrade_state(
+            &args.port_id,
+            &args.channel_id,
+            &channel_info,
+            args.counterparty_channel_state,
+            &args.counterparty_upgrade,
+            &args.proofs,
+        );
+
+        let upgrade_sequence = channel_info.channel.upgrade_sequence;
+        if self.has_upgrade_timed_out(&args.counterparty_upgrade.timeout) {
+            self.abort_upgrade(
+                &args.port_id,
+                &args.channel_id,
+                &mut channel_info,
+                upgrade_sequence,
+                COUNTERPARTY_UPGRADE_TIMEOUT_ERR_MSG,
+            );
+
+            return false;
+        }
+
+        self.set_recv_start_seq(
+            &mut channel_info,
+            args.counterparty_upgrade.next_sequence_send,
+        );
+        self.try_transition_to_flush_complete(&args.port_id, &args.channel_id, &mut channel_info);
+        self.update_channel_commitment(&args.port_id, &args.channel_id, &channel_info.channel);
+        self.channel_info(&args.port_id, &args.channel_id)
+            .set(&channel_info);
+
+        self.channel_upgrade_confirm_event(
+            &args.port_id,
+            &args.channel_id,
+            upgrade_sequence,
+            channel_info.channel.state,
+        );
+
+        if matches!(
+            args.counterparty_channel_state,
+            channel::State::FlushComplete
+        ) && matches!(channel_info.channel.state, channel::State::FlushComplete)
+        {
+            self.open_upgrade_handshake(&args.port_id, &args.channel_id, &mut channel_info);
+        }
+
+        true
+    }
+
+    /// Is called by a relayer to open the upgraded channel, once both ends have completed flushing.
+    #[endpoint(channelUpgradeOpen)]
+    fn channel_upgrade_open(&self, args: MsgChannelUpgradeOpen<Self::Api>) {
+        let mut channel_info = self.try_get_channel_info(&args.port_id, &args.channel_id);
+        let channel = &channel_info.channel;
+        require!(
+            matches!(channel.state, channel::State::FlushComplete),
+            INVALID_CHANNEL_STATE_ERR_MSG
+        );
+
+        let connection_info =
+            self.get_open_connection_for_channel(&channel.connection_hops, channel.ordering);
+        let counterparty_channel = match args.counterparty_channel_state {
+            channel::State::FlushComplete => self.build_counterparty_channel(
+                &args.port_id,
+                &args.channel_id,
+                channel,
+                &connection_info,
+                channel::State::FlushComplete,
+                channel.upgrade_sequence,
+            ),
+            // the counterparty has already opened the upgraded channel
+            channel::State::Open => {
+                require!(
+                    args.counterparty_upgrade_sequence >= channel.upgrade_sequence,
+                    INVALID_UPGRADE_SEQUENCE_ERR_MSG
+                );
+
+                let fields = &channel_info.upgrade.fields;
+                let upgrade_connection_info =
+                    self.try_get_connection_info(&fields.connection_hops.get(0));
+                let upgraded_channel = channel::Data {
+                    ordering: fields.ordering,
+                    version: fields.version.clone(),
+                    ..channel.clone()
+                };
+
+                self.build_counterparty_channel(
+                    &args.port_id,
+                    &args.channel_id,
+                    &upgraded_channel,
+                    &upgrade_connection_info,
+                    channel::State::Open,
+                    args.counterparty_upgrade_sequence,
+                )
+            }
+            _ => sc_panic!(INVALID_COUNTERPARTY_STATE_ERR_MSG),
+        };
+        self.verify_channel_state(VerifyChannelStateArgs {
+            connection_info: &connection_info,
+            height: args.proof_height,
+            proof: args.proof_channel,
+            port_id: &channel.counterparty.port_id,
+            channel_id: &channel.counterparty.channel_id,
+            channel: &counterparty_channel,
+        });
+
+        self.open_upgrade_handshake(&args.port_id, &args.channel_id, &mut channel_info);
+    }
+
+    /// Cancels the upgrade in progress and restores the channel to its pre-upgrade parameters.
+    ///
+    /// The module owning the channel may cancel at any time before flushing is complete.
+    /// Anyone else must prove the counterparty wrote an error receipt for the current upgrade.
+    #[endpoint(channelUpgradeCancel)]
+    fn channel_upgrade_cancel(&self, args: MsgCancelChannelUpgrade<Self::Api>) {
+        let mut channel_info = self.try_get_channel_info(&args.port_id, &args.channel_id);
+        require!(
+            self.is_upgrade_in_progress(&channel_info),
+            UPGRADE_NOT_FOUND_ERR_MSG
+        );
+
+        let caller = self.blockchain().get_caller();
+        let module = self.lookup_module_by_channel(&args.port_id, &args.channel_id);
+        let channel = &channel_info.channel;
+        let is_flush_complete = matches!(channel.state, channel::State::FlushComplete);
+        let mut sequence = channel.upgrade_sequence;
+        if caller != module || is_flush_complete {
+            let error_sequence = args.error_receipt.sequence;
+            require!(
+                error_sequence >= channel.upgrade_sequence,
+                INVALID_UPGRADE_SEQUENCE_ERR_MSG
+            );
+            require!(
+                !is_flush_complete || error_sequence == channel.upgrade_sequence,
+                INVALID_UPGRADE_SEQUENCE_ERR_MSG
+            );
+
+            let connection_info =
+                self.get_open_connection_for_channel(&channel.connection_hops, channel.ordering);
+            let client = self.check_and_get_client(&connection_info.client_id);
+            let membership_args = VerifyMembershipArgs {
+                client_id: connection_info.client_id.clone(),
+                height: args.proof_height,
+                delay_time_period: 0,
+                delay_block_period: 0,
+                proof: args.proof_upgrade_error.clone(),
+                prefix: connection_info.counterparty.prefix.key_prefix.clone(),
+                path: self.get_channel_upgrade_error_path(
+                    &channel.counterparty.port_id,
+                    &channel.counterparty.channel_id,
+                ),
+                value: args.error_receipt.to_proto(),
+            };
+            let membership_result: bool = self
+                .generic_client_proxy_impl_chan_upgrade(client)
+                .verify_membership(membership_args)
+                .execute_on_dest_context();
+            require!(membership_result, "Failed to verify upgrade error receipt");
+
+            sequence = error_sequence;
+        }
+
+        self.abort_upgrade(
+            &args.port_id,
+            &args.channel_id,
+            &mut channel_info,
+            sequence,
+            UPGRADE_CANCELLED_ERR_MSG,
+        );
+
+        self.channel_upgrade_cancel_event(&args.port_id, &args.channel_id, sequence);
+    }
+
+    /// Aborts the upgrade in progress if the counterparty did not complete flushing before the upgrade timeout.
+    #[endpoint(channelUpgradeTimeout)]
+    fn channel_upgrade_timeout(&self, args: MsgTimeoutChannelUpgrade<Self::Api>) {
+        let mut channel_info = self.try_get_channel_info(&args.port_id, &args.channel_id);
+        require!(
+            self.is_upgrade_in_progress(&channel_info),
+            UPGRADE_NOT_FOUND_ERR_MSG
+        );
+
+        let channel = &channel_info.channel;
+        require!(
+            matches!(
+                channel.state,
+                channel::State::Flushing | channel::State::FlushComplete
+            ),
+            INVALID_CHANNEL_STATE_ERR_MSG
+        );
+
+        let counterparty_channel = &args.counterparty_channel;
+        require!(
+            counterparty_channel.counterparty.port_id == args.port_id
+                && counterparty_channel.counterparty.channel_id == args.channel_id,
+            "Invalid counterparty channel"
+        );
+        require!(
+            !matches!(counterparty_channel.state, channel::State::FlushComplete),
+            INVALID_COUNTERPARTY_STATE_ERR_MSG
+        );
+        require!(
+            !matches!(counterparty_channel.state, channel::State::Open)
+                || counterparty_channel.upgrade_sequence < channel.upgrade_sequence,
+            "Counterparty channel already upgraded"
+        );
+
+        let connection_info = self.try_get_connection_info(&channel.connection_hops.get(0));
+        let upgrade_timeout = &channel_info.upgrade.timeout;
+        let height_reached =
+            !upgrade_timeout.height.is_zero() && args.proof_height >= upgrade_timeout.height;
+        if !height_reached {
+            let client = self.check_and_get_client(&connection_info.client_id);
+            let timestamp_at_height: UnixTimestamp = self
+                .generic_client_proxy_impl_chan_upgrade(client)
+                .get_timestamp_at_height(&connection_info.client_id, args.proof_height)
+                .execute_on_dest_context();
+            require!(
+                upgrade_timeout.timestamp != 0 && timestamp_at_height >= upgrade_timeout.timestamp,
+                "Upgrade timeout not reached"
+            );
+        }
+
+        self.verify_channel_state(VerifyChannelStateArgs {
+            connection_info: &connection_info,
+            height: args.proof_height,
+            proof: args.proof_channel,
+            port_id: &channel.counterparty.port_id,
+            channel_id: &channel.counterparty.channel_id,
+            channel: counterparty_channel,
+        });
+
+        let sequence = channel.upgrade_sequence;
+        self.abort_upgrade(
+            &args.port_id,
+            &args.channel_id,
+            &mut channel_info,
+            sequence,
+            UPGRADE_TIMED_OUT_ERR_MSG,
+        );
+
+        self.channel_upgrade_timeout_event(&args.port_id, &args.channel_id, sequence);
+    }
+
+    fn verify_counterparty_upgrade_state(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+        channel_info: &ChannelInfo<Self::Api>,
+        counterparty_state: channel::State,
+        counterparty_upgrade: &upgrade::Data<Self::Api>,
+        proofs: &ChannelUpgradeProofs<Self::Api>,
+    ) {
+        let channel = &channel_info.channel;
+        let connection_info =
+            self.get_open_connection_for_channel(&channel.connection_hops, channel.ordering);
+        let counterparty_channel = self.build_counterparty_channel(
+            port_id,
+            channel_id,
+            channel,
+            &connection_info,
+            counterparty_state,
+            channel.upgrade_sequence,
+        );
+        self.verify_channel_state(VerifyChannelStateArgs {
+            connection_info: &connection_info,
+            height: proofs.proof_height,
+            proof: proofs.proof_channel.clone(),
+            port_id: &channel.counterparty.port_id,
+            channel_id: &channel.counterparty.channel_id,
+            channel: &counterparty_channel,
+        });
+        self.verify_channel_upgrade(VerifyUpgradeArgs {
+            connection_info: &connection_info,
+            height: proofs.proof_height,
+            proof: proofs.proof_upgrade.clone(),
+            port_id: &channel.counterparty.port_id,
+            channel_id: &channel.counterparty.channel_id,
+            upgrade: counterparty_upgrade,
+        });
+    }
+
+    fn are_same_connection_hops(
+        &self,
+        first: &ConnectionHops<Self::Api>,
+        second: &ConnectionHops<Self::Api>,
+    ) -> bool {
+        if first.len() != second.len() {
+            return false;
+        }
+
+        for (first_hop, second_hop) in first.iter().zip(second.iter()) {
+            if *first_hop != *second_hop {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    #[proxy]
+    fn generic_client_proxy_impl_chan_upgrade(
+        &self,
+        sc_address: ManagedAddress,
+    ) -> client_interface::generic_client_proxy::GenericClientProxy<Self::Api>;
+
+    #[proxy]
+    fn ibc_module_proxy_impl_chan_upgrade(
+        &self,
+        sc_address: ManagedAddress,
+    ) -> ibc_module_interface::ibc_module_proxy::IbcModuleProxy<Self::Api>;
+}
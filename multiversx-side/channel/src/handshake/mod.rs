@@ -0,0 +1,5 @@
+pub mod chan_close;
+pub mod chan_internal;
+pub mod chan_open;
+pub mod chan_upgrade;
+pub mod upgrade_internal;
@@ -0,0 +1,356 @@
+use client_common::VerifyMembershipArgs;
+use common_types::{
+    channel_types::{
+        channel, channel_counterparty, error_receipt, height, timeout, upgrade, upgrade_fields,
+    },
+    connection_types::connection_end,
+    ChannelId, ConnectionHops, PortId, Proof, Sequence, UnixTimestamp,
+};
+use host::storage::ChannelInfo;
+
+use crate::interfaces::{
+    client_interface,
+    ibc_module_interface::{self, MsgOnChanUpgradeOpen},
+};
+
+multiversx_sc::imports!();
+
+/// 10 minutes, in nanoseconds
+pub const DEFAULT_UPGRADE_TIMEOUT_PERIOD: UnixTimestamp = 600_000_000_000;
+
+pub static UPGRADE_NOT_FOUND_ERR_MSG: &[u8] = b"Upgrade not found";
+pub static INCOMPATIBLE_UPGRADE_ERR_MSG: &[u8] = b"Incompatible upgrade fields";
+pub static INVALID_UPGRADE_SEQUENCE_ERR_MSG: &[u8] = b"Invalid upgrade sequence";
+pub static COUNTERPARTY_UPGRADE_TIMEOUT_ERR_MSG: &[u8] = b"Counterparty upgrade timed out";
+
+pub struct VerifyUpgradeArgs<'a, M: ManagedTypeApi> {
+    pub connection_info: &'a connection_end::Data<M>,
+    pub height: height::Data,
+    pub proof: Proof<M>,
+    pub port_id: &'a PortId<M>,
+    pub channel_id: &'a ChannelId<M>,
+    pub upgrade: &'a upgrade::Data<M>,
+}
+
+#[multiversx_sc::module]
+pub trait UpgradeInternalModule:
+    super::chan_internal::ChannelInternalModule
+    + host::module_manager::ModuleManagerModule
+    + host::storage::StorageModule
+    + host::commitment::CommitmentModule
+    + host::host_views::HostViewsModule
+    + crate::channel_libs::events::EventsModule
+    + common_modules::utils::UtilsModule
+    + crate::packet_handling::encoding::EncodingModule
+{
+    fn is_upgrade_in_progress(&self, channel_info: &ChannelInfo<Self::Api>) -> bool {
+        !channel_info.upgrade.fields.connection_hops.is_empty()
+    }
+
+    /// The proposed fields must use an open connection supporting the proposed ordering and must differ from the current ones
+    fn require_valid_upgrade_fields(
+        &self,
+        channel: &channel::Data<Self::Api>,
+        fields: &upgrade_fields::Data<Self::Api>,
+    ) {
+        require!(
+            !matches!(fields.ordering, channel::Order::NoneUnspecified),
+            "Invalid upgrade ordering"
+        );
+        let _ = self.get_open_connection_for_channel(&fields.connection_hops, fields.ordering);
+
+        let unchanged = fields.ordering == channel.ordering
+            && fields.connection_hops.get(0).clone_value()
+                == channel.connection_hops.get(0).clone_value()
+            && fields.version == channel.version;
+        require!(!unchanged, "Upgrade fields are unchanged");
+    }
+
+    /// Both ends must agree on ordering and version, and our proposed connection must be the counterparty of theirs
+    fn is_upgrade_compatible(
+        &self,
+        fields: &upgrade_fields::Data<Self::Api>,
+        counterparty_fields: &upgrade_fields::Data<Self::Api>,
+    ) -> bool {
+        if fields.ordering != counterparty_fields.ordering
+            || fields.version != counterparty_fields.version
+            || counterparty_fields.connection_hops.len() != 1
+        {
+            return false;
+        }
+
+        let connection_info = self.try_get_connection_info(&fields.connection_hops.get(0));
+
+        connection_info.counterparty.connection_id
+            == counterparty_fields.connection_hops.get(0).clone_value()
+    }
+
+    fn has_upgrade_timed_out(&self, upgrade_timeout: &timeout::Data) -> bool {
+        let block_nonce = self.blockchain().get_block_nonce();
+        let height_reached = upgrade_timeout.height.revision_height != 0
+            && block_nonce >= upgrade_timeout.height.revision_height;
+        let timestamp_reached = upgrade_timeout.timestamp != 0
+            && self.get_host_timestamp() >= upgrade_timeout.timestamp;
+
+        height_reached || timestamp_reached
+    }
+
+    /// Moves the channel to FLUSHING, fixing the last sequence sent before the upgrade and the upgrade timeout
+    fn start_flushing(&self, channel_info: &mut ChannelInfo<Self::Api>) {
+        channel_info.channel.state = channel::State::Flushing;
+        channel_info.upgrade.next_sequence_send = channel_info.next_seq_send;
+        channel_info.upgrade.timeout = timeout::Data {
+            height: height::Data::default(),
+            timestamp: self.get_host_timestamp() + DEFAULT_UPGRADE_TIMEOUT_PERIOD,
+        };
+    }
+
+    /// Packets from the counterparty with a sequence of at least `counterparty_next_seq_send` belong to the upgraded channel
+    fn set_recv_start_seq(
+        &self,
+        channel_info: &mut ChannelInfo<Self::Api>,
+        counterparty_next_seq_send: Sequence,
+    ) {
+        let rseq = &mut channel_info.recv_start_seq;
+        if rseq.seq == counterparty_next_seq_send {
+            return;
+        }
+
+        rseq.prev_seq = rseq.seq;
+        rseq.seq = counterparty_next_seq_send;
+    }
+
+    fn try_transition_to_flush_complete(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+        channel_info: &mut ChannelInfo<Self::Api>,
+    ) {
+        if !matches!(channel_info.channel.state, channel::State::Flushing) {
+            return;
+        }
+
+        if self.can_transition_to_flush_complete(port_id, channel_id, channel_info) {
+            channel_info.channel.state = channel::State::FlushComplete;
+        }
+    }
+
+    /// Completes the flush of an upgrading channel once the commitment of a packet it sent is deleted,
+    /// so it doesn't wait for another `channelUpgradeConfirm`
+    fn try_flush_after_packet_settled(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+        channel_info: &mut ChannelInfo<Self::Api>,
+    ) {
+        if !matches!(channel_info.channel.state, channel::State::Flushing) {
+            return;
+        }
+
+        self.try_transition_to_flush_complete(port_id, channel_id, channel_info);
+        if matches!(channel_info.channel.state, channel::State::FlushComplete) {
+            self.update_channel_commitment(port_id, channel_id, &channel_info.channel);
+            self.channel_info(port_id, channel_id).set(&*channel_info);
+        }
+    }
+
+    /// Ordered channels are flushed once every sent packet was acknowledged,
+    /// otherwise only the module knows whether packets are still in flight
+    fn can_transition_to_flush_complete(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+        channel_info: &ChannelInfo<Self::Api>,
+    ) -> bool {
+        if matches!(channel_info.channel.ordering, channel::Order::Ordered) {
+            return channel_info.next_seq_send == channel_info.next_seq_ack;
+        }
+
+        let module = self.lookup_module_by_channel(port_id, channel_id);
+        self.ibc_module_proxy_impl_upgrade_internal(module)
+            .can_transition_to_flush_complete(
+                port_id,
+                channel_id,
+                channel_info.channel.upgrade_sequence,
+            )
+            .execute_on_dest_context()
+    }
+
+    /// The channel end the counterparty is expected to have stored while the upgrade is in progress
+    fn build_counterparty_channel(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+        channel: &channel::Data<Self::Api>,
+        connection_info: &connection_end::Data<Self::Api>,
+        state: channel::State,
+        upgrade_sequence: Sequence,
+    ) -> channel::Data<Self::Api> {
+        channel::Data {
+            state,
+            ordering: channel.ordering,
+            counterparty: channel_counterparty::Data {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+            },
+            connection_hops: ConnectionHops::from_single_item(
+                connection_info.counterparty.connection_id.clone(),
+            ),
+            version: channel.version.clone(),
+            upgrade_sequence,
+        }
+    }
+
+    fn verify_channel_upgrade(&self, args: VerifyUpgradeArgs<Self::Api>) {
+        let client = self.check_and_get_client(&args.connection_info.client_id);
+        let membership_args = VerifyMembershipArgs {
+            client_id: args.connection_info.client_id.clone(),
+            height: args.height,
+            delay_time_period: 0,
+            delay_block_period: 0,
+            proof: args.proof.clone(),
+            prefix: args.connection_info.counterparty.prefix.key_prefix.clone(),
+            path: self.get_channel_upgrade_path(args.port_id, args.channel_id),
+            value: args.upgrade.to_proto(),
+        };
+        let membership_result: bool = self
+            .generic_client_proxy_impl_upgrade_internal(client)
+            .verify_membership(membership_args)
+            .execute_on_dest_context();
+        require!(membership_result, "Failed to verify channel upgrade");
+    }
+
+    fn update_upgrade_commitment(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+        upgrade: &upgrade::Data<Self::Api>,
+    ) {
+        let upgrade_key = self.get_channel_upgrade_commitment_key(port_id, channel_id);
+        let encoded_upgrade = upgrade.to_proto();
+        let hashed_upgrade = self.crypto().keccak256(encoded_upgrade);
+
+        self.commitments(&upgrade_key).set(hashed_upgrade);
+    }
+
+    fn delete_upgrade(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+        channel_info: &mut ChannelInfo<Self::Api>,
+    ) {
+        channel_info.upgrade = upgrade::Data::default();
+
+        let upgrade_key = self.get_channel_upgrade_commitment_key(port_id, channel_id);
+        self.commitments(&upgrade_key).clear();
+    }
+
+    fn write_error_receipt(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+        channel_info: &mut ChannelInfo<Self::Api>,
+        sequence: Sequence,
+        message: &[u8],
+    ) {
+        require!(
+            sequence > channel_info.latest_error_rec_seq,
+            "Error receipt already written for this upgrade sequence"
+        );
+        channel_info.latest_error_rec_seq = sequence;
+
+        let error_receipt = error_receipt::Data {
+            sequence,
+            message: ManagedBuffer::from(message),
+        };
+        let error_key = self.get_channel_upgrade_error_commitment_key(port_id, channel_id);
+        let encoded_receipt = error_receipt.to_proto();
+        let hashed_receipt = self.crypto().keccak256(encoded_receipt);
+        self.commitments(&error_key).set(hashed_receipt);
+
+        self.channel_upgrade_error_event(port_id, channel_id, &error_receipt);
+    }
+
+    /// Restores the channel to OPEN using its pre-upgrade parameters
+    /// and writes an error receipt, so the counterparty can cancel its side of the upgrade
+    fn abort_upgrade(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+        channel_info: &mut ChannelInfo<Self::Api>,
+        sequence: Sequence,
+        message: &[u8],
+    ) {
+        channel_info.channel.state = channel::State::Open;
+        channel_info.channel.upgrade_sequence = sequence;
+        self.delete_upgrade(port_id, channel_id, channel_info);
+        self.write_error_receipt(port_id, channel_id, channel_info, sequence, message);
+
+        self.update_channel_commitment(port_id, channel_id, &channel_info.channel);
+        self.channel_info(port_id, channel_id).set(&*channel_info);
+    }
+
+    /// Switches the channel to the upgraded parameters and moves it back to OPEN
+    fn open_upgrade_handshake(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+        channel_info: &mut ChannelInfo<Self::Api>,
+    ) {
+        let upgrade = channel_info.upgrade.clone();
+        let was_unordered = matches!(channel_info.channel.ordering, channel::Order::Unordered);
+        if was_unordered && matches!(upgrade.fields.ordering, channel::Order::Ordered) {
+            channel_info.next_seq_recv = channel_info.recv_start_seq.seq;
+            channel_info.next_seq_ack = upgrade.next_sequence_send;
+
+            let commitment_key = self.get_next_seq_recv_commitment_key(port_id, channel_id);
+            let encoded_val = self.encode_sequence(channel_info.next_seq_recv);
+            let hashed_val = self.crypto().keccak256(&encoded_val);
+            self.commitments(&commitment_key).set(hashed_val);
+        }
+        channel_info.ack_start_seq = upgrade.next_sequence_send;
+
+        let channel = &mut channel_info.channel;
+        channel.state = channel::State::Open;
+        channel.ordering = upgrade.fields.ordering;
+        channel.connection_hops = upgrade.fields.connection_hops.clone();
+        channel.version = upgrade.fields.version.clone();
+        self.update_channel_commitment(port_id, channel_id, channel);
+
+        let upgrade_sequence = channel.upgrade_sequence;
+        self.delete_upgrade(port_id, channel_id, channel_info);
+        self.channel_info(port_id, channel_id).set(&*channel_info);
+
+        let module = self.lookup_module_by_channel(port_id, channel_id);
+        let _: () = self
+            .ibc_module_proxy_impl_upgrade_internal(module)
+            .on_chan_upgrade_open(MsgOnChanUpgradeOpen {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+                upgrade_sequence,
+                order: upgrade.fields.ordering,
+                connection_hops: upgrade.fields.connection_hops,
+                version: upgrade.fields.version,
+            })
+            .execute_on_dest_context();
+
+        self.channel_upgrade_open_event(
+            port_id,
+            channel_id,
+            upgrade_sequence,
+            &channel_info.channel,
+        );
+    }
+
+    #[proxy]
+    fn generic_client_proxy_impl_upgrade_internal(
+        &self,
+        sc_address: ManagedAddress,
+    ) -> client_interface::generic_client_proxy::GenericClientProxy<Self::Api>;
+
+    #[proxy]
+    fn ibc_module_proxy_impl_upgrade_internal(
+        &self,
+        sc_address: ManagedAddress,
+    ) -> ibc_module_interface::ibc_module_proxy::IbcModuleProxy<Self::Api>;
+}
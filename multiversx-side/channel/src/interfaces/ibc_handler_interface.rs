@@ -0,0 +1,65 @@
+pub mod ibc_handler_proxy {
+    use common_types::{
+        channel_types::height, connection_types::connection_end, ChannelId, ConnectionId, PortId,
+        Sequence, UnixTimestamp, Version,
+    };
+
+    use crate::channel_libs::handshake_types::MsgChannelOpenInit;
+
+    multiversx_sc::imports!();
+
+    /// The endpoints an application calls on the IBC handler, to bind its ports and open and use its channels
+    ///
+    /// Middlewares expose `sendPacket` and `writeAcknowledgement` as well, so that the modules below them call them
+    /// in place of the handler.
+    #[multiversx_sc::proxy]
+    pub trait IbcHandlerProxy {
+        #[endpoint(bindPrefixedPort)]
+        fn bind_prefixed_port(&self, port_id: PortId<Self::Api>, prefix: PortId<Self::Api>);
+
+        #[endpoint(channelOpenInit)]
+        fn channel_open_init(
+            &self,
+            args: MsgChannelOpenInit<Self::Api>,
+        ) -> MultiValue2<ChannelId<Self::Api>, Version<Self::Api>>;
+
+        #[endpoint(sendPacket)]
+        fn send_packet(
+            &self,
+            src_port: PortId<Self::Api>,
+            src_channel: ChannelId<Self::Api>,
+            timeout_height: height::Data,
+            timeout_timestamp: UnixTimestamp,
+            data: ManagedBuffer,
+        ) -> Sequence;
+
+        #[endpoint(writeAcknowledgement)]
+        fn write_ack(
+            &self,
+            dest_port: PortId<Self::Api>,
+            dest_channel: ChannelId<Self::Api>,
+            seq: Sequence,
+            ack: ManagedBuffer,
+        );
+
+        #[view(getChannelStack)]
+        fn get_channel_stack(
+            &self,
+            port_id: PortId<Self::Api>,
+            channel_id: ChannelId<Self::Api>,
+        ) -> MultiValueEncoded<ManagedAddress>;
+
+        #[view(isChannelOpen)]
+        fn is_channel_open(
+            &self,
+            port_id: PortId<Self::Api>,
+            channel_id: ChannelId<Self::Api>,
+        ) -> bool;
+
+        #[view(getConnection)]
+        fn get_connection(
+            &self,
+            connection_id: ConnectionId<Self::Api>,
+        ) -> connection_end::Data<Self::Api>;
+    }
+}
@@ -1,6 +1,6 @@
 use common_types::{
     channel_types::{channel, channel_counterparty},
-    ChannelId, ConnectionHops, PortId, Version,
+    ChannelId, ConnectionHops, PortId, Sequence, Version,
 };
 
 multiversx_sc::imports!();
@@ -42,14 +42,53 @@ pub struct MsgOnChanOpenConfirm<M: ManagedTypeApi> {
 pub type MsgOnChanCloseInit<M> = MsgOnChanOpenConfirm<M>;
 pub type MsgOnChanCloseConfirm<M> = MsgOnChanOpenConfirm<M>;
 
+#[derive(TypeAbi, TopEncode, TopDecode)]
+pub struct MsgOnChanUpgradeInit<M: ManagedTypeApi> {
+    pub port_id: PortId<M>,
+    pub channel_id: ChannelId<M>,
+    pub upgrade_sequence: Sequence,
+    pub proposed_order: channel::Order,
+    pub proposed_connection_hops: ConnectionHops<M>,
+    pub proposed_version: Version<M>,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode)]
+pub struct MsgOnChanUpgradeTry<M: ManagedTypeApi> {
+    pub port_id: PortId<M>,
+    pub channel_id: ChannelId<M>,
+    pub upgrade_sequence: Sequence,
+    pub proposed_order: channel::Order,
+    pub proposed_connection_hops: ConnectionHops<M>,
+    pub counterparty_version: Version<M>,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode)]
+pub struct MsgOnChanUpgradeAck<M: ManagedTypeApi> {
+    pub port_id: PortId<M>,
+    pub channel_id: ChannelId<M>,
+    pub upgrade_sequence: Sequence,
+    pub counterparty_version: Version<M>,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode)]
+pub struct MsgOnChanUpgradeOpen<M: ManagedTypeApi> {
+    pub port_id: PortId<M>,
+    pub channel_id: ChannelId<M>,
+    pub upgrade_sequence: Sequence,
+    pub order: channel::Order,
+    pub connection_hops: ConnectionHops<M>,
+    pub version: Version<M>,
+}
+
 pub mod ibc_module_proxy {
-    use common_types::Version;
+    use common_types::{ChannelId, PortId, Sequence, Version};
 
     use crate::channel_libs::packet_types::Packet;
 
     use super::{
         MsgOnChanCloseConfirm, MsgOnChanCloseInit, MsgOnChanOpenAck, MsgOnChanOpenConfirm,
-        MsgOnChanOpenInit, MsgOnChanOpenTry,
+        MsgOnChanOpenInit, MsgOnChanOpenTry, MsgOnChanUpgradeAck, MsgOnChanUpgradeInit,
+        MsgOnChanUpgradeOpen, MsgOnChanUpgradeTry,
     };
 
     multiversx_sc::imports!();
@@ -102,6 +141,36 @@ pub mod ibc_module_proxy {
         #[endpoint(onChanCloseConfirm)]
         fn on_chan_close_confirm(&self, args: MsgOnChanCloseConfirm<Self::Api>);
 
+        /// Will verify that the upgrade parameters are valid and perform any custom upgrade INIT logic.
+        ///
+        /// The returned version is the one proposed to the counterparty. An error aborts the upgrade.
+        #[endpoint(onChanUpgradeInit)]
+        fn on_chan_upgrade_init(&self, args: MsgOnChanUpgradeInit<Self::Api>)
+            -> Version<Self::Api>;
+
+        /// Will verify the upgrade parameters proposed by the counterparty and select the final upgrade version.
+        #[endpoint(onChanUpgradeTry)]
+        fn on_chan_upgrade_try(&self, args: MsgOnChanUpgradeTry<Self::Api>) -> Version<Self::Api>;
+
+        /// Will error if the counterparty selected upgrade version is not compatible.
+        #[endpoint(onChanUpgradeAck)]
+        fn on_chan_upgrade_ack(&self, args: MsgOnChanUpgradeAck<Self::Api>);
+
+        /// Is called once the upgrade is complete, with the parameters the channel now uses.
+        #[endpoint(onChanUpgradeOpen)]
+        fn on_chan_upgrade_open(&self, args: MsgOnChanUpgradeOpen<Self::Api>);
+
+        /// Returns whether all packets in flight on an unordered channel have been flushed.
+        ///
+        /// Ordered channels are checked by core IBC directly, using the send and ack sequences.
+        #[view(canTransitionToFlushComplete)]
+        fn can_transition_to_flush_complete(
+            &self,
+            port_id: PortId<Self::Api>,
+            channel_id: ChannelId<Self::Api>,
+            upgrade_sequence: Sequence,
+        ) -> bool;
+
         /// Must return an acknowledgement that implements the Acknowledgement interface.
         ///
         /// In the case of an asynchronous acknowledgement, nil should be returned.
@@ -1,2 +1,3 @@
 pub mod client_interface;
+pub mod ibc_handler_interface;
 pub mod ibc_module_interface;
@@ -3,13 +3,20 @@
 multiversx_sc::imports!();
 
 pub mod channel_libs;
+pub mod handshake;
 pub mod interfaces;
+pub mod middleware;
 pub mod packet_handling;
 
 #[multiversx_sc::contract]
 pub trait Channel:
     channel_libs::ibc_channel_lib::IbcChannelLibModule
     + channel_libs::events::EventsModule
+    + handshake::chan_internal::ChannelInternalModule
+    + handshake::chan_open::ChannelOpenModule
+    + handshake::chan_close::ChannelCloseModule
+    + handshake::upgrade_internal::UpgradeInternalModule
+    + handshake::chan_upgrade::ChannelUpgradeModule
     + packet_handling::membership::MembershipModule
     + packet_handling::timeout::TimeoutModule
     + packet_handling::send::SendModule
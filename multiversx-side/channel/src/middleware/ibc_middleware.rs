@@ -0,0 +1,303 @@
+use common_types::{ChannelId, PortId, Version};
+
+use crate::interfaces::{
+    ibc_handler_interface::ibc_handler_proxy,
+    ibc_module_interface::{
+        ibc_module_proxy, MsgOnChanOpenInit, MsgOnChanOpenTry, MsgOnChanUpgradeInit,
+        MsgOnChanUpgradeTry,
+    },
+};
+
+use super::version;
+
+multiversx_sc::imports!();
+
+static UNSUPPORTED_VERSION_ERR_MSG: &[u8] = b"Unsupported middleware version";
+
+/// The base of the middleware contracts, which sit between the IBC handler and the module of a port
+///
+/// The handler calls the top of the stack of a channel, and every middleware forwards the callbacks to the module
+/// below it, down to the module of the port. `sendPacket` and `writeAcknowledgement` go the other way, every
+/// middleware forwarding them to the module above it, up to the handler.
+///
+/// A middleware which negotiates its own version wraps the version of the module below it, see `version`.
+#[multiversx_sc::module]
+pub trait IbcMiddlewareModule {
+    /// Returns the module below, to which the callbacks of the handler are forwarded
+    fn require_upper_module_caller(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+    ) -> ManagedAddress {
+        let (upper_module, lower_module) = self.get_stack_neighbours(port_id, channel_id);
+        require!(
+            self.blockchain().get_caller() == upper_module,
+            "Only the module above in the stack may call this endpoint"
+        );
+
+        lower_module
+    }
+
+    /// Returns the module above, to which the packets and acknowledgements of the module below are forwarded
+    fn require_lower_module_caller(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+    ) -> ManagedAddress {
+        let (upper_module, lower_module) = self.get_stack_neighbours(port_id, channel_id);
+        require!(
+            self.blockchain().get_caller() == lower_module,
+            "Only the module below in the stack may call this endpoint"
+        );
+
+        upper_module
+    }
+
+    /// The modules above and below this middleware in the stack of a channel
+    ///
+    /// The module above the first middleware is the handler, the module below the last one is the module of the port.
+    fn get_stack_neighbours(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+    ) -> (ManagedAddress, ManagedAddress) {
+        let ibc_handler = self.ibc_handler().get();
+        let stack: MultiValueEncoded<ManagedAddress> = self
+            .ibc_handler_proxy(ibc_handler.clone())
+            .get_channel_stack(port_id.clone(), channel_id.clone())
+            .execute_on_dest_context();
+
+        let sc_address = self.blockchain().get_sc_address();
+        let mut upper_module = ibc_handler;
+        let mut found = false;
+        for module in stack {
+            if found {
+                return (upper_module, module);
+            }
+
+            if module == sc_address {
+                found = true;
+            } else {
+                upper_module = module;
+            }
+        }
+
+        sc_panic!("Middleware not in the stack of the channel");
+    }
+
+    /// Negotiates the version of the middleware on a new channel, passing the rest of the version to the module below
+    ///
+    /// An empty version proposes `middleware_version`, along with the default version of the module below. Versions
+    /// the middleware didn't wrap go to the module below unchanged.
+    ///
+    /// Returns the version and whether the middleware is enabled on the channel.
+    fn wrapped_on_chan_open_init(
+        &self,
+        lower_module: ManagedAddress,
+        args: MsgOnChanOpenInit<Self::Api>,
+        version_key: &[u8],
+        middleware_version: &[u8],
+    ) -> (Version<Self::Api>, bool) {
+        let app_version = if args.version.is_empty() {
+            Version::new()
+        } else {
+            match version::unwrap(version_key, &args.version) {
+                Some((proposed_version, app_version)) => {
+                    require!(
+                        &proposed_version == middleware_version,
+                        UNSUPPORTED_VERSION_ERR_MSG
+                    );
+                    app_version
+                }
+                None => {
+                    let version = self
+                        .ibc_module_proxy(lower_module)
+                        .on_chan_open_init(args)
+                        .execute_on_dest_context();
+                    return (version, false);
+                }
+            }
+        };
+
+        let app_version: Version<Self::Api> = self
+            .ibc_module_proxy(lower_module)
+            .on_chan_open_init(MsgOnChanOpenInit {
+                version: app_version,
+                ..args
+            })
+            .execute_on_dest_context();
+        let version = version::wrap(
+            version_key,
+            &ManagedBuffer::from(middleware_version),
+            &app_version,
+        );
+
+        (version, true)
+    }
+
+    /// The middleware is enabled on the channel if the counterparty proposed it
+    fn wrapped_on_chan_open_try(
+        &self,
+        lower_module: ManagedAddress,
+        args: MsgOnChanOpenTry<Self::Api>,
+        version_key: &[u8],
+        middleware_version: &[u8],
+    ) -> (Version<Self::Api>, bool) {
+        let app_version = match version::unwrap(version_key, &args.counterparty_version) {
+            Some((counterparty_version, app_version)) => {
+                require!(
+                    &counterparty_version == middleware_version,
+                    UNSUPPORTED_VERSION_ERR_MSG
+                );
+                app_version
+            }
+            None => {
+                let version = self
+                    .ibc_module_proxy(lower_module)
+                    .on_chan_open_try(args)
+                    .execute_on_dest_context();
+                return (version, false);
+            }
+        };
+
+        let app_version: Version<Self::Api> = self
+            .ibc_module_proxy(lower_module)
+            .on_chan_open_try(MsgOnChanOpenTry {
+                counterparty_version: app_version,
+                ..args
+            })
+            .execute_on_dest_context();
+        let version = version::wrap(
+            version_key,
+            &ManagedBuffer::from(middleware_version),
+            &app_version,
+        );
+
+        (version, true)
+    }
+
+    /// Returns the counterparty version for the module below, which must be wrapped if the middleware is enabled
+    fn unwrap_counterparty_version(
+        &self,
+        counterparty_version: Version<Self::Api>,
+        enabled: bool,
+        version_key: &[u8],
+        middleware_version: &[u8],
+    ) -> Version<Self::Api> {
+        if !enabled {
+            return counterparty_version;
+        }
+
+        match version::unwrap(version_key, &counterparty_version) {
+            Some((counterparty_version, app_version)) => {
+                require!(
+                    &counterparty_version == middleware_version,
+                    UNSUPPORTED_VERSION_ERR_MSG
+                );
+                app_version
+            }
+            None => sc_panic!("Invalid counterparty middleware version"),
+        }
+    }
+
+    /// Upgrades may add or remove the middleware, so versions it didn't wrap go to the module below unchanged
+    fn wrapped_on_chan_upgrade_init(
+        &self,
+        lower_module: ManagedAddress,
+        args: MsgOnChanUpgradeInit<Self::Api>,
+        version_key: &[u8],
+        middleware_version: &[u8],
+    ) -> Version<Self::Api> {
+        let (proposed_version, app_version) =
+            match version::unwrap(version_key, &args.proposed_version) {
+                Some(versions) => versions,
+                None => {
+                    return self
+                        .ibc_module_proxy(lower_module)
+                        .on_chan_upgrade_init(args)
+                        .execute_on_dest_context()
+                }
+            };
+        require!(
+            &proposed_version == middleware_version,
+            UNSUPPORTED_VERSION_ERR_MSG
+        );
+
+        let app_version: Version<Self::Api> = self
+            .ibc_module_proxy(lower_module)
+            .on_chan_upgrade_init(MsgOnChanUpgradeInit {
+                proposed_version: app_version,
+                ..args
+            })
+            .execute_on_dest_context();
+
+        version::wrap(version_key, &proposed_version, &app_version)
+    }
+
+    fn wrapped_on_chan_upgrade_try(
+        &self,
+        lower_module: ManagedAddress,
+        args: MsgOnChanUpgradeTry<Self::Api>,
+        version_key: &[u8],
+        middleware_version: &[u8],
+    ) -> Version<Self::Api> {
+        let (counterparty_version, app_version) =
+            match version::unwrap(version_key, &args.counterparty_version) {
+                Some(versions) => versions,
+                None => {
+                    return self
+                        .ibc_module_proxy(lower_module)
+                        .on_chan_upgrade_try(args)
+                        .execute_on_dest_context()
+                }
+            };
+        require!(
+            &counterparty_version == middleware_version,
+            UNSUPPORTED_VERSION_ERR_MSG
+        );
+
+        let app_version: Version<Self::Api> = self
+            .ibc_module_proxy(lower_module)
+            .on_chan_upgrade_try(MsgOnChanUpgradeTry {
+                counterparty_version: app_version,
+                ..args
+            })
+            .execute_on_dest_context();
+
+        version::wrap(version_key, &counterparty_version, &app_version)
+    }
+
+    /// Returns the version for the module below, and whether the middleware is enabled by the version
+    fn unwrap_upgrade_version(
+        &self,
+        version: Version<Self::Api>,
+        version_key: &[u8],
+        middleware_version: &[u8],
+    ) -> (Version<Self::Api>, bool) {
+        match version::unwrap(version_key, &version) {
+            Some((version, app_version)) => {
+                require!(&version == middleware_version, UNSUPPORTED_VERSION_ERR_MSG);
+                (app_version, true)
+            }
+            None => (version, false),
+        }
+    }
+
+    /// Calls the module below
+    #[proxy]
+    fn ibc_module_proxy(
+        &self,
+        sc_address: ManagedAddress,
+    ) -> ibc_module_proxy::IbcModuleProxy<Self::Api>;
+
+    /// Calls the module above, which is either the handler or a middleware exposing the same endpoints
+    #[proxy]
+    fn ibc_handler_proxy(
+        &self,
+        sc_address: ManagedAddress,
+    ) -> ibc_handler_proxy::IbcHandlerProxy<Self::Api>;
+
+    #[view(getIbcHandler)]
+    #[storage_mapper("ibcHandler")]
+    fn ibc_handler(&self) -> SingleValueMapper<ManagedAddress>;
+}
@@ -0,0 +1,2 @@
+pub mod ibc_middleware;
+pub mod version;
@@ -0,0 +1,46 @@
+//! The channel versions of middlewares, which wrap the version of the module below them
+//!
+//! e.g. `{"fee_version":"ics29-1","app_version":"ics20-1"}`, as ibc-go middlewares encode them.
+
+use multiversx_sc::{api::ManagedTypeApi, types::ManagedBuffer};
+
+use common_types::{
+    json::{JsonObject, JsonObjectBuilder},
+    Version,
+};
+
+pub static APP_VERSION_KEY: &[u8] = b"app_version";
+
+/// `{"<version_key>":"<middleware_version>","app_version":"<app_version>"}`
+pub fn wrap<M: ManagedTypeApi>(
+    version_key: &[u8],
+    middleware_version: &ManagedBuffer<M>,
+    app_version: &Version<M>,
+) -> Version<M> {
+    JsonObjectBuilder::new()
+        .string(version_key, middleware_version)
+        .string(APP_VERSION_KEY, app_version)
+        .build()
+}
+
+/// Splits a wrapped version into the version of the middleware and the version of the module below it
+///
+/// Returns `None` if the middleware didn't wrap the version, in which case it belongs to a module lower in the stack.
+/// A missing app version is empty, while unknown fields make the version something else.
+pub fn unwrap<M: ManagedTypeApi>(
+    version_key: &[u8],
+    version: &Version<M>,
+) -> Option<(ManagedBuffer<M>, Version<M>)> {
+    let object = JsonObject::parse(version)?;
+    let middleware_version = object.get_string(version_key)?;
+    let (app_version, fields_len) = if object.contains(APP_VERSION_KEY) {
+        (object.get_string(APP_VERSION_KEY)?, 2)
+    } else {
+        (ManagedBuffer::new(), 1)
+    };
+    if object.len() != fields_len {
+        return None;
+    }
+
+    Some((middleware_version, app_version))
+}
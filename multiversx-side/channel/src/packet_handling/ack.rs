@@ -3,7 +3,7 @@ use common_modules::utils::UNEXPECTED_CHANNEL_STATE_ERR_MSG;
 use common_types::{
     channel_types::{channel, height},
     connection_types::connection_end,
-    ChannelId, Hash, Path, PortId, Sequence,
+    ChannelId, Hash, Path, PortId, Proof, Sequence,
 };
 use host::storage::ChannelInfo;
 
@@ -20,14 +20,16 @@ multiversx_sc::imports!();
 pub struct VerifyPacketAckArgs<'a, M: ManagedTypeApi> {
     pub connection_info: &'a connection_end::Data<M>,
     pub height: height::Data,
-    pub proof: Hash<M>,
+    pub proof: Proof<M>,
     pub path: Path<M>,
     pub ack_comm: Hash<M>,
 }
 
 #[multiversx_sc::module]
 pub trait AckModule:
-    host::module_manager::ModuleManagerModule
+    crate::handshake::upgrade_internal::UpgradeInternalModule
+    + crate::handshake::chan_internal::ChannelInternalModule
+    + host::module_manager::ModuleManagerModule
     + host::storage::StorageModule
     + common_modules::utils::UtilsModule
     + host::commitment::CommitmentModule
@@ -35,7 +37,11 @@ pub trait AckModule:
     + crate::channel_libs::events::EventsModule
     + super::encoding::EncodingModule
 {
-    /// Writes the packet execution acknowledgement to the state, which will be verified by the counterparty chain using AcknowledgePacket
+    /// Writes the asynchronous acknowledgement of a received packet to the state, which will be verified by the
+    /// counterparty chain using AcknowledgePacket
+    ///
+    /// Only the holder of the channel capability, i.e. the top of the module stack of the channel, may write it, and
+    /// only for a packet whose `onRecvPacket` returned an empty acknowledgement.
     #[endpoint(writeAcknowledgement)]
     fn write_ack_endpoint(
         &self,
@@ -51,10 +57,35 @@ pub trait AckModule:
         let channel = &channel_info.channel;
         self.require_state_open(channel.state);
         require!(!ack.is_empty(), "Empty ack");
+        require!(
+            self.pending_acks(&dest_port, &dest_channel)
+                .swap_remove(&seq),
+            "Ack not pending"
+        );
 
         self.write_ack(&dest_port, &dest_channel, seq, &ack);
     }
 
+    /// The sequences of the packets received on a channel which wait for their asynchronous acknowledgement
+    #[view(getPendingAcks)]
+    fn get_pending_acks(
+        &self,
+        port_id: PortId<Self::Api>,
+        channel_id: ChannelId<Self::Api>,
+    ) -> MultiValueEncoded<Sequence> {
+        self.pending_acks(&port_id, &channel_id).iter().collect()
+    }
+
+    #[view(isAckPending)]
+    fn is_ack_pending(
+        &self,
+        port_id: PortId<Self::Api>,
+        channel_id: ChannelId<Self::Api>,
+        seq: Sequence,
+    ) -> bool {
+        self.pending_acks(&port_id, &channel_id).contains(&seq)
+    }
+
     /// Is called by a module to process the acknowledgement of a packet previously sent by the calling module on a channel to a counterparty
     /// module on the counterparty chain.
     ///
@@ -81,11 +112,16 @@ pub trait AckModule:
                 &args.packet.dest_channel,
                 args.packet.seq,
             ),
-            ack_comm: self.crypto().sha256(&args.ack),
+            ack_comm: self.encode_ack(&args.ack),
         });
         self.check_packet_seq_by_order(&mut channel_info, &args.packet);
 
         comm_mapper.clear();
+        self.try_flush_after_packet_settled(
+            &args.packet.src_port,
+            &args.packet.src_channel,
+            &mut channel_info,
+        );
 
         let caller = self.blockchain().get_caller();
         let module = self.lookup_module_by_channel(&args.packet.src_port, &args.packet.src_channel);
@@ -109,7 +145,7 @@ pub trait AckModule:
         let comm_mapper = self.commitments(&ack_comm_key);
         require!(comm_mapper.is_empty(), "Ack already written");
 
-        let first_hash = self.crypto().sha256(ack);
+        let first_hash = self.encode_ack(ack);
         let second_hash = self.crypto().keccak256(first_hash.as_managed_buffer());
         comm_mapper.set(second_hash);
 
@@ -123,7 +159,7 @@ pub trait AckModule:
             height: args.height,
             delay_time_period: args.connection_info.delay_period,
             delay_block_period: self.calculate_block_delay(args.connection_info.delay_period),
-            proof: args.proof,
+            proof: args.proof.clone(),
             prefix: args.connection_info.counterparty.prefix.key_prefix.clone(),
             path: args.path,
             value: args.ack_comm.as_managed_buffer().clone(),
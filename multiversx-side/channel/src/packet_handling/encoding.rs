@@ -1,9 +1,15 @@
-use common_types::{channel_types::height, Hash, UnixTimestamp};
+use common_types::{channel_types::height, Hash, Sequence, UnixTimestamp};
 
 multiversx_sc::imports!();
 
+/// Value stored under the receipt path of a received packet, as done by ibc-go
+pub static SUCCESSFUL_RECEIPT: &[u8] = &[1];
+
+/// Values are encoded exactly as ICS-04 specifies and ibc-go stores them, so proofs verify across implementations
 #[multiversx_sc::module]
 pub trait EncodingModule: common_modules::utils::UtilsModule {
+    /// sha256(timeout_timestamp || timeout_revision_number || timeout_revision_height || sha256(data)),
+    /// with the numbers as 8 bytes big endian
     fn encode_and_hash(
         &self,
         timeout_height: height::Data,
@@ -13,15 +19,10 @@ pub trait EncodingModule: common_modules::utils::UtilsModule {
         let hashed_data = self.crypto().sha256(data);
 
         let mut encoded_buffer = ManagedBuffer::new();
-        let encoded_timestamp = self.encode_to_buffer(&timeout_timestamp);
-        let encoded_rev_number = self.encode_to_buffer(&timeout_height.revision_number);
-        let encoded_rev_height = self.encode_to_buffer(&timeout_height.revision_height);
-        let encoded_hashed_data = self.encode_to_buffer(&hashed_data);
-
-        encoded_buffer = encoded_buffer.concat(encoded_timestamp);
-        encoded_buffer = encoded_buffer.concat(encoded_rev_number);
-        encoded_buffer = encoded_buffer.concat(encoded_rev_height);
-        encoded_buffer = encoded_buffer.concat(encoded_hashed_data);
+        encoded_buffer.append_bytes(&timeout_timestamp.to_be_bytes());
+        encoded_buffer.append_bytes(&timeout_height.revision_number.to_be_bytes());
+        encoded_buffer.append_bytes(&timeout_height.revision_height.to_be_bytes());
+        encoded_buffer.append(hashed_data.as_managed_buffer());
 
         self.crypto().sha256(&encoded_buffer)
     }
@@ -36,4 +37,18 @@ pub trait EncodingModule: common_modules::utils::UtilsModule {
 
         self.crypto().keccak256(encoded_data.as_managed_buffer())
     }
+
+    /// sha256(ack)
+    fn encode_ack(&self, ack: &ManagedBuffer) -> Hash<Self::Api> {
+        self.crypto().sha256(ack)
+    }
+
+    /// 8 bytes big endian, as stored under the next sequence paths
+    fn encode_sequence(&self, seq: Sequence) -> ManagedBuffer {
+        ManagedBuffer::new_from_bytes(&seq.to_be_bytes())
+    }
+
+    fn encode_successful_receipt(&self) -> ManagedBuffer {
+        ManagedBuffer::new_from_bytes(SUCCESSFUL_RECEIPT)
+    }
 }
@@ -20,6 +20,7 @@ pub trait MembershipModule:
     + host::storage::StorageModule
     + host::commitment::CommitmentModule
     + common_modules::utils::UtilsModule
+    + super::encoding::EncodingModule
 {
     fn check_channel_membership(
         &self,
@@ -51,7 +52,7 @@ pub trait MembershipModule:
             "Packet may already be received"
         );
 
-        let encoded_value = self.encode_to_buffer(&timeout_args.get_next_seq_recv());
+        let encoded_value = self.encode_sequence(timeout_args.get_next_seq_recv());
         let membership_args = VerifyMembershipArgs {
             client_id: connection_info.client_id.clone(),
             height: timeout_args.get_proof_height(),
@@ -67,9 +68,6 @@ pub trait MembershipModule:
             .verify_membership(membership_args)
             .execute_on_dest_context();
         require!(membership_result, "Failed to verify next seq receive");
-
-        self.channel_info(&packet.src_port, &packet.src_channel)
-            .update(|channel_info| channel_info.channel.state = channel::State::Closed);
     }
 
     fn check_channel_unordered_membership(
@@ -124,7 +122,7 @@ pub trait MembershipModule:
             upgrade_sequence: args.counterparty_upgrade_seq,
         };
 
-        let encoded_value = self.encode_to_buffer(&expected_channel);
+        let encoded_value = expected_channel.to_proto();
         let membership_args = VerifyMembershipArgs {
             client_id: connection_info.client_id.clone(),
             height: args.proof_height,
@@ -132,10 +130,7 @@ pub trait MembershipModule:
             delay_block_period: self.calculate_block_delay(connection_info.delay_period),
             proof: args.proof_close.clone(),
             prefix: connection_info.counterparty.prefix.key_prefix.clone(),
-            path: self.get_next_seq_recv_commitment_path(
-                &args.packet.dest_port,
-                &args.packet.dest_channel,
-            ),
+            path: self.get_channel_path(&args.packet.dest_port, &args.packet.dest_channel),
             value: encoded_value,
         };
         let membership_result: bool = self
@@ -3,12 +3,12 @@ use common_modules::utils::UNEXPECTED_CHANNEL_STATE_ERR_MSG;
 use common_types::{
     channel_types::{channel, height},
     connection_types::connection_end,
-    Hash, Path, Sequence,
+    Hash, Path, Proof, Sequence,
 };
 use host::storage::ChannelInfo;
 
 use crate::{
-    channel_libs::packet_types::{MsgPacketRecv, Packet, PacketReceipt},
+    channel_libs::packet_types::{MsgPacketRecv, Packet},
     interfaces::{client_interface, ibc_module_interface},
     packet_handling::errors::{PACKET_ALREADY_PROCESSED_ERR_MSG, UNEXPECTED_PACKET_SOURCE_ERR_MSG},
 };
@@ -18,14 +18,16 @@ multiversx_sc::imports!();
 pub struct VerifyPacketCommitmentReceiveArgs<'a, M: ManagedTypeApi> {
     pub connection_info: &'a connection_end::Data<M>,
     pub height: height::Data,
-    pub proof: Hash<M>,
+    pub proof: Proof<M>,
     pub path: Path<M>,
     pub commitment: Hash<M>,
 }
 
 #[multiversx_sc::module]
 pub trait ReceiveModule:
-    host::module_manager::ModuleManagerModule
+    crate::handshake::upgrade_internal::UpgradeInternalModule
+    + crate::handshake::chan_internal::ChannelInternalModule
+    + host::module_manager::ModuleManagerModule
     + host::storage::StorageModule
     + common_modules::utils::UtilsModule
     + host::commitment::CommitmentModule
@@ -77,7 +79,16 @@ pub trait ReceiveModule:
             .on_recv_packet(args.packet.clone(), caller)
             .execute_on_dest_context();
 
-        if !ack.is_empty() {
+        // an empty acknowledgement is written later by the module, with `writeAcknowledgement`
+        if ack.is_empty() {
+            self.pending_acks(&args.packet.dest_port, &args.packet.dest_channel)
+                .insert(args.packet.seq);
+            self.pending_ack_event(
+                &args.packet.dest_port,
+                &args.packet.dest_channel,
+                args.packet.seq,
+            );
+        } else {
             self.write_ack(
                 &args.packet.dest_port,
                 &args.packet.dest_channel,
@@ -139,7 +150,7 @@ pub trait ReceiveModule:
             height: args.height,
             delay_time_period: args.connection_info.delay_period,
             delay_block_period: self.calculate_block_delay(args.connection_info.delay_period),
-            proof: args.proof,
+            proof: args.proof.clone(),
             prefix: args.connection_info.counterparty.prefix.key_prefix.clone(),
             path: args.path,
             value: args.commitment.as_managed_buffer().clone(),
@@ -180,7 +191,7 @@ pub trait ReceiveModule:
 
         let commitment_key =
             self.get_next_seq_recv_commitment_key(&packet.dest_port, &packet.dest_channel);
-        let encoded_val = self.encode_to_buffer(&channel_info.next_seq_recv);
+        let encoded_val = self.encode_sequence(channel_info.next_seq_recv);
         let hashed_val = self.crypto().keccak256(&encoded_val);
         self.commitments(&commitment_key).set(hashed_val);
 
@@ -209,7 +220,7 @@ pub trait ReceiveModule:
             "Channel packet receipt already exists"
         );
 
-        let encoded_success = self.encode_to_buffer(&PacketReceipt::Successful);
+        let encoded_success = self.encode_successful_receipt();
         let successful_hash = self.crypto().keccak256(&encoded_success);
         comm_mapper.set(successful_hash);
     }
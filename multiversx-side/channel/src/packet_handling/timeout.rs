@@ -1,3 +1,4 @@
+use common_modules::utils::UNEXPECTED_CHANNEL_STATE_ERR_MSG;
 use common_types::{
     channel_types::{channel, height},
     ChannelId, ClientId, Hash, PortId, Sequence, UnixTimestamp,
@@ -5,24 +6,33 @@ use common_types::{
 
 use crate::{
     channel_libs::packet_types::{MsgTimeoutOnClose, MsgTimeoutPacket, Packet},
-    interfaces::{client_interface, ibc_module_interface},
+    interfaces::{
+        client_interface,
+        ibc_module_interface::{self, MsgOnChanCloseConfirm},
+    },
     packet_handling::errors::{PACKET_COMM_MISMATCH_ERR_MSG, UNEXPECTED_PACKET_DEST_ERR_MSG},
 };
 
 multiversx_sc::imports!();
 
+static CHANNEL_CLOSED_ON_TIMEOUT_ERR_MSG: &[u8] = b"Channel closed on packet timeout";
+
 #[multiversx_sc::module]
 pub trait TimeoutModule:
-    host::module_manager::ModuleManagerModule
+    crate::handshake::upgrade_internal::UpgradeInternalModule
+    + crate::handshake::chan_internal::ChannelInternalModule
+    + host::module_manager::ModuleManagerModule
     + host::storage::StorageModule
     + host::commitment::CommitmentModule
+    + host::host_views::HostViewsModule
     + common_modules::utils::UtilsModule
     + super::membership::MembershipModule
+    + super::encoding::EncodingModule
     + crate::channel_libs::events::EventsModule
 {
     #[endpoint(timeoutPacket)]
     fn timeout_packet(&self, args: MsgTimeoutPacket<Self::Api>) {
-        let channel_info =
+        let mut channel_info =
             self.try_get_channel_info(&args.packet.src_port, &args.packet.src_channel);
         let channel = &channel_info.channel;
         self.check_expected_args(&args.packet, channel);
@@ -57,12 +67,21 @@ pub trait TimeoutModule:
 
         commitment_mapper.clear();
 
-        self.timeout_packet_final(args.packet);
+        let ordering = channel.ordering;
+        self.try_flush_after_packet_settled(
+            &args.packet.src_port,
+            &args.packet.src_channel,
+            &mut channel_info,
+        );
+        self.timeout_packet_final(&args.packet);
+        if matches!(ordering, channel::Order::Ordered) {
+            self.close_channel_on_timeout(&args.packet.src_port, &args.packet.src_channel);
+        }
     }
 
     #[endpoint(timeoutOnClose)]
     fn timeout_on_close(&self, args: MsgTimeoutOnClose<Self::Api>) {
-        let channel_info =
+        let mut channel_info =
             self.try_get_channel_info(&args.packet.src_port, &args.packet.src_channel);
         let channel = &channel_info.channel;
         self.check_expected_args(&args.packet, channel);
@@ -95,11 +114,29 @@ pub trait TimeoutModule:
             &args,
         );
 
-        self.timeout_packet_final(args.packet);
+        commitment_mapper.clear();
+
+        let ordering = channel.ordering;
+        self.try_flush_after_packet_settled(
+            &args.packet.src_port,
+            &args.packet.src_channel,
+            &mut channel_info,
+        );
+        self.timeout_packet_final(&args.packet);
+        if matches!(ordering, channel::Order::Ordered) {
+            self.close_channel_on_timeout(&args.packet.src_port, &args.packet.src_channel);
+        }
     }
 
+    /// Packets may also time out while the channel is flushing them for an upgrade
     fn check_expected_args(&self, packet: &Packet<Self::Api>, channel: &channel::Data<Self::Api>) {
-        self.require_state_open(channel.state);
+        require!(
+            matches!(
+                channel.state,
+                channel::State::Open | channel::State::Flushing
+            ),
+            UNEXPECTED_CHANNEL_STATE_ERR_MSG
+        );
         require!(
             packet.dest_port == channel.counterparty.port_id,
             UNEXPECTED_PACKET_DEST_ERR_MSG
@@ -145,25 +182,14 @@ pub trait TimeoutModule:
     }
 
     fn get_packet_commitment(&self, packet: &Packet<Self::Api>) -> Hash<Self::Api> {
-        let hashed_data = self.crypto().sha256(&packet.data);
-
-        let encoded_timeout_timestamp = self.encode_to_buffer(&packet.timeout_timestamp);
-        let encoded_revision_number = self.encode_to_buffer(&packet.timeout_height.revision_number);
-        let encoded_revision_height = self.encode_to_buffer(&packet.timeout_height.revision_height);
-        let encoded_hashed_data = self.encode_to_buffer(&hashed_data);
-
-        let mut encoded_buffer = ManagedBuffer::new();
-        encoded_buffer = encoded_buffer.concat(encoded_timeout_timestamp);
-        encoded_buffer = encoded_buffer.concat(encoded_revision_number);
-        encoded_buffer = encoded_buffer.concat(encoded_revision_height);
-        encoded_buffer = encoded_buffer.concat(encoded_hashed_data);
-
-        let first_hashing = self.crypto().sha256(&encoded_buffer);
-
-        self.crypto().keccak256(first_hashing.as_managed_buffer())
+        self.encode_and_hash_twice(
+            packet.timeout_height,
+            packet.timeout_timestamp,
+            &packet.data,
+        )
     }
 
-    fn timeout_packet_final(&self, packet: Packet<Self::Api>) {
+    fn timeout_packet_final(&self, packet: &Packet<Self::Api>) {
         let caller = self.blockchain().get_caller();
         let ibc_module = self.lookup_module_by_channel(&packet.src_port, &packet.src_channel);
         let _: () = self
@@ -171,7 +197,54 @@ pub trait TimeoutModule:
             .on_timeout_packet(packet.clone(), caller)
             .execute_on_dest_context();
 
-        self.timeout_packet_event(&packet);
+        self.timeout_packet_event(packet);
+    }
+
+    /// A timed out packet closes an ORDERED channel, as the counterparty can no longer receive the packets after it.
+    ///
+    /// An upgrade in progress is cancelled with an error receipt, and the module is notified through `onChanCloseConfirm`.
+    fn close_channel_on_timeout(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+    ) {
+        let mut channel_info = self.channel_info(port_id, channel_id).get();
+        if self.is_upgrade_in_progress(&channel_info) {
+            let upgrade_sequence = channel_info.channel.upgrade_sequence;
+            self.delete_upgrade(port_id, channel_id, &mut channel_info);
+            self.write_error_receipt(
+                port_id,
+                channel_id,
+                &mut channel_info,
+                upgrade_sequence,
+                CHANNEL_CLOSED_ON_TIMEOUT_ERR_MSG,
+            );
+        }
+
+        let channel = &mut channel_info.channel;
+        channel.state = channel::State::Closed;
+        self.update_channel_commitment(port_id, channel_id, channel);
+
+        let counterparty = channel.counterparty.clone();
+        let connection_id = channel.connection_hops.get(0).clone_value();
+        self.channel_info(port_id, channel_id).set(&channel_info);
+
+        let module = self.lookup_module_by_channel(port_id, channel_id);
+        let _: () = self
+            .ibc_module_proxy_impl_timeout(module)
+            .on_chan_close_confirm(MsgOnChanCloseConfirm {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+            })
+            .execute_on_dest_context();
+
+        self.channel_closed_event(
+            port_id,
+            channel_id,
+            &counterparty.port_id,
+            &counterparty.channel_id,
+            &connection_id,
+        );
     }
 
     #[proxy]
@@ -0,0 +1,177 @@
+use std::{cell::RefCell, rc::Rc};
+
+use ::channel::{
+    channel_libs::handshake_types::{MsgChannelCloseConfirm, MsgChannelCloseInit},
+    handshake::chan_close::ChannelCloseModule,
+};
+use channel_setup::*;
+use common_types::channel_types::channel;
+use host::storage::StorageModule;
+use mock_app::{MockApp, MOCK_APP_VERSION};
+use multiversx_sc::types::ManagedBuffer;
+use multiversx_sc_scenario::{
+    imports::BlockchainStateWrapper, managed_buffer, rust_biguint, DebugApi,
+};
+
+pub mod channel_setup;
+
+fn get_channel_setup() -> ChannelSetup<
+    impl 'static + Copy + Fn() -> ::channel::ContractObj<DebugApi>,
+    impl 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    impl 'static + Copy + Fn() -> mock_app::ContractObj<DebugApi>,
+> {
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_biguint!(0));
+    let setup = ChannelSetup::new(
+        Rc::new(RefCell::new(b_mock)),
+        &owner,
+        ::channel::contract_obj,
+        mock::contract_obj,
+        mock_app::contract_obj,
+    );
+    setup.create_open_channel(channel::Order::Unordered);
+
+    setup
+}
+
+#[test]
+fn channel_close_init_ok_test() {
+    let setup = get_channel_setup();
+    let mock_app_address = setup.mock_app_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &mock_app_address,
+            &setup.channel_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.channel_close_init(MsgChannelCloseInit {
+                    port_id: managed_buffer!(PORT_ID),
+                    channel_id: managed_buffer!(CHANNEL_ID),
+                });
+
+                let channel_info = sc
+                    .channel_info(&managed_buffer!(PORT_ID), &managed_buffer!(CHANNEL_ID))
+                    .get();
+                assert!(matches!(channel_info.channel.state, channel::State::Closed));
+            },
+        )
+        .assert_ok();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.mock_app_wrapper, |sc| {
+            let callbacks = sc.callbacks(&managed_buffer!(PORT_ID), &managed_buffer!(CHANNEL_ID));
+            assert_eq!(callbacks.len(), 1);
+            assert_eq!(callbacks.get(1), managed_buffer!(b"onChanCloseInit"));
+        })
+        .assert_ok();
+
+    // closing twice is not allowed
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &mock_app_address,
+            &setup.channel_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.channel_close_init(MsgChannelCloseInit {
+                    port_id: managed_buffer!(PORT_ID),
+                    channel_id: managed_buffer!(CHANNEL_ID),
+                });
+            },
+        )
+        .assert_user_error("Channel already closed");
+}
+
+#[test]
+fn channel_close_init_not_capability_owner_test() {
+    let setup = get_channel_setup();
+    let relayer = setup.relayer.clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(&relayer, &setup.channel_wrapper, &rust_biguint!(0), |sc| {
+            sc.channel_close_init(MsgChannelCloseInit {
+                port_id: managed_buffer!(PORT_ID),
+                channel_id: managed_buffer!(CHANNEL_ID),
+            });
+        })
+        .assert_user_error("Not allowed to use this port");
+}
+
+#[test]
+fn channel_close_confirm_ok_test() {
+    let setup = get_channel_setup();
+    DebugApi::dummy();
+    let proof = setup.mock_proof(
+        &channel_path(COUNTERPARTY_PORT_ID, COUNTERPARTY_CHANNEL_ID),
+        &encode_channel(&counterparty_channel(
+            channel::State::Closed,
+            channel::Order::Unordered,
+            CHANNEL_ID,
+            MOCK_APP_VERSION,
+        )),
+    );
+
+    let relayer = setup.relayer.clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(&relayer, &setup.channel_wrapper, &rust_biguint!(0), |sc| {
+            sc.channel_close_confirm(MsgChannelCloseConfirm {
+                port_id: managed_buffer!(PORT_ID),
+                channel_id: managed_buffer!(CHANNEL_ID),
+                proof_init: ManagedBuffer::new_from_bytes(&proof),
+                proof_height: PROOF_HEIGHT,
+            });
+
+            let channel_info = sc
+                .channel_info(&managed_buffer!(PORT_ID), &managed_buffer!(CHANNEL_ID))
+                .get();
+            assert!(matches!(channel_info.channel.state, channel::State::Closed));
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.mock_app_wrapper, |sc| {
+            let callbacks = sc.callbacks(&managed_buffer!(PORT_ID), &managed_buffer!(CHANNEL_ID));
+            assert_eq!(callbacks.len(), 1);
+            assert_eq!(callbacks.get(1), managed_buffer!(b"onChanCloseConfirm"));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn channel_close_confirm_counterparty_still_open_test() {
+    let setup = get_channel_setup();
+    DebugApi::dummy();
+    let proof = setup.mock_proof(
+        &channel_path(COUNTERPARTY_PORT_ID, COUNTERPARTY_CHANNEL_ID),
+        &encode_channel(&counterparty_channel(
+            channel::State::Open,
+            channel::Order::Unordered,
+            CHANNEL_ID,
+            MOCK_APP_VERSION,
+        )),
+    );
+
+    let relayer = setup.relayer.clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(&relayer, &setup.channel_wrapper, &rust_biguint!(0), |sc| {
+            sc.channel_close_confirm(MsgChannelCloseConfirm {
+                port_id: managed_buffer!(PORT_ID),
+                channel_id: managed_buffer!(CHANNEL_ID),
+                proof_init: ManagedBuffer::new_from_bytes(&proof),
+                proof_height: PROOF_HEIGHT,
+            });
+        })
+        .assert_user_error("Failed to verify channel state");
+}
@@ -0,0 +1,354 @@
+use std::{cell::RefCell, rc::Rc};
+
+use ::channel::{
+    channel_libs::handshake_types::{
+        MsgChannelOpenAck, MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry,
+    },
+    handshake::chan_open::ChannelOpenModule,
+};
+use channel_setup::*;
+use common_types::channel_types::channel;
+use host::{
+    commitment::CommitmentModule,
+    module_manager::ModuleManagerModule,
+    storage::{ChannelInfo, StorageModule},
+};
+use mock_app::{MockApp, MOCK_APP_VERSION};
+use multiversx_sc::{
+    codec::multi_types::MultiValue2, contract_base::ContractBase, types::ManagedBuffer,
+};
+use multiversx_sc_scenario::{
+    imports::BlockchainStateWrapper, managed_address, managed_buffer, rust_biguint, DebugApi,
+};
+
+pub mod channel_setup;
+
+fn get_channel_setup() -> ChannelSetup<
+    impl 'static + Copy + Fn() -> ::channel::ContractObj<DebugApi>,
+    impl 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    impl 'static + Copy + Fn() -> mock_app::ContractObj<DebugApi>,
+> {
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_biguint!(0));
+    ChannelSetup::new(
+        Rc::new(RefCell::new(b_mock)),
+        &owner,
+        ::channel::contract_obj,
+        mock::contract_obj,
+        mock_app::contract_obj,
+    )
+}
+
+#[test]
+fn channel_open_init_ok_test() {
+    let setup = get_channel_setup();
+    let relayer = setup.relayer.clone();
+    let mock_app_address = setup.mock_app_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(&relayer, &setup.channel_wrapper, &rust_biguint!(0), |sc| {
+            let result: MultiValue2<ManagedBuffer<DebugApi>, ManagedBuffer<DebugApi>> = sc
+                .channel_open_init(MsgChannelOpenInit {
+                    port_id: managed_buffer!(PORT_ID),
+                    channel: new_channel(channel::State::Init, channel::Order::Unordered, b"", b""),
+                });
+            let (channel_id, version) = result.into_tuple();
+            assert_eq!(channel_id, managed_buffer!(b"channel-0"));
+            assert_eq!(version, managed_buffer!(MOCK_APP_VERSION));
+
+            let channel_info = sc
+                .channel_info(&managed_buffer!(PORT_ID), &channel_id)
+                .get();
+            assert!(matches!(channel_info.channel.state, channel::State::Init));
+            assert_eq!(
+                channel_info.channel.version,
+                managed_buffer!(MOCK_APP_VERSION)
+            );
+            assert_eq!(channel_info.next_seq_send, 1);
+            assert_eq!(channel_info.next_seq_recv, 1);
+            assert_eq!(channel_info.next_seq_ack, 1);
+
+            assert_eq!(
+                sc.lookup_module_by_channel(&managed_buffer!(PORT_ID), &channel_id),
+                managed_address!(&mock_app_address)
+            );
+
+            let comm_key = sc.get_channel_commitment_key(&managed_buffer!(PORT_ID), &channel_id);
+            let encoded_channel = channel_info.channel.to_proto();
+            assert_eq!(
+                sc.commitments(&comm_key).get(),
+                sc.crypto().keccak256(encoded_channel)
+            );
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.mock_app_wrapper, |sc| {
+            let callbacks = sc.callbacks(&managed_buffer!(PORT_ID), &managed_buffer!(b"channel-0"));
+            assert_eq!(callbacks.len(), 1);
+            assert_eq!(callbacks.get(1), managed_buffer!(b"onChanOpenInit"));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn channel_open_init_unbound_port_test() {
+    let setup = get_channel_setup();
+    let relayer = setup.relayer.clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(&relayer, &setup.channel_wrapper, &rust_biguint!(0), |sc| {
+            let _ = sc.channel_open_init(MsgChannelOpenInit {
+                port_id: managed_buffer!(b"unknown-port"),
+                channel: new_channel(channel::State::Init, channel::Order::Unordered, b"", b""),
+            });
+        })
+        .assert_user_error("Port not found");
+}
+
+#[test]
+fn channel_open_init_connection_not_open_test() {
+    let setup = get_channel_setup();
+    let relayer = setup.relayer.clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(&relayer, &setup.channel_wrapper, &rust_biguint!(0), |sc| {
+            sc.connection_info(&managed_buffer!(CONNECTION_ID))
+                .update(|connection| {
+                    connection.state = common_types::connection_types::connection_end::State::Init
+                });
+
+            let _ = sc.channel_open_init(MsgChannelOpenInit {
+                port_id: managed_buffer!(PORT_ID),
+                channel: new_channel(channel::State::Init, channel::Order::Unordered, b"", b""),
+            });
+        })
+        .assert_user_error("Connection not open");
+}
+
+#[test]
+fn channel_open_init_wrong_state_test() {
+    let setup = get_channel_setup();
+    let relayer = setup.relayer.clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(&relayer, &setup.channel_wrapper, &rust_biguint!(0), |sc| {
+            let _ = sc.channel_open_init(MsgChannelOpenInit {
+                port_id: managed_buffer!(PORT_ID),
+                channel: new_channel(channel::State::Open, channel::Order::Unordered, b"", b""),
+            });
+        })
+        .assert_user_error("Invalid channel state");
+}
+
+#[test]
+fn channel_open_try_ok_test() {
+    let setup = get_channel_setup();
+    DebugApi::dummy();
+    let proof = setup.mock_proof(
+        &channel_path(COUNTERPARTY_PORT_ID, COUNTERPARTY_CHANNEL_ID),
+        &encode_channel(&counterparty_channel(
+            channel::State::Init,
+            channel::Order::Ordered,
+            b"",
+            MOCK_APP_VERSION,
+        )),
+    );
+
+    let relayer = setup.relayer.clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(&relayer, &setup.channel_wrapper, &rust_biguint!(0), |sc| {
+            let (channel_id, version) = sc
+                .channel_open_try(MsgChannelOpenTry {
+                    port_id: managed_buffer!(PORT_ID),
+                    channel: new_channel(
+                        channel::State::TryOpen,
+                        channel::Order::Ordered,
+                        COUNTERPARTY_CHANNEL_ID,
+                        b"",
+                    ),
+                    counterparty_version: managed_buffer!(MOCK_APP_VERSION),
+                    proof_init: ManagedBuffer::new_from_bytes(&proof),
+                    proof_height: PROOF_HEIGHT,
+                })
+                .into_tuple();
+            assert_eq!(channel_id, managed_buffer!(b"channel-0"));
+            assert_eq!(version, managed_buffer!(MOCK_APP_VERSION));
+
+            let channel_info = sc
+                .channel_info(&managed_buffer!(PORT_ID), &channel_id)
+                .get();
+            assert!(matches!(
+                channel_info.channel.state,
+                channel::State::TryOpen
+            ));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn channel_open_try_invalid_proof_test() {
+    let setup = get_channel_setup();
+    let relayer = setup.relayer.clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(&relayer, &setup.channel_wrapper, &rust_biguint!(0), |sc| {
+            let _ = sc.channel_open_try(MsgChannelOpenTry {
+                port_id: managed_buffer!(PORT_ID),
+                channel: new_channel(
+                    channel::State::TryOpen,
+                    channel::Order::Ordered,
+                    COUNTERPARTY_CHANNEL_ID,
+                    b"",
+                ),
+                counterparty_version: managed_buffer!(MOCK_APP_VERSION),
+                proof_init: ManagedBuffer::new_from_bytes(&[1u8; 32]),
+                proof_height: PROOF_HEIGHT,
+            });
+        })
+        .assert_user_error("Failed to verify channel state");
+}
+
+#[test]
+fn channel_open_ack_and_confirm_test() {
+    let setup = get_channel_setup();
+    DebugApi::dummy();
+    let relayer = setup.relayer.clone();
+
+    // chain A: INIT -> OPEN
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(&relayer, &setup.channel_wrapper, &rust_biguint!(0), |sc| {
+            let _ = sc.channel_open_init(MsgChannelOpenInit {
+                port_id: managed_buffer!(PORT_ID),
+                channel: new_channel(channel::State::Init, channel::Order::Unordered, b"", b""),
+            });
+        })
+        .assert_ok();
+
+    let proof_try = setup.mock_proof(
+        &channel_path(COUNTERPARTY_PORT_ID, COUNTERPARTY_CHANNEL_ID),
+        &encode_channel(&counterparty_channel(
+            channel::State::TryOpen,
+            channel::Order::Unordered,
+            b"channel-0",
+            MOCK_APP_VERSION,
+        )),
+    );
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(&relayer, &setup.channel_wrapper, &rust_biguint!(0), |sc| {
+            sc.channel_open_ack(MsgChannelOpenAck {
+                port_id: managed_buffer!(PORT_ID),
+                channel_id: managed_buffer!(b"channel-0"),
+                counterparty_version: managed_buffer!(MOCK_APP_VERSION),
+                counterparty_channel_id: managed_buffer!(COUNTERPARTY_CHANNEL_ID),
+                proof_try: ManagedBuffer::new_from_bytes(&proof_try),
+                proof_height: PROOF_HEIGHT,
+            });
+
+            let channel_info: ChannelInfo<DebugApi> = sc
+                .channel_info(&managed_buffer!(PORT_ID), &managed_buffer!(b"channel-0"))
+                .get();
+            assert!(matches!(channel_info.channel.state, channel::State::Open));
+            assert_eq!(
+                channel_info.channel.counterparty.channel_id,
+                managed_buffer!(COUNTERPARTY_CHANNEL_ID)
+            );
+        })
+        .assert_ok();
+
+    // chain A again: a second ack must be rejected
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(&relayer, &setup.channel_wrapper, &rust_biguint!(0), |sc| {
+            sc.channel_open_ack(MsgChannelOpenAck {
+                port_id: managed_buffer!(PORT_ID),
+                channel_id: managed_buffer!(b"channel-0"),
+                counterparty_version: managed_buffer!(MOCK_APP_VERSION),
+                counterparty_channel_id: managed_buffer!(COUNTERPARTY_CHANNEL_ID),
+                proof_try: ManagedBuffer::new_from_bytes(&[0u8; 32]),
+                proof_height: PROOF_HEIGHT,
+            });
+        })
+        .assert_user_error("Invalid channel state");
+
+    // chain B side: TRY_OPEN -> OPEN
+    let proof_init = setup.mock_proof(
+        &channel_path(COUNTERPARTY_PORT_ID, COUNTERPARTY_CHANNEL_ID),
+        &encode_channel(&counterparty_channel(
+            channel::State::Init,
+            channel::Order::Unordered,
+            b"",
+            MOCK_APP_VERSION,
+        )),
+    );
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(&relayer, &setup.channel_wrapper, &rust_biguint!(0), |sc| {
+            let _ = sc.channel_open_try(MsgChannelOpenTry {
+                port_id: managed_buffer!(PORT_ID),
+                channel: new_channel(
+                    channel::State::TryOpen,
+                    channel::Order::Unordered,
+                    COUNTERPARTY_CHANNEL_ID,
+                    b"",
+                ),
+                counterparty_version: managed_buffer!(MOCK_APP_VERSION),
+                proof_init: ManagedBuffer::new_from_bytes(&proof_init),
+                proof_height: PROOF_HEIGHT,
+            });
+        })
+        .assert_ok();
+
+    let proof_ack = setup.mock_proof(
+        &channel_path(COUNTERPARTY_PORT_ID, COUNTERPARTY_CHANNEL_ID),
+        &encode_channel(&counterparty_channel(
+            channel::State::Open,
+            channel::Order::Unordered,
+            b"channel-1",
+            MOCK_APP_VERSION,
+        )),
+    );
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(&relayer, &setup.channel_wrapper, &rust_biguint!(0), |sc| {
+            sc.channel_open_confirm(MsgChannelOpenConfirm {
+                port_id: managed_buffer!(PORT_ID),
+                channel_id: managed_buffer!(b"channel-1"),
+                proof_ack: ManagedBuffer::new_from_bytes(&proof_ack),
+                proof_height: PROOF_HEIGHT,
+            });
+
+            let channel_info = sc
+                .channel_info(&managed_buffer!(PORT_ID), &managed_buffer!(b"channel-1"))
+                .get();
+            assert!(matches!(channel_info.channel.state, channel::State::Open));
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.mock_app_wrapper, |sc| {
+            let callbacks = sc.callbacks(&managed_buffer!(PORT_ID), &managed_buffer!(b"channel-1"));
+            assert_eq!(callbacks.len(), 2);
+            assert_eq!(callbacks.get(1), managed_buffer!(b"onChanOpenTry"));
+            assert_eq!(callbacks.get(2), managed_buffer!(b"onChanOpenConfirm"));
+        })
+        .assert_ok();
+}
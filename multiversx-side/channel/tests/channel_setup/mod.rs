@@ -0,0 +1,312 @@
+#![allow(dead_code)]
+
+use std::{cell::RefCell, rc::Rc};
+
+use ::channel::{handshake::chan_internal::ChannelInternalModule, Channel};
+use client_common::ClientStatus;
+use common_types::{
+    channel_types::{channel, channel_counterparty, error_receipt, height, upgrade},
+    connection_types::{connection_end, counterparty, merkle_prefix, version},
+    ConnectionHops, FeatureVec, VersionVec,
+};
+use host::{
+    host_config::HostConfigModule,
+    module_manager::ModuleManagerModule,
+    storage::{ClientInfo, StorageModule},
+};
+use mock::{
+    client_logic::ClientLogicModule,
+    mock_types::{client_state, consensus_state},
+    views::ViewsModule,
+    Mock,
+};
+use mock_app::{MockApp, MOCK_APP_VERSION};
+use multiversx_sc::types::Address;
+use multiversx_sc_scenario::{
+    imports::{BlockchainStateWrapper, ContractObjWrapper},
+    managed_address, managed_buffer, rust_biguint, DebugApi,
+};
+
+pub static CLIENT_TYPE: &[u8] = b"mock-client";
+pub static CLIENT_ID: &[u8] = b"mock-client-0";
+pub static CONNECTION_ID: &[u8] = b"connection-0";
+pub static COUNTERPARTY_CLIENT_ID: &[u8] = b"counterparty-client-0";
+pub static COUNTERPARTY_CONNECTION_ID: &[u8] = b"connection-7";
+pub static PORT_ID: &[u8] = b"mock";
+pub static COUNTERPARTY_PORT_ID: &[u8] = b"counterparty-mock";
+pub static CHANNEL_ID: &[u8] = b"channel-0";
+pub static COUNTERPARTY_CHANNEL_ID: &[u8] = b"channel-5";
+pub static COMMITMENT_PREFIX: &[u8] = b"ibc";
+
+pub const PROOF_HEIGHT: height::Data = height::Data {
+    revision_number: 0,
+    revision_height: 10,
+};
+pub const CONSENSUS_TIMESTAMP: u64 = 1_000_000_000;
+
+pub struct ChannelSetup<ChannelObjBuilder, MockClientObjBuilder, MockAppObjBuilder>
+where
+    ChannelObjBuilder: 'static + Copy + Fn() -> ::channel::ContractObj<DebugApi>,
+    MockClientObjBuilder: 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    MockAppObjBuilder: 'static + Copy + Fn() -> mock_app::ContractObj<DebugApi>,
+{
+    pub b_mock: Rc<RefCell<BlockchainStateWrapper>>,
+    pub owner: Address,
+    pub relayer: Address,
+    pub channel_wrapper: ContractObjWrapper<::channel::ContractObj<DebugApi>, ChannelObjBuilder>,
+    pub mock_client_wrapper: ContractObjWrapper<mock::ContractObj<DebugApi>, MockClientObjBuilder>,
+    pub mock_app_wrapper: ContractObjWrapper<mock_app::ContractObj<DebugApi>, MockAppObjBuilder>,
+}
+
+impl<ChannelObjBuilder, MockClientObjBuilder, MockAppObjBuilder>
+    ChannelSetup<ChannelObjBuilder, MockClientObjBuilder, MockAppObjBuilder>
+where
+    ChannelObjBuilder: 'static + Copy + Fn() -> ::channel::ContractObj<DebugApi>,
+    MockClientObjBuilder: 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    MockAppObjBuilder: 'static + Copy + Fn() -> mock_app::ContractObj<DebugApi>,
+{
+    pub fn new(
+        b_mock: Rc<RefCell<BlockchainStateWrapper>>,
+        owner: &Address,
+        channel_builder: ChannelObjBuilder,
+        mock_client_builder: MockClientObjBuilder,
+        mock_app_builder: MockAppObjBuilder,
+    ) -> Self {
+        let rust_zero = rust_biguint!(0u64);
+        let relayer = b_mock.borrow_mut().create_user_account(&rust_zero);
+        let channel_wrapper = b_mock.borrow_mut().create_sc_account(
+            &rust_zero,
+            Some(owner),
+            channel_builder,
+            "channel",
+        );
+        let mock_client_wrapper = b_mock.borrow_mut().create_sc_account(
+            &rust_zero,
+            Some(owner),
+            mock_client_builder,
+            "mock client",
+        );
+        let mock_app_wrapper = b_mock.borrow_mut().create_sc_account(
+            &rust_zero,
+            Some(owner),
+            mock_app_builder,
+            "mock app",
+        );
+
+        let channel_address = channel_wrapper.address_ref().clone();
+        let mock_client_address = mock_client_wrapper.address_ref().clone();
+        let mock_app_address = mock_app_wrapper.address_ref().clone();
+
+        b_mock
+            .borrow_mut()
+            .execute_tx(owner, &mock_client_wrapper, &rust_zero, |sc| {
+                sc.init(managed_address!(&channel_address));
+
+                let client_id = managed_buffer!(CLIENT_ID);
+                sc.client_states(&client_id)
+                    .set(client_state::Data::new(PROOF_HEIGHT));
+                sc.consensus_states(&client_id, &PROOF_HEIGHT.to_biguint_concat())
+                    .set(consensus_state::Data::new(CONSENSUS_TIMESTAMP));
+                sc.statuses(&client_id).set(ClientStatus::Active);
+            })
+            .assert_ok();
+
+        b_mock
+            .borrow_mut()
+            .execute_tx(owner, &mock_app_wrapper, &rust_zero, |sc| {
+                sc.init(managed_address!(&channel_address));
+            })
+            .assert_ok();
+
+        b_mock
+            .borrow_mut()
+            .execute_tx(owner, &channel_wrapper, &rust_zero, |sc| {
+                sc.init();
+
+                sc.set_expected_time_per_block(6);
+                sc.register_client(
+                    managed_buffer!(CLIENT_TYPE),
+                    managed_address!(&mock_client_address),
+                );
+                sc.bind_port(
+                    managed_buffer!(PORT_ID),
+                    managed_address!(&mock_app_address),
+                );
+
+                sc.client_info(&managed_buffer!(CLIENT_ID)).set(ClientInfo {
+                    client_type: managed_buffer!(CLIENT_TYPE),
+                    client_impl: managed_address!(&mock_client_address),
+                });
+                sc.connection_info(&managed_buffer!(CONNECTION_ID))
+                    .set(open_connection());
+            })
+            .assert_ok();
+
+        Self {
+            b_mock,
+            owner: owner.clone(),
+            relayer,
+            channel_wrapper,
+            mock_client_wrapper,
+            mock_app_wrapper,
+        }
+    }
+
+    /// Stores an already open channel `CHANNEL_ID`, bound to the mock app
+    pub fn create_open_channel(&self, ordering: channel::Order) {
+        let mock_app_address = self.mock_app_wrapper.address_ref().clone();
+        self.b_mock
+            .borrow_mut()
+            .execute_tx(
+                &self.owner,
+                &self.channel_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    let port_id = managed_buffer!(PORT_ID);
+                    let channel_id = managed_buffer!(CHANNEL_ID);
+                    sc.claim_channel_capability(
+                        &port_id,
+                        &channel_id,
+                        &managed_address!(&mock_app_address),
+                    );
+                    sc.init_channel_info(
+                        &port_id,
+                        &channel_id,
+                        new_channel(
+                            channel::State::Open,
+                            ordering,
+                            COUNTERPARTY_CHANNEL_ID,
+                            MOCK_APP_VERSION,
+                        ),
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn add_consensus_state(&self, height: height::Data, timestamp: u64) {
+        self.b_mock
+            .borrow_mut()
+            .execute_tx(
+                &self.owner,
+                &self.mock_client_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    sc.consensus_states(&managed_buffer!(CLIENT_ID), &height.to_biguint_concat())
+                        .set(consensus_state::Data::new(timestamp));
+                },
+            )
+            .assert_ok();
+    }
+
+    /// Builds the proof the mock client expects for `value` being stored under `path` on the counterparty
+    pub fn mock_proof(&self, path: &[u8], value: &[u8]) -> Vec<u8> {
+        self.mock_proof_at(PROOF_HEIGHT, path, value)
+    }
+
+    pub fn mock_proof_at(&self, height: height::Data, path: &[u8], value: &[u8]) -> Vec<u8> {
+        let mut proof = Vec::new();
+        self.b_mock
+            .borrow_mut()
+            .execute_query(&self.mock_client_wrapper, |sc| {
+                let hash = sc.encode_and_hash(
+                    &height,
+                    &managed_buffer!(COMMITMENT_PREFIX),
+                    &managed_buffer!(path),
+                    &managed_buffer!(value),
+                );
+                proof = hash.to_byte_array().to_vec();
+            })
+            .assert_ok();
+
+        proof
+    }
+}
+
+pub fn channel_path(port_id: &[u8], channel_id: &[u8]) -> Vec<u8> {
+    let mut path = b"channelEnds/ports/".to_vec();
+    path.extend_from_slice(port_id);
+    path.extend_from_slice(b"/channels/");
+    path.extend_from_slice(channel_id);
+
+    path
+}
+
+pub fn open_connection() -> connection_end::Data<DebugApi> {
+    let mut features = FeatureVec::new();
+    features.push(managed_buffer!(channel::ORDERED));
+    features.push(managed_buffer!(channel::UNORDERED));
+
+    connection_end::Data {
+        client_id: managed_buffer!(CLIENT_ID),
+        versions: VersionVec::from_single_item(version::Data {
+            identifier: managed_buffer!(b"1"),
+            features,
+        }),
+        state: connection_end::State::Open,
+        counterparty: counterparty::Data {
+            client_id: managed_buffer!(COUNTERPARTY_CLIENT_ID),
+            connection_id: managed_buffer!(COUNTERPARTY_CONNECTION_ID),
+            prefix: merkle_prefix::Data {
+                key_prefix: managed_buffer!(COMMITMENT_PREFIX),
+            },
+        },
+        delay_period: 0,
+    }
+}
+
+pub fn new_channel(
+    state: channel::State,
+    ordering: channel::Order,
+    counterparty_channel_id: &[u8],
+    version: &[u8],
+) -> channel::Data<DebugApi> {
+    channel::Data {
+        state,
+        ordering,
+        counterparty: channel_counterparty::Data {
+            port_id: managed_buffer!(COUNTERPARTY_PORT_ID),
+            channel_id: managed_buffer!(counterparty_channel_id),
+        },
+        connection_hops: ConnectionHops::from_single_item(managed_buffer!(CONNECTION_ID)),
+        version: managed_buffer!(version),
+        upgrade_sequence: 0,
+    }
+}
+
+/// The channel end the counterparty is expected to have stored, as seen from this chain
+pub fn counterparty_channel(
+    state: channel::State,
+    ordering: channel::Order,
+    channel_id: &[u8],
+    version: &[u8],
+) -> channel::Data<DebugApi> {
+    channel::Data {
+        state,
+        ordering,
+        counterparty: channel_counterparty::Data {
+            port_id: managed_buffer!(PORT_ID),
+            channel_id: managed_buffer!(channel_id),
+        },
+        connection_hops: ConnectionHops::from_single_item(managed_buffer!(
+            COUNTERPARTY_CONNECTION_ID
+        )),
+        version: managed_buffer!(version),
+        upgrade_sequence: 0,
+    }
+}
+
+/// Protobuf encoding, as committed for channel ends
+pub fn encode_channel(channel: &channel::Data<DebugApi>) -> Vec<u8> {
+    channel.to_proto().to_boxed_bytes().into_vec()
+}
+
+/// Protobuf encoding, as committed for channel upgrades
+pub fn encode_upgrade(upgrade: &upgrade::Data<DebugApi>) -> Vec<u8> {
+    upgrade.to_proto().to_boxed_bytes().into_vec()
+}
+
+/// Protobuf encoding, as committed for upgrade error receipts
+pub fn encode_error_receipt(error_receipt: &error_receipt::Data<DebugApi>) -> Vec<u8> {
+    error_receipt.to_proto().to_boxed_bytes().into_vec()
+}
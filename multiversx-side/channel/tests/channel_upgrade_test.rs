@@ -0,0 +1,671 @@
+use std::{cell::RefCell, rc::Rc};
+
+use ::channel::{
+    channel_libs::handshake_types::{
+        ChannelUpgradeProofs, MsgCancelChannelUpgrade, MsgChannelUpgradeAck,
+        MsgChannelUpgradeConfirm, MsgChannelUpgradeInit, MsgChannelUpgradeOpen,
+        MsgChannelUpgradeTry, MsgTimeoutChannelUpgrade,
+    },
+    handshake::chan_upgrade::ChannelUpgradeModule,
+};
+use channel_setup::*;
+use common_types::{
+    channel_types::{channel, error_receipt, height, timeout, upgrade, upgrade_fields},
+    ConnectionHops, Proof,
+};
+use host::{commitment::CommitmentModule, storage::StorageModule};
+use mock_app::{MockApp, MOCK_APP_VERSION};
+use multiversx_sc::{contract_base::ContractBase, types::ManagedBuffer};
+use multiversx_sc_scenario::{
+    imports::BlockchainStateWrapper, managed_buffer, rust_biguint, DebugApi,
+};
+
+pub mod channel_setup;
+
+static UPGRADED_VERSION: &[u8] = b"mockapp-2";
+
+fn get_channel_setup() -> ChannelSetup<
+    impl 'static + Copy + Fn() -> ::channel::ContractObj<DebugApi>,
+    impl 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    impl 'static + Copy + Fn() -> mock_app::ContractObj<DebugApi>,
+> {
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_biguint!(0));
+    let setup = ChannelSetup::new(
+        Rc::new(RefCell::new(b_mock)),
+        &owner,
+        ::channel::contract_obj,
+        mock::contract_obj,
+        mock_app::contract_obj,
+    );
+    setup.create_open_channel(channel::Order::Unordered);
+
+    setup
+}
+
+fn proposed_fields(connection_id: &[u8]) -> upgrade_fields::Data<DebugApi> {
+    upgrade_fields::Data {
+        ordering: channel::Order::Unordered,
+        connection_hops: ConnectionHops::from_single_item(managed_buffer!(connection_id)),
+        version: managed_buffer!(UPGRADED_VERSION),
+    }
+}
+
+fn counterparty_upgrade(
+    next_sequence_send: u64,
+    timeout_timestamp: u64,
+) -> upgrade::Data<DebugApi> {
+    upgrade::Data {
+        fields: proposed_fields(COUNTERPARTY_CONNECTION_ID),
+        timeout: timeout::Data {
+            height: height::Data::default(),
+            timestamp: timeout_timestamp,
+        },
+        next_sequence_send,
+    }
+}
+
+fn counterparty_channel_with_seq(
+    state: channel::State,
+    upgrade_sequence: u64,
+) -> channel::Data<DebugApi> {
+    let mut channel = counterparty_channel(
+        state,
+        channel::Order::Unordered,
+        CHANNEL_ID,
+        MOCK_APP_VERSION,
+    );
+    channel.upgrade_sequence = upgrade_sequence;
+
+    channel
+}
+
+fn counterparty_upgrade_path() -> Vec<u8> {
+    let mut path = b"channelUpgrades/upgrades/ports/".to_vec();
+    path.extend_from_slice(COUNTERPARTY_PORT_ID);
+    path.extend_from_slice(b"/channels/");
+    path.extend_from_slice(COUNTERPARTY_CHANNEL_ID);
+
+    path
+}
+
+fn to_proof(proof: Vec<u8>) -> Proof<DebugApi> {
+    ManagedBuffer::new_from_bytes(&proof)
+}
+
+fn upgrade_proofs(setup_proofs: (Vec<u8>, Vec<u8>)) -> ChannelUpgradeProofs<DebugApi> {
+    ChannelUpgradeProofs {
+        proof_channel: to_proof(setup_proofs.0),
+        proof_upgrade: to_proof(setup_proofs.1),
+        proof_height: PROOF_HEIGHT,
+    }
+}
+
+fn init_upgrade<
+    ChannelBuilder: 'static + Copy + Fn() -> ::channel::ContractObj<DebugApi>,
+    MockClientBuilder: 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    MockAppBuilder: 'static + Copy + Fn() -> mock_app::ContractObj<DebugApi>,
+>(
+    setup: &ChannelSetup<ChannelBuilder, MockClientBuilder, MockAppBuilder>,
+) {
+    let mock_app_address = setup.mock_app_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &mock_app_address,
+            &setup.channel_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let upgrade_sequence = sc.channel_upgrade_init(MsgChannelUpgradeInit {
+                    port_id: managed_buffer!(PORT_ID),
+                    channel_id: managed_buffer!(CHANNEL_ID),
+                    proposed_upgrade_fields: proposed_fields(CONNECTION_ID),
+                });
+                assert_eq!(upgrade_sequence, 1);
+            },
+        )
+        .assert_ok();
+}
+
+fn try_upgrade<
+    ChannelBuilder: 'static + Copy + Fn() -> ::channel::ContractObj<DebugApi>,
+    MockClientBuilder: 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    MockAppBuilder: 'static + Copy + Fn() -> mock_app::ContractObj<DebugApi>,
+>(
+    setup: &ChannelSetup<ChannelBuilder, MockClientBuilder, MockAppBuilder>,
+    counterparty_upgrade_sequence: u64,
+) -> bool {
+    DebugApi::dummy();
+    let proof_channel = setup.mock_proof(
+        &channel_path(COUNTERPARTY_PORT_ID, COUNTERPARTY_CHANNEL_ID),
+        &encode_channel(&counterparty_channel_with_seq(
+            channel::State::Open,
+            counterparty_upgrade_sequence,
+        )),
+    );
+    let proof_upgrade = setup.mock_proof(
+        &counterparty_upgrade_path(),
+        &encode_upgrade(&counterparty_upgrade(0, 0)),
+    );
+
+    let relayer = setup.relayer.clone();
+    let mut result = false;
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(&relayer, &setup.channel_wrapper, &rust_biguint!(0), |sc| {
+            result = sc.channel_upgrade_try(MsgChannelUpgradeTry {
+                port_id: managed_buffer!(PORT_ID),
+                channel_id: managed_buffer!(CHANNEL_ID),
+                counterparty_upgrade_fields: proposed_fields(COUNTERPARTY_CONNECTION_ID),
+                counterparty_upgrade_sequence,
+                proposed_connection_hops: ConnectionHops::from_single_item(managed_buffer!(
+                    CONNECTION_ID
+                )),
+                proofs: upgrade_proofs((proof_channel, proof_upgrade)),
+            });
+        })
+        .assert_ok();
+
+    result
+}
+
+#[test]
+fn channel_upgrade_init_ok_test() {
+    let setup = get_channel_setup();
+    init_upgrade(&setup);
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.channel_wrapper, |sc| {
+            let port_id = managed_buffer!(PORT_ID);
+            let channel_id = managed_buffer!(CHANNEL_ID);
+            let channel_info = sc.channel_info(&port_id, &channel_id).get();
+            assert!(matches!(channel_info.channel.state, channel::State::Open));
+            assert_eq!(channel_info.channel.upgrade_sequence, 1);
+            assert_eq!(
+                channel_info.upgrade.fields.version,
+                managed_buffer!(UPGRADED_VERSION)
+            );
+
+            // the upgrade is committed with the protobuf encoding of ibc-go
+            let upgrade_key = sc.get_channel_upgrade_commitment_key(&port_id, &channel_id);
+            assert_eq!(
+                sc.commitments(&upgrade_key).get(),
+                sc.crypto().keccak256(channel_info.upgrade.to_proto())
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn channel_upgrade_init_unchanged_fields_test() {
+    let setup = get_channel_setup();
+    let mock_app_address = setup.mock_app_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &mock_app_address,
+            &setup.channel_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut fields = proposed_fields(CONNECTION_ID);
+                fields.version = managed_buffer!(MOCK_APP_VERSION);
+
+                let _ = sc.channel_upgrade_init(MsgChannelUpgradeInit {
+                    port_id: managed_buffer!(PORT_ID),
+                    channel_id: managed_buffer!(CHANNEL_ID),
+                    proposed_upgrade_fields: fields,
+                });
+            },
+        )
+        .assert_user_error("Upgrade fields are unchanged");
+}
+
+#[test]
+fn channel_upgrade_init_not_capability_owner_test() {
+    let setup = get_channel_setup();
+    let relayer = setup.relayer.clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(&relayer, &setup.channel_wrapper, &rust_biguint!(0), |sc| {
+            let _ = sc.channel_upgrade_init(MsgChannelUpgradeInit {
+                port_id: managed_buffer!(PORT_ID),
+                channel_id: managed_buffer!(CHANNEL_ID),
+                proposed_upgrade_fields: proposed_fields(CONNECTION_ID),
+            });
+        })
+        .assert_user_error("Not allowed to use this port");
+}
+
+#[test]
+fn channel_upgrade_try_ok_test() {
+    let setup = get_channel_setup();
+    assert!(try_upgrade(&setup, 1));
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.channel_wrapper, |sc| {
+            let channel_info = sc
+                .channel_info(&managed_buffer!(PORT_ID), &managed_buffer!(CHANNEL_ID))
+                .get();
+            assert!(matches!(
+                channel_info.channel.state,
+                channel::State::Flushing
+            ));
+            assert_eq!(channel_info.channel.upgrade_sequence, 1);
+            assert_eq!(channel_info.upgrade.next_sequence_send, 1);
+            assert_ne!(channel_info.upgrade.timeout.timestamp, 0);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn channel_upgrade_try_sequence_mismatch_test() {
+    let setup = get_channel_setup();
+    assert!(!try_upgrade(&setup, 3));
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.channel_wrapper, |sc| {
+            let port_id = managed_buffer!(PORT_ID);
+            let channel_id = managed_buffer!(CHANNEL_ID);
+            let channel_info = sc.channel_info(&port_id, &channel_id).get();
+            assert!(matches!(channel_info.channel.state, channel::State::Open));
+            assert_eq!(channel_info.channel.upgrade_sequence, 3);
+            assert_eq!(channel_info.latest_error_rec_seq, 3);
+
+            let error_key = sc.get_channel_upgrade_error_commitment_key(&port_id, &channel_id);
+            assert!(!sc.commitments(&error_key).is_empty());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn channel_upgrade_ack_and_open_test() {
+    let setup = get_channel_setup();
+    init_upgrade(&setup);
+
+    DebugApi::dummy();
+    let upgrade = counterparty_upgrade(1, 1_000_000_000_000);
+    let proof_channel = setup.mock_proof(
+        &channel_path(COUNTERPARTY_PORT_ID, COUNTERPARTY_CHANNEL_ID),
+        &encode_channel(&counterparty_channel_with_seq(channel::State::Flushing, 1)),
+    );
+    let proof_upgrade = setup.mock_proof(&counterparty_upgrade_path(), &encode_upgrade(&upgrade));
+
+    let relayer = setup.relayer.clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(&relayer, &setup.channel_wrapper, &rust_biguint!(0), |sc| {
+            let result = sc.channel_upgrade_ack(MsgChannelUpgradeAck {
+                port_id: managed_buffer!(PORT_ID),
+                channel_id: managed_buffer!(CHANNEL_ID),
+                counterparty_upgrade: counterparty_upgrade(1, 1_000_000_000_000),
+                proofs: upgrade_proofs((proof_channel, proof_upgrade)),
+            });
+            assert!(result);
+
+            let channel_info = sc
+                .channel_info(&managed_buffer!(PORT_ID), &managed_buffer!(CHANNEL_ID))
+                .get();
+            assert!(matches!(
+                channel_info.channel.state,
+                channel::State::FlushComplete
+            ));
+            assert_eq!(channel_info.recv_start_seq.seq, 1);
+        })
+        .assert_ok();
+
+    let proof_channel = setup.mock_proof(
+        &channel_path(COUNTERPARTY_PORT_ID, COUNTERPARTY_CHANNEL_ID),
+        &encode_channel(&counterparty_channel_with_seq(
+            channel::State::FlushComplete,
+            1,
+        )),
+    );
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(&relayer, &setup.channel_wrapper, &rust_biguint!(0), |sc| {
+            sc.channel_upgrade_open(MsgChannelUpgradeOpen {
+                port_id: managed_buffer!(PORT_ID),
+                channel_id: managed_buffer!(CHANNEL_ID),
+                counterparty_channel_state: channel::State::FlushComplete,
+                counterparty_upgrade_sequence: 1,
+                proof_channel: to_proof(proof_channel),
+                proof_height: PROOF_HEIGHT,
+            });
+
+            let port_id = managed_buffer!(PORT_ID);
+            let channel_id = managed_buffer!(CHANNEL_ID);
+            let channel_info = sc.channel_info(&port_id, &channel_id).get();
+            assert!(matches!(channel_info.channel.state, channel::State::Open));
+            assert_eq!(
+                channel_info.channel.version,
+                managed_buffer!(UPGRADED_VERSION)
+            );
+            assert_eq!(channel_info.channel.upgrade_sequence, 1);
+            assert!(channel_info.upgrade.fields.connection_hops.is_empty());
+            assert_eq!(channel_info.ack_start_seq, 1);
+
+            let upgrade_key = sc.get_channel_upgrade_commitment_key(&port_id, &channel_id);
+            assert!(sc.commitments(&upgrade_key).is_empty());
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.mock_app_wrapper, |sc| {
+            let callbacks = sc.callbacks(&managed_buffer!(PORT_ID), &managed_buffer!(CHANNEL_ID));
+            assert_eq!(callbacks.len(), 3);
+            assert_eq!(callbacks.get(1), managed_buffer!(b"onChanUpgradeInit"));
+            assert_eq!(callbacks.get(2), managed_buffer!(b"onChanUpgradeAck"));
+            assert_eq!(callbacks.get(3), managed_buffer!(b"onChanUpgradeOpen"));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn channel_upgrade_ack_flush_pending_test() {
+    let setup = get_channel_setup();
+    init_upgrade(&setup);
+
+    let owner = setup.owner.clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(&owner, &setup.mock_app_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_flush_blocked(managed_buffer!(PORT_ID), managed_buffer!(CHANNEL_ID), true);
+        })
+        .assert_ok();
+
+    DebugApi::dummy();
+    let proof_channel = setup.mock_proof(
+        &channel_path(COUNTERPARTY_PORT_ID, COUNTERPARTY_CHANNEL_ID),
+        &encode_channel(&counterparty_channel_with_seq(channel::State::Flushing, 1)),
+    );
+    let proof_upgrade = setup.mock_proof(
+        &counterparty_upgrade_path(),
+        &encode_upgrade(&counterparty_upgrade(1, 1_000_000_000_000)),
+    );
+
+    let relayer = setup.relayer.clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(&relayer, &setup.channel_wrapper, &rust_biguint!(0), |sc| {
+            let result = sc.channel_upgrade_ack(MsgChannelUpgradeAck {
+                port_id: managed_buffer!(PORT_ID),
+                channel_id: managed_buffer!(CHANNEL_ID),
+                counterparty_upgrade: counterparty_upgrade(1, 1_000_000_000_000),
+                proofs: upgrade_proofs((proof_channel, proof_upgrade)),
+            });
+            assert!(result);
+
+            let channel_info = sc
+                .channel_info(&managed_buffer!(PORT_ID), &managed_buffer!(CHANNEL_ID))
+                .get();
+            assert!(matches!(
+                channel_info.channel.state,
+                channel::State::Flushing
+            ));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn channel_upgrade_ack_counterparty_timed_out_test() {
+    let setup = get_channel_setup();
+    init_upgrade(&setup);
+    setup.b_mock.borrow_mut().set_block_timestamp(100);
+
+    DebugApi::dummy();
+    let proof_channel = setup.mock_proof(
+        &channel_path(COUNTERPARTY_PORT_ID, COUNTERPARTY_CHANNEL_ID),
+        &encode_channel(&counterparty_channel_with_seq(channel::State::Flushing, 1)),
+    );
+    let proof_upgrade = setup.mock_proof(
+        &counterparty_upgrade_path(),
+        &encode_upgrade(&counterparty_upgrade(1, 1_000)),
+    );
+
+    let relayer = setup.relayer.clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(&relayer, &setup.channel_wrapper, &rust_biguint!(0), |sc| {
+            let result = sc.channel_upgrade_ack(MsgChannelUpgradeAck {
+                port_id: managed_buffer!(PORT_ID),
+                channel_id: managed_buffer!(CHANNEL_ID),
+                counterparty_upgrade: counterparty_upgrade(1, 1_000),
+                proofs: upgrade_proofs((proof_channel, proof_upgrade)),
+            });
+            assert!(!result);
+
+            let channel_info = sc
+                .channel_info(&managed_buffer!(PORT_ID), &managed_buffer!(CHANNEL_ID))
+                .get();
+            assert!(matches!(channel_info.channel.state, channel::State::Open));
+            assert!(channel_info.upgrade.fields.connection_hops.is_empty());
+            assert_eq!(channel_info.latest_error_rec_seq, 1);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn channel_upgrade_try_and_confirm_test() {
+    let setup = get_channel_setup();
+    assert!(try_upgrade(&setup, 1));
+
+    DebugApi::dummy();
+    let upgrade = counterparty_upgrade(1, 1_000_000_000_000);
+    let proof_channel = setup.mock_proof(
+        &channel_path(COUNTERPARTY_PORT_ID, COUNTERPARTY_CHANNEL_ID),
+        &encode_channel(&counterparty_channel_with_seq(
+            channel::State::FlushComplete,
+            1,
+        )),
+    );
+    let proof_upgrade = setup.mock_proof(&counterparty_upgrade_path(), &encode_upgrade(&upgrade));
+
+    let relayer = setup.relayer.clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(&relayer, &setup.channel_wrapper, &rust_biguint!(0), |sc| {
+            let result = sc.channel_upgrade_confirm(MsgChannelUpgradeConfirm {
+                port_id: managed_buffer!(PORT_ID),
+                channel_id: managed_buffer!(CHANNEL_ID),
+                counterparty_channel_state: channel::State::FlushComplete,
+                counterparty_upgrade: counterparty_upgrade(1, 1_000_000_000_000),
+                proofs: upgrade_proofs((proof_channel, proof_upgrade)),
+            });
+            assert!(result);
+
+            let channel_info = sc
+                .channel_info(&managed_buffer!(PORT_ID), &managed_buffer!(CHANNEL_ID))
+                .get();
+            assert!(matches!(channel_info.channel.state, channel::State::Open));
+            assert_eq!(
+                channel_info.channel.version,
+                managed_buffer!(UPGRADED_VERSION)
+            );
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.mock_app_wrapper, |sc| {
+            let callbacks = sc.callbacks(&managed_buffer!(PORT_ID), &managed_buffer!(CHANNEL_ID));
+            assert_eq!(callbacks.len(), 2);
+            assert_eq!(callbacks.get(1), managed_buffer!(b"onChanUpgradeTry"));
+            assert_eq!(callbacks.get(2), managed_buffer!(b"onChanUpgradeOpen"));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn channel_upgrade_cancel_by_module_test() {
+    let setup = get_channel_setup();
+    init_upgrade(&setup);
+
+    let mock_app_address = setup.mock_app_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &mock_app_address,
+            &setup.channel_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.channel_upgrade_cancel(MsgCancelChannelUpgrade {
+                    port_id: managed_buffer!(PORT_ID),
+                    channel_id: managed_buffer!(CHANNEL_ID),
+                    error_receipt: error_receipt::Data {
+                        sequence: 0,
+                        message: managed_buffer!(b""),
+                    },
+                    proof_upgrade_error: ManagedBuffer::new_from_bytes(&[0u8; 32]),
+                    proof_height: PROOF_HEIGHT,
+                });
+
+                let channel_info = sc
+                    .channel_info(&managed_buffer!(PORT_ID), &managed_buffer!(CHANNEL_ID))
+                    .get();
+                assert!(matches!(channel_info.channel.state, channel::State::Open));
+                assert_eq!(
+                    channel_info.channel.version,
+                    managed_buffer!(MOCK_APP_VERSION)
+                );
+                assert!(channel_info.upgrade.fields.connection_hops.is_empty());
+                assert_eq!(channel_info.latest_error_rec_seq, 1);
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn channel_upgrade_cancel_with_error_receipt_test() {
+    let setup = get_channel_setup();
+    assert!(try_upgrade(&setup, 1));
+
+    DebugApi::dummy();
+    let mut error_path = b"channelUpgrades/upgradeError/ports/".to_vec();
+    error_path.extend_from_slice(COUNTERPARTY_PORT_ID);
+    error_path.extend_from_slice(b"/channels/");
+    error_path.extend_from_slice(COUNTERPARTY_CHANNEL_ID);
+    let receipt = error_receipt::Data::<DebugApi> {
+        sequence: 1,
+        message: managed_buffer!(b"Upgrade cancelled"),
+    };
+    let proof = setup.mock_proof(&error_path, &encode_error_receipt(&receipt));
+
+    let relayer = setup.relayer.clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(&relayer, &setup.channel_wrapper, &rust_biguint!(0), |sc| {
+            sc.channel_upgrade_cancel(MsgCancelChannelUpgrade {
+                port_id: managed_buffer!(PORT_ID),
+                channel_id: managed_buffer!(CHANNEL_ID),
+                error_receipt: error_receipt::Data {
+                    sequence: 1,
+                    message: managed_buffer!(b"wrong message"),
+                },
+                proof_upgrade_error: to_proof(proof.clone()),
+                proof_height: PROOF_HEIGHT,
+            });
+        })
+        .assert_user_error("Failed to verify upgrade error receipt");
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(&relayer, &setup.channel_wrapper, &rust_biguint!(0), |sc| {
+            sc.channel_upgrade_cancel(MsgCancelChannelUpgrade {
+                port_id: managed_buffer!(PORT_ID),
+                channel_id: managed_buffer!(CHANNEL_ID),
+                error_receipt: error_receipt::Data {
+                    sequence: 1,
+                    message: managed_buffer!(b"Upgrade cancelled"),
+                },
+                proof_upgrade_error: to_proof(proof),
+                proof_height: PROOF_HEIGHT,
+            });
+
+            let channel_info = sc
+                .channel_info(&managed_buffer!(PORT_ID), &managed_buffer!(CHANNEL_ID))
+                .get();
+            assert!(matches!(channel_info.channel.state, channel::State::Open));
+            assert!(channel_info.upgrade.fields.connection_hops.is_empty());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn channel_upgrade_timeout_test() {
+    let setup = get_channel_setup();
+    assert!(try_upgrade(&setup, 1));
+
+    let late_height = height::Data {
+        revision_number: 0,
+        revision_height: 20,
+    };
+    setup.add_consensus_state(late_height, 1_000_000_000_000);
+
+    DebugApi::dummy();
+    let proof_channel = setup.mock_proof_at(
+        late_height,
+        &channel_path(COUNTERPARTY_PORT_ID, COUNTERPARTY_CHANNEL_ID),
+        &encode_channel(&counterparty_channel_with_seq(channel::State::Flushing, 1)),
+    );
+
+    let relayer = setup.relayer.clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(&relayer, &setup.channel_wrapper, &rust_biguint!(0), |sc| {
+            sc.channel_upgrade_timeout(MsgTimeoutChannelUpgrade {
+                port_id: managed_buffer!(PORT_ID),
+                channel_id: managed_buffer!(CHANNEL_ID),
+                counterparty_channel: counterparty_channel_with_seq(channel::State::Flushing, 1),
+                proof_channel: to_proof(proof_channel.clone()),
+                proof_height: PROOF_HEIGHT,
+            });
+        })
+        .assert_user_error("Upgrade timeout not reached");
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(&relayer, &setup.channel_wrapper, &rust_biguint!(0), |sc| {
+            sc.channel_upgrade_timeout(MsgTimeoutChannelUpgrade {
+                port_id: managed_buffer!(PORT_ID),
+                channel_id: managed_buffer!(CHANNEL_ID),
+                counterparty_channel: counterparty_channel_with_seq(channel::State::Flushing, 1),
+                proof_channel: to_proof(proof_channel),
+                proof_height: late_height,
+            });
+
+            let channel_info = sc
+                .channel_info(&managed_buffer!(PORT_ID), &managed_buffer!(CHANNEL_ID))
+                .get();
+            assert!(matches!(channel_info.channel.state, channel::State::Open));
+            assert_eq!(
+                channel_info.channel.version,
+                managed_buffer!(MOCK_APP_VERSION)
+            );
+            assert_eq!(channel_info.latest_error_rec_seq, 1);
+        })
+        .assert_ok();
+}
@@ -0,0 +1,51 @@
+use ::channel::middleware::version;
+use multiversx_sc_scenario::{managed_buffer, DebugApi};
+
+static FEE_VERSION_KEY: &[u8] = b"fee_version";
+
+#[test]
+fn wrap_version_test() {
+    DebugApi::dummy();
+
+    let wrapped = version::wrap::<DebugApi>(
+        FEE_VERSION_KEY,
+        &managed_buffer!(b"ics29-1"),
+        &managed_buffer!(b"ics20-1"),
+    );
+    assert_eq!(
+        wrapped,
+        managed_buffer!(br#"{"fee_version":"ics29-1","app_version":"ics20-1"}"#)
+    );
+
+    let (middleware_version, app_version) = version::unwrap(FEE_VERSION_KEY, &wrapped).unwrap();
+    assert_eq!(middleware_version, managed_buffer!(b"ics29-1"));
+    assert_eq!(app_version, managed_buffer!(b"ics20-1"));
+}
+
+#[test]
+fn unwrap_version_without_app_version_test() {
+    DebugApi::dummy();
+
+    let (middleware_version, app_version) = version::unwrap::<DebugApi>(
+        FEE_VERSION_KEY,
+        &managed_buffer!(br#"{"fee_version":"ics29-1"}"#),
+    )
+    .unwrap();
+    assert_eq!(middleware_version, managed_buffer!(b"ics29-1"));
+    assert!(app_version.is_empty());
+}
+
+#[test]
+fn unwrap_version_of_other_module_test() {
+    DebugApi::dummy();
+
+    for other_version in [
+        &b"ics20-1"[..],
+        br#"{"other_version":"1","app_version":"ics20-1"}"#,
+        br#"{"fee_version":"ics29-1","app_version":"ics20-1","extra":"1"}"#,
+    ] {
+        assert!(
+            version::unwrap::<DebugApi>(FEE_VERSION_KEY, &managed_buffer!(other_version)).is_none()
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use ::channel::packet_handling::encoding::EncodingModule;
+use common_types::channel_types::height;
+use multiversx_sc::{contract_base::ContractBase, types::ManagedBuffer};
+use multiversx_sc_scenario::{managed_buffer, DebugApi};
+
+/// Commitments of the same packets computed with ibc-go's `CommitPacket` and `CommitAcknowledgement`
+static TRANSFER_PACKET_DATA: &[u8] =
+    br#"{"amount":"100","denom":"stake","receiver":"cosmos1","sender":"erd1"}"#;
+static TRANSFER_PACKET_COMMITMENT: &str =
+    "c7acce3b393b1878d98938b806916f8c8dde85b146fb353ffe452e20b9d943b7";
+static TIMESTAMP_ONLY_PACKET_COMMITMENT: &str =
+    "c34d10c4b6fd4e39cb4822120fd46f0c8b012b8409dbad64d4b9950dd842d80c";
+static EMPTY_PACKET_COMMITMENT: &str =
+    "e6414172e184a44066320223590766e7ff9d758405e51f5cdddb546267a848f5";
+static SUCCESSFUL_ACK: &[u8] = br#"{"result":"AQ=="}"#;
+static SUCCESSFUL_ACK_COMMITMENT: &str =
+    "08f7557ed51826fe18d84512bf24ec75001edbaf2123a477df72a0a9f3640a7c";
+
+fn managed_hex(hex_str: &str) -> ManagedBuffer<DebugApi> {
+    ManagedBuffer::new_from_bytes(&hex::decode(hex_str).unwrap())
+}
+
+#[test]
+fn packet_commitment_test() {
+    DebugApi::dummy();
+    let sc = ::channel::contract_obj::<DebugApi>();
+
+    let commitment = sc.encode_and_hash(
+        height::Data {
+            revision_number: 1,
+            revision_height: 100,
+        },
+        0,
+        &managed_buffer!(TRANSFER_PACKET_DATA),
+    );
+    assert_eq!(
+        commitment.as_managed_buffer(),
+        &managed_hex(TRANSFER_PACKET_COMMITMENT)
+    );
+
+    let commitment = sc.encode_and_hash(
+        height::Data::default(),
+        1_700_000_000_000_000_000,
+        &managed_buffer!(b"data"),
+    );
+    assert_eq!(
+        commitment.as_managed_buffer(),
+        &managed_hex(TIMESTAMP_ONLY_PACKET_COMMITMENT)
+    );
+
+    // zero values are kept as 8 zero bytes
+    let commitment = sc.encode_and_hash(height::Data::default(), 0, &ManagedBuffer::new());
+    assert_eq!(
+        commitment.as_managed_buffer(),
+        &managed_hex(EMPTY_PACKET_COMMITMENT)
+    );
+
+    // the local commitment is the keccak256 of the ibc-go value
+    let stored_commitment =
+        sc.encode_and_hash_twice(height::Data::default(), 0, &ManagedBuffer::new());
+    assert_eq!(
+        stored_commitment,
+        sc.crypto().keccak256(managed_hex(EMPTY_PACKET_COMMITMENT))
+    );
+}
+
+#[test]
+fn ack_commitment_test() {
+    DebugApi::dummy();
+    let sc = ::channel::contract_obj::<DebugApi>();
+
+    assert_eq!(
+        sc.encode_ack(&managed_buffer!(SUCCESSFUL_ACK))
+            .as_managed_buffer(),
+        &managed_hex(SUCCESSFUL_ACK_COMMITMENT)
+    );
+}
+
+#[test]
+fn sequence_and_receipt_encoding_test() {
+    DebugApi::dummy();
+    let sc = ::channel::contract_obj::<DebugApi>();
+
+    assert_eq!(sc.encode_sequence(1), managed_hex("0000000000000001"));
+    assert_eq!(sc.encode_sequence(0), managed_hex("0000000000000000"));
+    assert_eq!(
+        sc.encode_sequence(0x0102_0304_0506_0708),
+        managed_hex("0102030405060708")
+    );
+    assert_eq!(sc.encode_successful_receipt(), managed_hex("01"));
+}
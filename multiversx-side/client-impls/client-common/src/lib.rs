@@ -1,10 +1,15 @@
 #![no_std]
 
-use common_types::{channel_types::height, ClientId, Hash, Path, UnixTimestamp};
+use common_types::{channel_types::height, ClientId, Hash, Path, Proof, UnixTimestamp};
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+/// The store of the counterparty upgrade module, under which it commits the clients for its next revision
+pub static UPGRADE_STORE_KEY: &[u8] = b"upgrade";
+
+const NANO_SECONDS_MULT: u64 = 1_000_000_000;
+
 pub struct ConsensusStateUpdate<M: ManagedTypeApi> {
     pub consensus_state_commitment: Hash<M>,
     pub height: height::Data,
@@ -31,7 +36,7 @@ pub struct VerifyMembershipArgs<M: ManagedTypeApi> {
     pub height: height::Data,
     pub delay_time_period: UnixTimestamp,
     pub delay_block_period: u64,
-    pub proof: Hash<M>,
+    pub proof: Proof<M>,
     pub prefix: ManagedBuffer<M>,
     pub path: Path<M>,
     pub value: ManagedBuffer<M>,
@@ -43,7 +48,7 @@ pub struct VerifyNonMembershipArgs<M: ManagedTypeApi> {
     pub height: height::Data,
     pub delay_time_period: UnixTimestamp,
     pub delay_block_period: u64,
-    pub proof: Hash<M>,
+    pub proof: Proof<M>,
     pub prefix: ManagedBuffer<M>,
     pub path: Path<M>,
 }
@@ -59,6 +64,83 @@ pub trait CommonClientLogicModule {
         self.ibc_handler().set(ibc_handler);
     }
 
+    /// "upgradedIBCState/{upgrade height}/upgradedClient"
+    fn get_upgraded_client_path(&self, upgrade_height: u64) -> Path<Self::Api> {
+        sc_format!("upgradedIBCState/{}/upgradedClient", upgrade_height)
+    }
+
+    /// "upgradedIBCState/{upgrade height}/upgradedConsState"
+    fn get_upgraded_consensus_state_path(&self, upgrade_height: u64) -> Path<Self::Api> {
+        sc_format!("upgradedIBCState/{}/upgradedConsState", upgrade_height)
+    }
+
+    /// proofs may only be verified against active clients
+    fn require_active_status(&self, status: ClientStatus) {
+        require!(status != ClientStatus::Frozen, "Client is frozen");
+        require!(status != ClientStatus::Expired, "Client is expired");
+        require!(status == ClientStatus::Active, "Client not active");
+    }
+
+    /// records when the consensus state at `height` was stored, for the connection delay
+    fn set_processed_time_and_height(
+        &self,
+        client_id: &ClientId<Self::Api>,
+        height: &height::Data,
+    ) {
+        let height_key = height.to_biguint_concat();
+        self.processed_times(client_id, &height_key)
+            .set(self.blockchain().get_block_timestamp());
+        self.processed_heights(client_id, &height_key)
+            .set(self.blockchain().get_block_nonce());
+    }
+
+    fn copy_processed_time_and_height(
+        &self,
+        from_client_id: &ClientId<Self::Api>,
+        to_client_id: &ClientId<Self::Api>,
+        height: &height::Data,
+    ) {
+        let height_key = height.to_biguint_concat();
+        self.processed_times(to_client_id, &height_key)
+            .set(self.processed_times(from_client_id, &height_key).get());
+        self.processed_heights(to_client_id, &height_key)
+            .set(self.processed_heights(from_client_id, &height_key).get());
+    }
+
+    /// the consensus state at `height` must have been stored at least `delay_time_period` (nanoseconds) and
+    /// `delay_block_period` blocks ago
+    fn require_delay_period_passed(
+        &self,
+        client_id: &ClientId<Self::Api>,
+        height: &height::Data,
+        delay_time_period: UnixTimestamp,
+        delay_block_period: u64,
+    ) {
+        let height_key = height.to_biguint_concat();
+        let current_time = self
+            .blockchain()
+            .get_block_timestamp()
+            .saturating_mul(NANO_SECONDS_MULT);
+        let valid_time = self
+            .processed_times(client_id, &height_key)
+            .get()
+            .saturating_mul(NANO_SECONDS_MULT)
+            .saturating_add(delay_time_period);
+        require!(
+            current_time >= valid_time,
+            "Delay time period has not passed"
+        );
+
+        let valid_height = self
+            .processed_heights(client_id, &height_key)
+            .get()
+            .saturating_add(delay_block_period);
+        require!(
+            self.blockchain().get_block_nonce() >= valid_height,
+            "Delay block period has not passed"
+        );
+    }
+
     fn require_ibc_handler_caller(&self) {
         let caller = self.blockchain().get_caller();
         let ibc_handler = self.ibc_handler().get();
@@ -71,4 +153,20 @@ pub trait CommonClientLogicModule {
     #[view(getIbcHandler)]
     #[storage_mapper("ibcHandler")]
     fn ibc_handler(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// block timestamp at which the consensus state at `height` was stored
+    #[storage_mapper("processedTimes")]
+    fn processed_times(
+        &self,
+        client_id: &ClientId<Self::Api>,
+        height: &BigUint,
+    ) -> SingleValueMapper<UnixTimestamp>;
+
+    /// block nonce at which the consensus state at `height` was stored
+    #[storage_mapper("processedHeights")]
+    fn processed_heights(
+        &self,
+        client_id: &ClientId<Self::Api>,
+        height: &BigUint,
+    ) -> SingleValueMapper<u64>;
 }
@@ -0,0 +1,19 @@
+#![no_std]
+
+//! RLP decoding and Merkle-Patricia-Trie proof verification over managed types,
+//! shared by the client implementations of EVM-based chains
+
+use multiversx_sc::{
+    api::{ErrorApiImpl, ManagedTypeApi},
+    types::ManagedByteArray,
+};
+
+pub mod mpt;
+pub mod rlp;
+
+pub const ETH_ADDRESS_LENGTH: usize = 20;
+pub type EthAddress<M> = ManagedByteArray<M, ETH_ADDRESS_LENGTH>;
+
+fn signal_error<M: ManagedTypeApi>(message: &[u8]) -> ! {
+    M::error_api_impl().signal_error(message)
+}
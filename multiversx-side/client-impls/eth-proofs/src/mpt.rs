@@ -0,0 +1,237 @@
+use common_types::{Hash, HASH_LENGTH};
+use multiversx_sc::{
+    api::{CryptoApi, ManagedTypeApi},
+    contract_base::CryptoWrapper,
+    types::{ManagedBuffer, ManagedVec},
+};
+
+use crate::{rlp, signal_error, EthAddress};
+
+const BRANCH_NODE_ITEMS: usize = 17;
+const BRANCH_NODE_VALUE_INDEX: usize = 16;
+const LEAF_OR_EXTENSION_NODE_ITEMS: usize = 2;
+const MAX_KEY_LEN: usize = HASH_LENGTH;
+const MAX_ENCODED_PATH_LEN: usize = MAX_KEY_LEN + 1;
+const ACCOUNT_ITEMS: usize = 4;
+const ACCOUNT_STORAGE_ROOT_INDEX: usize = 2;
+
+/// keccak256(rlp(""))
+pub const EMPTY_TRIE_ROOT: [u8; HASH_LENGTH] = [
+    0x56, 0xe8, 0x1f, 0x17, 0x1b, 0xcc, 0x55, 0xa6, 0xff, 0x83, 0x45, 0xe6, 0x92, 0xc0, 0xf8, 0x6e,
+    0x5b, 0x48, 0xe0, 0x1b, 0x99, 0x6c, 0xad, 0xc0, 0x01, 0x62, 0x2f, 0xb5, 0xe3, 0x63, 0xb4, 0x21,
+];
+
+pub static INVALID_MPT_PROOF_ERR_MSG: &[u8] = b"Invalid MPT proof";
+pub static ACCOUNT_NOT_FOUND_ERR_MSG: &[u8] = b"Account not found";
+
+enum NodeRef<M: ManagedTypeApi> {
+    Hash(ManagedBuffer<M>),
+    Inline(ManagedBuffer<M>),
+}
+
+/// Verifies the account proof of `address` against the given state root, and returns the account's storage root
+///
+/// `account_proof` is the RLP encoded list of proof nodes, as returned by `eth_getProof`
+pub fn verify_account_storage_root<M: ManagedTypeApi + CryptoApi>(
+    state_root: &Hash<M>,
+    address: &EthAddress<M>,
+    account_proof: &ManagedBuffer<M>,
+) -> Hash<M> {
+    let key = CryptoWrapper::<M>::new().keccak256(address.as_managed_buffer());
+    let proof_nodes = decode_proof_nodes(account_proof);
+    let encoded_account = match verify_proof(state_root, key.as_managed_buffer(), &proof_nodes) {
+        Some(encoded_account) => encoded_account,
+        None => signal_error::<M>(ACCOUNT_NOT_FOUND_ERR_MSG),
+    };
+
+    // [nonce, balance, storageRoot, codeHash]
+    let account_items = rlp::decode_list(&encoded_account);
+    require_valid_proof::<M>(account_items.len() == ACCOUNT_ITEMS);
+
+    rlp::decode_hash(&account_items.get(ACCOUNT_STORAGE_ROOT_INDEX))
+}
+
+/// Verifies the storage proof of `slot` against the given storage root, and returns the stored value
+///
+/// The value is a big endian integer without leading zeros. None is returned if the proof shows the slot is empty.
+pub fn verify_storage_value<M: ManagedTypeApi + CryptoApi>(
+    storage_root: &Hash<M>,
+    slot: &Hash<M>,
+    storage_proof: &ManagedBuffer<M>,
+) -> Option<ManagedBuffer<M>> {
+    let key = CryptoWrapper::<M>::new().keccak256(slot.as_managed_buffer());
+    let proof_nodes = decode_proof_nodes(storage_proof);
+
+    verify_proof(storage_root, key.as_managed_buffer(), &proof_nodes)
+        .map(|encoded_value| rlp::decode_bytes(&encoded_value))
+}
+
+/// Decodes an RLP encoded list of proof nodes into the encodings of the nodes
+pub fn decode_proof_nodes<M: ManagedTypeApi>(
+    proof: &ManagedBuffer<M>,
+) -> ManagedVec<M, ManagedBuffer<M>> {
+    let mut proof_nodes = ManagedVec::new();
+    for encoded_node in &rlp::decode_list(proof) {
+        proof_nodes.push(rlp::decode_bytes(&encoded_node));
+    }
+
+    proof_nodes
+}
+
+/// Walks the given proof nodes from `root` along the path of `key`
+///
+/// Returns the value stored at `key`, or None if the proof shows there is no such value.
+/// Fails if the proof is invalid. Keys are at most 32 bytes long, secure tries use the hash of the key.
+pub fn verify_proof<M: ManagedTypeApi + CryptoApi>(
+    root: &Hash<M>,
+    key: &ManagedBuffer<M>,
+    proof_nodes: &ManagedVec<M, ManagedBuffer<M>>,
+) -> Option<ManagedBuffer<M>> {
+    let key_len = key.len();
+    require_valid_proof::<M>(key_len <= MAX_KEY_LEN);
+
+    let mut key_bytes = [0u8; MAX_KEY_LEN];
+    let _ = key.load_slice(0, &mut key_bytes[..key_len]);
+    let key_nibbles = key_len * 2;
+
+    let mut node_ref = NodeRef::Hash(root.as_managed_buffer().clone());
+    let mut proof_index = 0;
+    let mut key_index = 0;
+    loop {
+        let node = match node_ref {
+            NodeRef::Hash(node_hash) => {
+                if proof_index == proof_nodes.len() {
+                    require_valid_proof::<M>(proof_index == 0 && node_hash == &EMPTY_TRIE_ROOT);
+
+                    return None;
+                }
+
+                let node = proof_nodes.get(proof_index).clone_value();
+                let computed_hash = CryptoWrapper::<M>::new().keccak256(&node);
+                require_valid_proof::<M>(computed_hash.as_managed_buffer() == &node_hash);
+                proof_index += 1;
+
+                node
+            }
+            NodeRef::Inline(node) => node,
+        };
+
+        let items = rlp::decode_list(&node);
+        match items.len() {
+            BRANCH_NODE_ITEMS => {
+                if key_index == key_nibbles {
+                    let value = rlp::decode_bytes(&items.get(BRANCH_NODE_VALUE_INDEX));
+                    if value.is_empty() {
+                        return None;
+                    }
+
+                    return Some(value);
+                }
+
+                let child_index = nibble_at(&key_bytes, key_index) as usize;
+                key_index += 1;
+                match decode_node_ref(&items.get(child_index)) {
+                    Some(child_ref) => node_ref = child_ref,
+                    None => return None,
+                }
+            }
+            LEAF_OR_EXTENSION_NODE_ITEMS => {
+                let encoded_path = rlp::decode_bytes(&items.get(0));
+                let (is_leaf, path_len) = decode_path_prefix(&encoded_path);
+                if !path_matches_key(&encoded_path, path_len, &key_bytes[..key_len], key_index) {
+                    return None;
+                }
+
+                key_index += path_len;
+                if is_leaf {
+                    if key_index != key_nibbles {
+                        return None;
+                    }
+
+                    return Some(rlp::decode_bytes(&items.get(1)));
+                }
+
+                match decode_node_ref(&items.get(1)) {
+                    Some(child_ref) => node_ref = child_ref,
+                    None => signal_error::<M>(INVALID_MPT_PROOF_ERR_MSG),
+                }
+            }
+            _ => signal_error::<M>(INVALID_MPT_PROOF_ERR_MSG),
+        }
+    }
+}
+
+/// Children are either referenced by hash, or embedded if their encoding is shorter than 32 bytes
+fn decode_node_ref<M: ManagedTypeApi>(encoded: &ManagedBuffer<M>) -> Option<NodeRef<M>> {
+    if rlp::is_list(encoded) {
+        return Some(NodeRef::Inline(encoded.clone()));
+    }
+
+    let node_hash = rlp::decode_bytes(encoded);
+    if node_hash.is_empty() {
+        return None;
+    }
+
+    require_valid_proof::<M>(node_hash.len() == HASH_LENGTH);
+
+    Some(NodeRef::Hash(node_hash))
+}
+
+/// Decodes the flag of a hex-prefix encoded path
+///
+/// Returns (is_leaf, path_len), with the path length in nibbles
+fn decode_path_prefix<M: ManagedTypeApi>(encoded_path: &ManagedBuffer<M>) -> (bool, usize) {
+    let encoded_len = encoded_path.len();
+    require_valid_proof::<M>(encoded_len != 0 && encoded_len <= MAX_ENCODED_PATH_LEN);
+
+    let mut flag_byte = [0u8; 1];
+    let _ = encoded_path.load_slice(0, &mut flag_byte);
+    let flag = flag_byte[0] >> 4;
+    require_valid_proof::<M>(flag <= 3);
+
+    let is_leaf = flag >= 2;
+    let is_odd = flag % 2 == 1;
+    let path_len = if is_odd {
+        encoded_len * 2 - 1
+    } else {
+        require_valid_proof::<M>(flag_byte[0] & 0x0f == 0);
+        encoded_len * 2 - 2
+    };
+
+    (is_leaf, path_len)
+}
+
+fn path_matches_key<M: ManagedTypeApi>(
+    encoded_path: &ManagedBuffer<M>,
+    path_len: usize,
+    key: &[u8],
+    key_index: usize,
+) -> bool {
+    if key_index + path_len > key.len() * 2 {
+        return false;
+    }
+
+    let encoded_len = encoded_path.len();
+    let mut path = [0u8; MAX_ENCODED_PATH_LEN];
+    let _ = encoded_path.load_slice(0, &mut path[..encoded_len]);
+
+    // the path starts after the flag nibble, and after the padding nibble for even lengths
+    let first_nibble = encoded_len * 2 - path_len;
+    (0..path_len)
+        .all(|i| nibble_at(&path[..encoded_len], first_nibble + i) == nibble_at(key, key_index + i))
+}
+
+fn nibble_at(bytes: &[u8], index: usize) -> u8 {
+    let byte = bytes[index / 2];
+    if index.is_multiple_of(2) {
+        byte >> 4
+    } else {
+        byte & 0x0f
+    }
+}
+
+fn require_valid_proof<M: ManagedTypeApi>(condition: bool) {
+    if !condition {
+        signal_error::<M>(INVALID_MPT_PROOF_ERR_MSG);
+    }
+}
@@ -0,0 +1,163 @@
+use common_types::Hash;
+use multiversx_sc::{
+    api::ManagedTypeApi,
+    types::{ManagedBuffer, ManagedVec},
+};
+
+use crate::signal_error;
+
+const SHORT_STRING_OFFSET: u8 = 0x80;
+const LONG_STRING_OFFSET: u8 = 0xb7;
+const SHORT_LIST_OFFSET: u8 = 0xc0;
+const LONG_LIST_OFFSET: u8 = 0xf7;
+const MAX_SHORT_PAYLOAD_LEN: usize = 55;
+const MAX_LENGTH_OF_LENGTH: usize = 8;
+const U64_BYTES: usize = 8;
+
+pub static INVALID_RLP_ERR_MSG: &[u8] = b"Invalid RLP encoding";
+
+pub struct RlpItemInfo {
+    pub is_list: bool,
+    pub payload_offset: usize,
+    pub payload_len: usize,
+}
+
+impl RlpItemInfo {
+    /// offset of the first byte after the item
+    #[inline]
+    pub fn end_offset(&self) -> usize {
+        self.payload_offset + self.payload_len
+    }
+}
+
+/// Decodes the prefix of the RLP item starting at `offset`
+///
+/// Only canonical encodings are accepted
+pub fn item_info<M: ManagedTypeApi>(encoded: &ManagedBuffer<M>, offset: usize) -> RlpItemInfo {
+    let prefix = load_byte(encoded, offset);
+    let (is_list, prefix_len, payload_len) = if prefix < SHORT_STRING_OFFSET {
+        (false, 0, 1)
+    } else if prefix <= LONG_STRING_OFFSET {
+        let payload_len = (prefix - SHORT_STRING_OFFSET) as usize;
+        if payload_len == 1 {
+            require_valid::<M>(load_byte(encoded, offset + 1) >= SHORT_STRING_OFFSET);
+        }
+
+        (false, 1, payload_len)
+    } else if prefix < SHORT_LIST_OFFSET {
+        let len_of_len = (prefix - LONG_STRING_OFFSET) as usize;
+        let payload_len = load_long_length(encoded, offset + 1, len_of_len);
+
+        (false, 1 + len_of_len, payload_len)
+    } else if prefix <= LONG_LIST_OFFSET {
+        (true, 1, (prefix - SHORT_LIST_OFFSET) as usize)
+    } else {
+        let len_of_len = (prefix - LONG_LIST_OFFSET) as usize;
+        let payload_len = load_long_length(encoded, offset + 1, len_of_len);
+
+        (true, 1 + len_of_len, payload_len)
+    };
+
+    let info = RlpItemInfo {
+        is_list,
+        payload_offset: offset + prefix_len,
+        payload_len,
+    };
+    require_valid::<M>(info.end_offset() <= encoded.len());
+
+    info
+}
+
+/// Returns the RLP encoding of each item of the given list
+pub fn decode_list<M: ManagedTypeApi>(
+    encoded: &ManagedBuffer<M>,
+) -> ManagedVec<M, ManagedBuffer<M>> {
+    let list_info = item_info(encoded, 0);
+    require_valid::<M>(list_info.is_list && list_info.end_offset() == encoded.len());
+
+    let mut items = ManagedVec::new();
+    let mut offset = list_info.payload_offset;
+    while offset < list_info.end_offset() {
+        let item_end = item_info(encoded, offset).end_offset();
+        require_valid::<M>(item_end <= list_info.end_offset());
+
+        items.push(copy_slice(encoded, offset, item_end - offset));
+        offset = item_end;
+    }
+
+    items
+}
+
+/// Returns the payload of the given RLP encoded byte string
+pub fn decode_bytes<M: ManagedTypeApi>(encoded: &ManagedBuffer<M>) -> ManagedBuffer<M> {
+    let info = item_info(encoded, 0);
+    require_valid::<M>(!info.is_list && info.end_offset() == encoded.len());
+
+    copy_slice(encoded, info.payload_offset, info.payload_len)
+}
+
+pub fn decode_hash<M: ManagedTypeApi>(encoded: &ManagedBuffer<M>) -> Hash<M> {
+    match Hash::try_from(decode_bytes(encoded)) {
+        Ok(hash) => hash,
+        Err(_) => signal_error::<M>(INVALID_RLP_ERR_MSG),
+    }
+}
+
+/// Decodes a big endian unsigned integer, without leading zeros
+pub fn decode_u64<M: ManagedTypeApi>(encoded: &ManagedBuffer<M>) -> u64 {
+    let bytes = decode_bytes(encoded);
+    let len = bytes.len();
+    require_valid::<M>(len <= U64_BYTES);
+
+    let mut array = [0u8; U64_BYTES];
+    let _ = bytes.load_slice(0, &mut array[U64_BYTES - len..]);
+    require_valid::<M>(len == 0 || array[U64_BYTES - len] != 0);
+
+    u64::from_be_bytes(array)
+}
+
+pub fn is_list<M: ManagedTypeApi>(encoded: &ManagedBuffer<M>) -> bool {
+    item_info(encoded, 0).is_list
+}
+
+fn load_long_length<M: ManagedTypeApi>(
+    encoded: &ManagedBuffer<M>,
+    offset: usize,
+    len_of_len: usize,
+) -> usize {
+    require_valid::<M>(len_of_len <= MAX_LENGTH_OF_LENGTH);
+
+    let mut array = [0u8; MAX_LENGTH_OF_LENGTH];
+    let result = encoded.load_slice(offset, &mut array[MAX_LENGTH_OF_LENGTH - len_of_len..]);
+    require_valid::<M>(result.is_ok() && array[MAX_LENGTH_OF_LENGTH - len_of_len] != 0);
+
+    let len = u64::from_be_bytes(array);
+    require_valid::<M>(len > MAX_SHORT_PAYLOAD_LEN as u64 && len <= encoded.len() as u64);
+
+    len as usize
+}
+
+fn load_byte<M: ManagedTypeApi>(encoded: &ManagedBuffer<M>, offset: usize) -> u8 {
+    let mut byte = [0u8; 1];
+    let result = encoded.load_slice(offset, &mut byte);
+    require_valid::<M>(result.is_ok());
+
+    byte[0]
+}
+
+fn copy_slice<M: ManagedTypeApi>(
+    encoded: &ManagedBuffer<M>,
+    offset: usize,
+    len: usize,
+) -> ManagedBuffer<M> {
+    match encoded.copy_slice(offset, len) {
+        Some(slice) => slice,
+        None => signal_error::<M>(INVALID_RLP_ERR_MSG),
+    }
+}
+
+fn require_valid<M: ManagedTypeApi>(condition: bool) {
+    if !condition {
+        signal_error::<M>(INVALID_RLP_ERR_MSG);
+    }
+}
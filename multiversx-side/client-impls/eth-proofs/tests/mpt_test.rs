@@ -0,0 +1,221 @@
+use common_types::Hash;
+use eth_proofs::mpt;
+use multiversx_sc::types::{ManagedBuffer, ManagedVec};
+use multiversx_sc_scenario::DebugApi;
+use proofs_setup::{assert_user_error, byte_array_hex, managed_hex, vectors};
+
+pub mod proofs_setup;
+
+fn verify_proof(root: &str, key: &[u8], proof: &str) -> Option<ManagedBuffer<DebugApi>> {
+    let root: Hash<DebugApi> = byte_array_hex(root);
+    let proof_nodes = mpt::decode_proof_nodes(&managed_hex(proof));
+
+    mpt::verify_proof(&root, &ManagedBuffer::new_from_bytes(key), &proof_nodes)
+}
+
+#[test]
+fn verify_proof_test() {
+    DebugApi::dummy();
+
+    let vectors: [(&str, &str, &[u8], &[u8]); 7] = [
+        (
+            vectors::PUPPY_ROOT,
+            vectors::PUPPY_DOG_PROOF,
+            b"do",
+            b"verb",
+        ),
+        (
+            vectors::PUPPY_ROOT,
+            vectors::PUPPY_DOG_PROOF,
+            b"dog",
+            b"puppy",
+        ),
+        (
+            vectors::PUPPY_ROOT,
+            vectors::PUPPY_DOG_PROOF,
+            b"doge",
+            b"coin",
+        ),
+        (
+            vectors::PUPPY_ROOT,
+            vectors::PUPPY_HORSE_PROOF,
+            b"horse",
+            b"stallion",
+        ),
+        (
+            vectors::SMALL_VALUES_ROOT,
+            vectors::SMALL_VALUES_PROOF,
+            b"be",
+            b"e",
+        ),
+        (
+            vectors::SMALL_VALUES_ROOT,
+            vectors::SMALL_VALUES_PROOF,
+            b"bed",
+            b"d",
+        ),
+        (
+            vectors::SMALL_VALUES_ROOT,
+            vectors::SMALL_VALUES_PROOF,
+            b"dog",
+            b"puppy",
+        ),
+    ];
+    for (root, proof, key, value) in vectors {
+        assert_eq!(
+            verify_proof(root, key, proof),
+            Some(ManagedBuffer::new_from_bytes(value))
+        );
+    }
+}
+
+#[test]
+fn verify_proof_of_absence_test() {
+    DebugApi::dummy();
+
+    let vectors: [(&str, &str, &[u8]); 5] = [
+        (vectors::PUPPY_ROOT, vectors::PUPPY_DOG_PROOF, b"dogs"),
+        (vectors::PUPPY_ROOT, vectors::PUPPY_HORSE_PROOF, b"cat"),
+        (vectors::PUPPY_ROOT, vectors::PUPPY_HORSE_PROOF, b"h"),
+        (
+            vectors::SMALL_VALUES_ROOT,
+            vectors::SMALL_VALUES_PROOF,
+            b"bee",
+        ),
+        (vectors::EMPTY_TRIE_ROOT, "c0", b"dog"),
+    ];
+    for (root, proof, key) in vectors {
+        assert!(verify_proof(root, key, proof).is_none());
+    }
+}
+
+#[test]
+fn verify_proof_wrong_root_test() {
+    DebugApi::dummy();
+    assert_user_error(
+        || {
+            let _ = verify_proof(vectors::SMALL_VALUES_ROOT, b"dog", vectors::PUPPY_DOG_PROOF);
+        },
+        "Invalid MPT proof",
+    );
+}
+
+#[test]
+fn verify_proof_missing_node_test() {
+    DebugApi::dummy();
+
+    // the horse proof stops at the branch node, before the "do" subtrie
+    assert_user_error(
+        || {
+            let _ = verify_proof(vectors::PUPPY_ROOT, b"dog", vectors::PUPPY_HORSE_PROOF);
+        },
+        "Invalid MPT proof",
+    );
+}
+
+#[test]
+fn verify_proof_empty_proof_test() {
+    DebugApi::dummy();
+    assert_user_error(
+        || {
+            let _ = verify_proof(vectors::PUPPY_ROOT, b"dog", "c0");
+        },
+        "Invalid MPT proof",
+    );
+}
+
+#[test]
+fn verify_proof_tampered_node_test() {
+    DebugApi::dummy();
+
+    let mut proof_bytes = hex::decode(vectors::PUPPY_DOG_PROOF).unwrap();
+    let last_index = proof_bytes.len() - 1;
+    proof_bytes[last_index] ^= 1;
+
+    assert_user_error(
+        || {
+            let _ = verify_proof(vectors::PUPPY_ROOT, b"do", &hex::encode(&proof_bytes));
+        },
+        "Invalid MPT proof",
+    );
+}
+
+#[test]
+fn verify_account_storage_root_test() {
+    DebugApi::dummy();
+
+    let storage_root = mpt::verify_account_storage_root(
+        &byte_array_hex(vectors::STATE_ROOT),
+        &byte_array_hex(vectors::STORE_ADDRESS),
+        &managed_hex(vectors::ACCOUNT_PROOF),
+    );
+    assert_eq!(storage_root, byte_array_hex(vectors::STORAGE_ROOT));
+}
+
+#[test]
+fn verify_account_storage_root_wrong_state_root_test() {
+    DebugApi::dummy();
+    assert_user_error(
+        || {
+            let _ = mpt::verify_account_storage_root(
+                &byte_array_hex(vectors::STORAGE_ROOT),
+                &byte_array_hex(vectors::STORE_ADDRESS),
+                &managed_hex(vectors::ACCOUNT_PROOF),
+            );
+        },
+        "Invalid MPT proof",
+    );
+}
+
+#[test]
+fn verify_account_storage_root_unknown_account_test() {
+    DebugApi::dummy();
+    assert_user_error(
+        || {
+            let _ = mpt::verify_account_storage_root(
+                &byte_array_hex(vectors::STATE_ROOT),
+                &byte_array_hex(vectors::OTHER_ADDRESS),
+                &managed_hex(vectors::ACCOUNT_PROOF),
+            );
+        },
+        "Account not found",
+    );
+}
+
+#[test]
+fn verify_storage_value_test() {
+    DebugApi::dummy();
+
+    let storage_root: Hash<DebugApi> = byte_array_hex(vectors::STORAGE_ROOT);
+    assert_eq!(
+        mpt::verify_storage_value(
+            &storage_root,
+            &byte_array_hex(vectors::PACKET_SLOT),
+            &managed_hex(vectors::PACKET_PROOF),
+        ),
+        Some(managed_hex(vectors::PACKET_STORED_VALUE))
+    );
+    assert!(mpt::verify_storage_value(
+        &storage_root,
+        &byte_array_hex(vectors::ABSENT_SLOT),
+        &managed_hex(vectors::ABSENT_PROOF),
+    )
+    .is_none());
+}
+
+#[test]
+fn decode_proof_nodes_test() {
+    DebugApi::dummy();
+
+    let proof_nodes = mpt::decode_proof_nodes(&managed_hex(vectors::PUPPY_HORSE_PROOF));
+    assert_eq!(proof_nodes.len(), 2);
+    assert!(mpt::decode_proof_nodes::<DebugApi>(&managed_hex("c0")).is_empty());
+
+    let empty_proof = ManagedVec::<DebugApi, ManagedBuffer<DebugApi>>::new();
+    assert!(mpt::verify_proof(
+        &byte_array_hex(vectors::EMPTY_TRIE_ROOT),
+        &ManagedBuffer::new_from_bytes(b"dog"),
+        &empty_proof,
+    )
+    .is_none());
+}
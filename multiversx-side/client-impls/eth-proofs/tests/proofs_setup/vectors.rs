@@ -0,0 +1,33 @@
+//! Tries from the Ethereum trie tests (trietest.json), and proofs of an IBC store account in the eth_getProof format
+
+/// "puppy" trie: do -> verb, horse -> stallion, doge -> coin, dog -> puppy
+pub static PUPPY_ROOT: &str = "5991bb8c6514148a29db676a14ac506cd2cd5775ace63c30a4fe457715e9ac84";
+/// proof of "do", "dog", "doge" and of the absence of "dogs"
+pub static PUPPY_DOG_PROOF: &str = "f8c3a3e216a0bd3ee507e6c67cfefca98f84be47c1bbc009315fabc4405db4ba32190374572ab842f84080808080a094a9f95bd89698e4da1812e0518053813b4d5b87caaf6b3c6fa57e9e50c0ff68808080cf85206f727365887374616c6c696f6e8080808080808080a5e482006fa0d43b87fdcd4217013ccc92d04662e12d36e4cc25dc690077cd821a1956fc3e36b4f3808080808080de17dc808080808080c63584636f696e8080808080808080808570757070798080808080808080808476657262";
+/// proof of "horse" and of the absence of "cat" and "h"
+pub static PUPPY_HORSE_PROOF: &str = "f868a3e216a0bd3ee507e6c67cfefca98f84be47c1bbc009315fabc4405db4ba32190374572ab842f84080808080a094a9f95bd89698e4da1812e0518053813b4d5b87caaf6b3c6fa57e9e50c0ff68808080cf85206f727365887374616c6c696f6e8080808080808080";
+
+/// "smallValues" trie, with inline nodes: be -> e, dog -> puppy, bed -> d
+pub static SMALL_VALUES_ROOT: &str =
+    "3f67c7a47520f79faa29255d2d3c084a7a6df0453116ed7232ff10277a8be68b";
+/// proof of "be", "bed", "dog" and of the absence of "bee"
+pub static SMALL_VALUES_PROOF: &str = "f858a3e216a0dfa248cf59bfe3ba749d4aeb7c927f8dab8d5681ef81adef25d3634c30d6d35db3f28080d7820065d3808080808080c234648080808080808080806580ca83206f67857075707079808080808080808080808080";
+
+pub static EMPTY_TRIE_ROOT: &str =
+    "56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421";
+
+pub static STORE_ADDRESS: &str = "a1b2c3d4e5f60718293a4b5c6d7e8f9011223344";
+pub static OTHER_ADDRESS: &str = "9d829b80051421f1e706e522be1d7031576d2a46";
+pub static STORAGE_ROOT: &str = "61a50458bc101d9716b37ecc8a0254f9294cf180b8f06e3ea2c06e55318bc464";
+pub static STATE_ROOT: &str = "7b3ebffe2fcf699abda5d3075c7157a984e461347ffc09f158b3a9f5d7e3bf26";
+pub static ACCOUNT_PROOF: &str = "f90142b8d3f8d18080a0016e85c9a5d13ac353c44c832a2b55ea75be3fcf8a12b15732c9b73e921b44bb80808080a07c78c4cb24cf3b0db080242bccee8d595a9642e67dac365791f94311d5354ed78080a06931025c0a3c7b687bb99e54730fb8b32d1e7ed24be74b81ee4ce302b390ebd4a07c160b12dddc18be3734dbead5d8d0c880e3a549a5a42d0bab4c801ac37505d58080a03e272f515dfbf58c3b4d6f85c90c360edf867ba850c4e1a46779d6d66d5a88a5a067fea4d3b43abe14386af579a3cacdc1490184873dde0d39418f5e59319ea42e80b86bf869a031ee3838c831d51764f9af236c21646e869a15c0fc58e6cb0c0434a5deea40beb846f8440180a061a50458bc101d9716b37ecc8a0254f9294cf180b8f06e3ea2c06e55318bc464a0c97434ffff31dcb852bcff7bf4adf922b700173a56e257fc676a11950df62dae";
+
+/// commitment slot of "commitments/ports/mock/channels/channel-0/sequences/1"
+pub static PACKET_SLOT: &str = "74282a0329dca4fedf5d73a9ab303d08f6ded1ac45c59cff2a08faef874f525c";
+/// keccak256("packet-commitment"), without leading zeros
+pub static PACKET_STORED_VALUE: &str =
+    "2c2480b50911fba3f0efd45385ab09360e74978de645f781afb14e6bd2ddaf48";
+pub static PACKET_PROOF: &str = "f8dcb893f8918080a04fc5f13ab2f9ba0c2da88b0151ab0e7cf4d85d08cca45ccd923c6ab76323eb28a0e26209d09fd69ad8876077d4c834519d886a78146401cc114aca47b8acb74136a0cd457259696115235e64c7822334d62129e2f1604425a7da6494f35fc45be518808080808080a0960393adea7d53a967f1d24f052fc3faedec5847b68226478acf581188f37c5f8080808080b845f843a039159f54f65f113327ee7f312645acd5ae2ed678b0f8dae25462f756c5744e72a1a02c2480b50911fba3f0efd45385ab09360e74978de645f781afb14e6bd2ddaf48";
+/// commitment slot of "receipts/ports/mock/channels/channel-0/sequences/1"
+pub static ABSENT_SLOT: &str = "23f93949371f04141bb98260d8bf35e11bf47a474fac98170f840e1f1637f398";
+pub static ABSENT_PROOF: &str = "f895b893f8918080a04fc5f13ab2f9ba0c2da88b0151ab0e7cf4d85d08cca45ccd923c6ab76323eb28a0e26209d09fd69ad8876077d4c834519d886a78146401cc114aca47b8acb74136a0cd457259696115235e64c7822334d62129e2f1604425a7da6494f35fc45be518808080808080a0960393adea7d53a967f1d24f052fc3faedec5847b68226478acf581188f37c5f8080808080";
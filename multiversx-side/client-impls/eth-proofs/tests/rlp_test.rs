@@ -0,0 +1,199 @@
+use eth_proofs::rlp;
+use multiversx_sc::types::ManagedBuffer;
+use multiversx_sc_scenario::DebugApi;
+use proofs_setup::{assert_user_error, managed_hex};
+
+pub mod proofs_setup;
+
+// Vectors from the Ethereum RLP tests (rlptest.json)
+
+static LOREM_55: &[u8] = b"Lorem ipsum dolor sit amet, consectetur adipisicing eli";
+static LOREM_56: &[u8] = b"Lorem ipsum dolor sit amet, consectetur adipisicing elit";
+
+#[test]
+fn rlp_decode_bytes_test() {
+    DebugApi::dummy();
+
+    let vectors: [(&str, &[u8]); 7] = [
+        ("80", b""),
+        ("00", &[0x00]),
+        ("01", &[0x01]),
+        ("7f", &[0x7f]),
+        ("83646f67", b"dog"),
+        ("b74c6f72656d20697073756d20646f6c6f722073697420616d65742c20636f6e7365637465747572206164697069736963696e6720656c69", LOREM_55),
+        ("b8384c6f72656d20697073756d20646f6c6f722073697420616d65742c20636f6e7365637465747572206164697069736963696e6720656c6974", LOREM_56),
+    ];
+    for (encoded, expected) in vectors {
+        let encoded = managed_hex(encoded);
+        assert!(!rlp::is_list(&encoded));
+        assert_eq!(
+            rlp::decode_bytes(&encoded),
+            ManagedBuffer::<DebugApi>::new_from_bytes(expected)
+        );
+    }
+}
+
+#[test]
+fn rlp_decode_u64_test() {
+    DebugApi::dummy();
+
+    let vectors = [
+        ("80", 0),
+        ("01", 1),
+        ("10", 16),
+        ("4f", 79),
+        ("7f", 127),
+        ("8180", 128),
+        ("8203e8", 1_000),
+        ("830186a0", 100_000),
+        ("88ffffffffffffffff", u64::MAX),
+    ];
+    for (encoded, expected) in vectors {
+        assert_eq!(rlp::decode_u64(&managed_hex(encoded)), expected);
+    }
+}
+
+#[test]
+fn rlp_decode_list_test() {
+    DebugApi::dummy();
+
+    assert!(rlp::decode_list(&managed_hex("c0")).is_empty());
+
+    // ["dog", "god", "cat"]
+    let items = rlp::decode_list(&managed_hex("cc83646f6783676f6483636174"));
+    assert_eq!(items.len(), 3);
+    for (i, expected) in [b"dog", b"god", b"cat"].iter().enumerate() {
+        assert_eq!(
+            rlp::decode_bytes(&items.get(i)),
+            ManagedBuffer::new_from_bytes(*expected)
+        );
+    }
+
+    // ["zw", [4], 1]
+    let items = rlp::decode_list(&managed_hex("c6827a77c10401"));
+    assert_eq!(items.len(), 3);
+    assert_eq!(
+        rlp::decode_bytes(&items.get(0)),
+        ManagedBuffer::new_from_bytes(b"zw")
+    );
+    let inner_items = rlp::decode_list(&items.get(1));
+    assert_eq!(inner_items.len(), 1);
+    assert_eq!(rlp::decode_u64(&inner_items.get(0)), 4);
+    assert_eq!(rlp::decode_u64(&items.get(2)), 1);
+
+    // [ [ [], [] ], [] ]
+    let items = rlp::decode_list(&managed_hex("c4c2c0c0c0"));
+    assert_eq!(items.len(), 2);
+    assert_eq!(rlp::decode_list(&items.get(0)).len(), 2);
+    assert!(rlp::decode_list(&items.get(1)).is_empty());
+
+    // [ [], [[]], [ [], [[]] ] ]
+    let items = rlp::decode_list(&managed_hex("c7c0c1c0c3c0c1c0"));
+    assert_eq!(items.len(), 3);
+    assert!(rlp::decode_list(&items.get(0)).is_empty());
+    assert_eq!(rlp::decode_list(&items.get(1)).len(), 1);
+    assert_eq!(rlp::decode_list(&items.get(2)).len(), 2);
+}
+
+#[test]
+fn rlp_decode_long_list_test() {
+    DebugApi::dummy();
+
+    // [["key1", "val1"], ["key2", "val2"], ["key3", "val3"], ["key4", "val4"]]
+    let items = rlp::decode_list(&managed_hex("ecca846b6579318476616c31ca846b6579328476616c32ca846b6579338476616c33ca846b6579348476616c34"));
+    assert_eq!(items.len(), 4);
+    for (i, pair) in items.iter().enumerate() {
+        let pair_items = rlp::decode_list(&pair);
+        assert_eq!(pair_items.len(), 2);
+        assert_eq!(
+            rlp::decode_bytes(&pair_items.get(0)),
+            ManagedBuffer::new_from_bytes(format!("key{}", i + 1).as_bytes())
+        );
+        assert_eq!(
+            rlp::decode_bytes(&pair_items.get(1)),
+            ManagedBuffer::new_from_bytes(format!("val{}", i + 1).as_bytes())
+        );
+    }
+
+    // 4 * [["asdf", "qwer", "zxcv"]], with a long list prefix
+    let items = rlp::decode_list(&managed_hex("f840cf84617364668471776572847a786376cf84617364668471776572847a786376cf84617364668471776572847a786376cf84617364668471776572847a786376"));
+    assert_eq!(items.len(), 4);
+    for item in items.iter() {
+        let inner_items = rlp::decode_list(&item);
+        assert_eq!(inner_items.len(), 3);
+        assert_eq!(
+            rlp::decode_bytes(&inner_items.get(2)),
+            ManagedBuffer::new_from_bytes(b"zxcv")
+        );
+    }
+}
+
+#[test]
+fn rlp_invalid_bytes_test() {
+    DebugApi::dummy();
+
+    let vectors = [
+        // empty input
+        "",
+        // single bytes below 0x80 must be encoded as themselves
+        "8100",
+        "817f",
+        // payload shorter than its prefix announces
+        "83646f",
+        // trailing bytes
+        "83646f6700",
+        // strings shorter than 56 bytes must use the short form
+        "b80161",
+        // leading zeros in the length
+        "b90038",
+        // a list is not a byte string
+        "c0",
+    ];
+    for encoded in vectors {
+        assert_user_error(
+            || {
+                let _ = rlp::decode_bytes(&managed_hex(encoded));
+            },
+            "Invalid RLP encoding",
+        );
+    }
+}
+
+#[test]
+fn rlp_invalid_list_test() {
+    DebugApi::dummy();
+
+    let vectors = [
+        // item crosses the end of the list
+        "c88363617483646f",
+        // list announces more bytes than available
+        "c983636174",
+        // lists shorter than 56 bytes must use the short form
+        "f80183",
+        // a byte string is not a list
+        "83646f67",
+    ];
+    for encoded in vectors {
+        assert_user_error(
+            || {
+                let _ = rlp::decode_list(&managed_hex(encoded));
+            },
+            "Invalid RLP encoding",
+        );
+    }
+}
+
+#[test]
+fn rlp_invalid_u64_test() {
+    DebugApi::dummy();
+
+    // leading zeros, and more than 8 bytes
+    for encoded in ["820004", "89010000000000000000"] {
+        assert_user_error(
+            || {
+                let _ = rlp::decode_u64(&managed_hex(encoded));
+            },
+            "Invalid RLP encoding",
+        );
+    }
+}
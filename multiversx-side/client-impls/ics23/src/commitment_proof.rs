@@ -0,0 +1,348 @@
+use common_types::proto::{self, Field, FieldReader};
+use multiversx_sc::{
+    api::ManagedTypeApi,
+    derive::ManagedVecItem,
+    types::{ManagedBuffer, ManagedVec},
+};
+
+use crate::{require_valid_proof, signal_error, INVALID_PROOF_ERR_MSG};
+
+pub static COMPRESSED_PROOFS_NOT_SUPPORTED_ERR_MSG: &[u8] = b"Compressed proofs are not supported";
+
+/// Variants are in protobuf enum order
+#[derive(ManagedVecItem, Clone, Copy, PartialEq, Debug)]
+pub enum HashOp {
+    NoHash,
+    Sha256,
+    Sha512,
+    Keccak256,
+    Ripemd160,
+    Bitcoin,
+    Sha512_256,
+    Blake2b512,
+    Blake2s256,
+    Blake3,
+}
+
+impl HashOp {
+    pub fn from_proto<M: ManagedTypeApi>(value: u64) -> Self {
+        match value {
+            0 => HashOp::NoHash,
+            1 => HashOp::Sha256,
+            2 => HashOp::Sha512,
+            3 => HashOp::Keccak256,
+            4 => HashOp::Ripemd160,
+            5 => HashOp::Bitcoin,
+            6 => HashOp::Sha512_256,
+            7 => HashOp::Blake2b512,
+            8 => HashOp::Blake2s256,
+            9 => HashOp::Blake3,
+            _ => signal_error::<M>(INVALID_PROOF_ERR_MSG),
+        }
+    }
+
+    pub fn to_proto(self) -> u64 {
+        self as u64
+    }
+}
+
+/// Variants are in protobuf enum order
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum LengthOp {
+    NoPrefix,
+    VarProto,
+    VarRlp,
+    Fixed32Big,
+    Fixed32Little,
+    Fixed64Big,
+    Fixed64Little,
+    Require32Bytes,
+    Require64Bytes,
+}
+
+impl LengthOp {
+    pub fn from_proto<M: ManagedTypeApi>(value: u64) -> Self {
+        match value {
+            0 => LengthOp::NoPrefix,
+            1 => LengthOp::VarProto,
+            2 => LengthOp::VarRlp,
+            3 => LengthOp::Fixed32Big,
+            4 => LengthOp::Fixed32Little,
+            5 => LengthOp::Fixed64Big,
+            6 => LengthOp::Fixed64Little,
+            7 => LengthOp::Require32Bytes,
+            8 => LengthOp::Require64Bytes,
+            _ => signal_error::<M>(INVALID_PROOF_ERR_MSG),
+        }
+    }
+
+    pub fn to_proto(self) -> u64 {
+        self as u64
+    }
+}
+
+/// leaf hash = hash(prefix || length(prehash_key(key)) || length(prehash_value(value)))
+#[derive(Clone)]
+pub struct LeafOp<M: ManagedTypeApi> {
+    pub hash: HashOp,
+    pub prehash_key: HashOp,
+    pub prehash_value: HashOp,
+    pub length: LengthOp,
+    pub prefix: ManagedBuffer<M>,
+}
+
+/// inner hash = hash(prefix || child || suffix)
+#[derive(ManagedVecItem, Clone)]
+pub struct InnerOp<M: ManagedTypeApi> {
+    pub hash: HashOp,
+    pub prefix: ManagedBuffer<M>,
+    pub suffix: ManagedBuffer<M>,
+}
+
+impl<M: ManagedTypeApi> PartialEq for LeafOp<M> {
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash
+            && self.prehash_key == other.prehash_key
+            && self.prehash_value == other.prehash_value
+            && self.length == other.length
+            && self.prefix == other.prefix
+    }
+}
+
+/// Proves that `key` is set to `value` in the tree, with the path ordered from the leaf to the root
+pub struct ExistenceProof<M: ManagedTypeApi> {
+    pub key: ManagedBuffer<M>,
+    pub value: ManagedBuffer<M>,
+    pub leaf: LeafOp<M>,
+    pub path: ManagedVec<M, InnerOp<M>>,
+}
+
+/// Proves that `key` is not in the tree, through the existence of its neighbours
+///
+/// One of the neighbours is missing if `key` is before the first key or after the last key
+pub struct NonExistenceProof<M: ManagedTypeApi> {
+    pub key: ManagedBuffer<M>,
+    pub left: Option<ExistenceProof<M>>,
+    pub right: Option<ExistenceProof<M>>,
+}
+
+pub enum BatchEntry<M: ManagedTypeApi> {
+    Exist(ExistenceProof<M>),
+    Nonexist(NonExistenceProof<M>),
+}
+
+pub enum CommitmentProof<M: ManagedTypeApi> {
+    Exist(ExistenceProof<M>),
+    Nonexist(NonExistenceProof<M>),
+    /// the encoded batch entries, decoded lazily when looking up a key
+    Batch(ManagedVec<M, ManagedBuffer<M>>),
+}
+
+impl<M: ManagedTypeApi> CommitmentProof<M> {
+    /// Returns the existence proof for `key`, if any
+    pub fn get_existence_proof(self, key: &ManagedBuffer<M>) -> Option<ExistenceProof<M>> {
+        match self {
+            CommitmentProof::Exist(proof) => {
+                if &proof.key == key {
+                    Some(proof)
+                } else {
+                    None
+                }
+            }
+            CommitmentProof::Nonexist(_) => None,
+            CommitmentProof::Batch(entries) => {
+                entries
+                    .iter()
+                    .find_map(|entry| match decode_batch_entry(&entry) {
+                        BatchEntry::Exist(proof) if &proof.key == key => Some(proof),
+                        _ => None,
+                    })
+            }
+        }
+    }
+
+    /// Returns the non-existence proof for `key`, if any
+    pub fn get_non_existence_proof(self, key: &ManagedBuffer<M>) -> Option<NonExistenceProof<M>> {
+        match self {
+            CommitmentProof::Exist(_) => None,
+            CommitmentProof::Nonexist(proof) => {
+                if &proof.key == key {
+                    Some(proof)
+                } else {
+                    None
+                }
+            }
+            CommitmentProof::Batch(entries) => {
+                entries
+                    .iter()
+                    .find_map(|entry| match decode_batch_entry(&entry) {
+                        BatchEntry::Nonexist(proof) if &proof.key == key => Some(proof),
+                        _ => None,
+                    })
+            }
+        }
+    }
+}
+
+/// Decodes a protobuf encoded `ics23.CommitmentProof`
+pub fn decode_commitment_proof<M: ManagedTypeApi>(
+    encoded: &ManagedBuffer<M>,
+) -> CommitmentProof<M> {
+    let mut proof = None;
+    for Field { number, value } in FieldReader::new(encoded) {
+        proof = match number {
+            1 => Some(CommitmentProof::Exist(decode_existence_proof(
+                &value.into_bytes(),
+            ))),
+            2 => Some(CommitmentProof::Nonexist(decode_non_existence_proof(
+                &value.into_bytes(),
+            ))),
+            3 => Some(CommitmentProof::Batch(decode_batch_entries(
+                &value.into_bytes(),
+            ))),
+            4 => signal_error::<M>(COMPRESSED_PROOFS_NOT_SUPPORTED_ERR_MSG),
+            _ => proof,
+        };
+    }
+
+    match proof {
+        Some(proof) => proof,
+        None => signal_error::<M>(INVALID_PROOF_ERR_MSG),
+    }
+}
+
+pub fn decode_existence_proof<M: ManagedTypeApi>(encoded: &ManagedBuffer<M>) -> ExistenceProof<M> {
+    let mut key = ManagedBuffer::new();
+    let mut value = ManagedBuffer::new();
+    let mut leaf = None;
+    let mut path = ManagedVec::new();
+    for Field {
+        number,
+        value: field,
+    } in FieldReader::new(encoded)
+    {
+        match number {
+            1 => key = field.into_bytes(),
+            2 => value = field.into_bytes(),
+            3 => leaf = Some(decode_leaf_op(&field.into_bytes())),
+            4 => path.push(decode_inner_op(&field.into_bytes())),
+            _ => {}
+        }
+    }
+
+    match leaf {
+        Some(leaf) => ExistenceProof {
+            key,
+            value,
+            leaf,
+            path,
+        },
+        None => signal_error::<M>(INVALID_PROOF_ERR_MSG),
+    }
+}
+
+pub fn decode_non_existence_proof<M: ManagedTypeApi>(
+    encoded: &ManagedBuffer<M>,
+) -> NonExistenceProof<M> {
+    let mut proof = NonExistenceProof {
+        key: ManagedBuffer::new(),
+        left: None,
+        right: None,
+    };
+    for Field { number, value } in FieldReader::new(encoded) {
+        match number {
+            1 => proof.key = value.into_bytes(),
+            2 => proof.left = Some(decode_existence_proof(&value.into_bytes())),
+            3 => proof.right = Some(decode_existence_proof(&value.into_bytes())),
+            _ => {}
+        }
+    }
+    require_valid_proof::<M>(proof.left.is_some() || proof.right.is_some());
+
+    proof
+}
+
+pub fn decode_leaf_op<M: ManagedTypeApi>(encoded: &ManagedBuffer<M>) -> LeafOp<M> {
+    let mut leaf = LeafOp {
+        hash: HashOp::NoHash,
+        prehash_key: HashOp::NoHash,
+        prehash_value: HashOp::NoHash,
+        length: LengthOp::NoPrefix,
+        prefix: ManagedBuffer::new(),
+    };
+    for Field { number, value } in FieldReader::new(encoded) {
+        match number {
+            1 => leaf.hash = HashOp::from_proto::<M>(value.into_varint()),
+            2 => leaf.prehash_key = HashOp::from_proto::<M>(value.into_varint()),
+            3 => leaf.prehash_value = HashOp::from_proto::<M>(value.into_varint()),
+            4 => leaf.length = LengthOp::from_proto::<M>(value.into_varint()),
+            5 => leaf.prefix = value.into_bytes(),
+            _ => {}
+        }
+    }
+
+    leaf
+}
+
+pub fn encode_leaf_op<M: ManagedTypeApi>(leaf: &LeafOp<M>) -> ManagedBuffer<M> {
+    let mut encoded = ManagedBuffer::new();
+    proto::append_uint_field(&mut encoded, 1, leaf.hash.to_proto());
+    proto::append_uint_field(&mut encoded, 2, leaf.prehash_key.to_proto());
+    proto::append_uint_field(&mut encoded, 3, leaf.prehash_value.to_proto());
+    proto::append_uint_field(&mut encoded, 4, leaf.length.to_proto());
+    proto::append_bytes_field(&mut encoded, 5, &leaf.prefix);
+
+    encoded
+}
+
+pub fn decode_inner_op<M: ManagedTypeApi>(encoded: &ManagedBuffer<M>) -> InnerOp<M> {
+    let mut inner = InnerOp {
+        hash: HashOp::NoHash,
+        prefix: ManagedBuffer::new(),
+        suffix: ManagedBuffer::new(),
+    };
+    for Field { number, value } in FieldReader::new(encoded) {
+        match number {
+            1 => inner.hash = HashOp::from_proto::<M>(value.into_varint()),
+            2 => inner.prefix = value.into_bytes(),
+            3 => inner.suffix = value.into_bytes(),
+            _ => {}
+        }
+    }
+
+    inner
+}
+
+pub fn decode_batch_entry<M: ManagedTypeApi>(encoded: &ManagedBuffer<M>) -> BatchEntry<M> {
+    let mut entry = None;
+    for Field { number, value } in FieldReader::new(encoded) {
+        entry = match number {
+            1 => Some(BatchEntry::Exist(decode_existence_proof(
+                &value.into_bytes(),
+            ))),
+            2 => Some(BatchEntry::Nonexist(decode_non_existence_proof(
+                &value.into_bytes(),
+            ))),
+            _ => entry,
+        };
+    }
+
+    match entry {
+        Some(entry) => entry,
+        None => signal_error::<M>(INVALID_PROOF_ERR_MSG),
+    }
+}
+
+/// Returns the encoded entries of a `ics23.BatchProof`
+fn decode_batch_entries<M: ManagedTypeApi>(
+    encoded: &ManagedBuffer<M>,
+) -> ManagedVec<M, ManagedBuffer<M>> {
+    let mut entries = ManagedVec::new();
+    for Field { number, value } in FieldReader::new(encoded) {
+        if number == 1 {
+            entries.push(value.into_bytes());
+        }
+    }
+
+    entries
+}
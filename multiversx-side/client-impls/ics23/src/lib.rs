@@ -0,0 +1,24 @@
+#![no_std]
+
+//! ICS-23 vector commitment proof verification over managed types,
+//! shared by the client implementations of chains which store the IBC state in IAVL or simple merkle trees
+
+use multiversx_sc::api::{ErrorApiImpl, ManagedTypeApi};
+
+pub mod commitment_proof;
+pub mod merkle;
+pub mod ops;
+pub mod specs;
+pub mod verify;
+
+pub static INVALID_PROOF_ERR_MSG: &[u8] = b"Invalid commitment proof";
+
+fn signal_error<M: ManagedTypeApi>(message: &[u8]) -> ! {
+    M::error_api_impl().signal_error(message)
+}
+
+fn require_valid_proof<M: ManagedTypeApi>(condition: bool) {
+    if !condition {
+        signal_error::<M>(INVALID_PROOF_ERR_MSG);
+    }
+}
@@ -0,0 +1,104 @@
+use common_types::proto::{Field, FieldReader};
+use multiversx_sc::{
+    api::{CryptoApi, ManagedTypeApi},
+    types::{ManagedBuffer, ManagedVec},
+};
+
+use crate::{commitment_proof::decode_commitment_proof, specs::ProofSpec, verify};
+
+/// Verifies an IBC `MerkleProof` that `value` is stored under the key path, from the innermost store up to `root`
+///
+/// `key_path` is ordered from the outermost key, e.g. [prefix, path], and one proof per spec is expected, innermost first.
+pub fn verify_membership<M: ManagedTypeApi + CryptoApi>(
+    specs: &[ProofSpec<M>],
+    root: &ManagedBuffer<M>,
+    merkle_proof: &ManagedBuffer<M>,
+    key_path: &ManagedVec<M, ManagedBuffer<M>>,
+    value: &ManagedBuffer<M>,
+) -> bool {
+    let proofs = decode_merkle_proof(merkle_proof);
+    if proofs.len() != specs.len() || key_path.len() != specs.len() {
+        return false;
+    }
+
+    verify_chained_membership(specs, root, &proofs, key_path, value.clone())
+}
+
+/// Verifies an IBC `MerkleProof` that nothing is stored under the key path
+///
+/// The innermost proof is a non-existence proof, the others prove the existence of the innermost store's root.
+pub fn verify_non_membership<M: ManagedTypeApi + CryptoApi>(
+    specs: &[ProofSpec<M>],
+    root: &ManagedBuffer<M>,
+    merkle_proof: &ManagedBuffer<M>,
+    key_path: &ManagedVec<M, ManagedBuffer<M>>,
+) -> bool {
+    let proofs = decode_merkle_proof(merkle_proof);
+    if specs.is_empty() || proofs.len() != specs.len() || key_path.len() != specs.len() {
+        return false;
+    }
+
+    let key = key_path.get(key_path.len() - 1);
+    let non_existence_proof =
+        match decode_commitment_proof(&proofs.get(0)).get_non_existence_proof(&key) {
+            Some(non_existence_proof) => non_existence_proof,
+            None => return false,
+        };
+    let neighbour = match (&non_existence_proof.left, &non_existence_proof.right) {
+        (Some(neighbour), _) | (None, Some(neighbour)) => neighbour,
+        (None, None) => return false,
+    };
+    let subroot = verify::calculate_existence_root(neighbour);
+    if !verify::verify_non_existence(&non_existence_proof, &specs[0], &subroot, &key) {
+        return false;
+    }
+
+    verify_chained_membership(
+        &specs[1..],
+        root,
+        &proofs.slice(1, proofs.len()).unwrap_or_default(),
+        &key_path.slice(0, key_path.len() - 1).unwrap_or_default(),
+        subroot,
+    )
+}
+
+/// Decodes a protobuf encoded `ibc.core.commitment.v1.MerkleProof` into the encoded commitment proofs
+pub fn decode_merkle_proof<M: ManagedTypeApi>(
+    merkle_proof: &ManagedBuffer<M>,
+) -> ManagedVec<M, ManagedBuffer<M>> {
+    let mut proofs = ManagedVec::new();
+    for Field { number, value } in FieldReader::new(merkle_proof) {
+        if number == 1 {
+            proofs.push(value.into_bytes());
+        }
+    }
+
+    proofs
+}
+
+/// Each proof proves the existence of the root computed by the previous one, the last one against `root`
+fn verify_chained_membership<M: ManagedTypeApi + CryptoApi>(
+    specs: &[ProofSpec<M>],
+    root: &ManagedBuffer<M>,
+    proofs: &ManagedVec<M, ManagedBuffer<M>>,
+    key_path: &ManagedVec<M, ManagedBuffer<M>>,
+    mut value: ManagedBuffer<M>,
+) -> bool {
+    for (i, spec) in specs.iter().enumerate() {
+        let key = key_path.get(key_path.len() - 1 - i);
+        let existence_proof =
+            match decode_commitment_proof(&proofs.get(i)).get_existence_proof(&key) {
+                Some(existence_proof) => existence_proof,
+                None => return false,
+            };
+
+        let subroot = verify::calculate_existence_root(&existence_proof);
+        if !verify::verify_existence(&existence_proof, spec, &subroot, &key, &value) {
+            return false;
+        }
+
+        value = subroot;
+    }
+
+    &value == root
+}
@@ -0,0 +1,114 @@
+use multiversx_sc::{
+    api::{CryptoApi, ManagedTypeApi},
+    contract_base::CryptoWrapper,
+    types::ManagedBuffer,
+};
+
+use crate::{
+    commitment_proof::{HashOp, InnerOp, LeafOp, LengthOp},
+    require_valid_proof, signal_error,
+};
+
+const REQUIRED_32_BYTES: usize = 32;
+const REQUIRED_64_BYTES: usize = 64;
+const MAX_VARINT_LEN: usize = 10;
+
+pub static UNSUPPORTED_HASH_OP_ERR_MSG: &[u8] = b"Unsupported hash operation";
+pub static UNSUPPORTED_LENGTH_OP_ERR_MSG: &[u8] = b"Unsupported length operation";
+
+/// Computes the leaf hash of the `key` - `value` pair
+pub fn apply_leaf<M: ManagedTypeApi + CryptoApi>(
+    leaf: &LeafOp<M>,
+    key: &ManagedBuffer<M>,
+    value: &ManagedBuffer<M>,
+) -> ManagedBuffer<M> {
+    require_valid_proof::<M>(!key.is_empty() && !value.is_empty());
+
+    let mut data = leaf.prefix.clone();
+    data.append(&prepare_leaf_data(leaf.prehash_key, leaf.length, key));
+    data.append(&prepare_leaf_data(leaf.prehash_value, leaf.length, value));
+
+    do_hash(leaf.hash, &data)
+}
+
+/// Computes the hash of the inner node containing `child`
+pub fn apply_inner<M: ManagedTypeApi + CryptoApi>(
+    inner: &InnerOp<M>,
+    child: &ManagedBuffer<M>,
+) -> ManagedBuffer<M> {
+    require_valid_proof::<M>(!child.is_empty());
+
+    let mut data = inner.prefix.clone();
+    data.append(child);
+    data.append(&inner.suffix);
+
+    do_hash(inner.hash, &data)
+}
+
+pub fn do_hash<M: ManagedTypeApi + CryptoApi>(
+    hash_op: HashOp,
+    data: &ManagedBuffer<M>,
+) -> ManagedBuffer<M> {
+    let crypto = CryptoWrapper::<M>::new();
+    match hash_op {
+        HashOp::NoHash => data.clone(),
+        HashOp::Sha256 => crypto.sha256(data).as_managed_buffer().clone(),
+        HashOp::Keccak256 => crypto.keccak256(data).as_managed_buffer().clone(),
+        HashOp::Ripemd160 => crypto.ripemd160(data).as_managed_buffer().clone(),
+        HashOp::Bitcoin => crypto
+            .ripemd160(crypto.sha256(data).as_managed_buffer())
+            .as_managed_buffer()
+            .clone(),
+        _ => signal_error::<M>(UNSUPPORTED_HASH_OP_ERR_MSG),
+    }
+}
+
+fn prepare_leaf_data<M: ManagedTypeApi + CryptoApi>(
+    prehash: HashOp,
+    length: LengthOp,
+    data: &ManagedBuffer<M>,
+) -> ManagedBuffer<M> {
+    let hashed = do_hash(prehash, data);
+
+    do_length_op(length, &hashed)
+}
+
+fn do_length_op<M: ManagedTypeApi>(
+    length_op: LengthOp,
+    data: &ManagedBuffer<M>,
+) -> ManagedBuffer<M> {
+    let len = data.len();
+    let mut prefixed = match length_op {
+        LengthOp::NoPrefix => return data.clone(),
+        LengthOp::Require32Bytes => {
+            require_valid_proof::<M>(len == REQUIRED_32_BYTES);
+            return data.clone();
+        }
+        LengthOp::Require64Bytes => {
+            require_valid_proof::<M>(len == REQUIRED_64_BYTES);
+            return data.clone();
+        }
+        LengthOp::VarProto => encode_varint(len as u64),
+        LengthOp::Fixed32Big => ManagedBuffer::new_from_bytes(&(len as u32).to_be_bytes()),
+        LengthOp::Fixed32Little => ManagedBuffer::new_from_bytes(&(len as u32).to_le_bytes()),
+        LengthOp::Fixed64Big => ManagedBuffer::new_from_bytes(&(len as u64).to_be_bytes()),
+        LengthOp::Fixed64Little => ManagedBuffer::new_from_bytes(&(len as u64).to_le_bytes()),
+        LengthOp::VarRlp => signal_error::<M>(UNSUPPORTED_LENGTH_OP_ERR_MSG),
+    };
+    prefixed.append(data);
+
+    prefixed
+}
+
+fn encode_varint<M: ManagedTypeApi>(mut value: u64) -> ManagedBuffer<M> {
+    let mut bytes = [0u8; MAX_VARINT_LEN];
+    let mut len = 0;
+    while value >= 0x80 {
+        bytes[len] = (value as u8) | 0x80;
+        value >>= 7;
+        len += 1;
+    }
+    bytes[len] = value as u8;
+
+    ManagedBuffer::new_from_bytes(&bytes[..=len])
+}
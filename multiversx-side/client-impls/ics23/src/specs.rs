@@ -0,0 +1,152 @@
+use common_types::proto;
+use multiversx_sc::{
+    api::ManagedTypeApi,
+    types::{ManagedBuffer, ManagedVec},
+};
+
+use crate::commitment_proof::{encode_leaf_op, HashOp, LeafOp, LengthOp};
+
+const IAVL_CHILD_SIZE: usize = 33;
+const IAVL_MIN_PREFIX_LENGTH: usize = 4;
+const IAVL_MAX_PREFIX_LENGTH: usize = 12;
+const TENDERMINT_CHILD_SIZE: usize = 32;
+const TENDERMINT_PREFIX_LENGTH: usize = 1;
+const LEAF_PREFIX: &[u8] = &[0];
+
+/// Describes the tree layout, so that proofs of a different tree can't be replayed
+#[derive(Clone)]
+pub struct ProofSpec<M: ManagedTypeApi> {
+    pub leaf_spec: LeafOp<M>,
+    pub inner_spec: InnerSpec<M>,
+    /// 0 means no limit
+    pub max_depth: usize,
+    pub min_depth: usize,
+    pub prehash_key_before_comparison: bool,
+}
+
+/// Layout of the inner nodes, which have `child_order.len()` children of `child_size` bytes
+#[derive(Clone)]
+pub struct InnerSpec<M: ManagedTypeApi> {
+    /// the order in which the children are hashed, e.g. [0, 1] for left then right
+    pub child_order: ManagedVec<M, u32>,
+    pub child_size: usize,
+    pub min_prefix_length: usize,
+    pub max_prefix_length: usize,
+    /// the value of an empty child, empty if a child can't be empty
+    pub empty_child: ManagedBuffer<M>,
+    pub hash: HashOp,
+}
+
+impl<M: ManagedTypeApi> PartialEq for ProofSpec<M> {
+    fn eq(&self, other: &Self) -> bool {
+        self.leaf_spec == other.leaf_spec
+            && self.inner_spec == other.inner_spec
+            && self.max_depth == other.max_depth
+            && self.min_depth == other.min_depth
+            && self.prehash_key_before_comparison == other.prehash_key_before_comparison
+    }
+}
+
+impl<M: ManagedTypeApi> PartialEq for InnerSpec<M> {
+    fn eq(&self, other: &Self) -> bool {
+        self.child_order == other.child_order
+            && self.child_size == other.child_size
+            && self.min_prefix_length == other.min_prefix_length
+            && self.max_prefix_length == other.max_prefix_length
+            && self.empty_child == other.empty_child
+            && self.hash == other.hash
+    }
+}
+
+/// Spec of the IAVL trees of the Cosmos SDK stores
+pub fn iavl_spec<M: ManagedTypeApi>() -> ProofSpec<M> {
+    ProofSpec {
+        leaf_spec: sha256_leaf_spec(),
+        inner_spec: InnerSpec {
+            child_order: binary_child_order(),
+            child_size: IAVL_CHILD_SIZE,
+            min_prefix_length: IAVL_MIN_PREFIX_LENGTH,
+            max_prefix_length: IAVL_MAX_PREFIX_LENGTH,
+            empty_child: ManagedBuffer::new(),
+            hash: HashOp::Sha256,
+        },
+        max_depth: 0,
+        min_depth: 0,
+        prehash_key_before_comparison: false,
+    }
+}
+
+/// Spec of the simple merkle trees of Tendermint, used for the Cosmos SDK multistore
+pub fn tendermint_spec<M: ManagedTypeApi>() -> ProofSpec<M> {
+    ProofSpec {
+        leaf_spec: sha256_leaf_spec(),
+        inner_spec: InnerSpec {
+            child_order: binary_child_order(),
+            child_size: TENDERMINT_CHILD_SIZE,
+            min_prefix_length: TENDERMINT_PREFIX_LENGTH,
+            max_prefix_length: TENDERMINT_PREFIX_LENGTH,
+            empty_child: ManagedBuffer::new(),
+            hash: HashOp::Sha256,
+        },
+        max_depth: 0,
+        min_depth: 0,
+        prehash_key_before_comparison: false,
+    }
+}
+
+/// Specs of the proofs of a Cosmos SDK chain: the IAVL store proof first, then the multistore proof
+pub fn sdk_specs<M: ManagedTypeApi>() -> [ProofSpec<M>; 2] {
+    [iavl_spec(), tendermint_spec()]
+}
+
+pub fn is_iavl_spec<M: ManagedTypeApi>(spec: &ProofSpec<M>) -> bool {
+    spec == &iavl_spec()
+}
+
+/// ics23.ProofSpec, as listed in the client states of ibc-go
+pub fn encode_proof_spec<M: ManagedTypeApi>(spec: &ProofSpec<M>) -> ManagedBuffer<M> {
+    let mut encoded = ManagedBuffer::new();
+    proto::append_message_field(&mut encoded, 1, &encode_leaf_op(&spec.leaf_spec));
+    proto::append_message_field(&mut encoded, 2, &encode_inner_spec(&spec.inner_spec));
+    proto::append_uint_field(&mut encoded, 3, spec.max_depth as u64);
+    proto::append_uint_field(&mut encoded, 4, spec.min_depth as u64);
+    proto::append_uint_field(&mut encoded, 5, spec.prehash_key_before_comparison as u64);
+
+    encoded
+}
+
+fn encode_inner_spec<M: ManagedTypeApi>(spec: &InnerSpec<M>) -> ManagedBuffer<M> {
+    // repeated scalars are packed
+    let mut child_order = ManagedBuffer::new();
+    for child in spec.child_order.iter() {
+        proto::append_varint(&mut child_order, child as u64);
+    }
+
+    let mut encoded = ManagedBuffer::new();
+    proto::append_bytes_field(&mut encoded, 1, &child_order);
+    proto::append_uint_field(&mut encoded, 2, spec.child_size as u64);
+    proto::append_uint_field(&mut encoded, 3, spec.min_prefix_length as u64);
+    proto::append_uint_field(&mut encoded, 4, spec.max_prefix_length as u64);
+    proto::append_bytes_field(&mut encoded, 5, &spec.empty_child);
+    proto::append_uint_field(&mut encoded, 6, spec.hash.to_proto());
+
+    encoded
+}
+
+fn sha256_leaf_spec<M: ManagedTypeApi>() -> LeafOp<M> {
+    LeafOp {
+        hash: HashOp::Sha256,
+        prehash_key: HashOp::NoHash,
+        prehash_value: HashOp::Sha256,
+        length: LengthOp::VarProto,
+        prefix: ManagedBuffer::new_from_bytes(LEAF_PREFIX),
+    }
+}
+
+fn binary_child_order<M: ManagedTypeApi>() -> ManagedVec<M, u32> {
+    let mut child_order = ManagedVec::new();
+    child_order.push(0);
+    child_order.push(1);
+
+    child_order
+}
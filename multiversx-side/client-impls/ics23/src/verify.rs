@@ -0,0 +1,391 @@
+use core::cmp::Ordering;
+
+use common_types::proto;
+use multiversx_sc::{
+    api::{CryptoApi, ManagedTypeApi},
+    types::{ManagedBuffer, ManagedVec},
+};
+
+use crate::{
+    commitment_proof::{CommitmentProof, ExistenceProof, InnerOp, LeafOp, NonExistenceProof},
+    ops,
+    specs::{is_iavl_spec, InnerSpec, ProofSpec},
+};
+
+const COMPARE_CHUNK_LEN: usize = 32;
+/// after the height, size and version: the length prefix of the left child hash
+const IAVL_LEFT_CHILD_PREFIX_REMAINDER: usize = 1;
+/// after the height, size and version: the left child hash and the length prefixes of both hashes
+const IAVL_RIGHT_CHILD_PREFIX_REMAINDER: usize = 34;
+
+/// Verifies that `key` is set to `value` in the tree with the given root
+///
+/// Batch proofs are searched for an existence proof of `key`
+pub fn verify_membership<M: ManagedTypeApi + CryptoApi>(
+    spec: &ProofSpec<M>,
+    root: &ManagedBuffer<M>,
+    proof: CommitmentProof<M>,
+    key: &ManagedBuffer<M>,
+    value: &ManagedBuffer<M>,
+) -> bool {
+    match proof.get_existence_proof(key) {
+        Some(existence_proof) => verify_existence(&existence_proof, spec, root, key, value),
+        None => false,
+    }
+}
+
+/// Verifies that `key` is not in the tree with the given root
+///
+/// Batch proofs are searched for a non-existence proof of `key`
+pub fn verify_non_membership<M: ManagedTypeApi + CryptoApi>(
+    spec: &ProofSpec<M>,
+    root: &ManagedBuffer<M>,
+    proof: CommitmentProof<M>,
+    key: &ManagedBuffer<M>,
+) -> bool {
+    match proof.get_non_existence_proof(key) {
+        Some(non_existence_proof) => verify_non_existence(&non_existence_proof, spec, root, key),
+        None => false,
+    }
+}
+
+pub fn verify_existence<M: ManagedTypeApi + CryptoApi>(
+    proof: &ExistenceProof<M>,
+    spec: &ProofSpec<M>,
+    root: &ManagedBuffer<M>,
+    key: &ManagedBuffer<M>,
+    value: &ManagedBuffer<M>,
+) -> bool {
+    check_existence_against_spec(proof, spec)
+        && &proof.key == key
+        && &proof.value == value
+        && &calculate_existence_root(proof) == root
+}
+
+pub fn verify_non_existence<M: ManagedTypeApi + CryptoApi>(
+    proof: &NonExistenceProof<M>,
+    spec: &ProofSpec<M>,
+    root: &ManagedBuffer<M>,
+    key: &ManagedBuffer<M>,
+) -> bool {
+    if let Some(left) = &proof.left {
+        if !verify_existence(left, spec, root, &left.key, &left.value)
+            || compare_keys(spec, key, &left.key) != Ordering::Greater
+        {
+            return false;
+        }
+    }
+    if let Some(right) = &proof.right {
+        if !verify_existence(right, spec, root, &right.key, &right.value)
+            || compare_keys(spec, key, &right.key) != Ordering::Less
+        {
+            return false;
+        }
+    }
+
+    match (&proof.left, &proof.right) {
+        (None, Some(right)) => is_left_most(&spec.inner_spec, &right.path, right.path.len()),
+        (Some(left), None) => is_right_most(&spec.inner_spec, &left.path, left.path.len()),
+        (Some(left), Some(right)) => is_left_neighbor(&spec.inner_spec, &left.path, &right.path),
+        (None, None) => false,
+    }
+}
+
+/// Computes the root of the tree from the leaf and the path of the proof
+pub fn calculate_existence_root<M: ManagedTypeApi + CryptoApi>(
+    proof: &ExistenceProof<M>,
+) -> ManagedBuffer<M> {
+    let mut root = ops::apply_leaf(&proof.leaf, &proof.key, &proof.value);
+    for inner in &proof.path {
+        root = ops::apply_inner(&inner, &root);
+    }
+
+    root
+}
+
+fn check_existence_against_spec<M: ManagedTypeApi>(
+    proof: &ExistenceProof<M>,
+    spec: &ProofSpec<M>,
+) -> bool {
+    let path_len = proof.path.len();
+    if !check_leaf_against_spec(&proof.leaf, spec)
+        || (spec.min_depth > 0 && path_len < spec.min_depth)
+        || (spec.max_depth > 0 && path_len > spec.max_depth)
+    {
+        return false;
+    }
+
+    proof
+        .path
+        .iter()
+        .enumerate()
+        .all(|(i, inner)| check_inner_against_spec(&inner, spec, i + 1))
+}
+
+fn check_leaf_against_spec<M: ManagedTypeApi>(leaf: &LeafOp<M>, spec: &ProofSpec<M>) -> bool {
+    let leaf_spec = &spec.leaf_spec;
+    if is_iavl_spec(spec) && !is_valid_iavl_prefix(&leaf.prefix, 0) {
+        return false;
+    }
+
+    leaf.hash == leaf_spec.hash
+        && leaf.prehash_key == leaf_spec.prehash_key
+        && leaf.prehash_value == leaf_spec.prehash_value
+        && leaf.length == leaf_spec.length
+        && has_prefix(&leaf.prefix, &leaf_spec.prefix)
+}
+
+fn check_inner_against_spec<M: ManagedTypeApi>(
+    inner: &InnerOp<M>,
+    spec: &ProofSpec<M>,
+    layer: usize,
+) -> bool {
+    let inner_spec = &spec.inner_spec;
+    if is_iavl_spec(spec) && !is_valid_iavl_prefix(&inner.prefix, layer) {
+        return false;
+    }
+
+    // inner nodes must not be mistaken for leaves
+    let leaf_prefix = &spec.leaf_spec.prefix;
+    if !leaf_prefix.is_empty() && has_prefix(&inner.prefix, leaf_prefix) {
+        return false;
+    }
+
+    let max_left_children_len = (inner_spec.child_order.len() - 1) * inner_spec.child_size;
+    inner.hash == inner_spec.hash
+        && inner.prefix.len() >= inner_spec.min_prefix_length
+        && inner.prefix.len() <= inner_spec.max_prefix_length + max_left_children_len
+        && inner_spec.child_size > 0
+        && inner.suffix.len().is_multiple_of(inner_spec.child_size)
+}
+
+/// IAVL prefixes start with the node's height, size and version, followed by the child hash length prefixes for inner nodes
+fn is_valid_iavl_prefix<M: ManagedTypeApi>(prefix: &ManagedBuffer<M>, layer: usize) -> bool {
+    let mut offset = 0;
+    let (height, size, version) = match (
+        proto::try_read_signed_varint(prefix, &mut offset),
+        proto::try_read_signed_varint(prefix, &mut offset),
+        proto::try_read_signed_varint(prefix, &mut offset),
+    ) {
+        (Some(height), Some(size), Some(version)) => (height, size, version),
+        _ => return false,
+    };
+    if height < layer as i64 || size < 0 || version < 0 {
+        return false;
+    }
+
+    let remainder = prefix.len() - offset;
+    if layer == 0 {
+        remainder == 0
+    } else {
+        remainder == IAVL_LEFT_CHILD_PREFIX_REMAINDER
+            || remainder == IAVL_RIGHT_CHILD_PREFIX_REMAINDER
+    }
+}
+
+/// Checks that every step of the path (of the first `len` steps) is the leftmost child, or has only empty children to its left
+fn is_left_most<M: ManagedTypeApi>(
+    spec: &InnerSpec<M>,
+    path: &ManagedVec<M, InnerOp<M>>,
+    len: usize,
+) -> bool {
+    let padding = match get_padding(spec, 0) {
+        Some(padding) => padding,
+        None => return false,
+    };
+
+    path.iter()
+        .take(len)
+        .all(|step| padding.matches(&step) || left_branches_are_empty(spec, &step))
+}
+
+/// Checks that every step of the path (of the first `len` steps) is the rightmost child, or has only empty children to its right
+fn is_right_most<M: ManagedTypeApi>(
+    spec: &InnerSpec<M>,
+    path: &ManagedVec<M, InnerOp<M>>,
+    len: usize,
+) -> bool {
+    let last_branch = spec.child_order.len() as u32 - 1;
+    let padding = match get_padding(spec, last_branch) {
+        Some(padding) => padding,
+        None => return false,
+    };
+
+    path.iter()
+        .take(len)
+        .all(|step| padding.matches(&step) || right_branches_are_empty(spec, &step))
+}
+
+/// Checks that the leaves of the two paths are next to each other
+fn is_left_neighbor<M: ManagedTypeApi>(
+    spec: &InnerSpec<M>,
+    left: &ManagedVec<M, InnerOp<M>>,
+    right: &ManagedVec<M, InnerOp<M>>,
+) -> bool {
+    // skip the common path, from the root down
+    let mut left_len = left.len();
+    let mut right_len = right.len();
+    loop {
+        if left_len == 0 || right_len == 0 {
+            return false;
+        }
+
+        let top_left = left.get(left_len - 1);
+        let top_right = right.get(right_len - 1);
+        if top_left.prefix != top_right.prefix || top_left.suffix != top_right.suffix {
+            break;
+        }
+
+        left_len -= 1;
+        right_len -= 1;
+    }
+
+    // the first diverging nodes must be adjacent children of the same parent
+    let top_left = left.get(left_len - 1);
+    let top_right = right.get(right_len - 1);
+    if !is_left_step(spec, &top_left, &top_right) {
+        return false;
+    }
+
+    // and the paths below them must be the rightmost and the leftmost ones, respectively
+    is_right_most(spec, left, left_len - 1) && is_left_most(spec, right, right_len - 1)
+}
+
+fn is_left_step<M: ManagedTypeApi>(
+    spec: &InnerSpec<M>,
+    left: &InnerOp<M>,
+    right: &InnerOp<M>,
+) -> bool {
+    match (
+        order_from_padding(spec, left),
+        order_from_padding(spec, right),
+    ) {
+        (Some(left_branch), Some(right_branch)) => right_branch == left_branch + 1,
+        _ => false,
+    }
+}
+
+struct Padding {
+    min_prefix: usize,
+    max_prefix: usize,
+    suffix: usize,
+}
+
+impl Padding {
+    fn matches<M: ManagedTypeApi>(&self, step: &InnerOp<M>) -> bool {
+        step.prefix.len() >= self.min_prefix
+            && step.prefix.len() <= self.max_prefix
+            && step.suffix.len() == self.suffix
+    }
+}
+
+/// Expected prefix and suffix lengths of an inner node whose child is on `branch`
+fn get_padding<M: ManagedTypeApi>(spec: &InnerSpec<M>, branch: u32) -> Option<Padding> {
+    let position = get_position(spec, branch)?;
+    let prefix = position * spec.child_size;
+
+    Some(Padding {
+        min_prefix: prefix + spec.min_prefix_length,
+        max_prefix: prefix + spec.max_prefix_length,
+        suffix: (spec.child_order.len() - 1 - position) * spec.child_size,
+    })
+}
+
+/// Position of `branch` in the hashing order
+fn get_position<M: ManagedTypeApi>(spec: &InnerSpec<M>, branch: u32) -> Option<usize> {
+    spec.child_order.iter().position(|item| item == branch)
+}
+
+/// The branch of the child, deduced from the node's prefix and suffix lengths
+fn order_from_padding<M: ManagedTypeApi>(spec: &InnerSpec<M>, inner: &InnerOp<M>) -> Option<u32> {
+    (0..spec.child_order.len() as u32).find(|&branch| match get_padding(spec, branch) {
+        Some(padding) => padding.matches(inner),
+        None => false,
+    })
+}
+
+fn left_branches_are_empty<M: ManagedTypeApi>(spec: &InnerSpec<M>, inner: &InnerOp<M>) -> bool {
+    let left_branches = match order_from_padding(spec, inner) {
+        Some(0) | None => return false,
+        Some(branch) => branch as usize,
+    };
+
+    let child_size = spec.child_size;
+    let actual_prefix = match inner.prefix.len().checked_sub(left_branches * child_size) {
+        Some(actual_prefix) => actual_prefix,
+        None => return false,
+    };
+
+    (0..left_branches as u32).all(|branch| match get_position(spec, branch) {
+        Some(position) => {
+            inner
+                .prefix
+                .copy_slice(actual_prefix + position * child_size, child_size)
+                == Some(spec.empty_child.clone())
+        }
+        None => false,
+    })
+}
+
+fn right_branches_are_empty<M: ManagedTypeApi>(spec: &InnerSpec<M>, inner: &InnerOp<M>) -> bool {
+    let branch = match order_from_padding(spec, inner) {
+        Some(branch) => branch as usize,
+        None => return false,
+    };
+    let right_branches = spec.child_order.len() - 1 - branch;
+    if right_branches == 0 || inner.suffix.len() != spec.child_size * right_branches {
+        return false;
+    }
+
+    (0..right_branches as u32).all(|branch| match get_position(spec, branch) {
+        Some(position) => {
+            inner
+                .suffix
+                .copy_slice(position * spec.child_size, spec.child_size)
+                == Some(spec.empty_child.clone())
+        }
+        None => false,
+    })
+}
+
+/// Lexicographic order of the keys, hashed first if the spec requires it
+fn compare_keys<M: ManagedTypeApi + CryptoApi>(
+    spec: &ProofSpec<M>,
+    first: &ManagedBuffer<M>,
+    second: &ManagedBuffer<M>,
+) -> Ordering {
+    if spec.prehash_key_before_comparison {
+        let prehash_key = spec.leaf_spec.prehash_key;
+        return compare_bytes(
+            &ops::do_hash(prehash_key, first),
+            &ops::do_hash(prehash_key, second),
+        );
+    }
+
+    compare_bytes(first, second)
+}
+
+fn compare_bytes<M: ManagedTypeApi>(
+    first: &ManagedBuffer<M>,
+    second: &ManagedBuffer<M>,
+) -> Ordering {
+    let common_len = core::cmp::min(first.len(), second.len());
+    let mut first_chunk = [0u8; COMPARE_CHUNK_LEN];
+    let mut second_chunk = [0u8; COMPARE_CHUNK_LEN];
+    let mut offset = 0;
+    while offset < common_len {
+        let chunk_len = core::cmp::min(COMPARE_CHUNK_LEN, common_len - offset);
+        let _ = first.load_slice(offset, &mut first_chunk[..chunk_len]);
+        let _ = second.load_slice(offset, &mut second_chunk[..chunk_len]);
+        match first_chunk[..chunk_len].cmp(&second_chunk[..chunk_len]) {
+            Ordering::Equal => offset += chunk_len,
+            ordering => return ordering,
+        }
+    }
+
+    first.len().cmp(&second.len())
+}
+
+fn has_prefix<M: ManagedTypeApi>(bytes: &ManagedBuffer<M>, prefix: &ManagedBuffer<M>) -> bool {
+    bytes.copy_slice(0, prefix.len()).as_ref() == Some(prefix)
+}
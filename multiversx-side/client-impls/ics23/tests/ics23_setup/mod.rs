@@ -0,0 +1,26 @@
+#![allow(dead_code)]
+
+use std::panic::{self, AssertUnwindSafe};
+
+use ics23::commitment_proof::{decode_commitment_proof, CommitmentProof};
+use multiversx_sc::types::ManagedBuffer;
+use multiversx_sc_scenario::{multiversx_chain_vm::tx_mock::TxContextStack, DebugApi};
+
+pub mod vectors;
+
+pub fn managed_hex(hex_str: &str) -> ManagedBuffer<DebugApi> {
+    ManagedBuffer::new_from_bytes(&hex::decode(hex_str).unwrap())
+}
+
+pub fn decode_proof(hex_str: &str) -> CommitmentProof<DebugApi> {
+    decode_commitment_proof(&managed_hex(hex_str))
+}
+
+/// Runs `f` in the dummy environment set up by `DebugApi::dummy()`, and checks it signals the given error
+pub fn assert_user_error<F: FnOnce()>(f: F, expected_message: &str) {
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    assert!(result.is_err(), "expected error: {expected_message}");
+
+    let tx_result = TxContextStack::static_peek().result_lock().clone();
+    tx_result.assert_user_error(expected_message);
+}
@@ -0,0 +1,59 @@
+//! Proofs of a Cosmos SDK like state: an IAVL "ibc" store, committed in the simple merkle tree of the multistore
+
+pub static COMMITMENT_KEY: &[u8] = b"commitments/ports/transfer/channels/channel-0/sequences/1";
+pub static COMMITMENT_VALUE: &str =
+    "7c20d86675c5303deb7b35a4c19eefe3e7e792f995f0ebc313bc759fc22f999c";
+pub static FIRST_KEY: &[u8] = b"channelEnds/ports/transfer/channels/channel-0";
+pub static FIRST_VALUE: &[u8] = b"channel end";
+pub static LAST_KEY: &[u8] = b"receipts/ports/transfer/channels/channel-0/sequences/1";
+pub static LAST_VALUE: &[u8] = b"\x01";
+pub static MISSING_MIDDLE_KEY: &[u8] = b"commitments/ports/transfer/channels/channel-0/sequences/3";
+pub static MISSING_LEFT_KEY: &[u8] = b"acks/ports/transfer/channels/channel-0/sequences/1";
+pub static MISSING_RIGHT_KEY: &[u8] = b"receipts/ports/transfer/channels/channel-0/sequences/2";
+pub static IBC_STORE_KEY: &[u8] = b"ibc";
+
+/// root of the IAVL "ibc" store holding the keys below, leaf i having version i + 1
+pub static IAVL_ROOT: &str = "0834751b0a3ee3a1f83d5375274a3337d0d864b1367f81c353e16515ee6c5ca0";
+
+/// existence of COMMITMENT_KEY
+pub static IAVL_EXIST_PROOF: &str = "0aef010a39636f6d6d69746d656e74732f706f7274732f7472616e736665722f6368616e6e656c732f6368616e6e656c2d302f73657175656e6365732f3112207c20d86675c5303deb7b35a4c19eefe3e7e792f995f0ebc313bc759fc22f999c1a0b0801180120012a03000206222b08011204020408201a212011fc0711f36026e790f25024aeab3f90ece2ec36259a8fd5a47c3b3d11d527b42229080112250408082072d5a45a0195e594969a8b6386e605f5295954e89895f56ed03febaea13020b020222b08011204060e0e201a2120b31d05cf997c183c12ba4a73e5c1fadefac0173cd00a39915eaf8dbb657e371a";
+
+/// existence of LAST_KEY, whose path only goes through right children
+pub static IAVL_EXIST_RIGHT_CHILD_PROOF: &str = "0a9e010a3672656365697074732f706f7274732f7472616e736665722f6368616e6e656c732f6368616e6e656c2d302f73657175656e6365732f311201011a0b0801180120012a0300020e22290801122504060e2005a3c3e92bab66b7085ca86634ee6a94885dbaf4f0dfde35811e62ec1923e18820222908011225060e0e209903e3fe0e4ffe728565bc6709d803178a627bd1b1b1f6b8df68956aef2dadd620";
+
+/// absence of MISSING_MIDDLE_KEY, between two existing keys
+pub static IAVL_NONEXIST_MIDDLE_PROOF: &str = "12ea030a39636f6d6d69746d656e74732f706f7274732f7472616e736665722f6368616e6e656c732f6368616e6e656c2d302f73657175656e6365732f3312ed010a39636f6d6d69746d656e74732f706f7274732f7472616e736665722f6368616e6e656c732f6368616e6e656c2d302f73657175656e6365732f321220a790154d8499d809d3b85f48d584fcc844638b52daa055ed1d5e20af2ef964051a0b0801180120012a0300020822290801122502040820fdff389ae6e85c7a984ebb6272e232c23f7a31005aabb5f6fb6a947782c9eecb202229080112250408082072d5a45a0195e594969a8b6386e605f5295954e89895f56ed03febaea13020b020222b08011204060e0e201a2120b31d05cf997c183c12ba4a73e5c1fadefac0173cd00a39915eaf8dbb657e371a1abc010a18636f6e6e656374696f6e732f636f6e6e656374696f6e2d30120e636f6e6e656374696f6e20656e641a0b0801180120012a0300020a222b0801120402040c201a2120128ab01d666101be177c630f4ace278e9aa6c1856cca2b2e4d97f074c3f05157222b0801120404060e201a2120881c5c594bbad18853592dc7261d3d9d245edb7d1198fbd0a2fc7824ffba52f2222908011225060e0e209903e3fe0e4ffe728565bc6709d803178a627bd1b1b1f6b8df68956aef2dadd620";
+
+/// absence of MISSING_LEFT_KEY, before the first key
+pub static IAVL_NONEXIST_LEFT_PROOF: &str = "1287020a3261636b732f706f7274732f7472616e736665722f6368616e6e656c732f6368616e6e656c2d302f73657175656e6365732f311ad0010a2d6368616e6e656c456e64732f706f7274732f7472616e736665722f6368616e6e656c732f6368616e6e656c2d30120b6368616e6e656c20656e641a0b0801180120012a03000202222b08011204020404201a212078c631c9c4a8eccf42ce40c67eb06e3d7aefb36e63314cca5f0f13741b8308eb222b08011204040808201a21204070e184e21b1256aa28a7bf585f12f004d4b8850c84919d21b054a63788851e222b08011204060e0e201a2120b31d05cf997c183c12ba4a73e5c1fadefac0173cd00a39915eaf8dbb657e371a";
+
+/// absence of MISSING_RIGHT_KEY, after the last key
+pub static IAVL_NONEXIST_RIGHT_PROOF: &str = "12d9010a3672656365697074732f706f7274732f7472616e736665722f6368616e6e656c732f6368616e6e656c2d302f73657175656e6365732f32129e010a3672656365697074732f706f7274732f7472616e736665722f6368616e6e656c732f6368616e6e656c2d302f73657175656e6365732f311201011a0b0801180120012a0300020e22290801122504060e2005a3c3e92bab66b7085ca86634ee6a94885dbaf4f0dfde35811e62ec1923e18820222908011225060e0e209903e3fe0e4ffe728565bc6709d803178a627bd1b1b1f6b8df68956aef2dadd620";
+
+/// absence of MISSING_MIDDLE_KEY, proven with keys which are not neighbours
+pub static IAVL_NONEXIST_GAP_PROOF: &str = "12ec030a39636f6d6d69746d656e74732f706f7274732f7472616e736665722f6368616e6e656c732f6368616e6e656c2d302f73657175656e6365732f3312ef010a39636f6d6d69746d656e74732f706f7274732f7472616e736665722f6368616e6e656c732f6368616e6e656c2d302f73657175656e6365732f3112207c20d86675c5303deb7b35a4c19eefe3e7e792f995f0ebc313bc759fc22f999c1a0b0801180120012a03000206222b08011204020408201a212011fc0711f36026e790f25024aeab3f90ece2ec36259a8fd5a47c3b3d11d527b42229080112250408082072d5a45a0195e594969a8b6386e605f5295954e89895f56ed03febaea13020b020222b08011204060e0e201a2120b31d05cf997c183c12ba4a73e5c1fadefac0173cd00a39915eaf8dbb657e371a1abc010a18636f6e6e656374696f6e732f636f6e6e656374696f6e2d30120e636f6e6e656374696f6e20656e641a0b0801180120012a0300020a222b0801120402040c201a2120128ab01d666101be177c630f4ace278e9aa6c1856cca2b2e4d97f074c3f05157222b0801120404060e201a2120881c5c594bbad18853592dc7261d3d9d245edb7d1198fbd0a2fc7824ffba52f2222908011225060e0e209903e3fe0e4ffe728565bc6709d803178a627bd1b1b1f6b8df68956aef2dadd620";
+
+/// batch of the existence of FIRST_KEY, the absence of MISSING_MIDDLE_KEY and the existence of COMMITMENT_KEY
+pub static IAVL_BATCH_PROOF: &str = "1abb070ad3010ad0010a2d6368616e6e656c456e64732f706f7274732f7472616e736665722f6368616e6e656c732f6368616e6e656c2d30120b6368616e6e656c20656e641a0b0801180120012a03000202222b08011204020404201a212078c631c9c4a8eccf42ce40c67eb06e3d7aefb36e63314cca5f0f13741b8308eb222b08011204040808201a21204070e184e21b1256aa28a7bf585f12f004d4b8850c84919d21b054a63788851e222b08011204060e0e201a2120b31d05cf997c183c12ba4a73e5c1fadefac0173cd00a39915eaf8dbb657e371a0aed0312ea030a39636f6d6d69746d656e74732f706f7274732f7472616e736665722f6368616e6e656c732f6368616e6e656c2d302f73657175656e6365732f3312ed010a39636f6d6d69746d656e74732f706f7274732f7472616e736665722f6368616e6e656c732f6368616e6e656c2d302f73657175656e6365732f321220a790154d8499d809d3b85f48d584fcc844638b52daa055ed1d5e20af2ef964051a0b0801180120012a0300020822290801122502040820fdff389ae6e85c7a984ebb6272e232c23f7a31005aabb5f6fb6a947782c9eecb202229080112250408082072d5a45a0195e594969a8b6386e605f5295954e89895f56ed03febaea13020b020222b08011204060e0e201a2120b31d05cf997c183c12ba4a73e5c1fadefac0173cd00a39915eaf8dbb657e371a1abc010a18636f6e6e656374696f6e732f636f6e6e656374696f6e2d30120e636f6e6e656374696f6e20656e641a0b0801180120012a0300020a222b0801120402040c201a2120128ab01d666101be177c630f4ace278e9aa6c1856cca2b2e4d97f074c3f05157222b0801120404060e201a2120881c5c594bbad18853592dc7261d3d9d245edb7d1198fbd0a2fc7824ffba52f2222908011225060e0e209903e3fe0e4ffe728565bc6709d803178a627bd1b1b1f6b8df68956aef2dadd6200af2010aef010a39636f6d6d69746d656e74732f706f7274732f7472616e736665722f6368616e6e656c732f6368616e6e656c2d302f73657175656e6365732f3112207c20d86675c5303deb7b35a4c19eefe3e7e792f995f0ebc313bc759fc22f999c1a0b0801180120012a03000206222b08011204020408201a212011fc0711f36026e790f25024aeab3f90ece2ec36259a8fd5a47c3b3d11d527b42229080112250408082072d5a45a0195e594969a8b6386e605f5295954e89895f56ed03febaea13020b020222b08011204060e0e201a2120b31d05cf997c183c12ba4a73e5c1fadefac0173cd00a39915eaf8dbb657e371a";
+
+/// root of the simple merkle tree of the multistore: acc, bank, ibc -> IAVL_ROOT, staking, upgrade
+pub static TENDERMINT_ROOT: &str =
+    "729cc9000f2161506e88150b8da279a5fc5ecc7b115500003a6052ce537d74b7";
+
+/// existence of the "ibc" store
+pub static TENDERMINT_EXIST_PROOF: &str = "0aab010a0369626312200834751b0a3ee3a1f83d5375274a3337d0d864b1367f81c353e16515ee6c5ca01a090801180120012a0100222708011201011a207cdd50331bf332f61f3d9cc963d2d119693028da3dbe2d058358d969a1a61dd82225080112210123b1eef3a165314bc88c3b59de99a9e3aaaaf3ece3ed42e737789220a0bfa7b8222708011201011a20c79c51f67d450a8b08ce5dd411b713a88a556c7204e94d2aa866e157dfda0ea5";
+
+/// absence of the "gov" store, between "bank" and "ibc"
+pub static TENDERMINT_NONEXIST_PROOF: &str = "12e2020a03676f7612ac010a0462616e6b12204381dc2ab14285160c808659aee005d51255add7264b318d07c7417292c7442c1a090801180120012a010022250801122101a14c0316de8feeb4f4b154ac2317f7d0c965b52128e2651799b0f8862227afbc222708011201011a2020be819b67de71e94bab088a67cefa33a8dcadcab69417dfc28d70aeda368af9222708011201011a20c79c51f67d450a8b08ce5dd411b713a88a556c7204e94d2aa866e157dfda0ea51aab010a0369626312200834751b0a3ee3a1f83d5375274a3337d0d864b1367f81c353e16515ee6c5ca01a090801180120012a0100222708011201011a207cdd50331bf332f61f3d9cc963d2d119693028da3dbe2d058358d969a1a61dd82225080112210123b1eef3a165314bc88c3b59de99a9e3aaaaf3ece3ed42e737789220a0bfa7b8222708011201011a20c79c51f67d450a8b08ce5dd411b713a88a556c7204e94d2aa866e157dfda0ea5";
+
+/// absence of the "aaa" store, before the first store
+pub static TENDERMINT_NONEXIST_LEFT_PROOF: &str = "12b5010a036161611aad010a036163631220414322309db5c06d090a2e922ccc3e00708c993b9b96405de127b7fd8da2dd211a090801180120012a0100222708011201011a2033ad8e2d339d1b010606a1230aeadb09775d363be49b6c9c75f2e10a0706143a222708011201011a2020be819b67de71e94bab088a67cefa33a8dcadcab69417dfc28d70aeda368af9222708011201011a20c79c51f67d450a8b08ce5dd411b713a88a556c7204e94d2aa866e157dfda0ea5";
+
+/// absence of the "zzz" store, after the last store
+pub static TENDERMINT_NONEXIST_RIGHT_PROOF: &str = "12640a037a7a7a125d0a077570677261646512207fef9479c8bc5a3c86891cb82969cbf2ffc73c7350366c82286345d8292eebf51a090801180120012a0100222508011221013a8113f4de4f51e4ab45b3b04d43f469496805557a7c43fa736abf43531fc6b8";
+
+/// IBC MerkleProof of COMMITMENT_KEY in the "ibc" store, against TENDERMINT_ROOT
+pub static COMMITMENT_MERKLE_PROOF: &str = "0af2010aef010a39636f6d6d69746d656e74732f706f7274732f7472616e736665722f6368616e6e656c732f6368616e6e656c2d302f73657175656e6365732f3112207c20d86675c5303deb7b35a4c19eefe3e7e792f995f0ebc313bc759fc22f999c1a0b0801180120012a03000206222b08011204020408201a212011fc0711f36026e790f25024aeab3f90ece2ec36259a8fd5a47c3b3d11d527b42229080112250408082072d5a45a0195e594969a8b6386e605f5295954e89895f56ed03febaea13020b020222b08011204060e0e201a2120b31d05cf997c183c12ba4a73e5c1fadefac0173cd00a39915eaf8dbb657e371a0aae010aab010a0369626312200834751b0a3ee3a1f83d5375274a3337d0d864b1367f81c353e16515ee6c5ca01a090801180120012a0100222708011201011a207cdd50331bf332f61f3d9cc963d2d119693028da3dbe2d058358d969a1a61dd82225080112210123b1eef3a165314bc88c3b59de99a9e3aaaaf3ece3ed42e737789220a0bfa7b8222708011201011a20c79c51f67d450a8b08ce5dd411b713a88a556c7204e94d2aa866e157dfda0ea5";
+
+/// IBC MerkleProof of the absence of MISSING_RIGHT_KEY in the "ibc" store, against TENDERMINT_ROOT
+pub static RECEIPT_ABSENCE_MERKLE_PROOF: &str = "0adc0112d9010a3672656365697074732f706f7274732f7472616e736665722f6368616e6e656c732f6368616e6e656c2d302f73657175656e6365732f32129e010a3672656365697074732f706f7274732f7472616e736665722f6368616e6e656c732f6368616e6e656c2d302f73657175656e6365732f311201011a0b0801180120012a0300020e22290801122504060e2005a3c3e92bab66b7085ca86634ee6a94885dbaf4f0dfde35811e62ec1923e18820222908011225060e0e209903e3fe0e4ffe728565bc6709d803178a627bd1b1b1f6b8df68956aef2dadd6200aae010aab010a0369626312200834751b0a3ee3a1f83d5375274a3337d0d864b1367f81c353e16515ee6c5ca01a090801180120012a0100222708011201011a207cdd50331bf332f61f3d9cc963d2d119693028da3dbe2d058358d969a1a61dd82225080112210123b1eef3a165314bc88c3b59de99a9e3aaaaf3ece3ed42e737789220a0bfa7b8222708011201011a20c79c51f67d450a8b08ce5dd411b713a88a556c7204e94d2aa866e157dfda0ea5";
@@ -0,0 +1,322 @@
+use ics23::{
+    commitment_proof::{decode_commitment_proof, CommitmentProof},
+    ops,
+    specs::{self, iavl_spec, tendermint_spec, ProofSpec},
+    verify,
+};
+use ics23_setup::{assert_user_error, decode_proof, managed_hex, vectors};
+use multiversx_sc::types::ManagedBuffer;
+use multiversx_sc_scenario::{managed_buffer, DebugApi};
+
+pub mod ics23_setup;
+
+fn verify_membership(
+    spec: &ProofSpec<DebugApi>,
+    root: &str,
+    proof: &str,
+    key: &[u8],
+    value: &ManagedBuffer<DebugApi>,
+) -> bool {
+    verify::verify_membership(
+        spec,
+        &managed_hex(root),
+        decode_proof(proof),
+        &ManagedBuffer::new_from_bytes(key),
+        value,
+    )
+}
+
+fn verify_non_membership(spec: &ProofSpec<DebugApi>, root: &str, proof: &str, key: &[u8]) -> bool {
+    verify::verify_non_membership(
+        spec,
+        &managed_hex(root),
+        decode_proof(proof),
+        &ManagedBuffer::new_from_bytes(key),
+    )
+}
+
+#[test]
+fn verify_iavl_membership_test() {
+    DebugApi::dummy();
+
+    let commitment = managed_hex(vectors::COMMITMENT_VALUE);
+    assert!(verify_membership(
+        &iavl_spec(),
+        vectors::IAVL_ROOT,
+        vectors::IAVL_EXIST_PROOF,
+        vectors::COMMITMENT_KEY,
+        &commitment,
+    ));
+    assert!(verify_membership(
+        &iavl_spec(),
+        vectors::IAVL_ROOT,
+        vectors::IAVL_EXIST_RIGHT_CHILD_PROOF,
+        vectors::LAST_KEY,
+        &managed_buffer!(vectors::LAST_VALUE),
+    ));
+
+    // wrong value, key, root and spec
+    assert!(!verify_membership(
+        &iavl_spec(),
+        vectors::IAVL_ROOT,
+        vectors::IAVL_EXIST_PROOF,
+        vectors::COMMITMENT_KEY,
+        &managed_buffer!(b"other commitment"),
+    ));
+    assert!(!verify_membership(
+        &iavl_spec(),
+        vectors::IAVL_ROOT,
+        vectors::IAVL_EXIST_PROOF,
+        vectors::LAST_KEY,
+        &commitment,
+    ));
+    assert!(!verify_membership(
+        &iavl_spec(),
+        vectors::TENDERMINT_ROOT,
+        vectors::IAVL_EXIST_PROOF,
+        vectors::COMMITMENT_KEY,
+        &commitment,
+    ));
+    assert!(!verify_membership(
+        &tendermint_spec(),
+        vectors::IAVL_ROOT,
+        vectors::IAVL_EXIST_PROOF,
+        vectors::COMMITMENT_KEY,
+        &commitment,
+    ));
+}
+
+#[test]
+fn verify_tendermint_membership_test() {
+    DebugApi::dummy();
+
+    let iavl_root = managed_hex(vectors::IAVL_ROOT);
+    assert!(verify_membership(
+        &tendermint_spec(),
+        vectors::TENDERMINT_ROOT,
+        vectors::TENDERMINT_EXIST_PROOF,
+        vectors::IBC_STORE_KEY,
+        &iavl_root,
+    ));
+    assert!(!verify_membership(
+        &iavl_spec(),
+        vectors::TENDERMINT_ROOT,
+        vectors::TENDERMINT_EXIST_PROOF,
+        vectors::IBC_STORE_KEY,
+        &iavl_root,
+    ));
+}
+
+#[test]
+fn verify_iavl_non_membership_test() {
+    DebugApi::dummy();
+
+    for (proof, key) in [
+        (
+            vectors::IAVL_NONEXIST_MIDDLE_PROOF,
+            vectors::MISSING_MIDDLE_KEY,
+        ),
+        (vectors::IAVL_NONEXIST_LEFT_PROOF, vectors::MISSING_LEFT_KEY),
+        (
+            vectors::IAVL_NONEXIST_RIGHT_PROOF,
+            vectors::MISSING_RIGHT_KEY,
+        ),
+    ] {
+        assert!(verify_non_membership(
+            &iavl_spec(),
+            vectors::IAVL_ROOT,
+            proof,
+            key
+        ));
+        assert!(!verify_non_membership(
+            &iavl_spec(),
+            vectors::TENDERMINT_ROOT,
+            proof,
+            key
+        ));
+    }
+
+    // the proof is for another key
+    assert!(!verify_non_membership(
+        &iavl_spec(),
+        vectors::IAVL_ROOT,
+        vectors::IAVL_NONEXIST_LEFT_PROOF,
+        vectors::MISSING_RIGHT_KEY
+    ));
+}
+
+#[test]
+fn verify_non_membership_not_neighbours_test() {
+    DebugApi::dummy();
+
+    assert!(!verify_non_membership(
+        &iavl_spec(),
+        vectors::IAVL_ROOT,
+        vectors::IAVL_NONEXIST_GAP_PROOF,
+        vectors::MISSING_MIDDLE_KEY
+    ));
+}
+
+#[test]
+fn verify_tendermint_non_membership_test() {
+    DebugApi::dummy();
+
+    for (proof, key) in [
+        (vectors::TENDERMINT_NONEXIST_PROOF, &b"gov"[..]),
+        (vectors::TENDERMINT_NONEXIST_LEFT_PROOF, &b"aaa"[..]),
+        (vectors::TENDERMINT_NONEXIST_RIGHT_PROOF, &b"zzz"[..]),
+    ] {
+        assert!(verify_non_membership(
+            &tendermint_spec(),
+            vectors::TENDERMINT_ROOT,
+            proof,
+            key
+        ));
+    }
+}
+
+#[test]
+fn verify_existing_key_non_membership_test() {
+    DebugApi::dummy();
+
+    // a non-existence proof can't be built for an existing key, as the neighbours' range excludes it
+    let proof = decode_proof(vectors::IAVL_NONEXIST_MIDDLE_PROOF);
+    let non_existence_proof = match proof {
+        CommitmentProof::Nonexist(non_existence_proof) => non_existence_proof,
+        _ => panic!("expected a non-existence proof"),
+    };
+    let left_key = non_existence_proof.left.as_ref().unwrap().key.clone();
+    assert!(!verify::verify_non_existence(
+        &non_existence_proof,
+        &iavl_spec(),
+        &managed_hex(vectors::IAVL_ROOT),
+        &left_key,
+    ));
+}
+
+#[test]
+fn verify_batch_test() {
+    DebugApi::dummy();
+
+    assert!(verify_membership(
+        &iavl_spec(),
+        vectors::IAVL_ROOT,
+        vectors::IAVL_BATCH_PROOF,
+        vectors::FIRST_KEY,
+        &managed_buffer!(vectors::FIRST_VALUE),
+    ));
+    assert!(verify_membership(
+        &iavl_spec(),
+        vectors::IAVL_ROOT,
+        vectors::IAVL_BATCH_PROOF,
+        vectors::COMMITMENT_KEY,
+        &managed_hex(vectors::COMMITMENT_VALUE),
+    ));
+    assert!(verify_non_membership(
+        &iavl_spec(),
+        vectors::IAVL_ROOT,
+        vectors::IAVL_BATCH_PROOF,
+        vectors::MISSING_MIDDLE_KEY
+    ));
+
+    // the batch has no proof for these keys
+    assert!(!verify_membership(
+        &iavl_spec(),
+        vectors::IAVL_ROOT,
+        vectors::IAVL_BATCH_PROOF,
+        vectors::LAST_KEY,
+        &managed_buffer!(vectors::LAST_VALUE),
+    ));
+    assert!(!verify_non_membership(
+        &iavl_spec(),
+        vectors::IAVL_ROOT,
+        vectors::IAVL_BATCH_PROOF,
+        vectors::MISSING_LEFT_KEY
+    ));
+}
+
+#[test]
+fn calculate_existence_root_test() {
+    DebugApi::dummy();
+
+    let proof = decode_proof(vectors::TENDERMINT_EXIST_PROOF);
+    let existence_proof = proof
+        .get_existence_proof(&managed_buffer!(vectors::IBC_STORE_KEY))
+        .unwrap();
+    assert_eq!(existence_proof.value, managed_hex(vectors::IAVL_ROOT));
+    assert_eq!(
+        verify::calculate_existence_root(&existence_proof),
+        managed_hex(vectors::TENDERMINT_ROOT)
+    );
+}
+
+#[test]
+fn decode_invalid_proof_test() {
+    DebugApi::dummy();
+
+    // compressed batch
+    assert_user_error(
+        || {
+            let _ = decode_commitment_proof(&managed_hex("2200"));
+        },
+        "Compressed proofs are not supported",
+    );
+    // empty proof
+    assert_user_error(
+        || {
+            let _ = decode_commitment_proof::<DebugApi>(&ManagedBuffer::new());
+        },
+        "Invalid commitment proof",
+    );
+    // existence proof without a leaf
+    assert_user_error(
+        || {
+            let _ = decode_commitment_proof(&managed_hex("0a030a0161"));
+        },
+        "Invalid commitment proof",
+    );
+    // truncated
+    let proof = &vectors::IAVL_EXIST_PROOF[..vectors::IAVL_EXIST_PROOF.len() - 2];
+    assert_user_error(
+        || {
+            let _ = decode_proof(proof);
+        },
+        "Invalid protobuf encoding",
+    );
+}
+
+#[test]
+fn do_hash_test() {
+    DebugApi::dummy();
+
+    let data = managed_buffer!(b"abc");
+    assert_eq!(
+        ops::do_hash(ics23::commitment_proof::HashOp::Sha256, &data),
+        managed_hex("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+    );
+    assert_eq!(
+        ops::do_hash(ics23::commitment_proof::HashOp::NoHash, &data),
+        data
+    );
+    assert_user_error(
+        || {
+            let _ = ops::do_hash(ics23::commitment_proof::HashOp::Sha512, &data);
+        },
+        "Unsupported hash operation",
+    );
+}
+
+#[test]
+fn encode_proof_spec_test() {
+    DebugApi::dummy();
+
+    // as found in the client states of Cosmos SDK chains
+    assert_eq!(
+        specs::encode_proof_spec(&iavl_spec::<DebugApi>()),
+        managed_hex("0a090801180120012a0100120c0a02000110211804200c3001")
+    );
+    assert_eq!(
+        specs::encode_proof_spec(&tendermint_spec::<DebugApi>()),
+        managed_hex("0a090801180120012a0100120c0a0200011020180120013001")
+    );
+}
@@ -0,0 +1,119 @@
+use ics23::{merkle, specs};
+use ics23_setup::{managed_hex, vectors};
+use multiversx_sc::types::{ManagedBuffer, ManagedVec};
+use multiversx_sc_scenario::{managed_buffer, DebugApi};
+
+pub mod ics23_setup;
+
+fn key_path(key: &[u8]) -> ManagedVec<DebugApi, ManagedBuffer<DebugApi>> {
+    let mut key_path = ManagedVec::new();
+    key_path.push(managed_buffer!(vectors::IBC_STORE_KEY));
+    key_path.push(ManagedBuffer::new_from_bytes(key));
+
+    key_path
+}
+
+#[test]
+fn verify_membership_test() {
+    DebugApi::dummy();
+
+    let specs = specs::sdk_specs();
+    let root = managed_hex(vectors::TENDERMINT_ROOT);
+    let proof = managed_hex(vectors::COMMITMENT_MERKLE_PROOF);
+    let commitment = managed_hex(vectors::COMMITMENT_VALUE);
+    assert!(merkle::verify_membership(
+        &specs,
+        &root,
+        &proof,
+        &key_path(vectors::COMMITMENT_KEY),
+        &commitment,
+    ));
+
+    assert!(!merkle::verify_membership(
+        &specs,
+        &root,
+        &proof,
+        &key_path(vectors::COMMITMENT_KEY),
+        &managed_buffer!(b"other commitment"),
+    ));
+    assert!(!merkle::verify_membership(
+        &specs,
+        &managed_hex(vectors::IAVL_ROOT),
+        &proof,
+        &key_path(vectors::COMMITMENT_KEY),
+        &commitment,
+    ));
+
+    // the prefix must match the store name
+    let mut other_store_path = ManagedVec::new();
+    other_store_path.push(managed_buffer!(b"bank"));
+    other_store_path.push(managed_buffer!(vectors::COMMITMENT_KEY));
+    assert!(!merkle::verify_membership(
+        &specs,
+        &root,
+        &proof,
+        &other_store_path,
+        &commitment,
+    ));
+
+    // a proof per spec is required
+    assert!(!merkle::verify_membership(
+        &specs[..1],
+        &root,
+        &proof,
+        &key_path(vectors::COMMITMENT_KEY),
+        &commitment,
+    ));
+}
+
+#[test]
+fn verify_non_membership_test() {
+    DebugApi::dummy();
+
+    let specs = specs::sdk_specs();
+    let root = managed_hex(vectors::TENDERMINT_ROOT);
+    let proof = managed_hex(vectors::RECEIPT_ABSENCE_MERKLE_PROOF);
+    assert!(merkle::verify_non_membership(
+        &specs,
+        &root,
+        &proof,
+        &key_path(vectors::MISSING_RIGHT_KEY),
+    ));
+
+    assert!(!merkle::verify_non_membership(
+        &specs,
+        &managed_hex(vectors::IAVL_ROOT),
+        &proof,
+        &key_path(vectors::MISSING_RIGHT_KEY),
+    ));
+    assert!(!merkle::verify_non_membership(
+        &specs,
+        &root,
+        &proof,
+        &key_path(vectors::LAST_KEY),
+    ));
+
+    // a membership proof doesn't prove absence
+    assert!(!merkle::verify_non_membership(
+        &specs,
+        &root,
+        &managed_hex(vectors::COMMITMENT_MERKLE_PROOF),
+        &key_path(vectors::COMMITMENT_KEY),
+    ));
+}
+
+#[test]
+fn decode_merkle_proof_test() {
+    DebugApi::dummy();
+
+    let proofs = merkle::decode_merkle_proof(&managed_hex(vectors::COMMITMENT_MERKLE_PROOF));
+    assert_eq!(proofs.len(), 2);
+    assert_eq!(
+        proofs.get(0).clone_value(),
+        managed_hex(vectors::IAVL_EXIST_PROOF)
+    );
+    assert_eq!(
+        proofs.get(1).clone_value(),
+        managed_hex(vectors::TENDERMINT_EXIST_PROOF)
+    );
+}
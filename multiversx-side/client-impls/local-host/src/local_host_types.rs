@@ -1,9 +1,11 @@
 pub mod client_state {
-    use common_types::channel_types::height;
+    use common_types::{channel_types::height, proto};
 
     multiversx_sc::imports!();
     multiversx_sc::derive_imports!();
 
+    pub static TYPE_URL: &[u8] = b"/ibc.lightclients.localhost.v2.ClientState";
+
     #[derive(TypeAbi, TopEncode, TopDecode)]
     pub struct Data {
         pub latest_height: height::Data,
@@ -14,15 +16,26 @@ pub mod client_state {
         pub fn new(latest_height: height::Data) -> Self {
             Self { latest_height }
         }
+
+        /// ibc.lightclients.localhost.v2.ClientState
+        pub fn to_proto<M: ManagedTypeApi>(&self) -> ManagedBuffer<M> {
+            let mut encoded = ManagedBuffer::new();
+            proto::append_message_field(&mut encoded, 1, &self.latest_height.to_proto());
+
+            encoded
+        }
     }
 }
 
 pub mod consensus_state {
-    use common_types::UnixTimestamp;
+    use common_types::{proto, UnixTimestamp};
 
     multiversx_sc::imports!();
     multiversx_sc::derive_imports!();
 
+    /// ibc-go stores no consensus state for the localhost client, so this one only wraps the sentinel
+    pub static TYPE_URL: &[u8] = b"/ibc.lightclients.localhost.v2.ConsensusState";
+
     #[derive(TypeAbi, TopEncode, TopDecode)]
     pub struct Data {
         pub timestamp: UnixTimestamp,
@@ -33,5 +46,13 @@ pub mod consensus_state {
         pub fn new(timestamp: UnixTimestamp) -> Self {
             Self { timestamp }
         }
+
+        /// ibc.lightclients.localhost.v2.ConsensusState
+        pub fn to_proto<M: ManagedTypeApi>(&self) -> ManagedBuffer<M> {
+            let mut encoded = ManagedBuffer::new();
+            proto::append_uint_field(&mut encoded, 1, self.timestamp);
+
+            encoded
+        }
     }
 }
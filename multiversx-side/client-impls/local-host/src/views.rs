@@ -1,7 +1,7 @@
 use client_common::{
     ClientStatus, GetLatestInfoResultType, VerifyMembershipArgs, VerifyNonMembershipArgs,
 };
-use common_types::{channel_types::height, ClientId, Hash, UnixTimestamp};
+use common_types::{channel_types::height, client_types::any, ClientId, Hash, UnixTimestamp};
 use host::{host_views::ProxyTrait as _, storage::ProxyTrait as _};
 
 use crate::local_host_types::{client_state, consensus_state};
@@ -75,6 +75,9 @@ pub trait ViewsModule:
     /// The caller is expected to construct the full CommitmentPath from a CommitmentPrefix and a standardized path (as defined in ICS 24)
     ///
     /// Proof uses "DEFAULT_PROOF_BYTES"
+    ///
+    /// The connection delay is not enforced: the commitment is read from the current state of this chain, which has
+    /// no consensus state that could be processed too recently, as in ibc-go's 09-localhost
     #[view(verifyMembership)]
     fn verify_membership(&self, args: VerifyMembershipArgs<Self::Api>) -> bool {
         self.require_valid_client_id(&args.client_id);
@@ -84,7 +87,10 @@ pub trait ViewsModule:
         let default_proof = self
             .crypto()
             .keccak256(ManagedBuffer::from(DEFAULT_PROOF_BYTES));
-        require!(args.proof == default_proof, "Invalid proof");
+        require!(
+            &args.proof == default_proof.as_managed_buffer(),
+            "Invalid proof"
+        );
 
         let ibc_handler = self.ibc_handler().get();
         let hashed_path = self.crypto().keccak256(args.path);
@@ -99,6 +105,8 @@ pub trait ViewsModule:
     /// A generic proof verification method which verifies the absence of a given CommitmentPath at a specified height
     ///
     /// The caller is expected to construct the full CommitmentPath from a CommitmentPrefix and a standardized path (as defined in ICS 24)
+    ///
+    /// As for membership, the connection delay is not enforced
     #[view(verifyNonMembership)]
     fn verify_non_membership(&self, args: VerifyNonMembershipArgs<Self::Api>) -> bool {
         let _ = self.get_timestamp_at_height(&args.client_id, &args.height);
@@ -107,7 +115,10 @@ pub trait ViewsModule:
         let default_proof = self
             .crypto()
             .keccak256(ManagedBuffer::from(DEFAULT_PROOF_BYTES));
-        require!(args.proof == default_proof, "Invalid proof");
+        require!(
+            &args.proof == default_proof.as_managed_buffer(),
+            "Invalid proof"
+        );
 
         let ibc_handler = self.ibc_handler().get();
         let hashed_path = self.crypto().keccak256(args.path);
@@ -145,6 +156,26 @@ pub trait ViewsModule:
         consensus_state::Data { timestamp: 0 }
     }
 
+    /// returns the clientState, encoded as a protobuf Any, as committed by the handler
+    #[view(getClientStateAny)]
+    fn get_client_state_any(&self, client_id: &ClientId<Self::Api>) -> ManagedBuffer {
+        let client_state = self.get_client_state(client_id);
+
+        any::Data::new(client_state::TYPE_URL, client_state.to_proto()).to_proto()
+    }
+
+    /// returns the sentinel consensus state, encoded as a protobuf Any, as committed by the handler
+    #[view(getConsensusStateAny)]
+    fn get_consensus_state_any(
+        &self,
+        client_id: &ClientId<Self::Api>,
+        height: &height::Data,
+    ) -> ManagedBuffer {
+        let consensus_state = self.get_consensus_state(client_id, height);
+
+        any::Data::new(consensus_state::TYPE_URL, consensus_state.to_proto()).to_proto()
+    }
+
     fn require_ibc_prefix(&self, prefix: &ManagedBuffer) {
         let ibc_handler = self.ibc_handler().get();
         let ibc_prefix: ManagedBuffer = self
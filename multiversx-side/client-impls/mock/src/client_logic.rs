@@ -1,7 +1,7 @@
 use client_common::ClientStatus;
 use common_types::{channel_types::height, ClientId};
 
-use crate::mock_types::{client_state, consensus_state, header};
+use crate::mock_types::{client_state, consensus_state, header, misbehaviour};
 
 multiversx_sc::imports!();
 
@@ -29,6 +29,7 @@ pub trait ClientLogicModule: client_common::CommonClientLogicModule {
         mapper.set(&client_state);
         self.consensus_states(&client_id, &client_state.latest_height.to_biguint_concat())
             .set(consensus_state);
+        self.set_processed_time_and_height(&client_id, &client_state.latest_height);
         self.statuses(&client_id).set(ClientStatus::Active);
 
         client_state.latest_height
@@ -44,6 +45,9 @@ pub trait ClientLogicModule: client_common::CommonClientLogicModule {
     }
 
     /// updates the client state and returns the updated heights
+    ///
+    /// As with the Tendermint client, a header conflicting with a stored consensus state freezes the client, and no
+    /// height is updated.
     #[endpoint(updateClient)]
     fn update_client(
         &self,
@@ -64,22 +68,105 @@ pub trait ClientLogicModule: client_common::CommonClientLogicModule {
             "Invalid header"
         );
 
+        let consensus_state_mapper =
+            self.consensus_states(&client_id, &header.height.to_biguint_concat());
+        if !consensus_state_mapper.is_empty()
+            && consensus_state_mapper.get().timestamp != header.timestamp
+        {
+            self.statuses(&client_id).set(ClientStatus::Frozen);
+            self.client_frozen_event(&client_id);
+
+            return ManagedVec::new();
+        }
+
         let mapper = self.client_states(&client_id);
         let latest_client_state = mapper.get();
         if header.height > latest_client_state.latest_height {
             mapper.set(client_state::Data::new(header.height));
         }
 
-        self.consensus_states(&client_id, &header.height.to_biguint_concat())
-            .set(consensus_state::Data::new(header.timestamp));
+        consensus_state_mapper.set(consensus_state::Data::new(header.timestamp));
+        self.set_processed_time_and_height(&client_id, &header.height);
 
         ManagedVec::from_single_item(header.height)
     }
 
+    /// replaces the state of the subject client with the latest state of the substitute client, and returns the
+    /// recovered height
+    #[endpoint(recoverClient)]
+    fn recover_client(
+        &self,
+        subject_client_id: ClientId<Self::Api>,
+        substitute_client_id: ClientId<Self::Api>,
+    ) -> height::Data {
+        self.require_ibc_handler_caller();
+        self.require_known_client(&subject_client_id);
+        self.require_known_client(&substitute_client_id);
+
+        let substitute_client_state = self.client_states(&substitute_client_id).get();
+        let height_key = substitute_client_state.latest_height.to_biguint_concat();
+        let substitute_consensus_state = self
+            .consensus_states(&substitute_client_id, &height_key)
+            .get();
+
+        let latest_height = substitute_client_state.latest_height;
+        self.client_states(&subject_client_id)
+            .set(substitute_client_state);
+        self.consensus_states(&subject_client_id, &height_key)
+            .set(substitute_consensus_state);
+        self.copy_processed_time_and_height(
+            &substitute_client_id,
+            &subject_client_id,
+            &latest_height,
+        );
+        self.statuses(&subject_client_id).set(ClientStatus::Active);
+
+        latest_height
+    }
+
+    /// freezes the client, given two headers at the same height with different timestamps, or a header with an earlier
+    /// or equal timestamp than the one of a lower header
+    #[endpoint(submitMisbehaviour)]
+    fn submit_misbehaviour(
+        &self,
+        client_id: ClientId<Self::Api>,
+        misbehaviour: misbehaviour::Data,
+    ) {
+        self.require_not_frozen(&client_id);
+
+        let header_1 = &misbehaviour.header_1;
+        let header_2 = &misbehaviour.header_2;
+        require!(
+            header_1.height >= header_2.height,
+            "Header 1 height must be at least the header 2 height"
+        );
+
+        let is_misbehaviour = if header_1.height == header_2.height {
+            header_1.timestamp != header_2.timestamp
+        } else {
+            header_1.timestamp <= header_2.timestamp
+        };
+        require!(is_misbehaviour, "Headers do not conflict");
+
+        self.statuses(&client_id).set(ClientStatus::Frozen);
+        self.client_frozen_event(&client_id);
+    }
+
     fn require_known_client(&self, client_id: &ClientId<Self::Api>) {
         require!(!self.client_states(client_id).is_empty(), "Unknown client");
     }
 
+    fn require_not_frozen(&self, client_id: &ClientId<Self::Api>) {
+        self.require_known_client(client_id);
+        require!(
+            self.statuses(client_id).get() != ClientStatus::Frozen,
+            "Client is frozen"
+        );
+    }
+
+    #[event("clientFrozenEvent")]
+    fn client_frozen_event(&self, #[indexed] client_id: &ClientId<Self::Api>);
+
     #[storage_mapper("clientStates")]
     fn client_states(
         &self,
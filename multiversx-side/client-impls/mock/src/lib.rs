@@ -4,11 +4,15 @@ multiversx_sc::imports!();
 
 pub mod client_logic;
 pub mod mock_types;
+pub mod upgrade;
 pub mod views;
 
 #[multiversx_sc::contract]
 pub trait Mock:
-    client_common::CommonClientLogicModule + client_logic::ClientLogicModule + views::ViewsModule
+    client_common::CommonClientLogicModule
+    + client_logic::ClientLogicModule
+    + upgrade::UpgradeModule
+    + views::ViewsModule
 {
     #[init]
     fn init(&self, ibc_handler: ManagedAddress) {
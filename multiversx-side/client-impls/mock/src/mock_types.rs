@@ -1,9 +1,11 @@
 pub mod client_state {
-    use common_types::channel_types::height;
+    use common_types::{channel_types::height, proto};
 
     multiversx_sc::imports!();
     multiversx_sc::derive_imports!();
 
+    pub static TYPE_URL: &[u8] = b"/ibc.lightclients.mock.ClientState";
+
     #[derive(TypeAbi, TopEncode, TopDecode)]
     pub struct Data {
         pub latest_height: height::Data,
@@ -14,15 +16,25 @@ pub mod client_state {
         pub fn new(latest_height: height::Data) -> Self {
             Self { latest_height }
         }
+
+        /// ibc.lightclients.mock.ClientState
+        pub fn to_proto<M: ManagedTypeApi>(&self) -> ManagedBuffer<M> {
+            let mut encoded = ManagedBuffer::new();
+            proto::append_message_field(&mut encoded, 1, &self.latest_height.to_proto());
+
+            encoded
+        }
     }
 }
 
 pub mod consensus_state {
-    use common_types::UnixTimestamp;
+    use common_types::{proto, UnixTimestamp};
 
     multiversx_sc::imports!();
     multiversx_sc::derive_imports!();
 
+    pub static TYPE_URL: &[u8] = b"/ibc.lightclients.mock.ConsensusState";
+
     #[derive(TypeAbi, TopEncode, TopDecode)]
     pub struct Data {
         pub timestamp: UnixTimestamp,
@@ -33,6 +45,14 @@ pub mod consensus_state {
         pub fn new(timestamp: UnixTimestamp) -> Self {
             Self { timestamp }
         }
+
+        /// ibc.lightclients.mock.ConsensusState
+        pub fn to_proto<M: ManagedTypeApi>(&self) -> ManagedBuffer<M> {
+            let mut encoded = ManagedBuffer::new();
+            proto::append_uint_field(&mut encoded, 1, self.timestamp);
+
+            encoded
+        }
     }
 }
 
@@ -42,9 +62,22 @@ pub mod header {
     multiversx_sc::imports!();
     multiversx_sc::derive_imports!();
 
-    #[derive(TypeAbi, TopEncode, TopDecode)]
+    #[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
     pub struct Data {
         pub height: height::Data,
         pub timestamp: UnixTimestamp,
     }
 }
+
+pub mod misbehaviour {
+    use super::header;
+
+    multiversx_sc::imports!();
+    multiversx_sc::derive_imports!();
+
+    #[derive(TypeAbi, TopEncode, TopDecode)]
+    pub struct Data {
+        pub header_1: header::Data,
+        pub header_2: header::Data,
+    }
+}
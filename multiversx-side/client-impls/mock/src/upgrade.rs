@@ -0,0 +1,69 @@
+use client_common::UPGRADE_STORE_KEY;
+use common_types::{channel_types::height, ClientId, Proof};
+
+use crate::mock_types::{client_state, consensus_state};
+
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait UpgradeModule:
+    client_common::CommonClientLogicModule
+    + crate::client_logic::ClientLogicModule
+    + crate::views::ViewsModule
+{
+    /// restarts the client from the upgraded states committed by the counterparty at the latest height, and returns
+    /// the upgraded height
+    #[endpoint(upgradeClient)]
+    fn upgrade_client(
+        &self,
+        client_id: ClientId<Self::Api>,
+        client_state: client_state::Data,
+        consensus_state: consensus_state::Data,
+        proof_upgrade_client: Proof<Self::Api>,
+        proof_upgrade_consensus_state: Proof<Self::Api>,
+    ) -> height::Data {
+        self.require_ibc_handler_caller();
+        self.require_not_frozen(&client_id);
+
+        let latest_height = self.get_latest_height(&client_id);
+        require!(
+            client_state.latest_height > latest_height,
+            "Upgraded client height must be greater than the current height"
+        );
+
+        let upgrade_store_key = ManagedBuffer::from(UPGRADE_STORE_KEY);
+        let mut encoded_client_state = ManagedBuffer::new();
+        let _ = client_state.top_encode(&mut encoded_client_state);
+        let client_proof = self.encode_and_hash(
+            &latest_height,
+            &upgrade_store_key,
+            &self.get_upgraded_client_path(latest_height.revision_height),
+            &encoded_client_state,
+        );
+        require!(
+            client_proof.as_managed_buffer() == &proof_upgrade_client,
+            "Failed to verify upgraded client"
+        );
+
+        let mut encoded_consensus_state = ManagedBuffer::new();
+        let _ = consensus_state.top_encode(&mut encoded_consensus_state);
+        let consensus_state_proof = self.encode_and_hash(
+            &latest_height,
+            &upgrade_store_key,
+            &self.get_upgraded_consensus_state_path(latest_height.revision_height),
+            &encoded_consensus_state,
+        );
+        require!(
+            consensus_state_proof.as_managed_buffer() == &proof_upgrade_consensus_state,
+            "Failed to verify upgraded consensus state"
+        );
+
+        let upgraded_height = client_state.latest_height;
+        self.client_states(&client_id).set(client_state);
+        self.consensus_states(&client_id, &upgraded_height.to_biguint_concat())
+            .set(consensus_state);
+        self.set_processed_time_and_height(&client_id, &upgraded_height);
+
+        upgraded_height
+    }
+}
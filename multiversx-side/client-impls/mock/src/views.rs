@@ -1,7 +1,7 @@
 use client_common::{
     ClientStatus, GetLatestInfoResultType, VerifyMembershipArgs, VerifyNonMembershipArgs,
 };
-use common_types::{channel_types::height, ClientId, Hash, UnixTimestamp};
+use common_types::{channel_types::height, client_types::any, ClientId, Hash, UnixTimestamp};
 use host::host_views::ProxyTrait as _;
 
 use crate::mock_types::{client_state, consensus_state};
@@ -61,11 +61,19 @@ pub trait ViewsModule:
     /// The caller is expected to construct the full CommitmentPath from a CommitmentPrefix and a standardized path (as defined in ICS 24)
     #[view(verifyMembership)]
     fn verify_membership(&self, args: VerifyMembershipArgs<Self::Api>) -> bool {
+        self.require_known_client(&args.client_id);
+        self.require_active_status(self.get_status(&args.client_id));
         let _ = self.get_timestamp_at_height(&args.client_id, &args.height);
+        self.require_delay_period_passed(
+            &args.client_id,
+            &args.height,
+            args.delay_time_period,
+            args.delay_block_period,
+        );
         self.require_ibc_prefix(&args.prefix);
 
         let local_proof = self.encode_and_hash(&args.height, &args.prefix, &args.path, &args.value);
-        local_proof == args.proof
+        local_proof.as_managed_buffer() == &args.proof
     }
 
     /// A generic proof verification method which verifies the absence of a given CommitmentPath at a specified height
@@ -73,7 +81,15 @@ pub trait ViewsModule:
     /// The caller is expected to construct the full CommitmentPath from a CommitmentPrefix and a standardized path (as defined in ICS 24)
     #[view(verifyNonMembership)]
     fn verify_non_membership(&self, args: VerifyNonMembershipArgs<Self::Api>) -> bool {
+        self.require_known_client(&args.client_id);
+        self.require_active_status(self.get_status(&args.client_id));
         let _ = self.get_timestamp_at_height(&args.client_id, &args.height);
+        self.require_delay_period_passed(
+            &args.client_id,
+            &args.height,
+            args.delay_time_period,
+            args.delay_block_period,
+        );
         self.require_ibc_prefix(&args.prefix);
 
         let local_proof = self.encode_and_hash(
@@ -82,7 +98,7 @@ pub trait ViewsModule:
             &args.path,
             &ManagedBuffer::new(),
         );
-        local_proof == args.proof
+        local_proof.as_managed_buffer() == &args.proof
     }
 
     /// returns the clientState corresponding to `clientId`
@@ -106,6 +122,26 @@ pub trait ViewsModule:
         mapper.get()
     }
 
+    /// returns the clientState corresponding to `clientId`, encoded as a protobuf Any, as committed by the handler
+    #[view(getClientStateAny)]
+    fn get_client_state_any(&self, client_id: &ClientId<Self::Api>) -> ManagedBuffer {
+        let client_state = self.get_client_state(client_id);
+
+        any::Data::new(client_state::TYPE_URL, client_state.to_proto()).to_proto()
+    }
+
+    /// returns the consensus state at `height`, encoded as a protobuf Any, as committed by the handler
+    #[view(getConsensusStateAny)]
+    fn get_consensus_state_any(
+        &self,
+        client_id: &ClientId<Self::Api>,
+        height: &height::Data,
+    ) -> ManagedBuffer {
+        let consensus_state = self.get_consensus_state(client_id, height);
+
+        any::Data::new(consensus_state::TYPE_URL, consensus_state.to_proto()).to_proto()
+    }
+
     fn require_ibc_prefix(&self, prefix: &ManagedBuffer) {
         let ibc_handler = self.ibc_handler().get();
         let ibc_prefix: ManagedBuffer = self
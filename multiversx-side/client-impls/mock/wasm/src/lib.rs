@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           12
+// Endpoints:                           17
 // Async Callback (empty):               1
-// Total number of exported functions:  15
+// Total number of exported functions:  20
 
 #![no_std]
 
@@ -24,6 +24,9 @@ multiversx_sc_wasm_adapter::endpoints! {
         initializeClient => initialize_client
         setStatus => set_status
         updateClient => update_client
+        recoverClient => recover_client
+        submitMisbehaviour => submit_misbehaviour
+        upgradeClient => upgrade_client
         getTimestampAtHeight => get_timestamp_at_height
         getLatestHeight => get_latest_height
         getStatus => get_status
@@ -32,6 +35,8 @@ multiversx_sc_wasm_adapter::endpoints! {
         verifyNonMembership => verify_non_membership
         getClientState => get_client_state
         getConsensusState => get_consensus_state
+        getClientStateAny => get_client_state_any
+        getConsensusStateAny => get_consensus_state_any
     )
 }
 
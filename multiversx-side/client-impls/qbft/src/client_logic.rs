@@ -1,21 +1,32 @@
-use crate::qbft_types::{client_state, consensus_state, header};
-use common_types::{channel_types::height, ClientId, Hash, UnixTimestamp};
+use crate::qbft_types::{client_state, consensus_state, header, EthAddress, ETH_ADDRESS_LENGTH};
+use common_types::{channel_types::height, ClientId, Hash, UnixTimestamp, HASH_LENGTH};
+use eth_proofs::{mpt, rlp};
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-// Likely don't need all this
+const MIN_HEADER_ITEMS: usize = 15;
+const STATE_ROOT_INDEX: usize = 3;
+const HEIGHT_INDEX: usize = 8;
+const TIME_INDEX: usize = 11;
+const EXTRA_DATA_INDEX: usize = 12;
+// IBFT2: {Vanity, Validators, Vote, Round}
+// QBFT:  {Vanity, Validators, Vote, Round, Empty-Seals}
+const IBFT2_EXTRA_DATA_ITEMS: usize = 4;
+const QBFT_EXTRA_DATA_ITEMS: usize = 5;
+const EXTRA_DATA_VALIDATORS_INDEX: usize = 1;
 
-// static HEADER_TYPE_URL: &[u8] = b"/ibc.lightclients.qbft.v1.Header";
-// static CLIENT_STATE_TYPE_URL: &[u8] = b"/ibc.lightclients.qbft.v1.ClientState";
-// static CONSENSUS_STATE_TYPE_URL: &[u8] = b"/ibc.lightclients.qbft.v1.ConsensusState";
+const SEAL_LEN: usize = 65;
+const SIGNATURE_COMPONENT_LEN: usize = 32;
+const UNCOMPRESSED_PUBLIC_KEY_LEN: usize = 65;
+const UNCOMPRESSED_PUBLIC_KEY_PREFIX: u8 = 0x04;
 
 pub struct ParsedBesuHeader<M: ManagedTypeApi> {
     pub base: header::Data<M>,
     pub height: height::Data,
     pub state_root: Hash<M>,
     pub time: UnixTimestamp,
-    pub validators: ManagedVec<M, ManagedAddress<M>>, // TODO: Was RLPReader.RLPItem[]. Why?
+    pub validators: ManagedVec<M, EthAddress<M>>,
 }
 
 #[multiversx_sc::module]
@@ -25,6 +36,7 @@ pub trait ClientLogicModule:
     + host::storage::StorageModule
     + common_modules::utils::UtilsModule
 {
+    /// creates a new client with the given state
     #[endpoint(initializeClient)]
     fn initialize_client(
         &self,
@@ -34,76 +46,259 @@ pub trait ClientLogicModule:
     ) -> height::Data {
         self.require_ibc_handler_caller();
         require!(
-            client_state.latest_height.revision_height != 0,
+            client_state.latest_height.revision_number == 0
+                && client_state.latest_height.revision_height != 0,
             "Invalid client state height"
         );
+        require!(client_state.trusting_period != 0, "Invalid trusting period");
+        require!(consensus_state.timestamp != 0, "Invalid consensus state");
         require!(!consensus_state.validators.is_empty(), "Empty validators");
+        self.require_unique_validators(&consensus_state.validators);
 
-        self.client_states(&client_id).set(&client_state);
+        let mapper = self.client_states(&client_id);
+        require!(mapper.is_empty(), "Client already known");
+
+        mapper.set(&client_state);
         self.consensus_states(&client_id, &client_state.latest_height.to_biguint_concat())
             .set(consensus_state);
+        self.set_processed_time_and_height(&client_id, &client_state.latest_height);
 
         client_state.latest_height
     }
 
-    /// updates the client with the given header
+    /// updates the client with the given header and returns the updated heights
+    ///
+    /// The header must be sealed by more than 2/3 of its own validators, and by at least 1/3 of the validators trusted at `trusted_height`,
+    /// which allows the validator set to change between the trusted height and the header height
     #[endpoint(updateClient)]
     fn update_client(
         &self,
         client_id: ClientId<Self::Api>,
-        _header: header::Data<Self::Api>,
+        header: header::Data<Self::Api>,
     ) -> ManagedVec<height::Data> {
+        self.require_ibc_handler_caller();
         let client_state_mapper = self.client_states(&client_id);
-        require!(client_state_mapper.is_empty(), "Unknown client");
+        require!(!client_state_mapper.is_empty(), "Unknown client");
+
+        let trusted_consensus_state_mapper =
+            self.consensus_states(&client_id, &header.trusted_height.to_biguint_concat());
+        require!(
+            !trusted_consensus_state_mapper.is_empty(),
+            "Trusted consensus state not found"
+        );
 
-        // TODO
+        let parsed_header = self.parse_besu_header(header);
+        require!(
+            parsed_header.height > parsed_header.base.trusted_height,
+            "Header height must be greater than the trusted height"
+        );
+
+        let consensus_state_mapper =
+            self.consensus_states(&client_id, &parsed_header.height.to_biguint_concat());
+        if !consensus_state_mapper.is_empty() {
+            return ManagedVec::new();
+        }
 
-        ManagedVec::new()
+        let mut client_state = client_state_mapper.get();
+        let trusted_consensus_state = trusted_consensus_state_mapper.get();
+        self.require_valid_header_time(&client_state, &trusted_consensus_state, parsed_header.time);
+        self.verify_commit_seals(&trusted_consensus_state.validators, &parsed_header);
+
+        let storage_root = mpt::verify_account_storage_root(
+            &parsed_header.state_root,
+            &client_state.ibc_store_address,
+            &parsed_header.base.account_state_proof,
+        );
+        consensus_state_mapper.set(consensus_state::Data {
+            timestamp: parsed_header.time,
+            root: storage_root,
+            validators: parsed_header.validators,
+        });
+
+        if parsed_header.height > client_state.latest_height {
+            client_state.latest_height = parsed_header.height;
+            client_state_mapper.set(client_state);
+        }
+
+        self.set_processed_time_and_height(&client_id, &parsed_header.height);
+
+        ManagedVec::from_single_item(parsed_header.height)
     }
 
-    /*
-    function parseBesuHeader(Header.Data memory header) internal pure returns (ParsedBesuHeader memory) {
-        ParsedBesuHeader memory parsedHeader;
+    fn parse_besu_header(&self, header: header::Data<Self::Api>) -> ParsedBesuHeader<Self::Api> {
+        let items = rlp::decode_list(&header.besu_header);
+        require!(
+            items.len() >= MIN_HEADER_ITEMS,
+            "Unexpected Ethereum header format"
+        );
+
+        let state_root = rlp::decode_hash(&items.get(STATE_ROOT_INDEX));
+        let height = height::Data {
+            revision_number: 0,
+            revision_height: rlp::decode_u64(&items.get(HEIGHT_INDEX)),
+        };
+        let time = rlp::decode_u64(&items.get(TIME_INDEX));
 
-        parsedHeader.base = header;
-        RLPReader.RLPItem[] memory items = header.besu_header_rlp.toRlpItem().toList();
-        if (items.length < 15) {
-            revert UnexpectedEthereumHeaderFormat(items.length);
+        let extra_data = rlp::decode_bytes(&items.get(EXTRA_DATA_INDEX));
+        let extra_data_items = rlp::decode_list(&extra_data);
+        require!(
+            extra_data_items.len() == IBFT2_EXTRA_DATA_ITEMS
+                || extra_data_items.len() == QBFT_EXTRA_DATA_ITEMS,
+            "Unexpected extra data format"
+        );
+
+        let mut validators = ManagedVec::new();
+        for encoded_validator in
+            &rlp::decode_list(&extra_data_items.get(EXTRA_DATA_VALIDATORS_INDEX))
+        {
+            let validator = rlp::decode_bytes(&encoded_validator);
+            match EthAddress::try_from(validator) {
+                Ok(validator) => validators.push(validator),
+                Err(_) => sc_panic!("Invalid validator address"),
+            }
         }
-        parsedHeader.stateRoot = bytes32(items[3].toUint());
-        parsedHeader.height = Height.Data({revision_number: 0, revision_height: uint64(items[8].toUint())});
-        parsedHeader.time = uint64(items[11].toUint());
-        items = items[12].toBytes().toRlpItem().toList();
-        // IBFT2: {Vanity, Validators, Vote, Round}
-        // QBFT:  {Vanity, Validators, Vote, Round, Empty-Seals}
-        if (items.length != 4 && items.length != 5) {
-            revert UnexpectedExtraDataFormat(items.length);
+        require!(!validators.is_empty(), "Empty validators");
+        self.require_unique_validators(&validators);
+
+        ParsedBesuHeader {
+            base: header,
+            height,
+            state_root,
+            time,
+            validators,
         }
-        parsedHeader.validators = items[1].toList();
-        return parsedHeader;
     }
-     */
-
-    // fn parse_besu_header(&self, header: header::Data<Self::Api>) -> ParsedBesuHeader<Self::Api> {
-    //     let items = header.besu_header
-
-    //     let parsed_header = ParsedBesuHeader {
-    //         base: header,
-    //         height: todo!(),
-    //         state_root: todo!(),
-    //         time: todo!(),
-    //         validators: todo!(),
-    //     };
-    // }
-
-    // TODO: Replace the generic "ManagedBuffer" and "BigUint" with something specific once I figure out what it means
-    // Was this:
-    /*
-        mapping(string => ClientState.Data) internal clientStates;
-        mapping(string => mapping(uint128 => ConsensusState.Data)) internal consensusStates;
-        mapping(string => mapping(uint128 => uint256)) internal processedTimes;
-        mapping(string => mapping(uint128 => uint256)) internal processedHeights;
-    */
+
+    /// seals are counted per validator slot, so a validator listed twice would have its seal counted twice
+    fn require_unique_validators(&self, validators: &ManagedVec<EthAddress<Self::Api>>) {
+        for (i, validator) in validators.iter().enumerate() {
+            for other_validator in validators.iter().skip(i + 1) {
+                require!(*validator != *other_validator, "Duplicate validator");
+            }
+        }
+    }
+
+    fn require_valid_header_time(
+        &self,
+        client_state: &client_state::Data<Self::Api>,
+        trusted_consensus_state: &consensus_state::Data<Self::Api>,
+        header_time: UnixTimestamp,
+    ) {
+        let current_time = self.blockchain().get_block_timestamp();
+        require!(
+            trusted_consensus_state
+                .timestamp
+                .saturating_add(client_state.trusting_period)
+                > current_time,
+            "Trusted consensus state is outside the trusting period"
+        );
+        require!(
+            header_time < current_time.saturating_add(client_state.max_clock_drift),
+            "Header time exceeds the max clock drift"
+        );
+        require!(
+            header_time > trusted_consensus_state.timestamp,
+            "Header time must be greater than the trusted time"
+        );
+    }
+
+    fn verify_commit_seals(
+        &self,
+        trusted_validators: &ManagedVec<EthAddress<Self::Api>>,
+        parsed_header: &ParsedBesuHeader<Self::Api>,
+    ) {
+        let validators = &parsed_header.validators;
+        let seals = &parsed_header.base.seals;
+        let public_keys = &parsed_header.base.validator_public_keys;
+        require!(
+            seals.len() == validators.len() && public_keys.len() == validators.len(),
+            "Seals and public keys must match the validators"
+        );
+
+        let seal_count = seals.iter().filter(|seal| !seal.is_empty()).count();
+        require!(
+            seal_count * 3 > validators.len() * 2,
+            "Insufficient commit seals"
+        );
+
+        let commit_hash = self.crypto().keccak256(&parsed_header.base.besu_header);
+        let mut trusted_seal_count = 0;
+        for i in 0..validators.len() {
+            let seal = seals.get(i);
+            if seal.is_empty() {
+                continue;
+            }
+
+            let validator = validators.get(i).clone_value();
+            let public_key = public_keys.get(i);
+            require!(
+                self.eth_address_from_public_key(&public_key) == validator,
+                "Public key does not match validator"
+            );
+            require!(
+                self.verify_seal(&public_key, &commit_hash, &seal),
+                "Invalid commit seal"
+            );
+
+            if trusted_validators.contains(&validator) {
+                trusted_seal_count += 1;
+            }
+        }
+
+        require!(
+            trusted_seal_count * 3 >= trusted_validators.len(),
+            "Insufficient trusted commit seals"
+        );
+    }
+
+    /// Seals are 65 bytes secp256k1 signatures in the (r, s, v) form
+    fn verify_seal(
+        &self,
+        public_key: &ManagedBuffer,
+        commit_hash: &Hash<Self::Api>,
+        seal: &ManagedBuffer,
+    ) -> bool {
+        require!(seal.len() == SEAL_LEN, "Invalid seal length");
+
+        let r = seal
+            .copy_slice(0, SIGNATURE_COMPONENT_LEN)
+            .unwrap_or_default();
+        let s = seal
+            .copy_slice(SIGNATURE_COMPONENT_LEN, SIGNATURE_COMPONENT_LEN)
+            .unwrap_or_default();
+        let signature = self.crypto().encode_secp256k1_der_signature(&r, &s);
+
+        self.crypto().verify_custom_secp256k1(
+            public_key,
+            commit_hash.as_managed_buffer(),
+            &signature,
+            MessageHashType::ECDSAPlainMsg,
+        )
+    }
+
+    fn eth_address_from_public_key(&self, public_key: &ManagedBuffer) -> EthAddress<Self::Api> {
+        require!(
+            public_key.len() == UNCOMPRESSED_PUBLIC_KEY_LEN,
+            "Invalid public key"
+        );
+
+        let mut key_bytes = [0u8; UNCOMPRESSED_PUBLIC_KEY_LEN];
+        let _ = public_key.load_slice(0, &mut key_bytes);
+        require!(
+            key_bytes[0] == UNCOMPRESSED_PUBLIC_KEY_PREFIX,
+            "Invalid public key"
+        );
+
+        // the address is the last 20 bytes of the keccak256 hash of the key, without the prefix
+        let key_hash = self
+            .crypto()
+            .keccak256(ManagedBuffer::new_from_bytes(&key_bytes[1..]))
+            .to_byte_array();
+        let mut address = [0u8; ETH_ADDRESS_LENGTH];
+        address.copy_from_slice(&key_hash[HASH_LENGTH - ETH_ADDRESS_LENGTH..]);
+
+        EthAddress::new_from_bytes(&address)
+    }
 
     #[storage_mapper("clientStates")]
     fn client_states(
@@ -117,18 +312,4 @@ pub trait ClientLogicModule:
         client_id: &ClientId<Self::Api>,
         height: &BigUint,
     ) -> SingleValueMapper<consensus_state::Data<Self::Api>>;
-
-    #[storage_mapper("processedTimes")]
-    fn processed_times(
-        &self,
-        buffer: &ManagedBuffer,
-        biguint: &BigUint,
-    ) -> SingleValueMapper<BigUint>;
-
-    #[storage_mapper("processedHeights")]
-    fn processed_heights(
-        &self,
-        buffer: &ManagedBuffer,
-        biguint: &BigUint,
-    ) -> SingleValueMapper<BigUint>;
 }
@@ -4,14 +4,14 @@ multiversx_sc::imports!();
 
 pub mod client_logic;
 pub mod qbft_types;
+pub mod unsupported;
 pub mod views;
 
-// Ignore this whole crate, still work in progress. Not even entirely sure we need something like this yet.
-
 #[multiversx_sc::contract]
 pub trait Qbft:
     client_common::CommonClientLogicModule
     + client_logic::ClientLogicModule
+    + unsupported::UnsupportedModule
     + views::ViewsModule
     + host::host_views::HostViewsModule
     + host::storage::StorageModule
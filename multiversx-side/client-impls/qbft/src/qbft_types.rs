@@ -1,44 +1,94 @@
+pub use eth_proofs::{EthAddress, ETH_ADDRESS_LENGTH};
+
 pub mod client_state {
-    use common_types::{channel_types::height, ChainId};
+    use common_types::{channel_types::height, proto, ChainId};
+
+    use super::EthAddress;
 
     multiversx_sc::imports!();
     multiversx_sc::derive_imports!();
 
+    pub static TYPE_URL: &[u8] = b"/ibc.lightclients.qbft.v1.ClientState";
+
     #[derive(TypeAbi, TopEncode, TopDecode)]
     pub struct Data<M: ManagedTypeApi> {
         pub chain_id: ChainId<M>,
-        pub ibc_store_address: ManagedAddress<M>,
+        /// address of the IBC store contract on the counterparty chain, which holds the commitments
+        pub ibc_store_address: EthAddress<M>,
         pub latest_height: height::Data,
-        pub trusting_period: u64, // TODO: Likely timestamp
-        pub max_clock_drift: u64, // TODO: No idea what this means, likely a timestamp too?
+        /// in seconds
+        pub trusting_period: u64,
+        /// in seconds
+        pub max_clock_drift: u64,
+    }
+
+    impl<M: ManagedTypeApi> Data<M> {
+        /// ibc.lightclients.qbft.v1.ClientState
+        pub fn to_proto(&self) -> ManagedBuffer<M> {
+            let mut encoded = ManagedBuffer::new();
+            proto::append_bytes_field(&mut encoded, 1, self.chain_id.as_managed_buffer());
+            proto::append_bytes_field(&mut encoded, 2, self.ibc_store_address.as_managed_buffer());
+            proto::append_message_field(&mut encoded, 3, &self.latest_height.to_proto());
+            proto::append_uint_field(&mut encoded, 4, self.trusting_period);
+            proto::append_uint_field(&mut encoded, 5, self.max_clock_drift);
+
+            encoded
+        }
     }
 }
 
 pub mod consensus_state {
-    use common_types::{Hash, UnixTimestamp};
+    use common_types::{proto, Hash, UnixTimestamp};
+
+    use super::EthAddress;
 
     multiversx_sc::imports!();
     multiversx_sc::derive_imports!();
 
+    pub static TYPE_URL: &[u8] = b"/ibc.lightclients.qbft.v1.ConsensusState";
+
     #[derive(TypeAbi, TopEncode, TopDecode)]
     pub struct Data<M: ManagedTypeApi> {
+        /// seconds since unix epoch, as found in the besu header
         pub timestamp: UnixTimestamp,
+        /// storage root of the IBC store contract
         pub root: Hash<M>,
-        pub validators: ManagedVec<M, ManagedAddress<M>>, // TODO: Why was this bytes[]? Also, might be able to use ArrayVec over ManagedVec
+        pub validators: ManagedVec<M, EthAddress<M>>,
+    }
+
+    impl<M: ManagedTypeApi> Data<M> {
+        /// ibc.lightclients.qbft.v1.ConsensusState
+        pub fn to_proto(&self) -> ManagedBuffer<M> {
+            let mut encoded = ManagedBuffer::new();
+            proto::append_uint_field(&mut encoded, 1, self.timestamp);
+            proto::append_bytes_field(&mut encoded, 2, self.root.as_managed_buffer());
+            for validator in self.validators.iter() {
+                proto::append_message_field(&mut encoded, 3, validator.as_managed_buffer());
+            }
+
+            encoded
+        }
     }
 }
 
 pub mod header {
-    use common_types::{channel_types::height, Hash};
+    use common_types::channel_types::height;
 
     multiversx_sc::imports!();
     multiversx_sc::derive_imports!();
 
     #[derive(TypeAbi, TopEncode, TopDecode)]
     pub struct Data<M: ManagedTypeApi> {
+        /// RLP encoded header, with the commit seals removed from the extra data
         pub besu_header: ManagedBuffer<M>,
+        /// commit seals, in the same order as the validators from the header. Empty for missing seals.
         pub seals: ManagedVec<M, ManagedBuffer<M>>,
+        /// uncompressed secp256k1 public keys of the validators from the header, in the same order.
+        ///
+        /// Required since there is no public key recovery available. Empty for missing seals.
+        pub validator_public_keys: ManagedVec<M, ManagedBuffer<M>>,
         pub trusted_height: height::Data,
-        pub account_state_proof: Hash<M>,
+        /// RLP encoded list of the account proof nodes of the IBC store contract
+        pub account_state_proof: ManagedBuffer<M>,
     }
 }
@@ -0,0 +1,40 @@
+use common_types::{channel_types::height, ClientId, Proof};
+
+multiversx_sc::imports!();
+
+/// Generic client endpoints which QBFT clients do not support
+///
+/// Besu chains have no standard for committing upgraded clients, and a QBFT client has no frozen state, so
+/// misbehaviour can neither be submitted nor recovered from
+#[multiversx_sc::module]
+pub trait UnsupportedModule {
+    #[endpoint(upgradeClient)]
+    fn upgrade_client(
+        &self,
+        _client_id: ClientId<Self::Api>,
+        _encoded_client_state: ManagedBuffer,
+        _encoded_consensus_state: ManagedBuffer,
+        _proof_upgrade_client: Proof<Self::Api>,
+        _proof_upgrade_consensus_state: Proof<Self::Api>,
+    ) -> height::Data {
+        sc_panic!("Client upgrades are unsupported by QBFT clients");
+    }
+
+    #[endpoint(submitMisbehaviour)]
+    fn submit_misbehaviour(
+        &self,
+        _client_id: ClientId<Self::Api>,
+        _encoded_misbehaviour: ManagedBuffer,
+    ) {
+        sc_panic!("Misbehaviour is unsupported by QBFT clients");
+    }
+
+    #[endpoint(recoverClient)]
+    fn recover_client(
+        &self,
+        _subject_client_id: ClientId<Self::Api>,
+        _substitute_client_id: ClientId<Self::Api>,
+    ) -> height::Data {
+        sc_panic!("Client recovery is unsupported by QBFT clients");
+    }
+}
@@ -1,9 +1,20 @@
-use client_common::{ClientStatus, GetLatestInfoResultType};
-use common_types::{channel_types::height, ClientId, UnixTimestamp};
+use client_common::{
+    ClientStatus, GetLatestInfoResultType, VerifyMembershipArgs, VerifyNonMembershipArgs,
+};
+use common_types::{
+    channel_types::height, client_types::any, ClientId, Hash, UnixTimestamp, HASH_LENGTH,
+};
+
+use eth_proofs::mpt;
+
+use crate::qbft_types::{client_state, consensus_state};
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+/// storage slot of the commitments mapping in the IBC store contract
+const COMMITMENTS_SLOT: [u8; HASH_LENGTH] = [0u8; HASH_LENGTH];
+
 #[multiversx_sc::module]
 pub trait ViewsModule:
     client_common::CommonClientLogicModule
@@ -19,27 +30,39 @@ pub trait ViewsModule:
         client_id: ClientId<Self::Api>,
         height: height::Data,
     ) -> UnixTimestamp {
-        let mapper = self.consensus_states(&client_id, &height.to_biguint_concat());
-        require!(!mapper.is_empty(), "Consensus state not found");
+        let consensus_state = self.get_consensus_state(&client_id, &height);
 
-        let consensus_state = mapper.get();
         self.checked_timestamp_to_unix_mul(consensus_state.timestamp)
     }
 
     /// returns the latest height of the client state corresponding to `clientId`
     #[view(getLatestHeight)]
     fn get_latest_height(&self, client_id: &ClientId<Self::Api>) -> height::Data {
-        let mapper = self.client_states(client_id);
-        require!(!mapper.is_empty(), "Client state not found");
+        let client_state = self.get_client_state(client_id);
 
-        let client_state = mapper.get();
         client_state.latest_height
     }
 
     /// returns the status of the client corresponding to `clientId`
+    ///
+    /// The client is expired once the latest consensus state is outside the trusting period. "None" means the client is unknown.
     #[view(getStatus)]
-    fn get_status(&self, _client_id: &ClientId<Self::Api>) -> ClientStatus {
-        // TODO: Unsure why it's always considered active?
+    fn get_status(&self, client_id: &ClientId<Self::Api>) -> ClientStatus {
+        let client_state_mapper = self.client_states(client_id);
+        if client_state_mapper.is_empty() {
+            return ClientStatus::None;
+        }
+
+        let client_state = client_state_mapper.get();
+        let latest_consensus_state =
+            self.get_consensus_state(client_id, &client_state.latest_height);
+        let expiry_time = latest_consensus_state
+            .timestamp
+            .saturating_add(client_state.trusting_period);
+        if expiry_time <= self.blockchain().get_block_timestamp() {
+            return ClientStatus::Expired;
+        }
+
         ClientStatus::Active
     }
 
@@ -47,15 +70,105 @@ pub trait ViewsModule:
     #[view(getLatestInfo)]
     fn get_latest_info(&self, client_id: ClientId<Self::Api>) -> GetLatestInfoResultType {
         let latest_height = self.get_latest_height(&client_id);
-        let consensus_state = self
-            .consensus_states(&client_id, &latest_height.to_biguint_concat())
-            .get();
         let client_status = self.get_status(&client_id);
+        let latest_timestamp = self.get_timestamp_at_height(client_id, latest_height);
 
         GetLatestInfoResultType {
             latest_height,
-            latest_timestamp: consensus_state.timestamp,
+            latest_timestamp,
             client_status,
         }
     }
+
+    /// Verifies the storage proof of the commitment for `path` in the IBC store contract, at the given height
+    ///
+    /// The proof is the RLP encoded list of storage proof nodes, as returned by `eth_getProof`. The prefix is not used,
+    /// since all commitments are found in the same contract.
+    #[view(verifyMembership)]
+    fn verify_membership(&self, args: VerifyMembershipArgs<Self::Api>) -> bool {
+        self.require_active_status(self.get_status(&args.client_id));
+        let consensus_state = self.get_consensus_state(&args.client_id, &args.height);
+        self.require_delay_period_passed(
+            &args.client_id,
+            &args.height,
+            args.delay_time_period,
+            args.delay_block_period,
+        );
+        let slot = self.get_commitment_slot(&args.path);
+        let stored_value =
+            match mpt::verify_storage_value(&consensus_state.root, &slot, &args.proof) {
+                Some(stored_value) => stored_value,
+                None => return false,
+            };
+
+        // commitments are stored as keccak256(value), but storage values are encoded without leading zeros
+        let commitment = self.crypto().keccak256(&args.value);
+        BigUint::from_bytes_be_buffer(&stored_value)
+            == BigUint::from_bytes_be_buffer(commitment.as_managed_buffer())
+    }
+
+    /// Verifies the storage proof of absence of the commitment for `path` in the IBC store contract, at the given height
+    #[view(verifyNonMembership)]
+    fn verify_non_membership(&self, args: VerifyNonMembershipArgs<Self::Api>) -> bool {
+        self.require_active_status(self.get_status(&args.client_id));
+        let consensus_state = self.get_consensus_state(&args.client_id, &args.height);
+        self.require_delay_period_passed(
+            &args.client_id,
+            &args.height,
+            args.delay_time_period,
+            args.delay_block_period,
+        );
+        let slot = self.get_commitment_slot(&args.path);
+
+        mpt::verify_storage_value(&consensus_state.root, &slot, &args.proof).is_none()
+    }
+
+    /// returns the clientState corresponding to `clientId`
+    #[view(getClientState)]
+    fn get_client_state(&self, client_id: &ClientId<Self::Api>) -> client_state::Data<Self::Api> {
+        let mapper = self.client_states(client_id);
+        require!(!mapper.is_empty(), "Client state not found");
+
+        mapper.get()
+    }
+
+    #[view(getConsensusState)]
+    fn get_consensus_state(
+        &self,
+        client_id: &ClientId<Self::Api>,
+        height: &height::Data,
+    ) -> consensus_state::Data<Self::Api> {
+        let mapper = self.consensus_states(client_id, &height.to_biguint_concat());
+        require!(!mapper.is_empty(), "Consensus state not found");
+
+        mapper.get()
+    }
+
+    /// returns the clientState corresponding to `clientId`, encoded as a protobuf Any, as committed by the handler
+    #[view(getClientStateAny)]
+    fn get_client_state_any(&self, client_id: &ClientId<Self::Api>) -> ManagedBuffer {
+        let client_state = self.get_client_state(client_id);
+
+        any::Data::new(client_state::TYPE_URL, client_state.to_proto()).to_proto()
+    }
+
+    /// returns the consensus state at `height`, encoded as a protobuf Any, as committed by the handler
+    #[view(getConsensusStateAny)]
+    fn get_consensus_state_any(
+        &self,
+        client_id: &ClientId<Self::Api>,
+        height: &height::Data,
+    ) -> ManagedBuffer {
+        let consensus_state = self.get_consensus_state(client_id, height);
+
+        any::Data::new(consensus_state::TYPE_URL, consensus_state.to_proto()).to_proto()
+    }
+
+    /// slot of `commitments[keccak256(path)]`, i.e. keccak256(keccak256(path) || COMMITMENTS_SLOT)
+    fn get_commitment_slot(&self, path: &ManagedBuffer) -> Hash<Self::Api> {
+        let mut slot_preimage = self.crypto().keccak256(path).as_managed_buffer().clone();
+        slot_preimage.append_bytes(&COMMITMENTS_SLOT);
+
+        self.crypto().keccak256(slot_preimage)
+    }
 }
@@ -0,0 +1,315 @@
+use std::{cell::RefCell, rc::Rc};
+
+use client_common::{ClientStatus, CommonClientLogicModule};
+use common_types::channel_types::height;
+use multiversx_sc::types::ManagedBuffer;
+use multiversx_sc_scenario::{
+    imports::BlockchainStateWrapper, managed_buffer, rust_biguint, DebugApi,
+};
+use qbft::{client_logic::ClientLogicModule, unsupported::UnsupportedModule, views::ViewsModule};
+use qbft_setup::{
+    vectors::{self, HEADER_HEIGHT, HEADER_TIME, VALIDATOR_PUBLIC_KEYS},
+    QbftSetup, CLIENT_ID, MAX_CLOCK_DRIFT, TRUSTED_HEIGHT, TRUSTED_TIMESTAMP, TRUSTING_PERIOD,
+};
+
+pub mod qbft_setup;
+
+// Seals cannot be verified in the debug VM (secp256k1 is unavailable),
+// so the update tests cover the checks done before seal verification
+
+fn get_qbft_setup<
+    HostObjBuilder: 'static + Copy + Fn() -> host::ContractObj<DebugApi>,
+    QbftObjBuilder: 'static + Copy + Fn() -> qbft::ContractObj<DebugApi>,
+>(
+    host_builder: HostObjBuilder,
+    qbft_builder: QbftObjBuilder,
+) -> QbftSetup<HostObjBuilder, QbftObjBuilder> {
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_biguint!(0));
+    QbftSetup::new(
+        Rc::new(RefCell::new(b_mock)),
+        &owner,
+        host_builder,
+        qbft_builder,
+    )
+}
+
+#[test]
+fn initialize_client_test() {
+    let setup = get_qbft_setup(host::contract_obj, qbft::contract_obj);
+    setup.b_mock.borrow_mut().set_block_nonce(5);
+    setup.initialize_client(TRUSTED_HEIGHT).assert_ok();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.qbft_wrapper, |sc| {
+            let client_id = managed_buffer!(CLIENT_ID);
+            let latest_info = sc.get_latest_info(client_id.clone());
+            assert!(latest_info.latest_height == TRUSTED_HEIGHT);
+            assert_eq!(
+                latest_info.latest_timestamp,
+                TRUSTED_TIMESTAMP * 1_000_000_000
+            );
+            assert!(latest_info.client_status == ClientStatus::Active);
+
+            let height_key = TRUSTED_HEIGHT.to_biguint_concat();
+            assert_eq!(
+                sc.processed_times(&client_id, &height_key).get(),
+                TRUSTED_TIMESTAMP + 20
+            );
+            assert_eq!(sc.processed_heights(&client_id, &height_key).get(), 5);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn initialize_client_not_ibc_handler_test() {
+    let setup = get_qbft_setup(host::contract_obj, qbft::contract_obj);
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(&setup.owner, &setup.qbft_wrapper, &rust_biguint!(0), |sc| {
+            let _ = sc.initialize_client(
+                managed_buffer!(CLIENT_ID),
+                qbft_setup::new_client_state(TRUSTED_HEIGHT),
+                qbft_setup::new_consensus_state(TRUSTED_TIMESTAMP, &vectors::VALIDATORS),
+            );
+        })
+        .assert_user_error("Only the IBC handler may call this endpoint");
+}
+
+#[test]
+fn initialize_client_twice_test() {
+    let setup = get_qbft_setup(host::contract_obj, qbft::contract_obj);
+    setup.initialize_client(TRUSTED_HEIGHT).assert_ok();
+    setup
+        .initialize_client(TRUSTED_HEIGHT)
+        .assert_user_error("Client already known");
+}
+
+#[test]
+fn update_client_not_ibc_handler_test() {
+    let setup = get_qbft_setup(host::contract_obj, qbft::contract_obj);
+    setup.initialize_client(TRUSTED_HEIGHT).assert_ok();
+    setup
+        .update_client_as(
+            &setup.relayer,
+            vectors::HEADER,
+            TRUSTED_HEIGHT,
+            &[0, 1, 2],
+            VALIDATOR_PUBLIC_KEYS,
+        )
+        .assert_user_error("Only the IBC handler may call this endpoint");
+}
+
+#[test]
+fn update_client_unknown_client_test() {
+    let setup = get_qbft_setup(host::contract_obj, qbft::contract_obj);
+    setup
+        .update_client(
+            vectors::HEADER,
+            TRUSTED_HEIGHT,
+            &[0, 1, 2],
+            VALIDATOR_PUBLIC_KEYS,
+        )
+        .assert_user_error("Unknown client");
+}
+
+#[test]
+fn update_client_trusted_consensus_state_not_found_test() {
+    let setup = get_qbft_setup(host::contract_obj, qbft::contract_obj);
+    setup
+        .initialize_client(height::Data {
+            revision_number: 0,
+            revision_height: 9,
+        })
+        .assert_ok();
+    setup
+        .update_client(
+            vectors::HEADER,
+            TRUSTED_HEIGHT,
+            &[0, 1, 2],
+            VALIDATOR_PUBLIC_KEYS,
+        )
+        .assert_user_error("Trusted consensus state not found");
+}
+
+#[test]
+fn update_client_invalid_extra_data_test() {
+    let setup = get_qbft_setup(host::contract_obj, qbft::contract_obj);
+    setup.initialize_client(TRUSTED_HEIGHT).assert_ok();
+    setup
+        .update_client(
+            vectors::INVALID_EXTRA_DATA_HEADER,
+            TRUSTED_HEIGHT,
+            &[0, 1, 2],
+            VALIDATOR_PUBLIC_KEYS,
+        )
+        .assert_user_error("Unexpected extra data format");
+}
+
+#[test]
+fn update_client_outside_trusting_period_test() {
+    let setup = get_qbft_setup(host::contract_obj, qbft::contract_obj);
+    setup.initialize_client(TRUSTED_HEIGHT).assert_ok();
+    setup
+        .b_mock
+        .borrow_mut()
+        .set_block_timestamp(TRUSTED_TIMESTAMP + TRUSTING_PERIOD);
+    setup
+        .update_client(
+            vectors::HEADER,
+            TRUSTED_HEIGHT,
+            &[0, 1, 2],
+            VALIDATOR_PUBLIC_KEYS,
+        )
+        .assert_user_error("Trusted consensus state is outside the trusting period");
+}
+
+#[test]
+fn update_client_max_clock_drift_test() {
+    let setup = get_qbft_setup(host::contract_obj, qbft::contract_obj);
+    setup.initialize_client(TRUSTED_HEIGHT).assert_ok();
+    setup
+        .b_mock
+        .borrow_mut()
+        .set_block_timestamp(HEADER_TIME - MAX_CLOCK_DRIFT);
+    setup
+        .update_client(
+            vectors::HEADER,
+            TRUSTED_HEIGHT,
+            &[0, 1, 2],
+            VALIDATOR_PUBLIC_KEYS,
+        )
+        .assert_user_error("Header time exceeds the max clock drift");
+}
+
+#[test]
+fn update_client_insufficient_seals_test() {
+    let setup = get_qbft_setup(host::contract_obj, qbft::contract_obj);
+    setup.initialize_client(TRUSTED_HEIGHT).assert_ok();
+    setup
+        .update_client(
+            vectors::HEADER,
+            TRUSTED_HEIGHT,
+            &[0, 3],
+            VALIDATOR_PUBLIC_KEYS,
+        )
+        .assert_user_error("Insufficient commit seals");
+}
+
+#[test]
+fn update_client_public_key_mismatch_test() {
+    let setup = get_qbft_setup(host::contract_obj, qbft::contract_obj);
+    setup.initialize_client(TRUSTED_HEIGHT).assert_ok();
+
+    let mut public_keys = VALIDATOR_PUBLIC_KEYS;
+    public_keys.swap(0, 1);
+    setup
+        .update_client(vectors::HEADER, TRUSTED_HEIGHT, &[0, 1, 2], public_keys)
+        .assert_user_error("Public key does not match validator");
+}
+
+#[test]
+fn update_client_duplicate_validators_test() {
+    let setup = get_qbft_setup(host::contract_obj, qbft::contract_obj);
+    setup.initialize_client(TRUSTED_HEIGHT).assert_ok();
+
+    // a single trusted validator must not be able to fill every validator slot with its own seal
+    setup
+        .update_client(
+            vectors::DUPLICATE_VALIDATORS_HEADER,
+            TRUSTED_HEIGHT,
+            &[0, 1, 2, 3],
+            [VALIDATOR_PUBLIC_KEYS[0]; 4],
+        )
+        .assert_user_error("Duplicate validator");
+}
+
+#[test]
+fn update_client_not_after_trusted_height_test() {
+    let setup = get_qbft_setup(host::contract_obj, qbft::contract_obj);
+    let trusted_height = height::Data {
+        revision_number: 0,
+        revision_height: HEADER_HEIGHT,
+    };
+    setup.initialize_client(trusted_height).assert_ok();
+    setup
+        .update_client(
+            vectors::HEADER,
+            trusted_height,
+            &[0, 1, 2],
+            VALIDATOR_PUBLIC_KEYS,
+        )
+        .assert_user_error("Header height must be greater than the trusted height");
+}
+
+#[test]
+fn get_status_test() {
+    let setup = get_qbft_setup(host::contract_obj, qbft::contract_obj);
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.qbft_wrapper, |sc| {
+            assert!(sc.get_status(&managed_buffer!(CLIENT_ID)) == ClientStatus::None);
+        })
+        .assert_ok();
+
+    setup.initialize_client(TRUSTED_HEIGHT).assert_ok();
+    setup
+        .b_mock
+        .borrow_mut()
+        .set_block_timestamp(TRUSTED_TIMESTAMP + TRUSTING_PERIOD);
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.qbft_wrapper, |sc| {
+            assert!(sc.get_status(&managed_buffer!(CLIENT_ID)) == ClientStatus::Expired);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn unsupported_endpoints_test() {
+    let setup = get_qbft_setup(host::contract_obj, qbft::contract_obj);
+    setup.initialize_client(TRUSTED_HEIGHT).assert_ok();
+
+    let mut b_mock = setup.b_mock.borrow_mut();
+    b_mock
+        .execute_tx(
+            &setup.relayer,
+            &setup.qbft_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.submit_misbehaviour(managed_buffer!(CLIENT_ID), ManagedBuffer::new());
+            },
+        )
+        .assert_user_error("Misbehaviour is unsupported by QBFT clients");
+    b_mock
+        .execute_tx(
+            &setup.relayer,
+            &setup.qbft_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.recover_client(managed_buffer!(CLIENT_ID), managed_buffer!(b"qbft-1"));
+            },
+        )
+        .assert_user_error("Client recovery is unsupported by QBFT clients");
+    b_mock
+        .execute_tx(
+            &setup.relayer,
+            &setup.qbft_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.upgrade_client(
+                    managed_buffer!(CLIENT_ID),
+                    ManagedBuffer::new(),
+                    ManagedBuffer::new(),
+                    ManagedBuffer::new(),
+                    ManagedBuffer::new(),
+                );
+            },
+        )
+        .assert_user_error("Client upgrades are unsupported by QBFT clients");
+}
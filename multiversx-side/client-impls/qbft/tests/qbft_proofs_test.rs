@@ -0,0 +1,225 @@
+use std::{cell::RefCell, rc::Rc};
+
+use client_common::{VerifyMembershipArgs, VerifyNonMembershipArgs};
+use multiversx_sc::types::{ManagedBuffer, ManagedVec};
+use multiversx_sc_scenario::{
+    imports::BlockchainStateWrapper, managed_buffer, rust_biguint, DebugApi,
+};
+use qbft::{client_logic::ClientLogicModule, qbft_types::header, views::ViewsModule};
+use qbft_setup::{
+    byte_array_hex, managed_hex,
+    vectors::{self, HEADER_HEIGHT, HEADER_TIME},
+    QbftSetup, CLIENT_ID, TRUSTED_HEIGHT, TRUSTED_TIMESTAMP, TRUSTING_PERIOD,
+};
+
+pub mod qbft_setup;
+
+fn get_qbft_setup<
+    HostObjBuilder: 'static + Copy + Fn() -> host::ContractObj<DebugApi>,
+    QbftObjBuilder: 'static + Copy + Fn() -> qbft::ContractObj<DebugApi>,
+>(
+    host_builder: HostObjBuilder,
+    qbft_builder: QbftObjBuilder,
+) -> QbftSetup<HostObjBuilder, QbftObjBuilder> {
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_biguint!(0));
+    QbftSetup::new(
+        Rc::new(RefCell::new(b_mock)),
+        &owner,
+        host_builder,
+        qbft_builder,
+    )
+}
+
+fn new_header(besu_header: &str) -> header::Data<DebugApi> {
+    header::Data {
+        besu_header: managed_hex(besu_header),
+        seals: ManagedVec::new(),
+        validator_public_keys: ManagedVec::new(),
+        trusted_height: TRUSTED_HEIGHT,
+        account_state_proof: ManagedBuffer::new(),
+    }
+}
+
+#[test]
+fn parse_besu_header_test() {
+    let setup = get_qbft_setup(host::contract_obj, qbft::contract_obj);
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.qbft_wrapper, |sc| {
+            for besu_header in [vectors::HEADER, vectors::IBFT2_HEADER] {
+                let parsed_header = sc.parse_besu_header(new_header(besu_header));
+                assert_eq!(parsed_header.height.revision_number, 0);
+                assert_eq!(parsed_header.height.revision_height, HEADER_HEIGHT);
+                assert_eq!(parsed_header.time, HEADER_TIME);
+                assert_eq!(
+                    parsed_header.state_root,
+                    byte_array_hex(vectors::STATE_ROOT)
+                );
+
+                assert_eq!(parsed_header.validators.len(), vectors::VALIDATORS.len());
+                for (i, validator) in vectors::VALIDATORS.iter().enumerate() {
+                    assert_eq!(
+                        parsed_header.validators.get(i).clone_value(),
+                        byte_array_hex(validator)
+                    );
+                }
+            }
+        })
+        .assert_ok();
+}
+
+#[test]
+fn eth_address_from_public_key_test() {
+    let setup = get_qbft_setup(host::contract_obj, qbft::contract_obj);
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.qbft_wrapper, |sc| {
+            for (public_key, validator) in vectors::VALIDATOR_PUBLIC_KEYS
+                .iter()
+                .zip(vectors::VALIDATORS.iter())
+            {
+                assert_eq!(
+                    sc.eth_address_from_public_key(&managed_hex(public_key)),
+                    byte_array_hex(validator)
+                );
+            }
+        })
+        .assert_ok();
+}
+
+#[test]
+fn verify_membership_test() {
+    let setup = get_qbft_setup(host::contract_obj, qbft::contract_obj);
+    setup.initialize_client(TRUSTED_HEIGHT).assert_ok();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.qbft_wrapper, |sc| {
+            let new_args = |value: &[u8]| VerifyMembershipArgs {
+                client_id: managed_buffer!(CLIENT_ID),
+                height: TRUSTED_HEIGHT,
+                delay_time_period: 0,
+                delay_block_period: 0,
+                proof: managed_hex(vectors::PACKET_PROOF),
+                prefix: managed_buffer!(b"ibc"),
+                path: managed_buffer!(vectors::PACKET_COMMITMENT_PATH),
+                value: managed_buffer!(value),
+            };
+
+            assert!(sc.verify_membership(new_args(vectors::PACKET_COMMITMENT_VALUE)));
+            assert!(!sc.verify_membership(new_args(b"other-commitment")));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn verify_non_membership_test() {
+    let setup = get_qbft_setup(host::contract_obj, qbft::contract_obj);
+    setup.initialize_client(TRUSTED_HEIGHT).assert_ok();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.qbft_wrapper, |sc| {
+            let new_args = |path: &[u8], proof: &str| VerifyNonMembershipArgs {
+                client_id: managed_buffer!(CLIENT_ID),
+                height: TRUSTED_HEIGHT,
+                delay_time_period: 0,
+                delay_block_period: 0,
+                proof: managed_hex(proof),
+                prefix: managed_buffer!(b"ibc"),
+                path: managed_buffer!(path),
+            };
+
+            assert!(sc.verify_non_membership(new_args(vectors::ABSENT_PATH, vectors::ABSENT_PROOF)));
+            assert!(!sc.verify_non_membership(new_args(
+                vectors::PACKET_COMMITMENT_PATH,
+                vectors::PACKET_PROOF
+            )));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn verify_membership_tampered_proof_test() {
+    let setup = get_qbft_setup(host::contract_obj, qbft::contract_obj);
+    setup.initialize_client(TRUSTED_HEIGHT).assert_ok();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.qbft_wrapper, |sc| {
+            let mut proof_bytes = hex::decode(vectors::PACKET_PROOF).unwrap();
+            let last_index = proof_bytes.len() - 1;
+            proof_bytes[last_index] ^= 1;
+
+            let _ = sc.verify_membership(VerifyMembershipArgs {
+                client_id: managed_buffer!(CLIENT_ID),
+                height: TRUSTED_HEIGHT,
+                delay_time_period: 0,
+                delay_block_period: 0,
+                proof: ManagedBuffer::new_from_bytes(&proof_bytes),
+                prefix: managed_buffer!(b"ibc"),
+                path: managed_buffer!(vectors::PACKET_COMMITMENT_PATH),
+                value: managed_buffer!(vectors::PACKET_COMMITMENT_VALUE),
+            });
+        })
+        .assert_user_error("Invalid MPT proof");
+}
+
+#[test]
+fn try_verify_membership_expired_client_test() {
+    let setup = get_qbft_setup(host::contract_obj, qbft::contract_obj);
+    setup.initialize_client(TRUSTED_HEIGHT).assert_ok();
+    setup
+        .b_mock
+        .borrow_mut()
+        .set_block_timestamp(TRUSTED_TIMESTAMP + TRUSTING_PERIOD);
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.qbft_wrapper, |sc| {
+            let _ = sc.verify_membership(VerifyMembershipArgs {
+                client_id: managed_buffer!(CLIENT_ID),
+                height: TRUSTED_HEIGHT,
+                delay_time_period: 0,
+                delay_block_period: 0,
+                proof: managed_hex(vectors::PACKET_PROOF),
+                prefix: managed_buffer!(b"ibc"),
+                path: managed_buffer!(vectors::PACKET_COMMITMENT_PATH),
+                value: managed_buffer!(vectors::PACKET_COMMITMENT_VALUE),
+            });
+        })
+        .assert_user_error("Client is expired");
+}
+
+#[test]
+fn verify_non_membership_delay_block_period_test() {
+    let setup = get_qbft_setup(host::contract_obj, qbft::contract_obj);
+    setup.b_mock.borrow_mut().set_block_nonce(5);
+    setup.initialize_client(TRUSTED_HEIGHT).assert_ok();
+
+    let verify_with_delay = || {
+        setup
+            .b_mock
+            .borrow_mut()
+            .execute_query(&setup.qbft_wrapper, |sc| {
+                assert!(sc.verify_non_membership(VerifyNonMembershipArgs {
+                    client_id: managed_buffer!(CLIENT_ID),
+                    height: TRUSTED_HEIGHT,
+                    delay_time_period: 0,
+                    delay_block_period: 2,
+                    proof: managed_hex(vectors::ABSENT_PROOF),
+                    prefix: managed_buffer!(b"ibc"),
+                    path: managed_buffer!(vectors::ABSENT_PATH),
+                }));
+            })
+    };
+
+    setup.b_mock.borrow_mut().set_block_nonce(6);
+    verify_with_delay().assert_user_error("Delay block period has not passed");
+
+    setup.b_mock.borrow_mut().set_block_nonce(7);
+    verify_with_delay().assert_ok();
+}
@@ -0,0 +1,194 @@
+#![allow(dead_code)]
+
+use std::{cell::RefCell, rc::Rc};
+
+use common_types::channel_types::height;
+use host::Host;
+use multiversx_sc::types::{Address, ManagedBuffer, ManagedByteArray, ManagedVec};
+use multiversx_sc_scenario::{
+    imports::{BlockchainStateWrapper, ContractObjWrapper, TxResult},
+    managed_address, managed_buffer, rust_biguint, DebugApi,
+};
+use qbft::{
+    client_logic::ClientLogicModule,
+    qbft_types::{client_state, consensus_state, header, EthAddress},
+    Qbft,
+};
+
+pub mod vectors;
+
+pub static CLIENT_ID: &[u8] = b"qbft-0";
+
+pub const TRUSTED_HEIGHT: height::Data = height::Data {
+    revision_number: 0,
+    revision_height: 10,
+};
+pub const TRUSTED_TIMESTAMP: u64 = 1_700_000_000;
+pub const TRUSTING_PERIOD: u64 = 1_000;
+pub const MAX_CLOCK_DRIFT: u64 = 5;
+
+pub struct QbftSetup<HostObjBuilder, QbftObjBuilder>
+where
+    HostObjBuilder: 'static + Copy + Fn() -> host::ContractObj<DebugApi>,
+    QbftObjBuilder: 'static + Copy + Fn() -> qbft::ContractObj<DebugApi>,
+{
+    pub b_mock: Rc<RefCell<BlockchainStateWrapper>>,
+    pub owner: Address,
+    pub relayer: Address,
+    pub host_wrapper: ContractObjWrapper<host::ContractObj<DebugApi>, HostObjBuilder>,
+    pub qbft_wrapper: ContractObjWrapper<qbft::ContractObj<DebugApi>, QbftObjBuilder>,
+}
+
+impl<HostObjBuilder, QbftObjBuilder> QbftSetup<HostObjBuilder, QbftObjBuilder>
+where
+    HostObjBuilder: 'static + Copy + Fn() -> host::ContractObj<DebugApi>,
+    QbftObjBuilder: 'static + Copy + Fn() -> qbft::ContractObj<DebugApi>,
+{
+    pub fn new(
+        b_mock: Rc<RefCell<BlockchainStateWrapper>>,
+        owner: &Address,
+        host_builder: HostObjBuilder,
+        qbft_builder: QbftObjBuilder,
+    ) -> Self {
+        let rust_zero = rust_biguint!(0u64);
+        let relayer = b_mock.borrow_mut().create_user_account(&rust_zero);
+        let host_wrapper =
+            b_mock
+                .borrow_mut()
+                .create_sc_account(&rust_zero, Some(owner), host_builder, "host");
+        let qbft_wrapper =
+            b_mock
+                .borrow_mut()
+                .create_sc_account(&rust_zero, Some(owner), qbft_builder, "qbft");
+
+        let host_address = host_wrapper.address_ref().clone();
+        b_mock
+            .borrow_mut()
+            .execute_tx(owner, &host_wrapper, &rust_zero, |sc| {
+                sc.init();
+            })
+            .assert_ok();
+        b_mock
+            .borrow_mut()
+            .execute_tx(owner, &qbft_wrapper, &rust_zero, |sc| {
+                sc.init(managed_address!(&host_address));
+            })
+            .assert_ok();
+        b_mock
+            .borrow_mut()
+            .set_block_timestamp(TRUSTED_TIMESTAMP + 20);
+
+        Self {
+            b_mock,
+            owner: owner.clone(),
+            relayer,
+            host_wrapper,
+            qbft_wrapper,
+        }
+    }
+
+    /// Initializes `CLIENT_ID` through the IBC handler, trusting the header validators at the given height
+    pub fn initialize_client(&self, trusted_height: height::Data) -> TxResult {
+        let host_address = self.host_wrapper.address_ref().clone();
+        self.b_mock.borrow_mut().execute_tx(
+            &host_address,
+            &self.qbft_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.initialize_client(
+                    managed_buffer!(CLIENT_ID),
+                    new_client_state(trusted_height),
+                    new_consensus_state(TRUSTED_TIMESTAMP, &vectors::VALIDATORS),
+                );
+            },
+        )
+    }
+
+    /// Calls updateClient through the IBC handler with the given besu header, sealed by the validators at `sealed_by`
+    ///
+    /// The seals themselves are dummy values, since secp256k1 signatures cannot be verified in the debug VM
+    pub fn update_client(
+        &self,
+        besu_header: &'static str,
+        trusted_height: height::Data,
+        sealed_by: &'static [usize],
+        public_keys: [&'static str; 4],
+    ) -> TxResult {
+        let host_address = self.host_wrapper.address_ref().clone();
+        self.update_client_as(
+            &host_address,
+            besu_header,
+            trusted_height,
+            sealed_by,
+            public_keys,
+        )
+    }
+
+    /// Calls updateClient as `caller`, see `update_client`
+    pub fn update_client_as(
+        &self,
+        caller: &Address,
+        besu_header: &'static str,
+        trusted_height: height::Data,
+        sealed_by: &'static [usize],
+        public_keys: [&'static str; 4],
+    ) -> TxResult {
+        self.b_mock
+            .borrow_mut()
+            .execute_tx(caller, &self.qbft_wrapper, &rust_biguint!(0), |sc| {
+                let mut seals = ManagedVec::new();
+                let mut validator_public_keys = ManagedVec::new();
+                for (i, public_key) in public_keys.iter().enumerate() {
+                    if sealed_by.contains(&i) {
+                        seals.push(ManagedBuffer::new_from_bytes(&[1u8; 65]));
+                        validator_public_keys.push(managed_hex(public_key));
+                    } else {
+                        seals.push(ManagedBuffer::new());
+                        validator_public_keys.push(ManagedBuffer::new());
+                    }
+                }
+
+                let _ = sc.update_client(
+                    managed_buffer!(CLIENT_ID),
+                    header::Data {
+                        besu_header: managed_hex(besu_header),
+                        seals,
+                        validator_public_keys,
+                        trusted_height,
+                        account_state_proof: managed_hex(vectors::ACCOUNT_PROOF),
+                    },
+                );
+            })
+    }
+}
+
+pub fn managed_hex(hex_str: &str) -> ManagedBuffer<DebugApi> {
+    ManagedBuffer::new_from_bytes(&hex::decode(hex_str).unwrap())
+}
+
+pub fn byte_array_hex<const N: usize>(hex_str: &str) -> ManagedByteArray<DebugApi, N> {
+    ManagedByteArray::try_from(managed_hex(hex_str)).unwrap()
+}
+
+pub fn new_client_state(latest_height: height::Data) -> client_state::Data<DebugApi> {
+    client_state::Data {
+        chain_id: ManagedByteArray::default(),
+        ibc_store_address: byte_array_hex(vectors::STORE_ADDRESS),
+        latest_height,
+        trusting_period: TRUSTING_PERIOD,
+        max_clock_drift: MAX_CLOCK_DRIFT,
+    }
+}
+
+pub fn new_consensus_state(timestamp: u64, validators: &[&str]) -> consensus_state::Data<DebugApi> {
+    let mut managed_validators = ManagedVec::<DebugApi, EthAddress<DebugApi>>::new();
+    for validator in validators {
+        managed_validators.push(byte_array_hex(validator));
+    }
+
+    consensus_state::Data {
+        timestamp,
+        root: byte_array_hex(vectors::STORAGE_ROOT),
+        validators: managed_validators,
+    }
+}
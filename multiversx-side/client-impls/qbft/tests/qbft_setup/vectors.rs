@@ -0,0 +1,32 @@
+//! Generated from a QBFT header, an account proof of the IBC store contract and storage proofs of its commitments
+
+pub const HEADER_HEIGHT: u64 = 11;
+pub const HEADER_TIME: u64 = 1_700_000_010;
+
+pub static PACKET_COMMITMENT_PATH: &[u8] = b"commitments/ports/mock/channels/channel-0/sequences/1";
+pub static PACKET_COMMITMENT_VALUE: &[u8] = b"packet-commitment";
+pub static ABSENT_PATH: &[u8] = b"receipts/ports/mock/channels/channel-0/sequences/1";
+
+pub static STORE_ADDRESS: &str = "a1b2c3d4e5f60718293a4b5c6d7e8f9011223344";
+pub static STORAGE_ROOT: &str = "61a50458bc101d9716b37ecc8a0254f9294cf180b8f06e3ea2c06e55318bc464";
+pub static STATE_ROOT: &str = "7b3ebffe2fcf699abda5d3075c7157a984e461347ffc09f158b3a9f5d7e3bf26";
+pub static ACCOUNT_PROOF: &str = "f90142b8d3f8d18080a0016e85c9a5d13ac353c44c832a2b55ea75be3fcf8a12b15732c9b73e921b44bb80808080a07c78c4cb24cf3b0db080242bccee8d595a9642e67dac365791f94311d5354ed78080a06931025c0a3c7b687bb99e54730fb8b32d1e7ed24be74b81ee4ce302b390ebd4a07c160b12dddc18be3734dbead5d8d0c880e3a549a5a42d0bab4c801ac37505d58080a03e272f515dfbf58c3b4d6f85c90c360edf867ba850c4e1a46779d6d66d5a88a5a067fea4d3b43abe14386af579a3cacdc1490184873dde0d39418f5e59319ea42e80b86bf869a031ee3838c831d51764f9af236c21646e869a15c0fc58e6cb0c0434a5deea40beb846f8440180a061a50458bc101d9716b37ecc8a0254f9294cf180b8f06e3ea2c06e55318bc464a0c97434ffff31dcb852bcff7bf4adf922b700173a56e257fc676a11950df62dae";
+pub static PACKET_PROOF: &str = "f8dcb893f8918080a04fc5f13ab2f9ba0c2da88b0151ab0e7cf4d85d08cca45ccd923c6ab76323eb28a0e26209d09fd69ad8876077d4c834519d886a78146401cc114aca47b8acb74136a0cd457259696115235e64c7822334d62129e2f1604425a7da6494f35fc45be518808080808080a0960393adea7d53a967f1d24f052fc3faedec5847b68226478acf581188f37c5f8080808080b845f843a039159f54f65f113327ee7f312645acd5ae2ed678b0f8dae25462f756c5744e72a1a02c2480b50911fba3f0efd45385ab09360e74978de645f781afb14e6bd2ddaf48";
+pub static ABSENT_PROOF: &str = "f895b893f8918080a04fc5f13ab2f9ba0c2da88b0151ab0e7cf4d85d08cca45ccd923c6ab76323eb28a0e26209d09fd69ad8876077d4c834519d886a78146401cc114aca47b8acb74136a0cd457259696115235e64c7822334d62129e2f1604425a7da6494f35fc45be518808080808080a0960393adea7d53a967f1d24f052fc3faedec5847b68226478acf581188f37c5f8080808080";
+pub static HEADER: &str = "f90274a01111111111111111111111111111111111111111111111111111111111111111a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347942222222222222222222222222222222222222222a07b3ebffe2fcf699abda5d3075c7157a984e461347ffc09f158b3a9f5d7e3bf26a03333333333333333333333333333333333333333333333333333333333333333a04444444444444444444444444444444444444444444444444444444444444444b9010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010b8401c9c380825208846553f10ab87cf87aa00000000000000000000000000000000000000000000000000000000000000000f854949d829b80051421f1e706e522be1d7031576d2a4694e350aaddc325f733c7d05fe0966118770eccf7c49467d825ed8f02168c6db492ca848dec2188ff2680941d985234ebeb8fed2a5e0e6a6f9b1841029ecd69c080c0a05555555555555555555555555555555555555555555555555555555555555555880000000000000000";
+pub static IBFT2_HEADER: &str = "f90273a01111111111111111111111111111111111111111111111111111111111111111a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347942222222222222222222222222222222222222222a07b3ebffe2fcf699abda5d3075c7157a984e461347ffc09f158b3a9f5d7e3bf26a03333333333333333333333333333333333333333333333333333333333333333a04444444444444444444444444444444444444444444444444444444444444444b9010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010b8401c9c380825208846553f10ab87bf879a00000000000000000000000000000000000000000000000000000000000000000f854949d829b80051421f1e706e522be1d7031576d2a4694e350aaddc325f733c7d05fe0966118770eccf7c49467d825ed8f02168c6db492ca848dec2188ff2680941d985234ebeb8fed2a5e0e6a6f9b1841029ecd69c080a05555555555555555555555555555555555555555555555555555555555555555880000000000000000";
+pub static DUPLICATE_VALIDATORS_HEADER: &str = "f90274a01111111111111111111111111111111111111111111111111111111111111111a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347942222222222222222222222222222222222222222a07b3ebffe2fcf699abda5d3075c7157a984e461347ffc09f158b3a9f5d7e3bf26a03333333333333333333333333333333333333333333333333333333333333333a04444444444444444444444444444444444444444444444444444444444444444b9010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010b8401c9c380825208846553f10ab87cf87aa00000000000000000000000000000000000000000000000000000000000000000f854949d829b80051421f1e706e522be1d7031576d2a46949d829b80051421f1e706e522be1d7031576d2a46949d829b80051421f1e706e522be1d7031576d2a46949d829b80051421f1e706e522be1d7031576d2a46c080c0a05555555555555555555555555555555555555555555555555555555555555555880000000000000000";
+pub static INVALID_EXTRA_DATA_HEADER: &str = "f90272a01111111111111111111111111111111111111111111111111111111111111111a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347942222222222222222222222222222222222222222a07b3ebffe2fcf699abda5d3075c7157a984e461347ffc09f158b3a9f5d7e3bf26a03333333333333333333333333333333333333333333333333333333333333333a04444444444444444444444444444444444444444444444444444444444444444b9010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010b8401c9c380825208846553f10ab87af878a00000000000000000000000000000000000000000000000000000000000000000f854949d829b80051421f1e706e522be1d7031576d2a4694e350aaddc325f733c7d05fe0966118770eccf7c49467d825ed8f02168c6db492ca848dec2188ff2680941d985234ebeb8fed2a5e0e6a6f9b1841029ecd69c0a05555555555555555555555555555555555555555555555555555555555555555880000000000000000";
+
+pub static VALIDATORS: [&str; 4] = [
+    "9d829b80051421f1e706e522be1d7031576d2a46",
+    "e350aaddc325f733c7d05fe0966118770eccf7c4",
+    "67d825ed8f02168c6db492ca848dec2188ff2680",
+    "1d985234ebeb8fed2a5e0e6a6f9b1841029ecd69",
+];
+pub static VALIDATOR_PUBLIC_KEYS: [&str; 4] = [
+    "045fe7f977e71dba2ea1a68e21057beebb9be2ac30c6410aa38d4f3fbe41dcffd24535a04e923af75e64a9f6cdfb922004b40beec0649d36cf6ea095b7c4975cae",
+    "04f2ee15ea639b73fa3db9b34a245bdfa015c260c598b211bf05a1ecc4b3e3b4f29f1d8550a3d4ed2b79d361a836cab93620f758f4ad45f229d1424cfcc3141c50",
+    "0469c322e3248a5dfc29d73c5b0553b0185a35cd5bb6386747517ef7e53b15e287334f90d8a281449ed1917f38adf7e5e4a0b8a89a535b2a581a36a542a47c6d99",
+    "04f343681465b9efe82c933c3e8748c70cb8aa06539c361de20f72eac04e766393d4c166783bac9b00475fdedb30493c9158e2b1bb7ac08ba86f790f6796ad74b3",
+];
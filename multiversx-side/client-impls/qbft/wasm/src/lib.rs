@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           11
+// Endpoints:                           23
 // Async Callback (empty):               1
-// Total number of exported functions:  14
+// Total number of exported functions:  26
 
 #![no_std]
 
@@ -23,14 +23,26 @@ multiversx_sc_wasm_adapter::endpoints! {
         getIbcHandler => ibc_handler
         initializeClient => initialize_client
         updateClient => update_client
+        upgradeClient => upgrade_client
+        submitMisbehaviour => submit_misbehaviour
+        recoverClient => recover_client
         getTimestampAtHeight => get_timestamp_at_height
         getLatestHeight => get_latest_height
         getStatus => get_status
         getLatestInfo => get_latest_info
+        verifyMembership => verify_membership
+        verifyNonMembership => verify_non_membership
+        getClientState => get_client_state
+        getConsensusState => get_consensus_state
+        getClientStateAny => get_client_state_any
+        getConsensusStateAny => get_consensus_state_any
         getHostTimestamp => get_host_timestamp
         getCommitmentPrefix => get_commitment_prefix
         checkAndGetClient => check_and_get_client
         getCommitment => get_commitment
+        getConnection => get_connection
+        isChannelOpen => is_channel_open
+        getPortMiddlewares => port_middlewares
     )
 }
 
@@ -0,0 +1,285 @@
+use crate::tendermint_types::{client_state, consensus_state, header, TimestampNanos};
+use common_types::{channel_types::height, ClientId};
+
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait ClientLogicModule:
+    client_common::CommonClientLogicModule
+    + crate::verification::VerificationModule
+    + host::host_views::HostViewsModule
+    + host::storage::StorageModule
+    + common_modules::utils::UtilsModule
+{
+    /// creates a new client with the given state
+    #[endpoint(initializeClient)]
+    fn initialize_client(
+        &self,
+        client_id: ClientId<Self::Api>,
+        client_state: client_state::Data<Self::Api>,
+        consensus_state: consensus_state::Data<Self::Api>,
+    ) -> height::Data {
+        self.require_ibc_handler_caller();
+        require!(!client_state.chain_id.is_empty(), "Invalid chain id");
+        require!(
+            client_state.latest_height.revision_height != 0,
+            "Invalid client state height"
+        );
+        require!(
+            client_state.frozen_height.is_zero(),
+            "Client must not be frozen"
+        );
+
+        // the trust level must be in [1/3, 1]
+        let trust_level = client_state.trust_level;
+        require!(
+            trust_level.denominator != 0
+                && trust_level.numerator <= trust_level.denominator
+                && trust_level.numerator as u128 * 3 >= trust_level.denominator as u128,
+            "Invalid trust level"
+        );
+        require!(
+            client_state.trusting_period != 0
+                && client_state.trusting_period < client_state.unbonding_period,
+            "Invalid trusting period"
+        );
+        require!(consensus_state.timestamp != 0, "Invalid consensus state");
+
+        let mapper = self.client_states(&client_id);
+        require!(mapper.is_empty(), "Client already known");
+
+        mapper.set(&client_state);
+        self.consensus_states(&client_id, &client_state.latest_height.to_biguint_concat())
+            .set(consensus_state);
+        self.set_processed_time_and_height(&client_id, &client_state.latest_height);
+
+        client_state.latest_height
+    }
+
+    /// updates the client with the given header and returns the updated heights
+    ///
+    /// Adjacent headers must be signed by more than 2/3 of the trusted next validators. Non-adjacent headers must be signed
+    /// by more than the trust level of the trusted next validators, and by more than 2/3 of their own validators.
+    /// A valid header conflicting with an already stored consensus state freezes the client.
+    #[endpoint(updateClient)]
+    fn update_client(
+        &self,
+        client_id: ClientId<Self::Api>,
+        header: header::Data<Self::Api>,
+    ) -> ManagedVec<height::Data> {
+        self.require_ibc_handler_caller();
+
+        let client_state_mapper = self.client_states(&client_id);
+        require!(!client_state_mapper.is_empty(), "Unknown client");
+
+        let mut client_state = client_state_mapper.get();
+        require!(client_state.frozen_height.is_zero(), "Client is frozen");
+
+        let header_height = self.verify_header(&client_id, &client_state, &header);
+        let new_consensus_state = consensus_state::Data {
+            timestamp: header.block_header.time,
+            root: header.block_header.app_hash,
+            next_validators_hash: header.block_header.next_validators_hash,
+        };
+
+        let consensus_state_mapper =
+            self.consensus_states(&client_id, &header_height.to_biguint_concat());
+        if !consensus_state_mapper.is_empty() {
+            if consensus_state_mapper.get() != new_consensus_state {
+                self.freeze_client(&client_id, client_state);
+            }
+
+            return ManagedVec::new();
+        }
+
+        consensus_state_mapper.set(new_consensus_state);
+        if header_height > client_state.latest_height {
+            client_state.latest_height = header_height;
+            client_state_mapper.set(client_state);
+        }
+
+        self.set_processed_time_and_height(&client_id, &header_height);
+
+        ManagedVec::from_single_item(header_height)
+    }
+
+    /// replaces the state of the subject client with the latest state of the substitute client, and returns the
+    /// recovered height
+    ///
+    /// Only the chain id, the trusting period and the latest height may differ between the two clients.
+    #[endpoint(recoverClient)]
+    fn recover_client(
+        &self,
+        subject_client_id: ClientId<Self::Api>,
+        substitute_client_id: ClientId<Self::Api>,
+    ) -> height::Data {
+        self.require_ibc_handler_caller();
+
+        let subject_client_state_mapper = self.client_states(&subject_client_id);
+        let substitute_client_state_mapper = self.client_states(&substitute_client_id);
+        require!(
+            !subject_client_state_mapper.is_empty() && !substitute_client_state_mapper.is_empty(),
+            "Unknown client"
+        );
+
+        let mut client_state = subject_client_state_mapper.get();
+        let substitute_client_state = substitute_client_state_mapper.get();
+        require!(
+            client_state.trust_level.numerator == substitute_client_state.trust_level.numerator
+                && client_state.trust_level.denominator
+                    == substitute_client_state.trust_level.denominator
+                && client_state.unbonding_period == substitute_client_state.unbonding_period
+                && client_state.max_clock_drift == substitute_client_state.max_clock_drift,
+            "Incompatible client parameters"
+        );
+
+        let latest_height = substitute_client_state.latest_height;
+        let height_key = latest_height.to_biguint_concat();
+        self.consensus_states(&subject_client_id, &height_key).set(
+            self.consensus_states(&substitute_client_id, &height_key)
+                .get(),
+        );
+        self.copy_processed_time_and_height(
+            &substitute_client_id,
+            &subject_client_id,
+            &latest_height,
+        );
+
+        client_state.chain_id = substitute_client_state.chain_id;
+        client_state.trusting_period = substitute_client_state.trusting_period;
+        client_state.latest_height = latest_height;
+        client_state.frozen_height = height::Data::default();
+        subject_client_state_mapper.set(client_state);
+
+        latest_height
+    }
+
+    /// Verifies the header against the consensus state at its trusted height, and returns the header height
+    fn verify_header(
+        &self,
+        client_id: &ClientId<Self::Api>,
+        client_state: &client_state::Data<Self::Api>,
+        header: &header::Data<Self::Api>,
+    ) -> height::Data {
+        let block_header = &header.block_header;
+        let header_height = height::Data {
+            revision_number: client_state.latest_height.revision_number,
+            revision_height: block_header.height,
+        };
+        require!(
+            header.trusted_height.revision_number == header_height.revision_number,
+            "Invalid trusted height"
+        );
+        require!(
+            header_height > header.trusted_height,
+            "Header height must be greater than the trusted height"
+        );
+        require!(
+            block_header.chain_id == client_state.chain_id,
+            "Invalid chain id"
+        );
+
+        let trusted_consensus_state_mapper =
+            self.consensus_states(client_id, &header.trusted_height.to_biguint_concat());
+        require!(
+            !trusted_consensus_state_mapper.is_empty(),
+            "Trusted consensus state not found"
+        );
+
+        let trusted_consensus_state = trusted_consensus_state_mapper.get();
+        self.require_valid_header_time(client_state, &trusted_consensus_state, block_header.time);
+        require!(
+            self.hash_validator_set(&header.trusted_validators)
+                == trusted_consensus_state.next_validators_hash,
+            "Trusted validators do not match the trusted consensus state"
+        );
+        require!(
+            self.hash_validator_set(&header.validator_set) == block_header.validators_hash,
+            "Validator set does not match the header"
+        );
+
+        let commit = &header.commit;
+        let block_hash = self.hash_block_header(block_header);
+        require!(
+            commit.height == block_header.height
+                && &commit.block_id.hash == block_hash.as_managed_buffer(),
+            "Commit does not match the header"
+        );
+
+        if block_header.height == header.trusted_height.revision_height + 1 {
+            require!(
+                block_header.validators_hash == trusted_consensus_state.next_validators_hash,
+                "Adjacent header must be signed by the trusted validators"
+            );
+        } else {
+            self.verify_commit_trusting(
+                &client_state.chain_id,
+                &header.trusted_validators,
+                commit,
+                &client_state.trust_level,
+            );
+        }
+
+        self.verify_commit(&client_state.chain_id, &header.validator_set, commit);
+
+        header_height
+    }
+
+    fn require_valid_header_time(
+        &self,
+        client_state: &client_state::Data<Self::Api>,
+        trusted_consensus_state: &consensus_state::Data<Self::Api>,
+        header_time: TimestampNanos,
+    ) {
+        let current_time =
+            self.checked_timestamp_to_unix_mul(self.blockchain().get_block_timestamp());
+        let trusting_period = self.checked_timestamp_to_unix_mul(client_state.trusting_period);
+        let max_clock_drift = self.checked_timestamp_to_unix_mul(client_state.max_clock_drift);
+        require!(
+            trusted_consensus_state
+                .timestamp
+                .saturating_add(trusting_period)
+                > current_time,
+            "Trusted consensus state is outside the trusting period"
+        );
+        require!(
+            header_time < current_time.saturating_add(max_clock_drift),
+            "Header time exceeds the max clock drift"
+        );
+        require!(
+            header_time > trusted_consensus_state.timestamp,
+            "Header time must be greater than the trusted time"
+        );
+    }
+
+    /// the frozen height is the same for any misbehaviour, as in ibc-go
+    fn freeze_client(
+        &self,
+        client_id: &ClientId<Self::Api>,
+        mut client_state: client_state::Data<Self::Api>,
+    ) {
+        client_state.frozen_height = height::Data {
+            revision_number: 0,
+            revision_height: 1,
+        };
+        self.client_states(client_id).set(client_state);
+
+        self.client_frozen_event(client_id);
+    }
+
+    #[event("clientFrozenEvent")]
+    fn client_frozen_event(&self, #[indexed] client_id: &ClientId<Self::Api>);
+
+    #[storage_mapper("clientStates")]
+    fn client_states(
+        &self,
+        client_id: &ClientId<Self::Api>,
+    ) -> SingleValueMapper<client_state::Data<Self::Api>>;
+
+    #[storage_mapper("consensusStates")]
+    fn consensus_states(
+        &self,
+        client_id: &ClientId<Self::Api>,
+        height: &BigUint,
+    ) -> SingleValueMapper<consensus_state::Data<Self::Api>>;
+}
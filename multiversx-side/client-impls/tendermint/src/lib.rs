@@ -0,0 +1,31 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod client_logic;
+pub mod misbehaviour;
+pub mod tendermint_types;
+pub mod upgrade;
+pub mod verification;
+pub mod views;
+
+#[multiversx_sc::contract]
+pub trait Tendermint:
+    client_common::CommonClientLogicModule
+    + client_logic::ClientLogicModule
+    + misbehaviour::MisbehaviourModule
+    + upgrade::UpgradeModule
+    + verification::VerificationModule
+    + views::ViewsModule
+    + host::host_views::HostViewsModule
+    + host::storage::StorageModule
+    + common_modules::utils::UtilsModule
+{
+    #[init]
+    fn init(&self, ibc_handler: ManagedAddress) {
+        self.set_ibc_handler(&ibc_handler);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+}
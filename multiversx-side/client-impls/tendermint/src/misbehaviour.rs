@@ -0,0 +1,49 @@
+use crate::tendermint_types::misbehaviour;
+use common_types::ClientId;
+
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait MisbehaviourModule:
+    client_common::CommonClientLogicModule
+    + crate::client_logic::ClientLogicModule
+    + crate::verification::VerificationModule
+    + host::host_views::HostViewsModule
+    + host::storage::StorageModule
+    + common_modules::utils::UtilsModule
+{
+    /// freezes the client, given two valid headers which conflict with each other
+    #[endpoint(submitMisbehaviour)]
+    fn submit_misbehaviour(
+        &self,
+        client_id: ClientId<Self::Api>,
+        misbehaviour: misbehaviour::Data<Self::Api>,
+    ) {
+        self.require_ibc_handler_caller();
+
+        let client_state_mapper = self.client_states(&client_id);
+        require!(!client_state_mapper.is_empty(), "Unknown client");
+
+        let client_state = client_state_mapper.get();
+        require!(client_state.frozen_height.is_zero(), "Client is frozen");
+
+        let header_1 = &misbehaviour.header_1.block_header;
+        let header_2 = &misbehaviour.header_2.block_header;
+        require!(
+            header_1.height >= header_2.height,
+            "Header 1 height must be at least the header 2 height"
+        );
+
+        let is_misbehaviour = if header_1.height == header_2.height {
+            self.hash_block_header(header_1) != self.hash_block_header(header_2)
+        } else {
+            header_1.time <= header_2.time
+        };
+        require!(is_misbehaviour, "Headers do not conflict");
+
+        let _ = self.verify_header(&client_id, &client_state, &misbehaviour.header_1);
+        let _ = self.verify_header(&client_id, &client_state, &misbehaviour.header_2);
+
+        self.freeze_client(&client_id, client_state);
+    }
+}
@@ -0,0 +1,353 @@
+use multiversx_sc::types::ManagedByteArray;
+
+pub const ED25519_PUBLIC_KEY_LENGTH: usize = 32;
+pub const VALIDATOR_ADDRESS_LENGTH: usize = 20;
+
+pub type Ed25519PublicKey<M> = ManagedByteArray<M, ED25519_PUBLIC_KEY_LENGTH>;
+pub type ValidatorAddress<M> = ManagedByteArray<M, VALIDATOR_ADDRESS_LENGTH>;
+
+/// Tendermint times are stored as nanoseconds since unix epoch
+pub type TimestampNanos = u64;
+
+pub mod fraction {
+    use common_types::proto::{self, Field, FieldReader};
+
+    multiversx_sc::imports!();
+    multiversx_sc::derive_imports!();
+
+    #[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy)]
+    pub struct Data {
+        pub numerator: u64,
+        pub denominator: u64,
+    }
+
+    impl Data {
+        /// ibc.lightclients.tendermint.v1.Fraction
+        pub fn to_proto<M: ManagedTypeApi>(&self) -> ManagedBuffer<M> {
+            let mut encoded = ManagedBuffer::new();
+            proto::append_uint_field(&mut encoded, 1, self.numerator);
+            proto::append_uint_field(&mut encoded, 2, self.denominator);
+
+            encoded
+        }
+
+        /// ibc.lightclients.tendermint.v1.Fraction
+        pub fn from_proto<M: ManagedTypeApi>(encoded: &ManagedBuffer<M>) -> Self {
+            let mut data = Data {
+                numerator: 0,
+                denominator: 0,
+            };
+            for Field { number, value } in FieldReader::new(encoded) {
+                match number {
+                    1 => data.numerator = value.into_varint(),
+                    2 => data.denominator = value.into_varint(),
+                    _ => {}
+                }
+            }
+
+            data
+        }
+    }
+}
+
+pub mod client_state {
+    use common_types::{
+        channel_types::height,
+        proto::{self, Field, FieldReader},
+    };
+
+    use super::fraction;
+
+    multiversx_sc::imports!();
+    multiversx_sc::derive_imports!();
+
+    pub static TYPE_URL: &[u8] = b"/ibc.lightclients.tendermint.v1.ClientState";
+    /// key path under which the counterparty commits the client state of its next revision
+    pub static UPGRADE_PATH: [&[u8]; 2] = [b"upgrade", b"upgradedIBCState"];
+
+    #[derive(TypeAbi, TopEncode, TopDecode)]
+    pub struct Data<M: ManagedTypeApi> {
+        pub chain_id: ManagedBuffer<M>,
+        /// minimum fraction of the trusted voting power that must sign a non-adjacent header
+        pub trust_level: fraction::Data,
+        /// in seconds
+        pub trusting_period: u64,
+        /// in seconds
+        pub unbonding_period: u64,
+        /// in seconds
+        pub max_clock_drift: u64,
+        /// zero while the client is not frozen
+        pub frozen_height: height::Data,
+        pub latest_height: height::Data,
+    }
+
+    impl<M: ManagedTypeApi> Data<M> {
+        /// ibc.lightclients.tendermint.v1.ClientState, listing the Cosmos SDK proof specs and upgrade path
+        pub fn to_proto(&self) -> ManagedBuffer<M> {
+            let mut encoded = ManagedBuffer::new();
+            proto::append_bytes_field(&mut encoded, 1, &self.chain_id);
+            proto::append_message_field(&mut encoded, 2, &self.trust_level.to_proto());
+            proto::append_message_field(
+                &mut encoded,
+                3,
+                &proto::encode_duration(self.trusting_period),
+            );
+            proto::append_message_field(
+                &mut encoded,
+                4,
+                &proto::encode_duration(self.unbonding_period),
+            );
+            proto::append_message_field(
+                &mut encoded,
+                5,
+                &proto::encode_duration(self.max_clock_drift),
+            );
+            proto::append_message_field(&mut encoded, 6, &self.frozen_height.to_proto());
+            proto::append_message_field(&mut encoded, 7, &self.latest_height.to_proto());
+            for spec in ics23::specs::sdk_specs::<M>().iter() {
+                proto::append_message_field(
+                    &mut encoded,
+                    8,
+                    &ics23::specs::encode_proof_spec(spec),
+                );
+            }
+            for key in UPGRADE_PATH {
+                proto::append_message_field(&mut encoded, 9, &ManagedBuffer::new_from_bytes(key));
+            }
+
+            encoded
+        }
+
+        /// ibc.lightclients.tendermint.v1.ClientState. The proof specs and the upgrade path are ignored, since the
+        /// client only supports the Cosmos SDK ones.
+        pub fn from_proto(encoded: &ManagedBuffer<M>) -> Self {
+            let mut data = Data {
+                chain_id: ManagedBuffer::new(),
+                trust_level: fraction::Data::from_proto(&ManagedBuffer::<M>::new()),
+                trusting_period: 0,
+                unbonding_period: 0,
+                max_clock_drift: 0,
+                frozen_height: height::Data::default(),
+                latest_height: height::Data::default(),
+            };
+            for Field { number, value } in FieldReader::new(encoded) {
+                match number {
+                    1 => data.chain_id = value.into_bytes(),
+                    2 => data.trust_level = fraction::Data::from_proto(&value.into_bytes()),
+                    3 => data.trusting_period = proto::decode_duration(&value.into_bytes()),
+                    4 => data.unbonding_period = proto::decode_duration(&value.into_bytes()),
+                    5 => data.max_clock_drift = proto::decode_duration(&value.into_bytes()),
+                    6 => data.frozen_height = height::Data::from_proto(&value.into_bytes()),
+                    7 => data.latest_height = height::Data::from_proto(&value.into_bytes()),
+                    _ => {}
+                }
+            }
+
+            data
+        }
+    }
+}
+
+pub mod consensus_state {
+    use common_types::{
+        proto::{self, Field, FieldReader},
+        Hash, HASH_LENGTH,
+    };
+
+    use super::TimestampNanos;
+
+    multiversx_sc::imports!();
+    multiversx_sc::derive_imports!();
+
+    pub static TYPE_URL: &[u8] = b"/ibc.lightclients.tendermint.v1.ConsensusState";
+
+    #[derive(TypeAbi, TopEncode, TopDecode, PartialEq)]
+    pub struct Data<M: ManagedTypeApi> {
+        pub timestamp: TimestampNanos,
+        /// app hash of the block
+        pub root: Hash<M>,
+        pub next_validators_hash: Hash<M>,
+    }
+
+    impl<M: ManagedTypeApi> Data<M> {
+        /// ibc.lightclients.tendermint.v1.ConsensusState
+        pub fn to_proto(&self) -> ManagedBuffer<M> {
+            let mut root = ManagedBuffer::new();
+            proto::append_bytes_field(&mut root, 1, self.root.as_managed_buffer());
+
+            let mut encoded = ManagedBuffer::new();
+            proto::append_message_field(&mut encoded, 1, &proto::encode_timestamp(self.timestamp));
+            proto::append_message_field(&mut encoded, 2, &root);
+            proto::append_bytes_field(
+                &mut encoded,
+                3,
+                self.next_validators_hash.as_managed_buffer(),
+            );
+
+            encoded
+        }
+
+        /// ibc.lightclients.tendermint.v1.ConsensusState. An empty root, as committed for upgrades, is decoded as zero.
+        pub fn from_proto(encoded: &ManagedBuffer<M>) -> Self {
+            let mut data = Data {
+                timestamp: 0,
+                root: Hash::new_from_bytes(&[0u8; HASH_LENGTH]),
+                next_validators_hash: Hash::new_from_bytes(&[0u8; HASH_LENGTH]),
+            };
+            for Field { number, value } in FieldReader::new(encoded) {
+                match number {
+                    1 => data.timestamp = proto::decode_timestamp(&value.into_bytes()),
+                    2 => {
+                        for Field { number, value } in FieldReader::new(&value.into_bytes()) {
+                            if number == 1 {
+                                data.root = to_hash(value.into_bytes());
+                            }
+                        }
+                    }
+                    3 => data.next_validators_hash = to_hash(value.into_bytes()),
+                    _ => {}
+                }
+            }
+
+            data
+        }
+    }
+
+    fn to_hash<M: ManagedTypeApi>(bytes: ManagedBuffer<M>) -> Hash<M> {
+        match Hash::try_from(bytes) {
+            Ok(hash) => hash,
+            Err(_) => proto::signal_invalid::<M>(),
+        }
+    }
+}
+
+pub mod validator {
+    use super::{Ed25519PublicKey, ValidatorAddress};
+
+    multiversx_sc::imports!();
+    multiversx_sc::derive_imports!();
+
+    #[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+    pub struct Data<M: ManagedTypeApi> {
+        pub address: ValidatorAddress<M>,
+        pub pub_key: Ed25519PublicKey<M>,
+        pub voting_power: u64,
+    }
+}
+
+pub mod block_id {
+    multiversx_sc::imports!();
+    multiversx_sc::derive_imports!();
+
+    #[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq)]
+    pub struct Data<M: ManagedTypeApi> {
+        pub hash: ManagedBuffer<M>,
+        pub part_set_header_total: u32,
+        pub part_set_header_hash: ManagedBuffer<M>,
+    }
+}
+
+pub mod block_header {
+    use common_types::Hash;
+
+    use super::{block_id, TimestampNanos};
+
+    multiversx_sc::imports!();
+    multiversx_sc::derive_imports!();
+
+    /// Tendermint block header. Hashes which may be empty are kept as buffers.
+    #[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
+    pub struct Data<M: ManagedTypeApi> {
+        pub version_block: u64,
+        pub version_app: u64,
+        pub chain_id: ManagedBuffer<M>,
+        pub height: u64,
+        pub time: TimestampNanos,
+        pub last_block_id: block_id::Data<M>,
+        pub last_commit_hash: ManagedBuffer<M>,
+        pub data_hash: ManagedBuffer<M>,
+        pub validators_hash: Hash<M>,
+        pub next_validators_hash: Hash<M>,
+        pub consensus_hash: ManagedBuffer<M>,
+        pub app_hash: Hash<M>,
+        pub last_results_hash: ManagedBuffer<M>,
+        pub evidence_hash: ManagedBuffer<M>,
+        pub proposer_address: ManagedBuffer<M>,
+    }
+}
+
+pub mod commit_sig {
+    use super::TimestampNanos;
+
+    multiversx_sc::imports!();
+    multiversx_sc::derive_imports!();
+
+    #[derive(
+        TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq,
+    )]
+    pub enum BlockIdFlag {
+        Unknown,
+        Absent,
+        Commit,
+        Nil,
+    }
+
+    #[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem)]
+    pub struct Data<M: ManagedTypeApi> {
+        pub block_id_flag: BlockIdFlag,
+        pub validator_address: ManagedBuffer<M>,
+        pub timestamp: TimestampNanos,
+        pub signature: ManagedBuffer<M>,
+    }
+}
+
+pub mod commit {
+    use super::{block_id, commit_sig};
+
+    multiversx_sc::imports!();
+    multiversx_sc::derive_imports!();
+
+    /// precommits for the block, in the same order as the validator set
+    #[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
+    pub struct Data<M: ManagedTypeApi> {
+        pub height: u64,
+        pub round: u32,
+        pub block_id: block_id::Data<M>,
+        pub signatures: ManagedVec<M, commit_sig::Data<M>>,
+    }
+}
+
+pub mod header {
+    use common_types::channel_types::height;
+
+    use super::{block_header, commit, validator};
+
+    multiversx_sc::imports!();
+    multiversx_sc::derive_imports!();
+
+    #[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
+    pub struct Data<M: ManagedTypeApi> {
+        pub block_header: block_header::Data<M>,
+        pub commit: commit::Data<M>,
+        pub validator_set: ManagedVec<M, validator::Data<M>>,
+        pub trusted_height: height::Data,
+        /// the next validators of the trusted consensus state
+        pub trusted_validators: ManagedVec<M, validator::Data<M>>,
+    }
+}
+
+pub mod misbehaviour {
+    use super::header;
+
+    multiversx_sc::imports!();
+    multiversx_sc::derive_imports!();
+
+    /// two headers which conflict with each other
+    ///
+    /// Either they are at the same height with different block hashes, or header_1 is higher than header_2 but not more recent
+    #[derive(TypeAbi, TopEncode, TopDecode)]
+    pub struct Data<M: ManagedTypeApi> {
+        pub header_1: header::Data<M>,
+        pub header_2: header::Data<M>,
+    }
+}
@@ -0,0 +1,118 @@
+use client_common::UPGRADE_STORE_KEY;
+use common_types::{channel_types::height, client_types::any, ClientId, Proof};
+
+use crate::tendermint_types::{client_state, consensus_state};
+
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait UpgradeModule:
+    client_common::CommonClientLogicModule
+    + crate::client_logic::ClientLogicModule
+    + crate::verification::VerificationModule
+    + crate::views::ViewsModule
+    + host::host_views::HostViewsModule
+    + host::storage::StorageModule
+    + common_modules::utils::UtilsModule
+{
+    /// restarts the client from the upgraded states committed by the counterparty at the latest height, and returns
+    /// the upgraded height
+    ///
+    /// The upgraded states are the proto `Any` encodings committed by the counterparty. The chain id, the unbonding
+    /// period and the latest height are set by the counterparty chain, while the other parameters of the client are
+    /// kept. Proofs can only be verified again once the client is updated past the upgrade, since the upgraded
+    /// consensus state has no root.
+    #[endpoint(upgradeClient)]
+    fn upgrade_client(
+        &self,
+        client_id: ClientId<Self::Api>,
+        encoded_client_state: ManagedBuffer,
+        encoded_consensus_state: ManagedBuffer,
+        proof_upgrade_client: Proof<Self::Api>,
+        proof_upgrade_consensus_state: Proof<Self::Api>,
+    ) -> height::Data {
+        self.require_ibc_handler_caller();
+
+        let client_state_any = any::Data::<Self::Api>::from_proto(&encoded_client_state);
+        require!(
+            &client_state_any.type_url == client_state::TYPE_URL,
+            "Invalid client state type"
+        );
+        let consensus_state_any = any::Data::<Self::Api>::from_proto(&encoded_consensus_state);
+        require!(
+            &consensus_state_any.type_url == consensus_state::TYPE_URL,
+            "Invalid consensus state type"
+        );
+        let upgraded_client_state = client_state::Data::from_proto(&client_state_any.value);
+        let upgraded_consensus_state =
+            consensus_state::Data::<Self::Api>::from_proto(&consensus_state_any.value);
+
+        let client_state_mapper = self.client_states(&client_id);
+        require!(!client_state_mapper.is_empty(), "Unknown client");
+
+        let mut client_state = client_state_mapper.get();
+        require!(client_state.frozen_height.is_zero(), "Client is frozen");
+        require!(
+            upgraded_client_state.latest_height > client_state.latest_height,
+            "Upgraded client height must be greater than the current height"
+        );
+        require!(
+            !upgraded_client_state.chain_id.is_empty(),
+            "Invalid chain id"
+        );
+        require!(
+            client_state.trusting_period < upgraded_client_state.unbonding_period,
+            "Invalid trusting period"
+        );
+
+        let latest_consensus_state =
+            self.get_consensus_state(&client_id, &client_state.latest_height);
+        let upgrade_height = client_state.latest_height.revision_height;
+        let upgrade_store_key = ManagedBuffer::from(UPGRADE_STORE_KEY);
+
+        require!(
+            ics23::merkle::verify_membership(
+                &ics23::specs::sdk_specs(),
+                latest_consensus_state.root.as_managed_buffer(),
+                &proof_upgrade_client,
+                &self.build_key_path(
+                    upgrade_store_key.clone(),
+                    self.get_upgraded_client_path(upgrade_height)
+                ),
+                &encoded_client_state,
+            ),
+            "Failed to verify upgraded client"
+        );
+        require!(
+            ics23::merkle::verify_membership(
+                &ics23::specs::sdk_specs(),
+                latest_consensus_state.root.as_managed_buffer(),
+                &proof_upgrade_consensus_state,
+                &self.build_key_path(
+                    upgrade_store_key,
+                    self.get_upgraded_consensus_state_path(upgrade_height)
+                ),
+                &encoded_consensus_state,
+            ),
+            "Failed to verify upgraded consensus state"
+        );
+
+        let upgraded_height = upgraded_client_state.latest_height;
+        client_state.chain_id = upgraded_client_state.chain_id;
+        client_state.unbonding_period = upgraded_client_state.unbonding_period;
+        client_state.latest_height = upgraded_height;
+        client_state_mapper.set(client_state);
+
+        // sentinel root, as in ibc-go
+        let consensus_state = consensus_state::Data {
+            timestamp: upgraded_consensus_state.timestamp,
+            root: ManagedByteArray::new_from_bytes(&[0u8; 32]),
+            next_validators_hash: upgraded_consensus_state.next_validators_hash,
+        };
+        self.consensus_states(&client_id, &upgraded_height.to_biguint_concat())
+            .set(consensus_state);
+        self.set_processed_time_and_height(&client_id, &upgraded_height);
+
+        upgraded_height
+    }
+}
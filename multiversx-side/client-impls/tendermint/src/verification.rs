@@ -0,0 +1,255 @@
+use common_types::{proto, Hash};
+
+use crate::tendermint_types::{
+    block_header, block_id, commit,
+    commit_sig::{self, BlockIdFlag},
+    fraction, validator, Ed25519PublicKey, TimestampNanos,
+};
+
+multiversx_sc::imports!();
+
+const PRECOMMIT_VOTE_TYPE: u64 = 2;
+const LEAF_PREFIX: &[u8] = &[0];
+const INNER_PREFIX: &[u8] = &[1];
+
+#[multiversx_sc::module]
+pub trait VerificationModule {
+    /// merkle root of the protobuf encoded header fields, as computed by Tendermint
+    fn hash_block_header(&self, header: &block_header::Data<Self::Api>) -> Hash<Self::Api> {
+        let mut fields = ManagedVec::<Self::Api, ManagedBuffer>::new();
+
+        let mut version = ManagedBuffer::new();
+        proto::append_uint_field(&mut version, 1, header.version_block);
+        proto::append_uint_field(&mut version, 2, header.version_app);
+        fields.push(version);
+        fields.push(self.encode_bytes_value(&header.chain_id));
+
+        let mut height = ManagedBuffer::new();
+        proto::append_uint_field(&mut height, 1, header.height);
+        fields.push(height);
+        fields.push(proto::encode_timestamp(header.time));
+        fields.push(self.encode_block_id(&header.last_block_id));
+
+        for hash in [
+            &header.last_commit_hash,
+            &header.data_hash,
+            header.validators_hash.as_managed_buffer(),
+            header.next_validators_hash.as_managed_buffer(),
+            &header.consensus_hash,
+            header.app_hash.as_managed_buffer(),
+            &header.last_results_hash,
+            &header.evidence_hash,
+            &header.proposer_address,
+        ] {
+            fields.push(self.encode_bytes_value(hash));
+        }
+
+        self.merkle_root(&fields, 0, fields.len())
+    }
+
+    /// merkle root of the validators' public keys and voting powers
+    fn hash_validator_set(
+        &self,
+        validators: &ManagedVec<validator::Data<Self::Api>>,
+    ) -> Hash<Self::Api> {
+        let mut leaves = ManagedVec::<Self::Api, ManagedBuffer>::new();
+        for validator in validators {
+            let mut public_key = ManagedBuffer::new();
+            proto::append_bytes_field(&mut public_key, 1, validator.pub_key.as_managed_buffer());
+
+            let mut simple_validator = ManagedBuffer::new();
+            proto::append_message_field(&mut simple_validator, 1, &public_key);
+            proto::append_uint_field(&mut simple_validator, 2, validator.voting_power);
+            leaves.push(simple_validator);
+        }
+
+        self.merkle_root(&leaves, 0, leaves.len())
+    }
+
+    /// Verifies that validators holding more than 2/3 of the voting power of `validators` signed the commit
+    ///
+    /// The commit signatures must be in the same order as the validators
+    fn verify_commit(
+        &self,
+        chain_id: &ManagedBuffer,
+        validators: &ManagedVec<validator::Data<Self::Api>>,
+        commit: &commit::Data<Self::Api>,
+    ) {
+        require!(
+            commit.signatures.len() == validators.len(),
+            "Commit signatures must match the validators"
+        );
+
+        let total_voting_power = self.get_total_voting_power(validators);
+        let mut tallied_voting_power = 0u128;
+        for (commit_sig, validator) in commit.signatures.iter().zip(validators.iter()) {
+            if commit_sig.block_id_flag != BlockIdFlag::Commit {
+                continue;
+            }
+
+            require!(
+                &commit_sig.validator_address == validator.address.as_managed_buffer(),
+                "Commit signature does not match validator"
+            );
+            self.verify_commit_sig(chain_id, commit, &commit_sig, &validator.pub_key);
+
+            tallied_voting_power += validator.voting_power as u128;
+            if tallied_voting_power * 3 > total_voting_power * 2 {
+                return;
+            }
+        }
+
+        sc_panic!("Insufficient voting power");
+    }
+
+    /// Verifies that trusted validators holding more than `trust_level` of the trusted voting power signed the commit
+    ///
+    /// Signatures from validators which are not trusted are ignored
+    fn verify_commit_trusting(
+        &self,
+        chain_id: &ManagedBuffer,
+        trusted_validators: &ManagedVec<validator::Data<Self::Api>>,
+        commit: &commit::Data<Self::Api>,
+        trust_level: &fraction::Data,
+    ) {
+        let total_voting_power = self.get_total_voting_power(trusted_validators);
+        let mut seen_validators = ManagedVec::<Self::Api, usize>::new();
+        let mut tallied_voting_power = 0u128;
+        for commit_sig in &commit.signatures {
+            if commit_sig.block_id_flag != BlockIdFlag::Commit {
+                continue;
+            }
+
+            let validator_index = match trusted_validators.iter().position(|validator| {
+                validator.address.as_managed_buffer() == &commit_sig.validator_address
+            }) {
+                Some(validator_index) => validator_index,
+                None => continue,
+            };
+            require!(
+                !seen_validators.contains(&validator_index),
+                "Double vote from validator"
+            );
+            seen_validators.push(validator_index);
+
+            let validator = trusted_validators.get(validator_index);
+            self.verify_commit_sig(chain_id, commit, &commit_sig, &validator.pub_key);
+
+            tallied_voting_power += validator.voting_power as u128;
+            if tallied_voting_power * trust_level.denominator as u128
+                > total_voting_power * trust_level.numerator as u128
+            {
+                return;
+            }
+        }
+
+        sc_panic!("Insufficient trusted voting power");
+    }
+
+    /// Fails if the signature is invalid
+    fn verify_commit_sig(
+        &self,
+        chain_id: &ManagedBuffer,
+        commit: &commit::Data<Self::Api>,
+        commit_sig: &commit_sig::Data<Self::Api>,
+        pub_key: &Ed25519PublicKey<Self::Api>,
+    ) {
+        let sign_bytes = self.get_vote_sign_bytes(chain_id, commit, commit_sig.timestamp);
+        self.crypto().verify_ed25519(
+            pub_key.as_managed_buffer(),
+            &sign_bytes,
+            &commit_sig.signature,
+        );
+    }
+
+    /// length delimited CanonicalVote of the precommit for the commit's block
+    fn get_vote_sign_bytes(
+        &self,
+        chain_id: &ManagedBuffer,
+        commit: &commit::Data<Self::Api>,
+        timestamp: TimestampNanos,
+    ) -> ManagedBuffer {
+        let mut canonical_vote = ManagedBuffer::new();
+        proto::append_uint_field(&mut canonical_vote, 1, PRECOMMIT_VOTE_TYPE);
+        proto::append_sfixed64_field(&mut canonical_vote, 2, commit.height as i64);
+        proto::append_sfixed64_field(&mut canonical_vote, 3, commit.round as i64);
+        proto::append_message_field(
+            &mut canonical_vote,
+            4,
+            &self.encode_block_id(&commit.block_id),
+        );
+        proto::append_message_field(&mut canonical_vote, 5, &proto::encode_timestamp(timestamp));
+        proto::append_bytes_field(&mut canonical_vote, 6, chain_id);
+
+        proto::encode_length_delimited(&canonical_vote)
+    }
+
+    /// BlockID and CanonicalBlockID share the same encoding
+    fn encode_block_id(&self, block_id: &block_id::Data<Self::Api>) -> ManagedBuffer {
+        let mut part_set_header = ManagedBuffer::new();
+        proto::append_uint_field(
+            &mut part_set_header,
+            1,
+            block_id.part_set_header_total as u64,
+        );
+        proto::append_bytes_field(&mut part_set_header, 2, &block_id.part_set_header_hash);
+
+        let mut encoded = ManagedBuffer::new();
+        proto::append_bytes_field(&mut encoded, 1, &block_id.hash);
+        proto::append_message_field(&mut encoded, 2, &part_set_header);
+
+        encoded
+    }
+
+    /// google.protobuf.BytesValue, also used for the string chain id
+    fn encode_bytes_value(&self, bytes: &ManagedBuffer) -> ManagedBuffer {
+        let mut encoded = ManagedBuffer::new();
+        proto::append_bytes_field(&mut encoded, 1, bytes);
+
+        encoded
+    }
+
+    /// RFC 6962 merkle root of the leaves in [start, end), split at the largest power of two smaller than the leaf count
+    fn merkle_root(
+        &self,
+        leaves: &ManagedVec<ManagedBuffer>,
+        start: usize,
+        end: usize,
+    ) -> Hash<Self::Api> {
+        let leaf_count = end - start;
+        if leaf_count == 0 {
+            return self.crypto().sha256(ManagedBuffer::new());
+        }
+
+        if leaf_count == 1 {
+            let mut leaf = ManagedBuffer::new_from_bytes(LEAF_PREFIX);
+            leaf.append(&leaves.get(start));
+
+            return self.crypto().sha256(leaf);
+        }
+
+        let mut split = 1;
+        while split * 2 < leaf_count {
+            split *= 2;
+        }
+
+        let mut inner = ManagedBuffer::new_from_bytes(INNER_PREFIX);
+        inner.append(
+            self.merkle_root(leaves, start, start + split)
+                .as_managed_buffer(),
+        );
+        inner.append(
+            self.merkle_root(leaves, start + split, end)
+                .as_managed_buffer(),
+        );
+
+        self.crypto().sha256(inner)
+    }
+
+    fn get_total_voting_power(&self, validators: &ManagedVec<validator::Data<Self::Api>>) -> u128 {
+        validators
+            .iter()
+            .map(|validator| validator.voting_power as u128)
+            .sum()
+    }
+}
@@ -0,0 +1,180 @@
+use client_common::{
+    ClientStatus, GetLatestInfoResultType, VerifyMembershipArgs, VerifyNonMembershipArgs,
+};
+use common_types::{channel_types::height, client_types::any, ClientId, Path, UnixTimestamp};
+
+use crate::tendermint_types::{client_state, consensus_state};
+
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait ViewsModule:
+    client_common::CommonClientLogicModule
+    + crate::client_logic::ClientLogicModule
+    + crate::verification::VerificationModule
+    + host::host_views::HostViewsModule
+    + host::storage::StorageModule
+    + common_modules::utils::UtilsModule
+{
+    /// Timestamp is nanoseconds since unix epoch
+    #[view(getTimestampAtHeight)]
+    fn get_timestamp_at_height(
+        &self,
+        client_id: ClientId<Self::Api>,
+        height: height::Data,
+    ) -> UnixTimestamp {
+        let consensus_state = self.get_consensus_state(&client_id, &height);
+
+        consensus_state.timestamp
+    }
+
+    /// returns the latest height of the client state corresponding to `clientId`
+    #[view(getLatestHeight)]
+    fn get_latest_height(&self, client_id: &ClientId<Self::Api>) -> height::Data {
+        let client_state = self.get_client_state(client_id);
+
+        client_state.latest_height
+    }
+
+    /// returns the status of the client corresponding to `clientId`
+    ///
+    /// The client is frozen once misbehaviour was detected, and expired once the latest consensus state is outside the trusting period.
+    /// "None" means the client is unknown.
+    #[view(getStatus)]
+    fn get_status(&self, client_id: &ClientId<Self::Api>) -> ClientStatus {
+        let client_state_mapper = self.client_states(client_id);
+        if client_state_mapper.is_empty() {
+            return ClientStatus::None;
+        }
+
+        let client_state = client_state_mapper.get();
+        if !client_state.frozen_height.is_zero() {
+            return ClientStatus::Frozen;
+        }
+
+        let latest_consensus_state =
+            self.get_consensus_state(client_id, &client_state.latest_height);
+        let trusting_period = self.checked_timestamp_to_unix_mul(client_state.trusting_period);
+        let current_time =
+            self.checked_timestamp_to_unix_mul(self.blockchain().get_block_timestamp());
+        if latest_consensus_state
+            .timestamp
+            .saturating_add(trusting_period)
+            <= current_time
+        {
+            return ClientStatus::Expired;
+        }
+
+        ClientStatus::Active
+    }
+
+    /// returns the latest height, timestamp and status of the client corresponding to `clientId`
+    #[view(getLatestInfo)]
+    fn get_latest_info(&self, client_id: ClientId<Self::Api>) -> GetLatestInfoResultType {
+        let latest_height = self.get_latest_height(&client_id);
+        let client_status = self.get_status(&client_id);
+        let latest_timestamp = self.get_timestamp_at_height(client_id, latest_height);
+
+        GetLatestInfoResultType {
+            latest_height,
+            latest_timestamp,
+            client_status,
+        }
+    }
+
+    /// `proof` is an ICS-23 `MerkleProof` of `path` in the `prefix` store, against the app hash at `height`
+    #[view(verifyMembership)]
+    fn verify_membership(&self, args: VerifyMembershipArgs<Self::Api>) -> bool {
+        self.require_active_status(self.get_status(&args.client_id));
+
+        let consensus_state = self.get_consensus_state(&args.client_id, &args.height);
+        self.require_delay_period_passed(
+            &args.client_id,
+            &args.height,
+            args.delay_time_period,
+            args.delay_block_period,
+        );
+
+        ics23::merkle::verify_membership(
+            &ics23::specs::sdk_specs(),
+            consensus_state.root.as_managed_buffer(),
+            &args.proof,
+            &self.build_key_path(args.prefix, args.path),
+            &args.value,
+        )
+    }
+
+    /// `proof` is an ICS-23 `MerkleProof` of the absence of `path` in the `prefix` store, against the app hash at `height`
+    #[view(verifyNonMembership)]
+    fn verify_non_membership(&self, args: VerifyNonMembershipArgs<Self::Api>) -> bool {
+        self.require_active_status(self.get_status(&args.client_id));
+
+        let consensus_state = self.get_consensus_state(&args.client_id, &args.height);
+        self.require_delay_period_passed(
+            &args.client_id,
+            &args.height,
+            args.delay_time_period,
+            args.delay_block_period,
+        );
+
+        ics23::merkle::verify_non_membership(
+            &ics23::specs::sdk_specs(),
+            consensus_state.root.as_managed_buffer(),
+            &args.proof,
+            &self.build_key_path(args.prefix, args.path),
+        )
+    }
+
+    /// returns the clientState corresponding to `clientId`
+    #[view(getClientState)]
+    fn get_client_state(&self, client_id: &ClientId<Self::Api>) -> client_state::Data<Self::Api> {
+        let mapper = self.client_states(client_id);
+        require!(!mapper.is_empty(), "Client state not found");
+
+        mapper.get()
+    }
+
+    #[view(getConsensusState)]
+    fn get_consensus_state(
+        &self,
+        client_id: &ClientId<Self::Api>,
+        height: &height::Data,
+    ) -> consensus_state::Data<Self::Api> {
+        let mapper = self.consensus_states(client_id, &height.to_biguint_concat());
+        require!(!mapper.is_empty(), "Consensus state not found");
+
+        mapper.get()
+    }
+
+    /// returns the clientState corresponding to `clientId`, encoded as a protobuf Any, as committed by the handler
+    #[view(getClientStateAny)]
+    fn get_client_state_any(&self, client_id: &ClientId<Self::Api>) -> ManagedBuffer {
+        let client_state = self.get_client_state(client_id);
+
+        any::Data::new(client_state::TYPE_URL, client_state.to_proto()).to_proto()
+    }
+
+    /// returns the consensus state at `height`, encoded as a protobuf Any, as committed by the handler
+    #[view(getConsensusStateAny)]
+    fn get_consensus_state_any(
+        &self,
+        client_id: &ClientId<Self::Api>,
+        height: &height::Data,
+    ) -> ManagedBuffer {
+        let consensus_state = self.get_consensus_state(client_id, height);
+
+        any::Data::new(consensus_state::TYPE_URL, consensus_state.to_proto()).to_proto()
+    }
+
+    fn build_key_path(
+        &self,
+        prefix: ManagedBuffer,
+        path: Path<Self::Api>,
+    ) -> ManagedVec<ManagedBuffer> {
+        let mut key_path = ManagedVec::new();
+        key_path.push(prefix);
+        key_path.push(path);
+
+        key_path
+    }
+}
@@ -0,0 +1,786 @@
+use std::{cell::RefCell, rc::Rc};
+
+use client_common::{
+    ClientStatus, CommonClientLogicModule, VerifyMembershipArgs, VerifyNonMembershipArgs,
+};
+use common_types::{channel_types::height, client_types::any};
+use multiversx_sc::types::ManagedBuffer;
+use multiversx_sc_scenario::{
+    imports::BlockchainStateWrapper, managed_buffer, rust_biguint, DebugApi,
+};
+use tendermint::{
+    client_logic::ClientLogicModule,
+    misbehaviour::MisbehaviourModule,
+    tendermint_types::{client_state, consensus_state, fraction, misbehaviour},
+    views::ViewsModule,
+};
+use tendermint_setup::{
+    byte_array_hex, managed_hex,
+    vectors::{self, HEADER_11, HEADER_15},
+    TendermintSetup, CLIENT_ID, CURRENT_TIMESTAMP, MAX_CLOCK_DRIFT, SUBSTITUTE_CLIENT_ID,
+    TRUSTED_HEIGHT, TRUSTING_PERIOD,
+};
+
+pub mod tendermint_setup;
+
+fn get_tendermint_setup<
+    HostObjBuilder: 'static + Copy + Fn() -> host::ContractObj<DebugApi>,
+    TendermintObjBuilder: 'static + Copy + Fn() -> tendermint::ContractObj<DebugApi>,
+>(
+    host_builder: HostObjBuilder,
+    tendermint_builder: TendermintObjBuilder,
+) -> TendermintSetup<HostObjBuilder, TendermintObjBuilder> {
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_biguint!(0));
+    TendermintSetup::new(
+        Rc::new(RefCell::new(b_mock)),
+        &owner,
+        host_builder,
+        tendermint_builder,
+    )
+}
+
+fn header_height(revision_height: u64) -> height::Data {
+    height::Data {
+        revision_number: vectors::REVISION_NUMBER,
+        revision_height,
+    }
+}
+
+#[test]
+fn initialize_client_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    setup.b_mock.borrow_mut().set_block_nonce(5);
+    setup.initialize_client().assert_ok();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.tendermint_wrapper, |sc| {
+            let client_id = managed_buffer!(CLIENT_ID);
+            let latest_info = sc.get_latest_info(client_id.clone());
+            assert!(latest_info.latest_height == TRUSTED_HEIGHT);
+            assert_eq!(latest_info.latest_timestamp, vectors::TRUSTED_TIME);
+            assert!(latest_info.client_status == ClientStatus::Active);
+
+            let height_key = TRUSTED_HEIGHT.to_biguint_concat();
+            assert_eq!(
+                sc.processed_times(&client_id, &height_key).get(),
+                CURRENT_TIMESTAMP
+            );
+            assert_eq!(sc.processed_heights(&client_id, &height_key).get(), 5);
+
+            // the states are committed as the protobuf Any of ibc-go
+            let client_state_any = any::Data::from_proto(&sc.get_client_state_any(&client_id));
+            assert_eq!(
+                client_state_any.type_url,
+                managed_buffer!(client_state::TYPE_URL)
+            );
+            let client_state = client_state::Data::from_proto(&client_state_any.value);
+            assert!(client_state.latest_height == TRUSTED_HEIGHT);
+            assert_eq!(client_state.trusting_period, TRUSTING_PERIOD);
+
+            let consensus_state_any =
+                any::Data::from_proto(&sc.get_consensus_state_any(&client_id, &TRUSTED_HEIGHT));
+            assert_eq!(
+                consensus_state_any.type_url,
+                managed_buffer!(consensus_state::TYPE_URL)
+            );
+            assert!(
+                consensus_state::Data::from_proto(&consensus_state_any.value)
+                    == sc.get_consensus_state(&client_id, &TRUSTED_HEIGHT)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn initialize_client_not_ibc_handler_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &setup.owner,
+            &setup.tendermint_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.initialize_client(
+                    managed_buffer!(CLIENT_ID),
+                    tendermint_setup::new_client_state(),
+                    tendermint_setup::new_trusted_consensus_state(),
+                );
+            },
+        )
+        .assert_user_error("Only the IBC handler may call this endpoint");
+}
+
+#[test]
+fn initialize_client_invalid_trust_level_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    for (numerator, denominator) in [(1, 4), (4, 3), (1, 0)] {
+        setup
+            .initialize_client_with_state(|| {
+                let mut client_state = tendermint_setup::new_client_state();
+                client_state.trust_level = fraction::Data {
+                    numerator,
+                    denominator,
+                };
+                client_state
+            })
+            .assert_user_error("Invalid trust level");
+    }
+}
+
+#[test]
+fn initialize_client_invalid_trusting_period_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    setup
+        .initialize_client_with_state(|| {
+            let mut client_state = tendermint_setup::new_client_state();
+            client_state.trusting_period = client_state.unbonding_period;
+            client_state
+        })
+        .assert_user_error("Invalid trusting period");
+}
+
+#[test]
+fn initialize_client_twice_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    setup.initialize_client().assert_ok();
+    setup
+        .initialize_client()
+        .assert_user_error("Client already known");
+}
+
+#[test]
+fn update_client_adjacent_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    setup.initialize_client().assert_ok();
+    setup.update_client(&HEADER_11).assert_ok();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.tendermint_wrapper, |sc| {
+            let client_id = managed_buffer!(CLIENT_ID);
+            assert!(sc.get_latest_height(&client_id) == header_height(HEADER_11.height));
+
+            let consensus_state = sc.get_consensus_state(&client_id, &header_height(11));
+            assert_eq!(consensus_state.timestamp, HEADER_11.time);
+            assert_eq!(consensus_state.root, byte_array_hex(HEADER_11.app_hash));
+            assert_eq!(
+                consensus_state.next_validators_hash,
+                byte_array_hex(HEADER_11.next_validators_hash)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn update_client_non_adjacent_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    setup.initialize_client().assert_ok();
+    setup.update_client(&HEADER_15).assert_ok();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.tendermint_wrapper, |sc| {
+            let client_id = managed_buffer!(CLIENT_ID);
+            assert!(sc.get_latest_height(&client_id) == header_height(HEADER_15.height));
+            assert_eq!(
+                sc.get_timestamp_at_height(client_id, header_height(15)),
+                HEADER_15.time
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn update_client_unknown_client_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    setup
+        .update_client(&HEADER_11)
+        .assert_user_error("Unknown client");
+}
+
+#[test]
+fn update_client_insufficient_voting_power_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    setup.initialize_client().assert_ok();
+    setup
+        .update_client(&vectors::HEADER_11_NOT_ENOUGH_SIGNATURES)
+        .assert_user_error("Insufficient voting power");
+}
+
+#[test]
+fn update_client_insufficient_trusted_voting_power_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    setup.initialize_client().assert_ok();
+    setup
+        .update_client(&vectors::UNTRUSTED_HEADER_15)
+        .assert_user_error("Insufficient trusted voting power");
+}
+
+#[test]
+fn update_client_invalid_signature_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    setup.initialize_client().assert_ok();
+    setup
+        .update_client_with_header(|| {
+            let mut header = tendermint_setup::new_header(&HEADER_11);
+            let mut signature = hex::decode(HEADER_11.signatures[0]).unwrap();
+            signature[0] ^= 1;
+
+            let mut commit_sig = header.commit.signatures.get(0);
+            commit_sig.signature = ManagedBuffer::new_from_bytes(&signature);
+            let _ = header.commit.signatures.set(0, &commit_sig);
+            header
+        })
+        .assert_error(10, "invalid signature");
+}
+
+#[test]
+fn update_client_commit_mismatch_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    setup.initialize_client().assert_ok();
+    setup
+        .update_client_with_header(|| {
+            let mut header = tendermint_setup::new_header(&HEADER_11);
+            header.block_header.app_hash = byte_array_hex(vectors::TRUSTED_APP_HASH);
+            header
+        })
+        .assert_user_error("Commit does not match the header");
+}
+
+#[test]
+fn update_client_wrong_trusted_validators_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    setup.initialize_client().assert_ok();
+    setup
+        .update_client_with_header(|| {
+            let mut header = tendermint_setup::new_header(&HEADER_15);
+            header.trusted_validators = tendermint_setup::new_validator_set(HEADER_15.validators);
+            header
+        })
+        .assert_user_error("Trusted validators do not match the trusted consensus state");
+}
+
+#[test]
+fn update_client_outside_trusting_period_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    setup.initialize_client().assert_ok();
+    setup
+        .b_mock
+        .borrow_mut()
+        .set_block_timestamp(CURRENT_TIMESTAMP + TRUSTING_PERIOD);
+    setup
+        .update_client(&HEADER_11)
+        .assert_user_error("Trusted consensus state is outside the trusting period");
+}
+
+#[test]
+fn update_client_max_clock_drift_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    setup.initialize_client().assert_ok();
+    setup
+        .b_mock
+        .borrow_mut()
+        .set_block_timestamp(HEADER_15.time / 1_000_000_000 - MAX_CLOCK_DRIFT);
+    setup
+        .update_client(&HEADER_15)
+        .assert_user_error("Header time exceeds the max clock drift");
+}
+
+#[test]
+fn update_client_same_header_twice_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    setup.initialize_client().assert_ok();
+    setup.update_client(&HEADER_11).assert_ok();
+    setup.update_client(&HEADER_11).assert_ok();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.tendermint_wrapper, |sc| {
+            assert!(sc.get_status(&managed_buffer!(CLIENT_ID)) == ClientStatus::Active);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn try_update_client_not_ibc_handler_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    setup.initialize_client().assert_ok();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &setup.relayer,
+            &setup.tendermint_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.update_client(
+                    managed_buffer!(CLIENT_ID),
+                    tendermint_setup::new_header(&HEADER_11),
+                );
+            },
+        )
+        .assert_user_error("Only the IBC handler may call this endpoint");
+}
+
+#[test]
+fn update_client_conflicting_header_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    setup.initialize_client().assert_ok();
+    setup.update_client(&HEADER_11).assert_ok();
+    setup
+        .update_client(&vectors::CONFLICTING_HEADER_11)
+        .assert_ok();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.tendermint_wrapper, |sc| {
+            assert!(sc.get_status(&managed_buffer!(CLIENT_ID)) == ClientStatus::Frozen);
+        })
+        .assert_ok();
+    setup
+        .update_client(&HEADER_15)
+        .assert_user_error("Client is frozen");
+}
+
+#[test]
+fn submit_misbehaviour_same_height_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    setup.initialize_client().assert_ok();
+    setup
+        .submit_misbehaviour(&HEADER_11, &vectors::CONFLICTING_HEADER_11)
+        .assert_ok();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.tendermint_wrapper, |sc| {
+            let client_state = sc.get_client_state(&managed_buffer!(CLIENT_ID));
+            assert!(!client_state.frozen_height.is_zero());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn submit_misbehaviour_time_violation_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    setup.initialize_client().assert_ok();
+    setup
+        .submit_misbehaviour(&vectors::HEADER_12_EARLIER_TIME, &HEADER_11)
+        .assert_ok();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.tendermint_wrapper, |sc| {
+            assert!(sc.get_status(&managed_buffer!(CLIENT_ID)) == ClientStatus::Frozen);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn submit_misbehaviour_no_conflict_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    setup.initialize_client().assert_ok();
+    setup
+        .submit_misbehaviour(&HEADER_11, &HEADER_11)
+        .assert_user_error("Headers do not conflict");
+    setup
+        .submit_misbehaviour(&HEADER_15, &HEADER_11)
+        .assert_user_error("Headers do not conflict");
+}
+
+#[test]
+fn try_submit_misbehaviour_not_ibc_handler_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    setup.initialize_client().assert_ok();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &setup.relayer,
+            &setup.tendermint_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.submit_misbehaviour(
+                    managed_buffer!(CLIENT_ID),
+                    misbehaviour::Data {
+                        header_1: tendermint_setup::new_header(&HEADER_11),
+                        header_2: tendermint_setup::new_header(&vectors::CONFLICTING_HEADER_11),
+                    },
+                );
+            },
+        )
+        .assert_user_error("Only the IBC handler may call this endpoint");
+}
+
+#[test]
+fn try_verify_membership_frozen_client_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    setup.initialize_client().assert_ok();
+    setup
+        .submit_misbehaviour(&HEADER_11, &vectors::CONFLICTING_HEADER_11)
+        .assert_ok();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.tendermint_wrapper, |sc| {
+            sc.verify_membership(VerifyMembershipArgs {
+                client_id: managed_buffer!(CLIENT_ID),
+                height: TRUSTED_HEIGHT,
+                delay_time_period: 0,
+                delay_block_period: 0,
+                proof: managed_hex(vectors::COMMITMENT_PROOF),
+                prefix: managed_buffer!(b"ibc"),
+                path: managed_buffer!(vectors::COMMITMENT_PATH),
+                value: managed_hex(vectors::COMMITMENT),
+            });
+        })
+        .assert_user_error("Client is frozen");
+}
+
+#[test]
+fn try_verify_non_membership_expired_client_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    setup.initialize_client().assert_ok();
+    setup
+        .b_mock
+        .borrow_mut()
+        .set_block_timestamp(vectors::TRUSTED_TIME / 1_000_000_000 + TRUSTING_PERIOD + 1);
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.tendermint_wrapper, |sc| {
+            sc.verify_non_membership(VerifyNonMembershipArgs {
+                client_id: managed_buffer!(CLIENT_ID),
+                height: TRUSTED_HEIGHT,
+                delay_time_period: 0,
+                delay_block_period: 0,
+                proof: managed_hex(vectors::RECEIPT_ABSENCE_PROOF),
+                prefix: managed_buffer!(b"ibc"),
+                path: managed_buffer!(vectors::RECEIPT_PATH),
+            });
+        })
+        .assert_user_error("Client is expired");
+}
+
+#[test]
+fn recover_client_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    setup.initialize_client().assert_ok();
+    setup
+        .submit_misbehaviour(&HEADER_11, &vectors::CONFLICTING_HEADER_11)
+        .assert_ok();
+    setup
+        .initialize_substitute_client(|| client_state::Data {
+            chain_id: managed_buffer!(b"substitute-chain"),
+            trusting_period: TRUSTING_PERIOD + 500,
+            latest_height: header_height(11),
+            ..tendermint_setup::new_client_state()
+        })
+        .assert_ok();
+
+    setup.recover_client().assert_ok();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.tendermint_wrapper, |sc| {
+            let client_id = managed_buffer!(CLIENT_ID);
+            assert!(sc.get_status(&client_id) == ClientStatus::Active);
+
+            let client_state = sc.get_client_state(&client_id);
+            assert!(client_state.frozen_height.is_zero());
+            assert!(client_state.latest_height == header_height(11));
+            assert_eq!(client_state.chain_id, managed_buffer!(b"substitute-chain"));
+            assert_eq!(client_state.trusting_period, TRUSTING_PERIOD + 500);
+            assert!(
+                sc.get_consensus_state(&client_id, &header_height(11))
+                    == sc.get_consensus_state(
+                        &managed_buffer!(SUBSTITUTE_CLIENT_ID),
+                        &header_height(11)
+                    )
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn try_recover_client_incompatible_parameters_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    setup.initialize_client().assert_ok();
+    setup
+        .initialize_substitute_client(|| client_state::Data {
+            max_clock_drift: MAX_CLOCK_DRIFT * 2,
+            latest_height: header_height(11),
+            ..tendermint_setup::new_client_state()
+        })
+        .assert_ok();
+
+    setup
+        .recover_client()
+        .assert_user_error("Incompatible client parameters");
+}
+
+#[test]
+fn try_recover_client_not_ibc_handler_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    setup.initialize_client().assert_ok();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &setup.relayer,
+            &setup.tendermint_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.recover_client(
+                    managed_buffer!(CLIENT_ID),
+                    managed_buffer!(SUBSTITUTE_CLIENT_ID),
+                );
+            },
+        )
+        .assert_user_error("Only the IBC handler may call this endpoint");
+}
+
+#[test]
+fn upgrade_client_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    setup.initialize_upgradable_client().assert_ok();
+    setup
+        .upgrade_client(|| managed_hex(vectors::UPGRADED_CLIENT_STATE))
+        .assert_ok();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.tendermint_wrapper, |sc| {
+            let upgraded_height = height::Data {
+                revision_number: vectors::REVISION_NUMBER + 1,
+                revision_height: 1,
+            };
+            let client_state = sc.get_client_state(&managed_buffer!(CLIENT_ID));
+            assert!(client_state.chain_id == managed_buffer!(vectors::UPGRADED_CHAIN_ID));
+            assert_eq!(
+                client_state.unbonding_period,
+                vectors::UPGRADED_UNBONDING_PERIOD
+            );
+            assert_eq!(client_state.trusting_period, TRUSTING_PERIOD);
+            assert_eq!(client_state.max_clock_drift, MAX_CLOCK_DRIFT);
+            assert!(client_state.latest_height == upgraded_height);
+
+            let consensus_state =
+                sc.get_consensus_state(&managed_buffer!(CLIENT_ID), &upgraded_height);
+            assert_eq!(consensus_state.timestamp, vectors::UPGRADED_TIME);
+            assert!(
+                consensus_state.next_validators_hash
+                    == byte_array_hex(vectors::VALIDATOR_SET_A_HASH)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn try_upgrade_client_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    setup.initialize_upgradable_client().assert_ok();
+    let upgraded_height = height::Data {
+        revision_number: vectors::REVISION_NUMBER + 1,
+        revision_height: 1,
+    };
+
+    setup
+        .upgrade_client(|| {
+            tendermint_setup::encode_upgraded_client_state(
+                b"/ibc.lightclients.solomachine.v3.ClientState",
+                vectors::UPGRADED_UNBONDING_PERIOD,
+                upgraded_height,
+            )
+        })
+        .assert_user_error("Invalid client state type");
+    setup
+        .upgrade_client(|| {
+            tendermint_setup::encode_upgraded_client_state(
+                client_state::TYPE_URL,
+                vectors::UPGRADED_UNBONDING_PERIOD,
+                TRUSTED_HEIGHT,
+            )
+        })
+        .assert_user_error("Upgraded client height must be greater than the current height");
+    setup
+        .upgrade_client(|| {
+            tendermint_setup::encode_upgraded_client_state(
+                client_state::TYPE_URL,
+                TRUSTING_PERIOD,
+                upgraded_height,
+            )
+        })
+        .assert_user_error("Invalid trusting period");
+    // not the state committed by the counterparty
+    setup
+        .upgrade_client(|| {
+            tendermint_setup::encode_upgraded_client_state(
+                client_state::TYPE_URL,
+                vectors::UPGRADED_UNBONDING_PERIOD + 1,
+                upgraded_height,
+            )
+        })
+        .assert_user_error("Failed to verify upgraded client");
+
+    setup
+        .submit_misbehaviour(&HEADER_11, &vectors::CONFLICTING_HEADER_11)
+        .assert_ok();
+    setup
+        .upgrade_client(|| managed_hex(vectors::UPGRADED_CLIENT_STATE))
+        .assert_user_error("Client is frozen");
+}
+
+#[test]
+fn get_status_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.tendermint_wrapper, |sc| {
+            assert!(sc.get_status(&managed_buffer!(CLIENT_ID)) == ClientStatus::None);
+        })
+        .assert_ok();
+
+    setup.initialize_client().assert_ok();
+    setup
+        .b_mock
+        .borrow_mut()
+        .set_block_timestamp(vectors::TRUSTED_TIME / 1_000_000_000 + TRUSTING_PERIOD + 1);
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.tendermint_wrapper, |sc| {
+            assert!(sc.get_status(&managed_buffer!(CLIENT_ID)) == ClientStatus::Expired);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn verify_membership_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    setup.initialize_client().assert_ok();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.tendermint_wrapper, |sc| {
+            let args = |prefix: &[u8], value: &str| VerifyMembershipArgs {
+                client_id: managed_buffer!(CLIENT_ID),
+                height: TRUSTED_HEIGHT,
+                delay_time_period: 0,
+                delay_block_period: 0,
+                proof: managed_hex(vectors::COMMITMENT_PROOF),
+                prefix: ManagedBuffer::new_from_bytes(prefix),
+                path: managed_buffer!(vectors::COMMITMENT_PATH),
+                value: managed_hex(value),
+            };
+            assert!(sc.verify_membership(args(b"ibc", vectors::COMMITMENT)));
+            assert!(!sc.verify_membership(args(b"ibc", vectors::OTHER_HASH)));
+            assert!(!sc.verify_membership(args(b"bank", vectors::COMMITMENT)));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn verify_membership_delay_time_period_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    setup.initialize_client().assert_ok();
+
+    let verify_with_delay = || {
+        setup
+            .b_mock
+            .borrow_mut()
+            .execute_query(&setup.tendermint_wrapper, |sc| {
+                assert!(sc.verify_membership(VerifyMembershipArgs {
+                    client_id: managed_buffer!(CLIENT_ID),
+                    height: TRUSTED_HEIGHT,
+                    delay_time_period: 10 * 1_000_000_000,
+                    delay_block_period: 0,
+                    proof: managed_hex(vectors::COMMITMENT_PROOF),
+                    prefix: managed_buffer!(b"ibc"),
+                    path: managed_buffer!(vectors::COMMITMENT_PATH),
+                    value: managed_hex(vectors::COMMITMENT),
+                }));
+            })
+    };
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .set_block_timestamp(CURRENT_TIMESTAMP + 9);
+    verify_with_delay().assert_user_error("Delay time period has not passed");
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .set_block_timestamp(CURRENT_TIMESTAMP + 10);
+    verify_with_delay().assert_ok();
+}
+
+#[test]
+fn verify_non_membership_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    setup.initialize_client().assert_ok();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.tendermint_wrapper, |sc| {
+            assert!(sc.verify_non_membership(VerifyNonMembershipArgs {
+                client_id: managed_buffer!(CLIENT_ID),
+                height: TRUSTED_HEIGHT,
+                delay_time_period: 0,
+                delay_block_period: 0,
+                proof: managed_hex(vectors::RECEIPT_ABSENCE_PROOF),
+                prefix: managed_buffer!(b"ibc"),
+                path: managed_buffer!(vectors::RECEIPT_PATH),
+            }));
+
+            // the commitment exists
+            assert!(!sc.verify_non_membership(VerifyNonMembershipArgs {
+                client_id: managed_buffer!(CLIENT_ID),
+                height: TRUSTED_HEIGHT,
+                delay_time_period: 0,
+                delay_block_period: 0,
+                proof: managed_hex(vectors::COMMITMENT_PROOF),
+                prefix: managed_buffer!(b"ibc"),
+                path: managed_buffer!(vectors::COMMITMENT_PATH),
+            }));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn verify_membership_unknown_height_test() {
+    let setup = get_tendermint_setup(host::contract_obj, tendermint::contract_obj);
+    setup.initialize_client().assert_ok();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.tendermint_wrapper, |sc| {
+            let _ = sc.verify_membership(VerifyMembershipArgs {
+                client_id: managed_buffer!(CLIENT_ID),
+                height: header_height(HEADER_11.height),
+                delay_time_period: 0,
+                delay_block_period: 0,
+                proof: managed_hex(vectors::COMMITMENT_PROOF),
+                prefix: managed_buffer!(b"ibc"),
+                path: managed_buffer!(vectors::COMMITMENT_PATH),
+                value: managed_hex(vectors::COMMITMENT),
+            });
+        })
+        .assert_user_error("Consensus state not found");
+}
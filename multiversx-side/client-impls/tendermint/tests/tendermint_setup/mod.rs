@@ -0,0 +1,393 @@
+#![allow(dead_code)]
+
+use std::{cell::RefCell, rc::Rc};
+
+use common_types::{channel_types::height, client_types::any, proto};
+use host::Host;
+use multiversx_sc::types::{Address, ManagedBuffer, ManagedByteArray, ManagedVec};
+use multiversx_sc_scenario::{
+    imports::{BlockchainStateWrapper, ContractObjWrapper, TxResult},
+    managed_address, managed_buffer, rust_biguint, DebugApi,
+};
+use tendermint::{
+    client_logic::ClientLogicModule,
+    misbehaviour::MisbehaviourModule,
+    tendermint_types::{
+        block_header, block_id, client_state, commit,
+        commit_sig::{self, BlockIdFlag},
+        consensus_state, fraction, header, misbehaviour, validator,
+    },
+    upgrade::UpgradeModule,
+    Tendermint,
+};
+use vectors::HeaderVector;
+
+pub mod vectors;
+
+pub static CLIENT_ID: &[u8] = b"07-tendermint-0";
+pub static SUBSTITUTE_CLIENT_ID: &[u8] = b"07-tendermint-1";
+
+pub const TRUSTED_HEIGHT: height::Data = height::Data {
+    revision_number: vectors::REVISION_NUMBER,
+    revision_height: 10,
+};
+/// in seconds
+pub const CURRENT_TIMESTAMP: u64 = vectors::TRUSTED_TIME / 1_000_000_000 + 20;
+pub const TRUSTING_PERIOD: u64 = 1_000;
+pub const UNBONDING_PERIOD: u64 = 2_000;
+pub const MAX_CLOCK_DRIFT: u64 = 10;
+pub const TRUST_LEVEL: fraction::Data = fraction::Data {
+    numerator: 1,
+    denominator: 3,
+};
+/// validators trusted at TRUSTED_HEIGHT
+pub static TRUSTED_VALIDATORS: &[usize] = &[0, 1, 2, 3];
+
+pub struct TendermintSetup<HostObjBuilder, TendermintObjBuilder>
+where
+    HostObjBuilder: 'static + Copy + Fn() -> host::ContractObj<DebugApi>,
+    TendermintObjBuilder: 'static + Copy + Fn() -> tendermint::ContractObj<DebugApi>,
+{
+    pub b_mock: Rc<RefCell<BlockchainStateWrapper>>,
+    pub owner: Address,
+    pub relayer: Address,
+    pub host_wrapper: ContractObjWrapper<host::ContractObj<DebugApi>, HostObjBuilder>,
+    pub tendermint_wrapper:
+        ContractObjWrapper<tendermint::ContractObj<DebugApi>, TendermintObjBuilder>,
+}
+
+impl<HostObjBuilder, TendermintObjBuilder> TendermintSetup<HostObjBuilder, TendermintObjBuilder>
+where
+    HostObjBuilder: 'static + Copy + Fn() -> host::ContractObj<DebugApi>,
+    TendermintObjBuilder: 'static + Copy + Fn() -> tendermint::ContractObj<DebugApi>,
+{
+    pub fn new(
+        b_mock: Rc<RefCell<BlockchainStateWrapper>>,
+        owner: &Address,
+        host_builder: HostObjBuilder,
+        tendermint_builder: TendermintObjBuilder,
+    ) -> Self {
+        let rust_zero = rust_biguint!(0u64);
+        let relayer = b_mock.borrow_mut().create_user_account(&rust_zero);
+        let host_wrapper =
+            b_mock
+                .borrow_mut()
+                .create_sc_account(&rust_zero, Some(owner), host_builder, "host");
+        let tendermint_wrapper = b_mock.borrow_mut().create_sc_account(
+            &rust_zero,
+            Some(owner),
+            tendermint_builder,
+            "tendermint",
+        );
+
+        let host_address = host_wrapper.address_ref().clone();
+        b_mock
+            .borrow_mut()
+            .execute_tx(owner, &host_wrapper, &rust_zero, |sc| {
+                sc.init();
+            })
+            .assert_ok();
+        b_mock
+            .borrow_mut()
+            .execute_tx(owner, &tendermint_wrapper, &rust_zero, |sc| {
+                sc.init(managed_address!(&host_address));
+            })
+            .assert_ok();
+        b_mock.borrow_mut().set_block_timestamp(CURRENT_TIMESTAMP);
+
+        Self {
+            b_mock,
+            owner: owner.clone(),
+            relayer,
+            host_wrapper,
+            tendermint_wrapper,
+        }
+    }
+
+    /// Initializes `CLIENT_ID` through the IBC handler, trusting TRUSTED_VALIDATORS at TRUSTED_HEIGHT
+    pub fn initialize_client(&self) -> TxResult {
+        self.initialize_client_with_state(new_client_state)
+    }
+
+    pub fn initialize_client_with_state<F>(&self, client_state_builder: F) -> TxResult
+    where
+        F: FnOnce() -> client_state::Data<DebugApi>,
+    {
+        let host_address = self.host_wrapper.address_ref().clone();
+        self.b_mock.borrow_mut().execute_tx(
+            &host_address,
+            &self.tendermint_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.initialize_client(
+                    managed_buffer!(CLIENT_ID),
+                    client_state_builder(),
+                    new_trusted_consensus_state(),
+                );
+            },
+        )
+    }
+
+    /// Initializes `SUBSTITUTE_CLIENT_ID` through the IBC handler, trusting TRUSTED_VALIDATORS at the latest height of
+    /// the given state
+    pub fn initialize_substitute_client<F>(&self, client_state_builder: F) -> TxResult
+    where
+        F: FnOnce() -> client_state::Data<DebugApi>,
+    {
+        let host_address = self.host_wrapper.address_ref().clone();
+        self.b_mock.borrow_mut().execute_tx(
+            &host_address,
+            &self.tendermint_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.initialize_client(
+                    managed_buffer!(SUBSTITUTE_CLIENT_ID),
+                    client_state_builder(),
+                    new_trusted_consensus_state(),
+                );
+            },
+        )
+    }
+
+    /// Recovers `CLIENT_ID` with `SUBSTITUTE_CLIENT_ID` through the IBC handler
+    pub fn recover_client(&self) -> TxResult {
+        let host_address = self.host_wrapper.address_ref().clone();
+        self.b_mock.borrow_mut().execute_tx(
+            &host_address,
+            &self.tendermint_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.recover_client(
+                    managed_buffer!(CLIENT_ID),
+                    managed_buffer!(SUBSTITUTE_CLIENT_ID),
+                );
+            },
+        )
+    }
+
+    /// Initializes `CLIENT_ID` at TRUSTED_HEIGHT, with the app hash committing the upgraded states in the vectors
+    pub fn initialize_upgradable_client(&self) -> TxResult {
+        let host_address = self.host_wrapper.address_ref().clone();
+        self.b_mock.borrow_mut().execute_tx(
+            &host_address,
+            &self.tendermint_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.initialize_client(
+                    managed_buffer!(CLIENT_ID),
+                    new_client_state(),
+                    consensus_state::Data {
+                        root: byte_array_hex(vectors::UPGRADE_APP_HASH),
+                        ..new_trusted_consensus_state()
+                    },
+                );
+            },
+        )
+    }
+
+    /// Upgrades `CLIENT_ID` through the IBC handler, with the upgraded consensus state of the vectors
+    pub fn upgrade_client<F>(&self, encoded_client_state_builder: F) -> TxResult
+    where
+        F: FnOnce() -> ManagedBuffer<DebugApi>,
+    {
+        let host_address = self.host_wrapper.address_ref().clone();
+        self.b_mock.borrow_mut().execute_tx(
+            &host_address,
+            &self.tendermint_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.upgrade_client(
+                    managed_buffer!(CLIENT_ID),
+                    encoded_client_state_builder(),
+                    managed_hex(vectors::UPGRADED_CONSENSUS_STATE),
+                    managed_hex(vectors::UPGRADED_CLIENT_PROOF),
+                    managed_hex(vectors::UPGRADED_CONSENSUS_STATE_PROOF),
+                );
+            },
+        )
+    }
+
+    /// Updates `CLIENT_ID` through the IBC handler with the given header, trusting TRUSTED_VALIDATORS at TRUSTED_HEIGHT
+    pub fn update_client(&self, header_vector: &'static HeaderVector) -> TxResult {
+        self.update_client_with_header(move || new_header(header_vector))
+    }
+
+    pub fn update_client_with_header<F>(&self, header_builder: F) -> TxResult
+    where
+        F: FnOnce() -> header::Data<DebugApi>,
+    {
+        let host_address = self.host_wrapper.address_ref().clone();
+        self.b_mock.borrow_mut().execute_tx(
+            &host_address,
+            &self.tendermint_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.update_client(managed_buffer!(CLIENT_ID), header_builder());
+            },
+        )
+    }
+
+    /// Submits the misbehaviour of `CLIENT_ID` through the IBC handler
+    pub fn submit_misbehaviour(
+        &self,
+        header_1: &'static HeaderVector,
+        header_2: &'static HeaderVector,
+    ) -> TxResult {
+        let host_address = self.host_wrapper.address_ref().clone();
+        self.b_mock.borrow_mut().execute_tx(
+            &host_address,
+            &self.tendermint_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.submit_misbehaviour(
+                    managed_buffer!(CLIENT_ID),
+                    misbehaviour::Data {
+                        header_1: new_header(header_1),
+                        header_2: new_header(header_2),
+                    },
+                );
+            },
+        )
+    }
+}
+
+pub fn managed_hex(hex_str: &str) -> ManagedBuffer<DebugApi> {
+    ManagedBuffer::new_from_bytes(&hex::decode(hex_str).unwrap())
+}
+
+pub fn byte_array_hex<const N: usize>(hex_str: &str) -> ManagedByteArray<DebugApi, N> {
+    ManagedByteArray::try_from(managed_hex(hex_str)).unwrap()
+}
+
+pub fn new_client_state() -> client_state::Data<DebugApi> {
+    client_state::Data {
+        chain_id: managed_buffer!(vectors::CHAIN_ID),
+        trust_level: TRUST_LEVEL,
+        trusting_period: TRUSTING_PERIOD,
+        unbonding_period: UNBONDING_PERIOD,
+        max_clock_drift: MAX_CLOCK_DRIFT,
+        frozen_height: height::Data::default(),
+        latest_height: TRUSTED_HEIGHT,
+    }
+}
+
+/// proto `Any` of a zeroed client state with the given upgraded parameters, as committed by ibc-go
+pub fn encode_upgraded_client_state(
+    type_url: &[u8],
+    unbonding_period: u64,
+    latest_height: height::Data,
+) -> ManagedBuffer<DebugApi> {
+    let mut encoded = ManagedBuffer::new();
+    proto::append_bytes_field(
+        &mut encoded,
+        1,
+        &managed_buffer!(vectors::UPGRADED_CHAIN_ID),
+    );
+    proto::append_message_field(&mut encoded, 2, &ManagedBuffer::new());
+    proto::append_message_field(&mut encoded, 3, &proto::encode_duration(0));
+    proto::append_message_field(&mut encoded, 4, &proto::encode_duration(unbonding_period));
+    proto::append_message_field(&mut encoded, 5, &proto::encode_duration(0));
+    proto::append_message_field(&mut encoded, 6, &height::Data::default().to_proto());
+    proto::append_message_field(&mut encoded, 7, &latest_height.to_proto());
+
+    any::Data {
+        type_url: ManagedBuffer::new_from_bytes(type_url),
+        value: encoded,
+    }
+    .to_proto()
+}
+
+pub fn new_trusted_consensus_state() -> consensus_state::Data<DebugApi> {
+    consensus_state::Data {
+        timestamp: vectors::TRUSTED_TIME,
+        root: byte_array_hex(vectors::TRUSTED_APP_HASH),
+        next_validators_hash: byte_array_hex(vectors::VALIDATOR_SET_A_HASH),
+    }
+}
+
+pub fn new_validator_set(
+    validator_indexes: &[usize],
+) -> ManagedVec<DebugApi, validator::Data<DebugApi>> {
+    let mut validators = ManagedVec::new();
+    for &i in validator_indexes {
+        validators.push(validator::Data {
+            address: byte_array_hex(vectors::VALIDATOR_ADDRESSES[i]),
+            pub_key: byte_array_hex(vectors::VALIDATOR_PUBLIC_KEYS[i]),
+            voting_power: vectors::VOTING_POWER,
+        });
+    }
+
+    validators
+}
+
+pub fn new_block_id(hash: &str) -> block_id::Data<DebugApi> {
+    block_id::Data {
+        hash: managed_hex(hash),
+        part_set_header_total: vectors::PART_SET_TOTAL,
+        part_set_header_hash: managed_hex(vectors::PART_SET_HASH),
+    }
+}
+
+pub fn new_block_header(header_vector: &HeaderVector) -> block_header::Data<DebugApi> {
+    let other_hash = managed_hex(vectors::OTHER_HASH);
+    block_header::Data {
+        version_block: vectors::VERSION_BLOCK,
+        version_app: 0,
+        chain_id: managed_buffer!(vectors::CHAIN_ID),
+        height: header_vector.height,
+        time: header_vector.time,
+        last_block_id: new_block_id(vectors::LAST_BLOCK_HASH),
+        last_commit_hash: other_hash.clone(),
+        data_hash: other_hash.clone(),
+        validators_hash: byte_array_hex(header_vector.validators_hash),
+        next_validators_hash: byte_array_hex(header_vector.next_validators_hash),
+        consensus_hash: other_hash.clone(),
+        app_hash: byte_array_hex(header_vector.app_hash),
+        last_results_hash: other_hash.clone(),
+        evidence_hash: other_hash,
+        proposer_address: managed_hex(vectors::VALIDATOR_ADDRESSES[header_vector.validators[0]]),
+    }
+}
+
+pub fn new_commit(header_vector: &HeaderVector) -> commit::Data<DebugApi> {
+    let mut signatures = ManagedVec::new();
+    for (&i, signature) in header_vector
+        .validators
+        .iter()
+        .zip(header_vector.signatures.iter())
+    {
+        let commit_sig = if signature.is_empty() {
+            commit_sig::Data {
+                block_id_flag: BlockIdFlag::Absent,
+                validator_address: ManagedBuffer::new(),
+                timestamp: 0,
+                signature: ManagedBuffer::new(),
+            }
+        } else {
+            commit_sig::Data {
+                block_id_flag: BlockIdFlag::Commit,
+                validator_address: managed_hex(vectors::VALIDATOR_ADDRESSES[i]),
+                timestamp: header_vector.time + vectors::COMMIT_TIME_OFFSET,
+                signature: managed_hex(signature),
+            }
+        };
+        signatures.push(commit_sig);
+    }
+
+    commit::Data {
+        height: header_vector.height,
+        round: 0,
+        block_id: new_block_id(header_vector.block_hash),
+        signatures,
+    }
+}
+
+pub fn new_header(header_vector: &HeaderVector) -> header::Data<DebugApi> {
+    header::Data {
+        block_header: new_block_header(header_vector),
+        commit: new_commit(header_vector),
+        validator_set: new_validator_set(header_vector.validators),
+        trusted_height: TRUSTED_HEIGHT,
+        trusted_validators: new_validator_set(TRUSTED_VALIDATORS),
+    }
+}
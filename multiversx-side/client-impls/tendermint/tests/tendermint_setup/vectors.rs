@@ -0,0 +1,202 @@
+//! Headers of a test chain signed with ed25519 keys derived from the seeds [i + 1; 32],
+//! and the header of the Tendermint `TestHeaderHash` test
+
+pub struct HeaderVector {
+    pub height: u64,
+    pub time: u64,
+    pub app_hash: &'static str,
+    /// indexes in VALIDATOR_PUBLIC_KEYS
+    pub validators: &'static [usize],
+    pub validators_hash: &'static str,
+    pub next_validators_hash: &'static str,
+    pub block_hash: &'static str,
+    /// in the same order as the validators, empty for absent validators
+    pub signatures: &'static [&'static str],
+}
+
+pub static CHAIN_ID: &[u8] = b"testchain-1";
+pub const REVISION_NUMBER: u64 = 1;
+pub const VERSION_BLOCK: u64 = 11;
+pub const VOTING_POWER: u64 = 10;
+pub const TRUSTED_TIME: u64 = 1_700_000_000_123_456_789;
+pub const COMMIT_TIME_OFFSET: u64 = 1_000_000_000;
+pub const PART_SET_TOTAL: u32 = 1;
+pub static PART_SET_HASH: &str = "32b00340824cb3df21b2c63a1fa12ed0b1619a14b56c3eb5526821d906e0ae94";
+pub static LAST_BLOCK_HASH: &str =
+    "c64f25462c5c0ddd77dc6e39e9be13b27674c8c35c91a46b8a2aa09e020a6777";
+pub static OTHER_HASH: &str = "d9298a10d1b0735837dc4bd85dac641b0f3cef27a47e5d53a54f2f3f5b2fcffa";
+
+/// root of a multistore with an "ibc" IAVL store, which the proofs below are against
+pub static TRUSTED_APP_HASH: &str =
+    "729cc9000f2161506e88150b8da279a5fc5ecc7b115500003a6052ce537d74b7";
+pub static COMMITMENT_PATH: &[u8] = b"commitments/ports/transfer/channels/channel-0/sequences/1";
+pub static COMMITMENT: &str = "7c20d86675c5303deb7b35a4c19eefe3e7e792f995f0ebc313bc759fc22f999c";
+pub static RECEIPT_PATH: &[u8] = b"receipts/ports/transfer/channels/channel-0/sequences/2";
+/// IBC MerkleProof of COMMITMENT_PATH in the "ibc" store, against TRUSTED_APP_HASH
+pub static COMMITMENT_PROOF: &str = "0af2010aef010a39636f6d6d69746d656e74732f706f7274732f7472616e736665722f6368616e6e656c732f6368616e6e656c2d302f73657175656e6365732f3112207c20d86675c5303deb7b35a4c19eefe3e7e792f995f0ebc313bc759fc22f999c1a0b0801180120012a03000206222b08011204020408201a212011fc0711f36026e790f25024aeab3f90ece2ec36259a8fd5a47c3b3d11d527b42229080112250408082072d5a45a0195e594969a8b6386e605f5295954e89895f56ed03febaea13020b020222b08011204060e0e201a2120b31d05cf997c183c12ba4a73e5c1fadefac0173cd00a39915eaf8dbb657e371a0aae010aab010a0369626312200834751b0a3ee3a1f83d5375274a3337d0d864b1367f81c353e16515ee6c5ca01a090801180120012a0100222708011201011a207cdd50331bf332f61f3d9cc963d2d119693028da3dbe2d058358d969a1a61dd82225080112210123b1eef3a165314bc88c3b59de99a9e3aaaaf3ece3ed42e737789220a0bfa7b8222708011201011a20c79c51f67d450a8b08ce5dd411b713a88a556c7204e94d2aa866e157dfda0ea5";
+/// IBC MerkleProof of the absence of RECEIPT_PATH in the "ibc" store, against TRUSTED_APP_HASH
+pub static RECEIPT_ABSENCE_PROOF: &str = "0adc0112d9010a3672656365697074732f706f7274732f7472616e736665722f6368616e6e656c732f6368616e6e656c2d302f73657175656e6365732f32129e010a3672656365697074732f706f7274732f7472616e736665722f6368616e6e656c732f6368616e6e656c2d302f73657175656e6365732f311201011a0b0801180120012a0300020e22290801122504060e2005a3c3e92bab66b7085ca86634ee6a94885dbaf4f0dfde35811e62ec1923e18820222908011225060e0e209903e3fe0e4ffe728565bc6709d803178a627bd1b1b1f6b8df68956aef2dadd6200aae010aab010a0369626312200834751b0a3ee3a1f83d5375274a3337d0d864b1367f81c353e16515ee6c5ca01a090801180120012a0100222708011201011a207cdd50331bf332f61f3d9cc963d2d119693028da3dbe2d058358d969a1a61dd82225080112210123b1eef3a165314bc88c3b59de99a9e3aaaaf3ece3ed42e737789220a0bfa7b8222708011201011a20c79c51f67d450a8b08ce5dd411b713a88a556c7204e94d2aa866e157dfda0ea5";
+/// root of a multistore whose "upgrade" IAVL store holds the states committed by ibc-go for an upgrade at height 10,
+/// encoded as ibc-go does: proto `Any` of the `ZeroCustomFields` client state, and of a consensus state without root
+pub static UPGRADE_APP_HASH: &str =
+    "937fb7a20497db90e63bb83ed057d9df41c5ed0b1a14639947711c3f2f3119a1";
+pub static UPGRADED_CHAIN_ID: &[u8] = b"testchain-2";
+pub const UPGRADED_UNBONDING_PERIOD: u64 = 3_000;
+pub const UPGRADED_TIME: u64 = 1_700_000_015_000_000_000;
+pub static UPGRADED_CLIENT_STATE: &str = "0a2b2f6962632e6c69676874636c69656e74732e74656e6465726d696e742e76312e436c69656e74537461746512710a0b74657374636861696e2d3212001a00220308b8172a0032003a040802100142190a090801180120012a0100120c0a02000110211804200c300142190a090801180120012a0100120c0a02000110201801200130014a07757067726164654a1075706772616465644942435374617465";
+pub static UPGRADED_CONSENSUS_STATE: &str = "0a2e2f6962632e6c69676874636c69656e74732e74656e6465726d696e742e76312e436f6e73656e7375735374617465122c0a06088fe2cfaa0612001a20988cdea842ab3b9f582749dc389c3297c61d8a00fc3c6ee77075de178c83225e";
+/// IBC MerkleProof of UPGRADED_CLIENT_STATE at "upgradedIBCState/10/upgradedClient" in the "upgrade" store
+pub static UPGRADED_CLIENT_PROOF: &str = "0aaf020aac020a22757067726164656449424353746174652f31302f7570677261646564436c69656e7412a0010a2b2f6962632e6c69676874636c69656e74732e74656e6465726d696e742e76312e436c69656e74537461746512710a0b74657374636861696e2d3212001a00220308b8172a0032003a040802100142190a090801180120012a0100120c0a02000110211804200c300142190a090801180120012a0100120c0a02000110201801200130014a07757067726164654a10757067726164656449424353746174651a0b0801180120012a030002042229080112250204042003cc7f9c2db1c39985058c9464bf6a0fe54d7667a4c30ffb21d1927518e3f50220222b08011204040606201a2120647950f24768982a69ab41bd3e37c83f832c96f785b730468cc7693cbe4940390a5f0a5d0a0775706772616465122022844228a1aeb297adf09efa64ef1acdbe2e1a063fac14c4dfd80e2795dd85471a090801180120012a0100222508011221013a8113f4de4f51e4ab45b3b04d43f469496805557a7c43fa736abf43531fc6b8";
+/// IBC MerkleProof of UPGRADED_CONSENSUS_STATE at "upgradedIBCState/10/upgradedConsState" in the "upgrade" store
+pub static UPGRADED_CONSENSUS_STATE_PROOF: &str = "0ac2010abf010a25757067726164656449424353746174652f31302f7570677261646564436f6e735374617465125e0a2e2f6962632e6c69676874636c69656e74732e74656e6465726d696e742e76312e436f6e73656e7375735374617465122c0a06088fe2cfaa0612001a20988cdea842ab3b9f582749dc389c3297c61d8a00fc3c6ee77075de178c83225e1a0b0801180120012a0300020622290801122504060620effcca500fc4428b643294b77f3aeab7d637e50ea3cb75565a1421294a1ee34e200a5f0a5d0a0775706772616465122022844228a1aeb297adf09efa64ef1acdbe2e1a063fac14c4dfd80e2795dd85471a090801180120012a0100222508011221013a8113f4de4f51e4ab45b3b04d43f469496805557a7c43fa736abf43531fc6b8";
+pub static VALIDATOR_SET_A_HASH: &str =
+    "988cdea842ab3b9f582749dc389c3297c61d8a00fc3c6ee77075de178c83225e";
+
+pub static VALIDATOR_PUBLIC_KEYS: [&str; 8] = [
+    "8a88e3dd7409f195fd52db2d3cba5d72ca6709bf1d94121bf3748801b40f6f5c",
+    "8139770ea87d175f56a35466c34c7ecccb8d8a91b4ee37a25df60f5b8fc9b394",
+    "ed4928c628d1c2c6eae90338905995612959273a5c63f93636c14614ac8737d1",
+    "ca93ac1705187071d67b83c7ff0efe8108e8ec4530575d7726879333dbdabe7c",
+    "6e7a1cdd29b0b78fd13af4c5598feff4ef2a97166e3ca6f2e4fbfccd80505bf1",
+    "8a875fff1eb38451577acd5afee405456568dd7c89e090863a0557bc7af49f17",
+    "ea4a6c63e29c520abef5507b132ec5f9954776aebebe7b92421eea691446d22c",
+    "1398f62c6d1a457c51ba6a4b5f3dbd2f69fca93216218dc8997e416bd17d93ca",
+];
+pub static VALIDATOR_ADDRESSES: [&str; 8] = [
+    "34750f98bd59fcfc946da45aaabe933be154a4b5",
+    "6a3803d5f059902a1c6dafbc9ba4729212f7caac",
+    "b62e867fa2f33afe62d5d6b1642e1621d5433078",
+    "c5b940ed3f65c391965de8295fc5d25f474fa57b",
+    "7599776c3085e3f9da0d13071eb0b4ab50fd2bf6",
+    "72456720412037a6b339f884ce6d91bb4cc163a7",
+    "fe812c12f3ab4ce6ac5db69ac352f906cb1b11ef",
+    "5c29b78f10a35a49a6231d08ee840a04bcc3a37a",
+];
+
+/// adjacent to the trusted height, signed by 3 of the 4 validators
+pub static HEADER_11: HeaderVector = HeaderVector {
+    height: 11,
+    time: 1_700_000_005_123_456_789,
+    app_hash: "e990d68e2257a09fb18f712f7e87ee5f2f7938d070de014b454199ee66326236",
+    validators: &[0, 1, 2, 3],
+    validators_hash: "988cdea842ab3b9f582749dc389c3297c61d8a00fc3c6ee77075de178c83225e",
+    next_validators_hash: "988cdea842ab3b9f582749dc389c3297c61d8a00fc3c6ee77075de178c83225e",
+    block_hash: "081bd48569081a11fa79a2eab40f140a11f8ca7b91364dc25547bdc93ead45e7",
+    signatures: &[
+        "c1b43a9afc00f82277a2899b96930fa60c1d4057a74c12c1817a641f1b5deb0054cdf627e38e5f01bdc3a685d739471cb2f68acecad4e583d05dec5d2b079e03",
+        "d3e3d638ffd0823241e4ba858156bcdd99a4b30eadd7b35a68fa399df7ebaa51e7aca497a03e408e7be72c4508a938e65345da2194c18c11848da93f5c67b108",
+        "458c7e6c629ca87f4377456970b05082d45cee5f2ca53a97ded97e7868e09be68de96bbdd3582ec4f9883150b1e621c98f35a45a72483096daf20f8cedc27d06",
+        "",
+    ],
+};
+
+/// same height as HEADER_11, with a different app hash
+pub static CONFLICTING_HEADER_11: HeaderVector = HeaderVector {
+    height: 11,
+    time: 1_700_000_005_123_456_789,
+    app_hash: "7f13d61421cd6363be3611c5545a4107820d3fb51a8189018e482a58d49ffe16",
+    validators: &[0, 1, 2, 3],
+    validators_hash: "988cdea842ab3b9f582749dc389c3297c61d8a00fc3c6ee77075de178c83225e",
+    next_validators_hash: "988cdea842ab3b9f582749dc389c3297c61d8a00fc3c6ee77075de178c83225e",
+    block_hash: "51b5e65c5c1f7ae84dc1c50c4c496cee394e2a45269438f67418ed387f9b227a",
+    signatures: &[
+        "f0cc38552b72a15b8559e28b5deb3944ea768759c7d8cb9674a83a37a67b5e8541f2fac4ab173c77a0b7642ff98bcd5826feccbc2aff40a3b6f22f406575a808",
+        "565f8362b2c8d8435679f6ceb675454cab67e8ec73c8f75013821f7e5ec1c8b1227060f1f7892ab8e42cc3ef9a7a06c9343211aa1491ed8d237a2295efeeaf03",
+        "217f54c36a933cdbb79e325ce66f5c0dda5adcd060b7ffa663d439377618f61a27a58aede2cd63de9acf5f107a3c1498c2fdc111d2a5bc9c138348bb74a6b00d",
+        "",
+    ],
+};
+
+/// adjacent to the trusted height, signed by 2 of the 4 validators only
+pub static HEADER_11_NOT_ENOUGH_SIGNATURES: HeaderVector = HeaderVector {
+    height: 11,
+    time: 1_700_000_005_123_456_789,
+    app_hash: "e990d68e2257a09fb18f712f7e87ee5f2f7938d070de014b454199ee66326236",
+    validators: &[0, 1, 2, 3],
+    validators_hash: "988cdea842ab3b9f582749dc389c3297c61d8a00fc3c6ee77075de178c83225e",
+    next_validators_hash: "988cdea842ab3b9f582749dc389c3297c61d8a00fc3c6ee77075de178c83225e",
+    block_hash: "081bd48569081a11fa79a2eab40f140a11f8ca7b91364dc25547bdc93ead45e7",
+    signatures: &[
+        "c1b43a9afc00f82277a2899b96930fa60c1d4057a74c12c1817a641f1b5deb0054cdf627e38e5f01bdc3a685d739471cb2f68acecad4e583d05dec5d2b079e03",
+        "d3e3d638ffd0823241e4ba858156bcdd99a4b30eadd7b35a68fa399df7ebaa51e7aca497a03e408e7be72c4508a938e65345da2194c18c11848da93f5c67b108",
+        "",
+        "",
+    ],
+};
+
+/// skips from the trusted height, to a validator set sharing 3 validators with the trusted one
+pub static HEADER_15: HeaderVector = HeaderVector {
+    height: 15,
+    time: 1_700_000_025_123_456_789,
+    app_hash: "508b0fda0601233db0b4ffbb033085b615050ffc9c4bca27409c55da4287c83b",
+    validators: &[1, 2, 3, 4],
+    validators_hash: "c895f5e5d28e6b084e89c51bcc344540a814679ab7708a540d0e8d553cb820ae",
+    next_validators_hash: "c895f5e5d28e6b084e89c51bcc344540a814679ab7708a540d0e8d553cb820ae",
+    block_hash: "7a4cee0d72aa00bac94c53631d08fb37b7fa6dfb3c6d9b368362f8dbb1f0c9ca",
+    signatures: &[
+        "44458d92f16a0a66d0d12599181359cdf2407a46a5e92ee0ddfb8cd1744388aee23bc20ac8ce74b04c20ff5de0a108507fbd2c5d9ad66601eb41913fc41f7109",
+        "0d480e1b9e76e10999453d8ff8184a2cf7fa95fa39ead1e90ed27b05574b85463752cf31fb10007e8a13c156d308671d1ea3e43c1168a1d676b8be19dd091705",
+        "b02f99e9a5809f545129f9256591949327f540725dbe6d59cb84c49713cee3225ea805a0f1a3b35acc171a86c38c458cc690bec669cb635829433be05afa3308",
+        "df746c8b6cac9542c047efcd3aee0615dd259b41823f3d2dc2b860764316ede2db3b7195e72cf28560c1f964eb5103d6df0f7b19748a1c67b2aacaa0d834f107",
+    ],
+};
+
+/// skips from the trusted height, to a validator set sharing no validators with the trusted one
+pub static UNTRUSTED_HEADER_15: HeaderVector = HeaderVector {
+    height: 15,
+    time: 1_700_000_025_123_456_789,
+    app_hash: "508b0fda0601233db0b4ffbb033085b615050ffc9c4bca27409c55da4287c83b",
+    validators: &[4, 5, 6, 7],
+    validators_hash: "16afc447efacd22da8aa99b383db7ca5533d95a850974a06a74dc6004414e6f4",
+    next_validators_hash: "16afc447efacd22da8aa99b383db7ca5533d95a850974a06a74dc6004414e6f4",
+    block_hash: "12ea5b874ecff520a922e960750103c5a0489d940083c6f38d1299c5e505feb1",
+    signatures: &[
+        "81a176330bfd6a82d05e3a10961ed777e2a13f74eabea6202e2a8dc0ab28a18b306378f66c93db2c8381b43297c2fa94242711ce25ba48f68077f5f91937430b",
+        "a4d66518c63a69fcc42b459f5d403505752e80ad9c4b9631b0173e650b6c0e35c0f69a89e1d0ee295fcf958b39dfe3e9f8174333c5e43e9dac21a78cb1aaf006",
+        "5e2a826a11e3581b7d2f29df5240ec39f37e158f63edae8ef00b13113510e2cba39eaf7d41ed39dcd729854eb5d848eba48caaf31c8407ab077214a6f0472706",
+        "fffa349db214963044757014c5cbefc20e9939ae564750c28a3cd7d41732381a9ab58e32e5365ab27d9fed4a76c5cb204ddc2670deef54a1dc8803cf23cb810f",
+    ],
+};
+
+/// more recent than HEADER_11, but with an earlier time
+pub static HEADER_12_EARLIER_TIME: HeaderVector = HeaderVector {
+    height: 12,
+    time: 1_700_000_002_123_456_789,
+    app_hash: "043271ddbee2deeb120981285c885d29032347d388fab2cfee253a48c078dc31",
+    validators: &[0, 1, 2, 3],
+    validators_hash: "988cdea842ab3b9f582749dc389c3297c61d8a00fc3c6ee77075de178c83225e",
+    next_validators_hash: "988cdea842ab3b9f582749dc389c3297c61d8a00fc3c6ee77075de178c83225e",
+    block_hash: "3cee6fd194e3ad249d1f15a0bc7d172b110deddd7a6a1f703fdee534de018a6d",
+    signatures: &[
+        "007df9ea2047b0306eabf30231bafe0b36ed3a75bc356b8f50fb0f3e02add84aa71c66d958f7dd42fed93133507a3774955ab1468b53582b33e3145524e34206",
+        "e9513bb1b3f55fddeb911d1468615df0e76a2372ecbe9cd5950ba6d749362a84f94381f7e7a0c3e14a283594fb717cc1b3131e566c9ade92527bf9b442a0ba01",
+        "3a32f55d2d451323a7379933a008f8d6cf6ba93001a4c8ea61c9bb2cd0d1cc9bb384b0f66e839a2afe5e60c18cedfafd54ce18f0f2a1ded5cf5b8be123a91e03",
+        "",
+    ],
+};
+
+pub mod tendermint_header_hash_test {
+    pub static CHAIN_ID: &[u8] = b"chainId";
+    pub const VERSION_BLOCK: u64 = 1;
+    pub const VERSION_APP: u64 = 2;
+    pub const HEIGHT: u64 = 3;
+    /// 2019-10-13T16:14:44Z
+    pub const TIME: u64 = 1_570_983_284_000_000_000;
+    pub const LAST_BLOCK_PART_SET_TOTAL: u32 = 6;
+    pub static LAST_COMMIT_HASH: &str =
+        "e7aad01a1af897b05bcf78c7563b5d1adc2939d543dac949a5c8712156d19bf8";
+    pub static DATA_HASH: &str = "6d6e28b8b98b5327042ea50a57dd46e6cc851c72e528bdeaa6efdeeefe66a0b8";
+    pub static VALIDATORS_HASH: &str =
+        "db5d0767f57d844ba68132eaf74f6b8b83df6c03810a6a4378c2a6b2caf93e8d";
+    pub static NEXT_VALIDATORS_HASH: &str =
+        "1eef9748a3c48ff996033757d73200886e7b2b4e9d9df07b19a34a44bae3e2c8";
+    pub static CONSENSUS_HASH: &str =
+        "e5e566c41ed57e3ff8cc10f184178788b8faa602b07cf1f425217bd8179f1f24";
+    pub static APP_HASH: &str = "41cafae31cc70f5801fa1016a2dd54a9bcb8201b5b389919fe9976762532c516";
+    pub static LAST_RESULTS_HASH: &str =
+        "092e058630247ed6009863a12eee117d26cd9d08b5adcaab37f2ab35db475a37";
+    pub static EVIDENCE_HASH: &str =
+        "73865db08f49d58428905d389ab4ca4b96e45a3206c7a69d43a5dc7372e60714";
+    pub static PROPOSER_ADDRESS: &str = "27834082c131975497cdebfbdce6c8e5196a1354";
+    pub static HASH: &str = "f740121f553b5418c3efbd343c2dbfe9e007bb67b0d020a0741374bab65242a4";
+}
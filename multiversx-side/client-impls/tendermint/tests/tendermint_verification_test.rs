@@ -0,0 +1,140 @@
+use common_types::{client_types::any, proto};
+use multiversx_sc::types::{ManagedBuffer, ManagedVec};
+use multiversx_sc_scenario::{managed_buffer, DebugApi};
+use tendermint::{
+    tendermint_types::{block_header, block_id, client_state},
+    verification::VerificationModule,
+};
+use tendermint_setup::{
+    byte_array_hex, managed_hex,
+    vectors::{self, tendermint_header_hash_test, HEADER_11},
+};
+
+pub mod tendermint_setup;
+
+static ZERO_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+#[test]
+fn hash_block_header_tendermint_vector_test() {
+    DebugApi::dummy();
+    let sc = tendermint::contract_obj::<DebugApi>();
+
+    let header = block_header::Data {
+        version_block: tendermint_header_hash_test::VERSION_BLOCK,
+        version_app: tendermint_header_hash_test::VERSION_APP,
+        chain_id: managed_buffer!(tendermint_header_hash_test::CHAIN_ID),
+        height: tendermint_header_hash_test::HEIGHT,
+        time: tendermint_header_hash_test::TIME,
+        last_block_id: block_id::Data {
+            hash: managed_hex(ZERO_HASH),
+            part_set_header_total: tendermint_header_hash_test::LAST_BLOCK_PART_SET_TOTAL,
+            part_set_header_hash: managed_hex(ZERO_HASH),
+        },
+        last_commit_hash: managed_hex(tendermint_header_hash_test::LAST_COMMIT_HASH),
+        data_hash: managed_hex(tendermint_header_hash_test::DATA_HASH),
+        validators_hash: byte_array_hex(tendermint_header_hash_test::VALIDATORS_HASH),
+        next_validators_hash: byte_array_hex(tendermint_header_hash_test::NEXT_VALIDATORS_HASH),
+        consensus_hash: managed_hex(tendermint_header_hash_test::CONSENSUS_HASH),
+        app_hash: byte_array_hex(tendermint_header_hash_test::APP_HASH),
+        last_results_hash: managed_hex(tendermint_header_hash_test::LAST_RESULTS_HASH),
+        evidence_hash: managed_hex(tendermint_header_hash_test::EVIDENCE_HASH),
+        proposer_address: managed_hex(tendermint_header_hash_test::PROPOSER_ADDRESS),
+    };
+
+    assert_eq!(
+        sc.hash_block_header(&header),
+        byte_array_hex(tendermint_header_hash_test::HASH)
+    );
+}
+
+#[test]
+fn hash_block_header_test() {
+    DebugApi::dummy();
+    let sc = tendermint::contract_obj::<DebugApi>();
+
+    let header = tendermint_setup::new_block_header(&HEADER_11);
+    assert_eq!(
+        sc.hash_block_header(&header),
+        byte_array_hex(HEADER_11.block_hash)
+    );
+}
+
+#[test]
+fn hash_validator_set_test() {
+    DebugApi::dummy();
+    let sc = tendermint::contract_obj::<DebugApi>();
+
+    let validators = tendermint_setup::new_validator_set(tendermint_setup::TRUSTED_VALIDATORS);
+    assert_eq!(
+        sc.hash_validator_set(&validators),
+        byte_array_hex(vectors::VALIDATOR_SET_A_HASH)
+    );
+
+    let empty_set_hash = sc.hash_validator_set(&ManagedVec::new());
+    assert_eq!(
+        empty_set_hash,
+        byte_array_hex("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855")
+    );
+}
+
+#[test]
+fn append_varint_test() {
+    DebugApi::dummy();
+
+    for (value, expected) in [
+        (0u64, "00"),
+        (1, "01"),
+        (127, "7f"),
+        (128, "8001"),
+        (300, "ac02"),
+        (u64::MAX, "ffffffffffffffffff01"),
+    ] {
+        let mut encoded = ManagedBuffer::<DebugApi>::new();
+        proto::append_varint(&mut encoded, value);
+        assert_eq!(encoded, managed_hex(expected));
+    }
+}
+
+#[test]
+fn encode_timestamp_test() {
+    DebugApi::dummy();
+
+    // seconds 1_700_000_000 and nanos 123_456_789
+    assert_eq!(
+        proto::encode_timestamp::<DebugApi>(vectors::TRUSTED_TIME),
+        managed_hex("0880e2cfaa0610959aef3a")
+    );
+    assert_eq!(proto::encode_timestamp::<DebugApi>(0), ManagedBuffer::new());
+}
+
+#[test]
+fn decode_timestamp_and_duration_test() {
+    DebugApi::dummy();
+
+    assert_eq!(
+        proto::decode_timestamp(&managed_hex("0880e2cfaa0610959aef3a")),
+        vectors::TRUSTED_TIME
+    );
+    assert_eq!(
+        proto::decode_timestamp(&ManagedBuffer::<DebugApi>::new()),
+        0
+    );
+
+    let encoded_duration = proto::encode_duration::<DebugApi>(1_209_600);
+    assert_eq!(encoded_duration, managed_hex("0880ea49"));
+    assert_eq!(proto::decode_duration(&encoded_duration), 1_209_600);
+}
+
+#[test]
+fn client_state_proto_test() {
+    DebugApi::dummy();
+
+    // the upgraded client state committed by ibc-go has the layout of every Tendermint client state
+    let any_state = any::Data::from_proto(&managed_hex(vectors::UPGRADED_CLIENT_STATE));
+    let client_state = client_state::Data::<DebugApi>::from_proto(&any_state.value);
+    assert_eq!(
+        client_state.chain_id,
+        managed_buffer!(vectors::UPGRADED_CHAIN_ID)
+    );
+    assert_eq!(client_state.to_proto(), any_state.value);
+}
@@ -0,0 +1,49 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           23
+// Async Callback (empty):               1
+// Total number of exported functions:  26
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    tendermint
+    (
+        init => init
+        upgrade => upgrade
+        getIbcHandler => ibc_handler
+        initializeClient => initialize_client
+        updateClient => update_client
+        recoverClient => recover_client
+        submitMisbehaviour => submit_misbehaviour
+        upgradeClient => upgrade_client
+        getTimestampAtHeight => get_timestamp_at_height
+        getLatestHeight => get_latest_height
+        getStatus => get_status
+        getLatestInfo => get_latest_info
+        verifyMembership => verify_membership
+        verifyNonMembership => verify_non_membership
+        getClientState => get_client_state
+        getConsensusState => get_consensus_state
+        getClientStateAny => get_client_state_any
+        getConsensusStateAny => get_consensus_state_any
+        getHostTimestamp => get_host_timestamp
+        getCommitmentPrefix => get_commitment_prefix
+        checkAndGetClient => check_and_get_client
+        getCommitment => get_commitment
+        getConnection => get_connection
+        isChannelOpen => is_channel_open
+        getPortMiddlewares => port_middlewares
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
@@ -1,4 +1,5 @@
-use common_types::{channel_types::height, ClientId, ClientType};
+use client_common::{ClientStatus, GetLatestInfoResultType};
+use common_types::{channel_types::height, ClientId, ClientType, Proof};
 use host::storage::ClientInfo;
 
 multiversx_sc::imports!();
@@ -17,8 +18,24 @@ pub struct MsgUpdateClient<M: ManagedTypeApi> {
     pub encoded_client_message: ManagedBuffer<M>,
 }
 
+#[derive(TypeAbi, TopDecode)]
+pub struct MsgUpgradeClient<M: ManagedTypeApi> {
+    pub client_id: ClientId<M>,
+    pub encoded_client_state: ManagedBuffer<M>,
+    pub encoded_consensus_state: ManagedBuffer<M>,
+    pub proof_upgrade_client: Proof<M>,
+    pub proof_upgrade_consensus_state: Proof<M>,
+}
+
+#[derive(TypeAbi, TopDecode)]
+pub struct MsgSubmitMisbehaviour<M: ManagedTypeApi> {
+    pub client_id: ClientId<M>,
+    pub encoded_misbehaviour: ManagedBuffer<M>,
+}
+
 mod generic_client_proxy {
-    use common_types::{channel_types::height, ClientId};
+    use client_common::GetLatestInfoResultType;
+    use common_types::{channel_types::height, ClientId, Proof};
 
     multiversx_sc::imports!();
 
@@ -27,6 +44,7 @@ mod generic_client_proxy {
         #[endpoint(initializeClient)]
         fn initialize_client(
             &self,
+            client_id: ClientId<Self::Api>,
             encoded_client_state: ManagedBuffer,
             encoded_consensus_state: ManagedBuffer,
         ) -> height::Data;
@@ -38,11 +56,38 @@ mod generic_client_proxy {
             encoded_client_message: ManagedBuffer,
         ) -> ManagedVec<height::Data>;
 
-        #[view(getClientState)]
-        fn get_client_state(&self, client_id: &ClientId<Self::Api>) -> ManagedBuffer;
+        #[endpoint(upgradeClient)]
+        fn upgrade_client(
+            &self,
+            client_id: ClientId<Self::Api>,
+            encoded_client_state: ManagedBuffer,
+            encoded_consensus_state: ManagedBuffer,
+            proof_upgrade_client: Proof<Self::Api>,
+            proof_upgrade_consensus_state: Proof<Self::Api>,
+        ) -> height::Data;
 
-        #[view(getConsensusState)]
-        fn get_consensus_state(
+        #[endpoint(submitMisbehaviour)]
+        fn submit_misbehaviour(
+            &self,
+            client_id: ClientId<Self::Api>,
+            encoded_misbehaviour: ManagedBuffer,
+        );
+
+        #[endpoint(recoverClient)]
+        fn recover_client(
+            &self,
+            subject_client_id: ClientId<Self::Api>,
+            substitute_client_id: ClientId<Self::Api>,
+        ) -> height::Data;
+
+        #[view(getLatestInfo)]
+        fn get_latest_info(&self, client_id: &ClientId<Self::Api>) -> GetLatestInfoResultType;
+
+        #[view(getClientStateAny)]
+        fn get_client_state_any(&self, client_id: &ClientId<Self::Api>) -> ManagedBuffer;
+
+        #[view(getConsensusStateAny)]
+        fn get_consensus_state_any(
             &self,
             client_id: &ClientId<Self::Api>,
             height: &height::Data,
@@ -82,6 +127,9 @@ pub trait CreateAndUpdateClientsModule:
     }
 
     /// updates the consensus state and the state root from a provided header
+    ///
+    /// The client state is committed again even if no height was updated, as a client may freeze itself on a header
+    /// conflicting with a stored consensus state.
     #[endpoint(updateClient)]
     fn update_client(&self, args: MsgUpdateClient<Self::Api>) {
         let client_impl = self.check_and_get_client(&args.client_id);
@@ -90,9 +138,110 @@ pub trait CreateAndUpdateClientsModule:
             .update_client(args.client_id.clone(), args.encoded_client_message)
             .execute_on_dest_context();
 
-        if !heights.is_empty() {
-            self.update_client_commitments(args.client_id, heights);
-        }
+        self.update_client_commitments(args.client_id, heights);
+    }
+
+    /// upgrades the client to the next revision of the counterparty chain
+    ///
+    /// The upgraded client and consensus states must be proven under the upgrade path of the counterparty, against
+    /// the latest consensus state of the client. The client then restarts from the upgraded states.
+    #[endpoint(upgradeClient)]
+    fn upgrade_client(&self, args: MsgUpgradeClient<Self::Api>) {
+        let client_impl = self.check_and_get_client(&args.client_id);
+        let latest_info: GetLatestInfoResultType = self
+            .generic_client_proxy_impl(client_impl.clone())
+            .get_latest_info(&args.client_id)
+            .execute_on_dest_context();
+        require!(
+            latest_info.client_status == ClientStatus::Active,
+            "Client not active"
+        );
+
+        let height: height::Data = self
+            .generic_client_proxy_impl(client_impl.clone())
+            .upgrade_client(
+                args.client_id.clone(),
+                args.encoded_client_state,
+                args.encoded_consensus_state,
+                args.proof_upgrade_client,
+                args.proof_upgrade_consensus_state,
+            )
+            .execute_on_dest_context();
+
+        self.update_client_state_commitment(client_impl.clone(), &args.client_id);
+        self.update_consensus_state_commitment(client_impl, &args.client_id, &height);
+        self.client_upgraded_event(&args.client_id, &height);
+    }
+
+    /// freezes the client, given evidence of its misbehaviour
+    ///
+    /// The evidence is checked by the client implementation, e.g. two conflicting headers at the same height, or
+    /// headers violating time monotonicity. A frozen client can no longer verify proofs or be used to send packets.
+    #[endpoint(submitMisbehaviour)]
+    fn submit_misbehaviour(&self, args: MsgSubmitMisbehaviour<Self::Api>) {
+        let client_impl = self.check_and_get_client(&args.client_id);
+        let _: () = self
+            .generic_client_proxy_impl(client_impl.clone())
+            .submit_misbehaviour(args.client_id.clone(), args.encoded_misbehaviour)
+            .execute_on_dest_context();
+
+        self.update_client_state_commitment(client_impl, &args.client_id);
+        self.client_misbehaviour_event(&args.client_id);
+    }
+
+    /// replaces the state of an expired or frozen client with the latest state of an active substitute client of the
+    /// same type, so that the connections of the subject client can be used again
+    ///
+    /// The client implementation checks that the parameters of the two clients are compatible.
+    #[only_owner]
+    #[endpoint(recoverClient)]
+    fn recover_client(
+        &self,
+        subject_client_id: ClientId<Self::Api>,
+        substitute_client_id: ClientId<Self::Api>,
+    ) {
+        require!(
+            subject_client_id != substitute_client_id,
+            "Subject and substitute clients must differ"
+        );
+
+        let subject_info = self.try_get_client_info(&subject_client_id);
+        let substitute_info = self.try_get_client_info(&substitute_client_id);
+        require!(
+            subject_info.client_type == substitute_info.client_type,
+            "Client types do not match"
+        );
+
+        let client_impl = subject_info.client_impl;
+        let subject_latest_info: GetLatestInfoResultType = self
+            .generic_client_proxy_impl(client_impl.clone())
+            .get_latest_info(&subject_client_id)
+            .execute_on_dest_context();
+        let substitute_latest_info: GetLatestInfoResultType = self
+            .generic_client_proxy_impl(client_impl.clone())
+            .get_latest_info(&substitute_client_id)
+            .execute_on_dest_context();
+        require!(
+            subject_latest_info.client_status != ClientStatus::Active,
+            "Subject client is active"
+        );
+        require!(
+            substitute_latest_info.client_status == ClientStatus::Active,
+            "Substitute client is not active"
+        );
+        require!(
+            substitute_latest_info.latest_height > subject_latest_info.latest_height,
+            "Substitute client height must be greater than the subject client height"
+        );
+
+        let height: height::Data = self
+            .generic_client_proxy_impl(client_impl.clone())
+            .recover_client(subject_client_id.clone(), substitute_client_id.clone())
+            .execute_on_dest_context();
+
+        self.update_client_state_commitment(client_impl.clone(), &subject_client_id);
+        self.update_consensus_state_commitment(client_impl, &subject_client_id, &height);
+        self.client_recovered_event(&subject_client_id, &substitute_client_id);
     }
 
     #[endpoint(updateClientCommitments)]
@@ -102,19 +251,27 @@ pub trait CreateAndUpdateClientsModule:
         heights: ManagedVec<height::Data>,
     ) {
         let client = self.check_and_get_client(&client_id);
+        self.update_client_state_commitment(client.clone(), &client_id);
+
+        for height in &heights {
+            self.update_single_commitment(client.clone(), &client_id, &height);
+        }
+    }
+
+    fn update_client_state_commitment(
+        &self,
+        client: ManagedAddress,
+        client_id: &ClientId<Self::Api>,
+    ) {
         let encoded_client_state: ManagedBuffer = self
-            .generic_client_proxy_impl(client.clone())
-            .get_client_state(client_id.clone())
+            .generic_client_proxy_impl(client)
+            .get_client_state_any(client_id)
             .execute_on_dest_context();
 
-        let client_state_comm_key = self.get_client_state_commitment_key(&client_id);
+        let client_state_comm_key = self.get_client_state_commitment_key(client_id);
         let client_state_hash = self.crypto().keccak256(encoded_client_state);
         self.commitments(&client_state_comm_key)
             .set(client_state_hash);
-
-        for height in &heights {
-            self.update_single_commitment(client.clone(), &client_id, &height);
-        }
     }
 
     fn generate_client_identifier(
@@ -126,31 +283,43 @@ pub trait CreateAndUpdateClientsModule:
         sc_format!("{}-{}", client_type, next_client_seq)
     }
 
+    /// commits the states as stored by the client, encoded as protobuf Any like ibc-go does
     fn update_commitments_after_create(
         &self,
         args: MsgCreateClient<Self::Api>,
         client_impl: ManagedAddress,
         client_id: &ClientId<Self::Api>,
     ) {
-        let client_state_hash = self.crypto().keccak256(&args.encoded_client_state);
-        let consensus_state_hash = self.crypto().keccak256(&args.encoded_consensus_state);
         let height: height::Data = self
-            .generic_client_proxy_impl(client_impl)
-            .initialize_client(args.encoded_client_state, args.encoded_consensus_state)
+            .generic_client_proxy_impl(client_impl.clone())
+            .initialize_client(
+                client_id.clone(),
+                args.encoded_client_state,
+                args.encoded_consensus_state,
+            )
             .execute_on_dest_context();
 
-        let client_comm_key = self.get_client_state_commitment_key(client_id);
-        let consensus_comm_key = self.get_consensus_state_commitment_key(
+        self.update_client_state_commitment(client_impl.clone(), client_id);
+        self.update_consensus_state_commitment(client_impl, client_id, &height);
+    }
+
+    fn update_single_commitment(
+        &self,
+        client: ManagedAddress,
+        client_id: &ClientId<Self::Api>,
+        height: &height::Data,
+    ) {
+        let consensus_state_comm_key = self.get_consensus_state_commitment_key(
             client_id,
             height.revision_number,
             height.revision_height,
         );
-        self.commitments(&client_comm_key).set(&client_state_hash);
-        self.commitments(&consensus_comm_key)
-            .set(&consensus_state_hash);
+        if self.commitments(&consensus_state_comm_key).is_empty() {
+            self.update_consensus_state_commitment(client, client_id, height);
+        }
     }
 
-    fn update_single_commitment(
+    fn update_consensus_state_commitment(
         &self,
         client: ManagedAddress,
         client_id: &ClientId<Self::Api>,
@@ -158,7 +327,7 @@ pub trait CreateAndUpdateClientsModule:
     ) {
         let encoded_consensus_state: ManagedBuffer = self
             .generic_client_proxy_impl(client)
-            .get_consensus_state(client_id, height)
+            .get_consensus_state_any(client_id, height)
             .execute_on_dest_context();
 
         let consensus_state_comm_key = self.get_consensus_state_commitment_key(
@@ -166,11 +335,9 @@ pub trait CreateAndUpdateClientsModule:
             height.revision_number,
             height.revision_height,
         );
-        let comm_mapper = self.commitments(&consensus_state_comm_key);
-        if comm_mapper.is_empty() {
-            let hashed_consensus_state = self.crypto().keccak256(encoded_consensus_state);
-            comm_mapper.set(hashed_consensus_state);
-        }
+        let hashed_consensus_state = self.crypto().keccak256(encoded_consensus_state);
+        self.commitments(&consensus_state_comm_key)
+            .set(hashed_consensus_state);
     }
 
     #[proxy]
@@ -1,4 +1,4 @@
-use common_types::ClientId;
+use common_types::{channel_types::height, ClientId};
 
 multiversx_sc::imports!();
 
@@ -6,4 +6,21 @@ multiversx_sc::imports!();
 pub trait EventsModule {
     #[event("generatedClientIdEvent")]
     fn generated_client_id_event(&self, client_id: &ClientId<Self::Api>);
+
+    #[event("clientUpgradedEvent")]
+    fn client_upgraded_event(
+        &self,
+        #[indexed] client_id: &ClientId<Self::Api>,
+        #[indexed] height: &height::Data,
+    );
+
+    #[event("clientMisbehaviourEvent")]
+    fn client_misbehaviour_event(&self, #[indexed] client_id: &ClientId<Self::Api>);
+
+    #[event("clientRecoveredEvent")]
+    fn client_recovered_event(
+        &self,
+        #[indexed] subject_client_id: &ClientId<Self::Api>,
+        #[indexed] substitute_client_id: &ClientId<Self::Api>,
+    );
 }
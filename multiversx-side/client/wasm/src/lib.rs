@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           10
+// Endpoints:                           21
 // Async Callback (empty):               1
-// Total number of exported functions:  13
+// Total number of exported functions:  24
 
 #![no_std]
 
@@ -22,14 +22,25 @@ multiversx_sc_wasm_adapter::endpoints! {
         upgrade => upgrade
         createClient => create_client
         updateClient => update_client
+        upgradeClient => upgrade_client
+        submitMisbehaviour => submit_misbehaviour
+        recoverClient => recover_client
         updateClientCommitments => update_client_commitments
         setExpectedTimePerBlock => set_expected_time_per_block
+        setSelfClientTypeUrls => set_self_client_type_urls
         registerClient => register_client
         bindPort => bind_port
+        setPortMiddlewares => set_port_middlewares
+        bindPortPrefix => bind_port_prefix
+        bindPrefixedPort => bind_prefixed_port
         getHostTimestamp => get_host_timestamp
         getCommitmentPrefix => get_commitment_prefix
         checkAndGetClient => check_and_get_client
+        getChannelStack => get_channel_stack
         getCommitment => get_commitment
+        getConnection => get_connection
+        isChannelOpen => is_channel_open
+        getPortMiddlewares => port_middlewares
     )
 }
 
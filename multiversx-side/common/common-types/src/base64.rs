@@ -0,0 +1,74 @@
+//! Standard base64 with padding, as Go encodes the byte fields of JSON packet data
+
+use multiversx_sc::{api::ManagedTypeApi, types::ManagedBuffer};
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const PADDING: u8 = b'=';
+
+pub fn encode<M: ManagedTypeApi>(bytes: &ManagedBuffer<M>) -> ManagedBuffer<M> {
+    let mut encoded = ManagedBuffer::new();
+    let len = bytes.len();
+    let mut offset = 0;
+    while offset < len {
+        let chunk_len = core::cmp::min(3, len - offset);
+        let mut chunk = [0u8; 3];
+        let _ = bytes.load_slice(offset, &mut chunk[..chunk_len]);
+
+        let group = (chunk[0] as u32) << 16 | (chunk[1] as u32) << 8 | chunk[2] as u32;
+        let mut chars = [PADDING; 4];
+        for (i, char) in chars.iter_mut().enumerate().take(chunk_len + 1) {
+            *char = ALPHABET[((group >> (18 - 6 * i)) & 0x3f) as usize];
+        }
+        encoded.append_bytes(&chars);
+
+        offset += chunk_len;
+    }
+
+    encoded
+}
+
+/// Returns `None` if `encoded` isn't padded standard base64
+pub fn decode<M: ManagedTypeApi>(encoded: &ManagedBuffer<M>) -> Option<ManagedBuffer<M>> {
+    let len = encoded.len();
+    if !len.is_multiple_of(4) {
+        return None;
+    }
+
+    let mut decoded = ManagedBuffer::new();
+    let mut chars = [0u8; 4];
+    for offset in (0..len).step_by(4) {
+        encoded.load_slice(offset, &mut chars).ok()?;
+        let is_last = offset + 4 == len;
+        let padding_len = match (chars[2], chars[3]) {
+            (PADDING, PADDING) if is_last => 2,
+            (_, PADDING) if is_last => 1,
+            _ => 0,
+        };
+
+        let mut group = 0u32;
+        for char in &chars[..4 - padding_len] {
+            group = group << 6 | char_value(*char)? as u32;
+        }
+        group <<= 6 * padding_len;
+        // the bits dropped by the padding must be zero
+        if group & ((1 << (8 * padding_len)) - 1) != 0 {
+            return None;
+        }
+
+        let bytes = [(group >> 16) as u8, (group >> 8) as u8, group as u8];
+        decoded.append_bytes(&bytes[..3 - padding_len]);
+    }
+
+    Some(decoded)
+}
+
+fn char_value(char: u8) -> Option<u8> {
+    match char {
+        b'A'..=b'Z' => Some(char - b'A'),
+        b'a'..=b'z' => Some(char - b'a' + 26),
+        b'0'..=b'9' => Some(char - b'0' + 52),
+        b'+' => Some(62),
+        b'/' => Some(63),
+        _ => None,
+    }
+}
@@ -0,0 +1,129 @@
+//! Bech32 encoding of addresses, as used for the senders and receivers in the packet data of IBC applications
+
+use multiversx_sc::{
+    api::ManagedTypeApi,
+    types::{ManagedAddress, ManagedBuffer},
+};
+
+/// Human readable part of MultiversX addresses
+pub static ADDRESS_HRP: &[u8] = b"erd";
+
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const CHECKSUM_LEN: usize = 6;
+const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+const ADDRESS_LEN: usize = 32;
+/// 32 bytes take 52 groups of 5 bits
+const ADDRESS_DATA_LEN: usize = 52;
+const MAX_HRP_LEN: usize = 16;
+const MAX_ENCODED_LEN: usize = MAX_HRP_LEN + 1 + ADDRESS_DATA_LEN + CHECKSUM_LEN;
+
+pub fn encode_address<M: ManagedTypeApi>(address: &ManagedAddress<M>) -> ManagedBuffer<M> {
+    let bytes = address.to_byte_array();
+    let mut data = [0u8; ADDRESS_DATA_LEN];
+    let mut accumulator = 0u32;
+    let mut bits = 0;
+    let mut len = 0;
+    for byte in bytes {
+        accumulator = (accumulator << 8) | byte as u32;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            data[len] = ((accumulator >> bits) & 0x1f) as u8;
+            len += 1;
+        }
+    }
+    data[len] = ((accumulator << (5 - bits)) & 0x1f) as u8;
+
+    let checksum = create_checksum(ADDRESS_HRP, &data);
+    let mut encoded = ManagedBuffer::new_from_bytes(ADDRESS_HRP);
+    encoded.append_bytes(b"1");
+    for value in data.iter().chain(checksum.iter()) {
+        encoded.append_bytes(&[CHARSET[*value as usize]]);
+    }
+
+    encoded
+}
+
+/// Returns `None` if `encoded` isn't a valid lowercase bech32 address with the MultiversX prefix
+pub fn decode_address<M: ManagedTypeApi>(encoded: &ManagedBuffer<M>) -> Option<ManagedAddress<M>> {
+    let hrp_len = ADDRESS_HRP.len();
+    let len = encoded.len();
+    if len != hrp_len + 1 + ADDRESS_DATA_LEN + CHECKSUM_LEN {
+        return None;
+    }
+
+    let mut chars = [0u8; MAX_ENCODED_LEN];
+    encoded.load_slice(0, &mut chars[..len]).ok()?;
+    if &chars[..hrp_len] != ADDRESS_HRP || chars[hrp_len] != b'1' {
+        return None;
+    }
+
+    let mut values = [0u8; ADDRESS_DATA_LEN + CHECKSUM_LEN];
+    for (value, char) in values.iter_mut().zip(chars[hrp_len + 1..len].iter()) {
+        *value = CHARSET.iter().position(|c| c == char)? as u8;
+    }
+    if polymod_with_hrp(ADDRESS_HRP, &values) != 1 {
+        return None;
+    }
+
+    let mut bytes = [0u8; ADDRESS_LEN];
+    let mut accumulator = 0u32;
+    let mut bits = 0;
+    let mut len = 0;
+    for value in &values[..ADDRESS_DATA_LEN] {
+        accumulator = (accumulator << 5) | *value as u32;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes[len] = ((accumulator >> bits) & 0xff) as u8;
+            len += 1;
+        }
+    }
+    // the padding must be zero
+    if accumulator & ((1 << bits) - 1) != 0 {
+        return None;
+    }
+
+    Some(ManagedAddress::new_from_bytes(&bytes))
+}
+
+fn create_checksum(hrp: &[u8], data: &[u8]) -> [u8; CHECKSUM_LEN] {
+    let mut values = [0u8; ADDRESS_DATA_LEN + CHECKSUM_LEN];
+    values[..data.len()].copy_from_slice(data);
+    let polymod = polymod_with_hrp(hrp, &values[..data.len() + CHECKSUM_LEN]) ^ 1;
+
+    let mut checksum = [0u8; CHECKSUM_LEN];
+    for (i, value) in checksum.iter_mut().enumerate() {
+        *value = ((polymod >> (5 * (5 - i))) & 0x1f) as u8;
+    }
+
+    checksum
+}
+
+fn polymod_with_hrp(hrp: &[u8], values: &[u8]) -> u32 {
+    let mut checksum = 1;
+    for char in hrp {
+        checksum = polymod_step(checksum, char >> 5);
+    }
+    checksum = polymod_step(checksum, 0);
+    for char in hrp {
+        checksum = polymod_step(checksum, char & 0x1f);
+    }
+    for value in values {
+        checksum = polymod_step(checksum, *value);
+    }
+
+    checksum
+}
+
+fn polymod_step(checksum: u32, value: u8) -> u32 {
+    let top = checksum >> 25;
+    let mut checksum = ((checksum & 0x1ffffff) << 5) ^ value as u32;
+    for (i, generator) in GENERATOR.iter().enumerate() {
+        if (top >> i) & 1 == 1 {
+            checksum ^= generator;
+        }
+    }
+
+    checksum
+}
@@ -1,5 +1,9 @@
 pub mod channel {
-    use crate::{channel_types::channel_counterparty, ConnectionHops, Sequence, Version};
+    use crate::{
+        channel_types::channel_counterparty,
+        proto::{self, Field, FieldReader},
+        ConnectionHops, Sequence, Version,
+    };
 
     multiversx_sc::imports!();
     multiversx_sc::derive_imports!();
@@ -18,8 +22,27 @@ pub mod channel {
         FlushComplete,
     }
 
-    #[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy)]
+    impl State {
+        /// the variants are declared in the same order as the ibc.core.channel.v1.State values
+        pub fn from_proto<M: ManagedTypeApi>(value: u64) -> Self {
+            match value {
+                0 => State::UninitializedUnspecified,
+                1 => State::Init,
+                2 => State::TryOpen,
+                3 => State::Open,
+                4 => State::Closed,
+                5 => State::Flushing,
+                6 => State::FlushComplete,
+                _ => proto::signal_invalid::<M>(),
+            }
+        }
+    }
+
+    #[derive(
+        TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, Default, PartialEq,
+    )]
     pub enum Order {
+        #[default]
         NoneUnspecified,
         Unordered,
         Ordered,
@@ -35,9 +58,19 @@ pub mod channel {
                 Order::Ordered => ORDERED,
             }
         }
+
+        /// the variants are declared in the same order as the ibc.core.channel.v1.Order values
+        pub fn from_proto<M: ManagedTypeApi>(value: u64) -> Self {
+            match value {
+                0 => Order::NoneUnspecified,
+                1 => Order::Unordered,
+                2 => Order::Ordered,
+                _ => proto::signal_invalid::<M>(),
+            }
+        }
     }
 
-    #[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
+    #[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
     pub struct Data<M: ManagedTypeApi> {
         pub state: State,
         pub ordering: Order,
@@ -46,22 +79,98 @@ pub mod channel {
         pub version: Version<M>,
         pub upgrade_sequence: Sequence,
     }
+
+    impl<M: ManagedTypeApi> Data<M> {
+        /// ibc.core.channel.v1.Channel, as stored by ibc-go under the channel path
+        pub fn to_proto(&self) -> ManagedBuffer<M> {
+            let mut encoded = ManagedBuffer::new();
+            proto::append_uint_field(&mut encoded, 1, self.state as u64);
+            proto::append_uint_field(&mut encoded, 2, self.ordering as u64);
+            proto::append_message_field(&mut encoded, 3, &self.counterparty.to_proto());
+            for connection_id in self.connection_hops.iter() {
+                proto::append_message_field(&mut encoded, 4, &connection_id);
+            }
+            proto::append_bytes_field(&mut encoded, 5, &self.version);
+            proto::append_uint_field(&mut encoded, 6, self.upgrade_sequence);
+
+            encoded
+        }
+
+        pub fn from_proto(encoded: &ManagedBuffer<M>) -> Self {
+            let mut data = Data {
+                state: State::UninitializedUnspecified,
+                ordering: Order::NoneUnspecified,
+                counterparty: channel_counterparty::Data::from_proto(&ManagedBuffer::new()),
+                connection_hops: ConnectionHops::new(),
+                version: Version::new(),
+                upgrade_sequence: 0,
+            };
+            for Field { number, value } in FieldReader::new(encoded) {
+                match number {
+                    1 => data.state = State::from_proto::<M>(value.into_varint()),
+                    2 => data.ordering = Order::from_proto::<M>(value.into_varint()),
+                    3 => {
+                        data.counterparty =
+                            channel_counterparty::Data::from_proto(&value.into_bytes())
+                    }
+                    4 => data.connection_hops.push(value.into_bytes()),
+                    5 => data.version = value.into_bytes(),
+                    6 => data.upgrade_sequence = value.into_varint(),
+                    _ => {}
+                }
+            }
+
+            data
+        }
+    }
 }
 
 pub mod channel_counterparty {
     multiversx_sc::imports!();
     multiversx_sc::derive_imports!();
 
-    use crate::{ChannelId, PortId};
+    use crate::{
+        proto::{self, Field, FieldReader},
+        ChannelId, PortId,
+    };
 
-    #[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
+    #[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
     pub struct Data<M: ManagedTypeApi> {
         pub port_id: PortId<M>,
         pub channel_id: ChannelId<M>,
     }
+
+    impl<M: ManagedTypeApi> Data<M> {
+        /// ibc.core.channel.v1.Counterparty
+        pub fn to_proto(&self) -> ManagedBuffer<M> {
+            let mut encoded = ManagedBuffer::new();
+            proto::append_bytes_field(&mut encoded, 1, &self.port_id);
+            proto::append_bytes_field(&mut encoded, 2, &self.channel_id);
+
+            encoded
+        }
+
+        pub fn from_proto(encoded: &ManagedBuffer<M>) -> Self {
+            let mut data = Data {
+                port_id: PortId::new(),
+                channel_id: ChannelId::new(),
+            };
+            for Field { number, value } in FieldReader::new(encoded) {
+                match number {
+                    1 => data.port_id = value.into_bytes(),
+                    2 => data.channel_id = value.into_bytes(),
+                    _ => {}
+                }
+            }
+
+            data
+        }
+    }
 }
 
 pub mod height {
+    use crate::proto::{self, Field, FieldReader};
+
     multiversx_sc::imports!();
     multiversx_sc::derive_imports!();
 
@@ -76,6 +185,7 @@ pub mod height {
         PartialOrd,
         Clone,
         Copy,
+        Default,
     )]
     pub struct Data {
         pub revision_number: u64,
@@ -94,6 +204,28 @@ pub mod height {
 
             BigUint::from_bytes_be_buffer(&buffer)
         }
+
+        /// ibc.core.client.v1.Height
+        pub fn to_proto<M: ManagedTypeApi>(&self) -> ManagedBuffer<M> {
+            let mut encoded = ManagedBuffer::new();
+            proto::append_uint_field(&mut encoded, 1, self.revision_number);
+            proto::append_uint_field(&mut encoded, 2, self.revision_height);
+
+            encoded
+        }
+
+        pub fn from_proto<M: ManagedTypeApi>(encoded: &ManagedBuffer<M>) -> Self {
+            let mut data = Data::default();
+            for Field { number, value } in FieldReader::new(encoded) {
+                match number {
+                    1 => data.revision_number = value.into_varint(),
+                    2 => data.revision_height = value.into_varint(),
+                    _ => {}
+                }
+            }
+
+            data
+        }
     }
 
     #[cfg(test)]
@@ -135,59 +267,169 @@ pub mod height {
 
 pub mod timeout {
     use crate::channel_types::height;
-    use crate::UnixTimestamp;
+    use crate::{proto, UnixTimestamp};
 
     multiversx_sc::imports!();
     multiversx_sc::derive_imports!();
 
-    #[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
+    #[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, Default)]
     pub struct Data {
         pub height: height::Data,
         pub timestamp: UnixTimestamp,
     }
+
+    impl Data {
+        /// ibc.core.channel.v1.Timeout
+        pub fn to_proto<M: ManagedTypeApi>(&self) -> ManagedBuffer<M> {
+            let mut encoded = ManagedBuffer::new();
+            proto::append_message_field(&mut encoded, 1, &self.height.to_proto());
+            proto::append_uint_field(&mut encoded, 2, self.timestamp);
+
+            encoded
+        }
+    }
 }
 
 pub mod upgrade {
-    use crate::Sequence;
+    use crate::{proto, Sequence};
 
     use super::{timeout, upgrade_fields};
 
     multiversx_sc::imports!();
     multiversx_sc::derive_imports!();
 
-    #[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
+    #[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
     pub struct Data<M: ManagedTypeApi> {
         pub fields: upgrade_fields::Data<M>,
         pub timeout: timeout::Data,
         pub next_sequence_send: Sequence,
     }
+
+    impl<M: ManagedTypeApi> Default for Data<M> {
+        fn default() -> Self {
+            Self {
+                fields: upgrade_fields::Data::default(),
+                timeout: timeout::Data::default(),
+                next_sequence_send: 0,
+            }
+        }
+    }
+
+    impl<M: ManagedTypeApi> Data<M> {
+        /// ibc.core.channel.v1.Upgrade, as stored by ibc-go under the channel upgrade path
+        pub fn to_proto(&self) -> ManagedBuffer<M> {
+            let mut encoded = ManagedBuffer::new();
+            proto::append_message_field(&mut encoded, 1, &self.fields.to_proto());
+            proto::append_message_field(&mut encoded, 2, &self.timeout.to_proto());
+            proto::append_uint_field(&mut encoded, 3, self.next_sequence_send);
+
+            encoded
+        }
+    }
 }
 
 pub mod upgrade_fields {
-    use crate::{ConnectionHops, Version};
+    use crate::{proto, ConnectionHops, Version};
 
     use super::channel;
 
     multiversx_sc::imports!();
     multiversx_sc::derive_imports!();
 
-    #[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
+    #[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
     pub struct Data<M: ManagedTypeApi> {
         pub ordering: channel::Order,
         pub connection_hops: ConnectionHops<M>,
         pub version: Version<M>,
     }
+
+    impl<M: ManagedTypeApi> Default for Data<M> {
+        fn default() -> Self {
+            Self {
+                ordering: channel::Order::NoneUnspecified,
+                connection_hops: ConnectionHops::new(),
+                version: Version::new(),
+            }
+        }
+    }
+
+    impl<M: ManagedTypeApi> Data<M> {
+        /// ibc.core.channel.v1.UpgradeFields
+        pub fn to_proto(&self) -> ManagedBuffer<M> {
+            let mut encoded = ManagedBuffer::new();
+            proto::append_uint_field(&mut encoded, 1, self.ordering as u64);
+            for connection_id in self.connection_hops.iter() {
+                proto::append_message_field(&mut encoded, 2, &connection_id);
+            }
+            proto::append_bytes_field(&mut encoded, 3, &self.version);
+
+            encoded
+        }
+    }
 }
 
 pub mod error_receipt {
-    use crate::Sequence;
+    use crate::{proto, Sequence};
 
     multiversx_sc::imports!();
     multiversx_sc::derive_imports!();
 
-    #[derive(TypeAbi, TopEncode, TopDecode)]
+    #[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
     pub struct Data<M: ManagedTypeApi> {
         pub sequence: Sequence,
         pub message: ManagedBuffer<M>,
     }
+
+    impl<M: ManagedTypeApi> Data<M> {
+        /// ibc.core.channel.v1.ErrorReceipt, as stored by ibc-go under the channel upgrade error path
+        pub fn to_proto(&self) -> ManagedBuffer<M> {
+            let mut encoded = ManagedBuffer::new();
+            proto::append_uint_field(&mut encoded, 1, self.sequence);
+            proto::append_bytes_field(&mut encoded, 2, &self.message);
+
+            encoded
+        }
+    }
+}
+
+/// The JSON acknowledgement envelope used by the ibc-go applications
+pub mod acknowledgement {
+    use crate::json::{JsonObject, JsonObjectBuilder};
+
+    multiversx_sc::imports!();
+
+    /// base64 of the single byte 1, the result of successful token transfers
+    pub static SUCCESSFUL_RESULT: &[u8] = b"AQ==";
+
+    /// `{"result":"<result>"}`, with the result base64 encoded
+    pub fn new_result<M: ManagedTypeApi>(result: &ManagedBuffer<M>) -> ManagedBuffer<M> {
+        JsonObjectBuilder::new().string(b"result", result).build()
+    }
+
+    pub fn new_successful<M: ManagedTypeApi>() -> ManagedBuffer<M> {
+        new_result(&ManagedBuffer::new_from_bytes(SUCCESSFUL_RESULT))
+    }
+
+    /// `{"error":"<error>"}`
+    pub fn new_error<M: ManagedTypeApi>(error: &[u8]) -> ManagedBuffer<M> {
+        JsonObjectBuilder::new()
+            .string(b"error", &ManagedBuffer::new_from_bytes(error))
+            .build()
+    }
+
+    /// Returns `None` if `ack` isn't an acknowledgement holding either a result or an error
+    pub fn is_successful<M: ManagedTypeApi>(ack: &ManagedBuffer<M>) -> Option<bool> {
+        let object = JsonObject::parse(ack)?;
+        if object.len() != 1 {
+            return None;
+        }
+
+        if object.get_string(b"result").is_some() {
+            Some(true)
+        } else if object.get_string(b"error").is_some() {
+            Some(false)
+        } else {
+            None
+        }
+    }
 }
@@ -0,0 +1,47 @@
+pub mod any {
+    use crate::proto::{self, Field, FieldReader};
+
+    multiversx_sc::imports!();
+    multiversx_sc::derive_imports!();
+
+    /// google.protobuf.Any, which wraps the client and consensus states stored by ibc-go
+    #[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+    pub struct Data<M: ManagedTypeApi> {
+        pub type_url: ManagedBuffer<M>,
+        pub value: ManagedBuffer<M>,
+    }
+
+    impl<M: ManagedTypeApi> Data<M> {
+        #[inline]
+        pub fn new(type_url: &[u8], value: ManagedBuffer<M>) -> Self {
+            Self {
+                type_url: ManagedBuffer::new_from_bytes(type_url),
+                value,
+            }
+        }
+
+        pub fn to_proto(&self) -> ManagedBuffer<M> {
+            let mut encoded = ManagedBuffer::new();
+            proto::append_bytes_field(&mut encoded, 1, &self.type_url);
+            proto::append_bytes_field(&mut encoded, 2, &self.value);
+
+            encoded
+        }
+
+        pub fn from_proto(encoded: &ManagedBuffer<M>) -> Self {
+            let mut data = Data {
+                type_url: ManagedBuffer::new(),
+                value: ManagedBuffer::new(),
+            };
+            for Field { number, value } in FieldReader::new(encoded) {
+                match number {
+                    1 => data.type_url = value.into_bytes(),
+                    2 => data.value = value.into_bytes(),
+                    _ => {}
+                }
+            }
+
+            data
+        }
+    }
+}
@@ -1,4 +1,6 @@
 pub mod merkle_prefix {
+    use crate::proto::{self, Field, FieldReader};
+
     multiversx_sc::imports!();
     multiversx_sc::derive_imports!();
 
@@ -6,12 +8,36 @@ pub mod merkle_prefix {
     pub struct Data<M: ManagedTypeApi> {
         pub key_prefix: ManagedBuffer<M>,
     }
+
+    impl<M: ManagedTypeApi> Data<M> {
+        /// ibc.core.commitment.v1.MerklePrefix
+        pub fn to_proto(&self) -> ManagedBuffer<M> {
+            let mut encoded = ManagedBuffer::new();
+            proto::append_bytes_field(&mut encoded, 1, &self.key_prefix);
+
+            encoded
+        }
+
+        pub fn from_proto(encoded: &ManagedBuffer<M>) -> Self {
+            let mut key_prefix = ManagedBuffer::new();
+            for Field { number, value } in FieldReader::new(encoded) {
+                if number == 1 {
+                    key_prefix = value.into_bytes();
+                }
+            }
+
+            Data { key_prefix }
+        }
+    }
 }
 
 pub mod connection_end {
-    use crate::{ClientId, UnixTimestamp, VersionVec};
+    use crate::{
+        proto::{self, Field, FieldReader},
+        ClientId, UnixTimestamp, VersionVec,
+    };
 
-    use super::counterparty;
+    use super::{counterparty, version};
 
     multiversx_sc::imports!();
     multiversx_sc::derive_imports!();
@@ -24,6 +50,19 @@ pub mod connection_end {
         Open,
     }
 
+    impl State {
+        /// the variants are declared in the same order as the ibc.core.connection.v1.State values
+        pub fn from_proto<M: ManagedTypeApi>(value: u64) -> Self {
+            match value {
+                0 => State::UninitializedUnspecified,
+                1 => State::Init,
+                2 => State::TryOpen,
+                3 => State::Open,
+                _ => proto::signal_invalid::<M>(),
+            }
+        }
+    }
+
     #[derive(TypeAbi, TopEncode, TopDecode, Clone)]
     pub struct Data<M: ManagedTypeApi> {
         pub client_id: ClientId<M>,
@@ -32,10 +71,53 @@ pub mod connection_end {
         pub counterparty: counterparty::Data<M>,
         pub delay_period: UnixTimestamp,
     }
+
+    impl<M: ManagedTypeApi> Data<M> {
+        /// ibc.core.connection.v1.ConnectionEnd, as stored by ibc-go under the connection path
+        pub fn to_proto(&self) -> ManagedBuffer<M> {
+            let mut encoded = ManagedBuffer::new();
+            proto::append_bytes_field(&mut encoded, 1, &self.client_id);
+            for version in self.versions.iter() {
+                proto::append_message_field(&mut encoded, 2, &version.to_proto());
+            }
+            proto::append_uint_field(&mut encoded, 3, self.state as u64);
+            proto::append_message_field(&mut encoded, 4, &self.counterparty.to_proto());
+            proto::append_uint_field(&mut encoded, 5, self.delay_period);
+
+            encoded
+        }
+
+        pub fn from_proto(encoded: &ManagedBuffer<M>) -> Self {
+            let mut data = Data {
+                client_id: ClientId::new(),
+                versions: VersionVec::new(),
+                state: State::UninitializedUnspecified,
+                counterparty: counterparty::Data::from_proto(&ManagedBuffer::new()),
+                delay_period: 0,
+            };
+            for Field { number, value } in FieldReader::new(encoded) {
+                match number {
+                    1 => data.client_id = value.into_bytes(),
+                    2 => data
+                        .versions
+                        .push(version::Data::from_proto(&value.into_bytes())),
+                    3 => data.state = State::from_proto::<M>(value.into_varint()),
+                    4 => data.counterparty = counterparty::Data::from_proto(&value.into_bytes()),
+                    5 => data.delay_period = value.into_varint(),
+                    _ => {}
+                }
+            }
+
+            data
+        }
+    }
 }
 
 pub mod counterparty {
-    use crate::{ClientId, ConnectionId};
+    use crate::{
+        proto::{self, Field, FieldReader},
+        ClientId, ConnectionId,
+    };
 
     use super::merkle_prefix;
 
@@ -48,10 +130,45 @@ pub mod counterparty {
         pub connection_id: ConnectionId<M>,
         pub prefix: merkle_prefix::Data<M>,
     }
+
+    impl<M: ManagedTypeApi> Data<M> {
+        /// ibc.core.connection.v1.Counterparty
+        pub fn to_proto(&self) -> ManagedBuffer<M> {
+            let mut encoded = ManagedBuffer::new();
+            proto::append_bytes_field(&mut encoded, 1, &self.client_id);
+            proto::append_bytes_field(&mut encoded, 2, &self.connection_id);
+            proto::append_message_field(&mut encoded, 3, &self.prefix.to_proto());
+
+            encoded
+        }
+
+        pub fn from_proto(encoded: &ManagedBuffer<M>) -> Self {
+            let mut data = Data {
+                client_id: ClientId::new(),
+                connection_id: ConnectionId::new(),
+                prefix: merkle_prefix::Data {
+                    key_prefix: ManagedBuffer::new(),
+                },
+            };
+            for Field { number, value } in FieldReader::new(encoded) {
+                match number {
+                    1 => data.client_id = value.into_bytes(),
+                    2 => data.connection_id = value.into_bytes(),
+                    3 => data.prefix = merkle_prefix::Data::from_proto(&value.into_bytes()),
+                    _ => {}
+                }
+            }
+
+            data
+        }
+    }
 }
 
 pub mod version {
-    use crate::{FeatureId, FeatureVec};
+    use crate::{
+        proto::{self, Field, FieldReader},
+        FeatureId, FeatureVec,
+    };
 
     multiversx_sc::imports!();
     multiversx_sc::derive_imports!();
@@ -61,4 +178,33 @@ pub mod version {
         pub identifier: FeatureId<M>,
         pub features: FeatureVec<M>,
     }
+
+    impl<M: ManagedTypeApi> Data<M> {
+        /// ibc.core.connection.v1.Version
+        pub fn to_proto(&self) -> ManagedBuffer<M> {
+            let mut encoded = ManagedBuffer::new();
+            proto::append_bytes_field(&mut encoded, 1, &self.identifier);
+            for feature in self.features.iter() {
+                proto::append_message_field(&mut encoded, 2, &feature);
+            }
+
+            encoded
+        }
+
+        pub fn from_proto(encoded: &ManagedBuffer<M>) -> Self {
+            let mut data = Data {
+                identifier: FeatureId::new(),
+                features: FeatureVec::new(),
+            };
+            for Field { number, value } in FieldReader::new(encoded) {
+                match number {
+                    1 => data.identifier = value.into_bytes(),
+                    2 => data.features.push(value.into_bytes()),
+                    _ => {}
+                }
+            }
+
+            data
+        }
+    }
 }
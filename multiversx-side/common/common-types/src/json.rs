@@ -0,0 +1,471 @@
+//! Minimal JSON encoding and decoding, as used by the packet data and acknowledgements of IBC applications
+//!
+//! Decoding never signals an error, so that applications can answer malformed packets with an error acknowledgement.
+//! Strings are escaped the same way Go's encoding/json does, which is what ibc-go uses.
+
+use multiversx_sc::{
+    api::ManagedTypeApi,
+    types::{ManagedBuffer, ManagedVec},
+};
+
+const MAX_DEPTH: usize = 32;
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// The fields of a decoded JSON object, with the values kept in their raw JSON form
+pub struct JsonObject<M: ManagedTypeApi> {
+    keys: ManagedVec<M, ManagedBuffer<M>>,
+    values: ManagedVec<M, ManagedBuffer<M>>,
+}
+
+impl<M: ManagedTypeApi> JsonObject<M> {
+    /// Returns `None` if `encoded` isn't a single JSON object, or if it has duplicate keys
+    pub fn parse(encoded: &ManagedBuffer<M>) -> Option<Self> {
+        let mut reader = Reader::new(encoded);
+        reader.skip_whitespace();
+        reader.expect(b'{')?;
+
+        let mut object = JsonObject {
+            keys: ManagedVec::new(),
+            values: ManagedVec::new(),
+        };
+        reader.skip_whitespace();
+        if reader.peek()? == b'}' {
+            reader.offset += 1;
+        } else {
+            loop {
+                reader.skip_whitespace();
+                let key = decode_string(&reader.read_string()?)?;
+                if object.find(&key).is_some() {
+                    return None;
+                }
+
+                reader.skip_whitespace();
+                reader.expect(b':')?;
+                reader.skip_whitespace();
+                let value = reader.read_value()?;
+                object.keys.push(key);
+                object.values.push(value);
+
+                reader.skip_whitespace();
+                match reader.next_byte()? {
+                    b',' => continue,
+                    b'}' => break,
+                    _ => return None,
+                }
+            }
+        }
+
+        reader.skip_whitespace();
+        if reader.offset != encoded.len() {
+            return None;
+        }
+
+        Some(object)
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    pub fn contains(&self, key: &[u8]) -> bool {
+        self.find(&ManagedBuffer::new_from_bytes(key)).is_some()
+    }
+
+    /// The value of `key`, as raw JSON
+    pub fn get_raw(&self, key: &[u8]) -> Option<ManagedBuffer<M>> {
+        let index = self.find(&ManagedBuffer::new_from_bytes(key))?;
+
+        Some(self.values.get(index).clone_value())
+    }
+
+    /// The unescaped value of `key`, or `None` if the value is missing or not a string
+    pub fn get_string(&self, key: &[u8]) -> Option<ManagedBuffer<M>> {
+        decode_string(&self.get_raw(key)?)
+    }
+
+    pub fn get_object(&self, key: &[u8]) -> Option<JsonObject<M>> {
+        JsonObject::parse(&self.get_raw(key)?)
+    }
+
+    /// Accepts both numbers and strings holding a number, as protobuf's JSON mapping encodes 64 bit integers as strings
+    pub fn get_u64(&self, key: &[u8]) -> Option<u64> {
+        let raw = self.get_raw(key)?;
+        let digits = match decode_string(&raw) {
+            Some(decoded) => decoded,
+            None => raw,
+        };
+
+        parse_u64(&digits)
+    }
+
+    pub fn get_bool(&self, key: &[u8]) -> Option<bool> {
+        let raw = self.get_raw(key)?;
+        if raw == b"true" {
+            Some(true)
+        } else if raw == b"false" {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// The unescaped elements of an array of strings
+    pub fn get_string_array(&self, key: &[u8]) -> Option<ManagedVec<M, ManagedBuffer<M>>> {
+        let raw = self.get_raw(key)?;
+        let mut reader = Reader::new(&raw);
+        reader.expect(b'[')?;
+
+        let mut elements = ManagedVec::new();
+        reader.skip_whitespace();
+        if reader.peek()? == b']' {
+            return Some(elements);
+        }
+
+        loop {
+            reader.skip_whitespace();
+            elements.push(decode_string(&reader.read_string()?)?);
+
+            reader.skip_whitespace();
+            match reader.next_byte()? {
+                b',' => continue,
+                b']' => return Some(elements),
+                _ => return None,
+            }
+        }
+    }
+
+    fn find(&self, key: &ManagedBuffer<M>) -> Option<usize> {
+        self.keys
+            .iter()
+            .position(|existing_key| &*existing_key == key)
+    }
+}
+
+/// Builds a JSON object, with the fields in insertion order
+pub struct JsonObjectBuilder<M: ManagedTypeApi> {
+    encoded: ManagedBuffer<M>,
+}
+
+impl<M: ManagedTypeApi> Default for JsonObjectBuilder<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M: ManagedTypeApi> JsonObjectBuilder<M> {
+    pub fn new() -> Self {
+        JsonObjectBuilder {
+            encoded: ManagedBuffer::new_from_bytes(b"{"),
+        }
+    }
+
+    pub fn string(self, key: &[u8], value: &ManagedBuffer<M>) -> Self {
+        let mut raw = ManagedBuffer::new();
+        append_string(&mut raw, value);
+
+        self.raw(key, &raw)
+    }
+
+    /// Omits the field if `value` is empty, as protobuf's JSON mapping does for default values
+    pub fn optional_string(self, key: &[u8], value: &ManagedBuffer<M>) -> Self {
+        if value.is_empty() {
+            return self;
+        }
+
+        self.string(key, value)
+    }
+
+    pub fn string_array(self, key: &[u8], values: &ManagedVec<M, ManagedBuffer<M>>) -> Self {
+        let mut raw = ManagedBuffer::new_from_bytes(b"[");
+        for (i, value) in values.iter().enumerate() {
+            if i != 0 {
+                raw.append_bytes(b",");
+            }
+            append_string(&mut raw, &value);
+        }
+        raw.append_bytes(b"]");
+
+        self.raw(key, &raw)
+    }
+
+    /// Adds a value which is already JSON encoded, e.g. a number or a nested object
+    pub fn raw(mut self, key: &[u8], raw: &ManagedBuffer<M>) -> Self {
+        if self.encoded.len() > 1 {
+            self.encoded.append_bytes(b",");
+        }
+        append_string(&mut self.encoded, &ManagedBuffer::new_from_bytes(key));
+        self.encoded.append_bytes(b":");
+        self.encoded.append(raw);
+
+        self
+    }
+
+    pub fn build(mut self) -> ManagedBuffer<M> {
+        self.encoded.append_bytes(b"}");
+
+        self.encoded
+    }
+}
+
+/// Appends `value` as a quoted and escaped JSON string
+pub fn append_string<M: ManagedTypeApi>(buffer: &mut ManagedBuffer<M>, value: &ManagedBuffer<M>) {
+    buffer.append_bytes(b"\"");
+
+    let mut start = 0;
+    for offset in 0..value.len() {
+        let byte = load_byte(value, offset).unwrap_or_default();
+        let escaped: &[u8] = match byte {
+            b'"' => b"\\\"",
+            b'\\' => b"\\\\",
+            b'\n' => b"\\n",
+            b'\r' => b"\\r",
+            b'\t' => b"\\t",
+            0..=0x1f | b'<' | b'>' | b'&' => &[],
+            _ => continue,
+        };
+
+        if let Some(unescaped) = value.copy_slice(start, offset - start) {
+            buffer.append(&unescaped);
+        }
+        if escaped.is_empty() {
+            buffer.append_bytes(b"\\u00");
+            buffer.append_bytes(&[
+                HEX_DIGITS[(byte >> 4) as usize],
+                HEX_DIGITS[(byte & 0xf) as usize],
+            ]);
+        } else {
+            buffer.append_bytes(escaped);
+        }
+        start = offset + 1;
+    }
+
+    if let Some(unescaped) = value.copy_slice(start, value.len() - start) {
+        buffer.append(&unescaped);
+    }
+    buffer.append_bytes(b"\"");
+}
+
+/// Unescapes a raw JSON string, returning `None` if `raw` isn't a valid string
+pub fn decode_string<M: ManagedTypeApi>(raw: &ManagedBuffer<M>) -> Option<ManagedBuffer<M>> {
+    let len = raw.len();
+    if len < 2 || load_byte(raw, 0)? != b'"' || load_byte(raw, len - 1)? != b'"' {
+        return None;
+    }
+
+    let mut decoded = ManagedBuffer::new();
+    let mut offset = 1;
+    let mut start = 1;
+    while offset < len - 1 {
+        let byte = load_byte(raw, offset)?;
+        if byte < 0x20 || byte == b'"' {
+            return None;
+        }
+        if byte != b'\\' {
+            offset += 1;
+            continue;
+        }
+
+        decoded.append(&raw.copy_slice(start, offset - start)?);
+        let escape = load_byte(raw, offset + 1)?;
+        offset += 2;
+        match escape {
+            b'"' | b'\\' | b'/' => decoded.append_bytes(&[escape]),
+            b'b' => decoded.append_bytes(&[0x08]),
+            b'f' => decoded.append_bytes(&[0x0c]),
+            b'n' => decoded.append_bytes(b"\n"),
+            b'r' => decoded.append_bytes(b"\r"),
+            b't' => decoded.append_bytes(b"\t"),
+            b'u' => {
+                let mut code_point = read_hex4(raw, offset)?;
+                offset += 4;
+                if (0xd800..0xdc00).contains(&code_point) {
+                    if load_byte(raw, offset)? != b'\\' || load_byte(raw, offset + 1)? != b'u' {
+                        return None;
+                    }
+                    let low = read_hex4(raw, offset + 2)?;
+                    if !(0xdc00..0xe000).contains(&low) {
+                        return None;
+                    }
+                    offset += 6;
+                    code_point = 0x10000 + ((code_point - 0xd800) << 10) + (low - 0xdc00);
+                } else if (0xdc00..0xe000).contains(&code_point) {
+                    return None;
+                }
+                append_utf8(&mut decoded, code_point);
+            }
+            _ => return None,
+        }
+        start = offset;
+    }
+    if offset != len - 1 {
+        return None;
+    }
+
+    decoded.append(&raw.copy_slice(start, len - 1 - start)?);
+
+    Some(decoded)
+}
+
+/// Parses an unsigned decimal number without sign, exponent or leading zeros
+pub fn parse_u64<M: ManagedTypeApi>(digits: &ManagedBuffer<M>) -> Option<u64> {
+    let len = digits.len();
+    if len == 0 || (len > 1 && load_byte(digits, 0)? == b'0') {
+        return None;
+    }
+
+    let mut value = 0u64;
+    for offset in 0..len {
+        let digit = load_byte(digits, offset)?;
+        if !digit.is_ascii_digit() {
+            return None;
+        }
+
+        value = value.checked_mul(10)?.checked_add((digit - b'0') as u64)?;
+    }
+
+    Some(value)
+}
+
+struct Reader<'a, M: ManagedTypeApi> {
+    encoded: &'a ManagedBuffer<M>,
+    offset: usize,
+}
+
+impl<'a, M: ManagedTypeApi> Reader<'a, M> {
+    fn new(encoded: &'a ManagedBuffer<M>) -> Self {
+        Reader { encoded, offset: 0 }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        load_byte(self.encoded, self.offset)
+    }
+
+    fn next_byte(&mut self) -> Option<u8> {
+        let byte = self.peek()?;
+        self.offset += 1;
+
+        Some(byte)
+    }
+
+    fn expect(&mut self, expected: u8) -> Option<()> {
+        if self.next_byte()? != expected {
+            return None;
+        }
+
+        Some(())
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\n' | b'\r' | b'\t') = self.peek() {
+            self.offset += 1;
+        }
+    }
+
+    /// Reads a raw string, quotes included
+    fn read_string(&mut self) -> Option<ManagedBuffer<M>> {
+        let start = self.offset;
+        self.expect(b'"')?;
+        loop {
+            match self.next_byte()? {
+                b'"' => break,
+                b'\\' => self.offset += 1,
+                _ => {}
+            }
+        }
+
+        self.encoded.copy_slice(start, self.offset - start)
+    }
+
+    /// Reads a raw value, the nested objects and arrays being only checked for balanced brackets
+    fn read_value(&mut self) -> Option<ManagedBuffer<M>> {
+        let start = self.offset;
+        match self.peek()? {
+            b'"' => return self.read_string(),
+            b'{' | b'[' => {
+                let mut depth = 0;
+                loop {
+                    match self.peek()? {
+                        b'"' => {
+                            let _ = self.read_string()?;
+                            continue;
+                        }
+                        b'{' | b'[' => {
+                            depth += 1;
+                            if depth > MAX_DEPTH {
+                                return None;
+                            }
+                        }
+                        b'}' | b']' => depth -= 1,
+                        _ => {}
+                    }
+
+                    self.offset += 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+            }
+            _ => {
+                while let Some(b'0'..=b'9' | b'a'..=b'z' | b'E' | b'.' | b'+' | b'-') = self.peek()
+                {
+                    self.offset += 1;
+                }
+                if self.offset == start {
+                    return None;
+                }
+            }
+        }
+
+        self.encoded.copy_slice(start, self.offset - start)
+    }
+}
+
+fn load_byte<M: ManagedTypeApi>(buffer: &ManagedBuffer<M>, offset: usize) -> Option<u8> {
+    let mut byte = [0u8; 1];
+    buffer.load_slice(offset, &mut byte).ok()?;
+
+    Some(byte[0])
+}
+
+fn read_hex4<M: ManagedTypeApi>(raw: &ManagedBuffer<M>, offset: usize) -> Option<u32> {
+    let mut value = 0u32;
+    for i in 0..4 {
+        let digit = match load_byte(raw, offset + i)? {
+            byte @ b'0'..=b'9' => byte - b'0',
+            byte @ b'a'..=b'f' => byte - b'a' + 10,
+            byte @ b'A'..=b'F' => byte - b'A' + 10,
+            _ => return None,
+        };
+        value = (value << 4) | digit as u32;
+    }
+
+    Some(value)
+}
+
+fn append_utf8<M: ManagedTypeApi>(buffer: &mut ManagedBuffer<M>, code_point: u32) {
+    if code_point < 0x80 {
+        buffer.append_bytes(&[code_point as u8]);
+    } else if code_point < 0x800 {
+        buffer.append_bytes(&[
+            0xc0 | (code_point >> 6) as u8,
+            0x80 | (code_point & 0x3f) as u8,
+        ]);
+    } else if code_point < 0x10000 {
+        buffer.append_bytes(&[
+            0xe0 | (code_point >> 12) as u8,
+            0x80 | ((code_point >> 6) & 0x3f) as u8,
+            0x80 | (code_point & 0x3f) as u8,
+        ]);
+    } else {
+        buffer.append_bytes(&[
+            0xf0 | (code_point >> 18) as u8,
+            0x80 | ((code_point >> 12) & 0x3f) as u8,
+            0x80 | ((code_point >> 6) & 0x3f) as u8,
+            0x80 | (code_point & 0x3f) as u8,
+        ]);
+    }
+}
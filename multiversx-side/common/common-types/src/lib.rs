@@ -4,8 +4,14 @@ use connection_types::version;
 
 multiversx_sc::imports!();
 
+pub mod base64;
+pub mod bech32;
 pub mod channel_types;
+pub mod client_types;
 pub mod connection_types;
+pub mod json;
+pub mod proto;
+pub mod trace;
 
 pub const HASH_LENGTH: usize = 32;
 pub type Hash<M> = ManagedByteArray<M, HASH_LENGTH>;
@@ -22,6 +28,7 @@ pub type Feature<M> = ManagedBuffer<M>;
 pub type FeatureId<M> = ManagedBuffer<M>;
 pub type PortId<M> = ManagedBuffer<M>;
 pub type Path<M> = ManagedBuffer<M>;
+pub type Proof<M> = ManagedBuffer<M>;
 pub type Version<M> = ManagedBuffer<M>;
 
 pub type VersionVec<M> = ManagedVec<M, version::Data<M>>;
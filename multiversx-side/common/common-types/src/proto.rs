@@ -0,0 +1,295 @@
+//! Minimal protobuf encoding and decoding, as used by the IBC messages stored in commitments
+//!
+//! Fields with default values are omitted when encoding, as in proto3. Embedded messages are always written,
+//! since IBC declares them as non-nullable.
+
+use multiversx_sc::{
+    api::{ErrorApiImpl, ManagedTypeApi},
+    types::ManagedBuffer,
+};
+
+const WIRE_TYPE_VARINT: u64 = 0;
+const WIRE_TYPE_FIXED64: u64 = 1;
+const WIRE_TYPE_LEN: u64 = 2;
+const WIRE_TYPE_FIXED32: u64 = 5;
+const FIXED64_LEN: usize = 8;
+const FIXED32_LEN: usize = 4;
+const MAX_VARINT_LEN: usize = 10;
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+pub static INVALID_PROTOBUF_ERR_MSG: &[u8] = b"Invalid protobuf encoding";
+
+pub enum FieldValue<M: ManagedTypeApi> {
+    Varint(u64),
+    /// the raw little endian bytes of fixed32 and fixed64 fields
+    Fixed(ManagedBuffer<M>),
+    LengthDelimited(ManagedBuffer<M>),
+}
+
+impl<M: ManagedTypeApi> FieldValue<M> {
+    pub fn into_varint(self) -> u64 {
+        match self {
+            FieldValue::Varint(value) => value,
+            _ => signal_invalid::<M>(),
+        }
+    }
+
+    pub fn into_bytes(self) -> ManagedBuffer<M> {
+        match self {
+            FieldValue::LengthDelimited(bytes) => bytes,
+            _ => signal_invalid::<M>(),
+        }
+    }
+}
+
+pub struct Field<M: ManagedTypeApi> {
+    pub number: u64,
+    pub value: FieldValue<M>,
+}
+
+/// Iterates over the fields of an encoded message, in encoding order
+pub struct FieldReader<'a, M: ManagedTypeApi> {
+    encoded: &'a ManagedBuffer<M>,
+    offset: usize,
+}
+
+impl<'a, M: ManagedTypeApi> FieldReader<'a, M> {
+    pub fn new(encoded: &'a ManagedBuffer<M>) -> Self {
+        FieldReader { encoded, offset: 0 }
+    }
+}
+
+impl<'a, M: ManagedTypeApi> Iterator for FieldReader<'a, M> {
+    type Item = Field<M>;
+
+    fn next(&mut self) -> Option<Field<M>> {
+        if self.offset == self.encoded.len() {
+            return None;
+        }
+
+        let key = read_varint(self.encoded, &mut self.offset);
+        let number = key >> 3;
+        require_valid::<M>(number > 0);
+
+        let value = match key & 7 {
+            WIRE_TYPE_VARINT => FieldValue::Varint(read_varint(self.encoded, &mut self.offset)),
+            WIRE_TYPE_FIXED64 => {
+                FieldValue::Fixed(read_slice(self.encoded, &mut self.offset, FIXED64_LEN))
+            }
+            WIRE_TYPE_LEN => {
+                let len = read_varint(self.encoded, &mut self.offset);
+                require_valid::<M>(len <= (self.encoded.len() - self.offset) as u64);
+
+                FieldValue::LengthDelimited(read_slice(
+                    self.encoded,
+                    &mut self.offset,
+                    len as usize,
+                ))
+            }
+            WIRE_TYPE_FIXED32 => {
+                FieldValue::Fixed(read_slice(self.encoded, &mut self.offset, FIXED32_LEN))
+            }
+            _ => signal_invalid::<M>(),
+        };
+
+        Some(Field { number, value })
+    }
+}
+
+/// Reads the varint starting at `offset` and advances the offset past it
+pub fn read_varint<M: ManagedTypeApi>(encoded: &ManagedBuffer<M>, offset: &mut usize) -> u64 {
+    match try_read_varint(encoded, offset) {
+        Some(value) => value,
+        None => signal_invalid::<M>(),
+    }
+}
+
+/// Reads a zigzag encoded signed varint, as used by the sint64 type
+pub fn read_signed_varint<M: ManagedTypeApi>(
+    encoded: &ManagedBuffer<M>,
+    offset: &mut usize,
+) -> i64 {
+    decode_zigzag(read_varint(encoded, offset))
+}
+
+/// Same as `read_varint`, but returns `None` instead of failing on invalid input
+pub fn try_read_varint<M: ManagedTypeApi>(
+    encoded: &ManagedBuffer<M>,
+    offset: &mut usize,
+) -> Option<u64> {
+    let mut value = 0u64;
+    for i in 0..MAX_VARINT_LEN {
+        let mut byte = [0u8; 1];
+        encoded.load_slice(*offset, &mut byte).ok()?;
+        *offset += 1;
+
+        let payload = (byte[0] & 0x7f) as u64;
+        if i == MAX_VARINT_LEN - 1 && payload > 1 {
+            return None;
+        }
+
+        value |= payload << (7 * i);
+        if byte[0] & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+
+    None
+}
+
+/// Same as `read_signed_varint`, but returns `None` instead of failing on invalid input
+pub fn try_read_signed_varint<M: ManagedTypeApi>(
+    encoded: &ManagedBuffer<M>,
+    offset: &mut usize,
+) -> Option<i64> {
+    try_read_varint(encoded, offset).map(decode_zigzag)
+}
+
+pub fn append_varint<M: ManagedTypeApi>(buffer: &mut ManagedBuffer<M>, mut value: u64) {
+    let mut bytes = [0u8; MAX_VARINT_LEN];
+    let mut len = 0;
+    while value >= 0x80 {
+        bytes[len] = (value as u8) | 0x80;
+        value >>= 7;
+        len += 1;
+    }
+    bytes[len] = value as u8;
+
+    buffer.append_bytes(&bytes[..=len]);
+}
+
+pub fn append_uint_field<M: ManagedTypeApi>(buffer: &mut ManagedBuffer<M>, field: u64, value: u64) {
+    if value == 0 {
+        return;
+    }
+
+    append_key(buffer, field, WIRE_TYPE_VARINT);
+    append_varint(buffer, value);
+}
+
+pub fn append_sfixed64_field<M: ManagedTypeApi>(
+    buffer: &mut ManagedBuffer<M>,
+    field: u64,
+    value: i64,
+) {
+    if value == 0 {
+        return;
+    }
+
+    append_key(buffer, field, WIRE_TYPE_FIXED64);
+    buffer.append_bytes(&value.to_le_bytes());
+}
+
+/// Also used for strings, which are encoded the same way
+pub fn append_bytes_field<M: ManagedTypeApi>(
+    buffer: &mut ManagedBuffer<M>,
+    field: u64,
+    bytes: &ManagedBuffer<M>,
+) {
+    if bytes.is_empty() {
+        return;
+    }
+
+    append_message_field(buffer, field, bytes);
+}
+
+/// Written even if empty, as required for non-nullable messages and the elements of repeated fields
+pub fn append_message_field<M: ManagedTypeApi>(
+    buffer: &mut ManagedBuffer<M>,
+    field: u64,
+    message: &ManagedBuffer<M>,
+) {
+    append_key(buffer, field, WIRE_TYPE_LEN);
+    append_varint(buffer, message.len() as u64);
+    buffer.append(message);
+}
+
+/// google.protobuf.Timestamp, from nanoseconds since unix epoch
+pub fn encode_timestamp<M: ManagedTypeApi>(timestamp_nanos: u64) -> ManagedBuffer<M> {
+    let mut encoded = ManagedBuffer::new();
+    append_uint_field(&mut encoded, 1, timestamp_nanos / NANOS_PER_SECOND);
+    append_uint_field(&mut encoded, 2, timestamp_nanos % NANOS_PER_SECOND);
+
+    encoded
+}
+
+/// google.protobuf.Timestamp, to nanoseconds since unix epoch
+pub fn decode_timestamp<M: ManagedTypeApi>(encoded: &ManagedBuffer<M>) -> u64 {
+    let (seconds, nanos) = decode_seconds_and_nanos(encoded);
+
+    seconds
+        .checked_mul(NANOS_PER_SECOND)
+        .and_then(|timestamp| timestamp.checked_add(nanos))
+        .unwrap_or_else(|| signal_invalid::<M>())
+}
+
+/// google.protobuf.Duration, from seconds
+pub fn encode_duration<M: ManagedTypeApi>(seconds: u64) -> ManagedBuffer<M> {
+    let mut encoded = ManagedBuffer::new();
+    append_uint_field(&mut encoded, 1, seconds);
+
+    encoded
+}
+
+/// google.protobuf.Duration, to whole seconds
+pub fn decode_duration<M: ManagedTypeApi>(encoded: &ManagedBuffer<M>) -> u64 {
+    let (seconds, _) = decode_seconds_and_nanos(encoded);
+
+    seconds
+}
+
+/// message prefixed by its length, as done for vote sign bytes
+pub fn encode_length_delimited<M: ManagedTypeApi>(message: &ManagedBuffer<M>) -> ManagedBuffer<M> {
+    let mut encoded = ManagedBuffer::new();
+    append_varint(&mut encoded, message.len() as u64);
+    encoded.append(message);
+
+    encoded
+}
+
+pub fn signal_invalid<M: ManagedTypeApi>() -> ! {
+    M::error_api_impl().signal_error(INVALID_PROTOBUF_ERR_MSG)
+}
+
+fn decode_seconds_and_nanos<M: ManagedTypeApi>(encoded: &ManagedBuffer<M>) -> (u64, u64) {
+    let mut seconds = 0;
+    let mut nanos = 0;
+    for Field { number, value } in FieldReader::new(encoded) {
+        match number {
+            1 => seconds = value.into_varint(),
+            2 => nanos = value.into_varint(),
+            _ => {}
+        }
+    }
+    require_valid::<M>(seconds as i64 >= 0 && nanos < NANOS_PER_SECOND);
+
+    (seconds, nanos)
+}
+
+fn append_key<M: ManagedTypeApi>(buffer: &mut ManagedBuffer<M>, field: u64, wire_type: u64) {
+    append_varint(buffer, (field << 3) | wire_type);
+}
+
+fn read_slice<M: ManagedTypeApi>(
+    encoded: &ManagedBuffer<M>,
+    offset: &mut usize,
+    len: usize,
+) -> ManagedBuffer<M> {
+    match encoded.copy_slice(*offset, len) {
+        Some(slice) => {
+            *offset += len;
+            slice
+        }
+        None => signal_invalid::<M>(),
+    }
+}
+
+fn require_valid<M: ManagedTypeApi>(condition: bool) {
+    if !condition {
+        signal_invalid::<M>();
+    }
+}
+
+fn decode_zigzag(value: u64) -> i64 {
+    (value >> 1) as i64 ^ -((value & 1) as i64)
+}
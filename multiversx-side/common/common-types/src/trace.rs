@@ -0,0 +1,80 @@
+//! The traces of the assets moved by IBC applications, e.g. "transfer/channel-0/uatom"
+//!
+//! Every hop prepends the port and channel on which the asset was received.
+
+use multiversx_sc::{api::ManagedTypeApi, types::ManagedBuffer};
+
+use crate::{ChannelId, Hash, PortId};
+
+static HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+/// "{port_id}/{channel_id}/"
+pub fn hop_prefix<M: ManagedTypeApi>(
+    port_id: &PortId<M>,
+    channel_id: &ChannelId<M>,
+) -> ManagedBuffer<M> {
+    let mut prefix = port_id.clone();
+    prefix.append_bytes(b"/");
+    prefix.append(channel_id);
+    prefix.append_bytes(b"/");
+
+    prefix
+}
+
+/// Returns the trace without the hop prefix, if it starts with it
+pub fn strip_hop_prefix<M: ManagedTypeApi>(
+    trace: &ManagedBuffer<M>,
+    port_id: &PortId<M>,
+    channel_id: &ChannelId<M>,
+) -> Option<ManagedBuffer<M>> {
+    let prefix = hop_prefix(port_id, channel_id);
+    let prefix_len = prefix.len();
+    if trace.len() <= prefix_len || trace.copy_slice(0, prefix_len)? != prefix {
+        return None;
+    }
+
+    trace.copy_slice(prefix_len, trace.len() - prefix_len)
+}
+
+/// Splits a trace into the port, the channel and the rest of the trace
+pub fn split_first_hop<M: ManagedTypeApi>(
+    trace: &ManagedBuffer<M>,
+) -> Option<(PortId<M>, ChannelId<M>, ManagedBuffer<M>)> {
+    let len = trace.len();
+    let first_slash = find_slash(trace, 0)?;
+    let second_slash = find_slash(trace, first_slash + 1)?;
+    if first_slash == 0 || second_slash == first_slash + 1 || second_slash + 1 == len {
+        return None;
+    }
+
+    Some((
+        trace.copy_slice(0, first_slash)?,
+        trace.copy_slice(first_slash + 1, second_slash - first_slash - 1)?,
+        trace.copy_slice(second_slash + 1, len - second_slash - 1)?,
+    ))
+}
+
+/// "{prefix}{uppercase hex of hash}", as ibc-go shows the hashes of traces
+pub fn hex_with_prefix<M: ManagedTypeApi>(prefix: &[u8], hash: &Hash<M>) -> ManagedBuffer<M> {
+    let mut encoded = ManagedBuffer::new_from_bytes(prefix);
+    for byte in hash.to_byte_array() {
+        encoded.append_bytes(&[
+            HEX_DIGITS[(byte >> 4) as usize],
+            HEX_DIGITS[(byte & 0xf) as usize],
+        ]);
+    }
+
+    encoded
+}
+
+fn find_slash<M: ManagedTypeApi>(buffer: &ManagedBuffer<M>, from: usize) -> Option<usize> {
+    let mut byte = [0u8; 1];
+    for offset in from..buffer.len() {
+        buffer.load_slice(offset, &mut byte).ok()?;
+        if byte[0] == b'/' {
+            return Some(offset);
+        }
+    }
+
+    None
+}
@@ -0,0 +1,42 @@
+use common_types::base64;
+use multiversx_sc_scenario::{managed_buffer, DebugApi};
+
+static VECTORS: &[(&[u8], &[u8])] = &[
+    (b"", b""),
+    (b"f", b"Zg=="),
+    (b"fo", b"Zm8="),
+    (b"foo", b"Zm9v"),
+    (b"foob", b"Zm9vYg=="),
+    (b"fooba", b"Zm9vYmE="),
+    (b"foobar", b"Zm9vYmFy"),
+    (&[0xfb, 0xff, 0x01], b"+/8B"),
+];
+
+#[test]
+fn encode_test() {
+    DebugApi::dummy();
+
+    for (decoded, encoded) in VECTORS {
+        assert_eq!(
+            base64::encode::<DebugApi>(&managed_buffer!(decoded)),
+            managed_buffer!(encoded)
+        );
+    }
+}
+
+#[test]
+fn decode_test() {
+    DebugApi::dummy();
+
+    for (decoded, encoded) in VECTORS {
+        assert_eq!(
+            base64::decode::<DebugApi>(&managed_buffer!(encoded)),
+            Some(managed_buffer!(decoded))
+        );
+    }
+
+    // missing padding, padding in the middle, invalid characters and non-zero padding bits
+    for invalid in [&b"Zg"[..], b"Zg==Zm8=", b"Zm9v-mFy", b"Zh=="] {
+        assert_eq!(base64::decode::<DebugApi>(&managed_buffer!(invalid)), None);
+    }
+}
@@ -0,0 +1,56 @@
+use common_types::bech32;
+use multiversx_sc::types::{ManagedAddress, ManagedBuffer};
+use multiversx_sc_scenario::{managed_buffer, DebugApi};
+
+static ALICE_BECH32: &[u8] = b"erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th";
+static ALICE_HEX: &str = "0139472eff6886771a982f3083da5d421f24c29181e63888228dc81ca60d69e1";
+
+fn alice() -> ManagedAddress<DebugApi> {
+    ManagedAddress::new_from_bytes(&hex::decode(ALICE_HEX).unwrap().try_into().unwrap())
+}
+
+#[test]
+fn encode_address_test() {
+    DebugApi::dummy();
+
+    assert_eq!(
+        bech32::encode_address(&alice()),
+        managed_buffer!(ALICE_BECH32)
+    );
+    assert_eq!(
+        bech32::encode_address(&ManagedAddress::<DebugApi>::zero()),
+        managed_buffer!(b"erd1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq6gq4hu")
+    );
+}
+
+#[test]
+fn decode_address_test() {
+    DebugApi::dummy();
+
+    assert_eq!(
+        bech32::decode_address(&managed_buffer!(ALICE_BECH32)),
+        Some(alice())
+    );
+
+    // wrong checksum
+    let mut tampered = ALICE_BECH32.to_vec();
+    tampered[10] = b'q';
+    assert_eq!(
+        bech32::decode_address::<DebugApi>(&managed_buffer!(&tampered)),
+        None
+    );
+
+    // wrong prefix, uppercase, wrong length and invalid characters
+    for invalid in [
+        &b"cosmos1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th"[..],
+        b"ERD1QYU5WTHLDZR8WX5C9UCG8KJAGG0JFS53S8NR3ZPZ3HYPEFSDD8SSYCR6TH",
+        b"erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6t",
+        b"erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6tb",
+        b"",
+    ] {
+        assert_eq!(
+            bech32::decode_address(&ManagedBuffer::<DebugApi>::new_from_bytes(invalid)),
+            None
+        );
+    }
+}
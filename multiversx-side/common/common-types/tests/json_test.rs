@@ -0,0 +1,158 @@
+use common_types::json::{self, JsonObject, JsonObjectBuilder};
+use multiversx_sc::types::{ManagedBuffer, ManagedVec};
+use multiversx_sc_scenario::{managed_buffer, DebugApi};
+
+static PACKET_DATA: &[u8] = br#"{"amount":"100","denom":"transfer/channel-0/uatom","memo":"{\"forward\":{\"port\":\"transfer\",\"retries\":2}}","receiver":"erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th","sender":"cosmos1sender"}"#;
+
+#[test]
+fn parse_object_test() {
+    DebugApi::dummy();
+
+    let object = JsonObject::<DebugApi>::parse(&managed_buffer!(PACKET_DATA)).unwrap();
+    assert_eq!(object.len(), 5);
+    assert_eq!(object.get_string(b"amount"), Some(managed_buffer!(b"100")));
+    assert_eq!(object.get_u64(b"amount"), Some(100));
+    assert_eq!(
+        object.get_string(b"denom"),
+        Some(managed_buffer!(b"transfer/channel-0/uatom"))
+    );
+    assert_eq!(object.get_string(b"missing"), None);
+
+    let memo = object.get_string(b"memo").unwrap();
+    let memo = JsonObject::parse(&memo).unwrap();
+    let forward = memo.get_object(b"forward").unwrap();
+    assert_eq!(
+        forward.get_string(b"port"),
+        Some(managed_buffer!(b"transfer"))
+    );
+    assert_eq!(forward.get_u64(b"retries"), Some(2));
+    assert_eq!(forward.get_string(b"retries"), None);
+    assert_eq!(forward.get_raw(b"retries"), Some(managed_buffer!(b"2")));
+}
+
+#[test]
+fn parse_nested_values_test() {
+    DebugApi::dummy();
+
+    let object = JsonObject::<DebugApi>::parse(&managed_buffer!(
+        br#" { "ids" : ["a", "b\"]"] , "nested": {"list": [1, [2, {"x": "}"}]]}, "flag": true, "empty": {} } "#
+    ))
+    .unwrap();
+
+    let ids = object.get_string_array(b"ids").unwrap();
+    assert_eq!(ids.len(), 2);
+    assert_eq!(ids.get(0).clone_value(), managed_buffer!(b"a"));
+    assert_eq!(ids.get(1).clone_value(), managed_buffer!(b"b\"]"));
+    assert_eq!(
+        object.get_raw(b"nested"),
+        Some(managed_buffer!(br#"{"list": [1, [2, {"x": "}"}]]}"#))
+    );
+    assert_eq!(object.get_bool(b"flag"), Some(true));
+    assert!(object.get_object(b"empty").unwrap().is_empty());
+    assert_eq!(object.get_string_array(b"flag"), None);
+}
+
+#[test]
+fn parse_invalid_object_test() {
+    DebugApi::dummy();
+
+    for invalid in [
+        &b""[..],
+        b"[]",
+        b"{",
+        b"{\"a\":\"1\",}",
+        b"{\"a\" \"1\"}",
+        b"{\"a\":\"1\"} trailing",
+        b"{\"a\":\"1\",\"a\":\"2\"}",
+        b"{\"a\":}",
+        b"{\"a\":[1}",
+        b"{a:\"1\"}",
+    ] {
+        assert!(
+            JsonObject::<DebugApi>::parse(&ManagedBuffer::new_from_bytes(invalid)).is_none(),
+            "{}",
+            String::from_utf8_lossy(invalid)
+        );
+    }
+}
+
+#[test]
+fn decode_string_test() {
+    DebugApi::dummy();
+
+    assert_eq!(
+        json::decode_string::<DebugApi>(&managed_buffer!(
+            br#""a\"b\\c\/d\n\u0041\u00e9\ud83d\ude00""#
+        )),
+        Some(managed_buffer!("a\"b\\c/d\nA\u{e9}\u{1f600}".as_bytes()))
+    );
+    assert_eq!(
+        json::decode_string::<DebugApi>(&managed_buffer!(b"\"\"")),
+        Some(managed_buffer!(b""))
+    );
+
+    for invalid in [
+        &b"abc"[..],
+        b"\"abc",
+        b"\"a\"b\"",
+        b"\"\\x\"",
+        b"\"\\u12\"",
+        b"\"\\udc00\"",
+        b"\"a\nb\"",
+    ] {
+        assert_eq!(
+            json::decode_string(&ManagedBuffer::<DebugApi>::new_from_bytes(invalid)),
+            None
+        );
+    }
+}
+
+#[test]
+fn parse_u64_test() {
+    DebugApi::dummy();
+
+    assert_eq!(json::parse_u64::<DebugApi>(&managed_buffer!(b"0")), Some(0));
+    assert_eq!(
+        json::parse_u64::<DebugApi>(&managed_buffer!(b"18446744073709551615")),
+        Some(u64::MAX)
+    );
+    for invalid in [&b""[..], b"01", b"-1", b"1.5", b"18446744073709551616"] {
+        assert_eq!(
+            json::parse_u64(&ManagedBuffer::<DebugApi>::new_from_bytes(invalid)),
+            None
+        );
+    }
+}
+
+#[test]
+fn build_object_test() {
+    DebugApi::dummy();
+
+    let mut ids = ManagedVec::<DebugApi, ManagedBuffer<DebugApi>>::new();
+    ids.push(managed_buffer!(b"1"));
+    ids.push(managed_buffer!(b"2"));
+
+    let encoded = JsonObjectBuilder::new()
+        .string(b"amount", &managed_buffer!(b"100"))
+        .optional_string(b"memo", &ManagedBuffer::new())
+        .string(b"quoted", &managed_buffer!(b"a\"b\\c\n<&>\x01"))
+        .string_array(b"ids", &ids)
+        .raw(b"nested", &managed_buffer!(b"{\"x\":1}"))
+        .build();
+    assert_eq!(
+        encoded,
+        managed_buffer!(
+            br#"{"amount":"100","quoted":"a\"b\\c\n\u003c\u0026\u003e\u0001","ids":["1","2"],"nested":{"x":1}}"#
+        )
+    );
+
+    let decoded = JsonObject::parse(&encoded).unwrap();
+    assert_eq!(
+        decoded.get_string(b"quoted"),
+        Some(managed_buffer!(b"a\"b\\c\n<&>\x01"))
+    );
+    assert_eq!(
+        JsonObjectBuilder::<DebugApi>::new().build(),
+        managed_buffer!(b"{}")
+    );
+}
@@ -0,0 +1,268 @@
+use common_types::{
+    channel_types::{
+        channel, channel_counterparty, error_receipt, height, timeout, upgrade, upgrade_fields,
+    },
+    client_types::any,
+    connection_types::{connection_end, counterparty, merkle_prefix, version},
+    proto, ConnectionHops, FeatureVec, VersionVec,
+};
+use multiversx_sc::types::ManagedBuffer;
+use multiversx_sc_scenario::{managed_buffer, DebugApi};
+
+static CONNECTION_END: &str = "0a0f30372d74656e6465726d696e742d3112230a0131120d4f524445525f4f524445524544120f4f524445525f554e4f524445524544180122180a0f30372d74656e6465726d696e742d301a050a03696263";
+static CHANNEL: &str = "080310011a150a087472616e7366657212096368616e6e656c2d37220c636f6e6e656374696f6e2d302a0769637332302d313002";
+static UPGRADE: &str = "0a190801120c636f6e6e656374696f6e2d301a0769637332302d3212110a05080110ac02108080a8b1e39fe7cb171805";
+static ERROR_RECEIPT: &str = "0803121c757067726164652068616e647368616b652069732061626f72746564";
+static CLIENT_STATE: &str = "0a2b2f6962632e6c69676874636c69656e74732e74656e6465726d696e742e76312e436c69656e745374617465120d0a0b74657374636861696e2d31";
+
+fn managed_hex(hex_str: &str) -> ManagedBuffer<DebugApi> {
+    ManagedBuffer::new_from_bytes(&hex::decode(hex_str).unwrap())
+}
+
+fn assert_user_error<F: FnOnce()>(f: F, expected_message: &str) {
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(f));
+    assert!(result.is_err());
+
+    let tx_result = multiversx_sc_scenario::imports::TxContextStack::static_peek().extract_result();
+    assert_eq!(tx_result.result_message, expected_message);
+}
+
+fn new_connection_end() -> connection_end::Data<DebugApi> {
+    let mut features = FeatureVec::new();
+    features.push(managed_buffer!(b"ORDER_ORDERED"));
+    features.push(managed_buffer!(b"ORDER_UNORDERED"));
+
+    connection_end::Data {
+        client_id: managed_buffer!(b"07-tendermint-1"),
+        versions: VersionVec::from_single_item(version::Data {
+            identifier: managed_buffer!(b"1"),
+            features,
+        }),
+        state: connection_end::State::Init,
+        counterparty: counterparty::Data {
+            client_id: managed_buffer!(b"07-tendermint-0"),
+            connection_id: ManagedBuffer::new(),
+            prefix: merkle_prefix::Data {
+                key_prefix: managed_buffer!(b"ibc"),
+            },
+        },
+        delay_period: 0,
+    }
+}
+
+fn new_channel() -> channel::Data<DebugApi> {
+    channel::Data {
+        state: channel::State::Open,
+        ordering: channel::Order::Unordered,
+        counterparty: channel_counterparty::Data {
+            port_id: managed_buffer!(b"transfer"),
+            channel_id: managed_buffer!(b"channel-7"),
+        },
+        connection_hops: ConnectionHops::from_single_item(managed_buffer!(b"connection-0")),
+        version: managed_buffer!(b"ics20-1"),
+        upgrade_sequence: 2,
+    }
+}
+
+#[test]
+fn connection_end_proto_test() {
+    DebugApi::dummy();
+
+    let connection = new_connection_end();
+    let encoded = connection.to_proto();
+    assert_eq!(encoded, managed_hex(CONNECTION_END));
+
+    let decoded = connection_end::Data::from_proto(&encoded);
+    assert_eq!(decoded.client_id, connection.client_id);
+    assert!(matches!(decoded.state, connection_end::State::Init));
+    assert_eq!(decoded.versions.len(), 1);
+    assert_eq!(decoded.versions.get(0).features.len(), 2);
+    assert_eq!(
+        decoded.counterparty.prefix.key_prefix,
+        managed_buffer!(b"ibc")
+    );
+    assert_eq!(decoded.to_proto(), encoded);
+}
+
+#[test]
+fn channel_proto_test() {
+    DebugApi::dummy();
+
+    let channel = new_channel();
+    let encoded = channel.to_proto();
+    assert_eq!(encoded, managed_hex(CHANNEL));
+
+    let decoded = channel::Data::from_proto(&encoded);
+    assert!(matches!(decoded.state, channel::State::Open));
+    assert!(decoded.ordering == channel::Order::Unordered);
+    assert_eq!(
+        decoded.counterparty.channel_id,
+        managed_buffer!(b"channel-7")
+    );
+    assert_eq!(decoded.upgrade_sequence, 2);
+    assert_eq!(decoded.to_proto(), encoded);
+}
+
+#[test]
+fn upgrade_proto_test() {
+    DebugApi::dummy();
+
+    let upgrade = upgrade::Data::<DebugApi> {
+        fields: upgrade_fields::Data {
+            ordering: channel::Order::Unordered,
+            connection_hops: ConnectionHops::from_single_item(managed_buffer!(b"connection-0")),
+            version: managed_buffer!(b"ics20-2"),
+        },
+        timeout: timeout::Data {
+            height: height::Data {
+                revision_number: 1,
+                revision_height: 300,
+            },
+            timestamp: 1_700_000_000_000_000_000,
+        },
+        next_sequence_send: 5,
+    };
+    assert_eq!(upgrade.to_proto(), managed_hex(UPGRADE));
+
+    // the non-nullable height of a timestamp only timeout is written even if empty
+    let timeout = timeout::Data {
+        height: height::Data::default(),
+        timestamp: 10,
+    };
+    assert_eq!(timeout.to_proto::<DebugApi>(), managed_hex("0a00100a"));
+}
+
+#[test]
+fn error_receipt_proto_test() {
+    DebugApi::dummy();
+
+    let error_receipt = error_receipt::Data::<DebugApi> {
+        sequence: 3,
+        message: managed_buffer!(b"upgrade handshake is aborted"),
+    };
+    assert_eq!(error_receipt.to_proto(), managed_hex(ERROR_RECEIPT));
+}
+
+#[test]
+fn empty_messages_proto_test() {
+    DebugApi::dummy();
+
+    // the non-nullable counterparty is written even if empty
+    let channel = channel::Data::<DebugApi>::from_proto(&ManagedBuffer::new());
+    assert_eq!(channel.to_proto(), managed_hex("1a00"));
+
+    assert_eq!(
+        height::Data::default().to_proto::<DebugApi>(),
+        ManagedBuffer::new()
+    );
+}
+
+#[test]
+fn height_proto_test() {
+    DebugApi::dummy();
+
+    let height = height::Data {
+        revision_number: 1,
+        revision_height: 300,
+    };
+    let encoded = height.to_proto::<DebugApi>();
+    assert_eq!(encoded, managed_hex("080110ac02"));
+    assert!(height::Data::from_proto(&encoded) == height);
+}
+
+#[test]
+fn any_proto_test() {
+    DebugApi::dummy();
+
+    let client_state = any::Data::<DebugApi>::from_proto(&managed_hex(CLIENT_STATE));
+    assert_eq!(
+        client_state.type_url,
+        managed_buffer!(b"/ibc.lightclients.tendermint.v1.ClientState")
+    );
+    assert_eq!(
+        client_state.value,
+        managed_hex("0a0b74657374636861696e2d31")
+    );
+    assert_eq!(client_state.to_proto(), managed_hex(CLIENT_STATE));
+}
+
+#[test]
+fn unknown_fields_proto_test() {
+    DebugApi::dummy();
+
+    // field 7 is unknown to this version of the channel message
+    let mut encoded = managed_hex(CHANNEL);
+    encoded.append(&managed_hex("3801"));
+    assert_eq!(
+        channel::Data::from_proto(&encoded).to_proto(),
+        managed_hex(CHANNEL)
+    );
+}
+
+#[test]
+fn invalid_proto_test() {
+    DebugApi::dummy();
+
+    // unknown channel state
+    assert_user_error(
+        || {
+            let _ = channel::Data::<DebugApi>::from_proto(&managed_hex("0807"));
+        },
+        "Invalid protobuf encoding",
+    );
+    // the client id is not a string
+    assert_user_error(
+        || {
+            let _ = connection_end::Data::<DebugApi>::from_proto(&managed_hex("0801"));
+        },
+        "Invalid protobuf encoding",
+    );
+    // truncated
+    assert_user_error(
+        || {
+            let _ = channel::Data::<DebugApi>::from_proto(&managed_hex(&CHANNEL[..20]));
+        },
+        "Invalid protobuf encoding",
+    );
+}
+
+#[test]
+fn varint_test() {
+    DebugApi::dummy();
+
+    for (value, expected) in [
+        (0u64, "00"),
+        (300, "ac02"),
+        (u64::MAX, "ffffffffffffffffff01"),
+    ] {
+        let mut encoded = ManagedBuffer::<DebugApi>::new();
+        proto::append_varint(&mut encoded, value);
+        assert_eq!(encoded, managed_hex(expected));
+
+        let mut offset = 0;
+        assert_eq!(proto::read_varint(&encoded, &mut offset), value);
+        assert_eq!(offset, expected.len() / 2);
+    }
+
+    // zigzag: 0, -1, 1, -2
+    for (encoded, expected) in [("00", 0i64), ("01", -1), ("02", 1), ("03", -2)] {
+        let mut offset = 0;
+        assert_eq!(
+            proto::read_signed_varint(&managed_hex(encoded), &mut offset),
+            expected
+        );
+    }
+
+    let mut offset = 0;
+    assert_eq!(
+        proto::try_read_varint(&managed_hex("ffffffffffffffffff02"), &mut offset),
+        None
+    );
+    assert_user_error(
+        || {
+            let mut offset = 0;
+            let _ = proto::read_varint(&managed_hex("ffffffffffffffffff02"), &mut offset);
+        },
+        "Invalid protobuf encoding",
+    );
+}
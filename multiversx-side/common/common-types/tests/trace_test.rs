@@ -0,0 +1,65 @@
+use common_types::trace;
+use multiversx_sc_scenario::{managed_buffer, DebugApi};
+
+#[test]
+fn hop_prefix_test() {
+    DebugApi::dummy();
+
+    let port_id = managed_buffer!(b"transfer");
+    let channel_id = managed_buffer!(b"channel-0");
+    assert_eq!(
+        trace::hop_prefix::<DebugApi>(&port_id, &channel_id),
+        managed_buffer!(b"transfer/channel-0/")
+    );
+    assert_eq!(
+        trace::strip_hop_prefix::<DebugApi>(
+            &managed_buffer!(b"transfer/channel-0/transfer/channel-3/uatom"),
+            &port_id,
+            &channel_id
+        ),
+        Some(managed_buffer!(b"transfer/channel-3/uatom"))
+    );
+    assert_eq!(
+        trace::strip_hop_prefix::<DebugApi>(
+            &managed_buffer!(b"transfer/channel-01/uatom"),
+            &port_id,
+            &channel_id
+        ),
+        None
+    );
+    assert_eq!(
+        trace::strip_hop_prefix::<DebugApi>(
+            &managed_buffer!(b"transfer/channel-0/"),
+            &port_id,
+            &channel_id
+        ),
+        None
+    );
+}
+
+#[test]
+fn split_first_hop_test() {
+    DebugApi::dummy();
+
+    assert_eq!(
+        trace::split_first_hop::<DebugApi>(&managed_buffer!(b"transfer/channel-0/a/b")),
+        Some((
+            managed_buffer!(b"transfer"),
+            managed_buffer!(b"channel-0"),
+            managed_buffer!(b"a/b")
+        ))
+    );
+
+    for invalid in [
+        &b"uatom"[..],
+        b"transfer/uatom",
+        b"/channel-0/uatom",
+        b"transfer//uatom",
+        b"transfer/channel-0/",
+    ] {
+        assert_eq!(
+            trace::split_first_hop::<DebugApi>(&managed_buffer!(invalid)),
+            None
+        );
+    }
+}
@@ -0,0 +1,36 @@
+//! Helpers shared by the test setups of the apps
+
+use std::cell::RefCell;
+
+use common_types::bech32;
+use multiversx_sc::{contract_base::CallableContract, types::Address};
+use multiversx_sc_scenario::{
+    imports::{BlockchainStateWrapper, ContractObjWrapper},
+    managed_address,
+    multiversx_sc::contract_base::ContractBase,
+    DebugApi,
+};
+
+/// The bech32 address of an account, as used in packet data and metadata
+///
+/// It is encoded in a query of any deployed contract, which gives the managed types a context to live in.
+pub fn bech32_address<CB, ContractObjBuilder>(
+    b_mock: &RefCell<BlockchainStateWrapper>,
+    sc_wrapper: &ContractObjWrapper<CB, ContractObjBuilder>,
+    address: &Address,
+) -> String
+where
+    CB: ContractBase<Api = DebugApi> + CallableContract + 'static,
+    ContractObjBuilder: 'static + Copy + Fn() -> CB,
+{
+    let mut encoded = String::new();
+    b_mock
+        .borrow_mut()
+        .execute_query(sc_wrapper, |_| {
+            let bech32_address = bech32::encode_address::<DebugApi>(&managed_address!(address));
+            encoded = String::from_utf8(bech32_address.to_boxed_bytes().into_vec()).unwrap();
+        })
+        .assert_ok();
+
+    encoded
+}
@@ -1,17 +1,20 @@
 use client_common::VerifyMembershipArgs;
 use common_types::{
     channel_types::height,
+    client_types::any,
     connection_types::{connection_end, version},
-    ConnectionId, Hash, Path, VersionVec,
+    ConnectionId, Path, Proof, VersionVec,
 };
 
 multiversx_sc::imports!();
 
+static SELF_CLIENT_TYPES_NOT_SET_ERR_MSG: &[u8] = b"Self client types not set";
+
 pub struct VerifyClientStateArgs<M: ManagedTypeApi> {
     pub connection_info: connection_end::Data<M>,
     pub height: height::Data,
     pub path: Path<M>,
-    pub proof: Hash<M>,
+    pub proof: Proof<M>,
     pub client_state_bytes: ManagedBuffer<M>,
 }
 
@@ -19,14 +22,14 @@ pub struct VerifyConsensusStateArgs<M: ManagedTypeApi> {
     pub connection_info: connection_end::Data<M>,
     pub height: height::Data,
     pub consensus_height: height::Data,
-    pub proof: Hash<M>,
+    pub proof: Proof<M>,
     pub consensus_state_bytes: ManagedBuffer<M>,
 }
 
 pub struct VerifyConnectionStateArgs<M: ManagedTypeApi> {
     pub connection_info: connection_end::Data<M>,
     pub height: height::Data,
-    pub proof: Hash<M>,
+    pub proof: Proof<M>,
     pub counterparty_connection_id: ConnectionId<M>,
     pub counterparty_connection_info: connection_end::Data<M>,
 }
@@ -87,7 +90,7 @@ pub trait ConnectionInternalModule:
         connection_info: &connection_end::Data<Self::Api>,
     ) {
         let connection_key = self.get_connection_commitment_key(connection_id);
-        let encoded_connection = self.encode_to_buffer(&connection_info);
+        let encoded_connection = connection_info.to_proto();
         let hashed_connection = self.crypto().keccak256(encoded_connection);
 
         self.commitments(&connection_key).set(&hashed_connection);
@@ -100,13 +103,26 @@ pub trait ConnectionInternalModule:
     }
 
     fn verify_client_state(&self, args: VerifyClientStateArgs<Self::Api>) {
+        // the client state the counterparty keeps for this chain must be of its light client type
+        let client_state = any::Data::<Self::Api>::from_proto(&args.client_state_bytes);
+        let type_url_mapper = self.self_client_state_type_url();
+        require!(
+            !type_url_mapper.is_empty(),
+            SELF_CLIENT_TYPES_NOT_SET_ERR_MSG
+        );
+        require!(
+            client_state.type_url == type_url_mapper.get(),
+            "Invalid client state type"
+        );
+        require!(!client_state.value.is_empty(), "Invalid client state");
+
         let client = self.check_and_get_client(&args.connection_info.client_id);
         let args = VerifyMembershipArgs {
             client_id: args.connection_info.client_id,
             height: args.height,
             delay_time_period: 0,
             delay_block_period: 0,
-            proof: args.proof,
+            proof: args.proof.clone(),
             prefix: args.connection_info.counterparty.prefix.key_prefix,
             path: args.path,
             value: args.client_state_bytes,
@@ -119,6 +135,25 @@ pub trait ConnectionInternalModule:
     }
 
     fn verify_consensus_state(&self, args: VerifyConsensusStateArgs<Self::Api>) {
+        let consensus_state = any::Data::<Self::Api>::from_proto(&args.consensus_state_bytes);
+        let type_url_mapper = self.self_consensus_state_type_url();
+        require!(
+            !type_url_mapper.is_empty(),
+            SELF_CLIENT_TYPES_NOT_SET_ERR_MSG
+        );
+        require!(
+            consensus_state.type_url == type_url_mapper.get(),
+            "Invalid consensus state type"
+        );
+        require!(!consensus_state.value.is_empty(), "Invalid consensus state");
+
+        // the counterparty can only have stored a consensus state of a past block of this chain
+        require!(
+            args.consensus_height.revision_number == 0
+                && args.consensus_height.revision_height < self.blockchain().get_block_nonce(),
+            "Invalid consensus height"
+        );
+
         let client = self.check_and_get_client(&args.connection_info.client_id);
         let consensus_state_path = self.get_consensus_state_path(
             &args.connection_info.counterparty.client_id,
@@ -130,7 +165,7 @@ pub trait ConnectionInternalModule:
             height: args.height,
             delay_time_period: 0,
             delay_block_period: 0,
-            proof: args.proof,
+            proof: args.proof.clone(),
             prefix: args.connection_info.counterparty.prefix.key_prefix,
             path: consensus_state_path,
             value: args.consensus_state_bytes,
@@ -145,14 +180,14 @@ pub trait ConnectionInternalModule:
     fn verify_connection_state(&self, args: VerifyConnectionStateArgs<Self::Api>) {
         let client = self.check_and_get_client(&args.connection_info.client_id);
         let connection_path = self.get_connection_path(&args.counterparty_connection_id);
-        let encoded_connection = self.encode_to_buffer(&args.counterparty_connection_info);
+        let encoded_connection = args.counterparty_connection_info.to_proto();
 
         let args = VerifyMembershipArgs {
             client_id: args.connection_info.client_id,
             height: args.height,
             delay_time_period: 0,
             delay_block_period: 0,
-            proof: args.proof,
+            proof: args.proof.clone(),
             prefix: args.connection_info.counterparty.prefix.key_prefix,
             path: connection_path,
             value: encoded_connection,
@@ -1,6 +1,6 @@
 use common_types::{
     connection_types::{connection_end, counterparty, merkle_prefix},
-    ConnectionId, Hash,
+    ConnectionId,
 };
 
 use super::{
@@ -28,7 +28,7 @@ pub trait VerifyStatesModule:
     fn verify_all_states_open_try(
         &self,
         connection_info: connection_end::Data<Self::Api>,
-        self_consensus_state: &Hash<Self::Api>,
+        self_consensus_state: &ManagedBuffer,
         args: MsgConnectionOpenTry<Self::Api>,
     ) {
         let expected_counterparty = counterparty::Data {
@@ -65,14 +65,14 @@ pub trait VerifyStatesModule:
             height: args.proof_height,
             consensus_height: args.consensus_height,
             proof: args.proof_consensus,
-            consensus_state_bytes: self_consensus_state.as_managed_buffer().clone(),
+            consensus_state_bytes: self_consensus_state.clone(),
         });
     }
 
     fn verify_all_states_open_ack(
         &self,
         connection_info: connection_end::Data<Self::Api>,
-        self_consensus_state: &Hash<Self::Api>,
+        self_consensus_state: &ManagedBuffer,
         args: MsgConnectionOpenAck<Self::Api>,
     ) {
         let expected_counterparty = counterparty::Data {
@@ -109,7 +109,7 @@ pub trait VerifyStatesModule:
             height: args.proof_height,
             consensus_height: args.consensus_height,
             proof: args.proof_consensus,
-            consensus_state_bytes: self_consensus_state.as_managed_buffer().clone(),
+            consensus_state_bytes: self_consensus_state.clone(),
         });
     }
 
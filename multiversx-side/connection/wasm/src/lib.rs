@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           12
+// Endpoints:                           20
 // Async Callback (empty):               1
-// Total number of exported functions:  15
+// Total number of exported functions:  23
 
 #![no_std]
 
@@ -26,12 +26,20 @@ multiversx_sc_wasm_adapter::endpoints! {
         connectionOpenAck => connection_open_ack
         connectionOpenConfirm => connection_open_confirm
         setExpectedTimePerBlock => set_expected_time_per_block
+        setSelfClientTypeUrls => set_self_client_type_urls
         registerClient => register_client
         bindPort => bind_port
+        setPortMiddlewares => set_port_middlewares
+        bindPortPrefix => bind_port_prefix
+        bindPrefixedPort => bind_prefixed_port
         getHostTimestamp => get_host_timestamp
         getCommitmentPrefix => get_commitment_prefix
         checkAndGetClient => check_and_get_client
+        getChannelStack => get_channel_stack
         getCommitment => get_commitment
+        getConnection => get_connection
+        isChannelOpen => is_channel_open
+        getPortMiddlewares => port_middlewares
     )
 }
 
@@ -29,6 +29,25 @@ pub trait HostConfigModule:
         mapper.set(default_host_value);
     }
 
+    /// Sets the type URLs of the client and consensus states that counterparties must store for this chain, which are
+    /// checked during the connection handshake
+    #[only_owner]
+    #[endpoint(setSelfClientTypeUrls)]
+    fn set_self_client_type_urls(
+        &self,
+        client_state_type_url: ManagedBuffer,
+        consensus_state_type_url: ManagedBuffer,
+    ) {
+        require!(
+            !client_state_type_url.is_empty() && !consensus_state_type_url.is_empty(),
+            "Invalid type URL"
+        );
+
+        self.self_client_state_type_url().set(client_state_type_url);
+        self.self_consensus_state_type_url()
+            .set(consensus_state_type_url);
+    }
+
     #[only_owner]
     #[endpoint(registerClient)]
     fn register_client(&self, client_type: ClientType<Self::Api>, client: ManagedAddress) {
@@ -49,4 +68,68 @@ pub trait HostConfigModule:
 
         self.claim_port_capability(&port_id, &module);
     }
+
+    /// Wraps the module of a port with middlewares, listed from the one the handler calls down to the module
+    ///
+    /// Only the channels opened afterwards go through the new middlewares, as every channel keeps the stack it was
+    /// opened with. An empty list removes the middlewares.
+    #[only_owner]
+    #[endpoint(setPortMiddlewares)]
+    fn set_port_middlewares(
+        &self,
+        port_id: PortId<Self::Api>,
+        middlewares: MultiValueEncoded<ManagedAddress>,
+    ) {
+        let module = self.lookup_module_by_port(&port_id);
+
+        let mut mapper = self.port_middlewares(&port_id);
+        mapper.clear();
+        for middleware in middlewares {
+            self.require_valid_address(&middleware);
+            require!(
+                middleware != module,
+                "Middleware cannot be the module of the port"
+            );
+            require!(
+                !mapper.iter().any(|other| other == middleware),
+                "Duplicate middleware"
+            );
+
+            mapper.push(&middleware);
+        }
+    }
+
+    /// Lets `module` bind the free ports starting with `prefix` itself, e.g. one port per interchain account owner
+    #[only_owner]
+    #[endpoint(bindPortPrefix)]
+    fn bind_port_prefix(&self, prefix: PortId<Self::Api>, module: ManagedAddress) {
+        require!(self.is_valid_port_id(&prefix), "Invalid Port ID");
+        self.require_valid_address(&module);
+
+        let mapper = self.port_prefix_capabilities(&prefix);
+        require!(mapper.is_empty(), "Port prefix already claimed");
+
+        mapper.set(module);
+    }
+
+    /// Is called by a module to bind a port starting with one of its prefixes
+    #[endpoint(bindPrefixedPort)]
+    fn bind_prefixed_port(&self, port_id: PortId<Self::Api>, prefix: PortId<Self::Api>) {
+        require!(self.is_valid_port_id(&port_id), "Invalid Port ID");
+
+        let prefix_len = prefix.len();
+        require!(
+            port_id.len() > prefix_len && port_id.copy_slice(0, prefix_len) == Some(prefix.clone()),
+            "Port does not start with prefix"
+        );
+
+        let caller = self.blockchain().get_caller();
+        let mapper = self.port_prefix_capabilities(&prefix);
+        require!(
+            !mapper.is_empty() && mapper.get() == caller,
+            "Not allowed to use this port prefix"
+        );
+
+        self.claim_port_capability(&port_id, &caller);
+    }
 }
@@ -23,6 +23,31 @@ pub trait ModuleManagerModule: crate::storage::StorageModule {
         mapper.set(address);
     }
 
+    /// Gives a new channel the middlewares of its port, and the channel capability to the top of the stack
+    ///
+    /// Returns the module the handler calls for the channel: the first middleware, or the module of the port.
+    fn claim_channel_stack(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+    ) -> ManagedAddress {
+        let port_middlewares = self.port_middlewares(port_id);
+        let module = if port_middlewares.is_empty() {
+            self.lookup_module_by_port(port_id)
+        } else {
+            port_middlewares.get(1)
+        };
+
+        let mut channel_middlewares = self.channel_middlewares(port_id, channel_id);
+        for middleware in port_middlewares.iter() {
+            channel_middlewares.push(&middleware);
+        }
+
+        self.claim_channel_capability(port_id, channel_id, &module);
+
+        module
+    }
+
     fn authenticate_channel_capability(
         &self,
         port_id: &PortId<Self::Api>,
@@ -38,7 +63,7 @@ pub trait ModuleManagerModule: crate::storage::StorageModule {
 
     fn lookup_module_by_port(&self, port_id: &PortId<Self::Api>) -> ManagedAddress {
         let mapper = self.port_capabilities(port_id);
-        require!(mapper.is_empty(), "Port not found");
+        require!(!mapper.is_empty(), "Port not found");
 
         mapper.get()
     }
@@ -54,24 +79,25 @@ pub trait ModuleManagerModule: crate::storage::StorageModule {
         mapper.get()
     }
 
-    // TODO: Do we even need something like this?
-
-    /*
-     function canTransitionToFlushComplete(
-        Channel.Order ordering,
-        string calldata portId,
-        string calldata channelId,
-        uint64 upgradeSequence
-    ) internal view virtual returns (bool) {
-        if (ordering == Channel.Order.ORDER_ORDERED) {
-            ChannelStorage storage channelStorage = getChannelStorage()[portId][channelId];
-            if (channelStorage.nextSequenceSend == channelStorage.nextSequenceAck) {
-                return true;
-            }
-        }
-        return lookupUpgradableModuleByPortUnchecked(portId).canTransitionToFlushComplete(
-            portId, channelId, upgradeSequence, _msgSender()
+    /// The middlewares of a channel followed by the module of its port, in the order the handler's callbacks go
+    /// through them
+    #[view(getChannelStack)]
+    fn get_channel_stack(
+        &self,
+        port_id: PortId<Self::Api>,
+        channel_id: ChannelId<Self::Api>,
+    ) -> MultiValueEncoded<ManagedAddress> {
+        require!(
+            !self.channel_capabilities(&port_id, &channel_id).is_empty(),
+            "Channel not found"
         );
+
+        let mut stack = MultiValueEncoded::new();
+        for middleware in self.channel_middlewares(&port_id, &channel_id).iter() {
+            stack.push(middleware);
+        }
+        stack.push(self.lookup_module_by_port(&port_id));
+
+        stack
     }
-     */
 }
@@ -47,6 +47,26 @@ pub trait StorageModule {
         self.commitments(commitment_hash).get()
     }
 
+    #[view(getConnection)]
+    fn get_connection(
+        &self,
+        connection_id: &ConnectionId<Self::Api>,
+    ) -> connection_end::Data<Self::Api> {
+        self.try_get_connection_info(connection_id)
+    }
+
+    /// Lets modules check a channel before sending on it, without failing if it doesn't exist
+    #[view(isChannelOpen)]
+    fn is_channel_open(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+    ) -> bool {
+        let mapper = self.channel_info(port_id, channel_id);
+
+        !mapper.is_empty() && matches!(mapper.get().channel.state, channel::State::Open)
+    }
+
     /// calculates the block delay based on the expected time per block
     fn calculate_block_delay(&self, time_delay: UnixTimestamp) -> UnixTimestamp {
         if time_delay == 0 {
@@ -58,7 +78,7 @@ pub trait StorageModule {
             return 0;
         }
 
-        (time_delay + host_info.expected_time_per_block - 1) / host_info.expected_time_per_block
+        time_delay.div_ceil(host_info.expected_time_per_block)
     }
 
     fn get_next_client_seq(&self) -> Sequence {
@@ -134,6 +154,25 @@ pub trait StorageModule {
     #[storage_mapper("portCap")]
     fn port_capabilities(&self, port_id: &PortId<Self::Api>) -> SingleValueMapper<ManagedAddress>;
 
+    #[storage_mapper("portPrefixCap")]
+    fn port_prefix_capabilities(
+        &self,
+        prefix: &PortId<Self::Api>,
+    ) -> SingleValueMapper<ManagedAddress>;
+
+    /// The middlewares wrapping the module of a port, from the one the handler calls down to the module
+    #[view(getPortMiddlewares)]
+    #[storage_mapper("portMiddlewares")]
+    fn port_middlewares(&self, port_id: &PortId<Self::Api>) -> VecMapper<ManagedAddress>;
+
+    /// The middlewares of the port when the channel was opened, which the channel keeps for its whole lifetime
+    #[storage_mapper("channelMiddlewares")]
+    fn channel_middlewares(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+    ) -> VecMapper<ManagedAddress>;
+
     #[storage_mapper("channelCap")]
     fn channel_capabilities(
         &self,
@@ -144,12 +183,27 @@ pub trait StorageModule {
     #[storage_mapper("hostInfo")]
     fn host_info(&self) -> SingleValueMapper<HostInfo>;
 
+    /// Type URL of the client state counterparties store for this chain, i.e. of its light client
+    #[storage_mapper("selfClientStateTypeUrl")]
+    fn self_client_state_type_url(&self) -> SingleValueMapper<ManagedBuffer>;
+
+    #[storage_mapper("selfConsensusStateTypeUrl")]
+    fn self_consensus_state_type_url(&self) -> SingleValueMapper<ManagedBuffer>;
+
     #[storage_mapper("connInfo")]
     fn connection_info(
         &self,
         connection_id: &ConnectionId<Self::Api>,
     ) -> SingleValueMapper<connection_end::Data<Self::Api>>;
 
+    /// The packets received on a channel which wait for the asynchronous acknowledgement of their module
+    #[storage_mapper("pendingAcks")]
+    fn pending_acks(
+        &self,
+        port_id: &PortId<Self::Api>,
+        channel_id: &ChannelId<Self::Api>,
+    ) -> UnorderedSetMapper<Sequence>;
+
     #[storage_mapper("channelInfo")]
     fn channel_info(
         &self,
@@ -2,9 +2,11 @@ use std::{cell::RefCell, rc::Rc};
 
 use host::{
     host_config::HostConfigModule,
+    module_manager::ModuleManagerModule,
     storage::{HostInfo, StorageModule},
 };
 use host_setup::HostSetup;
+use multiversx_sc::types::MultiValueEncoded;
 use multiversx_sc_scenario::{
     imports::BlockchainStateWrapper, managed_address, managed_buffer, rust_biguint, DebugApi,
 };
@@ -278,3 +280,221 @@ fn try_bind_invalid_port_id() {
         )
         .assert_user_error("Invalid Port ID");
 }
+
+#[test]
+fn bind_prefixed_port_ok_test() {
+    let host_setup = get_host_setup(host::contract_obj);
+    let my_cool_module = host_setup
+        .b_mock
+        .borrow_mut()
+        .create_user_account(&rust_biguint!(0));
+    host_setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &host_setup.host_owner,
+            &host_setup.host_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.bind_port_prefix(
+                    managed_buffer!(b"my-cool-"),
+                    managed_address!(&my_cool_module),
+                );
+            },
+        )
+        .assert_ok();
+
+    host_setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &my_cool_module,
+            &host_setup.host_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.bind_prefixed_port(
+                    managed_buffer!(b"my-cool-module"),
+                    managed_buffer!(b"my-cool-"),
+                );
+
+                assert_eq!(
+                    sc.port_capabilities(&managed_buffer!(b"my-cool-module"))
+                        .get(),
+                    managed_address!(&my_cool_module),
+                );
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn try_bind_prefixed_port_without_prefix_test() {
+    let host_setup = get_host_setup(host::contract_obj);
+    let my_cool_module = host_setup
+        .b_mock
+        .borrow_mut()
+        .create_user_account(&rust_biguint!(0));
+    let other_module = host_setup
+        .b_mock
+        .borrow_mut()
+        .create_user_account(&rust_biguint!(0));
+    host_setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &host_setup.host_owner,
+            &host_setup.host_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.bind_port_prefix(
+                    managed_buffer!(b"my-cool-"),
+                    managed_address!(&my_cool_module),
+                );
+            },
+        )
+        .assert_ok();
+
+    host_setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &my_cool_module,
+            &host_setup.host_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.bind_prefixed_port(
+                    managed_buffer!(b"other-module"),
+                    managed_buffer!(b"my-cool-"),
+                );
+            },
+        )
+        .assert_user_error("Port does not start with prefix");
+
+    host_setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &other_module,
+            &host_setup.host_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.bind_prefixed_port(
+                    managed_buffer!(b"my-cool-module"),
+                    managed_buffer!(b"my-cool-"),
+                );
+            },
+        )
+        .assert_user_error("Not allowed to use this port prefix");
+}
+
+#[test]
+fn set_port_middlewares_ok_test() {
+    let host_setup = get_host_setup(host::contract_obj);
+    let my_cool_module = host_setup
+        .b_mock
+        .borrow_mut()
+        .create_user_account(&rust_biguint!(0));
+    let first_middleware = host_setup
+        .b_mock
+        .borrow_mut()
+        .create_user_account(&rust_biguint!(0));
+    let second_middleware = host_setup
+        .b_mock
+        .borrow_mut()
+        .create_user_account(&rust_biguint!(0));
+    host_setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &host_setup.host_owner,
+            &host_setup.host_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let port_id = managed_buffer!(b"my-cool-module");
+                sc.bind_port(port_id.clone(), managed_address!(&my_cool_module));
+
+                let mut middlewares = MultiValueEncoded::new();
+                middlewares.push(managed_address!(&first_middleware));
+                middlewares.push(managed_address!(&second_middleware));
+                sc.set_port_middlewares(port_id.clone(), middlewares);
+
+                let channel_id = managed_buffer!(b"channel-0");
+                assert_eq!(
+                    sc.claim_channel_stack(&port_id, &channel_id),
+                    managed_address!(&first_middleware)
+                );
+                assert_eq!(
+                    sc.channel_capabilities(&port_id, &channel_id).get(),
+                    managed_address!(&first_middleware)
+                );
+
+                // the channel keeps its stack when the middlewares of the port change
+                sc.set_port_middlewares(port_id.clone(), MultiValueEncoded::new());
+                assert_eq!(
+                    sc.claim_channel_stack(&port_id, &managed_buffer!(b"channel-1")),
+                    managed_address!(&my_cool_module)
+                );
+
+                let stack = sc.get_channel_stack(port_id, channel_id).to_vec();
+                assert_eq!(stack.len(), 3);
+                assert_eq!(*stack.get(0), managed_address!(&first_middleware));
+                assert_eq!(*stack.get(1), managed_address!(&second_middleware));
+                assert_eq!(*stack.get(2), managed_address!(&my_cool_module));
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn try_set_port_middlewares_unbound_port_test() {
+    let host_setup = get_host_setup(host::contract_obj);
+    let middleware = host_setup
+        .b_mock
+        .borrow_mut()
+        .create_user_account(&rust_biguint!(0));
+    host_setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &host_setup.host_owner,
+            &host_setup.host_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut middlewares = MultiValueEncoded::new();
+                middlewares.push(managed_address!(&middleware));
+                sc.set_port_middlewares(managed_buffer!(b"my-cool-module"), middlewares);
+            },
+        )
+        .assert_user_error("Port not found");
+}
+
+#[test]
+fn try_set_duplicate_port_middlewares_test() {
+    let host_setup = get_host_setup(host::contract_obj);
+    let my_cool_module = host_setup
+        .b_mock
+        .borrow_mut()
+        .create_user_account(&rust_biguint!(0));
+    let middleware = host_setup
+        .b_mock
+        .borrow_mut()
+        .create_user_account(&rust_biguint!(0));
+    host_setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &host_setup.host_owner,
+            &host_setup.host_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let port_id = managed_buffer!(b"my-cool-module");
+                sc.bind_port(port_id.clone(), managed_address!(&my_cool_module));
+
+                let mut middlewares = MultiValueEncoded::new();
+                middlewares.push(managed_address!(&middleware));
+                middlewares.push(managed_address!(&middleware));
+                sc.set_port_middlewares(port_id, middlewares);
+            },
+        )
+        .assert_user_error("Duplicate middleware");
+}
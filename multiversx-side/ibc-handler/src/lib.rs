@@ -0,0 +1,45 @@
+#![no_std]
+
+//! The IBC handler, which deploys the host, client, connection and channel modules as a single contract,
+//! so that they all share the same client, connection, channel and commitment storage
+
+multiversx_sc::imports!();
+
+#[multiversx_sc::contract]
+pub trait IbcHandler:
+    client::create_and_update_clients::CreateAndUpdateClientsModule
+    + client::merkle_proof::MerkleProofModule
+    + client::events::EventsModule
+    + connection::common::conn_lib::ConnectionLibModule
+    + connection::common::verify_states::VerifyStatesModule
+    + connection::common::conn_internal::ConnectionInternalModule
+    + connection::conn_endpoints::ConnectionEndpointsModule
+    + connection::common::events::EventsModule
+    + channel::channel_libs::ibc_channel_lib::IbcChannelLibModule
+    + channel::channel_libs::events::EventsModule
+    + channel::handshake::chan_internal::ChannelInternalModule
+    + channel::handshake::chan_open::ChannelOpenModule
+    + channel::handshake::chan_close::ChannelCloseModule
+    + channel::handshake::upgrade_internal::UpgradeInternalModule
+    + channel::handshake::chan_upgrade::ChannelUpgradeModule
+    + channel::packet_handling::membership::MembershipModule
+    + channel::packet_handling::timeout::TimeoutModule
+    + channel::packet_handling::send::SendModule
+    + channel::packet_handling::receive::ReceiveModule
+    + channel::packet_handling::ack::AckModule
+    + channel::packet_handling::encoding::EncodingModule
+    + host::commitment::CommitmentModule
+    + host::host_config::HostConfigModule
+    + host::host_views::HostViewsModule
+    + host::module_manager::ModuleManagerModule
+    + host::storage::StorageModule
+    + common_modules::client_lib::ClientLibModule
+    + common_modules::host_lib::HostLibModule
+    + common_modules::utils::UtilsModule
+{
+    #[init]
+    fn init(&self) {}
+
+    #[upgrade]
+    fn upgrade(&self) {}
+}
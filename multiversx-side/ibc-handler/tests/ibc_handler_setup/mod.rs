@@ -0,0 +1,375 @@
+#![allow(dead_code)]
+
+use std::{cell::RefCell, rc::Rc};
+
+use common_types::{
+    channel_types::{channel, channel_counterparty, height, upgrade},
+    client_types::any,
+    connection_types::{connection_end, counterparty, merkle_prefix, version},
+    ConnectionHops, FeatureVec, VersionVec,
+};
+use host::{
+    host_config::HostConfigModule,
+    storage::{ChannelInfo, RecvStartSequence},
+};
+use ibc_handler::IbcHandler;
+use mock::{
+    mock_types::{client_state, consensus_state},
+    views::ViewsModule,
+    Mock,
+};
+use mock_app::{MockApp, MOCK_APP_VERSION};
+use multiversx_sc::{
+    codec::TopEncode,
+    types::{Address, ManagedBuffer},
+};
+use multiversx_sc_scenario::{
+    imports::{BlockchainStateWrapper, ContractObjWrapper},
+    managed_address, managed_buffer, rust_biguint, DebugApi,
+};
+
+pub static CLIENT_TYPE: &[u8] = b"mock-client";
+pub static CLIENT_ID: &[u8] = b"mock-client-0";
+pub static CONNECTION_ID: &[u8] = b"connection-0";
+pub static COUNTERPARTY_CLIENT_ID: &[u8] = b"counterparty-client-0";
+pub static COUNTERPARTY_CONNECTION_ID: &[u8] = b"connection-7";
+pub static PORT_ID: &[u8] = b"mock";
+pub static COUNTERPARTY_PORT_ID: &[u8] = b"counterparty-mock";
+pub static CHANNEL_ID: &[u8] = b"channel-0";
+pub static COUNTERPARTY_CHANNEL_ID: &[u8] = b"channel-5";
+pub static COMMITMENT_PREFIX: &[u8] = b"ibc";
+
+pub const PROOF_HEIGHT: height::Data = height::Data {
+    revision_number: 0,
+    revision_height: 10,
+};
+pub const CONSENSUS_TIMESTAMP: u64 = 1_000_000_000;
+
+pub struct IbcHandlerSetup<IbcHandlerObjBuilder, MockClientObjBuilder, MockAppObjBuilder>
+where
+    IbcHandlerObjBuilder: 'static + Copy + Fn() -> ibc_handler::ContractObj<DebugApi>,
+    MockClientObjBuilder: 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    MockAppObjBuilder: 'static + Copy + Fn() -> mock_app::ContractObj<DebugApi>,
+{
+    pub b_mock: Rc<RefCell<BlockchainStateWrapper>>,
+    pub owner: Address,
+    pub relayer: Address,
+    pub ibc_handler_wrapper:
+        ContractObjWrapper<ibc_handler::ContractObj<DebugApi>, IbcHandlerObjBuilder>,
+    pub mock_client_wrapper: ContractObjWrapper<mock::ContractObj<DebugApi>, MockClientObjBuilder>,
+    pub mock_app_wrapper: ContractObjWrapper<mock_app::ContractObj<DebugApi>, MockAppObjBuilder>,
+}
+
+impl<IbcHandlerObjBuilder, MockClientObjBuilder, MockAppObjBuilder>
+    IbcHandlerSetup<IbcHandlerObjBuilder, MockClientObjBuilder, MockAppObjBuilder>
+where
+    IbcHandlerObjBuilder: 'static + Copy + Fn() -> ibc_handler::ContractObj<DebugApi>,
+    MockClientObjBuilder: 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    MockAppObjBuilder: 'static + Copy + Fn() -> mock_app::ContractObj<DebugApi>,
+{
+    pub fn new(
+        b_mock: Rc<RefCell<BlockchainStateWrapper>>,
+        owner: &Address,
+        ibc_handler_builder: IbcHandlerObjBuilder,
+        mock_client_builder: MockClientObjBuilder,
+        mock_app_builder: MockAppObjBuilder,
+    ) -> Self {
+        let rust_zero = rust_biguint!(0u64);
+        let relayer = b_mock.borrow_mut().create_user_account(&rust_zero);
+        let ibc_handler_wrapper = b_mock.borrow_mut().create_sc_account(
+            &rust_zero,
+            Some(owner),
+            ibc_handler_builder,
+            "ibc handler",
+        );
+        let mock_client_wrapper = b_mock.borrow_mut().create_sc_account(
+            &rust_zero,
+            Some(owner),
+            mock_client_builder,
+            "mock client",
+        );
+        let mock_app_wrapper = b_mock.borrow_mut().create_sc_account(
+            &rust_zero,
+            Some(owner),
+            mock_app_builder,
+            "mock app",
+        );
+
+        let ibc_handler_address = ibc_handler_wrapper.address_ref().clone();
+        let mock_client_address = mock_client_wrapper.address_ref().clone();
+        let mock_app_address = mock_app_wrapper.address_ref().clone();
+
+        b_mock
+            .borrow_mut()
+            .execute_tx(owner, &mock_client_wrapper, &rust_zero, |sc| {
+                sc.init(managed_address!(&ibc_handler_address));
+            })
+            .assert_ok();
+
+        b_mock
+            .borrow_mut()
+            .execute_tx(owner, &mock_app_wrapper, &rust_zero, |sc| {
+                sc.init(managed_address!(&ibc_handler_address));
+            })
+            .assert_ok();
+
+        b_mock
+            .borrow_mut()
+            .execute_tx(owner, &ibc_handler_wrapper, &rust_zero, |sc| {
+                sc.init();
+
+                sc.set_expected_time_per_block(6);
+                // the counterparty tracks this chain with a mock client too
+                sc.set_self_client_type_urls(
+                    managed_buffer!(client_state::TYPE_URL),
+                    managed_buffer!(consensus_state::TYPE_URL),
+                );
+                sc.register_client(
+                    managed_buffer!(CLIENT_TYPE),
+                    managed_address!(&mock_client_address),
+                );
+                sc.bind_port(
+                    managed_buffer!(PORT_ID),
+                    managed_address!(&mock_app_address),
+                );
+            })
+            .assert_ok();
+
+        Self {
+            b_mock,
+            owner: owner.clone(),
+            relayer,
+            ibc_handler_wrapper,
+            mock_client_wrapper,
+            mock_app_wrapper,
+        }
+    }
+
+    /// Builds the proof the mock client expects for `value` being stored under `path` on the counterparty
+    pub fn mock_proof(&self, path: &[u8], value: &[u8]) -> Vec<u8> {
+        self.mock_proof_in_store(COMMITMENT_PREFIX, path, value)
+    }
+
+    /// Builds the proof the mock client expects for `value` being stored under `path` in the `store` of the
+    /// counterparty
+    pub fn mock_proof_in_store(&self, store: &[u8], path: &[u8], value: &[u8]) -> Vec<u8> {
+        let mut proof = Vec::new();
+        self.b_mock
+            .borrow_mut()
+            .execute_query(&self.mock_client_wrapper, |sc| {
+                let hash = sc.encode_and_hash(
+                    &PROOF_HEIGHT,
+                    &managed_buffer!(store),
+                    &managed_buffer!(path),
+                    &managed_buffer!(value),
+                );
+                proof = hash.to_byte_array().to_vec();
+            })
+            .assert_ok();
+
+        proof
+    }
+}
+
+pub fn mock_client_state() -> Vec<u8> {
+    encode(&client_state::Data::new(PROOF_HEIGHT))
+}
+
+pub fn mock_consensus_state() -> Vec<u8> {
+    encode(&consensus_state::Data::new(CONSENSUS_TIMESTAMP))
+}
+
+/// The Any encoded client state the handler commits for a mock client
+pub fn committed_client_state(latest_height: height::Data) -> Vec<u8> {
+    let value = client_state::Data::new(latest_height).to_proto::<DebugApi>();
+    any_state(client_state::TYPE_URL, &value.to_boxed_bytes().into_vec())
+}
+
+/// The Any encoded consensus state the handler commits for a mock client
+pub fn committed_consensus_state(timestamp: u64) -> Vec<u8> {
+    let value = consensus_state::Data::new(timestamp).to_proto::<DebugApi>();
+    any_state(
+        consensus_state::TYPE_URL,
+        &value.to_boxed_bytes().into_vec(),
+    )
+}
+
+/// The Any encoded client or consensus state the counterparty stores for this chain
+pub fn any_state(type_url: &[u8], value: &[u8]) -> Vec<u8> {
+    any::Data::<DebugApi> {
+        type_url: managed_buffer!(type_url),
+        value: managed_buffer!(value),
+    }
+    .to_proto()
+    .to_boxed_bytes()
+    .into_vec()
+}
+
+pub fn ibc_version() -> version::Data<DebugApi> {
+    let mut features = FeatureVec::new();
+    features.push(managed_buffer!(channel::ORDERED));
+    features.push(managed_buffer!(channel::UNORDERED));
+
+    version::Data {
+        identifier: managed_buffer!(b"1"),
+        features,
+    }
+}
+
+/// The connection end the counterparty is expected to have stored, as seen from this chain
+pub fn counterparty_connection(state: connection_end::State) -> connection_end::Data<DebugApi> {
+    connection_end::Data {
+        client_id: managed_buffer!(COUNTERPARTY_CLIENT_ID),
+        versions: VersionVec::from_single_item(ibc_version()),
+        state,
+        counterparty: counterparty::Data {
+            client_id: managed_buffer!(CLIENT_ID),
+            connection_id: managed_buffer!(CONNECTION_ID),
+            prefix: merkle_prefix::Data {
+                key_prefix: managed_buffer!(COMMITMENT_PREFIX),
+            },
+        },
+        delay_period: 0,
+    }
+}
+
+/// The open connection of this chain to the counterparty
+pub fn open_connection() -> connection_end::Data<DebugApi> {
+    connection_end::Data {
+        client_id: managed_buffer!(CLIENT_ID),
+        versions: VersionVec::from_single_item(ibc_version()),
+        state: connection_end::State::Open,
+        counterparty: counterparty::Data {
+            client_id: managed_buffer!(COUNTERPARTY_CLIENT_ID),
+            connection_id: managed_buffer!(COUNTERPARTY_CONNECTION_ID),
+            prefix: merkle_prefix::Data {
+                key_prefix: managed_buffer!(COMMITMENT_PREFIX),
+            },
+        },
+        delay_period: 0,
+    }
+}
+
+/// The open channel of this chain to the counterparty
+pub fn open_channel_info(ordering: channel::Order) -> ChannelInfo<DebugApi> {
+    ChannelInfo {
+        channel: channel::Data {
+            state: channel::State::Open,
+            ordering,
+            counterparty: channel_counterparty::Data {
+                port_id: managed_buffer!(COUNTERPARTY_PORT_ID),
+                channel_id: managed_buffer!(COUNTERPARTY_CHANNEL_ID),
+            },
+            connection_hops: ConnectionHops::from_single_item(managed_buffer!(CONNECTION_ID)),
+            version: managed_buffer!(MOCK_APP_VERSION),
+            upgrade_sequence: 0,
+        },
+        next_seq_send: 1,
+        next_seq_recv: 1,
+        next_seq_ack: 1,
+        upgrade: upgrade::Data::default(),
+        latest_error_rec_seq: 0,
+        recv_start_seq: RecvStartSequence {
+            seq: 0,
+            prev_seq: 0,
+        },
+        ack_start_seq: 0,
+    }
+}
+
+/// The channel end the counterparty is expected to have stored, as seen from this chain
+pub fn counterparty_channel(
+    state: channel::State,
+    ordering: channel::Order,
+) -> channel::Data<DebugApi> {
+    channel::Data {
+        state,
+        ordering,
+        counterparty: channel_counterparty::Data {
+            port_id: managed_buffer!(PORT_ID),
+            channel_id: managed_buffer!(CHANNEL_ID),
+        },
+        connection_hops: ConnectionHops::from_single_item(managed_buffer!(
+            COUNTERPARTY_CONNECTION_ID
+        )),
+        version: managed_buffer!(MOCK_APP_VERSION),
+        upgrade_sequence: 0,
+    }
+}
+
+pub fn client_state_path(client_id: &[u8]) -> Vec<u8> {
+    format!("clients/{}/clientState", as_str(client_id)).into_bytes()
+}
+
+pub fn consensus_state_path(client_id: &[u8], height: height::Data) -> Vec<u8> {
+    format!(
+        "clients/{}/consensusStates/{}-{}",
+        as_str(client_id),
+        height.revision_number,
+        height.revision_height
+    )
+    .into_bytes()
+}
+
+pub fn connection_path(connection_id: &[u8]) -> Vec<u8> {
+    format!("connections/{}", as_str(connection_id)).into_bytes()
+}
+
+pub fn channel_path(port_id: &[u8], channel_id: &[u8]) -> Vec<u8> {
+    format!(
+        "channelEnds/ports/{}/channels/{}",
+        as_str(port_id),
+        as_str(channel_id)
+    )
+    .into_bytes()
+}
+
+pub fn packet_commitment_path(port_id: &[u8], channel_id: &[u8], seq: u64) -> Vec<u8> {
+    format!(
+        "commitments/ports/{}/channels/{}/sequences/{}",
+        as_str(port_id),
+        as_str(channel_id),
+        seq
+    )
+    .into_bytes()
+}
+
+pub fn ack_path(port_id: &[u8], channel_id: &[u8], seq: u64) -> Vec<u8> {
+    format!(
+        "acks/ports/{}/channels/{}/sequences/{}",
+        as_str(port_id),
+        as_str(channel_id),
+        seq
+    )
+    .into_bytes()
+}
+
+pub fn receipt_path(port_id: &[u8], channel_id: &[u8], seq: u64) -> Vec<u8> {
+    format!(
+        "receipts/ports/{}/channels/{}/sequences/{}",
+        as_str(port_id),
+        as_str(channel_id),
+        seq
+    )
+    .into_bytes()
+}
+
+pub fn next_seq_recv_path(port_id: &[u8], channel_id: &[u8]) -> Vec<u8> {
+    format!(
+        "nextSequenceRecv/ports/{}/channels/{}",
+        as_str(port_id),
+        as_str(channel_id)
+    )
+    .into_bytes()
+}
+
+pub fn encode<T: TopEncode>(value: &T) -> Vec<u8> {
+    let mut encoded = ManagedBuffer::<DebugApi>::new();
+    let _ = value.top_encode(&mut encoded);
+
+    encoded.to_boxed_bytes().into_vec()
+}
+
+fn as_str(identifier: &[u8]) -> &str {
+    std::str::from_utf8(identifier).unwrap()
+}
@@ -0,0 +1,1510 @@
+use std::{cell::RefCell, rc::Rc};
+
+use ::channel::{
+    channel_libs::{
+        handshake_types::{MsgChannelOpenAck, MsgChannelOpenInit},
+        packet_types::{
+            MsgPacketAcknowledgement, MsgPacketRecv, MsgTimeoutOnClose, MsgTimeoutPacket, Packet,
+        },
+    },
+    handshake::chan_open::ChannelOpenModule,
+    packet_handling::{
+        ack::AckModule, encoding::EncodingModule, receive::ReceiveModule, send::SendModule,
+        timeout::TimeoutModule,
+    },
+};
+use client::create_and_update_clients::{
+    CreateAndUpdateClientsModule, MsgCreateClient, MsgSubmitMisbehaviour, MsgUpdateClient,
+    MsgUpgradeClient,
+};
+use client_common::{ClientStatus, UPGRADE_STORE_KEY};
+use common_types::{
+    channel_types::{channel, channel_counterparty, height, upgrade_fields},
+    connection_types::{connection_end, counterparty, merkle_prefix, version},
+    ConnectionHops, FeatureVec,
+};
+use connection::{
+    common::conn_types::{MsgConnectionOpenAck, MsgConnectionOpenInit},
+    conn_endpoints::ConnectionEndpointsModule,
+};
+use host::{
+    commitment::CommitmentModule, module_manager::ModuleManagerModule, storage::StorageModule,
+};
+use ibc_handler_setup::*;
+use mock::{
+    client_logic::ClientLogicModule,
+    mock_types::{client_state, consensus_state, header, misbehaviour},
+    views::ViewsModule,
+};
+use mock_app::{MockApp, ASYNC_ACK_DATA, MOCK_APP_VERSION, SUCCESSFUL_ACK};
+use multiversx_sc::{contract_base::ContractBase, types::ManagedBuffer};
+use multiversx_sc_scenario::{
+    imports::{BlockchainStateWrapper, TxResult},
+    managed_buffer, rust_biguint, DebugApi,
+};
+
+pub mod ibc_handler_setup;
+
+const TIMEOUT_HEIGHT: height::Data = height::Data {
+    revision_number: 0,
+    revision_height: 1_000,
+};
+
+fn get_ibc_handler_setup() -> IbcHandlerSetup<
+    impl 'static + Copy + Fn() -> ibc_handler::ContractObj<DebugApi>,
+    impl 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    impl 'static + Copy + Fn() -> mock_app::ContractObj<DebugApi>,
+> {
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_biguint!(0));
+    IbcHandlerSetup::new(
+        Rc::new(RefCell::new(b_mock)),
+        &owner,
+        ibc_handler::contract_obj,
+        mock::contract_obj,
+        mock_app::contract_obj,
+    )
+}
+
+fn create_client<IbcHandlerObjBuilder, MockClientObjBuilder, MockAppObjBuilder>(
+    setup: &IbcHandlerSetup<IbcHandlerObjBuilder, MockClientObjBuilder, MockAppObjBuilder>,
+) where
+    IbcHandlerObjBuilder: 'static + Copy + Fn() -> ibc_handler::ContractObj<DebugApi>,
+    MockClientObjBuilder: 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    MockAppObjBuilder: 'static + Copy + Fn() -> mock_app::ContractObj<DebugApi>,
+{
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &setup.relayer,
+            &setup.ibc_handler_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let client_id = sc.create_client(MsgCreateClient {
+                    client_type: managed_buffer!(CLIENT_TYPE),
+                    encoded_client_state: managed_buffer!(&mock_client_state()),
+                    encoded_consensus_state: managed_buffer!(&mock_consensus_state()),
+                });
+                assert_eq!(client_id, managed_buffer!(CLIENT_ID));
+            },
+        )
+        .assert_ok();
+}
+
+fn test_packet(
+    seq: u64,
+    src: (&[u8], &[u8]),
+    dest: (&[u8], &[u8]),
+    data: &[u8],
+) -> Packet<DebugApi> {
+    Packet {
+        seq,
+        src_port: managed_buffer!(src.0),
+        src_channel: managed_buffer!(src.1),
+        dest_port: managed_buffer!(dest.0),
+        dest_channel: managed_buffer!(dest.1),
+        data: managed_buffer!(data),
+        timeout_height: TIMEOUT_HEIGHT,
+        timeout_timestamp: 0,
+    }
+}
+
+/// Opens `CHANNEL_ID` with the given ordering, skipping the handshakes
+fn open_channel<IbcHandlerObjBuilder, MockClientObjBuilder, MockAppObjBuilder>(
+    setup: &IbcHandlerSetup<IbcHandlerObjBuilder, MockClientObjBuilder, MockAppObjBuilder>,
+    ordering: channel::Order,
+) where
+    IbcHandlerObjBuilder: 'static + Copy + Fn() -> ibc_handler::ContractObj<DebugApi>,
+    MockClientObjBuilder: 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    MockAppObjBuilder: 'static + Copy + Fn() -> mock_app::ContractObj<DebugApi>,
+{
+    create_client(setup);
+
+    let owner = setup.owner.clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &owner,
+            &setup.ibc_handler_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.connection_info(&managed_buffer!(CONNECTION_ID))
+                    .set(open_connection());
+
+                let port_id = managed_buffer!(PORT_ID);
+                let channel_id = managed_buffer!(CHANNEL_ID);
+                sc.claim_channel_stack(&port_id, &channel_id);
+                sc.channel_info(&port_id, &channel_id)
+                    .set(open_channel_info(ordering));
+            },
+        )
+        .assert_ok();
+}
+
+/// Delivers a packet of the counterparty to `CHANNEL_ID`
+fn recv_packet<IbcHandlerObjBuilder, MockClientObjBuilder, MockAppObjBuilder>(
+    setup: &IbcHandlerSetup<IbcHandlerObjBuilder, MockClientObjBuilder, MockAppObjBuilder>,
+    seq: u64,
+    data: &[u8],
+) where
+    IbcHandlerObjBuilder: 'static + Copy + Fn() -> ibc_handler::ContractObj<DebugApi>,
+    MockClientObjBuilder: 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    MockAppObjBuilder: 'static + Copy + Fn() -> mock_app::ContractObj<DebugApi>,
+{
+    let mut packet_commitment = Vec::new();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.ibc_handler_wrapper, |sc| {
+            packet_commitment = sc
+                .encode_and_hash(TIMEOUT_HEIGHT, 0, &managed_buffer!(data))
+                .to_byte_array()
+                .to_vec();
+        })
+        .assert_ok();
+    let proof = setup.mock_proof(
+        &packet_commitment_path(COUNTERPARTY_PORT_ID, COUNTERPARTY_CHANNEL_ID, seq),
+        &packet_commitment,
+    );
+
+    let relayer = setup.relayer.clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &relayer,
+            &setup.ibc_handler_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.recieve_packet(MsgPacketRecv {
+                    packet: test_packet(
+                        seq,
+                        (COUNTERPARTY_PORT_ID, COUNTERPARTY_CHANNEL_ID),
+                        (PORT_ID, CHANNEL_ID),
+                        data,
+                    ),
+                    proof: ManagedBuffer::new_from_bytes(&proof),
+                    proof_height: PROOF_HEIGHT,
+                });
+            },
+        )
+        .assert_ok();
+}
+
+/// A packet sent on `CHANNEL_ID` which timed out at `PROOF_HEIGHT`, with its commitment stored as `sendPacket` does
+fn commit_timed_out_packet<IbcHandlerObjBuilder, MockClientObjBuilder, MockAppObjBuilder>(
+    setup: &IbcHandlerSetup<IbcHandlerObjBuilder, MockClientObjBuilder, MockAppObjBuilder>,
+    seq: u64,
+) where
+    IbcHandlerObjBuilder: 'static + Copy + Fn() -> ibc_handler::ContractObj<DebugApi>,
+    MockClientObjBuilder: 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    MockAppObjBuilder: 'static + Copy + Fn() -> mock_app::ContractObj<DebugApi>,
+{
+    let owner = setup.owner.clone();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &owner,
+            &setup.ibc_handler_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let packet = timed_out_packet(seq);
+                let comm_key =
+                    sc.get_packet_commitment_key(&packet.src_port, &packet.src_channel, packet.seq);
+                sc.commitments(&comm_key)
+                    .set(sc.get_packet_commitment(&packet));
+            },
+        )
+        .assert_ok();
+}
+
+fn timed_out_packet(seq: u64) -> Packet<DebugApi> {
+    Packet {
+        timeout_height: PROOF_HEIGHT,
+        ..test_packet(
+            seq,
+            (PORT_ID, CHANNEL_ID),
+            (COUNTERPARTY_PORT_ID, COUNTERPARTY_CHANNEL_ID),
+            b"outgoing data",
+        )
+    }
+}
+
+/// The proof that the counterparty did not receive the packet: its next receive sequence on ORDERED channels, the
+/// absence of its receipt on UNORDERED ones
+fn unreceived_proof<IbcHandlerObjBuilder, MockClientObjBuilder, MockAppObjBuilder>(
+    setup: &IbcHandlerSetup<IbcHandlerObjBuilder, MockClientObjBuilder, MockAppObjBuilder>,
+    ordering: channel::Order,
+    seq: u64,
+) -> Vec<u8>
+where
+    IbcHandlerObjBuilder: 'static + Copy + Fn() -> ibc_handler::ContractObj<DebugApi>,
+    MockClientObjBuilder: 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    MockAppObjBuilder: 'static + Copy + Fn() -> mock_app::ContractObj<DebugApi>,
+{
+    match ordering {
+        channel::Order::Ordered => setup.mock_proof(
+            &next_seq_recv_path(COUNTERPARTY_PORT_ID, COUNTERPARTY_CHANNEL_ID),
+            &seq.to_be_bytes(),
+        ),
+        _ => setup.mock_proof(
+            &receipt_path(COUNTERPARTY_PORT_ID, COUNTERPARTY_CHANNEL_ID, seq),
+            &[],
+        ),
+    }
+}
+
+/// Checks the outcome of a timeout on `CHANNEL_ID`: the packet commitment is gone and ORDERED channels are closed,
+/// with their commitment updated and the app notified
+fn check_timed_out<IbcHandlerObjBuilder, MockClientObjBuilder, MockAppObjBuilder>(
+    setup: &IbcHandlerSetup<IbcHandlerObjBuilder, MockClientObjBuilder, MockAppObjBuilder>,
+    ordering: channel::Order,
+    seq: u64,
+) where
+    IbcHandlerObjBuilder: 'static + Copy + Fn() -> ibc_handler::ContractObj<DebugApi>,
+    MockClientObjBuilder: 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    MockAppObjBuilder: 'static + Copy + Fn() -> mock_app::ContractObj<DebugApi>,
+{
+    let ordered = matches!(ordering, channel::Order::Ordered);
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.ibc_handler_wrapper, |sc| {
+            let port_id = managed_buffer!(PORT_ID);
+            let channel_id = managed_buffer!(CHANNEL_ID);
+            let comm_key = sc.get_packet_commitment_key(&port_id, &channel_id, seq);
+            assert!(sc.commitments(&comm_key).is_empty());
+
+            let channel = sc.channel_info(&port_id, &channel_id).get().channel;
+            if ordered {
+                assert!(matches!(channel.state, channel::State::Closed));
+
+                let channel_key = sc.get_channel_commitment_key(&port_id, &channel_id);
+                assert_eq!(
+                    sc.commitments(&channel_key).get(),
+                    sc.crypto().keccak256(channel.to_proto())
+                );
+            } else {
+                assert!(matches!(channel.state, channel::State::Open));
+            }
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.mock_app_wrapper, |sc| {
+            let callbacks = sc.callbacks(&managed_buffer!(PORT_ID), &managed_buffer!(CHANNEL_ID));
+            assert_eq!(callbacks.get(1), managed_buffer!(b"onTimeoutPacket"));
+            if ordered {
+                assert_eq!(callbacks.len(), 2);
+                assert_eq!(callbacks.get(2), managed_buffer!(b"onChanCloseConfirm"));
+            } else {
+                assert_eq!(callbacks.len(), 1);
+            }
+        })
+        .assert_ok();
+}
+
+/// Checks the acknowledgement written for a packet received on `CHANNEL_ID`, and whether it is still pending
+fn check_ack<IbcHandlerObjBuilder, MockClientObjBuilder, MockAppObjBuilder>(
+    setup: &IbcHandlerSetup<IbcHandlerObjBuilder, MockClientObjBuilder, MockAppObjBuilder>,
+    seq: u64,
+    expected_ack: Option<&[u8]>,
+    expected_pending: bool,
+) where
+    IbcHandlerObjBuilder: 'static + Copy + Fn() -> ibc_handler::ContractObj<DebugApi>,
+    MockClientObjBuilder: 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    MockAppObjBuilder: 'static + Copy + Fn() -> mock_app::ContractObj<DebugApi>,
+{
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.ibc_handler_wrapper, |sc| {
+            let ack_key = sc.get_packet_acknowledgement_commitment_key(
+                &managed_buffer!(PORT_ID),
+                &managed_buffer!(CHANNEL_ID),
+                seq,
+            );
+            match expected_ack {
+                Some(ack) => {
+                    let ack_hash = sc.encode_ack(&managed_buffer!(ack));
+                    assert_eq!(
+                        sc.commitments(&ack_key).get(),
+                        sc.crypto().keccak256(ack_hash.as_managed_buffer())
+                    );
+                }
+                None => assert!(sc.commitments(&ack_key).is_empty()),
+            }
+            assert_eq!(
+                sc.is_ack_pending(managed_buffer!(PORT_ID), managed_buffer!(CHANNEL_ID), seq),
+                expected_pending
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn create_client_test() {
+    let setup = get_ibc_handler_setup();
+    create_client(&setup);
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.ibc_handler_wrapper, |sc| {
+            let client_id = managed_buffer!(CLIENT_ID);
+            let client_info = sc.client_info(&client_id).get();
+            assert_eq!(client_info.client_type, managed_buffer!(CLIENT_TYPE));
+
+            let client_key = sc.get_client_state_commitment_key(&client_id);
+            assert_eq!(
+                sc.commitments(&client_key).get(),
+                sc.crypto()
+                    .keccak256(managed_buffer!(&committed_client_state(PROOF_HEIGHT)))
+            );
+
+            let consensus_key = sc.get_consensus_state_commitment_key(
+                &client_id,
+                PROOF_HEIGHT.revision_number,
+                PROOF_HEIGHT.revision_height,
+            );
+            assert_eq!(
+                sc.commitments(&consensus_key).get(),
+                sc.crypto()
+                    .keccak256(managed_buffer!(&committed_consensus_state(
+                        CONSENSUS_TIMESTAMP
+                    )))
+            );
+        })
+        .assert_ok();
+
+    // only registered client types can be created
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &setup.relayer,
+            &setup.ibc_handler_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.create_client(MsgCreateClient {
+                    client_type: managed_buffer!(b"unknown-client"),
+                    encoded_client_state: managed_buffer!(&mock_client_state()),
+                    encoded_consensus_state: managed_buffer!(&mock_consensus_state()),
+                });
+            },
+        )
+        .assert_user_error("Client not registered");
+}
+
+#[test]
+fn update_client_test() {
+    let setup = get_ibc_handler_setup();
+    let relayer = setup.relayer.clone();
+    create_client(&setup);
+
+    let update_client = |header: header::Data| {
+        setup.b_mock.borrow_mut().execute_tx(
+            &relayer,
+            &setup.ibc_handler_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.update_client(MsgUpdateClient {
+                    client_id: managed_buffer!(CLIENT_ID),
+                    encoded_client_message: managed_buffer!(&encode(&header)),
+                });
+            },
+        )
+    };
+    let updated_height = height::Data {
+        revision_number: 0,
+        revision_height: PROOF_HEIGHT.revision_height + 1,
+    };
+    update_client(header::Data {
+        height: updated_height,
+        timestamp: CONSENSUS_TIMESTAMP * 2,
+    })
+    .assert_ok();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.ibc_handler_wrapper, |sc| {
+            let client_id = managed_buffer!(CLIENT_ID);
+            let client_state_key = sc.get_client_state_commitment_key(&client_id);
+            assert_eq!(
+                sc.commitments(&client_state_key).get(),
+                sc.crypto()
+                    .keccak256(managed_buffer!(&committed_client_state(updated_height)))
+            );
+
+            let consensus_state_key = sc.get_consensus_state_commitment_key(
+                &client_id,
+                updated_height.revision_number,
+                updated_height.revision_height,
+            );
+            assert_eq!(
+                sc.commitments(&consensus_state_key).get(),
+                sc.crypto()
+                    .keccak256(managed_buffer!(&committed_consensus_state(
+                        CONSENSUS_TIMESTAMP * 2
+                    )))
+            );
+
+            // stale, so the client state is seen to be committed again below
+            sc.commitments(&client_state_key).clear();
+        })
+        .assert_ok();
+
+    // a conflicting header freezes the client without updating any height, its client state is still committed
+    update_client(header::Data {
+        height: updated_height,
+        timestamp: CONSENSUS_TIMESTAMP * 3,
+    })
+    .assert_ok();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.mock_client_wrapper, |sc| {
+            assert!(sc.get_status(&managed_buffer!(CLIENT_ID)) == ClientStatus::Frozen);
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.ibc_handler_wrapper, |sc| {
+            let client_state_key = sc.get_client_state_commitment_key(&managed_buffer!(CLIENT_ID));
+            assert_eq!(
+                sc.commitments(&client_state_key).get(),
+                sc.crypto()
+                    .keccak256(managed_buffer!(&committed_client_state(updated_height)))
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn client_connection_channel_packet_test() {
+    let setup = get_ibc_handler_setup();
+    let relayer = setup.relayer.clone();
+    let mock_app_address = setup.mock_app_wrapper.address_ref().clone();
+    DebugApi::dummy();
+
+    create_client(&setup);
+
+    // connection handshake, as chain A
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &relayer,
+            &setup.ibc_handler_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let connection_id = sc.connection_open_init(MsgConnectionOpenInit {
+                    client_id: managed_buffer!(CLIENT_ID),
+                    counterparty: counterparty::Data {
+                        client_id: managed_buffer!(COUNTERPARTY_CLIENT_ID),
+                        connection_id: managed_buffer!(COUNTERPARTY_CONNECTION_ID),
+                        prefix: merkle_prefix::Data {
+                            key_prefix: managed_buffer!(COMMITMENT_PREFIX),
+                        },
+                    },
+                    version: version::Data {
+                        identifier: managed_buffer!(b"1"),
+                        features: FeatureVec::new(),
+                    },
+                    delay_period: 0,
+                });
+                assert_eq!(connection_id, managed_buffer!(CONNECTION_ID));
+            },
+        )
+        .assert_ok();
+
+    let proof_try = setup.mock_proof(
+        &connection_path(COUNTERPARTY_CONNECTION_ID),
+        &counterparty_connection(connection_end::State::TryOpen)
+            .to_proto()
+            .to_boxed_bytes()
+            .into_vec(),
+    );
+    let open_ack = |client_state_bytes: &[u8], consensus_state_bytes: &[u8]| -> TxResult {
+        let proof_client = setup.mock_proof(
+            &client_state_path(COUNTERPARTY_CLIENT_ID),
+            client_state_bytes,
+        );
+        let proof_consensus = setup.mock_proof(
+            &consensus_state_path(COUNTERPARTY_CLIENT_ID, PROOF_HEIGHT),
+            consensus_state_bytes,
+        );
+        setup.b_mock.borrow_mut().execute_tx(
+            &relayer,
+            &setup.ibc_handler_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.connection_open_ack(MsgConnectionOpenAck {
+                    connection_id: managed_buffer!(CONNECTION_ID),
+                    client_state_bytes: managed_buffer!(client_state_bytes),
+                    version: ibc_version(),
+                    counterparty_connection_id: managed_buffer!(COUNTERPARTY_CONNECTION_ID),
+                    proof_try: ManagedBuffer::new_from_bytes(&proof_try),
+                    proof_client: ManagedBuffer::new_from_bytes(&proof_client),
+                    proof_consensus: ManagedBuffer::new_from_bytes(&proof_consensus),
+                    proof_height: PROOF_HEIGHT,
+                    consensus_height: PROOF_HEIGHT,
+                    host_consensus_state_proof: managed_buffer!(consensus_state_bytes),
+                });
+            },
+        )
+    };
+
+    let client_state_bytes = any_state(client_state::TYPE_URL, &mock_client_state());
+    let consensus_state_bytes = any_state(consensus_state::TYPE_URL, &mock_consensus_state());
+
+    // the counterparty can't have a consensus state of a future block of this chain
+    open_ack(&client_state_bytes, &consensus_state_bytes)
+        .assert_user_error("Invalid consensus height");
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .set_block_nonce(PROOF_HEIGHT.revision_height + 1);
+
+    // the counterparty must track this chain with a client of the expected type
+    let other_type_url = b"/ibc.lightclients.tendermint.v1.ClientState";
+    open_ack(
+        &any_state(other_type_url, &mock_client_state()),
+        &consensus_state_bytes,
+    )
+    .assert_user_error("Invalid client state type");
+    open_ack(
+        &client_state_bytes,
+        &any_state(other_type_url, &mock_consensus_state()),
+    )
+    .assert_user_error("Invalid consensus state type");
+
+    open_ack(&client_state_bytes, &consensus_state_bytes).assert_ok();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.ibc_handler_wrapper, |sc| {
+            let connection_info = sc.connection_info(&managed_buffer!(CONNECTION_ID)).get();
+            assert!(matches!(connection_info.state, connection_end::State::Open));
+        })
+        .assert_ok();
+
+    // channel handshake, as chain A
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &relayer,
+            &setup.ibc_handler_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let (channel_id, version) = sc
+                    .channel_open_init(MsgChannelOpenInit {
+                        port_id: managed_buffer!(PORT_ID),
+                        channel: channel::Data {
+                            state: channel::State::Init,
+                            ordering: channel::Order::Unordered,
+                            counterparty: channel_counterparty::Data {
+                                port_id: managed_buffer!(COUNTERPARTY_PORT_ID),
+                                channel_id: ManagedBuffer::new(),
+                            },
+                            connection_hops: ConnectionHops::from_single_item(managed_buffer!(
+                                CONNECTION_ID
+                            )),
+                            version: ManagedBuffer::new(),
+                            upgrade_sequence: 0,
+                        },
+                    })
+                    .into_tuple();
+                assert_eq!(channel_id, managed_buffer!(CHANNEL_ID));
+                assert_eq!(version, managed_buffer!(MOCK_APP_VERSION));
+            },
+        )
+        .assert_ok();
+
+    let proof_try = setup.mock_proof(
+        &channel_path(COUNTERPARTY_PORT_ID, COUNTERPARTY_CHANNEL_ID),
+        &counterparty_channel(channel::State::TryOpen, channel::Order::Unordered)
+            .to_proto()
+            .to_boxed_bytes()
+            .into_vec(),
+    );
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &relayer,
+            &setup.ibc_handler_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.channel_open_ack(MsgChannelOpenAck {
+                    port_id: managed_buffer!(PORT_ID),
+                    channel_id: managed_buffer!(CHANNEL_ID),
+                    counterparty_version: managed_buffer!(MOCK_APP_VERSION),
+                    counterparty_channel_id: managed_buffer!(COUNTERPARTY_CHANNEL_ID),
+                    proof_try: ManagedBuffer::new_from_bytes(&proof_try),
+                    proof_height: PROOF_HEIGHT,
+                });
+
+                let channel_info = sc
+                    .channel_info(&managed_buffer!(PORT_ID), &managed_buffer!(CHANNEL_ID))
+                    .get();
+                assert!(matches!(channel_info.channel.state, channel::State::Open));
+            },
+        )
+        .assert_ok();
+
+    // the app sends a packet
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &mock_app_address,
+            &setup.ibc_handler_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let seq = sc.send_packet(
+                    managed_buffer!(PORT_ID),
+                    managed_buffer!(CHANNEL_ID),
+                    TIMEOUT_HEIGHT,
+                    0,
+                    managed_buffer!(b"outgoing data"),
+                );
+                assert_eq!(seq, 1);
+
+                let comm_key = sc.get_packet_commitment_key(
+                    &managed_buffer!(PORT_ID),
+                    &managed_buffer!(CHANNEL_ID),
+                    seq,
+                );
+                assert_eq!(
+                    sc.commitments(&comm_key).get(),
+                    sc.encode_and_hash_twice(TIMEOUT_HEIGHT, 0, &managed_buffer!(b"outgoing data"))
+                );
+            },
+        )
+        .assert_ok();
+
+    // the counterparty sends a packet, which is received and acknowledged by the app
+    let mut packet_commitment = Vec::new();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.ibc_handler_wrapper, |sc| {
+            packet_commitment = sc
+                .encode_and_hash(TIMEOUT_HEIGHT, 0, &managed_buffer!(b"incoming data"))
+                .to_byte_array()
+                .to_vec();
+        })
+        .assert_ok();
+    let proof = setup.mock_proof(
+        &packet_commitment_path(COUNTERPARTY_PORT_ID, COUNTERPARTY_CHANNEL_ID, 1),
+        &packet_commitment,
+    );
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &relayer,
+            &setup.ibc_handler_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.recieve_packet(MsgPacketRecv {
+                    packet: test_packet(
+                        1,
+                        (COUNTERPARTY_PORT_ID, COUNTERPARTY_CHANNEL_ID),
+                        (PORT_ID, CHANNEL_ID),
+                        b"incoming data",
+                    ),
+                    proof: ManagedBuffer::new_from_bytes(&proof),
+                    proof_height: PROOF_HEIGHT,
+                });
+
+                let ack_key = sc.get_packet_acknowledgement_commitment_key(
+                    &managed_buffer!(PORT_ID),
+                    &managed_buffer!(CHANNEL_ID),
+                    1,
+                );
+                let ack_hash = sc.encode_ack(&managed_buffer!(SUCCESSFUL_ACK));
+                assert_eq!(
+                    sc.commitments(&ack_key).get(),
+                    sc.crypto().keccak256(ack_hash.as_managed_buffer())
+                );
+            },
+        )
+        .assert_ok();
+
+    // the counterparty acknowledges the sent packet
+    let mut ack_commitment = Vec::new();
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.ibc_handler_wrapper, |sc| {
+            ack_commitment = sc
+                .encode_ack(&managed_buffer!(SUCCESSFUL_ACK))
+                .to_byte_array()
+                .to_vec();
+        })
+        .assert_ok();
+    let proof = setup.mock_proof(
+        &ack_path(COUNTERPARTY_PORT_ID, COUNTERPARTY_CHANNEL_ID, 1),
+        &ack_commitment,
+    );
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &relayer,
+            &setup.ibc_handler_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.ack_packet(MsgPacketAcknowledgement {
+                    packet: test_packet(
+                        1,
+                        (PORT_ID, CHANNEL_ID),
+                        (COUNTERPARTY_PORT_ID, COUNTERPARTY_CHANNEL_ID),
+                        b"outgoing data",
+                    ),
+                    ack: managed_buffer!(SUCCESSFUL_ACK),
+                    proof: ManagedBuffer::new_from_bytes(&proof),
+                    proof_height: PROOF_HEIGHT,
+                });
+
+                let comm_key = sc.get_packet_commitment_key(
+                    &managed_buffer!(PORT_ID),
+                    &managed_buffer!(CHANNEL_ID),
+                    1,
+                );
+                assert!(sc.commitments(&comm_key).is_empty());
+            },
+        )
+        .assert_ok();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.mock_app_wrapper, |sc| {
+            let callbacks = sc.callbacks(&managed_buffer!(PORT_ID), &managed_buffer!(CHANNEL_ID));
+            assert_eq!(callbacks.len(), 4);
+            assert_eq!(callbacks.get(1), managed_buffer!(b"onChanOpenInit"));
+            assert_eq!(callbacks.get(2), managed_buffer!(b"onChanOpenAck"));
+            assert_eq!(callbacks.get(3), managed_buffer!(b"onRecvPacket"));
+            assert_eq!(
+                callbacks.get(4),
+                managed_buffer!(b"onAcknowledgementPacket")
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn async_ack_test() {
+    for ordering in [channel::Order::Unordered, channel::Order::Ordered] {
+        let setup = get_ibc_handler_setup();
+        let mock_app_address = setup.mock_app_wrapper.address_ref().clone();
+        open_channel(&setup, ordering);
+
+        recv_packet(&setup, 1, ASYNC_ACK_DATA);
+        recv_packet(&setup, 2, b"incoming data");
+        recv_packet(&setup, 3, ASYNC_ACK_DATA);
+
+        check_ack(&setup, 1, None, true);
+        check_ack(&setup, 2, Some(SUCCESSFUL_ACK), false);
+        setup
+            .b_mock
+            .borrow_mut()
+            .execute_query(&setup.ibc_handler_wrapper, |sc| {
+                let mut pending_acks: Vec<u64> = sc
+                    .get_pending_acks(managed_buffer!(PORT_ID), managed_buffer!(CHANNEL_ID))
+                    .into_iter()
+                    .collect();
+                pending_acks.sort();
+                assert_eq!(pending_acks, [1, 3]);
+            })
+            .assert_ok();
+
+        // the app acknowledges the packets in any order
+        for seq in [3, 1] {
+            setup
+                .b_mock
+                .borrow_mut()
+                .execute_tx(
+                    &mock_app_address,
+                    &setup.ibc_handler_wrapper,
+                    &rust_biguint!(0),
+                    |sc| {
+                        sc.write_ack_endpoint(
+                            managed_buffer!(PORT_ID),
+                            managed_buffer!(CHANNEL_ID),
+                            seq,
+                            managed_buffer!(SUCCESSFUL_ACK),
+                        );
+                    },
+                )
+                .assert_ok();
+            check_ack(&setup, seq, Some(SUCCESSFUL_ACK), false);
+        }
+    }
+}
+
+#[test]
+fn try_write_ack_not_pending_test() {
+    let setup = get_ibc_handler_setup();
+    let mock_app_address = setup.mock_app_wrapper.address_ref().clone();
+    open_channel(&setup, channel::Order::Unordered);
+    recv_packet(&setup, 1, ASYNC_ACK_DATA);
+    recv_packet(&setup, 2, b"incoming data");
+
+    let write_ack = |seq: u64| {
+        setup.b_mock.borrow_mut().execute_tx(
+            &mock_app_address,
+            &setup.ibc_handler_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.write_ack_endpoint(
+                    managed_buffer!(PORT_ID),
+                    managed_buffer!(CHANNEL_ID),
+                    seq,
+                    managed_buffer!(SUCCESSFUL_ACK),
+                );
+            },
+        )
+    };
+
+    // neither a packet which wasn't received, nor one acknowledged synchronously
+    write_ack(3).assert_user_error("Ack not pending");
+    write_ack(2).assert_user_error("Ack not pending");
+
+    write_ack(1).assert_ok();
+    write_ack(1).assert_user_error("Ack not pending");
+}
+
+#[test]
+fn try_write_ack_not_capability_holder_test() {
+    let setup = get_ibc_handler_setup();
+    let relayer = setup.relayer.clone();
+    open_channel(&setup, channel::Order::Unordered);
+    recv_packet(&setup, 1, ASYNC_ACK_DATA);
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &relayer,
+            &setup.ibc_handler_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.write_ack_endpoint(
+                    managed_buffer!(PORT_ID),
+                    managed_buffer!(CHANNEL_ID),
+                    1,
+                    managed_buffer!(SUCCESSFUL_ACK),
+                );
+            },
+        )
+        .assert_user_error("Not allowed to use this port");
+
+    check_ack(&setup, 1, None, true);
+}
+
+#[test]
+fn timeout_packet_test() {
+    for ordering in [channel::Order::Unordered, channel::Order::Ordered] {
+        let setup = get_ibc_handler_setup();
+        let relayer = setup.relayer.clone();
+        open_channel(&setup, ordering);
+        commit_timed_out_packet(&setup, 1);
+        let proof = unreceived_proof(&setup, ordering, 1);
+
+        setup
+            .b_mock
+            .borrow_mut()
+            .execute_tx(
+                &relayer,
+                &setup.ibc_handler_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    sc.timeout_packet(MsgTimeoutPacket {
+                        packet: timed_out_packet(1),
+                        proof: ManagedBuffer::new_from_bytes(&proof),
+                        proof_height: PROOF_HEIGHT,
+                        next_seq_recv: 1,
+                    });
+                },
+            )
+            .assert_ok();
+
+        check_timed_out(&setup, ordering, 1);
+    }
+}
+
+#[test]
+fn timeout_packet_flushes_upgrade_test() {
+    for ordering in [channel::Order::Unordered, channel::Order::Ordered] {
+        let setup = get_ibc_handler_setup();
+        let relayer = setup.relayer.clone();
+        let owner = setup.owner.clone();
+        open_channel(&setup, ordering);
+        commit_timed_out_packet(&setup, 1);
+        let proof = unreceived_proof(&setup, ordering, 1);
+
+        // an upgrade whose only in-flight packet is the timed out one
+        setup
+            .b_mock
+            .borrow_mut()
+            .execute_tx(
+                &owner,
+                &setup.ibc_handler_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    let mut channel_info = open_channel_info(ordering);
+                    channel_info.channel.state = channel::State::Flushing;
+                    channel_info.channel.upgrade_sequence = 1;
+                    channel_info.next_seq_send = 2;
+                    channel_info.upgrade.fields = upgrade_fields::Data {
+                        ordering,
+                        connection_hops: channel_info.channel.connection_hops.clone(),
+                        version: managed_buffer!(b"mock-2"),
+                    };
+                    sc.channel_info(&managed_buffer!(PORT_ID), &managed_buffer!(CHANNEL_ID))
+                        .set(channel_info);
+                },
+            )
+            .assert_ok();
+
+        setup
+            .b_mock
+            .borrow_mut()
+            .execute_tx(
+                &relayer,
+                &setup.ibc_handler_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    sc.timeout_packet(MsgTimeoutPacket {
+                        packet: timed_out_packet(1),
+                        proof: ManagedBuffer::new_from_bytes(&proof),
+                        proof_height: PROOF_HEIGHT,
+                        next_seq_recv: 1,
+                    });
+                },
+            )
+            .assert_ok();
+
+        // UNORDERED channels complete their flush, while ORDERED ones are closed, cancelling the upgrade
+        setup
+            .b_mock
+            .borrow_mut()
+            .execute_query(&setup.ibc_handler_wrapper, |sc| {
+                let port_id = managed_buffer!(PORT_ID);
+                let channel_id = managed_buffer!(CHANNEL_ID);
+                let channel = sc.channel_info(&port_id, &channel_id).get().channel;
+                let error_key = sc.get_channel_upgrade_error_commitment_key(&port_id, &channel_id);
+                if matches!(ordering, channel::Order::Ordered) {
+                    assert!(matches!(channel.state, channel::State::Closed));
+                    assert!(!sc.commitments(&error_key).is_empty());
+                } else {
+                    assert!(matches!(channel.state, channel::State::FlushComplete));
+                    assert!(sc.commitments(&error_key).is_empty());
+                }
+
+                let channel_key = sc.get_channel_commitment_key(&port_id, &channel_id);
+                assert_eq!(
+                    sc.commitments(&channel_key).get(),
+                    sc.crypto().keccak256(channel.to_proto())
+                );
+            })
+            .assert_ok();
+    }
+}
+
+#[test]
+fn timeout_on_close_test() {
+    for ordering in [channel::Order::Unordered, channel::Order::Ordered] {
+        let setup = get_ibc_handler_setup();
+        DebugApi::dummy();
+        let relayer = setup.relayer.clone();
+        open_channel(&setup, ordering);
+        commit_timed_out_packet(&setup, 1);
+        let proof_unreceived = unreceived_proof(&setup, ordering, 1);
+        let proof_close = setup.mock_proof(
+            &channel_path(COUNTERPARTY_PORT_ID, COUNTERPARTY_CHANNEL_ID),
+            &counterparty_channel(channel::State::Closed, ordering)
+                .to_proto()
+                .to_boxed_bytes()
+                .into_vec(),
+        );
+
+        setup
+            .b_mock
+            .borrow_mut()
+            .execute_tx(
+                &relayer,
+                &setup.ibc_handler_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    sc.timeout_on_close(MsgTimeoutOnClose {
+                        packet: timed_out_packet(1),
+                        proof_unreceived: ManagedBuffer::new_from_bytes(&proof_unreceived),
+                        proof_close: ManagedBuffer::new_from_bytes(&proof_close),
+                        proof_height: PROOF_HEIGHT,
+                        next_seq_recv: 1,
+                        counterparty_upgrade_seq: 0,
+                    });
+                },
+            )
+            .assert_ok();
+
+        check_timed_out(&setup, ordering, 1);
+    }
+}
+
+#[test]
+fn try_timeout_on_close_counterparty_open_test() {
+    let setup = get_ibc_handler_setup();
+    DebugApi::dummy();
+    let relayer = setup.relayer.clone();
+    let ordering = channel::Order::Ordered;
+    open_channel(&setup, ordering);
+    commit_timed_out_packet(&setup, 1);
+    let proof_unreceived = unreceived_proof(&setup, ordering, 1);
+    let proof_open = setup.mock_proof(
+        &channel_path(COUNTERPARTY_PORT_ID, COUNTERPARTY_CHANNEL_ID),
+        &counterparty_channel(channel::State::Open, ordering)
+            .to_proto()
+            .to_boxed_bytes()
+            .into_vec(),
+    );
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &relayer,
+            &setup.ibc_handler_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.timeout_on_close(MsgTimeoutOnClose {
+                    packet: timed_out_packet(1),
+                    proof_unreceived: ManagedBuffer::new_from_bytes(&proof_unreceived),
+                    proof_close: ManagedBuffer::new_from_bytes(&proof_open),
+                    proof_height: PROOF_HEIGHT,
+                    next_seq_recv: 1,
+                    counterparty_upgrade_seq: 0,
+                });
+            },
+        )
+        .assert_user_error("Failed to verify channel state");
+}
+
+/// Two headers of the counterparty at the same height
+fn mock_misbehaviour(timestamp_1: u64, timestamp_2: u64) -> misbehaviour::Data {
+    misbehaviour::Data {
+        header_1: header::Data {
+            height: PROOF_HEIGHT,
+            timestamp: timestamp_1,
+        },
+        header_2: header::Data {
+            height: PROOF_HEIGHT,
+            timestamp: timestamp_2,
+        },
+    }
+}
+
+#[test]
+fn submit_misbehaviour_test() {
+    let setup = get_ibc_handler_setup();
+    let relayer = setup.relayer.clone();
+    let mock_app_address = setup.mock_app_wrapper.address_ref().clone();
+    open_channel(&setup, channel::Order::Unordered);
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &relayer,
+            &setup.ibc_handler_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.submit_misbehaviour(MsgSubmitMisbehaviour {
+                    client_id: managed_buffer!(CLIENT_ID),
+                    encoded_misbehaviour: managed_buffer!(&encode(&mock_misbehaviour(
+                        CONSENSUS_TIMESTAMP,
+                        CONSENSUS_TIMESTAMP + 1
+                    ))),
+                });
+            },
+        )
+        .assert_ok();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.mock_client_wrapper, |sc| {
+            assert!(sc.get_status(&managed_buffer!(CLIENT_ID)) == ClientStatus::Frozen);
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &mock_app_address,
+            &setup.ibc_handler_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.send_packet(
+                    managed_buffer!(PORT_ID),
+                    managed_buffer!(CHANNEL_ID),
+                    TIMEOUT_HEIGHT,
+                    0,
+                    managed_buffer!(b"outgoing data"),
+                );
+            },
+        )
+        .assert_user_error("Client not active");
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &relayer,
+            &setup.ibc_handler_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.recieve_packet(MsgPacketRecv {
+                    packet: test_packet(
+                        1,
+                        (COUNTERPARTY_PORT_ID, COUNTERPARTY_CHANNEL_ID),
+                        (PORT_ID, CHANNEL_ID),
+                        b"incoming data",
+                    ),
+                    proof: managed_buffer!(b"proof"),
+                    proof_height: PROOF_HEIGHT,
+                });
+            },
+        )
+        .assert_user_error("Client is frozen");
+}
+
+#[test]
+fn try_submit_misbehaviour_no_conflict_test() {
+    let setup = get_ibc_handler_setup();
+    let relayer = setup.relayer.clone();
+    create_client(&setup);
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &relayer,
+            &setup.ibc_handler_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.submit_misbehaviour(MsgSubmitMisbehaviour {
+                    client_id: managed_buffer!(CLIENT_ID),
+                    encoded_misbehaviour: managed_buffer!(&encode(&mock_misbehaviour(
+                        CONSENSUS_TIMESTAMP,
+                        CONSENSUS_TIMESTAMP
+                    ))),
+                });
+            },
+        )
+        .assert_user_error("Headers do not conflict");
+}
+
+const SUBSTITUTE_CLIENT_ID: &[u8] = b"mock-client-1";
+const SUBSTITUTE_HEIGHT: height::Data = height::Data {
+    revision_number: 0,
+    revision_height: 20,
+};
+
+/// Creates `CLIENT_ID`, frozen by misbehaviour, and the active `SUBSTITUTE_CLIENT_ID` at `SUBSTITUTE_HEIGHT`
+fn create_frozen_and_substitute_clients<
+    IbcHandlerObjBuilder,
+    MockClientObjBuilder,
+    MockAppObjBuilder,
+>(
+    setup: &IbcHandlerSetup<IbcHandlerObjBuilder, MockClientObjBuilder, MockAppObjBuilder>,
+) where
+    IbcHandlerObjBuilder: 'static + Copy + Fn() -> ibc_handler::ContractObj<DebugApi>,
+    MockClientObjBuilder: 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    MockAppObjBuilder: 'static + Copy + Fn() -> mock_app::ContractObj<DebugApi>,
+{
+    create_client(setup);
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &setup.relayer,
+            &setup.ibc_handler_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.submit_misbehaviour(MsgSubmitMisbehaviour {
+                    client_id: managed_buffer!(CLIENT_ID),
+                    encoded_misbehaviour: managed_buffer!(&encode(&mock_misbehaviour(
+                        CONSENSUS_TIMESTAMP,
+                        CONSENSUS_TIMESTAMP + 1
+                    ))),
+                });
+
+                let client_id = sc.create_client(MsgCreateClient {
+                    client_type: managed_buffer!(CLIENT_TYPE),
+                    encoded_client_state: managed_buffer!(&encode(&client_state::Data::new(
+                        SUBSTITUTE_HEIGHT
+                    ))),
+                    encoded_consensus_state: managed_buffer!(&encode(&consensus_state::Data::new(
+                        CONSENSUS_TIMESTAMP * 2
+                    ))),
+                });
+                assert_eq!(client_id, managed_buffer!(SUBSTITUTE_CLIENT_ID));
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn recover_client_test() {
+    let setup = get_ibc_handler_setup();
+    let owner = setup.owner.clone();
+    create_frozen_and_substitute_clients(&setup);
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &owner,
+            &setup.ibc_handler_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.recover_client(
+                    managed_buffer!(CLIENT_ID),
+                    managed_buffer!(SUBSTITUTE_CLIENT_ID),
+                );
+
+                let client_id = managed_buffer!(CLIENT_ID);
+                let client_state_key = sc.get_client_state_commitment_key(&client_id);
+                assert_eq!(
+                    sc.commitments(&client_state_key).get(),
+                    sc.crypto()
+                        .keccak256(managed_buffer!(&committed_client_state(SUBSTITUTE_HEIGHT)))
+                );
+
+                let consensus_state_key = sc.get_consensus_state_commitment_key(
+                    &client_id,
+                    SUBSTITUTE_HEIGHT.revision_number,
+                    SUBSTITUTE_HEIGHT.revision_height,
+                );
+                assert_eq!(
+                    sc.commitments(&consensus_state_key).get(),
+                    sc.crypto()
+                        .keccak256(managed_buffer!(&committed_consensus_state(
+                            CONSENSUS_TIMESTAMP * 2
+                        )))
+                );
+            },
+        )
+        .assert_ok();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.mock_client_wrapper, |sc| {
+            let client_id = managed_buffer!(CLIENT_ID);
+            assert!(sc.get_status(&client_id) == ClientStatus::Active);
+            assert!(sc.get_latest_height(&client_id) == SUBSTITUTE_HEIGHT);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn try_recover_client_test() {
+    let setup = get_ibc_handler_setup();
+    let owner = setup.owner.clone();
+    create_frozen_and_substitute_clients(&setup);
+
+    let recover_client = |subject: &[u8], substitute: &[u8]| {
+        setup.b_mock.borrow_mut().execute_tx(
+            &owner,
+            &setup.ibc_handler_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.recover_client(managed_buffer!(subject), managed_buffer!(substitute));
+            },
+        )
+    };
+
+    recover_client(CLIENT_ID, CLIENT_ID)
+        .assert_user_error("Subject and substitute clients must differ");
+    recover_client(CLIENT_ID, b"mock-client-2").assert_user_error("Client not found");
+    recover_client(SUBSTITUTE_CLIENT_ID, CLIENT_ID).assert_user_error("Subject client is active");
+
+    let set_status = |client_id: &[u8], status: ClientStatus| {
+        setup
+            .b_mock
+            .borrow_mut()
+            .execute_tx(
+                &owner,
+                &setup.mock_client_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    sc.set_status(managed_buffer!(client_id), status);
+                },
+            )
+            .assert_ok();
+    };
+
+    set_status(SUBSTITUTE_CLIENT_ID, ClientStatus::Expired);
+    recover_client(SUBSTITUTE_CLIENT_ID, CLIENT_ID)
+        .assert_user_error("Substitute client is not active");
+
+    // the substitute must be more recent than the subject
+    set_status(CLIENT_ID, ClientStatus::Active);
+    recover_client(SUBSTITUTE_CLIENT_ID, CLIENT_ID).assert_user_error(
+        "Substitute client height must be greater than the subject client height",
+    );
+}
+
+/// The first height of the next revision of the counterparty
+const UPGRADED_HEIGHT: height::Data = height::Data {
+    revision_number: 1,
+    revision_height: 1,
+};
+
+fn upgraded_client_path(upgrade_height: u64) -> Vec<u8> {
+    format!("upgradedIBCState/{}/upgradedClient", upgrade_height).into_bytes()
+}
+
+fn upgraded_consensus_state_path(upgrade_height: u64) -> Vec<u8> {
+    format!("upgradedIBCState/{}/upgradedConsState", upgrade_height).into_bytes()
+}
+
+/// Upgrades `CLIENT_ID` to the given height, with the states committed by the counterparty at `PROOF_HEIGHT`
+fn upgrade_client<IbcHandlerObjBuilder, MockClientObjBuilder, MockAppObjBuilder>(
+    setup: &IbcHandlerSetup<IbcHandlerObjBuilder, MockClientObjBuilder, MockAppObjBuilder>,
+    upgraded_height: height::Data,
+    committed_height: height::Data,
+) -> TxResult
+where
+    IbcHandlerObjBuilder: 'static + Copy + Fn() -> ibc_handler::ContractObj<DebugApi>,
+    MockClientObjBuilder: 'static + Copy + Fn() -> mock::ContractObj<DebugApi>,
+    MockAppObjBuilder: 'static + Copy + Fn() -> mock_app::ContractObj<DebugApi>,
+{
+    DebugApi::dummy();
+    let proof_upgrade_client = setup.mock_proof_in_store(
+        UPGRADE_STORE_KEY,
+        &upgraded_client_path(PROOF_HEIGHT.revision_height),
+        &encode(&client_state::Data::new(committed_height)),
+    );
+    let proof_upgrade_consensus_state = setup.mock_proof_in_store(
+        UPGRADE_STORE_KEY,
+        &upgraded_consensus_state_path(PROOF_HEIGHT.revision_height),
+        &encode(&consensus_state::Data::new(CONSENSUS_TIMESTAMP * 2)),
+    );
+
+    setup.b_mock.borrow_mut().execute_tx(
+        &setup.relayer,
+        &setup.ibc_handler_wrapper,
+        &rust_biguint!(0),
+        |sc| {
+            sc.upgrade_client(MsgUpgradeClient {
+                client_id: managed_buffer!(CLIENT_ID),
+                encoded_client_state: managed_buffer!(&encode(&client_state::Data::new(
+                    upgraded_height
+                ))),
+                encoded_consensus_state: managed_buffer!(&encode(&consensus_state::Data::new(
+                    CONSENSUS_TIMESTAMP * 2
+                ))),
+                proof_upgrade_client: managed_buffer!(&proof_upgrade_client),
+                proof_upgrade_consensus_state: managed_buffer!(&proof_upgrade_consensus_state),
+            });
+        },
+    )
+}
+
+#[test]
+fn upgrade_client_test() {
+    let setup = get_ibc_handler_setup();
+    create_client(&setup);
+
+    upgrade_client(&setup, UPGRADED_HEIGHT, UPGRADED_HEIGHT).assert_ok();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.ibc_handler_wrapper, |sc| {
+            let client_id = managed_buffer!(CLIENT_ID);
+            let client_state_key = sc.get_client_state_commitment_key(&client_id);
+            assert_eq!(
+                sc.commitments(&client_state_key).get(),
+                sc.crypto()
+                    .keccak256(managed_buffer!(&committed_client_state(UPGRADED_HEIGHT)))
+            );
+
+            let consensus_state_key = sc.get_consensus_state_commitment_key(
+                &client_id,
+                UPGRADED_HEIGHT.revision_number,
+                UPGRADED_HEIGHT.revision_height,
+            );
+            assert_eq!(
+                sc.commitments(&consensus_state_key).get(),
+                sc.crypto()
+                    .keccak256(managed_buffer!(&committed_consensus_state(
+                        CONSENSUS_TIMESTAMP * 2
+                    )))
+            );
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_query(&setup.mock_client_wrapper, |sc| {
+            let client_id = managed_buffer!(CLIENT_ID);
+            assert!(sc.get_latest_height(&client_id) == UPGRADED_HEIGHT);
+            assert_eq!(
+                sc.get_timestamp_at_height(&client_id, &UPGRADED_HEIGHT),
+                CONSENSUS_TIMESTAMP * 2
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn try_upgrade_client_test() {
+    let setup = get_ibc_handler_setup();
+    create_client(&setup);
+
+    // the upgraded client must be the one committed by the counterparty
+    let other_height = height::Data {
+        revision_number: 1,
+        revision_height: 2,
+    };
+    upgrade_client(&setup, UPGRADED_HEIGHT, other_height)
+        .assert_user_error("Failed to verify upgraded client");
+
+    upgrade_client(&setup, PROOF_HEIGHT, PROOF_HEIGHT)
+        .assert_user_error("Upgraded client height must be greater than the current height");
+
+    setup
+        .b_mock
+        .borrow_mut()
+        .execute_tx(
+            &setup.owner,
+            &setup.mock_client_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_status(managed_buffer!(CLIENT_ID), ClientStatus::Frozen);
+            },
+        )
+        .assert_ok();
+    upgrade_client(&setup, UPGRADED_HEIGHT, UPGRADED_HEIGHT).assert_user_error("Client not active");
+}
@@ -0,0 +1,73 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           47
+// Async Callback (empty):               1
+// Total number of exported functions:  50
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    ibc_handler
+    (
+        init => init
+        upgrade => upgrade
+        createClient => create_client
+        updateClient => update_client
+        upgradeClient => upgrade_client
+        submitMisbehaviour => submit_misbehaviour
+        recoverClient => recover_client
+        updateClientCommitments => update_client_commitments
+        getCompatibleVersions => get_compatible_versions
+        connectionOpenInit => connection_open_init
+        connectionOpenTry => connection_open_try
+        connectionOpenAck => connection_open_ack
+        connectionOpenConfirm => connection_open_confirm
+        channelOpenInit => channel_open_init
+        channelOpenTry => channel_open_try
+        channelOpenAck => channel_open_ack
+        channelOpenConfirm => channel_open_confirm
+        channelCloseInit => channel_close_init
+        channelCloseConfirm => channel_close_confirm
+        channelUpgradeInit => channel_upgrade_init
+        channelUpgradeTry => channel_upgrade_try
+        channelUpgradeAck => channel_upgrade_ack
+        channelUpgradeConfirm => channel_upgrade_confirm
+        channelUpgradeOpen => channel_upgrade_open
+        channelUpgradeCancel => channel_upgrade_cancel
+        channelUpgradeTimeout => channel_upgrade_timeout
+        timeoutPacket => timeout_packet
+        timeoutOnClose => timeout_on_close
+        sendPacket => send_packet
+        recvPacket => recieve_packet
+        writeAcknowledgement => write_ack_endpoint
+        getPendingAcks => get_pending_acks
+        isAckPending => is_ack_pending
+        acknowledgePacket => ack_packet
+        setExpectedTimePerBlock => set_expected_time_per_block
+        setSelfClientTypeUrls => set_self_client_type_urls
+        registerClient => register_client
+        bindPort => bind_port
+        setPortMiddlewares => set_port_middlewares
+        bindPortPrefix => bind_port_prefix
+        bindPrefixedPort => bind_prefixed_port
+        getHostTimestamp => get_host_timestamp
+        getCommitmentPrefix => get_commitment_prefix
+        checkAndGetClient => check_and_get_client
+        getChannelStack => get_channel_stack
+        getCommitment => get_commitment
+        getConnection => get_connection
+        isChannelOpen => is_channel_open
+        getPortMiddlewares => port_middlewares
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}